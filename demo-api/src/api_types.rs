@@ -37,6 +37,13 @@ pub(crate) struct ProductFootprintResponse {
     pub(crate) data: ProductFootprint<ILeapType>,
 }
 
+#[derive(Debug, Serialize, Deserialize, Clone, JsonSchema, PartialEq)]
+#[serde(crate = "rocket::serde", rename_all = "camelCase")]
+/// HTTP Body of the detached JWS signature of a footprint
+pub(crate) struct FootprintSignatureResponse {
+    pub(crate) data: String,
+}
+
 #[derive(Debug, Serialize, Deserialize, JsonSchema, PartialEq)]
 #[serde(crate = "rocket::serde", rename_all = "camelCase")]
 /// HTTP Body of Action `ListFootprints`
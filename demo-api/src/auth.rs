@@ -11,6 +11,10 @@
 use std::collections::HashSet;
 
 use jsonwebtoken::errors::Result;
+use jsonwebtoken::jwk::{
+    AlgorithmParameters, CommonParameters, Jwk, JwkSet, KeyAlgorithm, PublicKeyUse,
+    RSAKeyParameters, RSAKeyType,
+};
 use jsonwebtoken::{Algorithm, TokenData};
 use jsonwebtoken::{DecodingKey, EncodingKey};
 use jsonwebtoken::{Header, Validation};
@@ -21,7 +25,10 @@ use rocket::request::{self, FromRequest, Request};
 use rocket::response::status;
 use rocket::serde::{Deserialize, Serialize};
 
-use base64::{engine::general_purpose::STANDARD, Engine as _};
+use base64::{
+    engine::general_purpose::{STANDARD, URL_SAFE_NO_PAD},
+    Engine as _,
+};
 use rocket_okapi::okapi::map;
 use rocket_okapi::okapi::openapi3::{
     Object, SecurityRequirement, SecurityScheme, SecuritySchemeData,
@@ -32,15 +39,44 @@ use rocket_okapi::{
 };
 use rsa::pkcs8::DecodePrivateKey;
 use rsa::pkcs8::EncodePublicKey;
+use rsa::traits::PublicKeyParts;
 use rsa::{pkcs8::LineEnding, RsaPrivateKey, RsaPublicKey};
 
+/// The `kid` of the public key in the Json Web Key Set
+pub const KEY_ID: &str = "Public key";
+
 #[derive(Clone)]
 pub struct KeyPair {
     pub pub_key: RsaPublicKey,
-    enc_key: EncodingKey,
+    pub(crate) enc_key: EncodingKey,
     dec_key: DecodingKey,
 }
 
+impl KeyPair {
+    /// The Json Web Key Set containing the public key of this key pair
+    pub fn jwk_set(&self) -> JwkSet {
+        JwkSet {
+            keys: vec![Jwk {
+                common: CommonParameters {
+                    public_key_use: Some(PublicKeyUse::Signature),
+                    key_operations: None,
+                    key_algorithm: Some(KeyAlgorithm::RS256),
+                    key_id: Some(KEY_ID.to_string()),
+                    x509_url: None,
+                    x509_chain: None,
+                    x509_sha1_fingerprint: None,
+                    x509_sha256_fingerprint: None,
+                },
+                algorithm: AlgorithmParameters::RSA(RSAKeyParameters {
+                    key_type: RSAKeyType::RSA,
+                    n: URL_SAFE_NO_PAD.encode(self.pub_key.n().to_bytes_be()),
+                    e: URL_SAFE_NO_PAD.encode(self.pub_key.e().to_bytes_be()),
+                }),
+            }],
+        }
+    }
+}
+
 #[derive(Clone, Debug, Serialize, Deserialize)]
 #[serde(crate = "rocket::serde", rename_all = "camelCase")]
 pub struct UserToken {
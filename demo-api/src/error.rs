@@ -43,6 +43,13 @@ pub(crate) enum NotImplementedCode {
     NotImplemented,
 }
 
+/// Error code enum for InternalError responses
+#[derive(Serialize, Deserialize, JsonSchema, PartialEq, Debug, Clone, Copy)]
+#[serde(crate = "rocket::serde")]
+pub(crate) enum InternalErrorCode {
+    InternalError,
+}
+
 /// Error code enum for Unauthorized responses
 #[derive(Serialize, Deserialize, JsonSchema, PartialEq, Debug, Clone, Copy)]
 #[serde(crate = "rocket::serde")]
@@ -57,6 +64,14 @@ pub(crate) enum GetPfError {
     BadRequest(BadRequest),
 }
 
+#[derive(Serialize, JsonSchema, PartialEq, Debug)]
+#[serde(crate = "rocket::serde")]
+pub(crate) enum GetPfSignatureError {
+    NoSuchFootprint(NoSuchFootprint),
+    BadRequest(BadRequest),
+    InternalError(InternalError),
+}
+
 #[derive(Serialize, Deserialize, JsonSchema, PartialEq, Debug)]
 #[serde(crate = "rocket::serde")]
 #[allow(dead_code)] // TODO: remove struct if not used
@@ -98,6 +113,14 @@ pub(crate) struct NotImplemented {
     pub(crate) code: NotImplementedCode,
 }
 
+#[derive(Serialize, Deserialize, JsonSchema, PartialEq, Debug)]
+#[serde(crate = "rocket::serde")]
+/// Response with an error code of `InternalError`. See Chapter "Error Codes" of the Tech Specs for mor details.
+pub(crate) struct InternalError {
+    pub(crate) message: &'static str,
+    pub(crate) code: InternalErrorCode,
+}
+
 #[derive(Serialize, Deserialize, JsonSchema, PartialEq, Debug)]
 #[serde(crate = "rocket::serde")]
 #[allow(dead_code)] // TODO: remove struct if not used
@@ -143,6 +166,15 @@ impl Default for NotImplemented {
     }
 }
 
+impl Default for InternalError {
+    fn default() -> Self {
+        InternalError {
+            message: "Internal Error",
+            code: InternalErrorCode::InternalError,
+        }
+    }
+}
+
 impl Default for Unauthorized {
     fn default() -> Self {
         Unauthorized {
@@ -161,6 +193,16 @@ impl<'r, 'o: 'r> Responder<'r, 'o> for GetPfError {
     }
 }
 
+impl<'r, 'o: 'r> Responder<'r, 'o> for GetPfSignatureError {
+    fn respond_to(self, req: &'r Request<'_>) -> response::Result<'o> {
+        match self {
+            GetPfSignatureError::BadRequest(e) => e.respond_to(req),
+            GetPfSignatureError::NoSuchFootprint(e) => e.respond_to(req),
+            GetPfSignatureError::InternalError(e) => e.respond_to(req),
+        }
+    }
+}
+
 impl<'r, 'o: 'r> Responder<'r, 'o> for NoSuchFootprint {
     fn respond_to(self, request: &'r Request<'_>) -> response::Result<'o> {
         Response::build()
@@ -197,6 +239,15 @@ impl<'r, 'o: 'r> Responder<'r, 'o> for NotImplemented {
     }
 }
 
+impl<'r, 'o: 'r> Responder<'r, 'o> for InternalError {
+    fn respond_to(self, request: &'r Request<'_>) -> response::Result<'o> {
+        Response::build()
+            .merge(Json(self).respond_to(request)?)
+            .status(Status::InternalServerError)
+            .ok()
+    }
+}
+
 impl<'r, 'o: 'r> Responder<'r, 'o> for Unauthorized {
     fn respond_to(self, request: &'r Request<'_>) -> response::Result<'o> {
         Response::build()
@@ -233,6 +284,19 @@ impl OpenApiResponderInner for GetPfError {
     }
 }
 
+impl OpenApiResponderInner for GetPfSignatureError {
+    fn responses(gen: &mut OpenApiGenerator) -> Result<Responses, OpenApiError> {
+        let mut responses = GetPfError::responses(gen)?;
+
+        let internal_error_responses = InternalError::responses(gen)?;
+        for (code, resp) in internal_error_responses.responses {
+            responses.responses.insert(code, resp);
+        }
+
+        Ok(responses)
+    }
+}
+
 impl OpenApiResponderInner for BadRequest {
     fn responses(gen: &mut OpenApiGenerator) -> Result<Responses, OpenApiError> {
         let resp = openapi_response::<BadRequest>(
@@ -274,6 +338,21 @@ impl OpenApiResponderInner for NoSuchFootprint {
     }
 }
 
+impl OpenApiResponderInner for InternalError {
+    fn responses(gen: &mut OpenApiGenerator) -> Result<Responses, OpenApiError> {
+        let resp = openapi_response::<InternalError>(
+            gen,
+            "500".to_owned(),
+            "\
+            # 500 Internal Server Error\n\
+            The server failed to process the request. \
+            "
+            .to_owned(),
+        );
+        Ok(resp)
+    }
+}
+
 impl OpenApiResponderInner for Unauthorized {
     fn responses(gen: &mut OpenApiGenerator) -> Result<Responses, OpenApiError> {
         let resp = openapi_response::<Unauthorized>(
@@ -19,10 +19,7 @@ mod sample_data;
 use auth::{load_keys, UserToken};
 use chrono::{DateTime, Utc};
 use ileap_data_model::Toc;
-use jsonwebtoken::jwk::{
-    AlgorithmParameters, CommonParameters, Jwk, JwkSet, KeyAlgorithm, PublicKeyUse,
-    RSAKeyParameters,
-};
+use jsonwebtoken::jwk::JwkSet;
 use okapi::openapi3::{Object, Parameter, ParameterValue};
 use rocket::form::Form;
 use rocket::request::FromRequest;
@@ -41,10 +38,8 @@ use std::cmp::min;
 use std::collections::HashMap;
 
 use api_types::*;
-use base64::{engine::general_purpose::URL_SAFE_NO_PAD, Engine as _};
 use openid_conf::OpenIdConfiguration;
 use pact_data_model::*;
-use rsa::traits::PublicKeyParts;
 use sample_data::{ILEAP_TAD_DEMO_DATA, PCF_DEMO_DATA};
 
 #[cfg(test)]
@@ -88,29 +83,7 @@ fn openid_configuration() -> Json<OpenIdConfiguration> {
 /// endpoint to retrieve the Json Web Key Set to verify the token's signature
 #[get("/jwks")]
 fn jwks(state: &State<KeyPair>) -> Json<JwkSet> {
-    let pub_key = &state.pub_key;
-
-    let jwks = JwkSet {
-        keys: vec![Jwk {
-            common: CommonParameters {
-                public_key_use: Some(PublicKeyUse::Signature),
-                key_operations: None,
-                key_algorithm: Some(KeyAlgorithm::RS256),
-                key_id: Some("Public key".to_string()),
-                x509_url: None,
-                x509_chain: None,
-                x509_sha1_fingerprint: None,
-                x509_sha256_fingerprint: None,
-            },
-            algorithm: AlgorithmParameters::RSA(RSAKeyParameters {
-                key_type: jsonwebtoken::jwk::RSAKeyType::RSA,
-                n: URL_SAFE_NO_PAD.encode(pub_key.n().to_bytes_be()),
-                e: URL_SAFE_NO_PAD.encode(pub_key.e().to_bytes_be()),
-            }),
-        }],
-    };
-
-    Json(jwks)
+    Json(state.jwk_set())
 }
 
 /// endpoint to create an oauth2 client credentials grant (RFC 6749 4.4)
@@ -277,16 +250,28 @@ fn filtered_data(filter: Option<&'_ str>) -> Result<Vec<ProductFootprint<ILeapTy
                                 .map(|v| v.to_string() == value)
                                 .unwrap_or_default(),
                             "productCategoryCpc" => pf.product_category_cpc.0 == value,
-                            "pcf/geographyCountry" => pf
-                                .clone()
-                                .pcf
-                                .geographic_scope
-                                .map(|v| {
-                                    v.geography_country()
-                                        .map(|v| v.0 == value)
-                                        .unwrap_or_default()
-                                })
-                                .unwrap_or_default(),
+                            // matches footprints of the country or of one of its subdivisions
+                            "pcf/geographyCountry" => {
+                                let country = GeographicScope::Country(ISO3166CC(value.clone()));
+                                pf.pcf
+                                    .geographic_scope
+                                    .as_ref()
+                                    .map(|v| country.contains(v))
+                                    .unwrap_or_default()
+                            }
+                            // matches footprints of the region or subregion, or of a subregion,
+                            // country or subdivision within it
+                            "pcf/geographyRegionOrSubregion" => {
+                                let region: UNRegionOrSubregion = serde_json::from_value(
+                                    serde_json::Value::String(value.clone()),
+                                )
+                                .map_err(|_| format!("Unknown region or subregion {value}"))?;
+                                pf.pcf
+                                    .geographic_scope
+                                    .as_ref()
+                                    .map(|v| v.is_within(&region))
+                                    .unwrap_or_default()
+                            }
                             "pcf/referencePeriodStart" => {
                                 pf.pcf.reference_period_start.to_string() == value
                             }
@@ -455,6 +440,30 @@ fn get_pcf(
         .unwrap_or_else(|| Err(error::GetPfError::NoSuchFootprint(Default::default())))
 }
 
+/// endpoint to retrieve a detached JWS signature of a footprint, see `pact_data_model::sign_footprint`
+#[openapi]
+#[get("/2/footprints/<id>/signature", format = "json")]
+fn get_pcf_signature(
+    id: PfIdParam,
+    auth: Option<UserToken>,
+    state: &State<KeyPair>,
+) -> Result<Json<FootprintSignatureResponse>, error::GetPfSignatureError> {
+    let Some(auth) = auth else {
+        return Err(error::GetPfSignatureError::BadRequest(Default::default()));
+    };
+
+    let footprints = filtered_by_auth(PCF_DEMO_DATA.to_vec(), &auth.username);
+    let Some(pcf) = footprints.iter().find(|pf| pf.id == id.0) else {
+        return Err(error::GetPfSignatureError::NoSuchFootprint(
+            Default::default(),
+        ));
+    };
+
+    sign_footprint(pcf, &state.enc_key, auth::KEY_ID)
+        .map(|signature| Json(FootprintSignatureResponse { data: signature }))
+        .map_err(|_| error::GetPfSignatureError::InternalError(Default::default()))
+}
+
 #[get("/2/footprints/<_id>", format = "json", rank = 2)]
 fn get_pcf_unauth(_id: &str) -> error::BadRequest {
     Default::default()
@@ -649,8 +658,13 @@ const OPENAPI_PATH: &str = "../openapi.json";
 
 fn create_server(key_pair: KeyPair) -> rocket::Rocket<rocket::Build> {
     let settings = OpenApiSettings::default();
-    let (mut openapi_routes, openapi_spec) =
-        openapi_get_routes_spec![settings: get_pcf, get_footprints, post_event, get_tad];
+    let (mut openapi_routes, openapi_spec) = openapi_get_routes_spec![
+        settings: get_pcf,
+        get_footprints,
+        get_pcf_signature,
+        post_event,
+        get_tad
+    ];
 
     openapi_routes.push(get_openapi_route(openapi_spec, &settings));
 
@@ -794,6 +808,66 @@ fn verify_token_signature_test() {
     );
 }
 
+#[test]
+fn verify_footprint_signature_test() {
+    let client = &Client::tracked(create_server(TEST_KEYPAIR.clone())).unwrap();
+
+    let token = UserToken {
+        username: "hello".to_string(),
+    };
+
+    let key_pair = client.rocket().state::<KeyPair>().unwrap();
+
+    let jwt = auth::encode_token(&token, key_pair).ok().unwrap();
+    let bearer_token = format!("Bearer {jwt}");
+
+    let jwks: JwkSet = client.get("/jwks").dispatch().into_json().unwrap();
+
+    let id = &PCF_DEMO_DATA[0].id.0;
+    let pf: ProductFootprintResponse = client
+        .get(format!("/2/footprints/{id}"))
+        .header(rocket::http::Header::new(
+            "Authorization",
+            bearer_token.clone(),
+        ))
+        .dispatch()
+        .into_json()
+        .unwrap();
+    let resp = client
+        .get(format!("/2/footprints/{id}/signature"))
+        .header(rocket::http::Header::new(
+            "Authorization",
+            bearer_token.clone(),
+        ))
+        .dispatch();
+    assert_eq!(rocket::http::Status::Ok, resp.status());
+    let signature: FootprintSignatureResponse = resp.into_json().unwrap();
+
+    assert!(signature.data.contains(".."));
+    verify_footprint(&pf.data, &signature.data, &jwks).unwrap();
+
+    let mut tampered = pf.data.clone();
+    tampered.comment = "tampered".to_string();
+    assert!(matches!(
+        verify_footprint(&tampered, &signature.data, &jwks),
+        Err(SignatureError::Invalid)
+    ));
+    assert!(matches!(
+        verify_footprint(&pf.data, "abc.def.ghi", &jwks),
+        Err(SignatureError::Malformed)
+    ));
+    assert!(matches!(
+        verify_footprint(&pf.data, &signature.data, &JwkSet { keys: vec![] }),
+        Err(SignatureError::UnknownKey(Some(_)))
+    ));
+
+    // unauthenticated
+    let resp = client
+        .get(format!("/2/footprints/{id}/signature"))
+        .dispatch();
+    assert_eq!(rocket::http::Status::BadRequest, resp.status());
+}
+
 #[test]
 fn get_list_test() {
     let client = &Client::tracked(create_server(TEST_KEYPAIR.clone())).unwrap();
@@ -860,7 +934,51 @@ fn get_list_with_filter_eq_test() {
 
     assert_eq!(rocket::http::Status::Ok, resp.status());
     let json: PfListingResponseInner = resp.into_json().unwrap();
-    assert_eq!(json.data.len(), 1);
+    // the footprints with geographyCountry FR and geographyCountrySubdivision FR-89
+    assert_eq!(json.data.len(), 2);
+}
+
+#[test]
+fn get_list_with_filter_region_test() {
+    let client = &Client::tracked(create_server(TEST_KEYPAIR.clone())).unwrap();
+
+    let token = UserToken {
+        username: "hello".to_string(),
+    };
+
+    let key_pair = client.rocket().state::<KeyPair>().unwrap();
+
+    let jwt = auth::encode_token(&token, key_pair).ok().unwrap();
+    let bearer_token = format!("Bearer {jwt}");
+
+    let get_list_with_limit_uri =
+        "/2/footprints?$filter=pcf/geographyRegionOrSubregion+eq+'Europe'";
+
+    let resp = client
+        .get(get_list_with_limit_uri)
+        .header(rocket::http::Header::new(
+            "Authorization",
+            bearer_token.clone(),
+        ))
+        .header(rocket::http::Header::new("Host", EXAMPLE_HOST))
+        .dispatch();
+
+    assert_eq!(rocket::http::Status::Ok, resp.status());
+    let json: PfListingResponseInner = resp.into_json().unwrap();
+    // Western Europe, DE, FR and FR-89
+    assert_eq!(json.data.len(), 4);
+
+    let get_list_with_limit_uri = "/2/footprints?$filter=pcf/geographyRegionOrSubregion+eq+'Asia'";
+
+    let resp = client
+        .get(get_list_with_limit_uri)
+        .header(rocket::http::Header::new("Authorization", bearer_token))
+        .header(rocket::http::Header::new("Host", EXAMPLE_HOST))
+        .dispatch();
+
+    assert_eq!(rocket::http::Status::Ok, resp.status());
+    let json: PfListingResponseInner = resp.into_json().unwrap();
+    assert_eq!(json.data.len(), 0);
 }
 
 #[test]
@@ -14,7 +14,7 @@ use uuid::uuid;
 lazy_static!(
     static ref EXAMPLE_1: ProductFootprint<ILeapType> = ProductFootprint {
         id: PfId(uuid!("91715e5e-fd0b-4d1c-8fab-76290c46e6ed")),
-        spec_version: SpecVersionString::from("2.0.0".to_string()),
+        spec_version: SpecVersionString("2.0.0".to_string()),
         preceding_pf_ids: None,
         version: VersionInteger(1),
         created: Utc.with_ymd_and_hms(2022, 3, 1, 9, 32, 20).unwrap(),
@@ -23,35 +23,35 @@ lazy_static!(
         status_comment: None,
         validity_period_start: Some(Utc.with_ymd_and_hms(2022, 3, 1, 9, 32, 20).unwrap()),
         validity_period_end: Some(Utc.with_ymd_and_hms(2024, 12, 31, 00, 00, 00).unwrap()),
-        company_name: String::from("My Corp").into(),
-        company_ids: CompanyIdSet(vec![Urn::from("urn:uuid:69585GB6-56T9-6958-E526-6FDGZJHU1326".to_string()), Urn::from("urn:epc:id:sgln:562958.00000.4".to_string())]),
+        company_name: String::from("My Corp").try_into().unwrap(),
+        company_ids: CompanyIdSet(vec![Urn("urn:uuid:69585GB6-56T9-6958-E526-6FDGZJHU1326".to_string()), Urn("urn:epc:id:sgln:562958.00000.4".to_string())]),
         product_description: "Bio-Ethanol 98%, corn feedstock (bulk - no packaging)".to_string(),
-        product_ids: ProductIdSet(vec![Urn::from("urn:gtin:5695872369587".to_string())]),
-        product_category_cpc: String::from("6398").into(),
-        product_name_company: String::from("Green Ethanol").into(),
+        product_ids: ProductIdSet(vec![Urn("urn:gtin:5695872369587".to_string())]),
+        product_category_cpc: String::from("6398").try_into().unwrap(),
+        product_name_company: String::from("Green Ethanol").try_into().unwrap(),
         comment: "".into(),
         pcf: CarbonFootprint {
             declared_unit: DeclaredUnit::Liter,
-            unitary_product_amount: dec!(1).into(),
-            p_cf_excluding_biogenic: dec!(1.63).into(),
+            unitary_product_amount: dec!(1).try_into().unwrap(),
+            p_cf_excluding_biogenic: dec!(1.63).try_into().unwrap(),
             p_cf_including_biogenic: Some(dec!(1.85).into()),
-            fossil_ghg_emissions: dec!(1.5).into(),
-            fossil_carbon_content: dec!(0).into(),
-            biogenic_carbon_content: dec!(0.41).into(),
-            d_luc_ghg_emissions: Some(dec!(0.8).into()),
-            land_management_ghg_emissions: Some(dec!(0.6).into()),
-            other_biogenic_ghg_emissions: Some(dec!(0.4).into()),
-            i_luc_ghg_emissions: Some(dec!(0).into()),
-            biogenic_carbon_withdrawal: Some(dec!(-1.5).into()),
-            aircraft_ghg_emissions: Some(dec!(0.2).into()),
+            fossil_ghg_emissions: dec!(1.5).try_into().unwrap(),
+            fossil_carbon_content: dec!(0).try_into().unwrap(),
+            biogenic_carbon_content: dec!(0.41).try_into().unwrap(),
+            d_luc_ghg_emissions: Some(dec!(0.8).try_into().unwrap()),
+            land_management_ghg_emissions: Some(dec!(0.6).try_into().unwrap()),
+            other_biogenic_ghg_emissions: Some(dec!(0.4).try_into().unwrap()),
+            i_luc_ghg_emissions: Some(dec!(0).try_into().unwrap()),
+            biogenic_carbon_withdrawal: Some(dec!(-1.5).try_into().unwrap()),
+            aircraft_ghg_emissions: Some(dec!(0.2).try_into().unwrap()),
             characterization_factors: CharacterizationFactors::Ar6,
-            ipcc_characterization_factors_sources: IpccCharacterizationFactorsSources::from(vec![String::from("AR6").into()]),
+            ipcc_characterization_factors_sources: vec![CharacterizationFactors::Ar6.into()].try_into().unwrap(),
             cross_sectoral_standards_used: CrossSectoralStandardSet(vec![DeprecatedCrossSectoralStandard::Ghgp, DeprecatedCrossSectoralStandard::ISO14067]),
             product_or_sector_specific_rules: Some(ProductOrSectorSpecificRuleSet(vec![
                 ProductOrSectorSpecificRule {
                     operator: ProductOrSectorSpecificRuleOperator::Other,
-                    rule_names: vec![String::from("The Product Carbon Footprint Guideline for the Chemical Industry, v.2.0").into()].into(),
-                    other_operator_name: Some(String::from("Tfs").into())
+                    rule_names: vec![String::from("The Product Carbon Footprint Guideline for the Chemical Industry, v.2.0").try_into().unwrap()].try_into().unwrap(),
+                    other_operator_name: Some(String::from("Tfs").try_into().unwrap())
                 }])),
             biogenic_accounting_methodology: Some(BiogenicAccountingMethodology::Ghgp),
             boundary_processes_description: String::from("1) Material acquisition and preprocessing, including growth of corn 2) Production: fuel consumption, electricity consumption, water consumption, process-generated direct emissions 3) Distribution and storage: transportation of the finished product from manufacturing site to storage site"),
@@ -59,8 +59,8 @@ lazy_static!(
             reference_period_end: Utc.with_ymd_and_hms(2022, 1, 1, 00, 00, 00).unwrap(),
             geographic_scope: Some(GeographicScope::Regional(UNRegionOrSubregion::WesternEurope)),
             secondary_emission_factor_sources: Some(EmissionFactorDSSet(vec![EmissionFactorDS {
-                name: String::from("Ecoinvent").into(),
-                version: String::from("3.1").into(),
+                name: String::from("Ecoinvent").try_into().unwrap(),
+                version: String::from("3.1").try_into().unwrap(),
             }])),
             exempted_emissions_percent: ExemptedEmissionsPercent(0.0),
             exempted_emissions_description: "".to_string(),
@@ -68,14 +68,14 @@ lazy_static!(
             packaging_ghg_emissions: None,
             allocation_rules_description: Some("Using mass allocation following the product specific rule as per PACT Framework decision-making tree".to_string()),
             uncertainty_assessment_description: Some("A model of corn production is involved in predicting emissions from the production of the corn feedstock. Emissions of N2O due to application of nitrogen fertilizers are based on a linear modeling of interactions of the fertilizer with the soil and plant systems. As these interactions are more complicated than the model assumes, there is uncertainty regarding the emissions resulting from this model".to_string()),
-            primary_data_share: Some(Percent::from(12.9)),
+            primary_data_share: Some(Percent::try_from(12.9).unwrap()),
             dqi: Some(DataQualityIndicators {
-                coverage_percent: Percent::from(78.0),
-                technological_d_q_r: 1.6.into(),
-                temporal_d_q_r: 2.6.into(),
-                geographical_d_q_r: 1.8.into(),
-                completeness_d_q_r: 1.7.into(),
-                reliability_d_q_r: 2.1.into()
+                coverage_percent: Percent::try_from(78.0).unwrap(),
+                technological_d_q_r: 1.6.try_into().unwrap(),
+                temporal_d_q_r: 2.6.try_into().unwrap(),
+                geographical_d_q_r: 1.8.try_into().unwrap(),
+                completeness_d_q_r: 1.7.try_into().unwrap(),
+                reliability_d_q_r: 2.1.try_into().unwrap()
             }),
             assurance: Some(Assurance::default()),
         },
@@ -84,7 +84,7 @@ lazy_static!(
 
     static ref EXAMPLE_2: ProductFootprint<ILeapType> = ProductFootprint {
         id: PfId(uuid!("61ff98c0-9e13-47d9-bb13-0b5381468165")),
-        spec_version: SpecVersionString::from("2.0.0".to_string()),
+        spec_version: SpecVersionString("2.0.0".to_string()),
         preceding_pf_ids: None,
         version: VersionInteger(1),
         created: Utc.with_ymd_and_hms(2022, 2, 22, 10, 47, 32).unwrap(),
@@ -93,35 +93,35 @@ lazy_static!(
         status_comment: None,
         validity_period_start: Some(Utc.with_ymd_and_hms(2022, 2, 22, 10, 47, 32).unwrap()),
         validity_period_end: Some(Utc.with_ymd_and_hms(2024, 12, 31, 00, 00, 00).unwrap()),
-        company_name: String::from("My Corp").into(),
-        company_ids: CompanyIdSet(vec![Urn::from("urn:uuid:51131FB5-42A2-4267-A402-0ECFEFAD1619".to_string()), Urn::from("urn:epc:id:sgln:4063973.00000.8".to_string())]),
+        company_name: String::from("My Corp").try_into().unwrap(),
+        company_ids: CompanyIdSet(vec![Urn("urn:uuid:51131FB5-42A2-4267-A402-0ECFEFAD1619".to_string()), Urn("urn:epc:id:sgln:4063973.00000.8".to_string())]),
         product_description: "12L Bottle of bio-Ethanol 98%, corn feedstock (including 100% fossil plastic packaging)".to_string(),
-        product_ids: ProductIdSet(vec![Urn::from("urn:gtin:4712345060507".to_string())]),
-        product_category_cpc: String::from("3342").into(),
-        product_name_company: String::from("Green Ethanol").into(),
+        product_ids: ProductIdSet(vec![Urn("urn:gtin:4712345060507".to_string())]),
+        product_category_cpc: String::from("3342").try_into().unwrap(),
+        product_name_company: String::from("Green Ethanol").try_into().unwrap(),
         comment: "".into(),
         pcf: CarbonFootprint {
             declared_unit: DeclaredUnit::Liter,
-            unitary_product_amount: dec!(12).into(),
-            p_cf_excluding_biogenic: dec!(1.75).into(),
+            unitary_product_amount: dec!(12).try_into().unwrap(),
+            p_cf_excluding_biogenic: dec!(1.75).try_into().unwrap(),
             p_cf_including_biogenic: Some(dec!(1.97).into()),
-            fossil_ghg_emissions: dec!(1.5).into(),
-            fossil_carbon_content: dec!(0).into(),
-            biogenic_carbon_content: dec!(0.41).into(),
-            d_luc_ghg_emissions: Some(dec!(0.8).into()),
-            land_management_ghg_emissions: Some(dec!(0.6).into()),
-            other_biogenic_ghg_emissions: Some(dec!(0.4).into()),
-            i_luc_ghg_emissions: Some(dec!(0).into()),
-            biogenic_carbon_withdrawal: Some(dec!(-1.5).into()),
-            aircraft_ghg_emissions: Some(dec!(0.2).into()),
+            fossil_ghg_emissions: dec!(1.5).try_into().unwrap(),
+            fossil_carbon_content: dec!(0).try_into().unwrap(),
+            biogenic_carbon_content: dec!(0.41).try_into().unwrap(),
+            d_luc_ghg_emissions: Some(dec!(0.8).try_into().unwrap()),
+            land_management_ghg_emissions: Some(dec!(0.6).try_into().unwrap()),
+            other_biogenic_ghg_emissions: Some(dec!(0.4).try_into().unwrap()),
+            i_luc_ghg_emissions: Some(dec!(0).try_into().unwrap()),
+            biogenic_carbon_withdrawal: Some(dec!(-1.5).try_into().unwrap()),
+            aircraft_ghg_emissions: Some(dec!(0.2).try_into().unwrap()),
             characterization_factors: CharacterizationFactors::Ar6,
-            ipcc_characterization_factors_sources: IpccCharacterizationFactorsSources::from(vec![String::from("AR6").into()]),
+            ipcc_characterization_factors_sources: vec![CharacterizationFactors::Ar6.into()].try_into().unwrap(),
             cross_sectoral_standards_used: CrossSectoralStandardSet(vec![DeprecatedCrossSectoralStandard::Ghgp, DeprecatedCrossSectoralStandard::ISO14067]),
             product_or_sector_specific_rules: Some(ProductOrSectorSpecificRuleSet(vec![
                 ProductOrSectorSpecificRule {
                     operator: ProductOrSectorSpecificRuleOperator::Other,
-                    rule_names: vec![String::from("The Product Carbon Footprint Guideline for the Chemical Industry, v.2.0").into()].into(),
-                    other_operator_name: Some(String::from("Tfs").into())
+                    rule_names: vec![String::from("The Product Carbon Footprint Guideline for the Chemical Industry, v.2.0").try_into().unwrap()].try_into().unwrap(),
+                    other_operator_name: Some(String::from("Tfs").try_into().unwrap())
                 }])),
             biogenic_accounting_methodology: Some(BiogenicAccountingMethodology::Ghgp),
             boundary_processes_description: String::from("1) Material acquisition and preprocessing, including growth of corn 2) Production: fuel consumption, electricity consumption, water consumption, process-generated direct emissions 3) Distribution and storage: transportation of the finished product from manufacturing site to storage site"),
@@ -129,23 +129,23 @@ lazy_static!(
             reference_period_end: Utc.with_ymd_and_hms(2022, 1, 1, 00, 00, 00).unwrap(),
             geographic_scope: Some(GeographicScope::Country(ISO3166CC(String::from("DE")))),
             secondary_emission_factor_sources: Some(EmissionFactorDSSet(vec![EmissionFactorDS {
-                name: String::from("Ecoinvent").into(),
-                version: String::from("3.1").into(),
+                name: String::from("Ecoinvent").try_into().unwrap(),
+                version: String::from("3.1").try_into().unwrap(),
             }])),
             exempted_emissions_percent: ExemptedEmissionsPercent(0.8),
             exempted_emissions_description: "Using the most conservative emission factor from a region with higher energy intensive grid for input A resulted in a contribution of 0.8% for this input. This is less than 1% and therefore considered under the cut off rule".to_string(),
             packaging_emissions_included: true,
-            packaging_ghg_emissions: Some(dec!(0.12).into()),
+            packaging_ghg_emissions: Some(dec!(0.12).try_into().unwrap()),
             allocation_rules_description: Some("Using mass allocation following the product specific rule as per PACT Framework decision-making tree".to_string()),
             uncertainty_assessment_description: None,
-            primary_data_share: Some(Percent::from(16.8)),
+            primary_data_share: Some(Percent::try_from(16.8).unwrap()),
             dqi: Some(DataQualityIndicators {
-                coverage_percent: Percent::from(87.0),
-                technological_d_q_r: 2.3.into(),
-                temporal_d_q_r: 1.4.into(),
-                geographical_d_q_r: 2.5.into(),
-                completeness_d_q_r: 1.1.into(),
-                reliability_d_q_r: 1.6.into()
+                coverage_percent: Percent::try_from(87.0).unwrap(),
+                technological_d_q_r: 2.3.try_into().unwrap(),
+                temporal_d_q_r: 1.4.try_into().unwrap(),
+                geographical_d_q_r: 2.5.try_into().unwrap(),
+                completeness_d_q_r: 1.1.try_into().unwrap(),
+                reliability_d_q_r: 1.6.try_into().unwrap()
             }),
             assurance: Some(Assurance::default()),
         },
@@ -155,7 +155,7 @@ lazy_static!(
     // a footprint deprecated by EXAMPLE_4
     static ref EXAMPLE_3: ProductFootprint<ILeapType> = ProductFootprint {
         id: PfId(uuid!("fb77319f-2338-4338-868a-98b2206340ad")),
-        spec_version: SpecVersionString::from("2.0.0".to_string()),
+        spec_version: SpecVersionString("2.0.0".to_string()),
         preceding_pf_ids: None,
         version: VersionInteger(2),
         created: Utc.with_ymd_and_hms(2022, 3, 15, 11, 47, 32).unwrap(),
@@ -164,34 +164,34 @@ lazy_static!(
         status_comment: Some("Replaced by a new version".to_string()),
         validity_period_start: Some(Utc.with_ymd_and_hms(2022, 3, 15, 11, 47, 32).unwrap()),
         validity_period_end: Some(Utc.with_ymd_and_hms(2023, 6, 27, 12, 12, 3).unwrap()),
-        company_name: String::from("My Corp").into(),
-        company_ids: CompanyIdSet(vec![Urn::from("urn:uuid:25639HN5-58Q6-1238-S596-9STHZHZJ5623".to_string()), Urn::from("urn:epc:id:sgln:6957976.00000.1".to_string())]),
+        company_name: String::from("My Corp").try_into().unwrap(),
+        company_ids: CompanyIdSet(vec![Urn("urn:uuid:25639HN5-58Q6-1238-S596-9STHZHZJ5623".to_string()), Urn("urn:epc:id:sgln:6957976.00000.1".to_string())]),
         product_description: "Cardboard box 50x40x40 cm".to_string(),
-        product_ids: ProductIdSet(vec![Urn::from("urn:gtin:5268596541023".to_string())]),
-        product_category_cpc: String::from("4365").into(),
-        product_name_company: String::from("Cardboard504040").into(),
+        product_ids: ProductIdSet(vec![Urn("urn:gtin:5268596541023".to_string())]),
+        product_category_cpc: String::from("4365").try_into().unwrap(),
+        product_name_company: String::from("Cardboard504040").try_into().unwrap(),
         comment: "".into(),
         pcf: CarbonFootprint {
             declared_unit: DeclaredUnit::Kilogram,
-            unitary_product_amount: dec!(0.8).into(),
-            p_cf_excluding_biogenic: dec!(0.28).into(),
+            unitary_product_amount: dec!(0.8).try_into().unwrap(),
+            p_cf_excluding_biogenic: dec!(0.28).try_into().unwrap(),
             p_cf_including_biogenic: Some(dec!(-0.28).into()),
-            fossil_ghg_emissions: dec!(0.19).into(),
-            fossil_carbon_content: dec!(0.08).into(),
-            biogenic_carbon_content: dec!(0.44).into(),
-            d_luc_ghg_emissions: Some(dec!(0.42).into()),
-            land_management_ghg_emissions: Some(dec!(0.34).into()),
-            other_biogenic_ghg_emissions: Some(dec!(0.2).into()),
-            i_luc_ghg_emissions: Some(dec!(0.03).into()),
-            biogenic_carbon_withdrawal: Some(dec!(-1.6).into()),
-            aircraft_ghg_emissions: Some(dec!(0.08).into()),
+            fossil_ghg_emissions: dec!(0.19).try_into().unwrap(),
+            fossil_carbon_content: dec!(0.08).try_into().unwrap(),
+            biogenic_carbon_content: dec!(0.44).try_into().unwrap(),
+            d_luc_ghg_emissions: Some(dec!(0.42).try_into().unwrap()),
+            land_management_ghg_emissions: Some(dec!(0.34).try_into().unwrap()),
+            other_biogenic_ghg_emissions: Some(dec!(0.2).try_into().unwrap()),
+            i_luc_ghg_emissions: Some(dec!(0.03).try_into().unwrap()),
+            biogenic_carbon_withdrawal: Some(dec!(-1.6).try_into().unwrap()),
+            aircraft_ghg_emissions: Some(dec!(0.08).try_into().unwrap()),
             characterization_factors: CharacterizationFactors::Ar5,
-            ipcc_characterization_factors_sources: IpccCharacterizationFactorsSources::from(vec![String::from("AR5").into(), String::from("AR6").into()]),
+            ipcc_characterization_factors_sources: vec![CharacterizationFactors::Ar5.into(), CharacterizationFactors::Ar6.into()].try_into().unwrap(),
             cross_sectoral_standards_used: CrossSectoralStandardSet(vec![DeprecatedCrossSectoralStandard::Ghgp]),
             product_or_sector_specific_rules: Some(ProductOrSectorSpecificRuleSet(vec![
                 ProductOrSectorSpecificRule {
                     operator: ProductOrSectorSpecificRuleOperator::EPDInternational,
-                    rule_names: vec![String::from("PCR cardboard").into()].into(),
+                    rule_names: vec![String::from("PCR cardboard").try_into().unwrap()].try_into().unwrap(),
                     other_operator_name: None
                 }
             ])),
@@ -201,8 +201,8 @@ lazy_static!(
             reference_period_end: Utc.with_ymd_and_hms(2022, 1, 1, 00, 00, 00).unwrap(),
             geographic_scope: Some(GeographicScope::Country(ISO3166CC("FR".to_string()))),
             secondary_emission_factor_sources: Some(EmissionFactorDSSet(vec![EmissionFactorDS {
-                name: String::from("Gabi").into(),
-                version: String::from("2022").into(),
+                name: String::from("Gabi").try_into().unwrap(),
+                version: String::from("2022").try_into().unwrap(),
             }])),
             exempted_emissions_percent: ExemptedEmissionsPercent(0.0),
             exempted_emissions_description: "".to_string(),
@@ -210,14 +210,14 @@ lazy_static!(
             packaging_ghg_emissions: None,
             allocation_rules_description: Some("No allocation used, process subdivision was possible".to_string()),
             uncertainty_assessment_description: None,
-            primary_data_share: Some(Percent::from(12.6)),
+            primary_data_share: Some(Percent::try_from(12.6).unwrap()),
             dqi: Some(DataQualityIndicators {
-                coverage_percent: Percent::from(83.0),
-                technological_d_q_r: 1.8.into(),
-                temporal_d_q_r: 1.2.into(),
-                geographical_d_q_r: 1.9.into(),
-                completeness_d_q_r: 1.7.into(),
-                reliability_d_q_r: 1.4.into()
+                coverage_percent: Percent::try_from(83.0).unwrap(),
+                technological_d_q_r: 1.8.try_into().unwrap(),
+                temporal_d_q_r: 1.2.try_into().unwrap(),
+                geographical_d_q_r: 1.9.try_into().unwrap(),
+                completeness_d_q_r: 1.7.try_into().unwrap(),
+                reliability_d_q_r: 1.4.try_into().unwrap()
             }),
             assurance: Some(Assurance {
                 assurance: true,
@@ -236,7 +236,7 @@ lazy_static!(
     // this is the PCF superseeding EXAMPLE_3
     static ref EXAMPLE_4: ProductFootprint<ILeapType> = ProductFootprint {
         id: PfId(uuid!("f369091a-aa5d-4248-9bd5-2812329e1ef1")),
-        spec_version: SpecVersionString::from("2.0.0".to_string()),
+        spec_version: SpecVersionString("2.0.0".to_string()),
         preceding_pf_ids: Some(NonEmptyPfIdVec(vec![PfId(uuid!("fb77319f-2338-4338-868a-98b2206340ad"))])),
         version: VersionInteger(1),
         created: Utc.with_ymd_and_hms(2023, 6, 27, 12, 12, 3).unwrap(),
@@ -245,34 +245,34 @@ lazy_static!(
         status_comment: None,
         validity_period_start: Some(Utc.with_ymd_and_hms(2023, 2, 1, 00, 00, 00).unwrap()),
         validity_period_end: Some(Utc.with_ymd_and_hms(2025, 8, 31, 00, 00, 00).unwrap()),
-        company_name: String::from("My Corp").into(),
-        company_ids: CompanyIdSet(vec![Urn::from("urn:uuid:25639HN5-58Q6-1238-S596-9STHZHZJ5623".to_string()), Urn::from("urn:epc:id:sgln:6957976.00000.1".to_string())]),
+        company_name: String::from("My Corp").try_into().unwrap(),
+        company_ids: CompanyIdSet(vec![Urn("urn:uuid:25639HN5-58Q6-1238-S596-9STHZHZJ5623".to_string()), Urn("urn:epc:id:sgln:6957976.00000.1".to_string())]),
         product_description: "Cardboard box 50x40x40 cm".to_string(),
-        product_ids: ProductIdSet(vec![Urn::from("urn:gtin:5268596541023".to_string())]),
-        product_category_cpc: String::from("4365").into(),
-        product_name_company: String::from("Cardboard504040").into(),
+        product_ids: ProductIdSet(vec![Urn("urn:gtin:5268596541023".to_string())]),
+        product_category_cpc: String::from("4365").try_into().unwrap(),
+        product_name_company: String::from("Cardboard504040").try_into().unwrap(),
         comment: "".into(),
         pcf: CarbonFootprint {
             declared_unit: DeclaredUnit::Kilogram,
-            unitary_product_amount: dec!(0.8).into(),
-            p_cf_excluding_biogenic: dec!(0.32).into(),
+            unitary_product_amount: dec!(0.8).try_into().unwrap(),
+            p_cf_excluding_biogenic: dec!(0.32).try_into().unwrap(),
             p_cf_including_biogenic: Some(dec!(-0.28).into()),
-            fossil_ghg_emissions: dec!(0.23).into(),
-            fossil_carbon_content: dec!(0.08).into(),
-            biogenic_carbon_content: dec!(0.44).into(),
-            d_luc_ghg_emissions: Some(dec!(0.42).into()),
-            land_management_ghg_emissions: Some(dec!(0.34).into()),
-            other_biogenic_ghg_emissions: Some(dec!(0.2).into()),
-            i_luc_ghg_emissions: Some(dec!(0.03).into()),
-            biogenic_carbon_withdrawal: Some(dec!(-1.6).into()),
-            aircraft_ghg_emissions: Some(dec!(0.08).into()),
+            fossil_ghg_emissions: dec!(0.23).try_into().unwrap(),
+            fossil_carbon_content: dec!(0.08).try_into().unwrap(),
+            biogenic_carbon_content: dec!(0.44).try_into().unwrap(),
+            d_luc_ghg_emissions: Some(dec!(0.42).try_into().unwrap()),
+            land_management_ghg_emissions: Some(dec!(0.34).try_into().unwrap()),
+            other_biogenic_ghg_emissions: Some(dec!(0.2).try_into().unwrap()),
+            i_luc_ghg_emissions: Some(dec!(0.03).try_into().unwrap()),
+            biogenic_carbon_withdrawal: Some(dec!(-1.6).try_into().unwrap()),
+            aircraft_ghg_emissions: Some(dec!(0.08).try_into().unwrap()),
             characterization_factors: CharacterizationFactors::Ar5,
-            ipcc_characterization_factors_sources: IpccCharacterizationFactorsSources::from(vec![String::from("AR5").into(), String::from("AR6").into()]),
+            ipcc_characterization_factors_sources: vec![CharacterizationFactors::Ar5.into(), CharacterizationFactors::Ar6.into()].try_into().unwrap(),
             cross_sectoral_standards_used: CrossSectoralStandardSet(vec![DeprecatedCrossSectoralStandard::Ghgp]),
             product_or_sector_specific_rules: Some(ProductOrSectorSpecificRuleSet(vec![
                 ProductOrSectorSpecificRule {
                     operator: ProductOrSectorSpecificRuleOperator::EPDInternational,
-                    rule_names: vec![String::from("PCR cardboard").into()].into(),
+                    rule_names: vec![String::from("PCR cardboard").try_into().unwrap()].try_into().unwrap(),
                     other_operator_name: None
                 }
             ])),
@@ -280,10 +280,10 @@ lazy_static!(
             boundary_processes_description: String::from("1) Material acquisition and preprocessing, including growth of trees 2) Production: fuel consumption, electricity consumption, water consumption, process-generated direct emissions 3) Distribution and storage: transportation of the finished product from manufacturing site to storage site"),
             reference_period_start: Utc.with_ymd_and_hms(2021, 1, 1, 00, 00, 00).unwrap(),
             reference_period_end: Utc.with_ymd_and_hms(2022, 1, 1, 00, 00, 00).unwrap(),
-            geographic_scope: Some(GeographicScope::Subdivision(String::from("FR-89").into())),
+            geographic_scope: Some(GeographicScope::Subdivision(String::from("FR-89").try_into().unwrap())),
             secondary_emission_factor_sources: Some(EmissionFactorDSSet(vec![EmissionFactorDS {
-                name: String::from("Gabi").into(),
-                version: String::from("2022").into(),
+                name: String::from("Gabi").try_into().unwrap(),
+                version: String::from("2022").try_into().unwrap(),
             }])),
             exempted_emissions_percent: ExemptedEmissionsPercent(0.0),
             exempted_emissions_description: "".to_string(),
@@ -291,14 +291,14 @@ lazy_static!(
             packaging_ghg_emissions: None,
             allocation_rules_description: Some("No allocation used, process subdivision was possible".to_string()),
             uncertainty_assessment_description: None,
-            primary_data_share: Some(Percent::from(12.6)),
+            primary_data_share: Some(Percent::try_from(12.6).unwrap()),
             dqi: Some(DataQualityIndicators {
-                coverage_percent: Percent::from(83.0),
-                technological_d_q_r: 1.8.into(),
-                temporal_d_q_r: 1.2.into(),
-                geographical_d_q_r: 1.9.into(),
-                completeness_d_q_r: 1.7.into(),
-                reliability_d_q_r: 1.4.into()
+                coverage_percent: Percent::try_from(83.0).unwrap(),
+                technological_d_q_r: 1.8.try_into().unwrap(),
+                temporal_d_q_r: 1.2.try_into().unwrap(),
+                geographical_d_q_r: 1.9.try_into().unwrap(),
+                completeness_d_q_r: 1.7.try_into().unwrap(),
+                reliability_d_q_r: 1.4.try_into().unwrap()
             }),
             assurance: Some(Assurance {
                 assurance: true,
@@ -317,7 +317,7 @@ lazy_static!(
     // a ShipmentFooprint PCF (iLEAP Extension)
     static ref SHIPMENT_SIMPLE_EXAMPLE: ProductFootprint<ILeapType> = ProductFootprint {
         id: PfId(uuid!("d9be4477-e351-45b3-acd9-e1da05e6f633")),
-        spec_version: SpecVersionString::from("2.0.0".to_string()),
+        spec_version: SpecVersionString("2.0.0".to_string()),
         preceding_pf_ids: None,
         version: VersionInteger(0),
         created: Utc.with_ymd_and_hms(2022, 5, 22, 21, 47, 32).unwrap(),
@@ -326,21 +326,21 @@ lazy_static!(
         status_comment: None,
         validity_period_start: None,
         validity_period_end: None,
-        company_name: String::from("Super Duper Transport Co.").into(),
-        company_ids: CompanyIdSet(vec![Urn::from("urn:epc:id:sgln:4063973.00000.8".to_string())]),
+        company_name: String::from("Super Duper Transport Co.").try_into().unwrap(),
+        company_ids: CompanyIdSet(vec![Urn("urn:epc:id:sgln:4063973.00000.8".to_string())]),
         product_description: String::from("Logistics emissions related to shipment with ID shipment-1"),
-        product_ids: ProductIdSet(vec![Urn::from("urn:pathfinder:product:customcode:vendor-assigned:shipment:shipment-simple-1".to_string())]),
-        product_category_cpc: String::from("83117").into(),
-        product_name_company: String::from("Shipment with ID shipment-simple-1").into(),
+        product_ids: ProductIdSet(vec![Urn("urn:pathfinder:product:customcode:vendor-assigned:shipment:shipment-simple-1".to_string())]),
+        product_category_cpc: String::from("83117").try_into().unwrap(),
+        product_name_company: String::from("Shipment with ID shipment-simple-1").try_into().unwrap(),
         comment: "".to_string(),
         pcf: CarbonFootprint {
             declared_unit: DeclaredUnit::TonKilometer,
-            unitary_product_amount: dec!(16920).into(),
-            p_cf_excluding_biogenic: dec!(1962.72).into(),
+            unitary_product_amount: dec!(16920).try_into().unwrap(),
+            p_cf_excluding_biogenic: dec!(1962.72).try_into().unwrap(),
             p_cf_including_biogenic: None,
-            fossil_ghg_emissions: dec!(1962.72).into(),
-            fossil_carbon_content: dec!(1962.72).into(),
-            biogenic_carbon_content: dec!(0).into(),
+            fossil_ghg_emissions: dec!(1962.72).try_into().unwrap(),
+            fossil_carbon_content: dec!(1962.72).try_into().unwrap(),
+            biogenic_carbon_content: dec!(0).try_into().unwrap(),
             d_luc_ghg_emissions: None,
             land_management_ghg_emissions: None,
             other_biogenic_ghg_emissions: None,
@@ -348,7 +348,7 @@ lazy_static!(
             biogenic_carbon_withdrawal: None,
             aircraft_ghg_emissions: None,
             characterization_factors: CharacterizationFactors::Ar6,
-            ipcc_characterization_factors_sources: IpccCharacterizationFactorsSources::from(vec![String::from("AR6").into()]),
+            ipcc_characterization_factors_sources: vec![CharacterizationFactors::Ar6.into()].try_into().unwrap(),
             cross_sectoral_standards_used: CrossSectoralStandardSet(vec![DeprecatedCrossSectoralStandard::Ghgp]),
             product_or_sector_specific_rules: Some(ProductOrSectorSpecificRuleSet(vec![])),
             biogenic_accounting_methodology: None,
@@ -357,8 +357,8 @@ lazy_static!(
             reference_period_end: Utc.with_ymd_and_hms(2022, 1, 1, 0, 0, 0).unwrap(),
             geographic_scope: None,
             secondary_emission_factor_sources: Some(EmissionFactorDSSet(vec![EmissionFactorDS {
-                name: String::from("Ecoinvent").into(),
-                version: String::from("3.9.1").into(),
+                name: String::from("Ecoinvent").try_into().unwrap(),
+                version: String::from("3.9.1").try_into().unwrap(),
             }])),
             exempted_emissions_percent: ExemptedEmissionsPercent(0.0),
             exempted_emissions_description: "".to_string(),
@@ -366,13 +366,13 @@ lazy_static!(
             packaging_ghg_emissions: None,
             allocation_rules_description: None,
             uncertainty_assessment_description: None,
-            primary_data_share: Some(Percent::from(56.12)),
+            primary_data_share: Some(Percent::try_from(56.12).unwrap()),
             dqi: None,
             assurance: None
         },
         extensions: Some(vec![
             DataModelExtension {
-                spec_version: SpecVersionString::from("2.0.0".to_string()),
+                spec_version: SpecVersionString("2.0.0".to_string()),
                 data_schema: "https://api.ileap.sine.dev/shipment-footprint.json".to_string(),
                 documentation: Some("https://sine-fdn.github.io/ileap-extension/".to_string()),
                 data: ILeapType::ShipmentFootprint(shipment_footprint(
@@ -402,7 +402,7 @@ lazy_static!(
 
     static ref SHIPMENT_MULTIMODAL_EXAMPLE: ProductFootprint<ILeapType> = ProductFootprint {
         id: PfId(uuid!("380baaac-6f47-471c-83d5-a9db87b8bede")),
-        spec_version: SpecVersionString::from("2.0.0".to_string()),
+        spec_version: SpecVersionString("2.0.0".to_string()),
         preceding_pf_ids: None,
         version: VersionInteger(0),
         created: Utc.with_ymd_and_hms(2022, 5, 22, 21, 47, 32).unwrap(),
@@ -411,21 +411,21 @@ lazy_static!(
         status_comment: None,
         validity_period_start: None,
         validity_period_end: None,
-        company_name: String::from("Super Duper Transport Co.").into(),
-        company_ids: CompanyIdSet(vec![Urn::from("urn:epc:id:sgln:4063973.00000.8".to_string())]),
+        company_name: String::from("Super Duper Transport Co.").try_into().unwrap(),
+        company_ids: CompanyIdSet(vec![Urn("urn:epc:id:sgln:4063973.00000.8".to_string())]),
         product_description: String::from("Logistics emissions related to shipment with ID shipment-multi-modal-1"),
-        product_ids: ProductIdSet(vec![Urn::from("urn:pathfinder:product:customcode:vendor-assigned:shipment:shipment-multi-modal-1".to_string())]),
-        product_category_cpc: String::from("83117").into(),
-        product_name_company: String::from("Shipment with ID shipment-multi-modal-1").into(),
+        product_ids: ProductIdSet(vec![Urn("urn:pathfinder:product:customcode:vendor-assigned:shipment:shipment-multi-modal-1".to_string())]),
+        product_category_cpc: String::from("83117").try_into().unwrap(),
+        product_name_company: String::from("Shipment with ID shipment-multi-modal-1").try_into().unwrap(),
         comment: "".to_string(),
         pcf: CarbonFootprint {
             declared_unit: DeclaredUnit::TonKilometer,
-            unitary_product_amount: dec!(33840).into(),
-            p_cf_excluding_biogenic: dec!(3131.06).into(),
+            unitary_product_amount: dec!(33840).try_into().unwrap(),
+            p_cf_excluding_biogenic: dec!(3131.06).try_into().unwrap(),
             p_cf_including_biogenic: None,
-            fossil_ghg_emissions: dec!(3131.06).into(),
-            fossil_carbon_content: dec!(3131.06).into(),
-            biogenic_carbon_content: dec!(0).into(),
+            fossil_ghg_emissions: dec!(3131.06).try_into().unwrap(),
+            fossil_carbon_content: dec!(3131.06).try_into().unwrap(),
+            biogenic_carbon_content: dec!(0).try_into().unwrap(),
             d_luc_ghg_emissions: None,
             land_management_ghg_emissions: None,
             other_biogenic_ghg_emissions: None,
@@ -433,7 +433,7 @@ lazy_static!(
             biogenic_carbon_withdrawal: None,
             aircraft_ghg_emissions: None,
             characterization_factors: CharacterizationFactors::Ar6,
-            ipcc_characterization_factors_sources: IpccCharacterizationFactorsSources::from(vec![String::from("AR6").into()]),
+            ipcc_characterization_factors_sources: vec![CharacterizationFactors::Ar6.into()].try_into().unwrap(),
             cross_sectoral_standards_used: CrossSectoralStandardSet(vec![DeprecatedCrossSectoralStandard::Ghgp]),
             product_or_sector_specific_rules: Some(ProductOrSectorSpecificRuleSet(vec![])),
             biogenic_accounting_methodology: None,
@@ -442,8 +442,8 @@ lazy_static!(
             reference_period_end: Utc.with_ymd_and_hms(2022, 1, 1, 0, 0, 0).unwrap(),
             geographic_scope: None,
             secondary_emission_factor_sources: Some(EmissionFactorDSSet(vec![EmissionFactorDS {
-                name: String::from("Ecoinvent").into(),
-                version: String::from("3.9.1").into(),
+                name: String::from("Ecoinvent").try_into().unwrap(),
+                version: String::from("3.9.1").try_into().unwrap(),
             }])),
             exempted_emissions_percent: ExemptedEmissionsPercent(0.0),
             exempted_emissions_description: "".to_string(),
@@ -451,13 +451,13 @@ lazy_static!(
             packaging_ghg_emissions: None,
             allocation_rules_description: None,
             uncertainty_assessment_description: None,
-            primary_data_share: Some(Percent::from(56.12)),
+            primary_data_share: Some(Percent::try_from(56.12).unwrap()),
             dqi: None,
             assurance: None
         },
         extensions: Some(vec![
             DataModelExtension {
-                spec_version: SpecVersionString::from("2.0.0".to_string()),
+                spec_version: SpecVersionString("2.0.0".to_string()),
                 data_schema: "https://api.ileap.sine.dev/shipment-footprint.json".to_string(),
                 documentation: Some("https://sine-fdn.github.io/ileap-extension/".to_string()),
                 data: ILeapType::ShipmentFootprint(shipment_footprint(
@@ -513,7 +513,7 @@ lazy_static!(
 
     static ref SHIPMENT_PRE_AND_POST_LEGS_EXAMPLE: ProductFootprint<ILeapType> = ProductFootprint {
         id: PfId(uuid!("4861c2a5-68a5-4c40-bcc0-7e234ad24184")),
-        spec_version: SpecVersionString::from("2.0.0".to_string()),
+        spec_version: SpecVersionString("2.0.0".to_string()),
         preceding_pf_ids: None,
         version: VersionInteger(0),
         created: Utc.with_ymd_and_hms(2022, 5, 22, 21, 47, 32).unwrap(),
@@ -522,21 +522,21 @@ lazy_static!(
         status_comment: None,
         validity_period_start: None,
         validity_period_end: None,
-        company_name: String::from("Super Duper Transport Co.").into(),
-        company_ids: CompanyIdSet(vec![Urn::from("urn:epc:id:sgln:4063973.00000.8".to_string())]),
+        company_name: String::from("Super Duper Transport Co.").try_into().unwrap(),
+        company_ids: CompanyIdSet(vec![Urn("urn:epc:id:sgln:4063973.00000.8".to_string())]),
         product_description: String::from("Logistics emissions related to shipment with ID pre-and-post-legs-1"),
-        product_ids: ProductIdSet(vec![Urn::from("urn:pathfinder:product:customcode:vendor-assigned:shipment:pre-and-post-legs-1".to_string())]),
-        product_category_cpc: String::from("83117").into(),
-        product_name_company: String::from("Shipment with ID pre-and-post-legs-1").into(),
+        product_ids: ProductIdSet(vec![Urn("urn:pathfinder:product:customcode:vendor-assigned:shipment:pre-and-post-legs-1".to_string())]),
+        product_category_cpc: String::from("83117").try_into().unwrap(),
+        product_name_company: String::from("Shipment with ID pre-and-post-legs-1").try_into().unwrap(),
         comment: "".to_string(),
         pcf: CarbonFootprint {
             declared_unit: DeclaredUnit::TonKilometer,
-            unitary_product_amount: dec!(320).into(),
-            p_cf_excluding_biogenic: dec!(385.76).into(),
+            unitary_product_amount: dec!(320).try_into().unwrap(),
+            p_cf_excluding_biogenic: dec!(385.76).try_into().unwrap(),
             p_cf_including_biogenic: None,
-            fossil_ghg_emissions: dec!(385.76).into(),
-            fossil_carbon_content: dec!(385.76).into(),
-            biogenic_carbon_content: dec!(0).into(),
+            fossil_ghg_emissions: dec!(385.76).try_into().unwrap(),
+            fossil_carbon_content: dec!(385.76).try_into().unwrap(),
+            biogenic_carbon_content: dec!(0).try_into().unwrap(),
             d_luc_ghg_emissions: None,
             land_management_ghg_emissions: None,
             other_biogenic_ghg_emissions: None,
@@ -544,7 +544,7 @@ lazy_static!(
             biogenic_carbon_withdrawal: None,
             aircraft_ghg_emissions: None,
             characterization_factors: CharacterizationFactors::Ar6,
-            ipcc_characterization_factors_sources: IpccCharacterizationFactorsSources::from(vec![String::from("AR6").into()]),
+            ipcc_characterization_factors_sources: vec![CharacterizationFactors::Ar6.into()].try_into().unwrap(),
             cross_sectoral_standards_used: CrossSectoralStandardSet(vec![DeprecatedCrossSectoralStandard::Ghgp]),
             product_or_sector_specific_rules: Some(ProductOrSectorSpecificRuleSet(vec![])),
             biogenic_accounting_methodology: None,
@@ -553,8 +553,8 @@ lazy_static!(
             reference_period_end: Utc.with_ymd_and_hms(2022, 1, 1, 0, 0, 0).unwrap(),
             geographic_scope: None,
             secondary_emission_factor_sources: Some(EmissionFactorDSSet(vec![EmissionFactorDS {
-                name: String::from("Ecoinvent").into(),
-                version: String::from("3.9.1").into(),
+                name: String::from("Ecoinvent").try_into().unwrap(),
+                version: String::from("3.9.1").try_into().unwrap(),
             }])),
             exempted_emissions_percent: ExemptedEmissionsPercent(0.0),
             exempted_emissions_description: "".to_string(),
@@ -562,13 +562,13 @@ lazy_static!(
             packaging_ghg_emissions: None,
             allocation_rules_description: None,
             uncertainty_assessment_description: None,
-            primary_data_share: Some(Percent::from(0.7)),
+            primary_data_share: Some(Percent::try_from(0.7).unwrap()),
             dqi: None,
             assurance: None
         },
         extensions: Some(vec![
             DataModelExtension {
-                spec_version: SpecVersionString::from("2.0.0".to_string()),
+                spec_version: SpecVersionString("2.0.0".to_string()),
                 data_schema: "https://api.ileap.sine.dev/shipment-footprint.json".to_string(),
                 documentation: Some("https://sine-fdn.github.io/ileap-extension/".to_string()),
                 data: ILeapType::ShipmentFootprint(shipment_footprint(
@@ -629,7 +629,7 @@ lazy_static!(
     // a TOC PCF (iLEAP Extension)
     static ref TOC_ROAD_EXAMPLE: ProductFootprint<ILeapType> = ProductFootprint {
         id: PfId(uuid!("f3c04ec8-b33a-43b1-9fa7-d6a448fd60af")),
-        spec_version: SpecVersionString::from("2.0.0".to_string()),
+        spec_version: SpecVersionString("2.0.0".to_string()),
         preceding_pf_ids: None,
         version: VersionInteger(0),
         created: Utc.with_ymd_and_hms(2022, 5, 22, 21, 47, 32).unwrap(),
@@ -638,21 +638,21 @@ lazy_static!(
         status_comment: None,
         validity_period_start: None,
         validity_period_end: None,
-        company_name: String::from("Super Duper Transport Co.").into(),
-        company_ids: CompanyIdSet(vec![Urn::from("urn:epc:id:sgln:4063973.00000.8".to_string())]),
+        company_name: String::from("Super Duper Transport Co.").try_into().unwrap(),
+        company_ids: CompanyIdSet(vec![Urn("urn:epc:id:sgln:4063973.00000.8".to_string())]),
         product_description: String::from("Logistics emissions related to TOC with ID toc-road-1"),
-        product_ids: ProductIdSet(vec![Urn::from("urn:pathfinder:product:customcode:vendor-assigned:toc:toc-road-1".to_string())]),
-        product_category_cpc: String::from("83117").into(),
-        product_name_company: String::from("TOC with ID toc-road-1").into(),
+        product_ids: ProductIdSet(vec![Urn("urn:pathfinder:product:customcode:vendor-assigned:toc:toc-road-1".to_string())]),
+        product_category_cpc: String::from("83117").try_into().unwrap(),
+        product_name_company: String::from("TOC with ID toc-road-1").try_into().unwrap(),
         comment: "".to_string(),
         pcf: CarbonFootprint {
             declared_unit: DeclaredUnit::TonKilometer,
-            unitary_product_amount: dec!(1).into(),
-            p_cf_excluding_biogenic: dec!(0.116).into(),
+            unitary_product_amount: dec!(1).try_into().unwrap(),
+            p_cf_excluding_biogenic: dec!(0.116).try_into().unwrap(),
             p_cf_including_biogenic: None,
-            fossil_ghg_emissions: dec!(0.116).into(),
-            fossil_carbon_content: dec!(0.116).into(),
-            biogenic_carbon_content: dec!(0).into(),
+            fossil_ghg_emissions: dec!(0.116).try_into().unwrap(),
+            fossil_carbon_content: dec!(0.116).try_into().unwrap(),
+            biogenic_carbon_content: dec!(0).try_into().unwrap(),
             d_luc_ghg_emissions: None,
             land_management_ghg_emissions: None,
             other_biogenic_ghg_emissions: None,
@@ -660,7 +660,7 @@ lazy_static!(
             biogenic_carbon_withdrawal: None,
             aircraft_ghg_emissions: None,
             characterization_factors: CharacterizationFactors::Ar6,
-            ipcc_characterization_factors_sources: IpccCharacterizationFactorsSources::from(vec![String::from("AR6").into()]),
+            ipcc_characterization_factors_sources: vec![CharacterizationFactors::Ar6.into()].try_into().unwrap(),
             cross_sectoral_standards_used: CrossSectoralStandardSet(vec![DeprecatedCrossSectoralStandard::Ghgp]),
             product_or_sector_specific_rules: Some(ProductOrSectorSpecificRuleSet(vec![])),
             biogenic_accounting_methodology: None,
@@ -669,8 +669,8 @@ lazy_static!(
             reference_period_end: Utc.with_ymd_and_hms(2022, 1, 1, 0, 0, 0).unwrap(),
             geographic_scope: None,
             secondary_emission_factor_sources: Some(EmissionFactorDSSet(vec![EmissionFactorDS {
-                name: String::from("Ecoinvent").into(),
-                version: String::from("3.9.1").into(),
+                name: String::from("Ecoinvent").try_into().unwrap(),
+                version: String::from("3.9.1").try_into().unwrap(),
             }])),
             exempted_emissions_percent: ExemptedEmissionsPercent(0.0),
             exempted_emissions_description: "".to_string(),
@@ -678,13 +678,13 @@ lazy_static!(
             packaging_ghg_emissions: None,
             allocation_rules_description: None,
             uncertainty_assessment_description: None,
-            primary_data_share: Some(Percent::from(56.12)),
+            primary_data_share: Some(Percent::try_from(56.12).unwrap()),
             dqi: None,
             assurance: None
         },
         extensions: Some(vec![
             DataModelExtension {
-                spec_version: SpecVersionString::from("2.0.0".to_string()),
+                spec_version: SpecVersionString("2.0.0".to_string()),
                 data_schema: "https://api.ileap.sine.dev/toc.json".to_string(),
                 documentation: Some("https://sine-fdn.github.io/ileap-extension/".to_string()),
                 data: ILeapType::Toc(toc(
@@ -720,7 +720,7 @@ lazy_static!(
 
     static ref TOC_ROAD_40T_EXAMPLE: ProductFootprint<ILeapType> = ProductFootprint {
         id: PfId(uuid!("f9526d98-de57-4d24-a131-95fcef75defb")),
-        spec_version: SpecVersionString::from("2.0.0".to_string()),
+        spec_version: SpecVersionString("2.0.0".to_string()),
         preceding_pf_ids: None,
         version: VersionInteger(0),
         created: Utc.with_ymd_and_hms(2022, 5, 22, 21, 47, 32).unwrap(),
@@ -729,21 +729,21 @@ lazy_static!(
         status_comment: None,
         validity_period_start: None,
         validity_period_end: None,
-        company_name: String::from("Super Duper Transport Co.").into(),
-        company_ids: CompanyIdSet(vec![Urn::from("urn:epc:id:sgln:4063973.00000.8".to_string())]),
+        company_name: String::from("Super Duper Transport Co.").try_into().unwrap(),
+        company_ids: CompanyIdSet(vec![Urn("urn:epc:id:sgln:4063973.00000.8".to_string())]),
         product_description: String::from("Logistics emissions related to TOC with ID truck-40t-euro5-de"),
-        product_ids: ProductIdSet(vec![Urn::from("urn:pathfinder:product:customcode:vendor-assigned:toc:truck-40t-euro5-de".to_string())]),
-        product_category_cpc: String::from("83117").into(),
-        product_name_company: String::from("TOC with ID truck-40t-euro5-de").into(),
+        product_ids: ProductIdSet(vec![Urn("urn:pathfinder:product:customcode:vendor-assigned:toc:truck-40t-euro5-de".to_string())]),
+        product_category_cpc: String::from("83117").try_into().unwrap(),
+        product_name_company: String::from("TOC with ID truck-40t-euro5-de").try_into().unwrap(),
         comment: "".to_string(),
         pcf: CarbonFootprint {
             declared_unit: DeclaredUnit::TonKilometer,
-            unitary_product_amount: dec!(1).into(),
-            p_cf_excluding_biogenic: dec!(0.116).into(),
+            unitary_product_amount: dec!(1).try_into().unwrap(),
+            p_cf_excluding_biogenic: dec!(0.116).try_into().unwrap(),
             p_cf_including_biogenic: None,
-            fossil_ghg_emissions: dec!(0.116).into(),
-            fossil_carbon_content: dec!(0.116).into(),
-            biogenic_carbon_content: dec!(0).into(),
+            fossil_ghg_emissions: dec!(0.116).try_into().unwrap(),
+            fossil_carbon_content: dec!(0.116).try_into().unwrap(),
+            biogenic_carbon_content: dec!(0).try_into().unwrap(),
             d_luc_ghg_emissions: None,
             land_management_ghg_emissions: None,
             other_biogenic_ghg_emissions: None,
@@ -751,7 +751,7 @@ lazy_static!(
             biogenic_carbon_withdrawal: None,
             aircraft_ghg_emissions: None,
             characterization_factors: CharacterizationFactors::Ar6,
-            ipcc_characterization_factors_sources: IpccCharacterizationFactorsSources::from(vec![String::from("AR6").into()]),
+            ipcc_characterization_factors_sources: vec![CharacterizationFactors::Ar6.into()].try_into().unwrap(),
             cross_sectoral_standards_used: CrossSectoralStandardSet(vec![DeprecatedCrossSectoralStandard::Ghgp]),
             product_or_sector_specific_rules: Some(ProductOrSectorSpecificRuleSet(vec![])),
             biogenic_accounting_methodology: None,
@@ -760,8 +760,8 @@ lazy_static!(
             reference_period_end: Utc.with_ymd_and_hms(2022, 1, 1, 0, 0, 0).unwrap(),
             geographic_scope: None,
             secondary_emission_factor_sources: Some(EmissionFactorDSSet(vec![EmissionFactorDS {
-                name: String::from("Ecoinvent").into(),
-                version: String::from("3.9.1").into(),
+                name: String::from("Ecoinvent").try_into().unwrap(),
+                version: String::from("3.9.1").try_into().unwrap(),
             }])),
             exempted_emissions_percent: ExemptedEmissionsPercent(0.0),
             exempted_emissions_description: "".to_string(),
@@ -769,13 +769,13 @@ lazy_static!(
             packaging_ghg_emissions: None,
             allocation_rules_description: None,
             uncertainty_assessment_description: None,
-            primary_data_share: Some(Percent::from(56.12)),
+            primary_data_share: Some(Percent::try_from(56.12).unwrap()),
             dqi: None,
             assurance: None
         },
         extensions: Some(vec![
             DataModelExtension {
-                spec_version: SpecVersionString::from("2.0.0".to_string()),
+                spec_version: SpecVersionString("2.0.0".to_string()),
                 data_schema: "https://api.ileap.sine.dev/toc.json".to_string(),
                 documentation: Some("https://sine-fdn.github.io/ileap-extension/".to_string()),
                 data: ILeapType::Toc(toc(
@@ -811,7 +811,7 @@ lazy_static!(
 
     static ref TOC_SMALL_TRUCK_EXAMPLE: ProductFootprint<ILeapType> = ProductFootprint {
         id: PfId(uuid!("eff7bded-948d-4ed6-adca-fc4a8f0602a5")),
-        spec_version: SpecVersionString::from("2.0.0".to_string()),
+        spec_version: SpecVersionString("2.0.0".to_string()),
         preceding_pf_ids: None,
         version: VersionInteger(0),
         created: Utc.with_ymd_and_hms(2022, 5, 22, 21, 47, 32).unwrap(),
@@ -820,21 +820,21 @@ lazy_static!(
         status_comment: None,
         validity_period_start: None,
         validity_period_end: None,
-        company_name: String::from("Super Duper Transport Co.").into(),
-        company_ids: CompanyIdSet(vec![Urn::from("urn:epc:id:sgln:4063973.00000.8".to_string())]),
+        company_name: String::from("Super Duper Transport Co.").try_into().unwrap(),
+        company_ids: CompanyIdSet(vec![Urn("urn:epc:id:sgln:4063973.00000.8".to_string())]),
         product_description: String::from("Logistics emissions related to TOC with ID toc-small-truck-1"),
-        product_ids: ProductIdSet(vec![Urn::from("urn:pathfinder:product:customcode:vendor-assigned:toc:toc-small-truck-1".to_string())]),
-        product_category_cpc: String::from("83117").into(),
-        product_name_company: String::from("TOC with ID toc-small-truck-1").into(),
+        product_ids: ProductIdSet(vec![Urn("urn:pathfinder:product:customcode:vendor-assigned:toc:toc-small-truck-1".to_string())]),
+        product_category_cpc: String::from("83117").try_into().unwrap(),
+        product_name_company: String::from("TOC with ID toc-small-truck-1").try_into().unwrap(),
         comment: "".to_string(),
         pcf: CarbonFootprint {
             declared_unit: DeclaredUnit::TonKilometer,
-            unitary_product_amount: dec!(1).into(),
-            p_cf_excluding_biogenic: dec!(0.793).into(),
+            unitary_product_amount: dec!(1).try_into().unwrap(),
+            p_cf_excluding_biogenic: dec!(0.793).try_into().unwrap(),
             p_cf_including_biogenic: None,
-            fossil_ghg_emissions: dec!(0.793).into(),
-            fossil_carbon_content: dec!(0.793).into(),
-            biogenic_carbon_content: dec!(0).into(),
+            fossil_ghg_emissions: dec!(0.793).try_into().unwrap(),
+            fossil_carbon_content: dec!(0.793).try_into().unwrap(),
+            biogenic_carbon_content: dec!(0).try_into().unwrap(),
             d_luc_ghg_emissions: None,
             land_management_ghg_emissions: None,
             other_biogenic_ghg_emissions: None,
@@ -842,7 +842,7 @@ lazy_static!(
             biogenic_carbon_withdrawal: None,
             aircraft_ghg_emissions: None,
             characterization_factors: CharacterizationFactors::Ar6,
-            ipcc_characterization_factors_sources: IpccCharacterizationFactorsSources::from(vec![String::from("AR6").into()]),
+            ipcc_characterization_factors_sources: vec![CharacterizationFactors::Ar6.into()].try_into().unwrap(),
             cross_sectoral_standards_used: CrossSectoralStandardSet(vec![DeprecatedCrossSectoralStandard::Ghgp]),
             product_or_sector_specific_rules: Some(ProductOrSectorSpecificRuleSet(vec![])),
             biogenic_accounting_methodology: None,
@@ -851,8 +851,8 @@ lazy_static!(
             reference_period_end: Utc.with_ymd_and_hms(2022, 1, 1, 0, 0, 0).unwrap(),
             geographic_scope: None,
             secondary_emission_factor_sources: Some(EmissionFactorDSSet(vec![EmissionFactorDS {
-                name: String::from("Ecoinvent").into(),
-                version: String::from("3.9.1").into(),
+                name: String::from("Ecoinvent").try_into().unwrap(),
+                version: String::from("3.9.1").try_into().unwrap(),
             }])),
             exempted_emissions_percent: ExemptedEmissionsPercent(0.0),
             exempted_emissions_description: "".to_string(),
@@ -860,13 +860,13 @@ lazy_static!(
             packaging_ghg_emissions: None,
             allocation_rules_description: None,
             uncertainty_assessment_description: None,
-            primary_data_share: Some(Percent::from(56.12)),
+            primary_data_share: Some(Percent::try_from(56.12).unwrap()),
             dqi: None,
             assurance: None
         },
         extensions: Some(vec![
             DataModelExtension {
-                spec_version: SpecVersionString::from("2.0.0".to_string()),
+                spec_version: SpecVersionString("2.0.0".to_string()),
                 data_schema: "https://api.ileap.sine.dev/toc.json".to_string(),
                 documentation: Some("https://sine-fdn.github.io/ileap-extension/".to_string()),
                 data: ILeapType::Toc(toc(
@@ -902,7 +902,7 @@ lazy_static!(
 
     static ref TOC_RAIL_EXAMPLE: ProductFootprint<ILeapType> = ProductFootprint {
         id: PfId(uuid!("02e10995-569a-4af9-b7a5-7c46dccb0fd3")),
-        spec_version: SpecVersionString::from("2.0.0".to_string()),
+        spec_version: SpecVersionString("2.0.0".to_string()),
         preceding_pf_ids: None,
         version: VersionInteger(0),
         created: Utc.with_ymd_and_hms(2022, 5, 22, 21, 47, 32).unwrap(),
@@ -911,21 +911,21 @@ lazy_static!(
         status_comment: None,
         validity_period_start: None,
         validity_period_end: None,
-        company_name: String::from("Super Duper Transport Co.").into(),
-        company_ids: CompanyIdSet(vec![Urn::from("urn:epc:id:sgln:4063973.00000.8".to_string())]),
+        company_name: String::from("Super Duper Transport Co.").try_into().unwrap(),
+        company_ids: CompanyIdSet(vec![Urn("urn:epc:id:sgln:4063973.00000.8".to_string())]),
         product_description: String::from("Logistics emissions related to TOC with ID toc-rail-1"),
-        product_ids: ProductIdSet(vec![Urn::from("urn:pathfinder:product:customcode:vendor-assigned:toc:toc-rail-1".to_string())]),
-        product_category_cpc: String::from("83117").into(),
-        product_name_company: String::from("TOC with ID toc-rail-1").into(),
+        product_ids: ProductIdSet(vec![Urn("urn:pathfinder:product:customcode:vendor-assigned:toc:toc-rail-1".to_string())]),
+        product_category_cpc: String::from("83117").try_into().unwrap(),
+        product_name_company: String::from("TOC with ID toc-rail-1").try_into().unwrap(),
         comment: "".to_string(),
         pcf: CarbonFootprint {
             declared_unit: DeclaredUnit::TonKilometer,
-            unitary_product_amount: dec!(1).into(),
-            p_cf_excluding_biogenic: dec!(0.007).into(),
+            unitary_product_amount: dec!(1).try_into().unwrap(),
+            p_cf_excluding_biogenic: dec!(0.007).try_into().unwrap(),
             p_cf_including_biogenic: None,
-            fossil_ghg_emissions: dec!(0.007).into(),
-            fossil_carbon_content: dec!(0.007).into(),
-            biogenic_carbon_content: dec!(0).into(),
+            fossil_ghg_emissions: dec!(0.007).try_into().unwrap(),
+            fossil_carbon_content: dec!(0.007).try_into().unwrap(),
+            biogenic_carbon_content: dec!(0).try_into().unwrap(),
             d_luc_ghg_emissions: None,
             land_management_ghg_emissions: None,
             other_biogenic_ghg_emissions: None,
@@ -933,7 +933,7 @@ lazy_static!(
             biogenic_carbon_withdrawal: None,
             aircraft_ghg_emissions: None,
             characterization_factors: CharacterizationFactors::Ar6,
-            ipcc_characterization_factors_sources: IpccCharacterizationFactorsSources::from(vec![String::from("AR6").into()]),
+            ipcc_characterization_factors_sources: vec![CharacterizationFactors::Ar6.into()].try_into().unwrap(),
             cross_sectoral_standards_used: CrossSectoralStandardSet(vec![DeprecatedCrossSectoralStandard::Ghgp]),
             product_or_sector_specific_rules: Some(ProductOrSectorSpecificRuleSet(vec![])),
             biogenic_accounting_methodology: None,
@@ -942,8 +942,8 @@ lazy_static!(
             reference_period_end: Utc.with_ymd_and_hms(2022, 1, 1, 0, 0, 0).unwrap(),
             geographic_scope: None,
             secondary_emission_factor_sources: Some(EmissionFactorDSSet(vec![EmissionFactorDS {
-                name: String::from("Ecoinvent").into(),
-                version: String::from("3.9.1").into(),
+                name: String::from("Ecoinvent").try_into().unwrap(),
+                version: String::from("3.9.1").try_into().unwrap(),
             }])),
             exempted_emissions_percent: ExemptedEmissionsPercent(0.0),
             exempted_emissions_description: "".to_string(),
@@ -951,13 +951,13 @@ lazy_static!(
             packaging_ghg_emissions: None,
             allocation_rules_description: None,
             uncertainty_assessment_description: None,
-            primary_data_share: Some(Percent::from(100.0)),
+            primary_data_share: Some(Percent::try_from(100.0).unwrap()),
             dqi: None,
             assurance: None
         },
         extensions: Some(vec![
             DataModelExtension {
-                spec_version: SpecVersionString::from("2.0.0".to_string()),
+                spec_version: SpecVersionString("2.0.0".to_string()),
                 data_schema: "https://api.ileap.sine.dev/toc.json".to_string(),
                 documentation: Some("https://sine-fdn.github.io/ileap-extension/".to_string()),
                 data:
@@ -993,7 +993,7 @@ lazy_static!(
 
     static ref HOC_TRANSSHIPMENT_EXAMPLE: ProductFootprint<ILeapType> = ProductFootprint {
         id: PfId(uuid!("46e095f0-f73d-4ace-adba-2ec8bf305339")),
-        spec_version: SpecVersionString::from("2.0.0".to_string()),
+        spec_version: SpecVersionString("2.0.0".to_string()),
         preceding_pf_ids: None,
         version: VersionInteger(0),
         created: Utc.with_ymd_and_hms(2024, 5, 22, 21, 47, 32).unwrap(),
@@ -1002,21 +1002,21 @@ lazy_static!(
         status_comment: None,
         validity_period_start: None,
         validity_period_end: None,
-        company_name: String::from("Hub Co.").into(),
-        company_ids: CompanyIdSet(vec![Urn::from("urn:epc:id:sgln:4063973.00000.9".to_string())]),
+        company_name: String::from("Hub Co.").try_into().unwrap(),
+        company_ids: CompanyIdSet(vec![Urn("urn:epc:id:sgln:4063973.00000.9".to_string())]),
         product_description: String::from("Logistics emissions related to HOC with ID hoc-transshipment-1"),
-        product_ids: ProductIdSet(vec![Urn::from("urn:pathfinder:product:customcode:vendor-assigned:hoc:hoc-transshipment-1".to_string())]),
-        product_category_cpc: String::from("83117").into(),
-        product_name_company:String::from("HOC with ID hoc-transshipment-1").into(),
+        product_ids: ProductIdSet(vec![Urn("urn:pathfinder:product:customcode:vendor-assigned:hoc:hoc-transshipment-1".to_string())]),
+        product_category_cpc: String::from("83117").try_into().unwrap(),
+        product_name_company:String::from("HOC with ID hoc-transshipment-1").try_into().unwrap(),
         comment: "".to_string(),
         pcf: CarbonFootprint {
             declared_unit: DeclaredUnit::Kilogram,
-            unitary_product_amount: dec!(1000).into(),
-            p_cf_excluding_biogenic: dec!(33).into(),
+            unitary_product_amount: dec!(1000).try_into().unwrap(),
+            p_cf_excluding_biogenic: dec!(33).try_into().unwrap(),
             p_cf_including_biogenic: None,
-            fossil_ghg_emissions: dec!(33).into(),
-            fossil_carbon_content: dec!(33).into(),
-            biogenic_carbon_content: dec!(0).into(),
+            fossil_ghg_emissions: dec!(33).try_into().unwrap(),
+            fossil_carbon_content: dec!(33).try_into().unwrap(),
+            biogenic_carbon_content: dec!(0).try_into().unwrap(),
             d_luc_ghg_emissions: None,
             land_management_ghg_emissions: None,
             other_biogenic_ghg_emissions: None,
@@ -1024,7 +1024,7 @@ lazy_static!(
             biogenic_carbon_withdrawal: None,
             aircraft_ghg_emissions: None,
             characterization_factors: CharacterizationFactors::Ar6,
-            ipcc_characterization_factors_sources: IpccCharacterizationFactorsSources::from(vec![String::from("AR6").into()]),
+            ipcc_characterization_factors_sources: vec![CharacterizationFactors::Ar6.into()].try_into().unwrap(),
             cross_sectoral_standards_used: CrossSectoralStandardSet(vec![DeprecatedCrossSectoralStandard::Ghgp]),
             product_or_sector_specific_rules: Some(ProductOrSectorSpecificRuleSet(vec![])),
             biogenic_accounting_methodology: None,
@@ -1033,8 +1033,8 @@ lazy_static!(
             reference_period_end: Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap(),
             geographic_scope: None,
             secondary_emission_factor_sources: Some(EmissionFactorDSSet(vec![EmissionFactorDS {
-                name: String::from("Ecoinvent").into(),
-                version: String::from("3.9.1").into(),
+                name: String::from("Ecoinvent").try_into().unwrap(),
+                version: String::from("3.9.1").try_into().unwrap(),
             }])),
             exempted_emissions_percent: ExemptedEmissionsPercent(0.0),
             exempted_emissions_description: "".to_string(),
@@ -1042,12 +1042,12 @@ lazy_static!(
             packaging_ghg_emissions: None,
             allocation_rules_description: None,
             uncertainty_assessment_description: None,
-            primary_data_share: Some(Percent::from(100.0)),
+            primary_data_share: Some(Percent::try_from(100.0).unwrap()),
             dqi: None,
             assurance: None
         },
         extensions: Some(vec![DataModelExtension {
-            spec_version: SpecVersionString::from("2.0.0".to_string()),
+            spec_version: SpecVersionString("2.0.0".to_string()),
             data_schema: "https://api.ileap.sine.dev/hoc.json".to_string(),
             documentation: Some("https://sine-fdn.github.io/ileap-extension/".to_string()),
             data: ILeapType::Hoc(hoc(HocArgs {
@@ -1063,7 +1063,7 @@ lazy_static!(
 
     static ref HOC_WAREHOUSE_EXAMPLE: ProductFootprint<ILeapType> = ProductFootprint {
         id: PfId(uuid!("2cf0a291-ad7f-43df-b0d7-b9554b0a9a02")),
-        spec_version: SpecVersionString::from("2.0.0".to_string()),
+        spec_version: SpecVersionString("2.0.0".to_string()),
         preceding_pf_ids: None,
         version: VersionInteger(0),
         created: Utc.with_ymd_and_hms(2024, 5, 22, 21, 47, 32).unwrap(),
@@ -1072,21 +1072,21 @@ lazy_static!(
         status_comment: None,
         validity_period_start: None,
         validity_period_end: None,
-        company_name: String::from("Hub Co.").into(),
-        company_ids: CompanyIdSet(vec![Urn::from("urn:epc:id:sgln:4063973.00000.9".to_string())]),
+        company_name: String::from("Hub Co.").try_into().unwrap(),
+        company_ids: CompanyIdSet(vec![Urn("urn:epc:id:sgln:4063973.00000.9".to_string())]),
         product_description: String::from("Logistics emissions related to HOC with ID hoc-warehouse-1"),
-        product_ids: ProductIdSet(vec![Urn::from("urn:pathfinder:product:customcode:vendor-assigned:hoc:hoc-warehouse-1".to_string())]),
-        product_category_cpc: String::from("83117").into(),
-        product_name_company:String::from("HOC with ID hoc-warehouse-1").into(),
+        product_ids: ProductIdSet(vec![Urn("urn:pathfinder:product:customcode:vendor-assigned:hoc:hoc-warehouse-1".to_string())]),
+        product_category_cpc: String::from("83117").try_into().unwrap(),
+        product_name_company:String::from("HOC with ID hoc-warehouse-1").try_into().unwrap(),
         comment: "".to_string(),
         pcf: CarbonFootprint {
             declared_unit: DeclaredUnit::Kilogram,
-            unitary_product_amount: dec!(1000).into(),
-            p_cf_excluding_biogenic: dec!(33).into(),
+            unitary_product_amount: dec!(1000).try_into().unwrap(),
+            p_cf_excluding_biogenic: dec!(33).try_into().unwrap(),
             p_cf_including_biogenic: None,
-            fossil_ghg_emissions: dec!(33).into(),
-            fossil_carbon_content: dec!(33).into(),
-            biogenic_carbon_content: dec!(0).into(),
+            fossil_ghg_emissions: dec!(33).try_into().unwrap(),
+            fossil_carbon_content: dec!(33).try_into().unwrap(),
+            biogenic_carbon_content: dec!(0).try_into().unwrap(),
             d_luc_ghg_emissions: None,
             land_management_ghg_emissions: None,
             other_biogenic_ghg_emissions: None,
@@ -1094,7 +1094,7 @@ lazy_static!(
             biogenic_carbon_withdrawal: None,
             aircraft_ghg_emissions: None,
             characterization_factors: CharacterizationFactors::Ar6,
-            ipcc_characterization_factors_sources: IpccCharacterizationFactorsSources::from(vec![String::from("AR6").into()]),
+            ipcc_characterization_factors_sources: vec![CharacterizationFactors::Ar6.into()].try_into().unwrap(),
             cross_sectoral_standards_used: CrossSectoralStandardSet(vec![DeprecatedCrossSectoralStandard::Ghgp]),
             product_or_sector_specific_rules: Some(ProductOrSectorSpecificRuleSet(vec![])),
             biogenic_accounting_methodology: None,
@@ -1103,8 +1103,8 @@ lazy_static!(
             reference_period_end: Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap(),
             geographic_scope: None,
             secondary_emission_factor_sources: Some(EmissionFactorDSSet(vec![EmissionFactorDS {
-                name: String::from("Ecoinvent").into(),
-                version: String::from("3.9.1").into(),
+                name: String::from("Ecoinvent").try_into().unwrap(),
+                version: String::from("3.9.1").try_into().unwrap(),
             }])),
             exempted_emissions_percent: ExemptedEmissionsPercent(0.0),
             exempted_emissions_description: "".to_string(),
@@ -1112,12 +1112,12 @@ lazy_static!(
             packaging_ghg_emissions: None,
             allocation_rules_description: None,
             uncertainty_assessment_description: None,
-            primary_data_share: Some(Percent::from(100.0)),
+            primary_data_share: Some(Percent::try_from(100.0).unwrap()),
             dqi: None,
             assurance: None
         },
         extensions: Some(vec![DataModelExtension {
-            spec_version: SpecVersionString::from("2.0.0".to_string()),
+            spec_version: SpecVersionString("2.0.0".to_string()),
             data_schema: "https://api.ileap.sine.dev/hoc.json".to_string(),
             documentation: Some("https://sine-fdn.github.io/ileap-extension/".to_string()),
             data: ILeapType::Hoc(
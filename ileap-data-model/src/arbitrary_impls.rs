@@ -492,7 +492,8 @@ impl Arbitrary for Tce {
 
         let distance = glec_distance.get_distance();
 
-        let transport_activity = WrappedDecimal::from(mass.0 * distance);
+        // in tkm, with the mass in kg
+        let transport_activity = WrappedDecimal::from(mass.0 / Decimal::ONE_THOUSAND * distance);
 
         let departure_at =
             Option::<DateTime<Utc>>::from(Utc::now() + Duration::days(u8::arbitrary(g) as i64));
@@ -564,7 +565,7 @@ fn gen_wrapped_decimal(g: &mut quickcheck::Gen) -> WrappedDecimal {
  */
 fn gen_pos_decimal(g: &mut quickcheck::Gen) -> PositiveDecimal {
     let decimal = Decimal::new(u16::arbitrary(g) as i64, 2);
-    PositiveDecimal::from(decimal)
+    PositiveDecimal(decimal)
 }
 
 fn gen_opt_pos_decimal(g: &mut quickcheck::Gen) -> Option<PositiveDecimal> {
@@ -0,0 +1,199 @@
+/*
+ * Copyright (c) 2024 SINE Foundation e.V.
+ *
+ * This software is released under the MIT License, see LICENSE.
+ */
+
+//! Consistency checks of the emissions of TCEs against the TOCs and HOCs they reference.
+//!
+//! The expected values follow the formulas of `gen_rnd_demo_data`, with the `mass` of a TCE in kg
+//! and its `distance` in km:
+//! - `transportActivity = mass / 1000 × distance`, i.e. in tkm,
+//! - `co2eWTW = co2eIntensityWTW × transportActivity` for a TCE referencing a TOC, and
+//! - `co2eWTW = co2eIntensityWTW × mass / 1000` for a TCE referencing a HOC,
+//!
+//! and likewise for `co2eTTW`. TCEs referencing a TOC in `TEUkm` or a HOC in `TEU` are only
+//! checked for their transport activity, as a TCE does not state the number of TEUs it carries.
+
+use rust_decimal::Decimal;
+use rust_decimal_macros::dec;
+use serde::Serialize;
+
+use crate::{Hoc, HubActivityUnit, ShipmentFootprint, Tce, Toc, TransportActivityUnit};
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+/// The deviation accepted between an expected and an actual value. A value is accepted if it
+/// deviates by at most `absolute` or by at most `relative` times the expected value.
+pub struct Tolerance {
+    pub relative: Decimal,
+    pub absolute: Decimal,
+}
+
+impl Default for Tolerance {
+    /// 1% relative deviation, or 0.01 absolute deviation to allow for values rounded to two
+    /// decimal places
+    fn default() -> Self {
+        Tolerance {
+            relative: dec!(0.01),
+            absolute: dec!(0.01),
+        }
+    }
+}
+
+impl Tolerance {
+    pub fn accepts(&self, expected: Decimal, actual: Decimal) -> bool {
+        let deviation = (actual - expected).abs();
+        deviation <= self.absolute || deviation <= self.relative * expected.abs()
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(
+    tag = "kind",
+    rename_all = "camelCase",
+    rename_all_fields = "camelCase"
+)]
+/// An inconsistency of a TCE found by `check_tce_consistency`
+pub enum Discrepancy {
+    /// The TCE references a TOC which is not among the given TOCs
+    UnknownToc { tce_id: String, toc_id: String },
+    /// The TCE references a HOC which is not among the given HOCs
+    UnknownHoc { tce_id: String, hoc_id: String },
+    /// A property of the TCE deviates from its expected value by more than the tolerance
+    Mismatch {
+        tce_id: String,
+        /// The name of the property, e.g. `co2eWTW`
+        property: &'static str,
+        expected: Decimal,
+        actual: Decimal,
+    },
+}
+
+impl std::fmt::Display for Discrepancy {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Discrepancy::UnknownToc { tce_id, toc_id } => {
+                write!(f, "TCE {tce_id}: unknown TOC {toc_id}")
+            }
+            Discrepancy::UnknownHoc { tce_id, hoc_id } => {
+                write!(f, "TCE {tce_id}: unknown HOC {hoc_id}")
+            }
+            Discrepancy::Mismatch {
+                tce_id,
+                property,
+                expected,
+                actual,
+            } => write!(
+                f,
+                "TCE {tce_id}: {property} is {actual}, expected {}",
+                expected.normalize()
+            ),
+        }
+    }
+}
+
+/// Checks the transport activity and emissions of each TCE of `shipment` against its mass,
+/// distance and the TOC or HOC it references, returning all discrepancies found. TCEs referencing
+/// neither a TOC nor a HOC are only checked for their transport activity.
+pub fn check_tce_consistency(
+    shipment: &ShipmentFootprint,
+    tocs: &[Toc],
+    hocs: &[Hoc],
+    tolerance: &Tolerance,
+) -> Vec<Discrepancy> {
+    let mut discrepancies = vec![];
+
+    for tce in shipment.tces.0.iter() {
+        let transport_activity = tce.mass.0 / Decimal::ONE_THOUSAND * tce.distance.get_distance();
+        discrepancies.extend(mismatch(
+            tce,
+            "transportActivity",
+            transport_activity,
+            tce.transport_activity.0,
+            tolerance,
+        ));
+
+        let emissions = match (&tce.toc_id, &tce.hoc_id) {
+            (Some(toc_id), _) => match tocs.iter().find(|toc| &toc.toc_id == toc_id) {
+                Some(toc) => toc_emissions(toc, tce),
+                None => {
+                    discrepancies.push(Discrepancy::UnknownToc {
+                        tce_id: tce.tce_id.clone(),
+                        toc_id: toc_id.clone(),
+                    });
+                    None
+                }
+            },
+            (None, Some(hoc_id)) => match hocs.iter().find(|hoc| &hoc.hoc_id == hoc_id) {
+                Some(hoc) => hoc_emissions(hoc, tce),
+                None => {
+                    discrepancies.push(Discrepancy::UnknownHoc {
+                        tce_id: tce.tce_id.clone(),
+                        hoc_id: hoc_id.clone(),
+                    });
+                    None
+                }
+            },
+            (None, None) => None,
+        };
+
+        if let Some((co2e_wtw, co2e_ttw)) = emissions {
+            discrepancies.extend(mismatch(
+                tce,
+                "co2eWTW",
+                co2e_wtw,
+                tce.co2e_wtw.0,
+                tolerance,
+            ));
+            discrepancies.extend(mismatch(
+                tce,
+                "co2eTTW",
+                co2e_ttw,
+                tce.co2e_ttw.0,
+                tolerance,
+            ));
+        }
+    }
+
+    discrepancies
+}
+
+/// The expected WTW and TTW emissions of `tce` given the intensities of `toc`
+fn toc_emissions(toc: &Toc, tce: &Tce) -> Option<(Decimal, Decimal)> {
+    match toc.transport_activity_unit {
+        TransportActivityUnit::Tkm => Some((
+            toc.co2e_intensity_wtw.0 * tce.transport_activity.0,
+            toc.co2e_intensity_ttw.0 * tce.transport_activity.0,
+        )),
+        TransportActivityUnit::TEUkm => None,
+    }
+}
+
+/// The expected WTW and TTW emissions of `tce` given the intensities of `hoc`
+fn hoc_emissions(hoc: &Hoc, tce: &Tce) -> Option<(Decimal, Decimal)> {
+    match hoc.hub_activity_unit {
+        HubActivityUnit::Tonnes => {
+            let tonnes = tce.mass.0 / Decimal::ONE_THOUSAND;
+            Some((
+                hoc.co2e_intensity_wtw.0 * tonnes,
+                hoc.co2e_intensity_ttw.0 * tonnes,
+            ))
+        }
+        HubActivityUnit::TEU => None,
+    }
+}
+
+fn mismatch(
+    tce: &Tce,
+    property: &'static str,
+    expected: Decimal,
+    actual: Decimal,
+    tolerance: &Tolerance,
+) -> Option<Discrepancy> {
+    (!tolerance.accepts(expected, actual)).then(|| Discrepancy::Mismatch {
+        tce_id: tce.tce_id.clone(),
+        property,
+        expected,
+        actual,
+    })
+}
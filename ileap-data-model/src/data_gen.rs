@@ -1,11 +1,13 @@
-use pact_data_model::{CharacterizationFactors, ProductFootprint, WrappedDecimal};
+use pact_data_model::{
+    CarbonFootprint, CharacterizationFactors, ExtensionRegistry, ProductFootprint, WrappedDecimal,
+};
 use quickcheck::*;
 use rust_decimal::Decimal;
 use rust_decimal_macros::dec;
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
-use crate::pact_integration::to_pcf;
+use crate::pact_integration::{data_schema_url, to_pcf};
 use crate::{
     GlecDistance, GlecDistanceKind, Hoc, HubActivityUnit, NonEmptyVec, PactMappedFields,
     ShipmentFootprint, Tce, Toc,
@@ -13,12 +15,27 @@ use crate::{
 
 #[derive(Debug, Serialize, Deserialize, JsonSchema, PartialEq, Clone)]
 #[serde(rename_all = "camelCase", untagged)]
+/// Any iLEAP data type embedded in a `ProductFootprint`.
+///
+/// Deserializing this type directly tries each variant in order, ignoring the extension's
+/// `dataSchema`. Prefer decoding footprints with `ileap_extension_registry` instead.
 pub enum ILeapType {
     ShipmentFootprint(ShipmentFootprint),
     Toc(Toc),
     Hoc(Hoc),
 }
 
+/// An `ExtensionRegistry` of the iLEAP data types, keyed by their `dataSchema` URLs
+pub fn ileap_extension_registry() -> ExtensionRegistry<ILeapType> {
+    ExtensionRegistry::new()
+        .register(
+            data_schema_url("shipment-footprint"),
+            ILeapType::ShipmentFootprint,
+        )
+        .register(data_schema_url("toc"), ILeapType::Toc)
+        .register(data_schema_url("hoc"), ILeapType::Hoc)
+}
+
 impl From<&ILeapType> for PactMappedFields {
     fn from(ileap_type: &ILeapType) -> Self {
         match ileap_type {
@@ -42,6 +59,10 @@ pub fn gen_rnd_demo_data(size: u8) -> Vec<ProductFootprint<ILeapType>> {
     let mut tocs = vec![];
     let mut hocs = vec![];
 
+    // the transport activity of a TCE in tkm, with its mass in kg
+    let tkm =
+        |tce: &Tce| (tce.mass.0 / Decimal::ONE_THOUSAND * tce.distance.get_distance()).round_dp(2);
+
     let num_of_shipments = u8::arbitrary(&mut og) % size + 1;
     for _ in 0..num_of_shipments {
         let mut ship_foot = ShipmentFootprint::arbitrary(&mut og);
@@ -89,29 +110,28 @@ pub fn gen_rnd_demo_data(size: u8) -> Vec<ProductFootprint<ILeapType>> {
                 };
                 tce.transport_activity = Decimal::from(0).into();
 
+                // the mass of a TCE is in kg, the intensities of a HOC are per tonne
+                let tonnes = tce.mass.0 / Decimal::ONE_THOUSAND;
                 tce.co2e_wtw =
-                    WrappedDecimal::from((hoc.co2e_intensity_wtw.0 * tce.mass.0).round_dp(2));
+                    WrappedDecimal::from((hoc.co2e_intensity_wtw.0 * tonnes).round_dp(2));
                 tce.co2e_ttw =
-                    WrappedDecimal::from((hoc.co2e_intensity_ttw.0 * tce.mass.0).round_dp(2));
+                    WrappedDecimal::from((hoc.co2e_intensity_ttw.0 * tonnes).round_dp(2));
 
-                let hoc = to_pcf(
+                hocs.extend(to_pcf(
                     ILeapType::Hoc(hoc),
                     "SINE Foundation",
                     "urn:sine:example",
                     Some(vec![CharacterizationFactors::Ar6]),
                     reference_period_start,
                     reference_period_end,
-                );
-
-                hocs.push(hoc);
+                ));
             }
 
             if tce.toc_id.is_some() {
                 let mut toc = Toc::arbitrary(&mut og);
                 toc.toc_id = tce.toc_id.clone().unwrap();
 
-                let distance = tce.distance.get_distance();
-                tce.transport_activity = (tce.mass.0 * distance).round_dp(2).into();
+                tce.transport_activity = tkm(&tce).into();
 
                 tce.toc_id = Some(toc.toc_id.clone());
 
@@ -122,16 +142,14 @@ pub fn gen_rnd_demo_data(size: u8) -> Vec<ProductFootprint<ILeapType>> {
                     (toc.co2e_intensity_ttw.0 * tce.transport_activity.0).round_dp(2),
                 );
 
-                let toc = to_pcf(
+                tocs.extend(to_pcf(
                     ILeapType::Toc(toc),
                     "SINE Foundation",
                     "urn:sine:example",
                     Some(vec![CharacterizationFactors::Ar6]),
                     reference_period_start,
                     reference_period_end,
-                );
-
-                tocs.push(toc.clone());
+                ));
             }
 
             tce.shipment_id.clone_from(&ship_foot.shipment_id);
@@ -146,24 +164,59 @@ pub fn gen_rnd_demo_data(size: u8) -> Vec<ProductFootprint<ILeapType>> {
 
         ship_foot.tces = NonEmptyVec::from(tces);
 
-        let ship_foot = to_pcf(
+        // shipments without transport activity, e.g. of a single hub operation, have no footprint
+        shipment_footprints.extend(to_pcf(
             ILeapType::ShipmentFootprint(ship_foot),
             "SINE Foundation",
             "urn:sine:example",
             Some(vec![CharacterizationFactors::Ar6]),
             reference_period_start,
             reference_period_end,
-        );
-
-        shipment_footprints.push(ship_foot);
+        ));
     }
 
     vec![shipment_footprints, tocs, hocs]
         .into_iter()
         .flatten()
+        .map(|pf| with_arbitrary_pcf_details(pf, &mut og))
         .collect()
 }
 
+/// Replaces the properties of the `CarbonFootprint` which `to_pcf` does not derive from the iLEAP
+/// data, such as the geographic scope, DQIs and assurance, with arbitrary valid values, so that
+/// demo footprints differ beyond their emissions
+fn with_arbitrary_pcf_details(
+    mut pf: ProductFootprint<ILeapType>,
+    g: &mut Gen,
+) -> ProductFootprint<ILeapType> {
+    let CarbonFootprint {
+        boundary_processes_description,
+        geographic_scope,
+        exempted_emissions_percent,
+        exempted_emissions_description,
+        allocation_rules_description,
+        uncertainty_assessment_description,
+        primary_data_share,
+        dqi,
+        assurance,
+        ..
+    } = CarbonFootprint::arbitrary(g);
+
+    pf.pcf = CarbonFootprint {
+        boundary_processes_description,
+        geographic_scope,
+        exempted_emissions_percent,
+        exempted_emissions_description,
+        allocation_rules_description,
+        uncertainty_assessment_description,
+        primary_data_share,
+        dqi,
+        assurance,
+        ..pf.pcf
+    };
+    pf
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -173,6 +226,9 @@ mod tests {
         let footprints = gen_rnd_demo_data(10);
 
         for footprint in footprints.iter() {
+            assert_eq!(footprint.validate(), vec![]);
+            assert!(!footprint.pcf.boundary_processes_description.is_empty());
+
             if let Some(extensions) = &footprint.extensions {
                 for extension in extensions.iter() {
                     if let ILeapType::ShipmentFootprint(ship_foot) = &extension.data {
@@ -0,0 +1,284 @@
+/*
+ * Copyright (c) 2024 SINE Foundation e.V.
+ *
+ * This software is released under the MIT License, see LICENSE.
+ */
+
+//! Lookup of well-to-wheel and tank-to-wheel emission factors of energy carriers in a table of
+//! default factors, and the completion of energy carriers whose emission factors are unknown.
+//!
+//! This crate does not bundle any default factors. Callers supply an `EmissionFactorLibrary` of
+//! published factors, e.g. of the GLEC Framework, naming its source and version.
+//!
+//! Factors are looked up by energy carrier, feedstock and region, with regional factors taking
+//! precedence over global ones. Carriers without feedstocks get the generic factor of the carrier.
+//! Carriers made of one or several feedstocks (each with a share if there are several) get the
+//! share-weighted average of the factors specific to their feedstocks. If a feedstock has no
+//! specific factor, or the shares are unknown, the factors are unknown as well.
+
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    EnergyCarrier, EnergyCarrierType, EnergyConsumptionUnit, Feedstock, FeedstockType,
+    WrappedDecimal,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+/// The region a default emission factor applies to
+pub enum FactorRegion {
+    Global,
+    Europe,
+    NorthAmerica,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+/// A default emission factor of an energy carrier
+pub struct DefaultEmissionFactor {
+    pub energy_carrier: EnergyCarrierType,
+    /// The feedstock the factor is specific to; `None` for the generic factor of the carrier
+    pub feedstock: Option<FeedstockType>,
+    pub region: FactorRegion,
+    /// The unit of energy carrier the factors refer to
+    pub unit: EnergyConsumptionUnit,
+    /// Well-to-wheel emissions in kg CO2e per `unit`
+    #[serde(rename = "emissionFactorWTW")]
+    pub emission_factor_wtw: Decimal,
+    /// Tank-to-wheel emissions in kg CO2e per `unit`
+    #[serde(rename = "emissionFactorTTW")]
+    pub emission_factor_ttw: Decimal,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+/// The origin of emission factors taken from an `EmissionFactorLibrary`
+pub struct FactorSource {
+    /// The name of the library
+    pub library: String,
+    pub version: String,
+    /// The region of the factors used, which is `Global` if the requested region had none
+    pub region: FactorRegion,
+    /// The feedstocks whose specific factors were used; empty if only generic factors were used
+    pub feedstocks: Vec<FeedstockType>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+/// Emission factors found by `EmissionFactorLibrary::lookup`
+pub struct FactorLookup {
+    pub unit: EnergyConsumptionUnit,
+    pub emission_factor_wtw: Decimal,
+    pub emission_factor_ttw: Decimal,
+    pub source: FactorSource,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+/// A versioned table of default emission factors
+pub struct EmissionFactorLibrary {
+    pub name: String,
+    pub version: String,
+    pub factors: Vec<DefaultEmissionFactor>,
+}
+
+impl EmissionFactorLibrary {
+    /// The emission factors of `energy_carrier` made of `feedstocks` in `region`, in `unit` if
+    /// given and otherwise in the unit listed first, or `None` if they are unknown. See the module
+    /// documentation.
+    pub fn lookup(
+        &self,
+        energy_carrier: &EnergyCarrierType,
+        feedstocks: Option<&[Feedstock]>,
+        region: FactorRegion,
+        unit: Option<&EnergyConsumptionUnit>,
+    ) -> Option<FactorLookup> {
+        let generic = self.find(energy_carrier, None, region, unit)?;
+        let unit = generic.unit.clone();
+
+        let mut source = FactorSource {
+            library: self.name.clone(),
+            version: self.version.clone(),
+            region: generic.region,
+            feedstocks: vec![],
+        };
+
+        // the feedstocks with their shares, which must all be known unless there is a single one
+        let blend: Vec<(&FeedstockType, Decimal)> = match feedstocks.unwrap_or_default() {
+            [] => {
+                return Some(FactorLookup {
+                    unit,
+                    emission_factor_wtw: generic.emission_factor_wtw,
+                    emission_factor_ttw: generic.emission_factor_ttw,
+                    source,
+                })
+            }
+            [single] => vec![(&single.feedstock, Decimal::ONE)],
+            feedstocks => feedstocks
+                .iter()
+                .map(|f| Some((&f.feedstock, f.feedstock_share.as_ref()?.0)))
+                .collect::<Option<_>>()?,
+        };
+        let total_share: Decimal = blend.iter().map(|(_, share)| share).sum();
+        if total_share.is_zero() {
+            return None;
+        }
+
+        let (mut wtw, mut ttw) = (Decimal::ZERO, Decimal::ZERO);
+        for (feedstock, share) in blend.into_iter().filter(|(_, share)| !share.is_zero()) {
+            let factor = self.find(energy_carrier, Some(feedstock), region, Some(&unit))?;
+            source.feedstocks.push(feedstock.clone());
+            if factor.region == FactorRegion::Global {
+                source.region = FactorRegion::Global;
+            }
+            wtw += share / total_share * factor.emission_factor_wtw;
+            ttw += share / total_share * factor.emission_factor_ttw;
+        }
+
+        Some(FactorLookup {
+            unit,
+            emission_factor_wtw: wtw.normalize(),
+            emission_factor_ttw: ttw.normalize(),
+            source,
+        })
+    }
+
+    /// The factor for exactly `feedstock` in `region`, or globally if `region` has none
+    fn find(
+        &self,
+        energy_carrier: &EnergyCarrierType,
+        feedstock: Option<&FeedstockType>,
+        region: FactorRegion,
+        unit: Option<&EnergyConsumptionUnit>,
+    ) -> Option<&DefaultEmissionFactor> {
+        let find_in = |region| {
+            self.factors.iter().find(|factor| {
+                &factor.energy_carrier == energy_carrier
+                    && factor.feedstock.as_ref() == feedstock
+                    && factor.region == region
+                    && unit.is_none_or(|unit| &factor.unit == unit)
+            })
+        };
+        find_in(region).or_else(|| find_in(FactorRegion::Global))
+    }
+
+    /// Completes `energy_carrier` with the default factors of this library for those of its
+    /// emission factors that are missing, see `lookup`. If it has no `energyConsumptionUnit`, it
+    /// is set to the unit of the factors unless an `energyConsumption` is given.
+    pub fn complete(
+        &self,
+        energy_carrier: PartialEnergyCarrier,
+        region: FactorRegion,
+    ) -> Result<CompletedEnergyCarrier, MissingEmissionFactor> {
+        let PartialEnergyCarrier {
+            energy_carrier: carrier_type,
+            feedstocks,
+            energy_consumption,
+            mut energy_consumption_unit,
+            emission_factor_wtw,
+            emission_factor_ttw,
+            relative_share,
+        } = energy_carrier;
+
+        let (emission_factor_wtw, emission_factor_ttw, source) =
+            match (emission_factor_wtw, emission_factor_ttw) {
+                (Some(wtw), Some(ttw)) => (wtw, ttw, None),
+                (wtw, ttw) => {
+                    let lookup = self
+                        .lookup(
+                            &carrier_type,
+                            feedstocks.as_deref(),
+                            region,
+                            energy_consumption_unit.as_ref(),
+                        )
+                        .ok_or_else(|| MissingEmissionFactor {
+                            energy_carrier: carrier_type.clone(),
+                            feedstocks: feedstocks
+                                .iter()
+                                .flatten()
+                                .map(|f| f.feedstock.clone())
+                                .collect(),
+                            region,
+                            unit: energy_consumption_unit.clone(),
+                        })?;
+                    if energy_consumption.is_none() {
+                        energy_consumption_unit.get_or_insert(lookup.unit);
+                    }
+                    (
+                        wtw.unwrap_or(lookup.emission_factor_wtw.into()),
+                        ttw.unwrap_or(lookup.emission_factor_ttw.into()),
+                        Some(lookup.source),
+                    )
+                }
+            };
+
+        Ok(CompletedEnergyCarrier {
+            energy_carrier: EnergyCarrier {
+                energy_carrier: carrier_type,
+                feedstocks,
+                energy_consumption,
+                energy_consumption_unit,
+                emission_factor_wtw,
+                emission_factor_ttw,
+                relative_share,
+            },
+            source,
+        })
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+/// An `EnergyCarrier` whose emission factors may be missing
+pub struct PartialEnergyCarrier {
+    pub energy_carrier: EnergyCarrierType,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub feedstocks: Option<Vec<Feedstock>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub energy_consumption: Option<WrappedDecimal>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub energy_consumption_unit: Option<EnergyConsumptionUnit>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "emissionFactorWTW")]
+    pub emission_factor_wtw: Option<WrappedDecimal>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "emissionFactorTTW")]
+    pub emission_factor_ttw: Option<WrappedDecimal>,
+    pub relative_share: WrappedDecimal,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+/// An `EnergyCarrier` completed by `EmissionFactorLibrary::complete`
+pub struct CompletedEnergyCarrier {
+    pub energy_carrier: EnergyCarrier,
+    /// The source of the default factors filled in; `None` if none were missing
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub source: Option<FactorSource>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+/// Error returned when a library has no default factor for an energy carrier
+pub struct MissingEmissionFactor {
+    pub energy_carrier: EnergyCarrierType,
+    /// The feedstocks of the energy carrier; empty if it has none
+    pub feedstocks: Vec<FeedstockType>,
+    pub region: FactorRegion,
+    pub unit: Option<EnergyConsumptionUnit>,
+}
+
+impl std::fmt::Display for MissingEmissionFactor {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "no default emission factor for {:?} in {:?}",
+            self.energy_carrier, self.region
+        )?;
+        if !self.feedstocks.is_empty() {
+            write!(f, " made of {:?}", self.feedstocks)?;
+        }
+        if let Some(unit) = &self.unit {
+            write!(f, " per {unit:?}")?;
+        }
+        Ok(())
+    }
+}
+
+impl std::error::Error for MissingEmissionFactor {}
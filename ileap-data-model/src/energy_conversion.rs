@@ -0,0 +1,238 @@
+/*
+ * Copyright (c) 2024 SINE Foundation e.V.
+ *
+ * This software is released under the MIT License, see LICENSE.
+ */
+
+//! Conversion of energy consumptions between the units of `EnergyConsumptionUnit`.
+//!
+//! Volumes and masses are converted to energy by the density (kg/l) and lower heating value
+//! (MJ/kg) of the energy carrier. The defaults below are typical values of the fuels as used by
+//! EN 16258 and the GLEC Framework; they can be overridden per energy carrier. Emission factors,
+//! being per unit of energy consumption, are converted inversely, so that the emissions of an
+//! energy carrier are the same in any unit.
+
+use rust_decimal::Decimal;
+
+use crate::{EnergyCarrier, EnergyCarrierType, EnergyConsumptionUnit};
+
+/// MJ per kWh
+const MJ_PER_KWH: Decimal = Decimal::from_parts(36, 0, 0, false, 1);
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+/// The physical properties of an energy carrier needed to convert between units
+pub struct EnergyProperties {
+    /// Density in kg/l; `None` for gaseous carriers and electricity
+    pub density: Option<Decimal>,
+    /// Lower heating value in MJ/kg; `None` for electricity
+    pub lower_heating_value: Option<Decimal>,
+}
+
+impl EnergyProperties {
+    /// The default properties of `energy_carrier`, see the module documentation
+    pub fn default_for(energy_carrier: &EnergyCarrierType) -> Self {
+        use EnergyCarrierType::*;
+
+        let (density, lower_heating_value) = match energy_carrier {
+            Diesel => (Some((832, 3)), Some((431, 1))),
+            Hvo => (Some((780, 3)), Some((440, 1))),
+            Petrol => (Some((745, 3)), Some((432, 1))),
+            Cng => (None, Some((451, 1))),
+            Lng => (Some((450, 3)), Some((491, 1))),
+            Lpg => (Some((550, 3)), Some((460, 1))),
+            Hfo => (Some((970, 3)), Some((402, 1))),
+            Mgo => (Some((890, 3)), Some((427, 1))),
+            AviationFuel => (Some((800, 3)), Some((441, 1))),
+            Hydrogen => (Some((708, 4)), Some((1200, 1))),
+            Methanol => (Some((793, 3)), Some((199, 1))),
+            Electric => (None, None),
+        };
+        let decimal = |(mantissa, scale)| Decimal::new(mantissa, scale);
+
+        EnergyProperties {
+            density: density.map(decimal),
+            lower_heating_value: lower_heating_value.map(decimal),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+/// Error returned when an energy consumption cannot be converted
+pub enum ConversionError {
+    /// The energy carrier has an energy consumption or emission factors but no unit
+    MissingUnit,
+    /// The density of the energy carrier is unknown
+    MissingDensity(EnergyCarrierType),
+    /// The lower heating value of the energy carrier is unknown
+    MissingLowerHeatingValue(EnergyCarrierType),
+    /// The density of the energy carrier is not greater than 0
+    InvalidDensity(EnergyCarrierType),
+    /// The lower heating value of the energy carrier is not greater than 0
+    InvalidLowerHeatingValue(EnergyCarrierType),
+    /// The converted amount or emission factors do not fit into a `Decimal`
+    Overflow,
+}
+
+impl std::fmt::Display for ConversionError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ConversionError::MissingUnit => write!(f, "energy consumption unit is missing"),
+            ConversionError::MissingDensity(carrier) => write!(f, "no density of {carrier:?}"),
+            ConversionError::MissingLowerHeatingValue(carrier) => {
+                write!(f, "no lower heating value of {carrier:?}")
+            }
+            ConversionError::InvalidDensity(carrier) => {
+                write!(f, "density of {carrier:?} is not greater than 0")
+            }
+            ConversionError::InvalidLowerHeatingValue(carrier) => {
+                write!(
+                    f,
+                    "lower heating value of {carrier:?} is not greater than 0"
+                )
+            }
+            ConversionError::Overflow => write!(f, "converted value is out of range"),
+        }
+    }
+}
+
+impl std::error::Error for ConversionError {}
+
+#[derive(Debug, Clone, Default, PartialEq)]
+/// Converts energy consumptions using the default properties of energy carriers, unless
+/// overridden
+pub struct EnergyConversion {
+    overrides: Vec<(EnergyCarrierType, EnergyProperties)>,
+}
+
+impl EnergyConversion {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Overrides the properties of `energy_carrier`, replacing any override set before.
+    /// Conversions requiring a property that is not greater than 0 fail.
+    pub fn with_properties(
+        mut self,
+        energy_carrier: EnergyCarrierType,
+        properties: EnergyProperties,
+    ) -> Self {
+        self.overrides
+            .retain(|(carrier, _)| carrier != &energy_carrier);
+        self.overrides.push((energy_carrier, properties));
+        self
+    }
+
+    /// The properties of `energy_carrier` used for conversions
+    pub fn properties(&self, energy_carrier: &EnergyCarrierType) -> EnergyProperties {
+        self.overrides
+            .iter()
+            .find(|(carrier, _)| carrier == energy_carrier)
+            .map(|(_, properties)| *properties)
+            .unwrap_or_else(|| EnergyProperties::default_for(energy_carrier))
+    }
+
+    /// The amount of `to` equivalent to one `from` of `energy_carrier`
+    pub fn factor(
+        &self,
+        energy_carrier: &EnergyCarrierType,
+        from: &EnergyConsumptionUnit,
+        to: &EnergyConsumptionUnit,
+    ) -> Result<Decimal, ConversionError> {
+        if from == to {
+            return Ok(Decimal::ONE);
+        }
+        self.megajoules(energy_carrier, from)?
+            .checked_div(self.megajoules(energy_carrier, to)?)
+            .ok_or(ConversionError::Overflow)
+    }
+
+    /// Converts `amount` of `energy_carrier` from `from` to `to`
+    pub fn convert(
+        &self,
+        energy_carrier: &EnergyCarrierType,
+        amount: Decimal,
+        from: &EnergyConsumptionUnit,
+        to: &EnergyConsumptionUnit,
+    ) -> Result<Decimal, ConversionError> {
+        amount
+            .checked_mul(self.factor(energy_carrier, from, to)?)
+            .ok_or(ConversionError::Overflow)
+    }
+
+    /// Expresses the energy consumption and emission factors of `energy_carrier` in `to`,
+    /// typically `MJ` or `kWh`. Its emissions, i.e. energy consumption times emission factors,
+    /// stay the same.
+    pub fn normalize(
+        &self,
+        energy_carrier: &EnergyCarrier,
+        to: &EnergyConsumptionUnit,
+    ) -> Result<EnergyCarrier, ConversionError> {
+        let from = energy_carrier
+            .energy_consumption_unit
+            .as_ref()
+            .ok_or(ConversionError::MissingUnit)?;
+        let factor = self.factor(&energy_carrier.energy_carrier, from, to)?;
+        let per_unit = |emission_factor: Decimal| {
+            emission_factor
+                .checked_div(factor)
+                .map(|d| d.normalize().into())
+                .ok_or(ConversionError::Overflow)
+        };
+
+        let energy_consumption = match &energy_carrier.energy_consumption {
+            Some(consumption) => Some(
+                consumption
+                    .0
+                    .checked_mul(factor)
+                    .ok_or(ConversionError::Overflow)?
+                    .normalize()
+                    .into(),
+            ),
+            None => None,
+        };
+
+        Ok(EnergyCarrier {
+            energy_consumption,
+            energy_consumption_unit: Some(to.clone()),
+            emission_factor_wtw: per_unit(energy_carrier.emission_factor_wtw.0)?,
+            emission_factor_ttw: per_unit(energy_carrier.emission_factor_ttw.0)?,
+            ..energy_carrier.clone()
+        })
+    }
+
+    /// MJ per `unit` of `energy_carrier`
+    fn megajoules(
+        &self,
+        energy_carrier: &EnergyCarrierType,
+        unit: &EnergyConsumptionUnit,
+    ) -> Result<Decimal, ConversionError> {
+        let properties = self.properties(energy_carrier);
+        let lower_heating_value = || match properties.lower_heating_value {
+            None => Err(ConversionError::MissingLowerHeatingValue(
+                energy_carrier.clone(),
+            )),
+            Some(lhv) if lhv <= Decimal::ZERO => Err(ConversionError::InvalidLowerHeatingValue(
+                energy_carrier.clone(),
+            )),
+            Some(lhv) => Ok(lhv),
+        };
+
+        match unit {
+            EnergyConsumptionUnit::MJ => Ok(Decimal::ONE),
+            EnergyConsumptionUnit::KWh => Ok(MJ_PER_KWH),
+            EnergyConsumptionUnit::Kg => lower_heating_value(),
+            EnergyConsumptionUnit::L => {
+                let density = match properties.density {
+                    None => return Err(ConversionError::MissingDensity(energy_carrier.clone())),
+                    Some(density) if density <= Decimal::ZERO => {
+                        return Err(ConversionError::InvalidDensity(energy_carrier.clone()))
+                    }
+                    Some(density) => density,
+                };
+                density
+                    .checked_mul(lower_heating_value()?)
+                    .ok_or(ConversionError::Overflow)
+            }
+        }
+    }
+}
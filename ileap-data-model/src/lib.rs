@@ -29,6 +29,24 @@ pub use data_gen::*;
 pub mod schema_gen;
 pub use schema_gen::*;
 
+mod consistency;
+pub use consistency::*;
+
+mod tce_chain;
+pub use tce_chain::*;
+
+mod toc_calculation;
+pub use toc_calculation::*;
+
+mod tce_calculation;
+pub use tce_calculation::*;
+
+mod emission_factors;
+pub use emission_factors::*;
+
+mod energy_conversion;
+pub use energy_conversion::*;
+
 #[derive(Debug, Serialize, Deserialize, Clone, JsonSchema, PartialEq)]
 #[serde(rename_all = "camelCase")]
 pub struct ShipmentFootprint {
@@ -530,7 +548,6 @@ impl<T> From<Vec<T>> for NonEmptyVec<T> {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use serde_json;
 
     #[test]
     fn test_transportactivityunit_deser() {
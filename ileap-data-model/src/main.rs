@@ -5,20 +5,235 @@
  * This software is released under the MIT License, see LICENSE.
  */
 
-use ileap_data_model::{Hoc, ShipmentFootprint, Tad, Toc};
-use std::io::Error;
+//! Command-line tool for the JSON Schemas of the iLEAP data model and for validating footprints
+//! with iLEAP extensions.
+//!
+//! Files to validate may contain a single footprint, an array of footprints or a response of the
+//! PACT API's `ListFootprints` action (i.e. an object with a `data` array).
+
+use clap::{Parser, Subcommand};
+use ileap_data_model::{
+    check_tce_chain, check_tce_consistency, ileap_extension_registry, validate_json, Discrepancy,
+    Hoc, ILeapType, ShipmentFootprint, Tad, Toc, Tolerance,
+};
+use pact_data_model::{json_footprints, ProductFootprint};
+use serde_json::Value;
+use std::fs;
+use std::path::PathBuf;
+use std::process::ExitCode;
 
 use ileap_data_model::schema_gen::write_schemas;
 
-fn main() -> Result<(), Error> {
-    write_schemas::<ShipmentFootprint>(
-        "ShipmentFootprint",
-        "shipment-footprint",
-        "pcf-shipment-footprint",
-    )?;
-    write_schemas::<Toc>("Toc", "toc", "pcf-toc")?;
-    write_schemas::<Tad>("Tad", "tad", "pcf-tad")?;
-    write_schemas::<Hoc>("Hoc", "hoc", "pcf-hoc")?;
-
-    Ok(())
+#[derive(Parser)]
+#[command(
+    version,
+    about = "Generate the JSON Schemas of the iLEAP data model and validate iLEAP footprints"
+)]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Writes the JSON Schemas of the iLEAP types and of footprints extended by them to a
+    /// directory
+    Schema {
+        #[arg(long)]
+        out: PathBuf,
+    },
+    /// Checks the footprints of a JSON file against the JSON Schema and the rules of the Tech
+    /// Spec, their iLEAP extensions against the JSON Schemas of the iLEAP data types, and the
+    /// TCEs of their shipments for a valid transport chain and emissions consistent with the TOCs
+    /// and HOCs of the file
+    Validate { file: PathBuf },
+}
+
+type Error = Box<dyn std::error::Error>;
+
+fn main() -> ExitCode {
+    match run(Cli::parse().command) {
+        Ok(exit_code) => exit_code,
+        Err(e) => {
+            eprintln!("error: {e}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn run(command: Command) -> Result<ExitCode, Error> {
+    match command {
+        Command::Schema { out } => {
+            fs::create_dir_all(&out)?;
+            write_schemas::<ShipmentFootprint>(
+                "ShipmentFootprint",
+                "shipment-footprint",
+                "pcf-shipment-footprint",
+                &out,
+            )?;
+            write_schemas::<Toc>("Toc", "toc", "pcf-toc", &out)?;
+            write_schemas::<Tad>("Tad", "tad", "pcf-tad", &out)?;
+            write_schemas::<Hoc>("Hoc", "hoc", "pcf-hoc", &out)?;
+            Ok(ExitCode::SUCCESS)
+        }
+        Command::Validate { file } => {
+            let (count, errors) = violations(&fs::read_to_string(file)?)?;
+
+            for error in &errors {
+                println!("{error}");
+            }
+            if errors.is_empty() {
+                println!("{count} footprint(s) valid");
+                Ok(ExitCode::SUCCESS)
+            } else {
+                println!("{} violation(s) found", errors.len());
+                Ok(ExitCode::FAILURE)
+            }
+        }
+    }
+}
+
+/// The number of footprints of `contents` and the violations found in them, referring to
+/// footprints by their index. TCEs referencing a TOC or HOC which is not in `contents` are only
+/// checked for their transport activity.
+fn violations(contents: &str) -> Result<(usize, Vec<String>), serde_json::Error> {
+    let footprints = json_footprints(contents)?;
+    let registry = ileap_extension_registry();
+
+    let mut errors = vec![];
+    let mut decoded = vec![];
+    for (i, footprint) in footprints.iter().enumerate() {
+        if let Err(violations) = validate_json(footprint) {
+            errors.extend(violations.iter().map(|v| format!("footprint {i}: {v}")));
+            continue;
+        }
+        let pf = match serde_json::from_value::<ProductFootprint<Value>>(footprint.clone()) {
+            Ok(pf) => pf,
+            Err(e) => {
+                errors.push(format!("footprint {i}: {e}"));
+                continue;
+            }
+        };
+        errors.extend(pf.validate().iter().map(|v| format!("footprint {i}: {v}")));
+        match registry.decode_footprint(pf) {
+            Ok(pf) => decoded.push((i, pf)),
+            Err(e) => errors.push(format!("footprint {i}: {e}")),
+        }
+    }
+
+    // the iLEAP data of the decoded footprints, with the index of their footprint
+    let ileap_data: Vec<(usize, &ILeapType)> = decoded
+        .iter()
+        .flat_map(|(i, pf)| {
+            let extensions = pf.extensions.iter().flatten();
+            extensions
+                .filter_map(|extension| extension.data.known())
+                .map(move |data| (*i, data))
+        })
+        .collect();
+    let (mut tocs, mut hocs) = (vec![], vec![]);
+    for (_, data) in &ileap_data {
+        match data {
+            ILeapType::Toc(toc) => tocs.push(toc.clone()),
+            ILeapType::Hoc(hoc) => hocs.push(hoc.clone()),
+            ILeapType::ShipmentFootprint(_) => {}
+        }
+    }
+
+    for (i, data) in &ileap_data {
+        let ILeapType::ShipmentFootprint(shipment) = data else {
+            continue;
+        };
+        let problems = check_tce_chain(shipment).problems;
+        errors.extend(problems.iter().map(|p| format!("footprint {i}: {p}")));
+        let discrepancies = check_tce_consistency(shipment, &tocs, &hocs, &Tolerance::default());
+        errors.extend(
+            discrepancies
+                .iter()
+                .filter(|d| matches!(d, Discrepancy::Mismatch { .. }))
+                .map(|d| format!("footprint {i}: {d}")),
+        );
+    }
+
+    Ok((footprints.len(), errors))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ileap_data_model::to_pcf;
+
+    /// A TOC and a shipment whose single TCE is consistent with it
+    fn footprints() -> Value {
+        let toc: Toc = serde_json::from_str(
+            r#"{"tocId":"truck-40t-euro5-de","isVerified":true,"isAccredited":true,"mode":"Road","energyCarriers":[{"energyCarrier":"Diesel","emissionFactorWTW":"3.6801","emissionFactorTTW":"3.2801","relativeShare":"1"}],"co2eIntensityWTW":"0.1","co2eIntensityTTW":"0.08","transportActivityUnit":"tkm"}"#,
+        )
+        .unwrap();
+        let shipment: ShipmentFootprint = serde_json::from_str(
+            r#"{"mass":"87","shipmentId":"1237890","tces":[{"tceId":"abcdef","prevTceIds":[],"tocId":"truck-40t-euro5-de","shipmentId":"1237890","mass":"87","distance":{"actual":"423"},"transportActivity":"36.801","co2eWTW":"3.6801","co2eTTW":"2.94408"}]}"#,
+        )
+        .unwrap();
+
+        let footprints = [ILeapType::Toc(toc), ILeapType::ShipmentFootprint(shipment)]
+            .into_iter()
+            .map(|data| {
+                to_pcf(
+                    data,
+                    "My Company",
+                    "urn:ileap:company:my-company",
+                    None,
+                    "2024-01-01T00:00:00Z".parse().unwrap(),
+                    "2025-01-01T00:00:00Z".parse().unwrap(),
+                )
+                .unwrap()
+            })
+            .collect::<Vec<_>>();
+        serde_json::to_value(footprints).unwrap()
+    }
+
+    #[test]
+    fn test_validate() {
+        let footprints = footprints();
+
+        let (checked, errors) = violations(&footprints.to_string()).unwrap();
+        assert_eq!((checked, errors), (2, vec![]));
+
+        let listing = serde_json::json!({ "data": footprints });
+        assert_eq!(violations(&listing.to_string()).unwrap(), (2, vec![]));
+
+        // a TCE referencing a TOC of another file is only checked for its transport activity
+        let shipment = footprints[1].to_string();
+        assert_eq!(violations(&shipment).unwrap(), (1, vec![]));
+
+        let mut invalid = footprints[1].clone();
+        invalid["extensions"][0]["data"]["mass"] = Value::Null;
+        let (checked, errors) = violations(&invalid.to_string()).unwrap();
+        assert_eq!(checked, 1);
+        assert!(!errors.is_empty());
+        assert!(errors
+            .iter()
+            .all(|e| e.starts_with("footprint 0: /extensions/0/data/mass")));
+    }
+
+    #[test]
+    fn test_validate_semantics() {
+        let mut footprints = footprints();
+        footprints[0]["pcf"]["referencePeriodEnd"] = "2023-01-01T00:00:00Z".into();
+        let tce = &mut footprints[1]["extensions"][0]["data"]["tces"][0];
+        tce["prevTceIds"] = serde_json::json!(["ghijkl"]);
+        tce["co2eWTW"] = "4".into();
+
+        let (checked, errors) = violations(&footprints.to_string()).unwrap();
+        assert_eq!(checked, 2);
+        assert_eq!(errors.len(), 3);
+        assert!(errors[0].starts_with("footprint 0: /pcf/referencePeriodEnd"));
+        assert_eq!(
+            errors[1],
+            "footprint 1: TCE abcdef: unknown prevTceId ghijkl"
+        );
+        assert_eq!(
+            errors[2],
+            "footprint 1: TCE abcdef: co2eWTW is 4, expected 3.6801"
+        );
+    }
 }
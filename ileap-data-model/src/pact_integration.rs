@@ -1,14 +1,14 @@
 use chrono::{DateTime, Utc};
 use pact_data_model::{
-    CarbonFootprint, CharacterizationFactors, CompanyIdSet, CrossSectoralStandardSet,
-    DataModelExtension, DeclaredUnit, DeprecatedCrossSectoralStandard, ExemptedEmissionsPercent,
-    IpccCharacterizationFactorsSource, PfId, PfStatus, PositiveDecimal, ProductFootprint,
-    ProductIdSet, SpecVersionString, Urn, VersionInteger,
+    CarbonFootprint, CharacterizationFactors, CodeAssigner, CompanyIdSet, ConstraintError,
+    CrossSectoralStandardSet, DataModelExtension, DeclaredUnit, DeprecatedCrossSectoralStandard,
+    ExemptedEmissionsPercent, IpccCharacterizationFactorsSource,
+    IpccCharacterizationFactorsSources, NonEmptyString, ParsedUrn, PositiveDecimal,
+    ProductFootprint, ProductIdSet, SpecVersionString, StrictlyPositiveDecimal, Urn,
 };
 use rust_decimal::Decimal;
 use schemars::JsonSchema;
 use serde::Serialize;
-use uuid::Uuid;
 
 use crate::{Hoc, HubActivityUnit, ShipmentFootprint, Toc};
 
@@ -105,6 +105,9 @@ impl From<&Toc> for PactMappedFields {
  * - company_name: the name of the company that is responsible for the product
  * - company_urn: the URN of the company that is responsible for the product
  * - characterization_factors: the optional IPCC characterization factors that were used in the calculation of the carbon footprint (TOC, HOC, ShipmentFootprint). If not defined `AR5` will be used.
+ *
+ * Fails if `company_name` is empty, `company_urn` is not a URN, the emissions of the iLEAP type
+ * are negative or its transport activity is not greater than 0.
  */
 pub fn to_pcf<T>(
     ileap_type: T,
@@ -114,7 +117,7 @@ pub fn to_pcf<T>(
     characterization_factors: Option<Vec<CharacterizationFactors>>,
     reference_period_start: DateTime<Utc>,
     reference_period_end: DateTime<Utc>,
-) -> ProductFootprint<T>
+) -> Result<ProductFootprint<T>, ConstraintError>
 where
     T: JsonSchema + Serialize,
     PactMappedFields: for<'a> From<&'a T>,
@@ -140,117 +143,92 @@ where
         p_cf_excluding_biogenic,
     } = (&ileap_type).into();
 
-    // Fasten your seatbelts, we are about to create a ProductFootprint...
-    ProductFootprint {
-        id: PfId(Uuid::new_v4()),
-        spec_version: SpecVersionString("2.2.0".to_string()),
-        preceding_pf_ids: None,
-        version: VersionInteger(1),
-        created: Utc::now(),
-        updated: None,
-        status: PfStatus::Active,
-        status_comment: None,
-        validity_period_start: None,
-        validity_period_end: None,
-        company_name: company_name.to_string().into(),
-        company_ids: CompanyIdSet(vec![Urn::from(company_urn.to_string())]),
-        product_description: "".to_string(),
-        product_ids: ProductIdSet(vec![Urn::from(format!(
-            "urn:pathfinder:product:customcode:vendor-assigned:{product_id_type}:{id}"
-        ))]),
-        product_category_cpc: String::from("83117").into(),
-        product_name_company: product_name_company.into(),
-        comment: "".to_string(),
-        pcf: CarbonFootprint {
-            declared_unit,
-            unitary_product_amount: unitary_product_amount.into(),
-            p_cf_excluding_biogenic: p_cf_excluding_biogenic.into(),
-            p_cf_including_biogenic: None,
-            fossil_ghg_emissions: p_cf_excluding_biogenic.into(),
-            fossil_carbon_content: PositiveDecimal::from(Decimal::from(0)),
-            biogenic_carbon_content: PositiveDecimal::from(Decimal::from(0)),
-            d_luc_ghg_emissions: None,
-            land_management_ghg_emissions: None,
-            other_biogenic_ghg_emissions: None,
-            i_luc_ghg_emissions: None,
-            biogenic_carbon_withdrawal: None,
-            aircraft_ghg_emissions: None,
-            characterization_factors,
-            ipcc_characterization_factors_sources: characterization_factors_sources.into(),
-            // TODO: Add cross_sectoral_standards with value vec![CrossSectoralStandardNew::Iso14083]
-            cross_sectoral_standards_used: CrossSectoralStandardSet(vec![
-                DeprecatedCrossSectoralStandard::Ghgp,
-            ]),
-            product_or_sector_specific_rules: None, // TODO: get clarity on whether GLEC should be specified
-            biogenic_accounting_methodology: None,
-            boundary_processes_description: "".to_string(),
-            reference_period_start,
-            reference_period_end,
-            geographic_scope: None,
-            secondary_emission_factor_sources: None,
-            exempted_emissions_percent: ExemptedEmissionsPercent(0.into()),
-            exempted_emissions_description: "".to_string(),
-            packaging_emissions_included: false,
-            packaging_ghg_emissions: None,
-            allocation_rules_description: None,
-            uncertainty_assessment_description: None,
-            primary_data_share: None,
-            dqi: None,
-            assurance: None,
-        },
-        extensions: Some(vec![DataModelExtension {
-            spec_version: SpecVersionString::from("0.2.0".to_string()),
-            data_schema: format!("https://api.ileap.sine.dev/{data_schema_id}.json"),
+    let unitary_product_amount = StrictlyPositiveDecimal::try_from(unitary_product_amount)?;
+    let p_cf_excluding_biogenic = PositiveDecimal::try_from(p_cf_excluding_biogenic)?;
+    let zero = PositiveDecimal::try_from(Decimal::ZERO).unwrap();
+
+    let pcf = CarbonFootprint::builder()
+        .declared_unit(declared_unit)
+        .unitary_product_amount(unitary_product_amount)
+        .p_cf_excluding_biogenic(p_cf_excluding_biogenic.clone())
+        .fossil_ghg_emissions(p_cf_excluding_biogenic)
+        .fossil_carbon_content(zero.clone())
+        .biogenic_carbon_content(zero)
+        .characterization_factors(characterization_factors)
+        .ipcc_characterization_factors_sources(characterization_factors_sources)
+        // TODO: Add cross_sectoral_standards with value vec![CrossSectoralStandardNew::Iso14083]
+        .cross_sectoral_standards_used(CrossSectoralStandardSet(vec![
+            DeprecatedCrossSectoralStandard::Ghgp,
+        ]))
+        // TODO: get clarity on whether GLEC should be specified in product_or_sector_specific_rules
+        .reference_period_start(reference_period_start)
+        .reference_period_end(reference_period_end)
+        .exempted_emissions_percent(ExemptedEmissionsPercent::try_from(0.0).unwrap())
+        .packaging_emissions_included(false)
+        .build()
+        .unwrap(); // all mandatory fields are set above
+
+    let pf = ProductFootprint::builder()
+        .created(Utc::now())
+        .company_name(NonEmptyString::try_from(company_name)?)
+        .company_ids(CompanyIdSet(vec![Urn::try_from(company_urn)?]))
+        .product_ids(ProductIdSet(vec![ParsedUrn::ProductCustomCode {
+            assigned_by: CodeAssigner::Vendor,
+            code: format!("{product_id_type}:{id}"),
+        }
+        .into()]))
+        .product_category_cpc(NonEmptyString::try_from("83117").unwrap())
+        .product_name_company(NonEmptyString::try_from(product_name_company).unwrap())
+        .pcf(pcf)
+        .extensions(vec![DataModelExtension {
+            spec_version: SpecVersionString::try_from("0.2.0").unwrap(),
+            data_schema: data_schema_url(data_schema_id),
             documentation: Some("https://sine-fdn.github.io/ileap-extension/".to_string()),
             data: ileap_type,
-        }]),
-    }
+        }])
+        .build()
+        .unwrap(); // all mandatory fields are set above
+
+    Ok(pf)
+}
+
+pub(crate) const ILEAP_DATA_SCHEMA_BASE_URL: &str = "https://api.ileap.sine.dev/";
+
+/// The `dataSchema` URL of the iLEAP data type with the given schema id, e.g. `toc`
+pub(crate) fn data_schema_url(data_schema_id: &str) -> String {
+    format!("{ILEAP_DATA_SCHEMA_BASE_URL}{data_schema_id}.json")
 }
 
 fn to_char_factors(
     characterization_factors: Option<Vec<CharacterizationFactors>>,
-) -> (
-    CharacterizationFactors,
-    Vec<IpccCharacterizationFactorsSource>,
-) {
-    let (characterization_factors, characterization_factors_sources) =
-        match characterization_factors {
-            None => (
-                CharacterizationFactors::Ar5,
-                vec![IpccCharacterizationFactorsSource::from("AR5".to_string())],
-            ),
-            Some(cf) => {
-                if cf.is_empty() {
-                    (
-                        CharacterizationFactors::Ar5,
-                        vec![IpccCharacterizationFactorsSource::from("AR5".to_string())],
-                    )
-                } else {
-                    let cf: Vec<IpccCharacterizationFactorsSource> = cf
-                        .iter()
-                        .map(|cf| match cf {
-                            CharacterizationFactors::Ar5 => {
-                                IpccCharacterizationFactorsSource::from("AR5".to_string())
-                            }
-                            CharacterizationFactors::Ar6 => {
-                                IpccCharacterizationFactorsSource::from("AR6".to_string())
-                            }
-                        })
-                        .collect();
-
-                    let characterization_factors = if cf
-                        .contains(&IpccCharacterizationFactorsSource::from("AR5".to_string()))
-                    {
-                        CharacterizationFactors::Ar5
-                    } else {
-                        CharacterizationFactors::Ar6
-                    };
-
-                    (characterization_factors, cf)
-                }
-            }
+) -> (CharacterizationFactors, IpccCharacterizationFactorsSources) {
+    let characterization_factors = match characterization_factors {
+        Some(cf) if !cf.is_empty() => cf,
+        _ => vec![CharacterizationFactors::Ar5],
+    };
+
+    let mut sources = vec![];
+    for cf in characterization_factors.iter() {
+        let source = IpccCharacterizationFactorsSource::from(*cf);
+        if !sources.contains(&source) {
+            sources.push(source);
+        }
+    }
+
+    // AR5 takes precedence; footprints can be restated with AR6 through
+    // `CarbonFootprint::restate` if a per-gas inventory is available
+    let characterization_factors =
+        if characterization_factors.contains(&CharacterizationFactors::Ar5) {
+            CharacterizationFactors::Ar5
+        } else {
+            CharacterizationFactors::Ar6
         };
-    (characterization_factors, characterization_factors_sources)
+
+    (
+        characterization_factors,
+        IpccCharacterizationFactorsSources::try_from(sources)
+            .expect("sources are non-empty and free of duplicates"),
+    )
 }
 
 #[test]
@@ -371,7 +349,8 @@ fn ship_foot_to_pfc() {
         None,
         Utc::now(),
         Utc::now() + chrono::Duration::days(1),
-    );
+    )
+    .unwrap();
 
     assert_eq!(
         pfc.product_name_company.0,
@@ -421,18 +400,32 @@ fn toc_to_pcf() {
     };
 
     let pfc = to_pcf(
-        toc,
+        toc.clone(),
         "test",
         "urn:test",
         None,
         Utc::now(),
         Utc::now() + chrono::Duration::days(1),
-    );
+    )
+    .unwrap();
 
     assert_eq!(pfc.product_name_company.0, "TOC with ID toc-test");
     assert_eq!(pfc.pcf.declared_unit, DeclaredUnit::TonKilometer);
     assert_eq!(pfc.pcf.unitary_product_amount.0, dec!(1));
     assert_eq!(pfc.pcf.p_cf_excluding_biogenic.0, dec!(0.007));
+
+    let period = (Utc::now(), Utc::now() + chrono::Duration::days(1));
+    let company_name = to_pcf(toc.clone(), "", "urn:test", None, period.0, period.1);
+    assert_eq!(company_name.unwrap_err().data_type, "NonEmptyString");
+    let company_urn = to_pcf(toc.clone(), "test", "test", None, period.0, period.1);
+    assert_eq!(company_urn.unwrap_err().data_type, "Urn");
+
+    let negative = Toc {
+        co2e_intensity_wtw: dec!(-0.007).into(),
+        ..toc
+    };
+    let emissions = to_pcf(negative, "test", "urn:test", None, period.0, period.1);
+    assert_eq!(emissions.unwrap_err().data_type, "PositiveDecimal");
 }
 
 #[test]
@@ -486,7 +479,8 @@ fn hoc_to_pfc() {
         None,
         Utc::now(),
         Utc::now() + chrono::Duration::days(1),
-    );
+    )
+    .unwrap();
 
     assert_eq!(pfc.product_name_company.0, "HOC with ID hoc-test");
     assert_eq!(pfc.pcf.declared_unit, DeclaredUnit::Kilogram);
@@ -5,26 +5,31 @@
  * This software is released under the MIT License, see LICENSE.
  */
 
-use pact_data_model::ProductFootprint;
+use crate::pact_integration::ILEAP_DATA_SCHEMA_BASE_URL;
+use crate::{Hoc, ShipmentFootprint, Tad, Toc};
+use pact_data_model::{validate_json_with_schema, ProductFootprint, SchemaViolation};
 use schemars::gen::{SchemaGenerator, SchemaSettings};
 use schemars::schema::{ObjectValidation, RootSchema, Schema, SchemaObject};
 use schemars::{schema_for, Map, Set};
-use serde_json::to_string_pretty;
+use serde_json::{to_string_pretty, Value};
 use std::fs::File;
 use std::io::{Error, Write};
+use std::path::Path;
 
+/// Writes the JSON Schema of `T` and of a `ProductFootprint` with a `T` extension to `out_dir`
 pub fn write_schemas<T: schemars::JsonSchema>(
     type_name: &str,
     schema_file_name: &str,
     pcf_schema_file_name: &str,
+    out_dir: &Path,
 ) -> Result<(), Error> {
     let schema = schema_for!(T);
 
-    write_schema_file(schema, schema_file_name)?;
+    write_schema_file(schema, schema_file_name, out_dir)?;
 
     let pcf_schema = gen_pcf_with_extension::<T>(type_name);
 
-    write_schema_file(pcf_schema, pcf_schema_file_name)?;
+    write_schema_file(pcf_schema, pcf_schema_file_name, out_dir)?;
 
     Ok(())
 }
@@ -101,11 +106,59 @@ pub fn gen_pcf_with_extension<T: schemars::JsonSchema>(type_name: &str) -> RootS
     pcf_schema
 }
 
-fn write_schema_file(schema: RootSchema, schema_name: &str) -> Result<(), Error> {
+/// Validates `value` against the JSON Schema of the PACT `ProductFootprint` and the data of each
+/// of its iLEAP extensions against the JSON Schema of the iLEAP data type given by its
+/// `dataSchema`. Extensions with other data schemas are not validated.
+pub fn validate_json(value: &Value) -> Result<(), Vec<SchemaViolation>> {
+    let mut violations = pact_data_model::validate_json(value)
+        .err()
+        .unwrap_or_default();
+
+    let extensions = value.get("extensions").and_then(Value::as_array);
+    for (i, extension) in extensions.into_iter().flatten().enumerate() {
+        let schema = extension
+            .get("dataSchema")
+            .and_then(Value::as_str)
+            .and_then(extension_data_schema);
+        let (Some(schema), Some(data)) = (schema, extension.get("data")) else {
+            continue;
+        };
+
+        if let Err(data_violations) = validate_json_with_schema(&schema, data) {
+            violations.extend(data_violations.into_iter().map(|v| SchemaViolation {
+                instance_path: format!("/extensions/{i}/data{}", v.instance_path),
+                ..v
+            }));
+        }
+    }
+
+    if violations.is_empty() {
+        Ok(())
+    } else {
+        Err(violations)
+    }
+}
+
+/// The JSON Schema of the iLEAP data type with the given `dataSchema` URL
+fn extension_data_schema(data_schema: &str) -> Option<RootSchema> {
+    let data_schema_id = data_schema
+        .strip_prefix(ILEAP_DATA_SCHEMA_BASE_URL)?
+        .strip_suffix(".json")?;
+
+    match data_schema_id {
+        "shipment-footprint" => Some(schema_for!(ShipmentFootprint)),
+        "toc" => Some(schema_for!(Toc)),
+        "hoc" => Some(schema_for!(Hoc)),
+        "tad" => Some(schema_for!(Tad)),
+        _ => None,
+    }
+}
+
+fn write_schema_file(schema: RootSchema, schema_name: &str, out_dir: &Path) -> Result<(), Error> {
     let schema_json = to_string_pretty(&schema)
         .unwrap_or_else(|_| panic!("Failed to serialize schema: {schema:?}"));
 
-    let mut schema_file = File::create(format!("./ileap-data-model/schemas/{schema_name}.json"))?;
+    let mut schema_file = File::create(out_dir.join(format!("{schema_name}.json")))?;
 
     schema_file.write_all(schema_json.as_bytes())?;
 
@@ -116,10 +169,6 @@ fn write_schema_file(schema: RootSchema, schema_name: &str) -> Result<(), Error>
 
 #[test]
 fn compare_schemas() {
-    use crate::{Hoc, ShipmentFootprint, Tad, Toc};
-    use schemars::schema_for;
-    use serde_json::to_string_pretty;
-    use serde_json::Value;
     use std::{fs::File, io::Read};
 
     fn read_schema(schema_name: &str) -> String {
@@ -0,0 +1,152 @@
+/*
+ * Copyright (c) 2024 SINE Foundation e.V.
+ *
+ * This software is released under the MIT License, see LICENSE.
+ */
+
+//! Calculation of the TCEs of the consignments transported by a TAD, given the TOC the TAD
+//! belongs to.
+//!
+//! The transport activity of each TCE is the mass of its consignment in tonnes times the distance
+//! of the TAD, and its emissions are the intensities of the TOC times that transport activity. The
+//! `mass` of the TAD, which may include the mass of consignments of other shippers, does not
+//! affect the TCEs. If the TOC is in `TEUkm`, the emissions of the TAD's TEU-km (see
+//! `calculate_toc`) are allocated to its consignments by mass instead, as consignments do not
+//! state the number of TEUs they take up. Packaging is allocated to the consignments by mass.
+
+use pact_data_model::PositiveDecimal;
+use rust_decimal::Decimal;
+
+use crate::toc_calculation::activity_amount;
+use crate::{
+    ConsignementId, ShipmentId, Tad, Tce, Toc, TocCalculationError, TransportActivityUnit,
+};
+
+#[derive(Debug, Clone, PartialEq)]
+/// A consignment transported by a TAD, as known to the shipper
+pub struct Consignment {
+    pub consignment_id: ConsignementId,
+    pub shipment_id: ShipmentId,
+    /// The mass in kg
+    pub mass: Decimal,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+/// Error returned when the TCEs of a TAD cannot be calculated
+pub enum TceCalculationError {
+    /// The transport mode of the TAD differs from the one of the TOC
+    ModeMismatch,
+    /// The TAD lists no consignments
+    NoConsignments,
+    /// The consignment with the given id is not among the given consignments
+    UnknownConsignment(ConsignementId),
+    /// The total mass of the consignments is zero
+    ZeroMass,
+    /// The transport activity of the TAD cannot be determined
+    TransportActivity(TocCalculationError),
+}
+
+impl std::fmt::Display for TceCalculationError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            TceCalculationError::ModeMismatch => {
+                write!(f, "transport modes of TAD and TOC differ")
+            }
+            TceCalculationError::NoConsignments => write!(f, "TAD lists no consignments"),
+            TceCalculationError::UnknownConsignment(consignment_id) => {
+                write!(f, "unknown consignment {consignment_id}")
+            }
+            TceCalculationError::ZeroMass => write!(f, "total mass of consignments is zero"),
+            TceCalculationError::TransportActivity(e) => e.fmt(f),
+        }
+    }
+}
+
+impl std::error::Error for TceCalculationError {}
+
+impl From<TocCalculationError> for TceCalculationError {
+    fn from(e: TocCalculationError) -> Self {
+        TceCalculationError::TransportActivity(e)
+    }
+}
+
+/// Calculates a TCE for each consignment of `tad`, in the order of its `consignmentIds`, whose
+/// masses and shipments are looked up in `consignments`.
+///
+/// The TCEs' transport activities are in tkm regardless of the unit of the TOC. Their ids are the
+/// TAD's `activityId` and the `consignmentId`, joined by `-`.
+pub fn calculate_tces(
+    tad: &Tad,
+    toc: &Toc,
+    consignments: &[Consignment],
+) -> Result<Vec<Tce>, TceCalculationError> {
+    if tad.mode != toc.mode {
+        return Err(TceCalculationError::ModeMismatch);
+    }
+    if tad.consignment_ids.is_empty() {
+        return Err(TceCalculationError::NoConsignments);
+    }
+
+    let consignments = tad
+        .consignment_ids
+        .iter()
+        .map(|id| {
+            consignments
+                .iter()
+                .find(|c| &c.consignment_id == id)
+                .ok_or_else(|| TceCalculationError::UnknownConsignment(id.clone()))
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+    let total_mass: Decimal = consignments.iter().map(|c| c.mass).sum();
+    if total_mass.is_zero() {
+        return Err(TceCalculationError::ZeroMass);
+    }
+
+    let distance = tad.distance.get_distance();
+    // the TEU-km of the whole TAD, to be allocated by mass
+    let teu_km = match toc.transport_activity_unit {
+        TransportActivityUnit::Tkm => None,
+        TransportActivityUnit::TEUkm => {
+            Some(distance * activity_amount(tad, &toc.transport_activity_unit)?)
+        }
+    };
+
+    let tces = consignments
+        .into_iter()
+        .map(|consignment| {
+            let share = consignment.mass / total_mass;
+            let tkm = consignment.mass / Decimal::ONE_THOUSAND * distance;
+            let activity = teu_km.map_or(tkm, |teu_km| teu_km * share);
+            Tce {
+                tce_id: format!("{}-{}", tad.activity_id, consignment.consignment_id),
+                prev_tce_ids: None,
+                toc_id: Some(toc.toc_id.clone()),
+                hoc_id: None,
+                shipment_id: consignment.shipment_id.clone(),
+                consignment_id: Some(consignment.consignment_id.clone()),
+                mass: consignment.mass.into(),
+                packaging_or_tr_eq_type: tad.packaging_or_tr_eq_type.clone(),
+                packaging_or_tr_eq_amount: tad
+                    .packaging_or_tr_eq_amount
+                    .map(|amount| PositiveDecimal((Decimal::from(amount) * share).normalize())),
+                distance: tad.distance.clone(),
+                origin: Some(tad.origin.clone()),
+                destination: Some(tad.destination.clone()),
+                transport_activity: tkm.normalize().into(),
+                departure_at: Some(tad.departure_at),
+                arrival_at: Some(tad.arrival_at),
+                flight_no: None,
+                voyage_no: None,
+                incoterms: None,
+                co2e_wtw: (toc.co2e_intensity_wtw.0 * activity).normalize().into(),
+                co2e_ttw: (toc.co2e_intensity_ttw.0 * activity).normalize().into(),
+                nox_ttw: None,
+                sox_ttw: None,
+                ch4_ttw: None,
+                pm_ttw: None,
+            }
+        })
+        .collect();
+
+    Ok(tces)
+}
@@ -0,0 +1,244 @@
+/*
+ * Copyright (c) 2024 SINE Foundation e.V.
+ *
+ * This software is released under the MIT License, see LICENSE.
+ */
+
+//! Validation of the transport chain of a `ShipmentFootprint`, i.e. the graph of its TCEs whose
+//! edges are given by `prevTceIds`.
+//!
+//! `prevTceIds` may list all preceding TCEs of a TCE rather than only its immediate predecessors,
+//! as `gen_rnd_demo_data` does. Hence, two TCEs are considered consecutive only if no other
+//! preceding TCE lies in between.
+
+use serde::Serialize;
+
+use crate::{ShipmentFootprint, Tce};
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(
+    tag = "kind",
+    rename_all = "camelCase",
+    rename_all_fields = "camelCase"
+)]
+/// A problem of the transport chain found by `check_tce_chain`
+pub enum ChainProblem {
+    /// More than one TCE has the same `tceId`
+    DuplicateTceId { tce_id: String },
+    /// An element of `prevTceIds` is not the `tceId` of a TCE of the shipment
+    DanglingPrevTceId { tce_id: String, prev_tce_id: String },
+    /// The TCEs precede each other in a cycle, each TCE preceding the next and the last one
+    /// preceding the first one
+    Cycle { tce_ids: Vec<String> },
+    /// The `shipmentId` of the TCE differs from the one of the shipment
+    ShipmentIdMismatch { tce_id: String, shipment_id: String },
+    /// The TCE has both a `tocId` and a `hocId`
+    TocAndHoc { tce_id: String },
+    /// The TCE has neither a `tocId` nor a `hocId`
+    NeitherTocNorHoc { tce_id: String },
+    /// The chain starts at a hub, i.e. a TCE with a `hocId` has no predecessors
+    StartsAtHub { tce_id: String },
+    /// The chain ends at a hub, i.e. a TCE with a `hocId` has no successors
+    EndsAtHub { tce_id: String },
+    /// A TCE with a `hocId` immediately follows another one
+    ConsecutiveHubs { prev_tce_id: String, tce_id: String },
+}
+
+impl std::fmt::Display for ChainProblem {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ChainProblem::DuplicateTceId { tce_id } => write!(f, "TCE {tce_id}: duplicate tceId"),
+            ChainProblem::DanglingPrevTceId {
+                tce_id,
+                prev_tce_id,
+            } => write!(f, "TCE {tce_id}: unknown prevTceId {prev_tce_id}"),
+            ChainProblem::Cycle { tce_ids } => write!(f, "cycle of TCEs {}", tce_ids.join(", ")),
+            ChainProblem::ShipmentIdMismatch {
+                tce_id,
+                shipment_id,
+            } => write!(
+                f,
+                "TCE {tce_id}: shipmentId {shipment_id} differs from the shipment's"
+            ),
+            ChainProblem::TocAndHoc { tce_id } => {
+                write!(f, "TCE {tce_id}: both tocId and hocId are defined")
+            }
+            ChainProblem::NeitherTocNorHoc { tce_id } => {
+                write!(f, "TCE {tce_id}: neither tocId nor hocId is defined")
+            }
+            ChainProblem::StartsAtHub { tce_id } => {
+                write!(f, "TCE {tce_id}: the chain starts at a hub")
+            }
+            ChainProblem::EndsAtHub { tce_id } => {
+                write!(f, "TCE {tce_id}: the chain ends at a hub")
+            }
+            ChainProblem::ConsecutiveHubs {
+                prev_tce_id,
+                tce_id,
+            } => write!(f, "TCE {tce_id}: hub immediately follows hub {prev_tce_id}"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+/// The result of `check_tce_chain`
+pub struct TceChain<'a> {
+    /// The TCEs in topological order, i.e. every TCE after its predecessors, keeping the order of
+    /// `tces` where possible. `None` if the TCEs precede each other in a cycle.
+    pub order: Option<Vec<&'a Tce>>,
+    /// All problems found; empty if the chain is valid
+    pub problems: Vec<ChainProblem>,
+}
+
+impl TceChain<'_> {
+    pub fn is_valid(&self) -> bool {
+        self.problems.is_empty()
+    }
+}
+
+/// Builds the graph of the TCEs of `shipment` and checks it for the problems of `ChainProblem`.
+/// Consecutive hubs are only checked for if the graph is acyclic.
+pub fn check_tce_chain(shipment: &ShipmentFootprint) -> TceChain<'_> {
+    let tces = &shipment.tces.0;
+    let mut problems = vec![];
+
+    for (i, tce) in tces.iter().enumerate() {
+        // reported once, at the second occurrence
+        if tces[..i]
+            .iter()
+            .filter(|other| other.tce_id == tce.tce_id)
+            .count()
+            == 1
+        {
+            problems.push(ChainProblem::DuplicateTceId {
+                tce_id: tce.tce_id.clone(),
+            });
+        }
+        if tce.shipment_id != shipment.shipment_id {
+            problems.push(ChainProblem::ShipmentIdMismatch {
+                tce_id: tce.tce_id.clone(),
+                shipment_id: tce.shipment_id.clone(),
+            });
+        }
+        match (&tce.toc_id, &tce.hoc_id) {
+            (Some(_), Some(_)) => problems.push(ChainProblem::TocAndHoc {
+                tce_id: tce.tce_id.clone(),
+            }),
+            (None, None) => problems.push(ChainProblem::NeitherTocNorHoc {
+                tce_id: tce.tce_id.clone(),
+            }),
+            _ => {}
+        }
+    }
+
+    // predecessors[i] are the indices of the TCEs preceding tces[i]
+    let mut predecessors = vec![vec![]; tces.len()];
+    for (i, tce) in tces.iter().enumerate() {
+        for prev_tce_id in tce.prev_tce_ids.iter().flatten() {
+            match tces.iter().position(|other| &other.tce_id == prev_tce_id) {
+                Some(j) if !predecessors[i].contains(&j) => predecessors[i].push(j),
+                Some(_) => {}
+                None => problems.push(ChainProblem::DanglingPrevTceId {
+                    tce_id: tce.tce_id.clone(),
+                    prev_tce_id: prev_tce_id.clone(),
+                }),
+            }
+        }
+    }
+
+    let is_hub = |i: usize| tces[i].hoc_id.is_some();
+    for i in (0..tces.len()).filter(|&i| is_hub(i)) {
+        if predecessors[i].is_empty() {
+            problems.push(ChainProblem::StartsAtHub {
+                tce_id: tces[i].tce_id.clone(),
+            });
+        }
+        if !predecessors.iter().any(|prevs| prevs.contains(&i)) {
+            problems.push(ChainProblem::EndsAtHub {
+                tce_id: tces[i].tce_id.clone(),
+            });
+        }
+    }
+
+    let order = match topological_order(&predecessors) {
+        Ok(order) => order,
+        Err(cycle) => {
+            problems.push(ChainProblem::Cycle {
+                tce_ids: cycle.into_iter().map(|i| tces[i].tce_id.clone()).collect(),
+            });
+            return TceChain {
+                order: None,
+                problems,
+            };
+        }
+    };
+
+    // ancestors[i] are the indices of the TCEs preceding tces[i], directly or transitively
+    let mut ancestors = vec![vec![]; tces.len()];
+    for &i in order.iter() {
+        for &j in predecessors[i].iter() {
+            for k in std::iter::once(j).chain(ancestors[j].clone()) {
+                if !ancestors[i].contains(&k) {
+                    ancestors[i].push(k);
+                }
+            }
+        }
+    }
+
+    for i in (0..tces.len()).filter(|&i| is_hub(i)) {
+        let immediate = predecessors[i].iter().filter(|&&j| {
+            !predecessors[i]
+                .iter()
+                .any(|&k| k != j && ancestors[k].contains(&j))
+        });
+        for &j in immediate.filter(|&&j| is_hub(j)) {
+            problems.push(ChainProblem::ConsecutiveHubs {
+                prev_tce_id: tces[j].tce_id.clone(),
+                tce_id: tces[i].tce_id.clone(),
+            });
+        }
+    }
+
+    TceChain {
+        order: Some(order.into_iter().map(|i| &tces[i]).collect()),
+        problems,
+    }
+}
+
+/// The indices of the nodes in topological order, each node after its predecessors and otherwise
+/// in order of their indices, or the indices of a cycle if there is one
+fn topological_order(predecessors: &[Vec<usize>]) -> Result<Vec<usize>, Vec<usize>> {
+    let mut order = vec![];
+    let mut placed = vec![false; predecessors.len()];
+
+    while order.len() < predecessors.len() {
+        let next = (0..predecessors.len())
+            .find(|&i| !placed[i] && predecessors[i].iter().all(|&j| placed[j]));
+        match next {
+            Some(i) => {
+                placed[i] = true;
+                order.push(i);
+            }
+            None => {
+                // every unplaced node has an unplaced predecessor, so following those from any
+                // unplaced node eventually revisits a node, closing a cycle
+                let mut path = vec![(0..predecessors.len()).find(|&i| !placed[i]).unwrap()];
+                loop {
+                    let last = *path.last().unwrap();
+                    let prev = *predecessors[last].iter().find(|&&j| !placed[j]).unwrap();
+                    if let Some(start) = path.iter().position(|&i| i == prev) {
+                        let mut cycle = path.split_off(start);
+                        cycle.reverse();
+                        // starting at the node listed first
+                        let first = (0..cycle.len()).min_by_key(|&i| cycle[i]).unwrap();
+                        cycle.rotate_left(first);
+                        return Err(cycle);
+                    }
+                    path.push(prev);
+                }
+            }
+        }
+    }
+
+    Ok(order)
+}
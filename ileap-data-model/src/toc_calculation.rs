@@ -0,0 +1,384 @@
+/*
+ * Copyright (c) 2024 SINE Foundation e.V.
+ *
+ * This software is released under the MIT License, see LICENSE.
+ */
+
+//! Calculation of the emission intensities of a transport operation category (TOC) from the
+//! transport activity data (TADs) of its operations, following ISO 14083 and the GLEC Framework.
+//!
+//! The emissions of a TAD are the sum over its energy carriers of the energy consumed times the
+//! emission factor of the carrier, which is hence expected per `energyConsumptionUnit`. The
+//! transport activity of a TAD is its distance in km times either its mass in tonnes (the TAD's
+//! `mass` being in kg) or the number of TEUs it carries. The intensities of the TOC are the total
+//! emissions of its TADs divided by their total transport activity.
+//!
+//! Energy consumptions in different units are converted to MJ with the default properties of
+//! `EnergyConversion`. To use other properties, normalize the energy carriers of the TADs to a
+//! common unit beforehand.
+
+use rust_decimal::Decimal;
+use serde::Serialize;
+
+use crate::{
+    EnergyCarrier, EnergyCarrierType, EnergyConsumptionUnit, EnergyConversion, Feedstock,
+    NonEmptyVec, PackagingOrTrEqType, Tad, TadTempControl, TemperatureControl, Toc,
+    TransportActivityUnit,
+};
+
+#[derive(Debug, Clone, PartialEq)]
+/// Error returned when a TOC cannot be calculated from a set of TADs
+pub enum TocCalculationError {
+    /// No TADs were given
+    NoTads,
+    /// The TADs are not all of the same transport mode
+    MixedModes,
+    /// The TAD has no mass, which is required for an activity in tkm
+    MissingMass(String),
+    /// The TAD does not carry TEU or FEU containers, which is required for an activity in TEUkm
+    MissingTeuCount(String),
+    /// The TAD has no energy carriers, or one without an energy consumption
+    MissingEnergyConsumption(String),
+    /// The energy consumptions of the TADs are in different units, not all of which can be
+    /// converted to MJ
+    MixedEnergyUnits,
+    /// The total transport activity of the TADs is zero
+    ZeroTransportActivity,
+}
+
+impl std::fmt::Display for TocCalculationError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            TocCalculationError::NoTads => write!(f, "no TADs given"),
+            TocCalculationError::MixedModes => write!(f, "TADs of different transport modes"),
+            TocCalculationError::MissingMass(activity_id) => {
+                write!(f, "TAD {activity_id}: mass is required for tkm")
+            }
+            TocCalculationError::MissingTeuCount(activity_id) => write!(
+                f,
+                "TAD {activity_id}: TEU or FEU containers are required for TEUkm"
+            ),
+            TocCalculationError::MissingEnergyConsumption(activity_id) => {
+                write!(f, "TAD {activity_id}: energy consumption is required")
+            }
+            TocCalculationError::MixedEnergyUnits => {
+                write!(f, "energy consumptions in inconvertible units")
+            }
+            TocCalculationError::ZeroTransportActivity => {
+                write!(f, "total transport activity is zero")
+            }
+        }
+    }
+}
+
+impl std::error::Error for TocCalculationError {}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+/// The inputs taken from a single TAD
+pub struct ActivityInput {
+    pub activity_id: String,
+    /// The distance in km
+    pub distance: Decimal,
+    /// The transport activity in the unit of the TOC
+    pub transport_activity: Decimal,
+    #[serde(rename = "co2eWTW")]
+    pub co2e_wtw: Decimal,
+    #[serde(rename = "co2eTTW")]
+    pub co2e_ttw: Decimal,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+/// The total energy consumed of an energy carrier and the resulting emissions
+pub struct EnergyUse {
+    pub energy_carrier: EnergyCarrierType,
+    pub energy_consumption: Decimal,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub energy_consumption_unit: Option<EnergyConsumptionUnit>,
+    #[serde(rename = "co2eWTW")]
+    pub co2e_wtw: Decimal,
+    #[serde(rename = "co2eTTW")]
+    pub co2e_ttw: Decimal,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+/// A TOC calculated by `calculate_toc`, together with the inputs it is based on
+pub struct TocCalculation {
+    pub toc: Toc,
+    /// The inputs taken from each TAD, in the order of the TADs
+    pub activities: Vec<ActivityInput>,
+    /// The energy used per energy carrier and unit, in order of first use
+    pub energy_use: Vec<EnergyUse>,
+    /// The total transport activity in `toc.transport_activity_unit`
+    pub transport_activity: Decimal,
+    pub co2e_wtw: Decimal,
+    pub co2e_ttw: Decimal,
+}
+
+/// Calculates a TOC with the given `toc_id` from the TADs of its operations, see the module
+/// documentation.
+///
+/// The load factor and empty distance factor of the TOC are the averages of those of the TADs,
+/// weighted by transport activity and distance, respectively, if all TADs state them. The
+/// temperature control of the TOC is `mixed` if the TADs differ in theirs.
+pub fn calculate_toc(
+    toc_id: impl Into<String>,
+    tads: &[Tad],
+    transport_activity_unit: TransportActivityUnit,
+) -> Result<TocCalculation, TocCalculationError> {
+    let mode = tads
+        .first()
+        .ok_or(TocCalculationError::NoTads)?
+        .mode
+        .clone();
+    if tads.iter().any(|tad| tad.mode != mode) {
+        return Err(TocCalculationError::MixedModes);
+    }
+
+    let mut activities = vec![];
+    let mut energy_use: Vec<FeedstockEnergyUse> = vec![];
+    for tad in tads {
+        let distance = tad.distance.get_distance();
+        let transport_activity = distance * activity_amount(tad, &transport_activity_unit)?;
+
+        let (mut co2e_wtw, mut co2e_ttw) = (Decimal::ZERO, Decimal::ZERO);
+        let carriers = match &tad.energy_carriers {
+            Some(carriers) if !carriers.0.is_empty() => &carriers.0,
+            _ => {
+                return Err(TocCalculationError::MissingEnergyConsumption(
+                    tad.activity_id.clone(),
+                ))
+            }
+        };
+        for carrier in carriers {
+            let consumption = carrier.energy_consumption.as_ref().ok_or_else(|| {
+                TocCalculationError::MissingEnergyConsumption(tad.activity_id.clone())
+            })?;
+            let wtw = consumption.0 * carrier.emission_factor_wtw.0;
+            let ttw = consumption.0 * carrier.emission_factor_ttw.0;
+            co2e_wtw += wtw;
+            co2e_ttw += ttw;
+
+            let used = energy_use.iter_mut().find(|(used, _)| {
+                used.energy_carrier == carrier.energy_carrier
+                    && used.energy_consumption_unit == carrier.energy_consumption_unit
+            });
+            match used {
+                Some((used, feedstocks)) => {
+                    used.energy_consumption += consumption.0;
+                    used.co2e_wtw += wtw;
+                    used.co2e_ttw += ttw;
+                    if *feedstocks != carrier.feedstocks {
+                        *feedstocks = None;
+                    }
+                }
+                None => energy_use.push((
+                    EnergyUse {
+                        energy_carrier: carrier.energy_carrier.clone(),
+                        energy_consumption: consumption.0,
+                        energy_consumption_unit: carrier.energy_consumption_unit.clone(),
+                        co2e_wtw: wtw,
+                        co2e_ttw: ttw,
+                    },
+                    carrier.feedstocks.clone(),
+                )),
+            }
+        }
+
+        activities.push(ActivityInput {
+            activity_id: tad.activity_id.clone(),
+            distance,
+            transport_activity,
+            co2e_wtw,
+            co2e_ttw,
+        });
+    }
+
+    let unit = &energy_use[0].0.energy_consumption_unit;
+    if energy_use
+        .iter()
+        .any(|(used, _)| &used.energy_consumption_unit != unit)
+    {
+        energy_use = in_megajoules(energy_use)?;
+    }
+
+    let transport_activity: Decimal = activities.iter().map(|a| a.transport_activity).sum();
+    if transport_activity.is_zero() {
+        return Err(TocCalculationError::ZeroTransportActivity);
+    }
+    let co2e_wtw: Decimal = activities.iter().map(|a| a.co2e_wtw).sum();
+    let co2e_ttw: Decimal = activities.iter().map(|a| a.co2e_ttw).sum();
+    let energy_consumption: Decimal = energy_use.iter().map(|(u, _)| u.energy_consumption).sum();
+
+    let energy_carriers = energy_use
+        .iter()
+        .map(|(used, feedstocks)| {
+            // the emission factors of a carrier are averaged over its TADs, weighted by energy
+            let factor = |co2e: Decimal| {
+                if used.energy_consumption.is_zero() {
+                    Decimal::ZERO
+                } else {
+                    co2e / used.energy_consumption
+                }
+            };
+            let relative_share = if energy_consumption.is_zero() {
+                Decimal::ONE / Decimal::from(energy_use.len())
+            } else {
+                used.energy_consumption / energy_consumption
+            };
+            EnergyCarrier {
+                energy_carrier: used.energy_carrier.clone(),
+                feedstocks: feedstocks.clone(),
+                energy_consumption: Some(used.energy_consumption.into()),
+                energy_consumption_unit: used.energy_consumption_unit.clone(),
+                emission_factor_wtw: factor(used.co2e_wtw).normalize().into(),
+                emission_factor_ttw: factor(used.co2e_ttw).normalize().into(),
+                relative_share: relative_share.normalize().into(),
+            }
+        })
+        .collect::<Vec<_>>();
+
+    let load_factor = weighted_average(tads.iter().zip(&activities).map(|(tad, activity)| {
+        let load_factor = tad.load_factor.as_ref()?;
+        Some((load_factor.0, activity.transport_activity))
+    }));
+    let empty_distance_factor =
+        weighted_average(tads.iter().zip(&activities).map(|(tad, activity)| {
+            let empty_distance_factor = tad.empty_distance_factor.as_ref()?;
+            Some((empty_distance_factor.0, activity.distance))
+        }));
+
+    let temperature_control = tads
+        .iter()
+        .map(|tad| tad.temperature_control.clone())
+        .collect::<Option<Vec<_>>>()
+        .map(|controls| {
+            if controls.iter().all(|c| *c == controls[0]) {
+                controls[0].clone().into()
+            } else {
+                TemperatureControl::Mixed
+            }
+        });
+
+    let toc = Toc {
+        toc_id: toc_id.into(),
+        certifications: None,
+        description: None,
+        mode,
+        load_factor: load_factor.map(|f| f.normalize().to_string()),
+        empty_distance_factor: empty_distance_factor.map(|f| f.normalize().to_string()),
+        temperature_control,
+        truck_loading_sequence: None,
+        air_shipping_option: None,
+        flight_length: None,
+        energy_carriers: NonEmptyVec::from(energy_carriers),
+        co2e_intensity_wtw: (co2e_wtw / transport_activity).normalize().into(),
+        co2e_intensity_ttw: (co2e_ttw / transport_activity).normalize().into(),
+        transport_activity_unit,
+    };
+
+    Ok(TocCalculation {
+        toc,
+        activities,
+        energy_use: energy_use.into_iter().map(|(used, _)| used).collect(),
+        transport_activity,
+        co2e_wtw,
+        co2e_ttw,
+    })
+}
+
+/// An energy use together with the feedstocks of its energy carrier, if the same for all TADs
+type FeedstockEnergyUse = (EnergyUse, Option<Vec<Feedstock>>);
+
+/// `energy_use` in MJ, merging the uses of an energy carrier in different units
+fn in_megajoules(
+    energy_use: Vec<FeedstockEnergyUse>,
+) -> Result<Vec<FeedstockEnergyUse>, TocCalculationError> {
+    let conversion = EnergyConversion::default();
+    let mut converted: Vec<FeedstockEnergyUse> = vec![];
+
+    for (mut used, feedstocks) in energy_use {
+        let unit = used
+            .energy_consumption_unit
+            .as_ref()
+            .ok_or(TocCalculationError::MixedEnergyUnits)?;
+        used.energy_consumption = conversion
+            .convert(
+                &used.energy_carrier,
+                used.energy_consumption,
+                unit,
+                &EnergyConsumptionUnit::MJ,
+            )
+            .map_err(|_| TocCalculationError::MixedEnergyUnits)?
+            .normalize();
+        used.energy_consumption_unit = Some(EnergyConsumptionUnit::MJ);
+
+        match converted
+            .iter_mut()
+            .find(|(other, _)| other.energy_carrier == used.energy_carrier)
+        {
+            Some((other, other_feedstocks)) => {
+                other.energy_consumption += used.energy_consumption;
+                other.co2e_wtw += used.co2e_wtw;
+                other.co2e_ttw += used.co2e_ttw;
+                if *other_feedstocks != feedstocks {
+                    *other_feedstocks = None;
+                }
+            }
+            None => converted.push((used, feedstocks)),
+        }
+    }
+
+    Ok(converted)
+}
+
+/// The amount transported by `tad` in `unit` per km, i.e. its mass in tonnes or its number of TEUs
+pub(crate) fn activity_amount(
+    tad: &Tad,
+    unit: &TransportActivityUnit,
+) -> Result<Decimal, TocCalculationError> {
+    match unit {
+        TransportActivityUnit::Tkm => tad
+            .mass
+            .as_ref()
+            .map(|mass| mass.0 / Decimal::ONE_THOUSAND)
+            .ok_or_else(|| TocCalculationError::MissingMass(tad.activity_id.clone())),
+        TransportActivityUnit::TEUkm => {
+            let teu_per_unit = match tad.packaging_or_tr_eq_type {
+                Some(PackagingOrTrEqType::ContainerTEU) => Decimal::ONE,
+                Some(PackagingOrTrEqType::ContainerFEU) => Decimal::TWO,
+                _ => {
+                    return Err(TocCalculationError::MissingTeuCount(
+                        tad.activity_id.clone(),
+                    ))
+                }
+            };
+            tad.packaging_or_tr_eq_amount
+                .map(|amount| Decimal::from(amount) * teu_per_unit)
+                .ok_or_else(|| TocCalculationError::MissingTeuCount(tad.activity_id.clone()))
+        }
+    }
+}
+
+/// The average of the given values, weighted by the given weights, or `None` if any value is
+/// missing or all weights are zero
+fn weighted_average(
+    values_and_weights: impl Iterator<Item = Option<(Decimal, Decimal)>>,
+) -> Option<Decimal> {
+    let values = values_and_weights.collect::<Option<Vec<_>>>()?;
+    let total_weight: Decimal = values.iter().map(|(_, weight)| weight).sum();
+    if total_weight.is_zero() {
+        return None;
+    }
+    Some(values.iter().map(|(v, w)| v * w).sum::<Decimal>() / total_weight)
+}
+
+impl From<TadTempControl> for TemperatureControl {
+    fn from(control: TadTempControl) -> Self {
+        match control {
+            TadTempControl::Ambient => TemperatureControl::Ambient,
+            TadTempControl::Refrigerated => TemperatureControl::Refrigerated,
+        }
+    }
+}
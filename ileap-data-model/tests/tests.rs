@@ -145,3 +145,911 @@ fn test_energyconsumptionunit_deser() {
         assert_eq!(expect, &serde_json::to_string(input).unwrap());
     }
 }
+
+#[test]
+fn test_validate_json() {
+    let toc: Toc = serde_json::from_str(
+        r#"{"tocId":"4561230","isVerified":true,"isAccredited":true,"mode":"Road","energyCarriers":[{"energyCarrier":"Diesel","emissionFactorWTW":"3.6801","emissionFactorTTW":"3.2801","relativeShare":"1"}],"co2eIntensityWTW":"3.6801","co2eIntensityTTW":"3.2801","transportActivityUnit":"tkm"}"#,
+    )
+    .unwrap();
+    let pcf = to_pcf(
+        toc,
+        "My Company",
+        "urn:ileap:company:my-company",
+        None,
+        "2024-01-01T00:00:00Z".parse().unwrap(),
+        "2025-01-01T00:00:00Z".parse().unwrap(),
+    )
+    .unwrap();
+
+    let mut value = serde_json::to_value(pcf).unwrap();
+    assert_eq!(validate_json(&value), Ok(()));
+
+    value["pcf"]["declaredUnit"] = "gallon".into();
+    value["extensions"][0]["data"]["mode"] = "Teleport".into();
+    value["extensions"][0]["data"]["energyCarriers"][0]
+        .as_object_mut()
+        .unwrap()
+        .remove("emissionFactorWTW");
+
+    let violations: Vec<_> = validate_json(&value)
+        .unwrap_err()
+        .into_iter()
+        .map(|v| (v.instance_path, v.keyword))
+        .collect();
+    assert_eq!(
+        violations,
+        vec![
+            ("/pcf/declaredUnit".to_string(), "enum"),
+            (
+                "/extensions/0/data/energyCarriers/0".to_string(),
+                "required"
+            ),
+            ("/extensions/0/data/mode".to_string(), "enum"),
+        ]
+    );
+}
+
+#[test]
+fn test_extension_registry_roundtrip() {
+    let ship_foot: ShipmentFootprint = serde_json::from_str(
+        r#"{"mass":"87","shipmentId":"1237890","tces":[{"tceId":"abcdef","shipmentId":"1237890","mass":"87","distance":{"actual":"423"},"transportActivity":"36.801","co2eWTW":"36.801","co2eTTW":"3.2801"}]}"#,
+    )
+    .unwrap();
+    let pcf = to_pcf(
+        ship_foot.clone(),
+        "My Company",
+        "urn:ileap:company:my-company",
+        None,
+        "2024-01-01T00:00:00Z".parse().unwrap(),
+        "2025-01-01T00:00:00Z".parse().unwrap(),
+    )
+    .unwrap();
+
+    let mut value = serde_json::to_value(pcf).unwrap();
+    let third_party = serde_json::json!({
+        "specVersion": "2.0.0",
+        "dataSchema": "https://example.com/packaging.json",
+        "data": { "material": "cardboard", "mass": 0.2 }
+    });
+    value["extensions"]
+        .as_array_mut()
+        .unwrap()
+        .push(third_party);
+
+    let decoded = ileap_extension_registry()
+        .decode_footprint(serde_json::from_value(value.clone()).unwrap())
+        .unwrap();
+    let extensions = decoded.extensions.as_ref().unwrap();
+    assert_eq!(
+        extensions[0].data.known(),
+        Some(&ILeapType::ShipmentFootprint(ship_foot))
+    );
+    assert_eq!(extensions[1].data.known(), None);
+
+    assert_eq!(serde_json::to_value(&decoded).unwrap(), value);
+}
+
+#[test]
+fn test_content_hash_of_extensions() {
+    let ship_foot: ShipmentFootprint = serde_json::from_str(
+        r#"{"mass":"87","shipmentId":"1237890","tces":[{"tceId":"abcdef","tocId":"truck-40t-euro5-de","shipmentId":"1237890","mass":"87","distance":{"actual":"423"},"transportActivity":"36.801","departureAt":"2024-01-01T08:00:00Z","co2eWTW":"3.6801","co2eTTW":"3.2801"}]}"#,
+    )
+    .unwrap();
+    let pf = to_pcf(
+        ILeapType::ShipmentFootprint(ship_foot),
+        "My Company",
+        "urn:ileap:company:my-company",
+        None,
+        "2024-01-01T00:00:00Z".parse().unwrap(),
+        "2025-01-01T00:00:00Z".parse().unwrap(),
+    )
+    .unwrap();
+    let hash = pf.content_hash().unwrap();
+
+    // the same footprint, with decimals of another scale and a timestamp in another time zone
+    let mut value = serde_json::to_value(&pf).unwrap();
+    let tce = &mut value["extensions"][0]["data"]["tces"][0];
+    tce["transportActivity"] = "36.8010".into();
+    tce["co2eWTW"] = "3.680100".into();
+    tce["departureAt"] = "2024-01-01T09:00:00+01:00".into();
+
+    let typed: pact_data_model::ProductFootprint<ILeapType> =
+        serde_json::from_value(value.clone()).unwrap();
+    assert_eq!(typed.content_hash().unwrap(), hash);
+    let untyped: pact_data_model::ProductFootprint<serde_json::Value> =
+        serde_json::from_value(value).unwrap();
+    assert_eq!(untyped.content_hash().unwrap(), hash);
+
+    let mut changed = typed;
+    let ILeapType::ShipmentFootprint(shipment) = &mut changed.extensions.as_mut().unwrap()[0].data
+    else {
+        unreachable!()
+    };
+    shipment.tces.0[0].co2e_wtw = dec!(3.7).into();
+    assert_ne!(changed.content_hash().unwrap(), hash);
+}
+
+/// The shipments, TOCs and HOCs of `footprints`
+fn ileap_data(
+    footprints: &[pact_data_model::ProductFootprint<ILeapType>],
+) -> (Vec<ShipmentFootprint>, Vec<Toc>, Vec<Hoc>) {
+    let (mut shipments, mut tocs, mut hocs) = (vec![], vec![], vec![]);
+    for extension in footprints
+        .iter()
+        .flat_map(|pf| pf.extensions.iter().flatten())
+    {
+        match extension.data.clone() {
+            ILeapType::ShipmentFootprint(shipment) => shipments.push(shipment),
+            ILeapType::Toc(toc) => tocs.push(toc),
+            ILeapType::Hoc(hoc) => hocs.push(hoc),
+        }
+    }
+    (shipments, tocs, hocs)
+}
+
+#[test]
+fn test_check_tce_consistency_demo_data() {
+    let (shipments, mut tocs, mut hocs) = ileap_data(&gen_rnd_demo_data(10));
+
+    // the generated TOC and HOC ids are not necessarily unique, so TCEs referencing an ambiguous
+    // one are reported as referencing an unknown one instead
+    let toc_ids: Vec<_> = tocs.iter().map(|toc| toc.toc_id.clone()).collect();
+    tocs.retain(|toc| toc_ids.iter().filter(|id| **id == toc.toc_id).count() == 1);
+    let hoc_ids: Vec<_> = hocs.iter().map(|hoc| hoc.hoc_id.clone()).collect();
+    hocs.retain(|hoc| hoc_ids.iter().filter(|id| **id == hoc.hoc_id).count() == 1);
+
+    for shipment in shipments.iter() {
+        for discrepancy in check_tce_consistency(shipment, &tocs, &hocs, &Tolerance::default()) {
+            assert!(
+                matches!(
+                    discrepancy,
+                    Discrepancy::UnknownToc { .. } | Discrepancy::UnknownHoc { .. }
+                ),
+                "{discrepancy}"
+            );
+        }
+    }
+}
+
+#[test]
+fn test_check_tce_consistency() {
+    let tocs: Vec<Toc> = serde_json::from_str(
+        r#"[{"tocId":"truck-40t-euro5-de","mode":"Road","energyCarriers":[{"energyCarrier":"Diesel","emissionFactorWTW":"3.6801","emissionFactorTTW":"3.2801","relativeShare":"1"}],"co2eIntensityWTW":"0.1","co2eIntensityTTW":"0.08","transportActivityUnit":"tkm"}]"#,
+    )
+    .unwrap();
+    let mut shipment: ShipmentFootprint = serde_json::from_str(
+        r#"{"mass":"87","shipmentId":"1237890","tces":[{"tceId":"abcdef","tocId":"truck-40t-euro5-de","shipmentId":"1237890","mass":"87","distance":{"actual":"423"},"transportActivity":"36.801","co2eWTW":"3.6801","co2eTTW":"2.94408"},{"tceId":"ghijkl","prevTceIds":["abcdef"],"hocId":"hub-de","shipmentId":"1237890","mass":"87","distance":{"actual":"0"},"transportActivity":"0","co2eWTW":"1","co2eTTW":"1"}]}"#,
+    )
+    .unwrap();
+
+    assert_eq!(
+        check_tce_consistency(&shipment, &tocs, &[], &Tolerance::default()),
+        vec![Discrepancy::UnknownHoc {
+            tce_id: "ghijkl".to_string(),
+            hoc_id: "hub-de".to_string(),
+        }]
+    );
+
+    // within 1% of the expected value
+    shipment.tces.0[0].co2e_wtw = dec!(3.7).into();
+    shipment.tces.0[0].co2e_ttw = dec!(3).into();
+    shipment.tces.0[1].transport_activity = dec!(10).into();
+    shipment.tces.0[1].toc_id = Some("truck-40t-euro5-de".to_string());
+    shipment.tces.0[1].hoc_id = None;
+
+    let discrepancies = check_tce_consistency(&shipment, &tocs, &[], &Tolerance::default());
+    assert_eq!(
+        discrepancies,
+        vec![
+            Discrepancy::Mismatch {
+                tce_id: "abcdef".to_string(),
+                property: "co2eTTW",
+                expected: dec!(2.94408),
+                actual: dec!(3),
+            },
+            Discrepancy::Mismatch {
+                tce_id: "ghijkl".to_string(),
+                property: "transportActivity",
+                expected: dec!(0),
+                actual: dec!(10),
+            },
+            Discrepancy::Mismatch {
+                tce_id: "ghijkl".to_string(),
+                property: "co2eTTW",
+                expected: dec!(0.8),
+                actual: dec!(1),
+            },
+        ]
+    );
+    assert_eq!(
+        discrepancies[0].to_string(),
+        "TCE abcdef: co2eTTW is 3, expected 2.94408"
+    );
+}
+
+#[test]
+fn test_check_tce_consistency_hoc() {
+    let hocs: Vec<Hoc> = serde_json::from_str(
+        r#"[{"hocId":"hub-de","hubType":"Warehouse","energyCarriers":[{"energyCarrier":"Electric","emissionFactorWTW":"0.4","emissionFactorTTW":"0","relativeShare":"1"}],"co2eIntensityWTW":"10","co2eIntensityTTW":"5","hubActivityUnit":"tonnes"}]"#,
+    )
+    .unwrap();
+    // 87 kg handled at a hub with intensities per tonne
+    let mut shipment: ShipmentFootprint = serde_json::from_str(
+        r#"{"mass":"87","shipmentId":"1237890","tces":[{"tceId":"ghijkl","hocId":"hub-de","shipmentId":"1237890","mass":"87","distance":{"actual":"0"},"transportActivity":"0","co2eWTW":"0.87","co2eTTW":"0.435"}]}"#,
+    )
+    .unwrap();
+
+    assert_eq!(
+        check_tce_consistency(&shipment, &[], &hocs, &Tolerance::default()),
+        vec![]
+    );
+
+    // emissions computed from the mass in kg instead of tonnes
+    shipment.tces.0[0].co2e_wtw = dec!(870).into();
+    shipment.tces.0[0].co2e_ttw = dec!(435).into();
+    assert_eq!(
+        check_tce_consistency(&shipment, &[], &hocs, &Tolerance::default()),
+        vec![
+            Discrepancy::Mismatch {
+                tce_id: "ghijkl".to_string(),
+                property: "co2eWTW",
+                expected: dec!(0.87),
+                actual: dec!(870),
+            },
+            Discrepancy::Mismatch {
+                tce_id: "ghijkl".to_string(),
+                property: "co2eTTW",
+                expected: dec!(0.435),
+                actual: dec!(435),
+            },
+        ]
+    );
+}
+
+/// A TCE of shipment `1237890` with the given ids, referencing a TOC unless `hoc_id` is given
+fn chain_tce(tce_id: &str, prev_tce_ids: &[&str], hoc_id: Option<&str>) -> serde_json::Value {
+    let mut tce = serde_json::json!({
+        "tceId": tce_id,
+        "prevTceIds": prev_tce_ids,
+        "shipmentId": "1237890",
+        "mass": "87",
+        "distance": { "actual": "423" },
+        "transportActivity": "36.801",
+        "co2eWTW": "3.6801",
+        "co2eTTW": "2.94408"
+    });
+    match hoc_id {
+        Some(hoc_id) => tce["hocId"] = hoc_id.into(),
+        None => tce["tocId"] = "truck-40t-euro5-de".into(),
+    }
+    tce
+}
+
+fn chain_shipment(tces: Vec<serde_json::Value>) -> ShipmentFootprint {
+    serde_json::from_value(serde_json::json!({
+        "mass": "87",
+        "shipmentId": "1237890",
+        "tces": tces
+    }))
+    .unwrap()
+}
+
+#[test]
+fn test_check_tce_chain() {
+    // listed out of order, with all preceding TCEs in prevTceIds
+    let shipment = chain_shipment(vec![
+        chain_tce("c", &["a", "b"], None),
+        chain_tce("a", &[], None),
+        chain_tce("b", &["a"], Some("hub-de")),
+    ]);
+
+    let chain = check_tce_chain(&shipment);
+    assert!(chain.is_valid(), "{:?}", chain.problems);
+    let order: Vec<_> = chain
+        .order
+        .unwrap()
+        .iter()
+        .map(|tce| tce.tce_id.as_str())
+        .collect();
+    assert_eq!(order, vec!["a", "b", "c"]);
+}
+
+#[test]
+fn test_check_tce_chain_problems() {
+    let mut shipment = chain_shipment(vec![
+        chain_tce("a", &[], Some("hub-nl")),
+        chain_tce("b", &["a", "x"], Some("hub-de")),
+        chain_tce("c", &["a", "b"], None),
+        chain_tce("c", &[], None),
+    ]);
+    let tces = &mut shipment.tces.0;
+    tces[2].hoc_id = Some("hub-fr".to_string());
+    tces[3].toc_id = None;
+    tces[3].shipment_id = "other".to_string();
+
+    let chain = check_tce_chain(&shipment);
+    assert_eq!(
+        chain.problems,
+        vec![
+            ChainProblem::TocAndHoc {
+                tce_id: "c".to_string()
+            },
+            ChainProblem::DuplicateTceId {
+                tce_id: "c".to_string()
+            },
+            ChainProblem::ShipmentIdMismatch {
+                tce_id: "c".to_string(),
+                shipment_id: "other".to_string()
+            },
+            ChainProblem::NeitherTocNorHoc {
+                tce_id: "c".to_string()
+            },
+            ChainProblem::DanglingPrevTceId {
+                tce_id: "b".to_string(),
+                prev_tce_id: "x".to_string()
+            },
+            ChainProblem::StartsAtHub {
+                tce_id: "a".to_string()
+            },
+            ChainProblem::EndsAtHub {
+                tce_id: "c".to_string()
+            },
+            ChainProblem::ConsecutiveHubs {
+                prev_tce_id: "a".to_string(),
+                tce_id: "b".to_string()
+            },
+            ChainProblem::ConsecutiveHubs {
+                prev_tce_id: "b".to_string(),
+                tce_id: "c".to_string()
+            },
+        ]
+    );
+    assert!(chain.order.is_some());
+}
+
+#[test]
+fn test_check_tce_chain_cycle() {
+    let shipment = chain_shipment(vec![
+        chain_tce("a", &[], None),
+        chain_tce("b", &["a", "d"], None),
+        chain_tce("c", &["b"], None),
+        chain_tce("d", &["c"], None),
+    ]);
+
+    let chain = check_tce_chain(&shipment);
+    assert_eq!(chain.order, None);
+    assert_eq!(
+        chain.problems,
+        vec![ChainProblem::Cycle {
+            tce_ids: vec!["b".to_string(), "c".to_string(), "d".to_string()]
+        }]
+    );
+    assert_eq!(chain.problems[0].to_string(), "cycle of TCEs b, c, d");
+}
+
+fn road_tad(activity_id: &str, mass: &str, distance: &str, diesel: &str) -> Tad {
+    serde_json::from_value(serde_json::json!({
+        "activityId": activity_id,
+        "consignmentIds": [format!("consignment-{activity_id}")],
+        "distance": { "actual": distance },
+        "mass": mass,
+        "origin": { "city": "Berlin", "country": "DE" },
+        "destination": { "city": "Hamburg", "country": "DE" },
+        "departureAt": "2024-01-01T08:00:00Z",
+        "arrivalAt": "2024-01-01T12:00:00Z",
+        "mode": "Road",
+        "energyCarriers": [{
+            "energyCarrier": "Diesel",
+            "energyConsumption": diesel,
+            "energyConsumptionUnit": "l",
+            "emissionFactorWTW": "3.24",
+            "emissionFactorTTW": "2.67",
+            "relativeShare": "1"
+        }]
+    }))
+    .unwrap()
+}
+
+#[test]
+fn test_calculate_toc() {
+    let mut tads = vec![
+        road_tad("tad-1", "10000", "100", "30"),
+        road_tad("tad-2", "20000", "200", "80"),
+    ];
+    tads[0].load_factor = Some(dec!(0.5).into());
+    tads[1].load_factor = Some(dec!(0.8).into());
+    tads[0].temperature_control = Some(TadTempControl::Ambient);
+    tads[1].temperature_control = Some(TadTempControl::Refrigerated);
+
+    let calculation = calculate_toc("toc-road", &tads, TransportActivityUnit::Tkm).unwrap();
+    assert_eq!(calculation.transport_activity, dec!(5000));
+    assert_eq!(calculation.co2e_wtw, dec!(356.4));
+    assert_eq!(
+        calculation
+            .activities
+            .iter()
+            .map(|a| (a.activity_id.as_str(), a.transport_activity))
+            .collect::<Vec<_>>(),
+        vec![("tad-1", dec!(1000)), ("tad-2", dec!(4000))]
+    );
+
+    let toc = calculation.toc;
+    assert_eq!(toc.mode, TransportMode::Road);
+    assert_eq!(toc.co2e_intensity_wtw, dec!(0.07128).into());
+    assert_eq!(toc.co2e_intensity_ttw, dec!(0.05874).into());
+    assert_eq!(toc.load_factor, Some("0.74".to_string()));
+    assert_eq!(toc.empty_distance_factor, None);
+    assert_eq!(toc.temperature_control, Some(TemperatureControl::Mixed));
+    assert_eq!(
+        toc.energy_carriers.0,
+        vec![EnergyCarrier {
+            energy_carrier: EnergyCarrierType::Diesel,
+            feedstocks: None,
+            energy_consumption: Some(dec!(110).into()),
+            energy_consumption_unit: Some(EnergyConsumptionUnit::L),
+            emission_factor_wtw: dec!(3.24).into(),
+            emission_factor_ttw: dec!(2.67).into(),
+            relative_share: dec!(1).into(),
+        }]
+    );
+}
+
+#[test]
+fn test_calculate_toc_errors() {
+    let tads = vec![road_tad("tad-1", "10000", "100", "30")];
+    assert_eq!(
+        calculate_toc("toc-road", &tads, TransportActivityUnit::TEUkm).unwrap_err(),
+        TocCalculationError::MissingTeuCount("tad-1".to_string())
+    );
+    assert_eq!(
+        calculate_toc("toc-road", &[], TransportActivityUnit::Tkm).unwrap_err(),
+        TocCalculationError::NoTads
+    );
+
+    let mut rail = road_tad("tad-2", "10000", "100", "30");
+    rail.mode = TransportMode::Rail;
+    rail.energy_carriers = None;
+    assert_eq!(
+        calculate_toc(
+            "toc-road",
+            &[tads[0].clone(), rail.clone()],
+            TransportActivityUnit::Tkm
+        )
+        .unwrap_err(),
+        TocCalculationError::MixedModes
+    );
+    assert_eq!(
+        calculate_toc("toc-rail", &[rail], TransportActivityUnit::Tkm).unwrap_err(),
+        TocCalculationError::MissingEnergyConsumption("tad-2".to_string())
+    );
+}
+
+#[test]
+fn test_calculate_tces() {
+    let mut tad = road_tad("tad-1", "10000", "100", "30");
+    tad.consignment_ids = vec!["consignment-a".to_string(), "consignment-b".to_string()];
+    tad.packaging_or_tr_eq_type = Some(PackagingOrTrEqType::Pallet);
+    tad.packaging_or_tr_eq_amount = Some(10);
+    let toc = calculate_toc(
+        "toc-road",
+        &[road_tad("tad-0", "20000", "200", "80")],
+        TransportActivityUnit::Tkm,
+    )
+    .unwrap()
+    .toc;
+    let consignments = [
+        Consignment {
+            consignment_id: "consignment-a".to_string(),
+            shipment_id: "shipment-a".to_string(),
+            mass: dec!(6000),
+        },
+        Consignment {
+            consignment_id: "consignment-b".to_string(),
+            shipment_id: "shipment-b".to_string(),
+            mass: dec!(4000),
+        },
+    ];
+
+    let tces = calculate_tces(&tad, &toc, &consignments).unwrap();
+    assert_eq!(
+        tces.iter()
+            .map(|tce| (
+                tce.tce_id.as_str(),
+                tce.transport_activity.0,
+                tce.co2e_wtw.0,
+                tce.packaging_or_tr_eq_amount.as_ref().unwrap().0
+            ))
+            .collect::<Vec<_>>(),
+        vec![
+            ("tad-1-consignment-a", dec!(600), dec!(38.88), dec!(6)),
+            ("tad-1-consignment-b", dec!(400), dec!(25.92), dec!(4)),
+        ]
+    );
+    assert_eq!(tces[0].shipment_id, "shipment-a");
+    assert_eq!(tces[0].origin.as_ref(), Some(&tad.origin));
+    assert_eq!(tces[0].departure_at, Some(tad.departure_at));
+
+    // consistent with the TOC they were calculated from
+    for tce in tces {
+        let shipment = ShipmentFootprint {
+            mass: tce.mass.0.to_string(),
+            volume: None,
+            shipment_id: tce.shipment_id.clone(),
+            tces: vec![tce].into(),
+        };
+        assert_eq!(
+            check_tce_consistency(
+                &shipment,
+                std::slice::from_ref(&toc),
+                &[],
+                &Tolerance::default()
+            ),
+            vec![]
+        );
+    }
+
+    // the TAD also carries 10 t of other shippers' goods, which the TCEs must not account for
+    tad.mass = Some(dec!(20000).into());
+    let tces = calculate_tces(&tad, &toc, &consignments).unwrap();
+    assert_eq!(
+        tces.iter()
+            .map(|tce| (tce.transport_activity.0, tce.co2e_wtw.0))
+            .collect::<Vec<_>>(),
+        vec![(dec!(600), dec!(38.88)), (dec!(400), dec!(25.92))]
+    );
+}
+
+#[test]
+fn test_calculate_tces_errors() {
+    let tad = road_tad("tad-1", "10000", "100", "30");
+    let toc = calculate_toc(
+        "toc-road",
+        std::slice::from_ref(&tad),
+        TransportActivityUnit::Tkm,
+    )
+    .unwrap()
+    .toc;
+
+    assert_eq!(
+        calculate_tces(&tad, &toc, &[]).unwrap_err(),
+        TceCalculationError::UnknownConsignment("consignment-tad-1".to_string())
+    );
+
+    let mut rail_toc = toc.clone();
+    rail_toc.mode = TransportMode::Rail;
+    assert_eq!(
+        calculate_tces(&tad, &rail_toc, &[]).unwrap_err(),
+        TceCalculationError::ModeMismatch
+    );
+
+    let mut teu_toc = toc;
+    teu_toc.transport_activity_unit = TransportActivityUnit::TEUkm;
+    let consignment = Consignment {
+        consignment_id: "consignment-tad-1".to_string(),
+        shipment_id: "shipment-1".to_string(),
+        mass: dec!(10000),
+    };
+    assert_eq!(
+        calculate_tces(&tad, &teu_toc, &[consignment]).unwrap_err(),
+        TceCalculationError::TransportActivity(TocCalculationError::MissingTeuCount(
+            "tad-1".to_string()
+        ))
+    );
+}
+
+/// A library of made-up factors, for testing the lookup only
+fn factor_library() -> EmissionFactorLibrary {
+    use EnergyCarrierType::*;
+    use EnergyConsumptionUnit::*;
+    use FactorRegion::*;
+
+    let factor = |energy_carrier, feedstock, region, unit, wtw, ttw| DefaultEmissionFactor {
+        energy_carrier,
+        feedstock,
+        region,
+        unit,
+        emission_factor_wtw: wtw,
+        emission_factor_ttw: ttw,
+    };
+
+    EmissionFactorLibrary {
+        name: "Test factors".to_string(),
+        version: "1".to_string(),
+        factors: vec![
+            factor(Diesel, None, Europe, L, dec!(3.24), dec!(2.67)),
+            factor(Hvo, None, Europe, L, dec!(0.82), dec!(0.03)),
+            factor(
+                Hvo,
+                Some(FeedstockType::CookingOil),
+                Europe,
+                L,
+                dec!(0.29),
+                dec!(0.03),
+            ),
+            factor(Hfo, None, Global, Kg, dec!(3.54), dec!(3.15)),
+            factor(Electric, None, Europe, KWh, dec!(0.33), dec!(0)),
+            factor(Electric, None, NorthAmerica, KWh, dec!(0.42), dec!(0)),
+        ],
+    }
+}
+
+#[test]
+fn test_emission_factor_lookup() {
+    let library = factor_library();
+    let feedstock = |feedstock, share: Option<rust_decimal::Decimal>| Feedstock {
+        feedstock,
+        feedstock_share: share.map(Into::into),
+        region_provenance: None,
+    };
+
+    let diesel = library
+        .lookup(
+            &EnergyCarrierType::Diesel,
+            None,
+            FactorRegion::Europe,
+            Some(&EnergyConsumptionUnit::L),
+        )
+        .unwrap();
+    assert_eq!(
+        (diesel.emission_factor_wtw, diesel.emission_factor_ttw),
+        (dec!(3.24), dec!(2.67))
+    );
+    assert_eq!(diesel.source.library, "Test factors");
+    assert_eq!(diesel.source.feedstocks, vec![]);
+
+    let cooking_oil = [feedstock(FeedstockType::CookingOil, None)];
+    let hvo = library
+        .lookup(
+            &EnergyCarrierType::Hvo,
+            Some(&cooking_oil),
+            FactorRegion::Europe,
+            Some(&EnergyConsumptionUnit::L),
+        )
+        .unwrap();
+    assert_eq!(hvo.emission_factor_wtw, dec!(0.29));
+    assert_eq!(hvo.source.feedstocks, vec![FeedstockType::CookingOil]);
+
+    // feedstocks without a specific factor make the factors of the blend unknown, rather than
+    // falling back to the generic factor of the carrier
+    let lookup_hvo = |feedstocks: &[Feedstock]| {
+        library.lookup(
+            &EnergyCarrierType::Hvo,
+            Some(feedstocks),
+            FactorRegion::Europe,
+            Some(&EnergyConsumptionUnit::L),
+        )
+    };
+    let blend = [
+        feedstock(FeedstockType::CookingOil, Some(dec!(0.5))),
+        feedstock(FeedstockType::Fossil, Some(dec!(0.5))),
+    ];
+    assert_eq!(lookup_hvo(&blend), None);
+    assert_eq!(lookup_hvo(&[feedstock(FeedstockType::Fossil, None)]), None);
+
+    // a blend is weighted by the shares of its feedstocks, ignoring those without a share
+    let blend = [
+        feedstock(FeedstockType::CookingOil, Some(dec!(1))),
+        feedstock(FeedstockType::Fossil, Some(dec!(0))),
+    ];
+    assert_eq!(lookup_hvo(&blend).unwrap().emission_factor_wtw, dec!(0.29));
+
+    let hfo = library
+        .lookup(&EnergyCarrierType::Hfo, None, FactorRegion::Europe, None)
+        .unwrap();
+    assert_eq!(hfo.unit, EnergyConsumptionUnit::Kg);
+    assert_eq!(hfo.source.region, FactorRegion::Global);
+
+    assert_eq!(
+        library.lookup(
+            &EnergyCarrierType::Electric,
+            None,
+            FactorRegion::Global,
+            None
+        ),
+        None
+    );
+}
+
+#[test]
+fn test_complete_energy_carrier() {
+    let library = factor_library();
+    let partial: PartialEnergyCarrier = serde_json::from_str(
+        r#"{"energyCarrier":"Electric","energyConsumption":"1200","energyConsumptionUnit":"kWh","emissionFactorTTW":"0","relativeShare":"1"}"#,
+    )
+    .unwrap();
+
+    let completed = library
+        .complete(partial.clone(), FactorRegion::NorthAmerica)
+        .unwrap();
+    assert_eq!(
+        completed.energy_carrier.emission_factor_wtw,
+        dec!(0.42).into()
+    );
+    assert_eq!(completed.energy_carrier.emission_factor_ttw, dec!(0).into());
+    assert_eq!(completed.source.unwrap().region, FactorRegion::NorthAmerica);
+
+    let mut complete = partial.clone();
+    complete.emission_factor_wtw = Some(dec!(0.1).into());
+    let completed = library.complete(complete, FactorRegion::Europe).unwrap();
+    assert_eq!(completed.source, None);
+    assert_eq!(
+        completed.energy_carrier.emission_factor_wtw,
+        dec!(0.1).into()
+    );
+
+    let mut in_mj = partial;
+    in_mj.energy_consumption_unit = Some(EnergyConsumptionUnit::MJ);
+    assert_eq!(
+        library
+            .complete(in_mj, FactorRegion::Europe)
+            .unwrap_err()
+            .to_string(),
+        "no default emission factor for Electric in Europe per MJ"
+    );
+
+    let fossil_hvo: PartialEnergyCarrier = serde_json::from_str(
+        r#"{"energyCarrier":"HVO","feedstocks":[{"feedstock":"Fossil"}],"energyConsumptionUnit":"l","relativeShare":"1"}"#,
+    )
+    .unwrap();
+    assert_eq!(
+        library
+            .complete(fossil_hvo, FactorRegion::Europe)
+            .unwrap_err()
+            .to_string(),
+        "no default emission factor for Hvo in Europe made of [Fossil] per L"
+    );
+}
+
+#[test]
+fn test_energy_conversion() {
+    let conversion = EnergyConversion::new();
+    let diesel = EnergyCarrierType::Diesel;
+    assert_eq!(
+        conversion
+            .factor(
+                &diesel,
+                &EnergyConsumptionUnit::L,
+                &EnergyConsumptionUnit::MJ
+            )
+            .unwrap(),
+        dec!(35.8592)
+    );
+    assert_eq!(
+        conversion
+            .convert(
+                &EnergyCarrierType::Electric,
+                dec!(10),
+                &EnergyConsumptionUnit::KWh,
+                &EnergyConsumptionUnit::MJ
+            )
+            .unwrap(),
+        dec!(36)
+    );
+    assert_eq!(
+        conversion
+            .factor(
+                &EnergyCarrierType::Electric,
+                &EnergyConsumptionUnit::KWh,
+                &EnergyConsumptionUnit::Kg
+            )
+            .unwrap_err(),
+        ConversionError::MissingLowerHeatingValue(EnergyCarrierType::Electric)
+    );
+
+    let carrier = road_tad("tad-1", "10000", "100", "30")
+        .energy_carriers
+        .unwrap()
+        .0
+        .remove(0);
+    let normalized = conversion
+        .normalize(&carrier, &EnergyConsumptionUnit::MJ)
+        .unwrap();
+    assert_eq!(
+        normalized.energy_consumption_unit,
+        Some(EnergyConsumptionUnit::MJ)
+    );
+    assert_eq!(
+        normalized.energy_consumption.as_ref().unwrap().0,
+        dec!(1075.776)
+    );
+    // the emissions are the same in any unit
+    let emissions = |carrier: &EnergyCarrier| {
+        (carrier.energy_consumption.as_ref().unwrap().0 * carrier.emission_factor_wtw.0).round_dp(6)
+    };
+    assert_eq!(emissions(&normalized), dec!(97.2));
+    let in_kwh = conversion
+        .normalize(&normalized, &EnergyConsumptionUnit::KWh)
+        .unwrap();
+    assert_eq!(emissions(&in_kwh), dec!(97.2));
+
+    let conversion = conversion.with_properties(
+        diesel.clone(),
+        EnergyProperties {
+            density: Some(dec!(0.84)),
+            lower_heating_value: Some(dec!(43)),
+        },
+    );
+    assert_eq!(
+        conversion
+            .factor(
+                &diesel,
+                &EnergyConsumptionUnit::L,
+                &EnergyConsumptionUnit::MJ
+            )
+            .unwrap(),
+        dec!(36.12)
+    );
+
+    let conversion = conversion.with_properties(
+        diesel.clone(),
+        EnergyProperties {
+            density: Some(dec!(0)),
+            lower_heating_value: Some(dec!(-43)),
+        },
+    );
+    assert_eq!(
+        conversion
+            .factor(
+                &diesel,
+                &EnergyConsumptionUnit::MJ,
+                &EnergyConsumptionUnit::L
+            )
+            .unwrap_err(),
+        ConversionError::InvalidDensity(diesel.clone())
+    );
+    assert_eq!(
+        conversion
+            .factor(
+                &diesel,
+                &EnergyConsumptionUnit::MJ,
+                &EnergyConsumptionUnit::Kg
+            )
+            .unwrap_err(),
+        ConversionError::InvalidLowerHeatingValue(diesel)
+    );
+}
+
+#[test]
+fn test_calculate_toc_mixed_units() {
+    let diesel = road_tad("tad-1", "10000", "100", "30");
+    let mut electric = road_tad("tad-2", "10000", "100", "0");
+    electric.energy_carriers = Some(
+        serde_json::from_value(serde_json::json!([{
+            "energyCarrier": "Electric",
+            "energyConsumption": "100",
+            "energyConsumptionUnit": "kWh",
+            "emissionFactorWTW": "0.4",
+            "emissionFactorTTW": "0",
+            "relativeShare": "1"
+        }]))
+        .unwrap(),
+    );
+
+    let calculation = calculate_toc(
+        "toc-road",
+        &[diesel.clone(), electric.clone()],
+        TransportActivityUnit::Tkm,
+    )
+    .unwrap();
+    assert_eq!(calculation.co2e_wtw, dec!(137.2));
+    assert_eq!(
+        calculation
+            .energy_use
+            .iter()
+            .map(|used| (used.energy_consumption, used.co2e_wtw))
+            .collect::<Vec<_>>(),
+        vec![(dec!(1075.776), dec!(97.2)), (dec!(360), dec!(40))]
+    );
+    assert!(calculation
+        .energy_use
+        .iter()
+        .all(|used| used.energy_consumption_unit == Some(EnergyConsumptionUnit::MJ)));
+
+    electric.energy_carriers.as_mut().unwrap().0[0].energy_consumption_unit =
+        Some(EnergyConsumptionUnit::Kg);
+    assert_eq!(
+        calculate_toc("toc-road", &[diesel, electric], TransportActivityUnit::Tkm).unwrap_err(),
+        TocCalculationError::MixedEnergyUnits
+    );
+}
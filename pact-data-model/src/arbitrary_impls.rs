@@ -0,0 +1,542 @@
+/*
+ * Copyright (c) 2024 SINE Foundation e.V.
+ *
+ * This software is released under the MIT License, see LICENSE.
+ */
+
+//! `quickcheck::Arbitrary` implementations for the data model (feature `quickcheck`), e.g. for
+//! property-based tests and fuzzing.
+//!
+//! Generated values are valid according to the JSON Schema and the rules checked by
+//! `ProductFootprint::validate`. In particular, numeric values respect the ranges of their types,
+//! periods end after they start, `packagingGhgEmissions` is only set if
+//! `packagingEmissionsIncluded` is true, and geographies are assigned ISO 3166 codes. The global
+//! geographic scope is represented by an absent `GeographicScope`.
+
+use chrono::{DateTime, Duration, TimeZone, Utc};
+use quickcheck::{Arbitrary, Gen};
+use rust_decimal::Decimal;
+use schemars::JsonSchema;
+use uuid::Uuid;
+
+use crate::*;
+
+/// Timestamps are generated between 2000-01-01 and 2030-01-01
+const MIN_TIMESTAMP: i64 = 946_684_800;
+const MAX_TIMESTAMP: i64 = 1_893_456_000;
+
+fn arbitrary_timestamp(g: &mut Gen) -> DateTime<Utc> {
+    let seconds = u64::arbitrary(g) % (MAX_TIMESTAMP - MIN_TIMESTAMP) as u64;
+    Utc.timestamp_opt(MIN_TIMESTAMP + seconds as i64, 0)
+        .unwrap()
+}
+
+/// A timestamp between 1 hour and ~7.5 years (`u16::MAX` hours) after `start`
+fn arbitrary_timestamp_after(start: DateTime<Utc>, g: &mut Gen) -> DateTime<Utc> {
+    start + Duration::hours(u16::arbitrary(g) as i64 + 1)
+}
+
+/// A non-negative decimal with up to 4 decimal places
+fn arbitrary_decimal(g: &mut Gen) -> Decimal {
+    Decimal::new(u32::arbitrary(g) as i64, u32::arbitrary(g) % 5)
+}
+
+fn arbitrary_name(prefix: &str, g: &mut Gen) -> String {
+    format!("{prefix} {}", u16::arbitrary(g))
+}
+
+/// Between 1 and 3 distinct elements generated by `item`, as required for set-like vectors
+fn arbitrary_set<T: PartialEq>(g: &mut Gen, mut item: impl FnMut(&mut Gen) -> T) -> Vec<T> {
+    let len = usize::arbitrary(g) % 3 + 1;
+    let mut set = Vec::with_capacity(len);
+    for _ in 0..len {
+        let item = item(g);
+        if !set.contains(&item) {
+            set.push(item);
+        }
+    }
+    set
+}
+
+/// A non-empty subset of `values`, in their order
+fn arbitrary_subset<T: Clone>(values: &[T], g: &mut Gen) -> Vec<T> {
+    let mut subset: Vec<T> = values
+        .iter()
+        .filter(|_| bool::arbitrary(g))
+        .cloned()
+        .collect();
+    if subset.is_empty() {
+        subset.push(g.choose(values).unwrap().clone());
+    }
+    subset
+}
+
+impl<T: Arbitrary + JsonSchema> Arbitrary for ProductFootprint<T> {
+    fn arbitrary(g: &mut Gen) -> Self {
+        let id = PfId::arbitrary(g);
+        let preceding_pf_ids = Option::<NonEmptyPfIdVec>::arbitrary(g)
+            .map(|ids| NonEmptyPfIdVec(ids.0.into_iter().filter(|pf_id| *pf_id != id).collect()));
+        let created = arbitrary_timestamp(g);
+        let validity_period_start = Option::<()>::arbitrary(g).map(|_| arbitrary_timestamp(g));
+
+        ProductFootprint {
+            id,
+            spec_version: SpecVersionString::arbitrary(g),
+            preceding_pf_ids: preceding_pf_ids.filter(|ids| !ids.0.is_empty()),
+            version: VersionInteger::arbitrary(g),
+            created,
+            updated: Option::<()>::arbitrary(g).map(|_| arbitrary_timestamp_after(created, g)),
+            status: PfStatus::arbitrary(g),
+            status_comment: Option::<()>::arbitrary(g).map(|_| arbitrary_name("Status", g)),
+            validity_period_start,
+            validity_period_end: validity_period_start
+                .map(|start| arbitrary_timestamp_after(start, g)),
+            company_name: NonEmptyString(arbitrary_name("Company", g)),
+            company_ids: CompanyIdSet::arbitrary(g),
+            product_description: arbitrary_name("Description of product", g),
+            product_ids: ProductIdSet::arbitrary(g),
+            product_category_cpc: NonEmptyString((u16::arbitrary(g) % 10000).to_string()),
+            product_name_company: NonEmptyString(arbitrary_name("Product", g)),
+            comment: arbitrary_name("Comment", g),
+            pcf: CarbonFootprint::arbitrary(g),
+            extensions: Option::<Vec<DataModelExtension<T>>>::arbitrary(g),
+        }
+    }
+}
+
+impl<T: Arbitrary + JsonSchema> Arbitrary for DataModelExtension<T> {
+    fn arbitrary(g: &mut Gen) -> Self {
+        DataModelExtension {
+            spec_version: SpecVersionString::arbitrary(g),
+            data_schema: format!("https://example.com/extension-{}.json", u8::arbitrary(g)),
+            documentation: Option::<()>::arbitrary(g)
+                .map(|_| "https://example.com/documentation".to_string()),
+            data: T::arbitrary(g),
+        }
+    }
+}
+
+impl Arbitrary for CarbonFootprint {
+    fn arbitrary(g: &mut Gen) -> Self {
+        let p_cf_excluding_biogenic = arbitrary_decimal(g);
+        // the fossil share of pCfExcludingBiogenic, in percent
+        let fossil_share = Decimal::new((u8::arbitrary(g) % 101) as i64, 2);
+        let characterization_factors = CharacterizationFactors::arbitrary(g);
+        let reference_period_start = arbitrary_timestamp(g);
+        let packaging_emissions_included = bool::arbitrary(g);
+
+        CarbonFootprint {
+            declared_unit: DeclaredUnit::arbitrary(g),
+            unitary_product_amount: StrictlyPositiveDecimal::arbitrary(g),
+            p_cf_excluding_biogenic: PositiveDecimal(p_cf_excluding_biogenic),
+            p_cf_including_biogenic: Option::<WrappedDecimal>::arbitrary(g),
+            fossil_ghg_emissions: PositiveDecimal(
+                (p_cf_excluding_biogenic * fossil_share).normalize(),
+            ),
+            fossil_carbon_content: PositiveDecimal::arbitrary(g),
+            biogenic_carbon_content: PositiveDecimal::arbitrary(g),
+            d_luc_ghg_emissions: Option::<PositiveDecimal>::arbitrary(g),
+            land_management_ghg_emissions: Option::<PositiveDecimal>::arbitrary(g),
+            other_biogenic_ghg_emissions: Option::<PositiveDecimal>::arbitrary(g),
+            i_luc_ghg_emissions: Option::<PositiveDecimal>::arbitrary(g),
+            biogenic_carbon_withdrawal: Option::<NegativeDecimal>::arbitrary(g),
+            aircraft_ghg_emissions: Option::<PositiveDecimal>::arbitrary(g),
+            characterization_factors,
+            ipcc_characterization_factors_sources: IpccCharacterizationFactorsSources(vec![
+                IpccCharacterizationFactorsSource::from(characterization_factors),
+            ]),
+            cross_sectoral_standards_used: CrossSectoralStandardSet::arbitrary(g),
+            product_or_sector_specific_rules: Option::<ProductOrSectorSpecificRuleSet>::arbitrary(
+                g,
+            ),
+            biogenic_accounting_methodology: Option::<BiogenicAccountingMethodology>::arbitrary(g),
+            boundary_processes_description: arbitrary_name("Boundary processes", g),
+            reference_period_start,
+            reference_period_end: arbitrary_timestamp_after(reference_period_start, g),
+            geographic_scope: Option::<GeographicScope>::arbitrary(g),
+            secondary_emission_factor_sources: Option::<EmissionFactorDSSet>::arbitrary(g),
+            exempted_emissions_percent: ExemptedEmissionsPercent::arbitrary(g),
+            exempted_emissions_description: arbitrary_name("Exempted emissions", g),
+            packaging_emissions_included,
+            packaging_ghg_emissions: Option::<PositiveDecimal>::arbitrary(g)
+                .filter(|_| packaging_emissions_included),
+            allocation_rules_description: Option::<()>::arbitrary(g)
+                .map(|_| arbitrary_name("Allocation rules", g)),
+            uncertainty_assessment_description: Option::<()>::arbitrary(g)
+                .map(|_| arbitrary_name("Uncertainty assessment", g)),
+            primary_data_share: Option::<Percent>::arbitrary(g),
+            dqi: Option::<DataQualityIndicators>::arbitrary(g),
+            assurance: Option::<Assurance>::arbitrary(g),
+        }
+    }
+}
+
+impl Arbitrary for GeographicScope {
+    /// Generates a regional, country or subdivision scope, but never the global scope, which is
+    /// represented by the absence of a `GeographicScope`
+    fn arbitrary(g: &mut Gen) -> Self {
+        match u8::arbitrary(g) % 3 {
+            0 => GeographicScope::Regional(UNRegionOrSubregion::arbitrary(g)),
+            1 => GeographicScope::Country(ISO3166CC::arbitrary(g)),
+            _ => {
+                let subdivision = g.choose(iso3166::subdivisions()).unwrap();
+                GeographicScope::Subdivision(NonEmptyString(subdivision.code.to_string()))
+            }
+        }
+    }
+}
+
+impl Arbitrary for DataQualityIndicators {
+    fn arbitrary(g: &mut Gen) -> Self {
+        DataQualityIndicators {
+            coverage_percent: Percent::arbitrary(g),
+            technological_d_q_r: FloatBetween1and3::arbitrary(g),
+            temporal_d_q_r: FloatBetween1and3::arbitrary(g),
+            geographical_d_q_r: FloatBetween1and3::arbitrary(g),
+            completeness_d_q_r: FloatBetween1and3::arbitrary(g),
+            reliability_d_q_r: FloatBetween1and3::arbitrary(g),
+        }
+    }
+}
+
+impl Arbitrary for Assurance {
+    fn arbitrary(g: &mut Gen) -> Self {
+        Assurance {
+            assurance: bool::arbitrary(g),
+            coverage: Option::<AssuranceCoverage>::arbitrary(g),
+            level: Option::<AssuranceLevel>::arbitrary(g),
+            boundary: Option::<AssuranceBoundary>::arbitrary(g),
+            provider_name: arbitrary_name("Assurance provider", g),
+            completed_at: Option::<()>::arbitrary(g).map(|_| arbitrary_timestamp(g)),
+            standard_name: Option::<()>::arbitrary(g).map(|_| "ISO 14064-3".to_string()),
+            comments: Option::<()>::arbitrary(g).map(|_| arbitrary_name("Comment", g)),
+        }
+    }
+}
+
+impl Arbitrary for AssuranceCoverage {
+    fn arbitrary(g: &mut Gen) -> Self {
+        let coverage = &[
+            AssuranceCoverage::CorporateLevel,
+            AssuranceCoverage::ProductLine,
+            AssuranceCoverage::PcfSystem,
+            AssuranceCoverage::ProductLevel,
+        ];
+
+        g.choose(coverage).unwrap().to_owned()
+    }
+}
+
+impl Arbitrary for AssuranceLevel {
+    fn arbitrary(g: &mut Gen) -> Self {
+        let level = &[AssuranceLevel::Limited, AssuranceLevel::Reasonable];
+
+        g.choose(level).unwrap().to_owned()
+    }
+}
+
+impl Arbitrary for AssuranceBoundary {
+    fn arbitrary(g: &mut Gen) -> Self {
+        let boundary = &[
+            AssuranceBoundary::GateToGate,
+            AssuranceBoundary::CradleToGate,
+        ];
+
+        g.choose(boundary).unwrap().to_owned()
+    }
+}
+
+impl Arbitrary for PfId {
+    fn arbitrary(g: &mut Gen) -> Self {
+        PfId(Uuid::from_u128(u128::arbitrary(g)))
+    }
+}
+
+impl Arbitrary for NonEmptyPfIdVec {
+    fn arbitrary(g: &mut Gen) -> Self {
+        NonEmptyPfIdVec(arbitrary_set(g, Arbitrary::arbitrary))
+    }
+}
+
+impl Arbitrary for SpecVersionString {
+    fn arbitrary(g: &mut Gen) -> Self {
+        let spec_version = &["2.0.0", "2.1.0", "2.2.0", "2.3.0"];
+
+        SpecVersionString(g.choose(spec_version).unwrap().to_string())
+    }
+}
+
+impl Arbitrary for VersionInteger {
+    fn arbitrary(g: &mut Gen) -> Self {
+        VersionInteger(u16::arbitrary(g) as i32)
+    }
+}
+
+impl Arbitrary for PfStatus {
+    fn arbitrary(g: &mut Gen) -> Self {
+        let status = &[PfStatus::Active, PfStatus::Deprecated];
+
+        g.choose(status).unwrap().to_owned()
+    }
+}
+
+impl Arbitrary for Urn {
+    fn arbitrary(g: &mut Gen) -> Self {
+        Urn(format!("urn:uuid:{}", Uuid::from_u128(u128::arbitrary(g))))
+    }
+}
+
+/// A `urn:uuid` URN or a custom code of the given kind, i.e. `company` or `product`
+fn arbitrary_urn(kind: &str, g: &mut Gen) -> Urn {
+    let assigner = g.choose(&["buyer-assigned", "vendor-assigned"]).unwrap();
+    if bool::arbitrary(g) {
+        Urn::arbitrary(g)
+    } else {
+        Urn(format!(
+            "urn:pathfinder:{kind}:customcode:{assigner}:{}",
+            u32::arbitrary(g)
+        ))
+    }
+}
+
+impl Arbitrary for CompanyIdSet {
+    fn arbitrary(g: &mut Gen) -> Self {
+        CompanyIdSet(arbitrary_set(g, |g| arbitrary_urn("company", g)))
+    }
+}
+
+impl Arbitrary for ProductIdSet {
+    fn arbitrary(g: &mut Gen) -> Self {
+        ProductIdSet(arbitrary_set(g, |g| arbitrary_urn("product", g)))
+    }
+}
+
+impl Arbitrary for DeclaredUnit {
+    fn arbitrary(g: &mut Gen) -> Self {
+        let declared_unit = &[
+            DeclaredUnit::Liter,
+            DeclaredUnit::Kilogram,
+            DeclaredUnit::CubicMeter,
+            DeclaredUnit::KilowattHour,
+            DeclaredUnit::Megajoule,
+            DeclaredUnit::TonKilometer,
+            DeclaredUnit::SquareMeter,
+        ];
+
+        *g.choose(declared_unit).unwrap()
+    }
+}
+
+impl Arbitrary for CharacterizationFactors {
+    fn arbitrary(g: &mut Gen) -> Self {
+        let characterization_factors =
+            &[CharacterizationFactors::Ar5, CharacterizationFactors::Ar6];
+
+        *g.choose(characterization_factors).unwrap()
+    }
+}
+
+impl Arbitrary for DeprecatedCrossSectoralStandard {
+    fn arbitrary(g: &mut Gen) -> Self {
+        let standard = &[
+            DeprecatedCrossSectoralStandard::Ghgp,
+            DeprecatedCrossSectoralStandard::ISO14067,
+            DeprecatedCrossSectoralStandard::ISO14044,
+        ];
+
+        *g.choose(standard).unwrap()
+    }
+}
+
+impl Arbitrary for CrossSectoralStandardSet {
+    fn arbitrary(g: &mut Gen) -> Self {
+        let standards = [
+            DeprecatedCrossSectoralStandard::Ghgp,
+            DeprecatedCrossSectoralStandard::ISO14067,
+            DeprecatedCrossSectoralStandard::ISO14044,
+        ];
+
+        CrossSectoralStandardSet(arbitrary_subset(&standards, g))
+    }
+}
+
+impl Arbitrary for BiogenicAccountingMethodology {
+    fn arbitrary(g: &mut Gen) -> Self {
+        let methodology = &[
+            BiogenicAccountingMethodology::Pef,
+            BiogenicAccountingMethodology::Iso,
+            BiogenicAccountingMethodology::Ghgp,
+            BiogenicAccountingMethodology::Quantis,
+        ];
+
+        g.choose(methodology).unwrap().to_owned()
+    }
+}
+
+impl Arbitrary for ProductOrSectorSpecificRuleOperator {
+    fn arbitrary(g: &mut Gen) -> Self {
+        let operator = &[
+            ProductOrSectorSpecificRuleOperator::Pef,
+            ProductOrSectorSpecificRuleOperator::EPDInternational,
+            ProductOrSectorSpecificRuleOperator::Other,
+        ];
+
+        g.choose(operator).unwrap().to_owned()
+    }
+}
+
+impl Arbitrary for ProductOrSectorSpecificRule {
+    fn arbitrary(g: &mut Gen) -> Self {
+        let operator = ProductOrSectorSpecificRuleOperator::arbitrary(g);
+        let other_operator_name = match operator {
+            ProductOrSectorSpecificRuleOperator::Other => {
+                Some(NonEmptyString(arbitrary_name("Operator", g)))
+            }
+            _ => None,
+        };
+
+        ProductOrSectorSpecificRule {
+            operator,
+            rule_names: NonEmptyStringVec(arbitrary_set(g, |g| {
+                NonEmptyString(arbitrary_name("Rule", g))
+            })),
+            other_operator_name,
+        }
+    }
+}
+
+impl Arbitrary for ProductOrSectorSpecificRuleSet {
+    fn arbitrary(g: &mut Gen) -> Self {
+        ProductOrSectorSpecificRuleSet(arbitrary_set(g, Arbitrary::arbitrary))
+    }
+}
+
+impl Arbitrary for EmissionFactorDS {
+    fn arbitrary(g: &mut Gen) -> Self {
+        let name = &["ecoinvent", "GaBi", "Agribalyse", "GLEC"];
+
+        EmissionFactorDS {
+            name: NonEmptyString(g.choose(name).unwrap().to_string()),
+            version: NonEmptyString(format!("{}.{}", u8::arbitrary(g) % 10, u8::arbitrary(g))),
+        }
+    }
+}
+
+impl Arbitrary for EmissionFactorDSSet {
+    fn arbitrary(g: &mut Gen) -> Self {
+        EmissionFactorDSSet(arbitrary_set(g, Arbitrary::arbitrary))
+    }
+}
+
+impl Arbitrary for UNRegionOrSubregion {
+    fn arbitrary(g: &mut Gen) -> Self {
+        use UNRegionOrSubregion::*;
+        let region = &[
+            Africa,
+            Americas,
+            Asia,
+            Europe,
+            Oceania,
+            AustraliaAndNewZealand,
+            CentralAsia,
+            EasternAsia,
+            EasternEurope,
+            LatinAmericaAndTheCaribbean,
+            Melanesia,
+            Micronesia,
+            NorthernAfrica,
+            NorthernAmerica,
+            NorthernEurope,
+            Polynesia,
+            SouthEasternAsia,
+            SouthernAsia,
+            SouthernEurope,
+            SubSaharanAfrica,
+            WesternAsia,
+            WesternEurope,
+        ];
+
+        g.choose(region).unwrap().to_owned()
+    }
+}
+
+impl Arbitrary for ISO3166CC {
+    fn arbitrary(g: &mut Gen) -> Self {
+        ISO3166CC(g.choose(iso3166::countries()).unwrap().code.to_string())
+    }
+}
+
+impl Arbitrary for PositiveDecimal {
+    fn arbitrary(g: &mut Gen) -> Self {
+        PositiveDecimal(arbitrary_decimal(g))
+    }
+}
+
+impl Arbitrary for StrictlyPositiveDecimal {
+    fn arbitrary(g: &mut Gen) -> Self {
+        let mantissa = u32::arbitrary(g).max(1) as i64;
+        StrictlyPositiveDecimal(Decimal::new(mantissa, u32::arbitrary(g) % 5))
+    }
+}
+
+impl Arbitrary for NegativeDecimal {
+    fn arbitrary(g: &mut Gen) -> Self {
+        NegativeDecimal(-arbitrary_decimal(g))
+    }
+}
+
+impl Arbitrary for WrappedDecimal {
+    fn arbitrary(g: &mut Gen) -> Self {
+        WrappedDecimal(Decimal::new(
+            i32::arbitrary(g) as i64,
+            u32::arbitrary(g) % 5,
+        ))
+    }
+}
+
+impl Arbitrary for ExemptedEmissionsPercent {
+    fn arbitrary(g: &mut Gen) -> Self {
+        ExemptedEmissionsPercent((u16::arbitrary(g) % 501) as f64 / 100.0)
+    }
+}
+
+impl Arbitrary for Percent {
+    fn arbitrary(g: &mut Gen) -> Self {
+        Percent((u16::arbitrary(g) % 10001) as f64 / 100.0)
+    }
+}
+
+impl Arbitrary for FloatBetween1and3 {
+    fn arbitrary(g: &mut Gen) -> Self {
+        FloatBetween1and3(1.0 + (u8::arbitrary(g) % 201) as f32 / 100.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use quickcheck::{QuickCheck, TestResult};
+    use std::collections::BTreeMap;
+
+    /// Extension data must be a JSON object
+    type Extension = BTreeMap<String, u8>;
+
+    fn prop_valid(pf: ProductFootprint<Extension>) -> TestResult {
+        let value = serde_json::to_value(&pf).unwrap();
+        let roundtrip: ProductFootprint<Extension> = serde_json::from_value(value.clone()).unwrap();
+
+        TestResult::from_bool(
+            pf.validate().is_empty()
+                && pf.pcf.validate().is_empty()
+                && validate_json(&value).is_ok()
+                && roundtrip == pf,
+        )
+    }
+
+    #[test]
+    fn test_arbitrary_footprints_are_valid() {
+        QuickCheck::new()
+            .tests(100)
+            .quickcheck(prop_valid as fn(ProductFootprint<Extension>) -> TestResult);
+    }
+}
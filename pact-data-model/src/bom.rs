@@ -0,0 +1,380 @@
+/*
+ * Copyright (c) 2024 SINE Foundation e.V.
+ *
+ * This software is released under the MIT License, see LICENSE.
+ */
+
+//! Aggregation of the footprints of the components of a bill of materials into the cradle-to-gate
+//! `CarbonFootprint` of the composite product.
+//!
+//! Each component contributes its footprint rescaled to the quantity used in the composite (see
+//! `CarbonFootprint::rescale`). `primaryDataShare`, `dqi` and `exemptedEmissionsPercent` are
+//! averaged, weighted by the components' share of the composite's `pCfExcludingBiogenic`.
+
+use rust_decimal::prelude::ToPrimitive;
+use rust_decimal::Decimal;
+use schemars::JsonSchema;
+
+use crate::{
+    CarbonFootprint, CrossSectoralStandardSet, DataQualityIndicators, DeclaredUnit,
+    EmissionFactorDSSet, ExemptedEmissionsPercent, FloatBetween1and3,
+    IpccCharacterizationFactorsSources, NegativeDecimal, Percent, PositiveDecimal,
+    ProductFootprint, RescaleError, StrictlyPositiveDecimal, WrappedDecimal,
+};
+
+#[derive(Debug, Clone)]
+/// A component of a bill of materials
+pub struct BomComponent<'a, T: JsonSchema> {
+    pub footprint: &'a ProductFootprint<T>,
+    /// Quantity of the component in the composite, in the component's declared unit
+    pub quantity: StrictlyPositiveDecimal,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+/// Error returned by `aggregate_bom`
+pub enum BomError {
+    /// The bill of materials has no components
+    NoComponents,
+    /// The component at the given index uses different `characterizationFactors` than the first
+    /// component
+    InconsistentCharacterizationFactors(usize),
+    /// The footprint of the component at the given index could not be rescaled to its quantity
+    Rescale(usize, RescaleError),
+}
+
+impl std::fmt::Display for BomError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            BomError::NoComponents => f.write_str("bill of materials has no components"),
+            BomError::InconsistentCharacterizationFactors(i) => write!(
+                f,
+                "component {i} uses different characterization factors than component 0"
+            ),
+            BomError::Rescale(i, e) => write!(f, "component {i}: {e}"),
+        }
+    }
+}
+
+impl std::error::Error for BomError {}
+
+/// Aggregates the footprints of `components` into the cradle-to-gate footprint of
+/// `unitary_product_amount` units of the composite product, declared in `declared_unit`.
+///
+/// All components must use the same `characterizationFactors`. Emission and carbon content
+/// values are summed; optional values are only defined if defined by any component, except for
+/// `pCfIncludingBiogenic` and `packagingGhgEmissions`, which must be defined by all components.
+/// The reference period spans the reference periods of all components, and the geographic scope
+/// is only kept if it is the same for all components.
+pub fn aggregate_bom<T: JsonSchema>(
+    components: &[BomComponent<'_, T>],
+    declared_unit: DeclaredUnit,
+    unitary_product_amount: StrictlyPositiveDecimal,
+) -> Result<CarbonFootprint, BomError> {
+    let Some(first) = components.first() else {
+        return Err(BomError::NoComponents);
+    };
+    let first = &first.footprint.pcf;
+
+    let mut pcfs = Vec::with_capacity(components.len());
+    for (i, component) in components.iter().enumerate() {
+        let pcf = &component.footprint.pcf;
+        if pcf.characterization_factors != first.characterization_factors {
+            return Err(BomError::InconsistentCharacterizationFactors(i));
+        }
+        pcfs.push(
+            pcf.rescale(component.quantity.clone())
+                .map_err(|e| BomError::Rescale(i, e))?,
+        );
+    }
+
+    let sum = |f: &dyn Fn(&CarbonFootprint) -> Decimal| pcfs.iter().map(f).sum::<Decimal>();
+    let sum_optional = |f: &dyn Fn(&CarbonFootprint) -> Option<Decimal>| {
+        let values: Vec<Decimal> = pcfs.iter().filter_map(f).collect();
+        (!values.is_empty()).then(|| values.into_iter().sum::<Decimal>())
+    };
+    let sum_all = |f: &dyn Fn(&CarbonFootprint) -> Option<Decimal>| {
+        pcfs.iter().map(f).sum::<Option<Decimal>>()
+    };
+
+    let p_cf_excluding_biogenic = sum(&|pcf| pcf.p_cf_excluding_biogenic.0);
+
+    // weights of the components by their share of the composite's emissions, falling back to
+    // equal weights if the composite has no emissions at all
+    let weights: Vec<f64> = if p_cf_excluding_biogenic.is_zero() {
+        vec![1.0 / pcfs.len() as f64; pcfs.len()]
+    } else {
+        pcfs.iter()
+            .map(|pcf| {
+                (pcf.p_cf_excluding_biogenic.0 / p_cf_excluding_biogenic)
+                    .to_f64()
+                    .unwrap_or_default()
+            })
+            .collect()
+    };
+    let weighted = |f: &dyn Fn(&CarbonFootprint) -> Option<f64>| {
+        pcfs.iter()
+            .zip(&weights)
+            .map(|(pcf, w)| f(pcf).map(|v| v * w))
+            .sum::<Option<f64>>()
+    };
+
+    let primary_data_share = weighted(&|pcf| pcf.primary_data_share.as_ref().map(|p| p.0));
+    let dqi = pcfs.iter().all(|pcf| pcf.dqi.is_some()).then(|| {
+        let dqr = |f: &dyn Fn(&DataQualityIndicators) -> f32| {
+            let dqr = weighted(&|pcf| pcf.dqi.as_ref().map(|dqi| f(dqi) as f64));
+            // weights sum up to 1, but rounding may push the result slightly out of [1, 3]
+            FloatBetween1and3((dqr.unwrap_or(1.0) as f32).clamp(1.0, 3.0))
+        };
+        DataQualityIndicators {
+            coverage_percent: Percent(
+                weighted(&|pcf| pcf.dqi.as_ref().map(|dqi| dqi.coverage_percent.0))
+                    .unwrap_or_default()
+                    .clamp(0.0, 100.0),
+            ),
+            technological_d_q_r: dqr(&|dqi| dqi.technological_d_q_r.0),
+            temporal_d_q_r: dqr(&|dqi| dqi.temporal_d_q_r.0),
+            geographical_d_q_r: dqr(&|dqi| dqi.geographical_d_q_r.0),
+            completeness_d_q_r: dqr(&|dqi| dqi.completeness_d_q_r.0),
+            reliability_d_q_r: dqr(&|dqi| dqi.reliability_d_q_r.0),
+        }
+    });
+
+    let packaging_emissions_included = pcfs.iter().all(|pcf| pcf.packaging_emissions_included);
+
+    Ok(CarbonFootprint {
+        declared_unit,
+        unitary_product_amount,
+        p_cf_excluding_biogenic: PositiveDecimal(p_cf_excluding_biogenic),
+        p_cf_including_biogenic: sum_all(&|pcf| pcf.p_cf_including_biogenic.as_ref().map(|d| d.0))
+            .map(WrappedDecimal),
+        fossil_ghg_emissions: PositiveDecimal(sum(&|pcf| pcf.fossil_ghg_emissions.0)),
+        fossil_carbon_content: PositiveDecimal(sum(&|pcf| pcf.fossil_carbon_content.0)),
+        biogenic_carbon_content: PositiveDecimal(sum(&|pcf| pcf.biogenic_carbon_content.0)),
+        d_luc_ghg_emissions: sum_optional(&|pcf| pcf.d_luc_ghg_emissions.as_ref().map(|d| d.0))
+            .map(PositiveDecimal),
+        land_management_ghg_emissions: sum_optional(&|pcf| {
+            pcf.land_management_ghg_emissions.as_ref().map(|d| d.0)
+        })
+        .map(PositiveDecimal),
+        other_biogenic_ghg_emissions: sum_optional(&|pcf| {
+            pcf.other_biogenic_ghg_emissions.as_ref().map(|d| d.0)
+        })
+        .map(PositiveDecimal),
+        i_luc_ghg_emissions: sum_optional(&|pcf| pcf.i_luc_ghg_emissions.as_ref().map(|d| d.0))
+            .map(PositiveDecimal),
+        biogenic_carbon_withdrawal: sum_optional(&|pcf| {
+            pcf.biogenic_carbon_withdrawal.as_ref().map(|d| d.0)
+        })
+        .map(NegativeDecimal),
+        aircraft_ghg_emissions: sum_optional(&|pcf| {
+            pcf.aircraft_ghg_emissions.as_ref().map(|d| d.0)
+        })
+        .map(PositiveDecimal),
+        characterization_factors: first.characterization_factors,
+        ipcc_characterization_factors_sources: IpccCharacterizationFactorsSources(union(
+            pcfs.iter()
+                .map(|pcf| &pcf.ipcc_characterization_factors_sources.0),
+        )),
+        cross_sectoral_standards_used: CrossSectoralStandardSet(union(
+            pcfs.iter().map(|pcf| &pcf.cross_sectoral_standards_used.0),
+        )),
+        product_or_sector_specific_rules: None,
+        biogenic_accounting_methodology: same(
+            pcfs.iter().map(|pcf| &pcf.biogenic_accounting_methodology),
+        )
+        .flatten(),
+        boundary_processes_description: format!(
+            "Cradle-to-gate aggregation of {} components",
+            pcfs.len()
+        ),
+        reference_period_start: pcfs
+            .iter()
+            .map(|pcf| pcf.reference_period_start)
+            .min()
+            .unwrap_or(first.reference_period_start),
+        reference_period_end: pcfs
+            .iter()
+            .map(|pcf| pcf.reference_period_end)
+            .max()
+            .unwrap_or(first.reference_period_end),
+        geographic_scope: same(pcfs.iter().map(|pcf| &pcf.geographic_scope)).flatten(),
+        secondary_emission_factor_sources: Some(union(
+            pcfs.iter()
+                .filter_map(|pcf| pcf.secondary_emission_factor_sources.as_ref())
+                .map(|sources| &sources.0),
+        ))
+        .filter(|sources| !sources.is_empty())
+        .map(EmissionFactorDSSet),
+        exempted_emissions_percent: ExemptedEmissionsPercent(
+            weighted(&|pcf| Some(pcf.exempted_emissions_percent.0))
+                .unwrap_or_default()
+                .clamp(0.0, 5.0),
+        ),
+        exempted_emissions_description: String::new(),
+        packaging_emissions_included,
+        packaging_ghg_emissions: sum_all(&|pcf| pcf.packaging_ghg_emissions.as_ref().map(|d| d.0))
+            .filter(|_| packaging_emissions_included)
+            .map(PositiveDecimal),
+        allocation_rules_description: None,
+        uncertainty_assessment_description: None,
+        primary_data_share: primary_data_share.map(|p| Percent(p.clamp(0.0, 100.0))),
+        dqi,
+        assurance: None,
+    })
+}
+
+/// all distinct items of `lists`, in order of their first occurrence
+fn union<'a, I: Clone + PartialEq + 'a>(lists: impl Iterator<Item = &'a Vec<I>>) -> Vec<I> {
+    let mut union: Vec<I> = vec![];
+    for item in lists.flatten() {
+        if !union.contains(item) {
+            union.push(item.clone());
+        }
+    }
+    union
+}
+
+/// the common value of all `values`, `None` if they differ
+fn same<'a, I: Clone + PartialEq + 'a>(mut values: impl Iterator<Item = &'a I>) -> Option<I> {
+    let first = values.next()?;
+    values.all(|v| v == first).then(|| first.clone())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_data::example_pf;
+    use crate::{CharacterizationFactors, EmissionFactorDS, NonEmptyString};
+    use rust_decimal_macros::dec;
+
+    fn ef(name: &str) -> EmissionFactorDS {
+        EmissionFactorDS {
+            name: NonEmptyString(name.to_string()),
+            version: NonEmptyString("1.0".to_string()),
+        }
+    }
+
+    #[test]
+    fn test_aggregate_bom() {
+        let mut a = example_pf();
+        a.pcf.p_cf_excluding_biogenic = PositiveDecimal(dec!(2));
+        a.pcf.fossil_ghg_emissions = PositiveDecimal(dec!(2));
+        a.pcf.d_luc_ghg_emissions = Some(PositiveDecimal(dec!(0.5)));
+        a.pcf.primary_data_share = Some(Percent(100.0));
+        a.pcf.secondary_emission_factor_sources = Some(EmissionFactorDSSet(vec![ef("ecoinvent")]));
+
+        let mut b = example_pf();
+        b.pcf.declared_unit = DeclaredUnit::Liter;
+        b.pcf.unitary_product_amount = StrictlyPositiveDecimal(dec!(2));
+        b.pcf.p_cf_excluding_biogenic = PositiveDecimal(dec!(4));
+        b.pcf.fossil_ghg_emissions = PositiveDecimal(dec!(3));
+        b.pcf.aircraft_ghg_emissions = Some(PositiveDecimal(dec!(1)));
+        b.pcf.primary_data_share = Some(Percent(0.0));
+        b.pcf.secondary_emission_factor_sources =
+            Some(EmissionFactorDSSet(vec![ef("GaBi"), ef("ecoinvent")]));
+
+        let composite = aggregate_bom(
+            &[
+                BomComponent {
+                    footprint: &a,
+                    quantity: StrictlyPositiveDecimal(dec!(3)),
+                },
+                BomComponent {
+                    footprint: &b,
+                    quantity: StrictlyPositiveDecimal(dec!(1)),
+                },
+            ],
+            DeclaredUnit::Kilogram,
+            StrictlyPositiveDecimal(Decimal::ONE),
+        )
+        .unwrap();
+
+        // 3 * 2 + 1 / 2 * 4
+        assert_eq!(composite.p_cf_excluding_biogenic.0, dec!(8));
+        assert_eq!(composite.fossil_ghg_emissions.0, dec!(7.5));
+        assert_eq!(composite.d_luc_ghg_emissions.as_ref().unwrap().0, dec!(1.5));
+        assert_eq!(
+            composite.aircraft_ghg_emissions.as_ref().unwrap().0,
+            dec!(0.5)
+        );
+        assert_eq!(composite.other_biogenic_ghg_emissions, None);
+        assert_eq!(composite.primary_data_share, Some(Percent(75.0)));
+        assert_eq!(composite.dqi, None);
+        assert_eq!(
+            composite.secondary_emission_factor_sources,
+            Some(EmissionFactorDSSet(vec![ef("ecoinvent"), ef("GaBi")]))
+        );
+        assert!(composite.validate().is_empty());
+    }
+
+    #[test]
+    fn test_aggregate_bom_dqi() {
+        let dqi = |coverage, dqr| DataQualityIndicators {
+            coverage_percent: Percent(coverage),
+            technological_d_q_r: FloatBetween1and3(dqr),
+            temporal_d_q_r: FloatBetween1and3(dqr),
+            geographical_d_q_r: FloatBetween1and3(dqr),
+            completeness_d_q_r: FloatBetween1and3(dqr),
+            reliability_d_q_r: FloatBetween1and3(dqr),
+        };
+
+        let mut a = example_pf();
+        a.pcf.dqi = Some(dqi(100.0, 1.0));
+        let mut b = example_pf();
+        b.pcf.p_cf_excluding_biogenic = PositiveDecimal(dec!(3));
+        b.pcf.dqi = Some(dqi(60.0, 3.0));
+
+        let components = [
+            BomComponent {
+                footprint: &a,
+                quantity: StrictlyPositiveDecimal(Decimal::ONE),
+            },
+            BomComponent {
+                footprint: &b,
+                quantity: StrictlyPositiveDecimal(Decimal::ONE),
+            },
+        ];
+        let composite = aggregate_bom(
+            &components,
+            DeclaredUnit::Kilogram,
+            StrictlyPositiveDecimal(dec!(2)),
+        )
+        .unwrap();
+
+        assert_eq!(composite.dqi, Some(dqi(70.0, 2.5)));
+    }
+
+    #[test]
+    fn test_aggregate_bom_errors() {
+        let a = example_pf();
+        let mut b = example_pf();
+        b.pcf.characterization_factors = CharacterizationFactors::Ar5;
+
+        let components = [
+            BomComponent {
+                footprint: &a,
+                quantity: StrictlyPositiveDecimal(Decimal::ONE),
+            },
+            BomComponent {
+                footprint: &b,
+                quantity: StrictlyPositiveDecimal(Decimal::ONE),
+            },
+        ];
+        assert_eq!(
+            aggregate_bom(
+                &components,
+                DeclaredUnit::Kilogram,
+                StrictlyPositiveDecimal(Decimal::ONE)
+            ),
+            Err(BomError::InconsistentCharacterizationFactors(1))
+        );
+        assert_eq!(
+            aggregate_bom::<serde_json::Value>(
+                &[],
+                DeclaredUnit::Kilogram,
+                StrictlyPositiveDecimal(Decimal::ONE)
+            ),
+            Err(BomError::NoComponents)
+        );
+    }
+}
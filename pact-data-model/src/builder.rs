@@ -0,0 +1,598 @@
+/*
+ * Copyright (c) 2024 SINE Foundation e.V.
+ *
+ * This software is released under the MIT License, see LICENSE.
+ */
+
+//! Builders for `ProductFootprint`s and `CarbonFootprint`s.
+//!
+//! Properties with a default of the Tech Spec (e.g. `specVersion`, `version` or `status`) and
+//! optional properties can be omitted. All other mandatory properties must be set before calling
+//! `build`, which otherwise fails with a `MissingFieldsError` listing them.
+
+use chrono::{DateTime, Utc};
+use schemars::JsonSchema;
+use uuid::Uuid;
+
+use crate::{
+    Assurance, BiogenicAccountingMethodology, CarbonFootprint, CharacterizationFactors,
+    CompanyIdSet, CrossSectoralStandardSet, DataModelExtension, DataQualityIndicators,
+    DeclaredUnit, EmissionFactorDSSet, ExemptedEmissionsPercent, GeographicScope,
+    IpccCharacterizationFactorsSources, NegativeDecimal, NonEmptyPfIdVec, NonEmptyString, Percent,
+    PfId, PfStatus, PositiveDecimal, ProductFootprint, ProductIdSet,
+    ProductOrSectorSpecificRuleSet, SpecVersionString, StrictlyPositiveDecimal, VersionInteger,
+    WrappedDecimal,
+};
+
+/// Spec Version set by `ProductFootprintBuilder` unless specified otherwise
+pub const DEFAULT_SPEC_VERSION: &str = "2.2.0";
+
+#[derive(Debug, Clone, PartialEq)]
+/// Error returned by `build` if mandatory properties without a default have not been set
+pub struct MissingFieldsError {
+    /// The names of the missing properties, as used in JSON
+    pub fields: Vec<&'static str>,
+}
+
+impl std::fmt::Display for MissingFieldsError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "missing mandatory fields: {}", self.fields.join(", "))
+    }
+}
+
+impl std::error::Error for MissingFieldsError {}
+
+#[derive(Debug, Clone)]
+/// Builder for `ProductFootprint`s, see `ProductFootprint::builder`
+pub struct ProductFootprintBuilder<T: JsonSchema> {
+    id: PfId,
+    spec_version: SpecVersionString,
+    preceding_pf_ids: Option<NonEmptyPfIdVec>,
+    version: VersionInteger,
+    created: Option<DateTime<Utc>>,
+    updated: Option<DateTime<Utc>>,
+    status: PfStatus,
+    status_comment: Option<String>,
+    validity_period_start: Option<DateTime<Utc>>,
+    validity_period_end: Option<DateTime<Utc>>,
+    company_name: Option<NonEmptyString>,
+    company_ids: Option<CompanyIdSet>,
+    product_description: String,
+    product_ids: Option<ProductIdSet>,
+    product_category_cpc: Option<NonEmptyString>,
+    product_name_company: Option<NonEmptyString>,
+    comment: String,
+    pcf: Option<CarbonFootprint>,
+    extensions: Option<Vec<DataModelExtension<T>>>,
+}
+
+#[derive(Debug, Clone, Default)]
+/// Builder for `CarbonFootprint`s, see `CarbonFootprint::builder`
+pub struct CarbonFootprintBuilder {
+    declared_unit: Option<DeclaredUnit>,
+    unitary_product_amount: Option<StrictlyPositiveDecimal>,
+    p_cf_excluding_biogenic: Option<PositiveDecimal>,
+    p_cf_including_biogenic: Option<WrappedDecimal>,
+    fossil_ghg_emissions: Option<PositiveDecimal>,
+    fossil_carbon_content: Option<PositiveDecimal>,
+    biogenic_carbon_content: Option<PositiveDecimal>,
+    d_luc_ghg_emissions: Option<PositiveDecimal>,
+    land_management_ghg_emissions: Option<PositiveDecimal>,
+    other_biogenic_ghg_emissions: Option<PositiveDecimal>,
+    i_luc_ghg_emissions: Option<PositiveDecimal>,
+    biogenic_carbon_withdrawal: Option<NegativeDecimal>,
+    aircraft_ghg_emissions: Option<PositiveDecimal>,
+    characterization_factors: Option<CharacterizationFactors>,
+    ipcc_characterization_factors_sources: Option<IpccCharacterizationFactorsSources>,
+    cross_sectoral_standards_used: Option<CrossSectoralStandardSet>,
+    product_or_sector_specific_rules: Option<ProductOrSectorSpecificRuleSet>,
+    biogenic_accounting_methodology: Option<BiogenicAccountingMethodology>,
+    boundary_processes_description: String,
+    reference_period_start: Option<DateTime<Utc>>,
+    reference_period_end: Option<DateTime<Utc>>,
+    geographic_scope: Option<GeographicScope>,
+    secondary_emission_factor_sources: Option<EmissionFactorDSSet>,
+    exempted_emissions_percent: Option<ExemptedEmissionsPercent>,
+    exempted_emissions_description: String,
+    packaging_emissions_included: Option<bool>,
+    packaging_ghg_emissions: Option<PositiveDecimal>,
+    allocation_rules_description: Option<String>,
+    uncertainty_assessment_description: Option<String>,
+    primary_data_share: Option<Percent>,
+    dqi: Option<DataQualityIndicators>,
+    assurance: Option<Assurance>,
+}
+
+impl<T: JsonSchema> ProductFootprint<T> {
+    /// Returns a builder with a fresh `id`, `specVersion` 2.2.0, `version` 1, status `Active`,
+    /// and an empty `productDescription` and `comment`.
+    pub fn builder() -> ProductFootprintBuilder<T> {
+        ProductFootprintBuilder::new()
+    }
+}
+
+impl CarbonFootprint {
+    /// Returns a builder with an empty `boundaryProcessesDescription` and
+    /// `exemptedEmissionsDescription`.
+    pub fn builder() -> CarbonFootprintBuilder {
+        CarbonFootprintBuilder::default()
+    }
+}
+
+impl<T: JsonSchema> Default for ProductFootprintBuilder<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: JsonSchema> ProductFootprintBuilder<T> {
+    pub fn new() -> Self {
+        ProductFootprintBuilder {
+            id: PfId(Uuid::new_v4()),
+            spec_version: SpecVersionString(DEFAULT_SPEC_VERSION.to_string()),
+            preceding_pf_ids: None,
+            version: VersionInteger(1),
+            created: None,
+            updated: None,
+            status: PfStatus::Active,
+            status_comment: None,
+            validity_period_start: None,
+            validity_period_end: None,
+            company_name: None,
+            company_ids: None,
+            product_description: String::new(),
+            product_ids: None,
+            product_category_cpc: None,
+            product_name_company: None,
+            comment: String::new(),
+            pcf: None,
+            extensions: None,
+        }
+    }
+
+    pub fn id(mut self, id: PfId) -> Self {
+        self.id = id;
+        self
+    }
+
+    pub fn spec_version(mut self, spec_version: SpecVersionString) -> Self {
+        self.spec_version = spec_version;
+        self
+    }
+
+    pub fn preceding_pf_ids(mut self, preceding_pf_ids: NonEmptyPfIdVec) -> Self {
+        self.preceding_pf_ids = Some(preceding_pf_ids);
+        self
+    }
+
+    pub fn version(mut self, version: VersionInteger) -> Self {
+        self.version = version;
+        self
+    }
+
+    pub fn created(mut self, created: DateTime<Utc>) -> Self {
+        self.created = Some(created);
+        self
+    }
+
+    pub fn updated(mut self, updated: DateTime<Utc>) -> Self {
+        self.updated = Some(updated);
+        self
+    }
+
+    pub fn status(mut self, status: PfStatus) -> Self {
+        self.status = status;
+        self
+    }
+
+    pub fn status_comment(mut self, status_comment: impl Into<String>) -> Self {
+        self.status_comment = Some(status_comment.into());
+        self
+    }
+
+    pub fn validity_period_start(mut self, validity_period_start: DateTime<Utc>) -> Self {
+        self.validity_period_start = Some(validity_period_start);
+        self
+    }
+
+    pub fn validity_period_end(mut self, validity_period_end: DateTime<Utc>) -> Self {
+        self.validity_period_end = Some(validity_period_end);
+        self
+    }
+
+    pub fn company_name(mut self, company_name: NonEmptyString) -> Self {
+        self.company_name = Some(company_name);
+        self
+    }
+
+    pub fn company_ids(mut self, company_ids: CompanyIdSet) -> Self {
+        self.company_ids = Some(company_ids);
+        self
+    }
+
+    pub fn product_description(mut self, product_description: impl Into<String>) -> Self {
+        self.product_description = product_description.into();
+        self
+    }
+
+    pub fn product_ids(mut self, product_ids: ProductIdSet) -> Self {
+        self.product_ids = Some(product_ids);
+        self
+    }
+
+    pub fn product_category_cpc(mut self, product_category_cpc: NonEmptyString) -> Self {
+        self.product_category_cpc = Some(product_category_cpc);
+        self
+    }
+
+    pub fn product_name_company(mut self, product_name_company: NonEmptyString) -> Self {
+        self.product_name_company = Some(product_name_company);
+        self
+    }
+
+    pub fn comment(mut self, comment: impl Into<String>) -> Self {
+        self.comment = comment.into();
+        self
+    }
+
+    pub fn pcf(mut self, pcf: CarbonFootprint) -> Self {
+        self.pcf = Some(pcf);
+        self
+    }
+
+    pub fn extensions(mut self, extensions: Vec<DataModelExtension<T>>) -> Self {
+        self.extensions = Some(extensions);
+        self
+    }
+
+    /// Builds the `ProductFootprint`, failing if any of `created`, `companyName`, `companyIds`,
+    /// `productIds`, `productCategoryCpc`, `productNameCompany` or `pcf` has not been set.
+    pub fn build(self) -> Result<ProductFootprint<T>, MissingFieldsError> {
+        let mut missing = vec![];
+        if self.created.is_none() {
+            missing.push("created");
+        }
+        if self.company_name.is_none() {
+            missing.push("companyName");
+        }
+        if self.company_ids.is_none() {
+            missing.push("companyIds");
+        }
+        if self.product_ids.is_none() {
+            missing.push("productIds");
+        }
+        if self.product_category_cpc.is_none() {
+            missing.push("productCategoryCpc");
+        }
+        if self.product_name_company.is_none() {
+            missing.push("productNameCompany");
+        }
+        if self.pcf.is_none() {
+            missing.push("pcf");
+        }
+
+        if !missing.is_empty() {
+            return Err(MissingFieldsError { fields: missing });
+        }
+
+        // all unwraps are covered by the checks above
+        Ok(ProductFootprint {
+            id: self.id,
+            spec_version: self.spec_version,
+            preceding_pf_ids: self.preceding_pf_ids,
+            version: self.version,
+            created: self.created.unwrap(),
+            updated: self.updated,
+            status: self.status,
+            status_comment: self.status_comment,
+            validity_period_start: self.validity_period_start,
+            validity_period_end: self.validity_period_end,
+            company_name: self.company_name.unwrap(),
+            company_ids: self.company_ids.unwrap(),
+            product_description: self.product_description,
+            product_ids: self.product_ids.unwrap(),
+            product_category_cpc: self.product_category_cpc.unwrap(),
+            product_name_company: self.product_name_company.unwrap(),
+            comment: self.comment,
+            pcf: self.pcf.unwrap(),
+            extensions: self.extensions,
+        })
+    }
+}
+
+impl CarbonFootprintBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn declared_unit(mut self, declared_unit: DeclaredUnit) -> Self {
+        self.declared_unit = Some(declared_unit);
+        self
+    }
+
+    pub fn unitary_product_amount(
+        mut self,
+        unitary_product_amount: StrictlyPositiveDecimal,
+    ) -> Self {
+        self.unitary_product_amount = Some(unitary_product_amount);
+        self
+    }
+
+    pub fn p_cf_excluding_biogenic(mut self, p_cf_excluding_biogenic: PositiveDecimal) -> Self {
+        self.p_cf_excluding_biogenic = Some(p_cf_excluding_biogenic);
+        self
+    }
+
+    pub fn p_cf_including_biogenic(mut self, p_cf_including_biogenic: WrappedDecimal) -> Self {
+        self.p_cf_including_biogenic = Some(p_cf_including_biogenic);
+        self
+    }
+
+    pub fn fossil_ghg_emissions(mut self, fossil_ghg_emissions: PositiveDecimal) -> Self {
+        self.fossil_ghg_emissions = Some(fossil_ghg_emissions);
+        self
+    }
+
+    pub fn fossil_carbon_content(mut self, fossil_carbon_content: PositiveDecimal) -> Self {
+        self.fossil_carbon_content = Some(fossil_carbon_content);
+        self
+    }
+
+    pub fn biogenic_carbon_content(mut self, biogenic_carbon_content: PositiveDecimal) -> Self {
+        self.biogenic_carbon_content = Some(biogenic_carbon_content);
+        self
+    }
+
+    pub fn d_luc_ghg_emissions(mut self, d_luc_ghg_emissions: PositiveDecimal) -> Self {
+        self.d_luc_ghg_emissions = Some(d_luc_ghg_emissions);
+        self
+    }
+
+    pub fn land_management_ghg_emissions(
+        mut self,
+        land_management_ghg_emissions: PositiveDecimal,
+    ) -> Self {
+        self.land_management_ghg_emissions = Some(land_management_ghg_emissions);
+        self
+    }
+
+    pub fn other_biogenic_ghg_emissions(
+        mut self,
+        other_biogenic_ghg_emissions: PositiveDecimal,
+    ) -> Self {
+        self.other_biogenic_ghg_emissions = Some(other_biogenic_ghg_emissions);
+        self
+    }
+
+    pub fn i_luc_ghg_emissions(mut self, i_luc_ghg_emissions: PositiveDecimal) -> Self {
+        self.i_luc_ghg_emissions = Some(i_luc_ghg_emissions);
+        self
+    }
+
+    pub fn biogenic_carbon_withdrawal(
+        mut self,
+        biogenic_carbon_withdrawal: NegativeDecimal,
+    ) -> Self {
+        self.biogenic_carbon_withdrawal = Some(biogenic_carbon_withdrawal);
+        self
+    }
+
+    pub fn aircraft_ghg_emissions(mut self, aircraft_ghg_emissions: PositiveDecimal) -> Self {
+        self.aircraft_ghg_emissions = Some(aircraft_ghg_emissions);
+        self
+    }
+
+    pub fn characterization_factors(
+        mut self,
+        characterization_factors: CharacterizationFactors,
+    ) -> Self {
+        self.characterization_factors = Some(characterization_factors);
+        self
+    }
+
+    pub fn ipcc_characterization_factors_sources(
+        mut self,
+        ipcc_characterization_factors_sources: IpccCharacterizationFactorsSources,
+    ) -> Self {
+        self.ipcc_characterization_factors_sources = Some(ipcc_characterization_factors_sources);
+        self
+    }
+
+    pub fn cross_sectoral_standards_used(
+        mut self,
+        cross_sectoral_standards_used: CrossSectoralStandardSet,
+    ) -> Self {
+        self.cross_sectoral_standards_used = Some(cross_sectoral_standards_used);
+        self
+    }
+
+    pub fn product_or_sector_specific_rules(
+        mut self,
+        product_or_sector_specific_rules: ProductOrSectorSpecificRuleSet,
+    ) -> Self {
+        self.product_or_sector_specific_rules = Some(product_or_sector_specific_rules);
+        self
+    }
+
+    pub fn biogenic_accounting_methodology(
+        mut self,
+        biogenic_accounting_methodology: BiogenicAccountingMethodology,
+    ) -> Self {
+        self.biogenic_accounting_methodology = Some(biogenic_accounting_methodology);
+        self
+    }
+
+    pub fn boundary_processes_description(
+        mut self,
+        boundary_processes_description: impl Into<String>,
+    ) -> Self {
+        self.boundary_processes_description = boundary_processes_description.into();
+        self
+    }
+
+    pub fn reference_period_start(mut self, reference_period_start: DateTime<Utc>) -> Self {
+        self.reference_period_start = Some(reference_period_start);
+        self
+    }
+
+    pub fn reference_period_end(mut self, reference_period_end: DateTime<Utc>) -> Self {
+        self.reference_period_end = Some(reference_period_end);
+        self
+    }
+
+    pub fn geographic_scope(mut self, geographic_scope: GeographicScope) -> Self {
+        self.geographic_scope = Some(geographic_scope);
+        self
+    }
+
+    pub fn secondary_emission_factor_sources(
+        mut self,
+        secondary_emission_factor_sources: EmissionFactorDSSet,
+    ) -> Self {
+        self.secondary_emission_factor_sources = Some(secondary_emission_factor_sources);
+        self
+    }
+
+    pub fn exempted_emissions_percent(
+        mut self,
+        exempted_emissions_percent: ExemptedEmissionsPercent,
+    ) -> Self {
+        self.exempted_emissions_percent = Some(exempted_emissions_percent);
+        self
+    }
+
+    pub fn exempted_emissions_description(
+        mut self,
+        exempted_emissions_description: impl Into<String>,
+    ) -> Self {
+        self.exempted_emissions_description = exempted_emissions_description.into();
+        self
+    }
+
+    pub fn packaging_emissions_included(mut self, packaging_emissions_included: bool) -> Self {
+        self.packaging_emissions_included = Some(packaging_emissions_included);
+        self
+    }
+
+    pub fn packaging_ghg_emissions(mut self, packaging_ghg_emissions: PositiveDecimal) -> Self {
+        self.packaging_ghg_emissions = Some(packaging_ghg_emissions);
+        self
+    }
+
+    pub fn allocation_rules_description(
+        mut self,
+        allocation_rules_description: impl Into<String>,
+    ) -> Self {
+        self.allocation_rules_description = Some(allocation_rules_description.into());
+        self
+    }
+
+    pub fn uncertainty_assessment_description(
+        mut self,
+        uncertainty_assessment_description: impl Into<String>,
+    ) -> Self {
+        self.uncertainty_assessment_description = Some(uncertainty_assessment_description.into());
+        self
+    }
+
+    pub fn primary_data_share(mut self, primary_data_share: Percent) -> Self {
+        self.primary_data_share = Some(primary_data_share);
+        self
+    }
+
+    pub fn dqi(mut self, dqi: DataQualityIndicators) -> Self {
+        self.dqi = Some(dqi);
+        self
+    }
+
+    pub fn assurance(mut self, assurance: Assurance) -> Self {
+        self.assurance = Some(assurance);
+        self
+    }
+
+    /// Builds the `CarbonFootprint`, failing if any mandatory property other than
+    /// `boundaryProcessesDescription` and `exemptedEmissionsDescription` has not been set.
+    pub fn build(self) -> Result<CarbonFootprint, MissingFieldsError> {
+        let mut missing = vec![];
+        if self.declared_unit.is_none() {
+            missing.push("declaredUnit");
+        }
+        if self.unitary_product_amount.is_none() {
+            missing.push("unitaryProductAmount");
+        }
+        if self.p_cf_excluding_biogenic.is_none() {
+            missing.push("pCfExcludingBiogenic");
+        }
+        if self.fossil_ghg_emissions.is_none() {
+            missing.push("fossilGhgEmissions");
+        }
+        if self.fossil_carbon_content.is_none() {
+            missing.push("fossilCarbonContent");
+        }
+        if self.biogenic_carbon_content.is_none() {
+            missing.push("biogenicCarbonContent");
+        }
+        if self.characterization_factors.is_none() {
+            missing.push("characterizationFactors");
+        }
+        if self.ipcc_characterization_factors_sources.is_none() {
+            missing.push("ipccCharacterizationFactorsSources");
+        }
+        if self.cross_sectoral_standards_used.is_none() {
+            missing.push("crossSectoralStandardsUsed");
+        }
+        if self.reference_period_start.is_none() {
+            missing.push("referencePeriodStart");
+        }
+        if self.reference_period_end.is_none() {
+            missing.push("referencePeriodEnd");
+        }
+        if self.exempted_emissions_percent.is_none() {
+            missing.push("exemptedEmissionsPercent");
+        }
+        if self.packaging_emissions_included.is_none() {
+            missing.push("packagingEmissionsIncluded");
+        }
+        if !missing.is_empty() {
+            return Err(MissingFieldsError { fields: missing });
+        }
+
+        // all unwraps are covered by the checks above
+        Ok(CarbonFootprint {
+            declared_unit: self.declared_unit.unwrap(),
+            unitary_product_amount: self.unitary_product_amount.unwrap(),
+            p_cf_excluding_biogenic: self.p_cf_excluding_biogenic.unwrap(),
+            p_cf_including_biogenic: self.p_cf_including_biogenic,
+            fossil_ghg_emissions: self.fossil_ghg_emissions.unwrap(),
+            fossil_carbon_content: self.fossil_carbon_content.unwrap(),
+            biogenic_carbon_content: self.biogenic_carbon_content.unwrap(),
+            d_luc_ghg_emissions: self.d_luc_ghg_emissions,
+            land_management_ghg_emissions: self.land_management_ghg_emissions,
+            other_biogenic_ghg_emissions: self.other_biogenic_ghg_emissions,
+            i_luc_ghg_emissions: self.i_luc_ghg_emissions,
+            biogenic_carbon_withdrawal: self.biogenic_carbon_withdrawal,
+            aircraft_ghg_emissions: self.aircraft_ghg_emissions,
+            characterization_factors: self.characterization_factors.unwrap(),
+            ipcc_characterization_factors_sources: self
+                .ipcc_characterization_factors_sources
+                .unwrap(),
+            cross_sectoral_standards_used: self.cross_sectoral_standards_used.unwrap(),
+            product_or_sector_specific_rules: self.product_or_sector_specific_rules,
+            biogenic_accounting_methodology: self.biogenic_accounting_methodology,
+            boundary_processes_description: self.boundary_processes_description,
+            reference_period_start: self.reference_period_start.unwrap(),
+            reference_period_end: self.reference_period_end.unwrap(),
+            geographic_scope: self.geographic_scope,
+            secondary_emission_factor_sources: self.secondary_emission_factor_sources,
+            exempted_emissions_percent: self.exempted_emissions_percent.unwrap(),
+            exempted_emissions_description: self.exempted_emissions_description,
+            packaging_emissions_included: self.packaging_emissions_included.unwrap(),
+            packaging_ghg_emissions: self.packaging_ghg_emissions,
+            allocation_rules_description: self.allocation_rules_description,
+            uncertainty_assessment_description: self.uncertainty_assessment_description,
+            primary_data_share: self.primary_data_share,
+            dqi: self.dqi,
+            assurance: self.assurance,
+        })
+    }
+}
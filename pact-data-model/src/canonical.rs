@@ -0,0 +1,297 @@
+/*
+ * Copyright (c) 2024 SINE Foundation e.V.
+ *
+ * This software is released under the MIT License, see LICENSE.
+ */
+
+//! Canonical JSON serialization of `ProductFootprint`s and a SHA-256 content hash on top of it,
+//! e.g. for deduplication, caching or tamper detection.
+//!
+//! The canonical serialization of a footprint is its compact JSON serialization with
+//! - the keys of all objects sorted lexicographically, including within extensions,
+//! - all decimals normalized, i.e. without trailing zeros (`"1.50"` becomes `"1.5"`), and
+//! - all timestamps in UTC with nanosecond precision, e.g. `2023-06-01T00:00:00.000000000Z`.
+//!
+//! The data of extensions is normalized independently of its Rust type, so that a footprint has
+//! the same canonical serialization whether its extensions are typed or plain `serde_json::Value`s.
+//! Only the decimal properties of iLEAP data types (`EXTENSION_QUANTITY_PROPERTIES` of `diff`)
+//! and their timestamp properties (`EXTENSION_TIMESTAMP_PROPERTIES`) are normalized, and only if
+//! their value is a decimal of the form of the JSON Schemas (`^-?\d+(\.\d+)?$`) or an RFC 3339
+//! date-time, respectively. Any other data of extensions is kept as is.
+//!
+//! Two footprints that are equal up to the scale of their decimals or the representation of their
+//! timestamps therefore have the same canonical serialization and content hash.
+
+use chrono::{DateTime, SecondsFormat, Utc};
+use schemars::JsonSchema;
+use serde::Serialize;
+use serde_json::{Map, Value};
+use sha2::{Digest, Sha256};
+
+use crate::diff::EXTENSION_QUANTITY_PROPERTIES;
+use crate::{
+    CarbonFootprint, NegativeDecimal, PositiveDecimal, ProductFootprint, StrictlyPositiveDecimal,
+    WrappedDecimal,
+};
+
+/// Properties of iLEAP data types (`TCE` and `TAD`) holding timestamps, wherever they occur within
+/// the data of an extension
+const EXTENSION_TIMESTAMP_PROPERTIES: [&str; 2] = ["departureAt", "arrivalAt"];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+/// SHA-256 hash of the canonical serialization of a `ProductFootprint`
+pub struct ContentHash(pub [u8; 32]);
+
+impl std::fmt::Display for ContentHash {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.0.iter().try_for_each(|b| write!(f, "{b:02x}"))
+    }
+}
+
+impl<T: JsonSchema + Serialize> ProductFootprint<T> {
+    /// The canonical JSON serialization of this footprint, see the module documentation
+    pub fn to_canonical_json(&self) -> Result<String, serde_json::Error> {
+        let mut value = serde_json::to_value(self)?;
+
+        value["pcf"] = serde_json::to_value(self.pcf.normalized())?;
+
+        let timestamps = [
+            ("/created", Some(&self.created)),
+            ("/updated", self.updated.as_ref()),
+            ("/validityPeriodStart", self.validity_period_start.as_ref()),
+            ("/validityPeriodEnd", self.validity_period_end.as_ref()),
+            (
+                "/pcf/referencePeriodStart",
+                Some(&self.pcf.reference_period_start),
+            ),
+            (
+                "/pcf/referencePeriodEnd",
+                Some(&self.pcf.reference_period_end),
+            ),
+            (
+                "/pcf/assurance/completedAt",
+                self.pcf
+                    .assurance
+                    .as_ref()
+                    .and_then(|a| a.completed_at.as_ref()),
+            ),
+        ];
+        for (pointer, timestamp) in timestamps {
+            if let (Some(field), Some(timestamp)) = (value.pointer_mut(pointer), timestamp) {
+                *field = Value::String(canonical_timestamp(timestamp));
+            }
+        }
+
+        let extensions = value.get_mut("extensions").and_then(Value::as_array_mut);
+        for extension in extensions.into_iter().flatten() {
+            if let Some(data) = extension.get_mut("data") {
+                normalize_extension_data(data);
+            }
+        }
+
+        serde_json::to_string(&sort_keys(value))
+    }
+
+    /// The SHA-256 hash of the canonical JSON serialization of this footprint
+    pub fn content_hash(&self) -> Result<ContentHash, serde_json::Error> {
+        let canonical_json = self.to_canonical_json()?;
+        Ok(ContentHash(
+            Sha256::digest(canonical_json.as_bytes()).into(),
+        ))
+    }
+}
+
+impl CarbonFootprint {
+    /// This footprint with all decimals normalized
+    fn normalized(&self) -> CarbonFootprint {
+        let positive = |d: &PositiveDecimal| PositiveDecimal(d.0.normalize());
+        let optional = |d: &Option<PositiveDecimal>| d.as_ref().map(positive);
+
+        CarbonFootprint {
+            unitary_product_amount: StrictlyPositiveDecimal(
+                self.unitary_product_amount.0.normalize(),
+            ),
+            p_cf_excluding_biogenic: positive(&self.p_cf_excluding_biogenic),
+            p_cf_including_biogenic: self
+                .p_cf_including_biogenic
+                .as_ref()
+                .map(|d| WrappedDecimal(d.0.normalize())),
+            fossil_ghg_emissions: positive(&self.fossil_ghg_emissions),
+            fossil_carbon_content: positive(&self.fossil_carbon_content),
+            biogenic_carbon_content: positive(&self.biogenic_carbon_content),
+            d_luc_ghg_emissions: optional(&self.d_luc_ghg_emissions),
+            land_management_ghg_emissions: optional(&self.land_management_ghg_emissions),
+            other_biogenic_ghg_emissions: optional(&self.other_biogenic_ghg_emissions),
+            i_luc_ghg_emissions: optional(&self.i_luc_ghg_emissions),
+            biogenic_carbon_withdrawal: self
+                .biogenic_carbon_withdrawal
+                .as_ref()
+                .map(|d| NegativeDecimal(d.0.normalize())),
+            aircraft_ghg_emissions: optional(&self.aircraft_ghg_emissions),
+            packaging_ghg_emissions: optional(&self.packaging_ghg_emissions),
+            ..self.clone()
+        }
+    }
+}
+
+fn canonical_timestamp(timestamp: &DateTime<Utc>) -> String {
+    timestamp.to_rfc3339_opts(SecondsFormat::Nanos, true)
+}
+
+/// Normalizes the decimals and timestamps of known properties within the data of an extension,
+/// see the module documentation
+fn normalize_extension_data(value: &mut Value) {
+    match value {
+        Value::Object(object) => {
+            for (property, value) in object.iter_mut() {
+                match value {
+                    Value::String(s)
+                        if EXTENSION_QUANTITY_PROPERTIES.contains(&property.as_str()) =>
+                    {
+                        if let Some(decimal) = canonical_decimal(s) {
+                            *s = decimal;
+                        }
+                    }
+                    Value::String(s)
+                        if EXTENSION_TIMESTAMP_PROPERTIES.contains(&property.as_str()) =>
+                    {
+                        if let Ok(timestamp) = DateTime::parse_from_rfc3339(s) {
+                            *s = canonical_timestamp(&timestamp.with_timezone(&Utc));
+                        }
+                    }
+                    value => normalize_extension_data(value),
+                }
+            }
+        }
+        Value::Array(items) => items.iter_mut().for_each(normalize_extension_data),
+        _ => {}
+    }
+}
+
+/// `s` without trailing zeros if it is a decimal of the form `^-?\d+(\.\d+)?$`. Leading zeros are
+/// kept.
+fn canonical_decimal(s: &str) -> Option<String> {
+    let digits = |p: &str| !p.is_empty() && p.bytes().all(|b| b.is_ascii_digit());
+    let unsigned = s.strip_prefix('-').unwrap_or(s);
+    let decimal = match unsigned.split_once('.') {
+        Some((integer, fraction)) if digits(integer) && digits(fraction) => {
+            s.trim_end_matches('0').trim_end_matches('.')
+        }
+        None if digits(unsigned) => s,
+        _ => return None,
+    };
+
+    match decimal.strip_prefix('-') {
+        Some(zero) if zero.bytes().all(|b| b == b'0') => Some(zero.to_string()),
+        _ => Some(decimal.to_string()),
+    }
+}
+
+/// `value` with the keys of all objects sorted, independently of whether `serde_json` preserves
+/// the insertion order of keys
+fn sort_keys(value: Value) -> Value {
+    match value {
+        Value::Object(object) => {
+            let mut entries: Vec<_> = object.into_iter().collect();
+            entries.sort_by(|(a, _), (b, _)| a.cmp(b));
+            Value::Object(
+                entries
+                    .into_iter()
+                    .map(|(k, v)| (k, sort_keys(v)))
+                    .collect::<Map<_, _>>(),
+            )
+        }
+        Value::Array(items) => Value::Array(items.into_iter().map(sort_keys).collect()),
+        value => value,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_data::example_pf;
+    use rust_decimal_macros::dec;
+
+    #[test]
+    fn test_canonical_json() {
+        let mut pf = example_pf();
+        pf.pcf.p_cf_excluding_biogenic = PositiveDecimal(dec!(1.50));
+        pf.created = "2023-06-01T12:00:00Z".parse().unwrap();
+
+        let canonical = pf.to_canonical_json().unwrap();
+        assert!(canonical.contains(r#""pCfExcludingBiogenic":"1.5""#));
+        assert!(canonical.contains(r#""created":"2023-06-01T12:00:00.000000000Z""#));
+
+        let keys: Vec<_> = serde_json::from_str::<Value>(&canonical).unwrap()["pcf"]
+            .as_object()
+            .unwrap()
+            .keys()
+            .cloned()
+            .collect();
+        let mut sorted = keys.clone();
+        sorted.sort();
+        assert_eq!(keys, sorted);
+    }
+
+    #[test]
+    fn test_canonical_extension_data() {
+        let mut pf = example_pf();
+        pf.extensions = Some(vec![crate::DataModelExtension {
+            spec_version: crate::SpecVersionString("2.0.0".to_string()),
+            data_schema: "https://example.com/extension.json".to_string(),
+            documentation: None,
+            data: serde_json::json!({
+                "shipmentId": "1237890.10",
+                "mass": "87.50",
+                "distance": { "actual": "423.0" },
+                "tces": [{ "departureAt": "2024-01-01T09:00:00+01:00", "flightNo": "0010" }],
+                "note": "2024-01-01T09:00:00+01:00"
+            }),
+        }]);
+
+        let canonical: Value = serde_json::from_str(&pf.to_canonical_json().unwrap()).unwrap();
+        assert_eq!(
+            canonical["extensions"][0]["data"],
+            serde_json::json!({
+                "shipmentId": "1237890.10",
+                "mass": "87.5",
+                "distance": { "actual": "423" },
+                "tces": [{ "departureAt": "2024-01-01T08:00:00.000000000Z", "flightNo": "0010" }],
+                "note": "2024-01-01T09:00:00+01:00"
+            })
+        );
+    }
+
+    #[test]
+    fn test_canonical_decimal() {
+        assert_eq!(canonical_decimal("1.50"), Some("1.5".to_string()));
+        assert_eq!(canonical_decimal("10.0"), Some("10".to_string()));
+        assert_eq!(canonical_decimal("-0.00"), Some("0".to_string()));
+        assert_eq!(canonical_decimal("0042"), Some("0042".to_string()));
+        assert_eq!(canonical_decimal("1."), None);
+        assert_eq!(canonical_decimal("1.5e3"), None);
+        assert_eq!(canonical_decimal("v1.0"), None);
+    }
+
+    #[test]
+    fn test_content_hash() {
+        let pf = example_pf();
+        let hash = pf.content_hash().unwrap();
+        assert_eq!(hash.to_string().len(), 64);
+
+        // equal up to the scale of decimals
+        let mut rescaled = pf.clone();
+        rescaled.pcf.p_cf_excluding_biogenic =
+            PositiveDecimal(pf.pcf.p_cf_excluding_biogenic.0 * dec!(1.000));
+        assert_eq!(rescaled.content_hash().unwrap(), hash);
+
+        // roundtrip through JSON
+        let roundtrip: ProductFootprint<serde_json::Value> =
+            serde_json::from_str(&pf.to_canonical_json().unwrap()).unwrap();
+        assert_eq!(roundtrip.content_hash().unwrap(), hash);
+
+        let mut changed = pf.clone();
+        changed.pcf.p_cf_excluding_biogenic = PositiveDecimal(dec!(42));
+        assert_ne!(changed.content_hash().unwrap(), hash);
+    }
+}
@@ -0,0 +1,472 @@
+/*
+ * Copyright (c) 2024 SINE Foundation e.V.
+ *
+ * This software is released under the MIT License, see LICENSE.
+ */
+
+//! Structured, field-level differences between two versions of a `ProductFootprint`, e.g. for
+//! reviewing an updated footprint published by a supplier.
+//!
+//! The two versions are compared on their JSON serialization, which includes extension payloads
+//! such as iLEAP `ShipmentFootprint`s. Changes are located by JSON Pointers into the new version
+//! (or into the old version for removed values). Specifically,
+//! - decimal values at the known quantity paths of the PACT data model (`QUANTITY_PATHS`) and of
+//!   iLEAP extensions (`EXTENSION_QUANTITY_PROPERTIES`) are compared numerically and changes are
+//!   reported with their absolute and relative delta, while any other value, even if it looks like
+//!   a number, is compared as is,
+//! - arrays of plain values such as `companyIds` or `productIds` are compared as sets, and
+//! - arrays of objects are matched by an identifying property (`id`, `tceId`, `tocId`, `hocId`,
+//!   `activityId` or `dataSchema`) if all elements carry a unique one, and by index otherwise.
+
+use std::str::FromStr;
+
+use rust_decimal::Decimal;
+use schemars::JsonSchema;
+use serde::Serialize;
+use serde_json::Value;
+
+use crate::schema_validation::child;
+use crate::ProductFootprint;
+
+/// Properties identifying the elements of arrays of objects, in order of precedence
+const IDENTIFYING_PROPERTIES: [&str; 6] =
+    ["id", "tceId", "tocId", "hocId", "activityId", "dataSchema"];
+
+/// JSON Pointers to the decimal values of a `ProductFootprint`, where `*` matches any token
+const QUANTITY_PATHS: [&str; 16] = [
+    "/pcf/unitaryProductAmount",
+    "/pcf/pCfExcludingBiogenic",
+    "/pcf/pCfIncludingBiogenic",
+    "/pcf/fossilGhgEmissions",
+    "/pcf/fossilCarbonContent",
+    "/pcf/biogenicCarbonContent",
+    "/pcf/dLucGhgEmissions",
+    "/pcf/landManagementGhgEmissions",
+    "/pcf/otherBiogenicGhgEmissions",
+    "/pcf/iLucGhgEmissions",
+    "/pcf/biogenicCarbonWithdrawal",
+    "/pcf/aircraftGhgEmissions",
+    "/pcf/exemptedEmissionsPercent",
+    "/pcf/packagingGhgEmissions",
+    "/pcf/primaryDataShare",
+    "/pcf/dqi/*",
+];
+
+/// Properties of iLEAP data types (`ShipmentFootprint`, `TCE`, `TOC`, `HOC`, `TAD` and their
+/// energy carriers) holding decimal values, wherever they occur within the data of an extension
+pub(crate) const EXTENSION_QUANTITY_PROPERTIES: [&str; 22] = [
+    "mass",
+    "volume",
+    "packagingOrTrEqAmount",
+    "actual",
+    "gcd",
+    "sfd",
+    "transportActivity",
+    "co2eWTW",
+    "co2eTTW",
+    "noxTTW",
+    "soxTTW",
+    "ch4TTW",
+    "pmTTW",
+    "co2eIntensityWTW",
+    "co2eIntensityTTW",
+    "loadFactor",
+    "emptyDistanceFactor",
+    "energyConsumption",
+    "emissionFactorWTW",
+    "emissionFactorTTW",
+    "relativeShare",
+    "feedstockShare",
+];
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize)]
+/// The changes between two versions of a `ProductFootprint`
+pub struct FootprintDiff {
+    pub changes: Vec<Change>,
+}
+
+impl FootprintDiff {
+    /// Whether the two versions are equal
+    pub fn is_empty(&self) -> bool {
+        self.changes.is_empty()
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(
+    tag = "kind",
+    rename_all = "camelCase",
+    rename_all_fields = "camelCase"
+)]
+/// A single change between two versions of a `ProductFootprint`
+pub enum Change {
+    /// A decimal value changed. Changes whose delta does not fit into a `Decimal` are reported as
+    /// `Modified`.
+    Quantity {
+        path: String,
+        old: Decimal,
+        new: Decimal,
+        /// `new - old`
+        absolute_delta: Decimal,
+        /// `(new - old) / |old|`, unless `old` is zero
+        relative_delta: Option<Decimal>,
+    },
+    /// Any other value changed
+    Modified {
+        path: String,
+        old: Value,
+        new: Value,
+    },
+    /// A property or array element was added
+    Added { path: String, value: Value },
+    /// A property or array element was removed
+    Removed { path: String, value: Value },
+    /// An item was added to the set at `path`, e.g. a URN to `companyIds`
+    SetItemAdded { path: String, item: Value },
+    /// An item was removed from the set at `path`
+    SetItemRemoved { path: String, item: Value },
+}
+
+/// The changes from `old` to `new`
+pub fn diff<T: JsonSchema + Serialize>(
+    old: &ProductFootprint<T>,
+    new: &ProductFootprint<T>,
+) -> Result<FootprintDiff, serde_json::Error> {
+    let old = serde_json::to_value(old)?;
+    let new = serde_json::to_value(new)?;
+
+    let mut changes = vec![];
+    diff_values("", &old, &new, &mut changes);
+    Ok(FootprintDiff { changes })
+}
+
+fn diff_values(path: &str, old: &Value, new: &Value, changes: &mut Vec<Change>) {
+    if old == new {
+        return;
+    }
+
+    match (old, new) {
+        (Value::Object(old), Value::Object(new)) => {
+            for (key, old_value) in old {
+                match new.get(key) {
+                    Some(new_value) => {
+                        diff_values(&child(path, key), old_value, new_value, changes)
+                    }
+                    None => changes.push(Change::Removed {
+                        path: child(path, key),
+                        value: old_value.clone(),
+                    }),
+                }
+            }
+            for (key, new_value) in new.iter().filter(|(key, _)| !old.contains_key(*key)) {
+                changes.push(Change::Added {
+                    path: child(path, key),
+                    value: new_value.clone(),
+                });
+            }
+        }
+        (Value::Array(old), Value::Array(new)) => diff_arrays(path, old, new, changes),
+        _ if !is_quantity_path(path) => changes.push(Change::Modified {
+            path: path.to_string(),
+            old: old.clone(),
+            new: new.clone(),
+        }),
+        _ => match (as_decimal(old), as_decimal(new)) {
+            (Some(old), Some(new)) if old == new => {}
+            (Some(old), Some(new)) => changes.push(quantity_change(path, old, new)),
+            _ => changes.push(Change::Modified {
+                path: path.to_string(),
+                old: old.clone(),
+                new: new.clone(),
+            }),
+        },
+    }
+}
+
+fn diff_arrays(path: &str, old: &[Value], new: &[Value], changes: &mut Vec<Change>) {
+    let is_plain = |v: &Value| !v.is_object() && !v.is_array();
+    if old.iter().chain(new).all(is_plain) {
+        for item in old.iter().filter(|item| !new.contains(item)) {
+            changes.push(Change::SetItemRemoved {
+                path: path.to_string(),
+                item: item.clone(),
+            });
+        }
+        for item in new.iter().filter(|item| !old.contains(item)) {
+            changes.push(Change::SetItemAdded {
+                path: path.to_string(),
+                item: item.clone(),
+            });
+        }
+        return;
+    }
+
+    let Some(property) = identifying_property(old, new) else {
+        for (i, (old_item, new_item)) in old.iter().zip(new).enumerate() {
+            diff_values(&child(path, i), old_item, new_item, changes);
+        }
+        for (i, old_item) in old.iter().enumerate().skip(new.len()) {
+            changes.push(Change::Removed {
+                path: child(path, i),
+                value: old_item.clone(),
+            });
+        }
+        for (i, new_item) in new.iter().enumerate().skip(old.len()) {
+            changes.push(Change::Added {
+                path: child(path, i),
+                value: new_item.clone(),
+            });
+        }
+        return;
+    };
+
+    let position = |items: &[Value], id: &Value| items.iter().position(|i| &i[property] == id);
+    for (i, old_item) in old.iter().enumerate() {
+        match position(new, &old_item[property]) {
+            Some(j) => diff_values(&child(path, j), old_item, &new[j], changes),
+            None => changes.push(Change::Removed {
+                path: child(path, i),
+                value: old_item.clone(),
+            }),
+        }
+    }
+    for (j, new_item) in new.iter().enumerate() {
+        if position(old, &new_item[property]).is_none() {
+            changes.push(Change::Added {
+                path: child(path, j),
+                value: new_item.clone(),
+            });
+        }
+    }
+}
+
+/// The first of `IDENTIFYING_PROPERTIES` which all elements of `old` and of `new` carry, with
+/// unique values within each array
+fn identifying_property(old: &[Value], new: &[Value]) -> Option<&'static str> {
+    let identifies = |items: &[Value], property: &str| {
+        let ids: Vec<_> = items.iter().map(|item| item.get(property)).collect();
+        ids.iter()
+            .enumerate()
+            .all(|(i, id)| id.is_some_and(|id| !id.is_null()) && !ids[..i].contains(id))
+    };
+
+    IDENTIFYING_PROPERTIES
+        .into_iter()
+        .find(|property| identifies(old, property) && identifies(new, property))
+}
+
+/// Whether `path` points to a decimal value, see `QUANTITY_PATHS` and
+/// `EXTENSION_QUANTITY_PROPERTIES`
+fn is_quantity_path(path: &str) -> bool {
+    let tokens: Vec<_> = path.split('/').skip(1).collect();
+    if let ["extensions", _, "data", .., property] = tokens[..] {
+        return EXTENSION_QUANTITY_PROPERTIES.contains(&property);
+    }
+
+    QUANTITY_PATHS.iter().any(|pattern| {
+        let pattern: Vec<_> = pattern.split('/').skip(1).collect();
+        pattern.len() == tokens.len()
+            && pattern
+                .iter()
+                .zip(&tokens)
+                .all(|(expected, token)| *expected == "*" || expected == token)
+    })
+}
+
+fn as_decimal(value: &Value) -> Option<Decimal> {
+    match value {
+        Value::String(s) => Decimal::from_str(s).ok(),
+        Value::Number(n) => {
+            let n = n.to_string();
+            Decimal::from_str(&n)
+                .or_else(|_| Decimal::from_scientific(&n))
+                .ok()
+        }
+        _ => None,
+    }
+}
+
+fn quantity_change(path: &str, old: Decimal, new: Decimal) -> Change {
+    let absolute_delta = new.checked_sub(old);
+    let relative_delta = absolute_delta
+        .filter(|_| !old.is_zero())
+        .and_then(|delta| delta.checked_div(old.abs()))
+        .map(|delta| delta.normalize());
+
+    match absolute_delta {
+        Some(absolute_delta) => Change::Quantity {
+            path: path.to_string(),
+            old,
+            new,
+            absolute_delta: absolute_delta.normalize(),
+            relative_delta,
+        },
+        None => Change::Modified {
+            path: path.to_string(),
+            old: Value::String(old.to_string()),
+            new: Value::String(new.to_string()),
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_data::example_pf;
+    use crate::{PositiveDecimal, Urn};
+    use rust_decimal_macros::dec;
+    use serde_json::json;
+
+    #[test]
+    fn test_diff_equal() {
+        let pf = example_pf();
+        assert!(diff(&pf, &pf).unwrap().is_empty());
+
+        // equal up to the scale of decimals
+        let mut rescaled = pf.clone();
+        rescaled.pcf.p_cf_excluding_biogenic =
+            PositiveDecimal(pf.pcf.p_cf_excluding_biogenic.0 * dec!(1.00));
+        assert!(diff(&pf, &rescaled).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_diff_footprint() {
+        let old = example_pf();
+        let mut new = old.clone();
+        new.pcf.p_cf_excluding_biogenic = PositiveDecimal(dec!(1.25));
+        new.comment = "updated".to_string();
+        new.company_ids.0.push(Urn(
+            "urn:pathfinder:company:customcode:buyer-assigned:4321".to_string()
+        ));
+        let removed = new.product_ids.0.remove(0);
+
+        assert_eq!(
+            diff(&old, &new).unwrap().changes,
+            vec![
+                Change::Modified {
+                    path: "/comment".to_string(),
+                    old: json!(old.comment),
+                    new: json!("updated"),
+                },
+                Change::SetItemAdded {
+                    path: "/companyIds".to_string(),
+                    item: json!("urn:pathfinder:company:customcode:buyer-assigned:4321"),
+                },
+                Change::Quantity {
+                    path: "/pcf/pCfExcludingBiogenic".to_string(),
+                    old: dec!(1),
+                    new: dec!(1.25),
+                    absolute_delta: dec!(0.25),
+                    relative_delta: Some(dec!(0.25)),
+                },
+                Change::SetItemRemoved {
+                    path: "/productIds".to_string(),
+                    item: json!(removed.0),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_diff_extensions() {
+        let tce = |id: &str, co2e: &str| json!({ "tceId": id, "co2eWTW": co2e });
+
+        let mut old = example_pf();
+        old.extensions = Some(vec![crate::DataModelExtension {
+            spec_version: crate::SpecVersionString("2.0.0".to_string()),
+            data_schema: "https://api.ileap.sine.dev/shipment-footprint.json".to_string(),
+            documentation: None,
+            data: json!({ "tces": [tce("a", "10"), tce("b", "20")] }),
+        }]);
+        let mut new = old.clone();
+        new.extensions.as_mut().unwrap()[0].data =
+            json!({ "tces": [tce("b", "30"), tce("c", "5")] });
+
+        let changes = diff(&old, &new).unwrap().changes;
+        assert_eq!(
+            changes,
+            vec![
+                Change::Removed {
+                    path: "/extensions/0/data/tces/0".to_string(),
+                    value: tce("a", "10"),
+                },
+                Change::Quantity {
+                    path: "/extensions/0/data/tces/0/co2eWTW".to_string(),
+                    old: dec!(20),
+                    new: dec!(30),
+                    absolute_delta: dec!(10),
+                    relative_delta: Some(dec!(0.5)),
+                },
+                Change::Added {
+                    path: "/extensions/0/data/tces/1".to_string(),
+                    value: tce("c", "5"),
+                },
+            ]
+        );
+
+        assert_eq!(
+            serde_json::to_value(&changes[1]).unwrap(),
+            json!({
+                "kind": "quantity",
+                "path": "/extensions/0/data/tces/0/co2eWTW",
+                "old": "20",
+                "new": "30",
+                "absoluteDelta": "10",
+                "relativeDelta": "0.5",
+            })
+        );
+    }
+
+    #[test]
+    fn test_diff_numeric_strings() {
+        // numeric-looking values outside of the known quantity paths are plain modifications
+        let mut old = example_pf();
+        old.version = crate::VersionInteger(1);
+        old.extensions = Some(vec![crate::DataModelExtension {
+            spec_version: crate::SpecVersionString("2.0.0".to_string()),
+            data_schema: "https://api.ileap.sine.dev/shipment-footprint.json".to_string(),
+            documentation: None,
+            data: json!({ "shipmentId": "1234", "mass": "10" }),
+        }]);
+        let mut new = old.clone();
+        new.version = crate::VersionInteger(2);
+        new.extensions.as_mut().unwrap()[0].data = json!({ "shipmentId": "1235", "mass": "12" });
+
+        assert_eq!(
+            diff(&old, &new).unwrap().changes,
+            vec![
+                Change::Quantity {
+                    path: "/extensions/0/data/mass".to_string(),
+                    old: dec!(10),
+                    new: dec!(12),
+                    absolute_delta: dec!(2),
+                    relative_delta: Some(dec!(0.2)),
+                },
+                Change::Modified {
+                    path: "/extensions/0/data/shipmentId".to_string(),
+                    old: json!("1234"),
+                    new: json!("1235"),
+                },
+                Change::Modified {
+                    path: "/version".to_string(),
+                    old: json!(1),
+                    new: json!(2),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_is_quantity_path() {
+        assert!(is_quantity_path("/pcf/pCfExcludingBiogenic"));
+        assert!(is_quantity_path("/pcf/dqi/coveragePercent"));
+        assert!(is_quantity_path("/extensions/0/data/tces/1/co2eWTW"));
+        assert!(is_quantity_path("/extensions/0/data/tces/1/distance/gcd"));
+
+        assert!(!is_quantity_path("/version"));
+        assert!(!is_quantity_path("/pcf/geographyCountry"));
+        assert!(!is_quantity_path("/pcf/dqi"));
+        assert!(!is_quantity_path("/extensions/0/specVersion"));
+        assert!(!is_quantity_path("/extensions/0/data/tces/1/tceId"));
+    }
+}
@@ -0,0 +1,231 @@
+/*
+ * Copyright (c) 2024 SINE Foundation e.V.
+ *
+ * This software is released under the MIT License, see LICENSE.
+ */
+
+//! Decoding of heterogeneous `DataModelExtension`s by their `dataSchema` URL.
+//!
+//! An `ExtensionRegistry` maps `dataSchema` URLs to the Rust types of the extensions they denote.
+//! Decoding a footprint with raw JSON extensions decodes each extension into the type registered
+//! for its URL, and keeps extensions with unregistered URLs as raw JSON. Since both are serialized
+//! as-is, decoding and serializing a footprint again is lossless.
+
+use std::collections::HashMap;
+
+use schemars::JsonSchema;
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use serde_json::Value;
+
+use crate::{DataModelExtension, ProductFootprint};
+
+#[derive(Debug, Clone, PartialEq, Serialize, JsonSchema)]
+#[serde(untagged)]
+/// The data of an extension, decoded if its `dataSchema` URL is registered
+pub enum ExtensionData<T> {
+    Known(T),
+    Unknown(Value),
+}
+
+impl<T> ExtensionData<T> {
+    /// The decoded data, if the extension is known
+    pub fn known(&self) -> Option<&T> {
+        match self {
+            ExtensionData::Known(data) => Some(data),
+            ExtensionData::Unknown(_) => None,
+        }
+    }
+}
+
+#[derive(Debug)]
+/// Error returned when the data of an extension with a registered `dataSchema` URL cannot be
+/// decoded into the registered type
+pub struct ExtensionError {
+    /// The index of the extension within the footprint's extensions
+    pub index: usize,
+    pub data_schema: String,
+    pub error: serde_json::Error,
+}
+
+impl std::fmt::Display for ExtensionError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "cannot decode extension {} ({}): {}",
+            self.index, self.data_schema, self.error
+        )
+    }
+}
+
+impl std::error::Error for ExtensionError {}
+
+type Decoder<T> = Box<dyn Fn(Value) -> Result<T, serde_json::Error> + Send + Sync>;
+
+/// A mapping of `dataSchema` URLs to the types of the extensions they denote, all of which are
+/// wrapped into `T`, e.g. an enum with a variant per extension type
+pub struct ExtensionRegistry<T> {
+    decoders: HashMap<String, Decoder<T>>,
+}
+
+impl<T> Default for ExtensionRegistry<T> {
+    fn default() -> Self {
+        ExtensionRegistry {
+            decoders: HashMap::new(),
+        }
+    }
+}
+
+impl<T: JsonSchema + 'static> ExtensionRegistry<T> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Registers the type `E` for extensions with the given `dataSchema` URL, replacing any type
+    /// registered before. Decoded extensions are wrapped into `T` by `wrap`.
+    pub fn register<E: DeserializeOwned + 'static>(
+        mut self,
+        data_schema: impl Into<String>,
+        wrap: fn(E) -> T,
+    ) -> Self {
+        self.decoders.insert(
+            data_schema.into(),
+            Box::new(move |data| serde_json::from_value(data).map(wrap)),
+        );
+        self
+    }
+
+    /// Whether a type is registered for the given `dataSchema` URL
+    pub fn is_registered(&self, data_schema: &str) -> bool {
+        self.decoders.contains_key(data_schema)
+    }
+
+    /// Decodes `extension` into the type registered for its `dataSchema` URL, or keeps it as raw
+    /// JSON if there is none
+    pub fn decode(
+        &self,
+        extension: DataModelExtension<Value>,
+    ) -> Result<DataModelExtension<ExtensionData<T>>, serde_json::Error> {
+        let data = match self.decoders.get(&extension.data_schema) {
+            Some(decode) => ExtensionData::Known(decode(extension.data)?),
+            None => ExtensionData::Unknown(extension.data),
+        };
+
+        Ok(DataModelExtension {
+            spec_version: extension.spec_version,
+            data_schema: extension.data_schema,
+            documentation: extension.documentation,
+            data,
+        })
+    }
+
+    /// Decodes the extensions of `pf`, see `decode`
+    pub fn decode_footprint(
+        &self,
+        pf: ProductFootprint<Value>,
+    ) -> Result<ProductFootprint<ExtensionData<T>>, ExtensionError> {
+        let extensions = pf
+            .extensions
+            .map(|extensions| {
+                extensions
+                    .into_iter()
+                    .enumerate()
+                    .map(|(index, extension)| {
+                        let data_schema = extension.data_schema.clone();
+                        self.decode(extension).map_err(|error| ExtensionError {
+                            index,
+                            data_schema,
+                            error,
+                        })
+                    })
+                    .collect::<Result<Vec<_>, _>>()
+            })
+            .transpose()?;
+
+        Ok(ProductFootprint {
+            id: pf.id,
+            spec_version: pf.spec_version,
+            preceding_pf_ids: pf.preceding_pf_ids,
+            version: pf.version,
+            created: pf.created,
+            updated: pf.updated,
+            status: pf.status,
+            status_comment: pf.status_comment,
+            validity_period_start: pf.validity_period_start,
+            validity_period_end: pf.validity_period_end,
+            company_name: pf.company_name,
+            company_ids: pf.company_ids,
+            product_description: pf.product_description,
+            product_ids: pf.product_ids,
+            product_category_cpc: pf.product_category_cpc,
+            product_name_company: pf.product_name_company,
+            comment: pf.comment,
+            pcf: pf.pcf,
+            extensions,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_data::example_pf;
+    use crate::SpecVersionString;
+    use serde::Deserialize;
+    use serde_json::json;
+
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
+    struct Weight {
+        kg: u32,
+    }
+
+    fn extension(data_schema: &str, data: Value) -> DataModelExtension<Value> {
+        DataModelExtension {
+            spec_version: SpecVersionString("2.0.0".to_string()),
+            data_schema: data_schema.to_string(),
+            documentation: None,
+            data,
+        }
+    }
+
+    #[test]
+    fn test_decode_footprint() {
+        let registry =
+            ExtensionRegistry::new().register("https://example.com/weight.json", |w: Weight| w);
+
+        let mut pf = example_pf();
+        pf.extensions = Some(vec![
+            extension("https://example.com/weight.json", json!({ "kg": 42 })),
+            extension("https://example.com/other.json", json!({ "kg": "unknown" })),
+        ]);
+
+        let decoded = registry.decode_footprint(pf.clone()).unwrap();
+        let extensions = decoded.extensions.as_ref().unwrap();
+        assert_eq!(extensions[0].data.known(), Some(&Weight { kg: 42 }));
+        assert_eq!(
+            extensions[1].data,
+            ExtensionData::Unknown(json!({ "kg": "unknown" }))
+        );
+
+        assert_eq!(
+            serde_json::to_value(&decoded).unwrap(),
+            serde_json::to_value(&pf).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_decode_footprint_error() {
+        let registry =
+            ExtensionRegistry::new().register("https://example.com/weight.json", |w: Weight| w);
+
+        let mut pf = example_pf();
+        pf.extensions = Some(vec![
+            extension("https://example.com/other.json", json!(null)),
+            extension("https://example.com/weight.json", json!({ "kg": "heavy" })),
+        ]);
+
+        let error = registry.decode_footprint(pf).unwrap_err();
+        assert_eq!(error.index, 1);
+        assert_eq!(error.data_schema, "https://example.com/weight.json");
+    }
+}
@@ -0,0 +1,474 @@
+/*
+ * Copyright (c) 2024 SINE Foundation e.V.
+ *
+ * This software is released under the MIT License, see LICENSE.
+ */
+
+//! Import and export of `ProductFootprint`s as flat CSV, e.g. for exchange via spreadsheets.
+//!
+//! The CSV has a header row and one row per footprint. Columns are named after the JSON
+//! properties of the footprint, with nested properties of `pcf`, `pcf.dqi` and `pcf.assurance`
+//! joined by dots (e.g. `pcf.declaredUnit` or `pcf.dqi.coveragePercent`). The geographic scope
+//! of a `CarbonFootprint` is given by one of the columns `pcf.geographyRegionOrSubregion`,
+//! `pcf.geographyCountry` and `pcf.geographyCountrySubdivision`. Further,
+//! - empty cells denote absent optional properties,
+//! - multi-valued columns (`precedingPfIds`, `companyIds`, `productIds`,
+//!   `pcf.ipccCharacterizationFactorsSources` and `pcf.crossSectoralStandardsUsed`) contain their
+//!   values joined by `|`, and
+//! - `pcf.productOrSectorSpecificRules`, `pcf.secondaryEmissionFactorSources` and `extensions`
+//!   contain their JSON serialization.
+//!
+//! `to_csv` writes all columns in the order of `csv_columns`. `from_csv` accepts any subset of
+//! them in any order and reports all invalid cells, by row and column, at once.
+
+use schemars::JsonSchema;
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use serde_json::{Map, Number, Value};
+
+use crate::{validate_json, ProductFootprint};
+
+/// Separator of the values of multi-valued columns
+const LIST_SEPARATOR: char = '|';
+
+/// Prefixes of the columns of optional nested objects, which are absent if all their cells are
+/// empty
+const OPTIONAL_GROUPS: [&str; 2] = ["pcf.dqi.", "pcf.assurance."];
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Kind {
+    Text,
+    Number,
+    Bool,
+    List,
+    Json,
+}
+
+struct Column {
+    name: &'static str,
+    kind: Kind,
+    required: bool,
+}
+
+const fn column(name: &'static str, kind: Kind, required: bool) -> Column {
+    Column {
+        name,
+        kind,
+        required,
+    }
+}
+
+const COLUMNS: &[Column] = &[
+    column("id", Kind::Text, true),
+    column("specVersion", Kind::Text, true),
+    column("precedingPfIds", Kind::List, false),
+    column("version", Kind::Number, true),
+    column("created", Kind::Text, true),
+    column("updated", Kind::Text, false),
+    column("status", Kind::Text, true),
+    column("statusComment", Kind::Text, false),
+    column("validityPeriodStart", Kind::Text, false),
+    column("validityPeriodEnd", Kind::Text, false),
+    column("companyName", Kind::Text, true),
+    column("companyIds", Kind::List, true),
+    column("productDescription", Kind::Text, true),
+    column("productIds", Kind::List, true),
+    column("productCategoryCpc", Kind::Text, true),
+    column("productNameCompany", Kind::Text, true),
+    column("comment", Kind::Text, true),
+    column("pcf.declaredUnit", Kind::Text, true),
+    column("pcf.unitaryProductAmount", Kind::Text, true),
+    column("pcf.pCfExcludingBiogenic", Kind::Text, true),
+    column("pcf.pCfIncludingBiogenic", Kind::Text, false),
+    column("pcf.fossilGhgEmissions", Kind::Text, true),
+    column("pcf.fossilCarbonContent", Kind::Text, true),
+    column("pcf.biogenicCarbonContent", Kind::Text, true),
+    column("pcf.dLucGhgEmissions", Kind::Text, false),
+    column("pcf.landManagementGhgEmissions", Kind::Text, false),
+    column("pcf.otherBiogenicGhgEmissions", Kind::Text, false),
+    column("pcf.iLucGhgEmissions", Kind::Text, false),
+    column("pcf.biogenicCarbonWithdrawal", Kind::Text, false),
+    column("pcf.aircraftGhgEmissions", Kind::Text, false),
+    column("pcf.characterizationFactors", Kind::Text, true),
+    column("pcf.ipccCharacterizationFactorsSources", Kind::List, true),
+    column("pcf.crossSectoralStandardsUsed", Kind::List, true),
+    column("pcf.productOrSectorSpecificRules", Kind::Json, false),
+    column("pcf.biogenicAccountingMethodology", Kind::Text, false),
+    column("pcf.boundaryProcessesDescription", Kind::Text, true),
+    column("pcf.referencePeriodStart", Kind::Text, true),
+    column("pcf.referencePeriodEnd", Kind::Text, true),
+    column("pcf.geographyRegionOrSubregion", Kind::Text, false),
+    column("pcf.geographyCountry", Kind::Text, false),
+    column("pcf.geographyCountrySubdivision", Kind::Text, false),
+    column("pcf.secondaryEmissionFactorSources", Kind::Json, false),
+    column("pcf.exemptedEmissionsPercent", Kind::Number, true),
+    column("pcf.exemptedEmissionsDescription", Kind::Text, true),
+    column("pcf.packagingEmissionsIncluded", Kind::Bool, true),
+    column("pcf.packagingGhgEmissions", Kind::Text, false),
+    column("pcf.allocationRulesDescription", Kind::Text, false),
+    column("pcf.uncertaintyAssessmentDescription", Kind::Text, false),
+    column("pcf.primaryDataShare", Kind::Number, false),
+    column("pcf.dqi.coveragePercent", Kind::Number, true),
+    column("pcf.dqi.technologicalDQR", Kind::Number, true),
+    column("pcf.dqi.temporalDQR", Kind::Number, true),
+    column("pcf.dqi.geographicalDQR", Kind::Number, true),
+    column("pcf.dqi.completenessDQR", Kind::Number, true),
+    column("pcf.dqi.reliabilityDQR", Kind::Number, true),
+    column("pcf.assurance.assurance", Kind::Bool, true),
+    column("pcf.assurance.coverage", Kind::Text, false),
+    column("pcf.assurance.level", Kind::Text, false),
+    column("pcf.assurance.boundary", Kind::Text, false),
+    column("pcf.assurance.providerName", Kind::Text, true),
+    column("pcf.assurance.completedAt", Kind::Text, false),
+    column("pcf.assurance.standardName", Kind::Text, false),
+    column("pcf.assurance.comments", Kind::Text, false),
+    column("extensions", Kind::Json, false),
+];
+
+#[derive(Debug)]
+/// Error returned when writing or reading flat CSV fails
+pub enum CsvError {
+    /// The CSV could not be written or read, e.g. due to an I/O error or an unequal number of
+    /// cells per row
+    Csv(csv::Error),
+    /// A footprint could not be serialized
+    Json(serde_json::Error),
+    /// The header contains a column which is not part of the layout
+    UnknownColumn(String),
+    /// Some cells do not hold valid values
+    Invalid(Vec<CellError>),
+}
+
+impl std::fmt::Display for CsvError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            CsvError::Csv(e) => write!(f, "{e}"),
+            CsvError::Json(e) => write!(f, "{e}"),
+            CsvError::UnknownColumn(column) => write!(f, "unknown column \"{column}\""),
+            CsvError::Invalid(errors) => {
+                let errors: Vec<_> = errors.iter().map(ToString::to_string).collect();
+                f.write_str(&errors.join("; "))
+            }
+        }
+    }
+}
+
+impl std::error::Error for CsvError {}
+
+impl From<csv::Error> for CsvError {
+    fn from(e: csv::Error) -> Self {
+        CsvError::Csv(e)
+    }
+}
+
+impl From<serde_json::Error> for CsvError {
+    fn from(e: serde_json::Error) -> Self {
+        CsvError::Json(e)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+/// An invalid value in a flat CSV
+pub struct CellError {
+    /// The number of the row, starting at 1 for the header. Rows are counted by record, i.e. a
+    /// row with line breaks in quoted cells is a single row.
+    pub row: u64,
+    /// The column of the invalid value, if it can be attributed to a single column
+    pub column: Option<&'static str>,
+    pub message: String,
+}
+
+impl std::fmt::Display for CellError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.column {
+            Some(column) => write!(f, "row {}, column {column}: {}", self.row, self.message),
+            None => write!(f, "row {}: {}", self.row, self.message),
+        }
+    }
+}
+
+/// The columns of the flat CSV layout, in the order written by `to_csv`
+pub fn csv_columns() -> impl Iterator<Item = &'static str> {
+    COLUMNS.iter().map(|c| c.name)
+}
+
+/// Writes `footprints` to `writer` as flat CSV, see the module documentation
+pub fn to_csv<T: JsonSchema + Serialize, W: std::io::Write>(
+    footprints: &[ProductFootprint<T>],
+    writer: W,
+) -> Result<(), CsvError> {
+    let mut writer = csv::Writer::from_writer(writer);
+    writer.write_record(csv_columns())?;
+
+    for pf in footprints {
+        let value = serde_json::to_value(pf)?;
+        let row = COLUMNS
+            .iter()
+            .map(|c| to_cell(c, value.pointer(&pointer(c.name))))
+            .collect::<Result<Vec<_>, _>>()?;
+        writer.write_record(row)?;
+    }
+
+    writer.flush().map_err(csv::Error::from)?;
+    Ok(())
+}
+
+/// Reads footprints in flat CSV from `reader`, see the module documentation
+pub fn from_csv<T: JsonSchema + DeserializeOwned, R: std::io::Read>(
+    reader: R,
+) -> Result<Vec<ProductFootprint<T>>, CsvError> {
+    let mut reader = csv::Reader::from_reader(reader);
+    let header = reader
+        .headers()?
+        .iter()
+        .map(|name| {
+            COLUMNS
+                .iter()
+                .find(|c| c.name == name)
+                .ok_or_else(|| CsvError::UnknownColumn(name.to_string()))
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let mut footprints = vec![];
+    let mut errors = vec![];
+    for (i, record) in reader.records().enumerate() {
+        let record = record?;
+        let row = i as u64 + 2;
+        match from_row(&header, &record, row) {
+            Ok(pf) => footprints.push(pf),
+            Err(e) => errors.extend(e),
+        }
+    }
+
+    if errors.is_empty() {
+        Ok(footprints)
+    } else {
+        Err(CsvError::Invalid(errors))
+    }
+}
+
+fn from_row<T: JsonSchema + DeserializeOwned>(
+    header: &[&'static Column],
+    record: &csv::StringRecord,
+    row: u64,
+) -> Result<ProductFootprint<T>, Vec<CellError>> {
+    let cells: Vec<_> = header.iter().zip(record.iter()).collect();
+    let is_absent_group = |prefix: &str| {
+        cells
+            .iter()
+            .all(|(c, cell)| !c.name.starts_with(prefix) || cell.trim().is_empty())
+    };
+    let absent_groups: Vec<_> = OPTIONAL_GROUPS
+        .into_iter()
+        .filter(|prefix| is_absent_group(prefix))
+        .collect();
+
+    let mut pf = Map::new();
+    let mut errors = vec![];
+    for (column, cell) in cells {
+        if absent_groups.iter().any(|g| column.name.starts_with(g)) {
+            continue;
+        }
+        match from_cell(column, cell) {
+            Ok(Some(value)) => insert(&mut pf, column.name, value),
+            Ok(None) => {}
+            Err(message) => errors.push(CellError {
+                row,
+                column: Some(column.name),
+                message,
+            }),
+        }
+    }
+    if !errors.is_empty() {
+        return Err(errors);
+    }
+
+    let pf = Value::Object(pf);
+    if let Err(violations) = validate_json(&pf) {
+        return Err(violations
+            .into_iter()
+            .map(|v| CellError {
+                row,
+                column: column_of(&v.instance_path),
+                message: v.to_string(),
+            })
+            .collect());
+    }
+
+    serde_json::from_value(pf).map_err(|e| {
+        vec![CellError {
+            row,
+            column: None,
+            message: e.to_string(),
+        }]
+    })
+}
+
+fn to_cell(column: &Column, value: Option<&Value>) -> Result<String, serde_json::Error> {
+    Ok(match (column.kind, value) {
+        (_, None | Some(Value::Null)) => String::new(),
+        (_, Some(Value::String(s))) => s.clone(),
+        (Kind::List, Some(Value::Array(items))) => items
+            .iter()
+            .map(|item| match item {
+                Value::String(s) => s.clone(),
+                item => item.to_string(),
+            })
+            .collect::<Vec<_>>()
+            .join(&LIST_SEPARATOR.to_string()),
+        (Kind::Json, Some(value)) => serde_json::to_string(value)?,
+        (_, Some(value)) => value.to_string(),
+    })
+}
+
+/// The JSON value of `cell`, or `None` if the property is absent
+fn from_cell(column: &Column, cell: &str) -> Result<Option<Value>, String> {
+    let trimmed = cell.trim();
+    if trimmed.is_empty() {
+        return match column.kind {
+            _ if !column.required => Ok(None),
+            Kind::Text => Ok(Some(Value::String(String::new()))),
+            Kind::List => Ok(Some(Value::Array(vec![]))),
+            _ => Err("missing value".to_string()),
+        };
+    }
+
+    let value = match column.kind {
+        Kind::Text => Value::String(cell.to_string()),
+        Kind::Number => Value::Number(
+            trimmed
+                .parse::<Number>()
+                .map_err(|_| format!("\"{trimmed}\" is not a number"))?,
+        ),
+        Kind::Bool => match trimmed.to_lowercase().as_str() {
+            "true" => Value::Bool(true),
+            "false" => Value::Bool(false),
+            _ => return Err(format!("\"{trimmed}\" is neither true nor false")),
+        },
+        Kind::List => Value::Array(
+            trimmed
+                .split(LIST_SEPARATOR)
+                .map(|item| Value::String(item.trim().to_string()))
+                .collect(),
+        ),
+        Kind::Json => serde_json::from_str(trimmed).map_err(|e| format!("invalid JSON: {e}"))?,
+    };
+    Ok(Some(value))
+}
+
+/// Inserts `value` at the dotted `name` into `object`
+fn insert(object: &mut Map<String, Value>, name: &str, value: Value) {
+    match name.split_once('.') {
+        Some((key, rest)) => {
+            let child = object
+                .entry(key)
+                .or_insert_with(|| Value::Object(Map::new()));
+            if let Value::Object(child) = child {
+                insert(child, rest, value);
+            }
+        }
+        None => {
+            object.insert(name.to_string(), value);
+        }
+    }
+}
+
+/// The JSON Pointer to the property of the column `name`
+fn pointer(name: &str) -> String {
+    format!("/{}", name.replace('.', "/"))
+}
+
+/// The column holding the value at the JSON Pointer `path`
+fn column_of(path: &str) -> Option<&'static str> {
+    COLUMNS
+        .iter()
+        .map(|c| c.name)
+        .filter(|name| {
+            let pointer = pointer(name);
+            path == pointer || path.starts_with(&format!("{pointer}/"))
+        })
+        .max_by_key(|name| name.len())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_data::example_pf;
+    use crate::{
+        Assurance, DataQualityIndicators, FloatBetween1and3, GeographicScope, Percent, Urn,
+        ISO3166CC,
+    };
+
+    #[test]
+    fn test_csv_roundtrip() {
+        let mut pf = example_pf();
+        pf.company_ids.0.push(Urn(
+            "urn:pathfinder:company:customcode:vendor-assigned:1234".to_string(),
+        ));
+        pf.pcf.geographic_scope = Some(GeographicScope::Country(ISO3166CC("DE".to_string())));
+        pf.pcf.dqi = Some(DataQualityIndicators {
+            coverage_percent: Percent(80.0),
+            technological_d_q_r: FloatBetween1and3(1.5),
+            temporal_d_q_r: FloatBetween1and3(2.0),
+            geographical_d_q_r: FloatBetween1and3(1.0),
+            completeness_d_q_r: FloatBetween1and3(3.0),
+            reliability_d_q_r: FloatBetween1and3(2.5),
+        });
+
+        let mut other = example_pf();
+        other.pcf.assurance = Some(Assurance {
+            assurance: true,
+            provider_name: "My Auditor".to_string(),
+            ..Default::default()
+        });
+        other.extensions = Some(vec![crate::DataModelExtension {
+            spec_version: crate::SpecVersionString("2.0.0".to_string()),
+            data_schema: "https://example.com/extension.json".to_string(),
+            documentation: None,
+            data: serde_json::json!({ "a": [1, 2] }),
+        }]);
+
+        let footprints = vec![pf, other];
+        let mut csv = vec![];
+        to_csv(&footprints, &mut csv).unwrap();
+
+        let csv = String::from_utf8(csv).unwrap();
+        assert!(csv.starts_with("id,specVersion,precedingPfIds,version,created,"));
+        assert!(csv
+            .contains("urn:uuid:4a9ef8a6|urn:pathfinder:company:customcode:vendor-assigned:1234"));
+
+        let parsed: Vec<ProductFootprint<Value>> = from_csv(csv.as_bytes()).unwrap();
+        assert_eq!(parsed, footprints);
+    }
+
+    #[test]
+    fn test_csv_errors() {
+        // rows are numbered by record, regardless of line breaks within cells
+        let mut multiline = example_pf();
+        multiline.comment = "first line\nsecond line".to_string();
+
+        let mut csv = vec![];
+        to_csv(&[multiline, example_pf()], &mut csv).unwrap();
+        let csv = String::from_utf8(csv)
+            .unwrap()
+            .replacen(",false,", ",maybe,", 1)
+            .replace(",kilogram,", ",bananas,");
+
+        let Err(CsvError::Invalid(errors)) = from_csv::<Value, _>(csv.as_bytes()) else {
+            panic!("expected invalid cells");
+        };
+        let errors: Vec<_> = errors.iter().map(|e| (e.row, e.column)).collect();
+        assert_eq!(
+            errors,
+            vec![
+                (2, Some("pcf.packagingEmissionsIncluded")),
+                (3, Some("pcf.declaredUnit")),
+            ]
+        );
+
+        assert!(matches!(
+            from_csv::<Value, _>("id,foo\n".as_bytes()),
+            Err(CsvError::UnknownColumn(column)) if column == "foo"
+        ));
+    }
+}
@@ -0,0 +1,139 @@
+/*
+ * Copyright (c) 2024 SINE Foundation e.V.
+ *
+ * This software is released under the MIT License, see LICENSE.
+ */
+
+//! Containment of `GeographicScope`s, based on the UN M49 hierarchy of regions and subregions
+//! and the ISO 3166 tables of the `iso3166` module.
+//!
+//! Countries and subdivisions that are not listed in the ISO 3166 tables are only contained in
+//! `Global` and in themselves.
+
+use crate::{iso3166, GeographicScope, UNRegionOrSubregion};
+
+impl UNRegionOrSubregion {
+    /// The region a subregion belongs to, `None` for regions
+    pub fn region(&self) -> Option<UNRegionOrSubregion> {
+        use UNRegionOrSubregion::*;
+
+        match self {
+            Africa | Americas | Asia | Europe | Oceania => None,
+            NorthernAfrica | SubSaharanAfrica => Some(Africa),
+            LatinAmericaAndTheCaribbean | NorthernAmerica => Some(Americas),
+            CentralAsia | EasternAsia | SouthEasternAsia | SouthernAsia | WesternAsia => Some(Asia),
+            EasternEurope | NorthernEurope | SouthernEurope | WesternEurope => Some(Europe),
+            AustraliaAndNewZealand | Melanesia | Micronesia | Polynesia => Some(Oceania),
+        }
+    }
+
+    /// Whether `other` is this region or subregion, or one of its subregions
+    pub fn contains(&self, other: &UNRegionOrSubregion) -> bool {
+        self == other || other.region().as_ref() == Some(self)
+    }
+
+    /// Whether the country with the given ISO 3166-1 alpha-2 code lies within this region or
+    /// subregion
+    fn contains_country(&self, code: &str) -> bool {
+        iso3166::country(code)
+            .map(|c| c.region.as_ref() == Some(self) || c.subregion.as_ref() == Some(self))
+            .unwrap_or_default()
+    }
+}
+
+impl GeographicScope {
+    /// Whether `other` lies entirely within this geographic scope, e.g. `Regional(Europe)`
+    /// contains `Regional(WesternEurope)`, `Country("DE")` and `Subdivision("DE-BY")`. Every scope
+    /// contains itself.
+    pub fn contains(&self, other: &GeographicScope) -> bool {
+        use GeographicScope::*;
+
+        match (self, other) {
+            (Global, _) => true,
+            (_, Global) => false,
+            (Regional(region), Regional(other)) => region.contains(other),
+            (Regional(region), Country(country)) => region.contains_country(&country.0),
+            (Regional(region), Subdivision(_)) => other
+                .subdivision()
+                .map(|s| region.contains_country(s.country_code()))
+                .unwrap_or_default(),
+            (Country(country), Country(other)) => country == other,
+            (Country(country), Subdivision(_)) => other
+                .subdivision()
+                .map(|s| s.country_code() == country.0)
+                .unwrap_or_default(),
+            (Subdivision(subdivision), Subdivision(other_subdivision)) => {
+                if subdivision == other_subdivision {
+                    return true;
+                }
+                let mut parent = other.subdivision().and_then(|s| s.parent());
+                while let Some(p) = parent {
+                    if p.code == subdivision.0 {
+                        return true;
+                    }
+                    parent = p.parent();
+                }
+                false
+            }
+            (Country(_) | Subdivision(_), Regional(_)) | (Subdivision(_), Country(_)) => false,
+        }
+    }
+
+    /// Whether this geographic scope lies entirely within the given region or subregion
+    pub fn is_within(&self, region: &UNRegionOrSubregion) -> bool {
+        GeographicScope::Regional(region.clone()).contains(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{NonEmptyString, ISO3166CC};
+    use UNRegionOrSubregion::*;
+
+    fn country(code: &str) -> GeographicScope {
+        GeographicScope::Country(ISO3166CC(code.to_string()))
+    }
+
+    fn subdivision(code: &str) -> GeographicScope {
+        GeographicScope::Subdivision(NonEmptyString(code.to_string()))
+    }
+
+    #[test]
+    fn test_region_hierarchy() {
+        assert_eq!(WesternEurope.region(), Some(Europe));
+        assert_eq!(Europe.region(), None);
+        assert!(Europe.contains(&WesternEurope));
+        assert!(Europe.contains(&Europe));
+        assert!(!WesternEurope.contains(&Europe));
+        assert!(!Europe.contains(&WesternAsia));
+    }
+
+    #[test]
+    fn test_contains() {
+        let global = GeographicScope::Global;
+        let europe = GeographicScope::Regional(Europe);
+        let western_europe = GeographicScope::Regional(WesternEurope);
+
+        assert!(country("DE").is_within(&WesternEurope));
+        assert!(western_europe.contains(&country("DE")));
+        assert!(europe.contains(&western_europe));
+        assert!(global.contains(&europe));
+        assert!(!europe.contains(&global));
+        assert!(!western_europe.contains(&europe));
+
+        assert!(subdivision("DE-BY").is_within(&Europe));
+        assert!(country("DE").contains(&subdivision("DE-BY")));
+        assert!(!country("FR").contains(&subdivision("DE-BY")));
+        assert!(!subdivision("DE-BY").contains(&country("DE")));
+        assert!(subdivision("FR-IDF").contains(&subdivision("FR-75")));
+        assert!(!subdivision("FR-75").contains(&subdivision("FR-IDF")));
+
+        assert!(!country("BR").is_within(&Europe));
+        assert!(country("BR").is_within(&LatinAmericaAndTheCaribbean));
+        assert!(!country("XX").is_within(&Europe));
+        assert!(!country("DE").contains(&subdivision("DE-ZZ")));
+        assert!(global.contains(&country("XX")));
+        assert!(country("XX").contains(&country("XX")));
+    }
+}
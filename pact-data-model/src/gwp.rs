@@ -0,0 +1,374 @@
+/*
+ * Copyright (c) 2024 SINE Foundation e.V.
+ *
+ * This software is released under the MIT License, see LICENSE.
+ */
+
+//! IPCC 100-year global warming potentials (GWP100) of the Fourth, Fifth and Sixth Assessment
+//! Reports, the computation of CO2 equivalents from a per-gas inventory and the restatement of a
+//! `CarbonFootprint` from one set of characterization factors to another.
+//!
+//! AR4 values are taken from AR4 WG1 Table 2.14, AR5 values from AR5 WG1 Table 8.A.1 (without
+//! climate-carbon feedbacks; fossil methane including the CO2 from its oxidation), AR6 values
+//! from AR6 WG1 Table 7.15 and Table 7.SM.7. AR4 does not distinguish fossil and biogenic methane.
+
+use std::collections::BTreeMap;
+
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    CarbonFootprint, CharacterizationFactors, IpccCharacterizationFactorsSource,
+    IpccCharacterizationFactorsSources, PositiveDecimal, WrappedDecimal,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+/// An IPCC Assessment Report providing a set of GWP100 values
+pub enum IpccReport {
+    #[serde(rename = "AR4")]
+    Ar4,
+    #[serde(rename = "AR5")]
+    Ar5,
+    #[serde(rename = "AR6")]
+    Ar6,
+}
+
+impl From<CharacterizationFactors> for IpccReport {
+    fn from(characterization_factors: CharacterizationFactors) -> Self {
+        match characterization_factors {
+            CharacterizationFactors::Ar5 => IpccReport::Ar5,
+            CharacterizationFactors::Ar6 => IpccReport::Ar6,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+/// A greenhouse gas with a GWP100 value in all supported IPCC reports
+pub enum GreenhouseGas {
+    #[serde(rename = "CO2")]
+    Co2,
+    #[serde(rename = "CH4-fossil")]
+    Ch4Fossil,
+    #[serde(rename = "CH4-biogenic")]
+    Ch4Biogenic,
+    #[serde(rename = "N2O")]
+    N2o,
+    #[serde(rename = "HFC-23")]
+    Hfc23,
+    #[serde(rename = "HFC-32")]
+    Hfc32,
+    #[serde(rename = "HFC-125")]
+    Hfc125,
+    #[serde(rename = "HFC-134a")]
+    Hfc134a,
+    #[serde(rename = "HFC-143a")]
+    Hfc143a,
+    #[serde(rename = "HFC-152a")]
+    Hfc152a,
+    #[serde(rename = "HFC-227ea")]
+    Hfc227ea,
+    #[serde(rename = "HFC-245fa")]
+    Hfc245fa,
+    #[serde(rename = "CF4")]
+    Cf4,
+    #[serde(rename = "C2F6")]
+    C2f6,
+    #[serde(rename = "SF6")]
+    Sf6,
+    #[serde(rename = "NF3")]
+    Nf3,
+}
+
+impl GreenhouseGas {
+    /// The 100-year global warming potential of this gas according to `report`, in kg CO2e per
+    /// kg of the gas
+    pub fn gwp100(self, report: IpccReport) -> Decimal {
+        use GreenhouseGas::*;
+        use IpccReport::*;
+
+        let (mantissa, scale) = match (self, report) {
+            (Co2, _) => (1, 0),
+            (Ch4Fossil, Ar4) | (Ch4Biogenic, Ar4) => (25, 0),
+            (Ch4Fossil, Ar5) => (30, 0),
+            (Ch4Fossil, Ar6) => (298, 1),
+            (Ch4Biogenic, Ar5) => (28, 0),
+            (Ch4Biogenic, Ar6) => (270, 1),
+            (N2o, Ar4) => (298, 0),
+            (N2o, Ar5) => (265, 0),
+            (N2o, Ar6) => (273, 0),
+            (Hfc23, Ar4) => (14800, 0),
+            (Hfc23, Ar5) => (12400, 0),
+            (Hfc23, Ar6) => (14600, 0),
+            (Hfc32, Ar4) => (675, 0),
+            (Hfc32, Ar5) => (677, 0),
+            (Hfc32, Ar6) => (771, 0),
+            (Hfc125, Ar4) => (3500, 0),
+            (Hfc125, Ar5) => (3170, 0),
+            (Hfc125, Ar6) => (3740, 0),
+            (Hfc134a, Ar4) => (1430, 0),
+            (Hfc134a, Ar5) => (1300, 0),
+            (Hfc134a, Ar6) => (1530, 0),
+            (Hfc143a, Ar4) => (4470, 0),
+            (Hfc143a, Ar5) => (4800, 0),
+            (Hfc143a, Ar6) => (5810, 0),
+            (Hfc152a, Ar4) => (124, 0),
+            (Hfc152a, Ar5) => (138, 0),
+            (Hfc152a, Ar6) => (164, 0),
+            (Hfc227ea, Ar4) => (3220, 0),
+            (Hfc227ea, Ar5) => (3350, 0),
+            (Hfc227ea, Ar6) => (3600, 0),
+            (Hfc245fa, Ar4) => (1030, 0),
+            (Hfc245fa, Ar5) => (858, 0),
+            (Hfc245fa, Ar6) => (962, 0),
+            (Cf4, Ar4) => (7390, 0),
+            (Cf4, Ar5) => (6630, 0),
+            (Cf4, Ar6) => (7380, 0),
+            (C2f6, Ar4) => (12200, 0),
+            (C2f6, Ar5) => (11100, 0),
+            (C2f6, Ar6) => (12400, 0),
+            (Sf6, Ar4) => (22800, 0),
+            (Sf6, Ar5) => (23500, 0),
+            (Sf6, Ar6) => (25200, 0),
+            (Nf3, Ar4) => (17200, 0),
+            (Nf3, Ar5) => (16100, 0),
+            (Nf3, Ar6) => (17400, 0),
+        };
+        Decimal::new(mantissa, scale)
+    }
+
+    /// Whether this gas is of biogenic origin
+    pub fn is_biogenic(self) -> bool {
+        self == GreenhouseGas::Ch4Biogenic
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+/// Error returned when computing CO2 equivalents or restating a `CarbonFootprint` fails
+pub enum GwpError {
+    /// The inventory contains a negative mass of the given gas
+    NegativeMass(GreenhouseGas),
+    /// The restated value of the given field would be negative
+    NegativeValue(&'static str),
+    /// A CO2 equivalent does not fit into a `Decimal`
+    Overflow,
+}
+
+impl std::fmt::Display for GwpError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            GwpError::NegativeMass(gas) => write!(f, "negative mass of {gas:?} in inventory"),
+            GwpError::NegativeValue(field) => write!(f, "restated {field} would be negative"),
+            GwpError::Overflow => f.write_str("CO2 equivalent is out of range"),
+        }
+    }
+}
+
+impl std::error::Error for GwpError {}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(transparent)]
+/// Emitted masses of greenhouse gases, in kg
+pub struct GhgInventory(pub BTreeMap<GreenhouseGas, Decimal>);
+
+impl GhgInventory {
+    /// The CO2 equivalent of this inventory according to `report`, in kg CO2e
+    pub fn co2e(&self, report: IpccReport) -> Result<Decimal, GwpError> {
+        self.co2e_where(report, |_| true)
+    }
+
+    /// The CO2 equivalent of the gases of this inventory matching `filter`
+    fn co2e_where(
+        &self,
+        report: IpccReport,
+        filter: impl Fn(GreenhouseGas) -> bool,
+    ) -> Result<Decimal, GwpError> {
+        self.0.iter().filter(|(gas, _)| filter(**gas)).try_fold(
+            Decimal::ZERO,
+            |sum, (gas, mass)| {
+                if mass.is_sign_negative() && !mass.is_zero() {
+                    return Err(GwpError::NegativeMass(*gas));
+                }
+                mass.checked_mul(gas.gwp100(report))
+                    .and_then(|co2e| sum.checked_add(co2e))
+                    .ok_or(GwpError::Overflow)
+            },
+        )
+    }
+}
+
+impl CarbonFootprint {
+    /// Restates this footprint with the given characterization factors.
+    ///
+    /// `inventory` must contain the emitted masses of the gases included in
+    /// `pCfExcludingBiogenic`, i.e. all greenhouse gas emissions but biogenic CO2, for
+    /// `unitaryProductAmount` units of the declared unit. The difference in CO2 equivalents is
+    /// added to `pCfExcludingBiogenic` and `pCfIncludingBiogenic`, the difference of fossil gases
+    /// to `fossilGhgEmissions` and the difference of biogenic methane to
+    /// `otherBiogenicGhgEmissions`, if present. In `ipccCharacterizationFactorsSources`, the
+    /// source of the former characterization factors is replaced by the new one, keeping all
+    /// other sources.
+    pub fn restate(
+        &self,
+        inventory: &GhgInventory,
+        characterization_factors: CharacterizationFactors,
+    ) -> Result<CarbonFootprint, GwpError> {
+        let (from, to) = (
+            IpccReport::from(self.characterization_factors),
+            IpccReport::from(characterization_factors),
+        );
+        let delta = |biogenic: bool| -> Result<Decimal, GwpError> {
+            let filter = |gas: GreenhouseGas| gas.is_biogenic() == biogenic;
+            inventory
+                .co2e_where(to, filter)?
+                .checked_sub(inventory.co2e_where(from, filter)?)
+                .ok_or(GwpError::Overflow)
+        };
+        let (fossil_delta, biogenic_delta) = (delta(false)?, delta(true)?);
+        let total_delta = fossil_delta
+            .checked_add(biogenic_delta)
+            .ok_or(GwpError::Overflow)?;
+
+        let add = |d: Decimal, delta: Decimal| d.checked_add(delta).ok_or(GwpError::Overflow);
+        let add_positive = |d: &PositiveDecimal, delta: Decimal, field| {
+            let d = add(d.0, delta)?;
+            if d.is_sign_negative() && !d.is_zero() {
+                return Err(GwpError::NegativeValue(field));
+            }
+            Ok(PositiveDecimal(d.normalize()))
+        };
+
+        let (from_source, to_source) = (
+            IpccCharacterizationFactorsSource::from(self.characterization_factors),
+            IpccCharacterizationFactorsSource::from(characterization_factors),
+        );
+        let mut sources = vec![to_source];
+        for source in self.ipcc_characterization_factors_sources.0.iter() {
+            if source != &from_source && !sources.contains(source) {
+                sources.push(source.clone());
+            }
+        }
+
+        Ok(CarbonFootprint {
+            p_cf_excluding_biogenic: add_positive(
+                &self.p_cf_excluding_biogenic,
+                total_delta,
+                "pCfExcludingBiogenic",
+            )?,
+            p_cf_including_biogenic: self
+                .p_cf_including_biogenic
+                .as_ref()
+                .map(|d| add(d.0, total_delta).map(|d| WrappedDecimal(d.normalize())))
+                .transpose()?,
+            fossil_ghg_emissions: add_positive(
+                &self.fossil_ghg_emissions,
+                fossil_delta,
+                "fossilGhgEmissions",
+            )?,
+            other_biogenic_ghg_emissions: self
+                .other_biogenic_ghg_emissions
+                .as_ref()
+                .map(|d| add_positive(d, biogenic_delta, "otherBiogenicGhgEmissions"))
+                .transpose()?,
+            characterization_factors,
+            ipcc_characterization_factors_sources: IpccCharacterizationFactorsSources(sources),
+            ..self.clone()
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_data::example_pf;
+    use rust_decimal_macros::dec;
+
+    fn inventory(gases: &[(GreenhouseGas, Decimal)]) -> GhgInventory {
+        GhgInventory(gases.iter().cloned().collect())
+    }
+
+    #[test]
+    fn test_co2e() {
+        let inventory = inventory(&[
+            (GreenhouseGas::Co2, dec!(10)),
+            (GreenhouseGas::Ch4Fossil, dec!(0.5)),
+            (GreenhouseGas::N2o, dec!(0.01)),
+        ]);
+
+        assert_eq!(inventory.co2e(IpccReport::Ar4).unwrap(), dec!(25.48));
+        assert_eq!(inventory.co2e(IpccReport::Ar5).unwrap(), dec!(27.65));
+        assert_eq!(inventory.co2e(IpccReport::Ar6).unwrap(), dec!(27.63));
+        assert_eq!(
+            GhgInventory::default().co2e(IpccReport::Ar6),
+            Ok(Decimal::ZERO)
+        );
+
+        let negative = self::inventory(&[(GreenhouseGas::Sf6, dec!(-1))]);
+        assert_eq!(
+            negative.co2e(IpccReport::Ar6),
+            Err(GwpError::NegativeMass(GreenhouseGas::Sf6))
+        );
+    }
+
+    #[test]
+    fn test_inventory_serde() {
+        let inventory: GhgInventory =
+            serde_json::from_str(r#"{"CO2": "1.5", "CH4-biogenic": "0.1"}"#).unwrap();
+        assert_eq!(inventory.0[&GreenhouseGas::Co2], dec!(1.5));
+        assert_eq!(inventory.0[&GreenhouseGas::Ch4Biogenic], dec!(0.1));
+    }
+
+    #[test]
+    fn test_restate() {
+        let mut cf = example_pf().pcf;
+        cf.characterization_factors = CharacterizationFactors::Ar5;
+        // 10 kg CO2 + 0.5 kg fossil CH4 + 0.1 kg biogenic CH4, AR5
+        cf.p_cf_excluding_biogenic = PositiveDecimal(dec!(27.8));
+        cf.p_cf_including_biogenic = Some(WrappedDecimal(dec!(20)));
+        cf.fossil_ghg_emissions = PositiveDecimal(dec!(25));
+        cf.other_biogenic_ghg_emissions = Some(PositiveDecimal(dec!(2.8)));
+        let ar4 = IpccCharacterizationFactorsSource::try_from("AR4").unwrap();
+        cf.ipcc_characterization_factors_sources = IpccCharacterizationFactorsSources(vec![
+            ar4.clone(),
+            CharacterizationFactors::Ar5.into(),
+        ]);
+
+        let inventory = inventory(&[
+            (GreenhouseGas::Co2, dec!(10)),
+            (GreenhouseGas::Ch4Fossil, dec!(0.5)),
+            (GreenhouseGas::Ch4Biogenic, dec!(0.1)),
+        ]);
+        let restated = cf
+            .restate(&inventory, CharacterizationFactors::Ar6)
+            .unwrap();
+
+        assert_eq!(
+            restated.characterization_factors,
+            CharacterizationFactors::Ar6
+        );
+        assert_eq!(
+            restated.ipcc_characterization_factors_sources,
+            IpccCharacterizationFactorsSources(vec![CharacterizationFactors::Ar6.into(), ar4])
+        );
+        assert_eq!(restated.fossil_ghg_emissions.0, dec!(24.9));
+        assert_eq!(
+            restated.other_biogenic_ghg_emissions.as_ref().unwrap().0,
+            dec!(2.7)
+        );
+        assert_eq!(restated.p_cf_excluding_biogenic.0, dec!(27.6));
+        assert_eq!(
+            restated.p_cf_including_biogenic.as_ref().unwrap().0,
+            dec!(19.8)
+        );
+
+        let back = restated
+            .restate(&inventory, CharacterizationFactors::Ar5)
+            .unwrap();
+        assert_eq!(back.p_cf_excluding_biogenic, cf.p_cf_excluding_biogenic);
+
+        cf.fossil_ghg_emissions = PositiveDecimal(Decimal::ZERO);
+        assert_eq!(
+            cf.restate(&inventory, CharacterizationFactors::Ar6),
+            Err(GwpError::NegativeValue("fossilGhgEmissions"))
+        );
+    }
+}
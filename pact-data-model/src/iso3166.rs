@@ -0,0 +1,154 @@
+/*
+ * Copyright (c) 2024 SINE Foundation e.V.
+ *
+ * This software is released under the MIT License, see LICENSE.
+ */
+
+//! Bundled ISO 3166-1 (alpha-2) country and ISO 3166-2 subdivision tables, including the UN M49
+//! region and subregion of every country.
+
+use crate::{GeographicScope, UNRegionOrSubregion, ISO3166CC};
+
+mod data;
+
+#[derive(Debug, PartialEq)]
+/// An entry of the ISO 3166-1 table
+pub struct Country {
+    /// ISO 3166-1 alpha-2 code
+    pub code: &'static str,
+    /// ISO 3166-1 English short name
+    pub name: &'static str,
+    /// UN M49 region, absent for Antarctica
+    pub region: Option<UNRegionOrSubregion>,
+    /// UN M49 subregion, absent for Antarctica
+    pub subregion: Option<UNRegionOrSubregion>,
+}
+
+#[derive(Debug, PartialEq)]
+/// An entry of the ISO 3166-2 table
+pub struct Subdivision {
+    /// ISO 3166-2 code, e.g. `FR-75`
+    pub code: &'static str,
+    pub name: &'static str,
+    /// ISO 3166-2 code of the enclosing subdivision, e.g. `FR-IDF` for `FR-75`
+    pub parent: Option<&'static str>,
+}
+
+/// Looks up a country by its ISO 3166-1 alpha-2 code
+pub fn country(code: &str) -> Option<&'static Country> {
+    data::COUNTRIES
+        .binary_search_by(|c| c.code.cmp(code))
+        .ok()
+        .map(|i| &data::COUNTRIES[i])
+}
+
+/// Looks up a subdivision by its ISO 3166-2 code
+pub fn subdivision(code: &str) -> Option<&'static Subdivision> {
+    data::SUBDIVISIONS
+        .binary_search_by(|s| s.code.cmp(code))
+        .ok()
+        .map(|i| &data::SUBDIVISIONS[i])
+}
+
+/// All ISO 3166-1 countries, sorted by code
+pub fn countries() -> &'static [Country] {
+    data::COUNTRIES
+}
+
+/// All ISO 3166-2 subdivisions, sorted by code
+pub fn subdivisions() -> &'static [Subdivision] {
+    data::SUBDIVISIONS
+}
+
+impl Subdivision {
+    /// The ISO 3166-1 alpha-2 code of the country this subdivision belongs to
+    pub fn country_code(&self) -> &'static str {
+        &self.code[..2]
+    }
+
+    /// The country this subdivision belongs to
+    pub fn country(&self) -> &'static Country {
+        country(self.country_code()).expect("subdivision of an unknown country")
+    }
+
+    /// The enclosing subdivision, if any
+    pub fn parent(&self) -> Option<&'static Subdivision> {
+        self.parent.and_then(subdivision)
+    }
+}
+
+impl ISO3166CC {
+    /// Looks up the country of this code in the ISO 3166-1 table
+    pub fn country(&self) -> Option<&'static Country> {
+        country(&self.0)
+    }
+
+    pub fn name(&self) -> Option<&'static str> {
+        self.country().map(|c| c.name)
+    }
+
+    pub fn region(&self) -> Option<UNRegionOrSubregion> {
+        self.country().and_then(|c| c.region.clone())
+    }
+
+    pub fn subregion(&self) -> Option<UNRegionOrSubregion> {
+        self.country().and_then(|c| c.subregion.clone())
+    }
+}
+
+impl GeographicScope {
+    /// Looks up the subdivision of a `Subdivision` scope in the ISO 3166-2 table
+    pub fn subdivision(&self) -> Option<&'static Subdivision> {
+        match self {
+            GeographicScope::Subdivision(code) => subdivision(&code.0),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_country_lookup() {
+        let de = ISO3166CC("DE".to_string());
+        assert!(de.is_valid());
+        assert_eq!(de.name(), Some("Germany"));
+        assert_eq!(de.region(), Some(UNRegionOrSubregion::Europe));
+        assert_eq!(de.subregion(), Some(UNRegionOrSubregion::WesternEurope));
+
+        assert_eq!(
+            country("BR").and_then(|c| c.subregion.clone()),
+            Some(UNRegionOrSubregion::LatinAmericaAndTheCaribbean)
+        );
+        assert_eq!(country("AQ").and_then(|c| c.region.clone()), None);
+
+        for code in ["XX", "de", "DEU", ""] {
+            assert!(!ISO3166CC(code.to_string()).is_valid(), "{code}");
+        }
+        assert!(countries()
+            .iter()
+            .all(|c| ISO3166CC(c.code.to_string()).is_valid()));
+    }
+
+    #[test]
+    fn test_subdivision_lookup() {
+        let paris = subdivision("FR-75").unwrap();
+        assert_eq!(paris.name, "Paris");
+        assert_eq!(paris.country().name, "France");
+        assert_eq!(paris.parent().map(|s| s.code), Some("FR-IDF"));
+
+        assert_eq!(subdivision("DE-BY").map(|s| s.name), Some("Bayern"));
+        assert_eq!(subdivision("DE-BY").unwrap().parent(), None);
+        assert_eq!(subdivision("DE-ZZ"), None);
+        assert_eq!(subdivision("GB-ABD").and_then(|s| s.parent), Some("GB-SCT"));
+
+        assert!(data::COUNTRIES.windows(2).all(|w| w[0].code < w[1].code));
+        assert!(data::SUBDIVISIONS.windows(2).all(|w| w[0].code < w[1].code));
+        assert!(data::SUBDIVISIONS
+            .iter()
+            .all(|s| country(s.country_code()).is_some()
+                && s.parent.is_none_or(|p| subdivision(p).is_some())));
+    }
+}
@@ -0,0 +1,5398 @@
+/*
+ * Copyright (c) 2024 SINE Foundation e.V.
+ *
+ * This software is released under the MIT License, see LICENSE.
+ */
+
+//! ISO 3166-1 and ISO 3166-2 tables, generated from the Debian `iso-codes` package (version
+//! 4.15.0). UN M49 regions and subregions follow the UN Statistics Division's "Standard country
+//! or area codes for statistical use"; Taiwan is assigned to Eastern Asia.
+//!
+//! Both tables are sorted by code.
+
+use super::{Country, Subdivision};
+use crate::UNRegionOrSubregion::*;
+
+#[rustfmt::skip]
+pub(super) static COUNTRIES: &[Country] = &[
+    Country { code: "AD", name: "Andorra", region: Some(Europe), subregion: Some(SouthernEurope) },
+    Country { code: "AE", name: "United Arab Emirates", region: Some(Asia), subregion: Some(WesternAsia) },
+    Country { code: "AF", name: "Afghanistan", region: Some(Asia), subregion: Some(SouthernAsia) },
+    Country { code: "AG", name: "Antigua and Barbuda", region: Some(Americas), subregion: Some(LatinAmericaAndTheCaribbean) },
+    Country { code: "AI", name: "Anguilla", region: Some(Americas), subregion: Some(LatinAmericaAndTheCaribbean) },
+    Country { code: "AL", name: "Albania", region: Some(Europe), subregion: Some(SouthernEurope) },
+    Country { code: "AM", name: "Armenia", region: Some(Asia), subregion: Some(WesternAsia) },
+    Country { code: "AO", name: "Angola", region: Some(Africa), subregion: Some(SubSaharanAfrica) },
+    Country { code: "AQ", name: "Antarctica", region: None, subregion: None },
+    Country { code: "AR", name: "Argentina", region: Some(Americas), subregion: Some(LatinAmericaAndTheCaribbean) },
+    Country { code: "AS", name: "American Samoa", region: Some(Oceania), subregion: Some(Polynesia) },
+    Country { code: "AT", name: "Austria", region: Some(Europe), subregion: Some(WesternEurope) },
+    Country { code: "AU", name: "Australia", region: Some(Oceania), subregion: Some(AustraliaAndNewZealand) },
+    Country { code: "AW", name: "Aruba", region: Some(Americas), subregion: Some(LatinAmericaAndTheCaribbean) },
+    Country { code: "AX", name: "Åland Islands", region: Some(Europe), subregion: Some(NorthernEurope) },
+    Country { code: "AZ", name: "Azerbaijan", region: Some(Asia), subregion: Some(WesternAsia) },
+    Country { code: "BA", name: "Bosnia and Herzegovina", region: Some(Europe), subregion: Some(SouthernEurope) },
+    Country { code: "BB", name: "Barbados", region: Some(Americas), subregion: Some(LatinAmericaAndTheCaribbean) },
+    Country { code: "BD", name: "Bangladesh", region: Some(Asia), subregion: Some(SouthernAsia) },
+    Country { code: "BE", name: "Belgium", region: Some(Europe), subregion: Some(WesternEurope) },
+    Country { code: "BF", name: "Burkina Faso", region: Some(Africa), subregion: Some(SubSaharanAfrica) },
+    Country { code: "BG", name: "Bulgaria", region: Some(Europe), subregion: Some(EasternEurope) },
+    Country { code: "BH", name: "Bahrain", region: Some(Asia), subregion: Some(WesternAsia) },
+    Country { code: "BI", name: "Burundi", region: Some(Africa), subregion: Some(SubSaharanAfrica) },
+    Country { code: "BJ", name: "Benin", region: Some(Africa), subregion: Some(SubSaharanAfrica) },
+    Country { code: "BL", name: "Saint Barthélemy", region: Some(Americas), subregion: Some(LatinAmericaAndTheCaribbean) },
+    Country { code: "BM", name: "Bermuda", region: Some(Americas), subregion: Some(NorthernAmerica) },
+    Country { code: "BN", name: "Brunei Darussalam", region: Some(Asia), subregion: Some(SouthEasternAsia) },
+    Country { code: "BO", name: "Bolivia, Plurinational State of", region: Some(Americas), subregion: Some(LatinAmericaAndTheCaribbean) },
+    Country { code: "BQ", name: "Bonaire, Sint Eustatius and Saba", region: Some(Americas), subregion: Some(LatinAmericaAndTheCaribbean) },
+    Country { code: "BR", name: "Brazil", region: Some(Americas), subregion: Some(LatinAmericaAndTheCaribbean) },
+    Country { code: "BS", name: "Bahamas", region: Some(Americas), subregion: Some(LatinAmericaAndTheCaribbean) },
+    Country { code: "BT", name: "Bhutan", region: Some(Asia), subregion: Some(SouthernAsia) },
+    Country { code: "BV", name: "Bouvet Island", region: Some(Americas), subregion: Some(LatinAmericaAndTheCaribbean) },
+    Country { code: "BW", name: "Botswana", region: Some(Africa), subregion: Some(SubSaharanAfrica) },
+    Country { code: "BY", name: "Belarus", region: Some(Europe), subregion: Some(EasternEurope) },
+    Country { code: "BZ", name: "Belize", region: Some(Americas), subregion: Some(LatinAmericaAndTheCaribbean) },
+    Country { code: "CA", name: "Canada", region: Some(Americas), subregion: Some(NorthernAmerica) },
+    Country { code: "CC", name: "Cocos (Keeling) Islands", region: Some(Oceania), subregion: Some(AustraliaAndNewZealand) },
+    Country { code: "CD", name: "Congo, The Democratic Republic of the", region: Some(Africa), subregion: Some(SubSaharanAfrica) },
+    Country { code: "CF", name: "Central African Republic", region: Some(Africa), subregion: Some(SubSaharanAfrica) },
+    Country { code: "CG", name: "Congo", region: Some(Africa), subregion: Some(SubSaharanAfrica) },
+    Country { code: "CH", name: "Switzerland", region: Some(Europe), subregion: Some(WesternEurope) },
+    Country { code: "CI", name: "Côte d'Ivoire", region: Some(Africa), subregion: Some(SubSaharanAfrica) },
+    Country { code: "CK", name: "Cook Islands", region: Some(Oceania), subregion: Some(Polynesia) },
+    Country { code: "CL", name: "Chile", region: Some(Americas), subregion: Some(LatinAmericaAndTheCaribbean) },
+    Country { code: "CM", name: "Cameroon", region: Some(Africa), subregion: Some(SubSaharanAfrica) },
+    Country { code: "CN", name: "China", region: Some(Asia), subregion: Some(EasternAsia) },
+    Country { code: "CO", name: "Colombia", region: Some(Americas), subregion: Some(LatinAmericaAndTheCaribbean) },
+    Country { code: "CR", name: "Costa Rica", region: Some(Americas), subregion: Some(LatinAmericaAndTheCaribbean) },
+    Country { code: "CU", name: "Cuba", region: Some(Americas), subregion: Some(LatinAmericaAndTheCaribbean) },
+    Country { code: "CV", name: "Cabo Verde", region: Some(Africa), subregion: Some(SubSaharanAfrica) },
+    Country { code: "CW", name: "Curaçao", region: Some(Americas), subregion: Some(LatinAmericaAndTheCaribbean) },
+    Country { code: "CX", name: "Christmas Island", region: Some(Oceania), subregion: Some(AustraliaAndNewZealand) },
+    Country { code: "CY", name: "Cyprus", region: Some(Asia), subregion: Some(WesternAsia) },
+    Country { code: "CZ", name: "Czechia", region: Some(Europe), subregion: Some(EasternEurope) },
+    Country { code: "DE", name: "Germany", region: Some(Europe), subregion: Some(WesternEurope) },
+    Country { code: "DJ", name: "Djibouti", region: Some(Africa), subregion: Some(SubSaharanAfrica) },
+    Country { code: "DK", name: "Denmark", region: Some(Europe), subregion: Some(NorthernEurope) },
+    Country { code: "DM", name: "Dominica", region: Some(Americas), subregion: Some(LatinAmericaAndTheCaribbean) },
+    Country { code: "DO", name: "Dominican Republic", region: Some(Americas), subregion: Some(LatinAmericaAndTheCaribbean) },
+    Country { code: "DZ", name: "Algeria", region: Some(Africa), subregion: Some(NorthernAfrica) },
+    Country { code: "EC", name: "Ecuador", region: Some(Americas), subregion: Some(LatinAmericaAndTheCaribbean) },
+    Country { code: "EE", name: "Estonia", region: Some(Europe), subregion: Some(NorthernEurope) },
+    Country { code: "EG", name: "Egypt", region: Some(Africa), subregion: Some(NorthernAfrica) },
+    Country { code: "EH", name: "Western Sahara", region: Some(Africa), subregion: Some(NorthernAfrica) },
+    Country { code: "ER", name: "Eritrea", region: Some(Africa), subregion: Some(SubSaharanAfrica) },
+    Country { code: "ES", name: "Spain", region: Some(Europe), subregion: Some(SouthernEurope) },
+    Country { code: "ET", name: "Ethiopia", region: Some(Africa), subregion: Some(SubSaharanAfrica) },
+    Country { code: "FI", name: "Finland", region: Some(Europe), subregion: Some(NorthernEurope) },
+    Country { code: "FJ", name: "Fiji", region: Some(Oceania), subregion: Some(Melanesia) },
+    Country { code: "FK", name: "Falkland Islands (Malvinas)", region: Some(Americas), subregion: Some(LatinAmericaAndTheCaribbean) },
+    Country { code: "FM", name: "Micronesia, Federated States of", region: Some(Oceania), subregion: Some(Micronesia) },
+    Country { code: "FO", name: "Faroe Islands", region: Some(Europe), subregion: Some(NorthernEurope) },
+    Country { code: "FR", name: "France", region: Some(Europe), subregion: Some(WesternEurope) },
+    Country { code: "GA", name: "Gabon", region: Some(Africa), subregion: Some(SubSaharanAfrica) },
+    Country { code: "GB", name: "United Kingdom", region: Some(Europe), subregion: Some(NorthernEurope) },
+    Country { code: "GD", name: "Grenada", region: Some(Americas), subregion: Some(LatinAmericaAndTheCaribbean) },
+    Country { code: "GE", name: "Georgia", region: Some(Asia), subregion: Some(WesternAsia) },
+    Country { code: "GF", name: "French Guiana", region: Some(Americas), subregion: Some(LatinAmericaAndTheCaribbean) },
+    Country { code: "GG", name: "Guernsey", region: Some(Europe), subregion: Some(NorthernEurope) },
+    Country { code: "GH", name: "Ghana", region: Some(Africa), subregion: Some(SubSaharanAfrica) },
+    Country { code: "GI", name: "Gibraltar", region: Some(Europe), subregion: Some(SouthernEurope) },
+    Country { code: "GL", name: "Greenland", region: Some(Americas), subregion: Some(NorthernAmerica) },
+    Country { code: "GM", name: "Gambia", region: Some(Africa), subregion: Some(SubSaharanAfrica) },
+    Country { code: "GN", name: "Guinea", region: Some(Africa), subregion: Some(SubSaharanAfrica) },
+    Country { code: "GP", name: "Guadeloupe", region: Some(Americas), subregion: Some(LatinAmericaAndTheCaribbean) },
+    Country { code: "GQ", name: "Equatorial Guinea", region: Some(Africa), subregion: Some(SubSaharanAfrica) },
+    Country { code: "GR", name: "Greece", region: Some(Europe), subregion: Some(SouthernEurope) },
+    Country { code: "GS", name: "South Georgia and the South Sandwich Islands", region: Some(Americas), subregion: Some(LatinAmericaAndTheCaribbean) },
+    Country { code: "GT", name: "Guatemala", region: Some(Americas), subregion: Some(LatinAmericaAndTheCaribbean) },
+    Country { code: "GU", name: "Guam", region: Some(Oceania), subregion: Some(Micronesia) },
+    Country { code: "GW", name: "Guinea-Bissau", region: Some(Africa), subregion: Some(SubSaharanAfrica) },
+    Country { code: "GY", name: "Guyana", region: Some(Americas), subregion: Some(LatinAmericaAndTheCaribbean) },
+    Country { code: "HK", name: "Hong Kong", region: Some(Asia), subregion: Some(EasternAsia) },
+    Country { code: "HM", name: "Heard Island and McDonald Islands", region: Some(Oceania), subregion: Some(AustraliaAndNewZealand) },
+    Country { code: "HN", name: "Honduras", region: Some(Americas), subregion: Some(LatinAmericaAndTheCaribbean) },
+    Country { code: "HR", name: "Croatia", region: Some(Europe), subregion: Some(SouthernEurope) },
+    Country { code: "HT", name: "Haiti", region: Some(Americas), subregion: Some(LatinAmericaAndTheCaribbean) },
+    Country { code: "HU", name: "Hungary", region: Some(Europe), subregion: Some(EasternEurope) },
+    Country { code: "ID", name: "Indonesia", region: Some(Asia), subregion: Some(SouthEasternAsia) },
+    Country { code: "IE", name: "Ireland", region: Some(Europe), subregion: Some(NorthernEurope) },
+    Country { code: "IL", name: "Israel", region: Some(Asia), subregion: Some(WesternAsia) },
+    Country { code: "IM", name: "Isle of Man", region: Some(Europe), subregion: Some(NorthernEurope) },
+    Country { code: "IN", name: "India", region: Some(Asia), subregion: Some(SouthernAsia) },
+    Country { code: "IO", name: "British Indian Ocean Territory", region: Some(Africa), subregion: Some(SubSaharanAfrica) },
+    Country { code: "IQ", name: "Iraq", region: Some(Asia), subregion: Some(WesternAsia) },
+    Country { code: "IR", name: "Iran, Islamic Republic of", region: Some(Asia), subregion: Some(SouthernAsia) },
+    Country { code: "IS", name: "Iceland", region: Some(Europe), subregion: Some(NorthernEurope) },
+    Country { code: "IT", name: "Italy", region: Some(Europe), subregion: Some(SouthernEurope) },
+    Country { code: "JE", name: "Jersey", region: Some(Europe), subregion: Some(NorthernEurope) },
+    Country { code: "JM", name: "Jamaica", region: Some(Americas), subregion: Some(LatinAmericaAndTheCaribbean) },
+    Country { code: "JO", name: "Jordan", region: Some(Asia), subregion: Some(WesternAsia) },
+    Country { code: "JP", name: "Japan", region: Some(Asia), subregion: Some(EasternAsia) },
+    Country { code: "KE", name: "Kenya", region: Some(Africa), subregion: Some(SubSaharanAfrica) },
+    Country { code: "KG", name: "Kyrgyzstan", region: Some(Asia), subregion: Some(CentralAsia) },
+    Country { code: "KH", name: "Cambodia", region: Some(Asia), subregion: Some(SouthEasternAsia) },
+    Country { code: "KI", name: "Kiribati", region: Some(Oceania), subregion: Some(Micronesia) },
+    Country { code: "KM", name: "Comoros", region: Some(Africa), subregion: Some(SubSaharanAfrica) },
+    Country { code: "KN", name: "Saint Kitts and Nevis", region: Some(Americas), subregion: Some(LatinAmericaAndTheCaribbean) },
+    Country { code: "KP", name: "Korea, Democratic People's Republic of", region: Some(Asia), subregion: Some(EasternAsia) },
+    Country { code: "KR", name: "Korea, Republic of", region: Some(Asia), subregion: Some(EasternAsia) },
+    Country { code: "KW", name: "Kuwait", region: Some(Asia), subregion: Some(WesternAsia) },
+    Country { code: "KY", name: "Cayman Islands", region: Some(Americas), subregion: Some(LatinAmericaAndTheCaribbean) },
+    Country { code: "KZ", name: "Kazakhstan", region: Some(Asia), subregion: Some(CentralAsia) },
+    Country { code: "LA", name: "Lao People's Democratic Republic", region: Some(Asia), subregion: Some(SouthEasternAsia) },
+    Country { code: "LB", name: "Lebanon", region: Some(Asia), subregion: Some(WesternAsia) },
+    Country { code: "LC", name: "Saint Lucia", region: Some(Americas), subregion: Some(LatinAmericaAndTheCaribbean) },
+    Country { code: "LI", name: "Liechtenstein", region: Some(Europe), subregion: Some(WesternEurope) },
+    Country { code: "LK", name: "Sri Lanka", region: Some(Asia), subregion: Some(SouthernAsia) },
+    Country { code: "LR", name: "Liberia", region: Some(Africa), subregion: Some(SubSaharanAfrica) },
+    Country { code: "LS", name: "Lesotho", region: Some(Africa), subregion: Some(SubSaharanAfrica) },
+    Country { code: "LT", name: "Lithuania", region: Some(Europe), subregion: Some(NorthernEurope) },
+    Country { code: "LU", name: "Luxembourg", region: Some(Europe), subregion: Some(WesternEurope) },
+    Country { code: "LV", name: "Latvia", region: Some(Europe), subregion: Some(NorthernEurope) },
+    Country { code: "LY", name: "Libya", region: Some(Africa), subregion: Some(NorthernAfrica) },
+    Country { code: "MA", name: "Morocco", region: Some(Africa), subregion: Some(NorthernAfrica) },
+    Country { code: "MC", name: "Monaco", region: Some(Europe), subregion: Some(WesternEurope) },
+    Country { code: "MD", name: "Moldova, Republic of", region: Some(Europe), subregion: Some(EasternEurope) },
+    Country { code: "ME", name: "Montenegro", region: Some(Europe), subregion: Some(SouthernEurope) },
+    Country { code: "MF", name: "Saint Martin (French part)", region: Some(Americas), subregion: Some(LatinAmericaAndTheCaribbean) },
+    Country { code: "MG", name: "Madagascar", region: Some(Africa), subregion: Some(SubSaharanAfrica) },
+    Country { code: "MH", name: "Marshall Islands", region: Some(Oceania), subregion: Some(Micronesia) },
+    Country { code: "MK", name: "North Macedonia", region: Some(Europe), subregion: Some(SouthernEurope) },
+    Country { code: "ML", name: "Mali", region: Some(Africa), subregion: Some(SubSaharanAfrica) },
+    Country { code: "MM", name: "Myanmar", region: Some(Asia), subregion: Some(SouthEasternAsia) },
+    Country { code: "MN", name: "Mongolia", region: Some(Asia), subregion: Some(EasternAsia) },
+    Country { code: "MO", name: "Macao", region: Some(Asia), subregion: Some(EasternAsia) },
+    Country { code: "MP", name: "Northern Mariana Islands", region: Some(Oceania), subregion: Some(Micronesia) },
+    Country { code: "MQ", name: "Martinique", region: Some(Americas), subregion: Some(LatinAmericaAndTheCaribbean) },
+    Country { code: "MR", name: "Mauritania", region: Some(Africa), subregion: Some(SubSaharanAfrica) },
+    Country { code: "MS", name: "Montserrat", region: Some(Americas), subregion: Some(LatinAmericaAndTheCaribbean) },
+    Country { code: "MT", name: "Malta", region: Some(Europe), subregion: Some(SouthernEurope) },
+    Country { code: "MU", name: "Mauritius", region: Some(Africa), subregion: Some(SubSaharanAfrica) },
+    Country { code: "MV", name: "Maldives", region: Some(Asia), subregion: Some(SouthernAsia) },
+    Country { code: "MW", name: "Malawi", region: Some(Africa), subregion: Some(SubSaharanAfrica) },
+    Country { code: "MX", name: "Mexico", region: Some(Americas), subregion: Some(LatinAmericaAndTheCaribbean) },
+    Country { code: "MY", name: "Malaysia", region: Some(Asia), subregion: Some(SouthEasternAsia) },
+    Country { code: "MZ", name: "Mozambique", region: Some(Africa), subregion: Some(SubSaharanAfrica) },
+    Country { code: "NA", name: "Namibia", region: Some(Africa), subregion: Some(SubSaharanAfrica) },
+    Country { code: "NC", name: "New Caledonia", region: Some(Oceania), subregion: Some(Melanesia) },
+    Country { code: "NE", name: "Niger", region: Some(Africa), subregion: Some(SubSaharanAfrica) },
+    Country { code: "NF", name: "Norfolk Island", region: Some(Oceania), subregion: Some(AustraliaAndNewZealand) },
+    Country { code: "NG", name: "Nigeria", region: Some(Africa), subregion: Some(SubSaharanAfrica) },
+    Country { code: "NI", name: "Nicaragua", region: Some(Americas), subregion: Some(LatinAmericaAndTheCaribbean) },
+    Country { code: "NL", name: "Netherlands", region: Some(Europe), subregion: Some(WesternEurope) },
+    Country { code: "NO", name: "Norway", region: Some(Europe), subregion: Some(NorthernEurope) },
+    Country { code: "NP", name: "Nepal", region: Some(Asia), subregion: Some(SouthernAsia) },
+    Country { code: "NR", name: "Nauru", region: Some(Oceania), subregion: Some(Micronesia) },
+    Country { code: "NU", name: "Niue", region: Some(Oceania), subregion: Some(Polynesia) },
+    Country { code: "NZ", name: "New Zealand", region: Some(Oceania), subregion: Some(AustraliaAndNewZealand) },
+    Country { code: "OM", name: "Oman", region: Some(Asia), subregion: Some(WesternAsia) },
+    Country { code: "PA", name: "Panama", region: Some(Americas), subregion: Some(LatinAmericaAndTheCaribbean) },
+    Country { code: "PE", name: "Peru", region: Some(Americas), subregion: Some(LatinAmericaAndTheCaribbean) },
+    Country { code: "PF", name: "French Polynesia", region: Some(Oceania), subregion: Some(Polynesia) },
+    Country { code: "PG", name: "Papua New Guinea", region: Some(Oceania), subregion: Some(Melanesia) },
+    Country { code: "PH", name: "Philippines", region: Some(Asia), subregion: Some(SouthEasternAsia) },
+    Country { code: "PK", name: "Pakistan", region: Some(Asia), subregion: Some(SouthernAsia) },
+    Country { code: "PL", name: "Poland", region: Some(Europe), subregion: Some(EasternEurope) },
+    Country { code: "PM", name: "Saint Pierre and Miquelon", region: Some(Americas), subregion: Some(NorthernAmerica) },
+    Country { code: "PN", name: "Pitcairn", region: Some(Oceania), subregion: Some(Polynesia) },
+    Country { code: "PR", name: "Puerto Rico", region: Some(Americas), subregion: Some(LatinAmericaAndTheCaribbean) },
+    Country { code: "PS", name: "Palestine, State of", region: Some(Asia), subregion: Some(WesternAsia) },
+    Country { code: "PT", name: "Portugal", region: Some(Europe), subregion: Some(SouthernEurope) },
+    Country { code: "PW", name: "Palau", region: Some(Oceania), subregion: Some(Micronesia) },
+    Country { code: "PY", name: "Paraguay", region: Some(Americas), subregion: Some(LatinAmericaAndTheCaribbean) },
+    Country { code: "QA", name: "Qatar", region: Some(Asia), subregion: Some(WesternAsia) },
+    Country { code: "RE", name: "Réunion", region: Some(Africa), subregion: Some(SubSaharanAfrica) },
+    Country { code: "RO", name: "Romania", region: Some(Europe), subregion: Some(EasternEurope) },
+    Country { code: "RS", name: "Serbia", region: Some(Europe), subregion: Some(SouthernEurope) },
+    Country { code: "RU", name: "Russian Federation", region: Some(Europe), subregion: Some(EasternEurope) },
+    Country { code: "RW", name: "Rwanda", region: Some(Africa), subregion: Some(SubSaharanAfrica) },
+    Country { code: "SA", name: "Saudi Arabia", region: Some(Asia), subregion: Some(WesternAsia) },
+    Country { code: "SB", name: "Solomon Islands", region: Some(Oceania), subregion: Some(Melanesia) },
+    Country { code: "SC", name: "Seychelles", region: Some(Africa), subregion: Some(SubSaharanAfrica) },
+    Country { code: "SD", name: "Sudan", region: Some(Africa), subregion: Some(NorthernAfrica) },
+    Country { code: "SE", name: "Sweden", region: Some(Europe), subregion: Some(NorthernEurope) },
+    Country { code: "SG", name: "Singapore", region: Some(Asia), subregion: Some(SouthEasternAsia) },
+    Country { code: "SH", name: "Saint Helena, Ascension and Tristan da Cunha", region: Some(Africa), subregion: Some(SubSaharanAfrica) },
+    Country { code: "SI", name: "Slovenia", region: Some(Europe), subregion: Some(SouthernEurope) },
+    Country { code: "SJ", name: "Svalbard and Jan Mayen", region: Some(Europe), subregion: Some(NorthernEurope) },
+    Country { code: "SK", name: "Slovakia", region: Some(Europe), subregion: Some(EasternEurope) },
+    Country { code: "SL", name: "Sierra Leone", region: Some(Africa), subregion: Some(SubSaharanAfrica) },
+    Country { code: "SM", name: "San Marino", region: Some(Europe), subregion: Some(SouthernEurope) },
+    Country { code: "SN", name: "Senegal", region: Some(Africa), subregion: Some(SubSaharanAfrica) },
+    Country { code: "SO", name: "Somalia", region: Some(Africa), subregion: Some(SubSaharanAfrica) },
+    Country { code: "SR", name: "Suriname", region: Some(Americas), subregion: Some(LatinAmericaAndTheCaribbean) },
+    Country { code: "SS", name: "South Sudan", region: Some(Africa), subregion: Some(SubSaharanAfrica) },
+    Country { code: "ST", name: "Sao Tome and Principe", region: Some(Africa), subregion: Some(SubSaharanAfrica) },
+    Country { code: "SV", name: "El Salvador", region: Some(Americas), subregion: Some(LatinAmericaAndTheCaribbean) },
+    Country { code: "SX", name: "Sint Maarten (Dutch part)", region: Some(Americas), subregion: Some(LatinAmericaAndTheCaribbean) },
+    Country { code: "SY", name: "Syrian Arab Republic", region: Some(Asia), subregion: Some(WesternAsia) },
+    Country { code: "SZ", name: "Eswatini", region: Some(Africa), subregion: Some(SubSaharanAfrica) },
+    Country { code: "TC", name: "Turks and Caicos Islands", region: Some(Americas), subregion: Some(LatinAmericaAndTheCaribbean) },
+    Country { code: "TD", name: "Chad", region: Some(Africa), subregion: Some(SubSaharanAfrica) },
+    Country { code: "TF", name: "French Southern Territories", region: Some(Africa), subregion: Some(SubSaharanAfrica) },
+    Country { code: "TG", name: "Togo", region: Some(Africa), subregion: Some(SubSaharanAfrica) },
+    Country { code: "TH", name: "Thailand", region: Some(Asia), subregion: Some(SouthEasternAsia) },
+    Country { code: "TJ", name: "Tajikistan", region: Some(Asia), subregion: Some(CentralAsia) },
+    Country { code: "TK", name: "Tokelau", region: Some(Oceania), subregion: Some(Polynesia) },
+    Country { code: "TL", name: "Timor-Leste", region: Some(Asia), subregion: Some(SouthEasternAsia) },
+    Country { code: "TM", name: "Turkmenistan", region: Some(Asia), subregion: Some(CentralAsia) },
+    Country { code: "TN", name: "Tunisia", region: Some(Africa), subregion: Some(NorthernAfrica) },
+    Country { code: "TO", name: "Tonga", region: Some(Oceania), subregion: Some(Polynesia) },
+    Country { code: "TR", name: "Türkiye", region: Some(Asia), subregion: Some(WesternAsia) },
+    Country { code: "TT", name: "Trinidad and Tobago", region: Some(Americas), subregion: Some(LatinAmericaAndTheCaribbean) },
+    Country { code: "TV", name: "Tuvalu", region: Some(Oceania), subregion: Some(Polynesia) },
+    Country { code: "TW", name: "Taiwan, Province of China", region: Some(Asia), subregion: Some(EasternAsia) },
+    Country { code: "TZ", name: "Tanzania, United Republic of", region: Some(Africa), subregion: Some(SubSaharanAfrica) },
+    Country { code: "UA", name: "Ukraine", region: Some(Europe), subregion: Some(EasternEurope) },
+    Country { code: "UG", name: "Uganda", region: Some(Africa), subregion: Some(SubSaharanAfrica) },
+    Country { code: "UM", name: "United States Minor Outlying Islands", region: Some(Oceania), subregion: Some(Micronesia) },
+    Country { code: "US", name: "United States", region: Some(Americas), subregion: Some(NorthernAmerica) },
+    Country { code: "UY", name: "Uruguay", region: Some(Americas), subregion: Some(LatinAmericaAndTheCaribbean) },
+    Country { code: "UZ", name: "Uzbekistan", region: Some(Asia), subregion: Some(CentralAsia) },
+    Country { code: "VA", name: "Holy See (Vatican City State)", region: Some(Europe), subregion: Some(SouthernEurope) },
+    Country { code: "VC", name: "Saint Vincent and the Grenadines", region: Some(Americas), subregion: Some(LatinAmericaAndTheCaribbean) },
+    Country { code: "VE", name: "Venezuela, Bolivarian Republic of", region: Some(Americas), subregion: Some(LatinAmericaAndTheCaribbean) },
+    Country { code: "VG", name: "Virgin Islands, British", region: Some(Americas), subregion: Some(LatinAmericaAndTheCaribbean) },
+    Country { code: "VI", name: "Virgin Islands, U.S.", region: Some(Americas), subregion: Some(LatinAmericaAndTheCaribbean) },
+    Country { code: "VN", name: "Viet Nam", region: Some(Asia), subregion: Some(SouthEasternAsia) },
+    Country { code: "VU", name: "Vanuatu", region: Some(Oceania), subregion: Some(Melanesia) },
+    Country { code: "WF", name: "Wallis and Futuna", region: Some(Oceania), subregion: Some(Polynesia) },
+    Country { code: "WS", name: "Samoa", region: Some(Oceania), subregion: Some(Polynesia) },
+    Country { code: "YE", name: "Yemen", region: Some(Asia), subregion: Some(WesternAsia) },
+    Country { code: "YT", name: "Mayotte", region: Some(Africa), subregion: Some(SubSaharanAfrica) },
+    Country { code: "ZA", name: "South Africa", region: Some(Africa), subregion: Some(SubSaharanAfrica) },
+    Country { code: "ZM", name: "Zambia", region: Some(Africa), subregion: Some(SubSaharanAfrica) },
+    Country { code: "ZW", name: "Zimbabwe", region: Some(Africa), subregion: Some(SubSaharanAfrica) },
+];
+
+#[rustfmt::skip]
+pub(super) static SUBDIVISIONS: &[Subdivision] = &[
+    Subdivision { code: "AD-02", name: "Canillo", parent: None },
+    Subdivision { code: "AD-03", name: "Encamp", parent: None },
+    Subdivision { code: "AD-04", name: "La Massana", parent: None },
+    Subdivision { code: "AD-05", name: "Ordino", parent: None },
+    Subdivision { code: "AD-06", name: "Sant Julià de Lòria", parent: None },
+    Subdivision { code: "AD-07", name: "Andorra la Vella", parent: None },
+    Subdivision { code: "AD-08", name: "Escaldes-Engordany", parent: None },
+    Subdivision { code: "AE-AJ", name: "‘Ajmān", parent: None },
+    Subdivision { code: "AE-AZ", name: "Abū Z̧aby", parent: None },
+    Subdivision { code: "AE-DU", name: "Dubayy", parent: None },
+    Subdivision { code: "AE-FU", name: "Al Fujayrah", parent: None },
+    Subdivision { code: "AE-RK", name: "Ra’s al Khaymah", parent: None },
+    Subdivision { code: "AE-SH", name: "Ash Shāriqah", parent: None },
+    Subdivision { code: "AE-UQ", name: "Umm al Qaywayn", parent: None },
+    Subdivision { code: "AF-BAL", name: "Balkh", parent: None },
+    Subdivision { code: "AF-BAM", name: "Bāmyān", parent: None },
+    Subdivision { code: "AF-BDG", name: "Bādghīs", parent: None },
+    Subdivision { code: "AF-BDS", name: "Badakhshān", parent: None },
+    Subdivision { code: "AF-BGL", name: "Baghlān", parent: None },
+    Subdivision { code: "AF-DAY", name: "Dāykundī", parent: None },
+    Subdivision { code: "AF-FRA", name: "Farāh", parent: None },
+    Subdivision { code: "AF-FYB", name: "Fāryāb", parent: None },
+    Subdivision { code: "AF-GHA", name: "Ghaznī", parent: None },
+    Subdivision { code: "AF-GHO", name: "Ghōr", parent: None },
+    Subdivision { code: "AF-HEL", name: "Helmand", parent: None },
+    Subdivision { code: "AF-HER", name: "Herāt", parent: None },
+    Subdivision { code: "AF-JOW", name: "Jowzjān", parent: None },
+    Subdivision { code: "AF-KAB", name: "Kābul", parent: None },
+    Subdivision { code: "AF-KAN", name: "Kandahār", parent: None },
+    Subdivision { code: "AF-KAP", name: "Kāpīsā", parent: None },
+    Subdivision { code: "AF-KDZ", name: "Kunduz", parent: None },
+    Subdivision { code: "AF-KHO", name: "Khōst", parent: None },
+    Subdivision { code: "AF-KNR", name: "Kunaṟ", parent: None },
+    Subdivision { code: "AF-LAG", name: "Laghmān", parent: None },
+    Subdivision { code: "AF-LOG", name: "Lōgar", parent: None },
+    Subdivision { code: "AF-NAN", name: "Nangarhār", parent: None },
+    Subdivision { code: "AF-NIM", name: "Nīmrōz", parent: None },
+    Subdivision { code: "AF-NUR", name: "Nūristān", parent: None },
+    Subdivision { code: "AF-PAN", name: "Panjshayr", parent: None },
+    Subdivision { code: "AF-PAR", name: "Parwān", parent: None },
+    Subdivision { code: "AF-PIA", name: "Paktiyā", parent: None },
+    Subdivision { code: "AF-PKA", name: "Paktīkā", parent: None },
+    Subdivision { code: "AF-SAM", name: "Samangān", parent: None },
+    Subdivision { code: "AF-SAR", name: "Sar-e Pul", parent: None },
+    Subdivision { code: "AF-TAK", name: "Takhār", parent: None },
+    Subdivision { code: "AF-URU", name: "Uruzgān", parent: None },
+    Subdivision { code: "AF-WAR", name: "Wardak", parent: None },
+    Subdivision { code: "AF-ZAB", name: "Zābul", parent: None },
+    Subdivision { code: "AG-03", name: "Saint George", parent: None },
+    Subdivision { code: "AG-04", name: "Saint John", parent: None },
+    Subdivision { code: "AG-05", name: "Saint Mary", parent: None },
+    Subdivision { code: "AG-06", name: "Saint Paul", parent: None },
+    Subdivision { code: "AG-07", name: "Saint Peter", parent: None },
+    Subdivision { code: "AG-08", name: "Saint Philip", parent: None },
+    Subdivision { code: "AG-10", name: "Barbuda", parent: None },
+    Subdivision { code: "AG-11", name: "Redonda", parent: None },
+    Subdivision { code: "AL-01", name: "Berat", parent: None },
+    Subdivision { code: "AL-02", name: "Durrës", parent: None },
+    Subdivision { code: "AL-03", name: "Elbasan", parent: None },
+    Subdivision { code: "AL-04", name: "Fier", parent: None },
+    Subdivision { code: "AL-05", name: "Gjirokastër", parent: None },
+    Subdivision { code: "AL-06", name: "Korçë", parent: None },
+    Subdivision { code: "AL-07", name: "Kukës", parent: None },
+    Subdivision { code: "AL-08", name: "Lezhë", parent: None },
+    Subdivision { code: "AL-09", name: "Dibër", parent: None },
+    Subdivision { code: "AL-10", name: "Shkodër", parent: None },
+    Subdivision { code: "AL-11", name: "Tiranë", parent: None },
+    Subdivision { code: "AL-12", name: "Vlorë", parent: None },
+    Subdivision { code: "AM-AG", name: "Aragac̣otn", parent: None },
+    Subdivision { code: "AM-AR", name: "Ararat", parent: None },
+    Subdivision { code: "AM-AV", name: "Armavir", parent: None },
+    Subdivision { code: "AM-ER", name: "Erevan", parent: None },
+    Subdivision { code: "AM-GR", name: "Geġark'unik'", parent: None },
+    Subdivision { code: "AM-KT", name: "Kotayk'", parent: None },
+    Subdivision { code: "AM-LO", name: "Loṙi", parent: None },
+    Subdivision { code: "AM-SH", name: "Širak", parent: None },
+    Subdivision { code: "AM-SU", name: "Syunik'", parent: None },
+    Subdivision { code: "AM-TV", name: "Tavuš", parent: None },
+    Subdivision { code: "AM-VD", name: "Vayoć Jor", parent: None },
+    Subdivision { code: "AO-BGO", name: "Bengo", parent: None },
+    Subdivision { code: "AO-BGU", name: "Benguela", parent: None },
+    Subdivision { code: "AO-BIE", name: "Bié", parent: None },
+    Subdivision { code: "AO-CAB", name: "Cabinda", parent: None },
+    Subdivision { code: "AO-CCU", name: "Cuando Cubango", parent: None },
+    Subdivision { code: "AO-CNN", name: "Cunene", parent: None },
+    Subdivision { code: "AO-CNO", name: "Cuanza-Norte", parent: None },
+    Subdivision { code: "AO-CUS", name: "Cuanza-Sul", parent: None },
+    Subdivision { code: "AO-HUA", name: "Huambo", parent: None },
+    Subdivision { code: "AO-HUI", name: "Huíla", parent: None },
+    Subdivision { code: "AO-LNO", name: "Lunda-Norte", parent: None },
+    Subdivision { code: "AO-LSU", name: "Lunda-Sul", parent: None },
+    Subdivision { code: "AO-LUA", name: "Luanda", parent: None },
+    Subdivision { code: "AO-MAL", name: "Malange", parent: None },
+    Subdivision { code: "AO-MOX", name: "Moxico", parent: None },
+    Subdivision { code: "AO-NAM", name: "Namibe", parent: None },
+    Subdivision { code: "AO-UIG", name: "Uíge", parent: None },
+    Subdivision { code: "AO-ZAI", name: "Zaire", parent: None },
+    Subdivision { code: "AR-A", name: "Salta", parent: None },
+    Subdivision { code: "AR-B", name: "Buenos Aires", parent: None },
+    Subdivision { code: "AR-C", name: "Ciudad Autónoma de Buenos Aires", parent: None },
+    Subdivision { code: "AR-D", name: "San Luis", parent: None },
+    Subdivision { code: "AR-E", name: "Entre Ríos", parent: None },
+    Subdivision { code: "AR-F", name: "La Rioja", parent: None },
+    Subdivision { code: "AR-G", name: "Santiago del Estero", parent: None },
+    Subdivision { code: "AR-H", name: "Chaco", parent: None },
+    Subdivision { code: "AR-J", name: "San Juan", parent: None },
+    Subdivision { code: "AR-K", name: "Catamarca", parent: None },
+    Subdivision { code: "AR-L", name: "La Pampa", parent: None },
+    Subdivision { code: "AR-M", name: "Mendoza", parent: None },
+    Subdivision { code: "AR-N", name: "Misiones", parent: None },
+    Subdivision { code: "AR-P", name: "Formosa", parent: None },
+    Subdivision { code: "AR-Q", name: "Neuquén", parent: None },
+    Subdivision { code: "AR-R", name: "Río Negro", parent: None },
+    Subdivision { code: "AR-S", name: "Santa Fe", parent: None },
+    Subdivision { code: "AR-T", name: "Tucumán", parent: None },
+    Subdivision { code: "AR-U", name: "Chubut", parent: None },
+    Subdivision { code: "AR-V", name: "Tierra del Fuego", parent: None },
+    Subdivision { code: "AR-W", name: "Corrientes", parent: None },
+    Subdivision { code: "AR-X", name: "Córdoba", parent: None },
+    Subdivision { code: "AR-Y", name: "Jujuy", parent: None },
+    Subdivision { code: "AR-Z", name: "Santa Cruz", parent: None },
+    Subdivision { code: "AT-1", name: "Burgenland", parent: None },
+    Subdivision { code: "AT-2", name: "Kärnten", parent: None },
+    Subdivision { code: "AT-3", name: "Niederösterreich", parent: None },
+    Subdivision { code: "AT-4", name: "Oberösterreich", parent: None },
+    Subdivision { code: "AT-5", name: "Salzburg", parent: None },
+    Subdivision { code: "AT-6", name: "Steiermark", parent: None },
+    Subdivision { code: "AT-7", name: "Tirol", parent: None },
+    Subdivision { code: "AT-8", name: "Vorarlberg", parent: None },
+    Subdivision { code: "AT-9", name: "Wien", parent: None },
+    Subdivision { code: "AU-ACT", name: "Australian Capital Territory", parent: None },
+    Subdivision { code: "AU-NSW", name: "New South Wales", parent: None },
+    Subdivision { code: "AU-NT", name: "Northern Territory", parent: None },
+    Subdivision { code: "AU-QLD", name: "Queensland", parent: None },
+    Subdivision { code: "AU-SA", name: "South Australia", parent: None },
+    Subdivision { code: "AU-TAS", name: "Tasmania", parent: None },
+    Subdivision { code: "AU-VIC", name: "Victoria", parent: None },
+    Subdivision { code: "AU-WA", name: "Western Australia", parent: None },
+    Subdivision { code: "AZ-ABS", name: "Abşeron", parent: None },
+    Subdivision { code: "AZ-AGA", name: "Ağstafa", parent: None },
+    Subdivision { code: "AZ-AGC", name: "Ağcabədi", parent: None },
+    Subdivision { code: "AZ-AGM", name: "Ağdam", parent: None },
+    Subdivision { code: "AZ-AGS", name: "Ağdaş", parent: None },
+    Subdivision { code: "AZ-AGU", name: "Ağsu", parent: None },
+    Subdivision { code: "AZ-AST", name: "Astara", parent: None },
+    Subdivision { code: "AZ-BA", name: "Bakı", parent: None },
+    Subdivision { code: "AZ-BAB", name: "Babək", parent: Some("AZ-NX") },
+    Subdivision { code: "AZ-BAL", name: "Balakən", parent: None },
+    Subdivision { code: "AZ-BAR", name: "Bərdə", parent: None },
+    Subdivision { code: "AZ-BEY", name: "Beyləqan", parent: None },
+    Subdivision { code: "AZ-BIL", name: "Biləsuvar", parent: None },
+    Subdivision { code: "AZ-CAB", name: "Cəbrayıl", parent: None },
+    Subdivision { code: "AZ-CAL", name: "Cəlilabad", parent: None },
+    Subdivision { code: "AZ-CUL", name: "Culfa", parent: Some("AZ-NX") },
+    Subdivision { code: "AZ-DAS", name: "Daşkəsən", parent: None },
+    Subdivision { code: "AZ-FUZ", name: "Füzuli", parent: None },
+    Subdivision { code: "AZ-GA", name: "Gəncə", parent: None },
+    Subdivision { code: "AZ-GAD", name: "Gədəbəy", parent: None },
+    Subdivision { code: "AZ-GOR", name: "Goranboy", parent: None },
+    Subdivision { code: "AZ-GOY", name: "Göyçay", parent: None },
+    Subdivision { code: "AZ-GYG", name: "Göygöl", parent: None },
+    Subdivision { code: "AZ-HAC", name: "Hacıqabul", parent: None },
+    Subdivision { code: "AZ-IMI", name: "İmişli", parent: None },
+    Subdivision { code: "AZ-ISM", name: "İsmayıllı", parent: None },
+    Subdivision { code: "AZ-KAL", name: "Kəlbəcər", parent: None },
+    Subdivision { code: "AZ-KAN", name: "Kǝngǝrli", parent: Some("AZ-NX") },
+    Subdivision { code: "AZ-KUR", name: "Kürdəmir", parent: None },
+    Subdivision { code: "AZ-LA", name: "Lənkəran", parent: None },
+    Subdivision { code: "AZ-LAC", name: "Laçın", parent: None },
+    Subdivision { code: "AZ-LAN", name: "Lənkəran", parent: None },
+    Subdivision { code: "AZ-LER", name: "Lerik", parent: None },
+    Subdivision { code: "AZ-MAS", name: "Masallı", parent: None },
+    Subdivision { code: "AZ-MI", name: "Mingəçevir", parent: None },
+    Subdivision { code: "AZ-NA", name: "Naftalan", parent: None },
+    Subdivision { code: "AZ-NEF", name: "Neftçala", parent: None },
+    Subdivision { code: "AZ-NV", name: "Naxçıvan", parent: Some("AZ-NX") },
+    Subdivision { code: "AZ-NX", name: "Naxçıvan", parent: None },
+    Subdivision { code: "AZ-OGU", name: "Oğuz", parent: None },
+    Subdivision { code: "AZ-ORD", name: "Ordubad", parent: Some("AZ-NX") },
+    Subdivision { code: "AZ-QAB", name: "Qəbələ", parent: None },
+    Subdivision { code: "AZ-QAX", name: "Qax", parent: None },
+    Subdivision { code: "AZ-QAZ", name: "Qazax", parent: None },
+    Subdivision { code: "AZ-QBA", name: "Quba", parent: None },
+    Subdivision { code: "AZ-QBI", name: "Qubadlı", parent: None },
+    Subdivision { code: "AZ-QOB", name: "Qobustan", parent: None },
+    Subdivision { code: "AZ-QUS", name: "Qusar", parent: None },
+    Subdivision { code: "AZ-SA", name: "Şəki", parent: None },
+    Subdivision { code: "AZ-SAB", name: "Sabirabad", parent: None },
+    Subdivision { code: "AZ-SAD", name: "Sədərək", parent: Some("AZ-NX") },
+    Subdivision { code: "AZ-SAH", name: "Şahbuz", parent: Some("AZ-NX") },
+    Subdivision { code: "AZ-SAK", name: "Şəki", parent: None },
+    Subdivision { code: "AZ-SAL", name: "Salyan", parent: None },
+    Subdivision { code: "AZ-SAR", name: "Şərur", parent: Some("AZ-NX") },
+    Subdivision { code: "AZ-SAT", name: "Saatlı", parent: None },
+    Subdivision { code: "AZ-SBN", name: "Şabran", parent: None },
+    Subdivision { code: "AZ-SIY", name: "Siyəzən", parent: None },
+    Subdivision { code: "AZ-SKR", name: "Şəmkir", parent: None },
+    Subdivision { code: "AZ-SM", name: "Sumqayıt", parent: None },
+    Subdivision { code: "AZ-SMI", name: "Şamaxı", parent: None },
+    Subdivision { code: "AZ-SMX", name: "Samux", parent: None },
+    Subdivision { code: "AZ-SR", name: "Şirvan", parent: None },
+    Subdivision { code: "AZ-SUS", name: "Şuşa", parent: None },
+    Subdivision { code: "AZ-TAR", name: "Tərtər", parent: None },
+    Subdivision { code: "AZ-TOV", name: "Tovuz", parent: None },
+    Subdivision { code: "AZ-UCA", name: "Ucar", parent: None },
+    Subdivision { code: "AZ-XA", name: "Xankəndi", parent: None },
+    Subdivision { code: "AZ-XAC", name: "Xaçmaz", parent: None },
+    Subdivision { code: "AZ-XCI", name: "Xocalı", parent: None },
+    Subdivision { code: "AZ-XIZ", name: "Xızı", parent: None },
+    Subdivision { code: "AZ-XVD", name: "Xocavənd", parent: None },
+    Subdivision { code: "AZ-YAR", name: "Yardımlı", parent: None },
+    Subdivision { code: "AZ-YE", name: "Yevlax", parent: None },
+    Subdivision { code: "AZ-YEV", name: "Yevlax", parent: None },
+    Subdivision { code: "AZ-ZAN", name: "Zəngilan", parent: None },
+    Subdivision { code: "AZ-ZAQ", name: "Zaqatala", parent: None },
+    Subdivision { code: "AZ-ZAR", name: "Zərdab", parent: None },
+    Subdivision { code: "BA-BIH", name: "Federacija Bosne i Hercegovine", parent: None },
+    Subdivision { code: "BA-BRC", name: "Brčko distrikt", parent: None },
+    Subdivision { code: "BA-SRP", name: "Republika Srpska", parent: None },
+    Subdivision { code: "BB-01", name: "Christ Church", parent: None },
+    Subdivision { code: "BB-02", name: "Saint Andrew", parent: None },
+    Subdivision { code: "BB-03", name: "Saint George", parent: None },
+    Subdivision { code: "BB-04", name: "Saint James", parent: None },
+    Subdivision { code: "BB-05", name: "Saint John", parent: None },
+    Subdivision { code: "BB-06", name: "Saint Joseph", parent: None },
+    Subdivision { code: "BB-07", name: "Saint Lucy", parent: None },
+    Subdivision { code: "BB-08", name: "Saint Michael", parent: None },
+    Subdivision { code: "BB-09", name: "Saint Peter", parent: None },
+    Subdivision { code: "BB-10", name: "Saint Philip", parent: None },
+    Subdivision { code: "BB-11", name: "Saint Thomas", parent: None },
+    Subdivision { code: "BD-01", name: "Bandarban", parent: Some("BD-B") },
+    Subdivision { code: "BD-02", name: "Barguna", parent: Some("BD-A") },
+    Subdivision { code: "BD-03", name: "Bogura", parent: Some("BD-E") },
+    Subdivision { code: "BD-04", name: "Brahmanbaria", parent: Some("BD-B") },
+    Subdivision { code: "BD-05", name: "Bagerhat", parent: Some("BD-D") },
+    Subdivision { code: "BD-06", name: "Barishal", parent: Some("BD-A") },
+    Subdivision { code: "BD-07", name: "Bhola", parent: Some("BD-A") },
+    Subdivision { code: "BD-08", name: "Cumilla", parent: Some("BD-B") },
+    Subdivision { code: "BD-09", name: "Chandpur", parent: Some("BD-B") },
+    Subdivision { code: "BD-10", name: "Chattogram", parent: Some("BD-B") },
+    Subdivision { code: "BD-11", name: "Cox's Bazar", parent: Some("BD-B") },
+    Subdivision { code: "BD-12", name: "Chuadanga", parent: Some("BD-D") },
+    Subdivision { code: "BD-13", name: "Dhaka", parent: Some("BD-C") },
+    Subdivision { code: "BD-14", name: "Dinajpur", parent: Some("BD-F") },
+    Subdivision { code: "BD-15", name: "Faridpur", parent: Some("BD-C") },
+    Subdivision { code: "BD-16", name: "Feni", parent: Some("BD-B") },
+    Subdivision { code: "BD-17", name: "Gopalganj", parent: Some("BD-C") },
+    Subdivision { code: "BD-18", name: "Gazipur", parent: Some("BD-C") },
+    Subdivision { code: "BD-19", name: "Gaibandha", parent: Some("BD-F") },
+    Subdivision { code: "BD-20", name: "Habiganj", parent: Some("BD-G") },
+    Subdivision { code: "BD-21", name: "Jamalpur", parent: Some("BD-H") },
+    Subdivision { code: "BD-22", name: "Jashore", parent: Some("BD-D") },
+    Subdivision { code: "BD-23", name: "Jhenaidah", parent: Some("BD-D") },
+    Subdivision { code: "BD-24", name: "Joypurhat", parent: Some("BD-E") },
+    Subdivision { code: "BD-25", name: "Jhalakathi", parent: Some("BD-A") },
+    Subdivision { code: "BD-26", name: "Kishoreganj", parent: Some("BD-C") },
+    Subdivision { code: "BD-27", name: "Khulna", parent: Some("BD-D") },
+    Subdivision { code: "BD-28", name: "Kurigram", parent: Some("BD-F") },
+    Subdivision { code: "BD-29", name: "Khagrachhari", parent: Some("BD-B") },
+    Subdivision { code: "BD-30", name: "Kushtia", parent: Some("BD-D") },
+    Subdivision { code: "BD-31", name: "Lakshmipur", parent: Some("BD-B") },
+    Subdivision { code: "BD-32", name: "Lalmonirhat", parent: Some("BD-F") },
+    Subdivision { code: "BD-33", name: "Manikganj", parent: Some("BD-C") },
+    Subdivision { code: "BD-34", name: "Mymensingh", parent: Some("BD-H") },
+    Subdivision { code: "BD-35", name: "Munshiganj", parent: Some("BD-C") },
+    Subdivision { code: "BD-36", name: "Madaripur", parent: Some("BD-C") },
+    Subdivision { code: "BD-37", name: "Magura", parent: Some("BD-D") },
+    Subdivision { code: "BD-38", name: "Moulvibazar", parent: Some("BD-G") },
+    Subdivision { code: "BD-39", name: "Meherpur", parent: Some("BD-D") },
+    Subdivision { code: "BD-40", name: "Narayanganj", parent: Some("BD-C") },
+    Subdivision { code: "BD-41", name: "Netrakona", parent: Some("BD-H") },
+    Subdivision { code: "BD-42", name: "Narsingdi", parent: Some("BD-C") },
+    Subdivision { code: "BD-43", name: "Narail", parent: Some("BD-D") },
+    Subdivision { code: "BD-44", name: "Natore", parent: Some("BD-E") },
+    Subdivision { code: "BD-45", name: "Chapai Nawabganj", parent: Some("BD-E") },
+    Subdivision { code: "BD-46", name: "Nilphamari", parent: Some("BD-F") },
+    Subdivision { code: "BD-47", name: "Noakhali", parent: Some("BD-B") },
+    Subdivision { code: "BD-48", name: "Naogaon", parent: Some("BD-E") },
+    Subdivision { code: "BD-49", name: "Pabna", parent: Some("BD-E") },
+    Subdivision { code: "BD-50", name: "Pirojpur", parent: Some("BD-A") },
+    Subdivision { code: "BD-51", name: "Patuakhali", parent: Some("BD-A") },
+    Subdivision { code: "BD-52", name: "Panchagarh", parent: Some("BD-F") },
+    Subdivision { code: "BD-53", name: "Rajbari", parent: Some("BD-C") },
+    Subdivision { code: "BD-54", name: "Rajshahi", parent: Some("BD-E") },
+    Subdivision { code: "BD-55", name: "Rangpur", parent: Some("BD-F") },
+    Subdivision { code: "BD-56", name: "Rangamati", parent: Some("BD-B") },
+    Subdivision { code: "BD-57", name: "Sherpur", parent: Some("BD-H") },
+    Subdivision { code: "BD-58", name: "Satkhira", parent: Some("BD-D") },
+    Subdivision { code: "BD-59", name: "Sirajganj", parent: Some("BD-E") },
+    Subdivision { code: "BD-60", name: "Sylhet", parent: Some("BD-G") },
+    Subdivision { code: "BD-61", name: "Sunamganj", parent: Some("BD-G") },
+    Subdivision { code: "BD-62", name: "Shariatpur", parent: Some("BD-C") },
+    Subdivision { code: "BD-63", name: "Tangail", parent: Some("BD-C") },
+    Subdivision { code: "BD-64", name: "Thakurgaon", parent: Some("BD-F") },
+    Subdivision { code: "BD-A", name: "Barishal", parent: None },
+    Subdivision { code: "BD-B", name: "Chattogram", parent: None },
+    Subdivision { code: "BD-C", name: "Dhaka", parent: None },
+    Subdivision { code: "BD-D", name: "Khulna", parent: None },
+    Subdivision { code: "BD-E", name: "Rajshahi", parent: None },
+    Subdivision { code: "BD-F", name: "Rangpur", parent: None },
+    Subdivision { code: "BD-G", name: "Sylhet", parent: None },
+    Subdivision { code: "BD-H", name: "Mymensingh", parent: None },
+    Subdivision { code: "BE-BRU", name: "Brussels Hoofdstedelijk Gewest", parent: None },
+    Subdivision { code: "BE-VAN", name: "Antwerpen", parent: Some("BE-VLG") },
+    Subdivision { code: "BE-VBR", name: "Vlaams-Brabant", parent: Some("BE-VLG") },
+    Subdivision { code: "BE-VLG", name: "Vlaams Gewest", parent: None },
+    Subdivision { code: "BE-VLI", name: "Limburg", parent: Some("BE-VLG") },
+    Subdivision { code: "BE-VOV", name: "Oost-Vlaanderen", parent: Some("BE-VLG") },
+    Subdivision { code: "BE-VWV", name: "West-Vlaanderen", parent: Some("BE-VLG") },
+    Subdivision { code: "BE-WAL", name: "wallonne, Région", parent: None },
+    Subdivision { code: "BE-WBR", name: "Brabant wallon", parent: Some("BE-WAL") },
+    Subdivision { code: "BE-WHT", name: "Hainaut", parent: Some("BE-WAL") },
+    Subdivision { code: "BE-WLG", name: "Liège", parent: Some("BE-WAL") },
+    Subdivision { code: "BE-WLX", name: "Luxembourg", parent: Some("BE-WAL") },
+    Subdivision { code: "BE-WNA", name: "Namur", parent: Some("BE-WAL") },
+    Subdivision { code: "BF-01", name: "Boucle du Mouhoun", parent: None },
+    Subdivision { code: "BF-02", name: "Cascades", parent: None },
+    Subdivision { code: "BF-03", name: "Centre", parent: None },
+    Subdivision { code: "BF-04", name: "Centre-Est", parent: None },
+    Subdivision { code: "BF-05", name: "Centre-Nord", parent: None },
+    Subdivision { code: "BF-06", name: "Centre-Ouest", parent: None },
+    Subdivision { code: "BF-07", name: "Centre-Sud", parent: None },
+    Subdivision { code: "BF-08", name: "Est", parent: None },
+    Subdivision { code: "BF-09", name: "Hauts-Bassins", parent: None },
+    Subdivision { code: "BF-10", name: "Nord", parent: None },
+    Subdivision { code: "BF-11", name: "Plateau-Central", parent: None },
+    Subdivision { code: "BF-12", name: "Sahel", parent: None },
+    Subdivision { code: "BF-13", name: "Sud-Ouest", parent: None },
+    Subdivision { code: "BF-BAL", name: "Balé", parent: Some("BF-01") },
+    Subdivision { code: "BF-BAM", name: "Bam", parent: Some("BF-05") },
+    Subdivision { code: "BF-BAN", name: "Banwa", parent: Some("BF-01") },
+    Subdivision { code: "BF-BAZ", name: "Bazèga", parent: Some("BF-07") },
+    Subdivision { code: "BF-BGR", name: "Bougouriba", parent: Some("BF-13") },
+    Subdivision { code: "BF-BLG", name: "Boulgou", parent: Some("BF-04") },
+    Subdivision { code: "BF-BLK", name: "Boulkiemdé", parent: Some("BF-06") },
+    Subdivision { code: "BF-COM", name: "Comoé", parent: Some("BF-02") },
+    Subdivision { code: "BF-GAN", name: "Ganzourgou", parent: Some("BF-11") },
+    Subdivision { code: "BF-GNA", name: "Gnagna", parent: Some("BF-08") },
+    Subdivision { code: "BF-GOU", name: "Gourma", parent: Some("BF-08") },
+    Subdivision { code: "BF-HOU", name: "Houet", parent: Some("BF-09") },
+    Subdivision { code: "BF-IOB", name: "Ioba", parent: Some("BF-13") },
+    Subdivision { code: "BF-KAD", name: "Kadiogo", parent: Some("BF-03") },
+    Subdivision { code: "BF-KEN", name: "Kénédougou", parent: Some("BF-09") },
+    Subdivision { code: "BF-KMD", name: "Komondjari", parent: Some("BF-08") },
+    Subdivision { code: "BF-KMP", name: "Kompienga", parent: Some("BF-08") },
+    Subdivision { code: "BF-KOP", name: "Koulpélogo", parent: Some("BF-04") },
+    Subdivision { code: "BF-KOS", name: "Kossi", parent: Some("BF-01") },
+    Subdivision { code: "BF-KOT", name: "Kouritenga", parent: Some("BF-04") },
+    Subdivision { code: "BF-KOW", name: "Kourwéogo", parent: Some("BF-11") },
+    Subdivision { code: "BF-LER", name: "Léraba", parent: Some("BF-02") },
+    Subdivision { code: "BF-LOR", name: "Loroum", parent: Some("BF-10") },
+    Subdivision { code: "BF-MOU", name: "Mouhoun", parent: Some("BF-01") },
+    Subdivision { code: "BF-NAM", name: "Namentenga", parent: Some("BF-05") },
+    Subdivision { code: "BF-NAO", name: "Nahouri", parent: Some("BF-07") },
+    Subdivision { code: "BF-NAY", name: "Nayala", parent: Some("BF-01") },
+    Subdivision { code: "BF-NOU", name: "Noumbiel", parent: Some("BF-13") },
+    Subdivision { code: "BF-OUB", name: "Oubritenga", parent: Some("BF-11") },
+    Subdivision { code: "BF-OUD", name: "Oudalan", parent: Some("BF-12") },
+    Subdivision { code: "BF-PAS", name: "Passoré", parent: Some("BF-10") },
+    Subdivision { code: "BF-PON", name: "Poni", parent: Some("BF-13") },
+    Subdivision { code: "BF-SEN", name: "Séno", parent: Some("BF-12") },
+    Subdivision { code: "BF-SIS", name: "Sissili", parent: Some("BF-06") },
+    Subdivision { code: "BF-SMT", name: "Sanmatenga", parent: Some("BF-05") },
+    Subdivision { code: "BF-SNG", name: "Sanguié", parent: Some("BF-06") },
+    Subdivision { code: "BF-SOM", name: "Soum", parent: Some("BF-12") },
+    Subdivision { code: "BF-SOR", name: "Sourou", parent: Some("BF-01") },
+    Subdivision { code: "BF-TAP", name: "Tapoa", parent: Some("BF-08") },
+    Subdivision { code: "BF-TUI", name: "Tuy", parent: Some("BF-09") },
+    Subdivision { code: "BF-YAG", name: "Yagha", parent: Some("BF-12") },
+    Subdivision { code: "BF-YAT", name: "Yatenga", parent: Some("BF-10") },
+    Subdivision { code: "BF-ZIR", name: "Ziro", parent: Some("BF-06") },
+    Subdivision { code: "BF-ZON", name: "Zondoma", parent: Some("BF-10") },
+    Subdivision { code: "BF-ZOU", name: "Zoundwéogo", parent: Some("BF-07") },
+    Subdivision { code: "BG-01", name: "Blagoevgrad", parent: None },
+    Subdivision { code: "BG-02", name: "Burgas", parent: None },
+    Subdivision { code: "BG-03", name: "Varna", parent: None },
+    Subdivision { code: "BG-04", name: "Veliko Tarnovo", parent: None },
+    Subdivision { code: "BG-05", name: "Vidin", parent: None },
+    Subdivision { code: "BG-06", name: "Vratsa", parent: None },
+    Subdivision { code: "BG-07", name: "Gabrovo", parent: None },
+    Subdivision { code: "BG-08", name: "Dobrich", parent: None },
+    Subdivision { code: "BG-09", name: "Kardzhali", parent: None },
+    Subdivision { code: "BG-10", name: "Kyustendil", parent: None },
+    Subdivision { code: "BG-11", name: "Lovech", parent: None },
+    Subdivision { code: "BG-12", name: "Montana", parent: None },
+    Subdivision { code: "BG-13", name: "Pazardzhik", parent: None },
+    Subdivision { code: "BG-14", name: "Pernik", parent: None },
+    Subdivision { code: "BG-15", name: "Pleven", parent: None },
+    Subdivision { code: "BG-16", name: "Plovdiv", parent: None },
+    Subdivision { code: "BG-17", name: "Razgrad", parent: None },
+    Subdivision { code: "BG-18", name: "Ruse", parent: None },
+    Subdivision { code: "BG-19", name: "Silistra", parent: None },
+    Subdivision { code: "BG-20", name: "Sliven", parent: None },
+    Subdivision { code: "BG-21", name: "Smolyan", parent: None },
+    Subdivision { code: "BG-22", name: "Sofia (stolitsa)", parent: None },
+    Subdivision { code: "BG-23", name: "Sofia", parent: None },
+    Subdivision { code: "BG-24", name: "Stara Zagora", parent: None },
+    Subdivision { code: "BG-25", name: "Targovishte", parent: None },
+    Subdivision { code: "BG-26", name: "Haskovo", parent: None },
+    Subdivision { code: "BG-27", name: "Shumen", parent: None },
+    Subdivision { code: "BG-28", name: "Yambol", parent: None },
+    Subdivision { code: "BH-13", name: "Al ‘Āşimah", parent: None },
+    Subdivision { code: "BH-14", name: "Al Janūbīyah", parent: None },
+    Subdivision { code: "BH-15", name: "Al Muḩarraq", parent: None },
+    Subdivision { code: "BH-17", name: "Ash Shamālīyah", parent: None },
+    Subdivision { code: "BI-BB", name: "Bubanza", parent: None },
+    Subdivision { code: "BI-BL", name: "Bujumbura Rural", parent: None },
+    Subdivision { code: "BI-BM", name: "Bujumbura Mairie", parent: None },
+    Subdivision { code: "BI-BR", name: "Bururi", parent: None },
+    Subdivision { code: "BI-CA", name: "Cankuzo", parent: None },
+    Subdivision { code: "BI-CI", name: "Cibitoke", parent: None },
+    Subdivision { code: "BI-GI", name: "Gitega", parent: None },
+    Subdivision { code: "BI-KI", name: "Kirundo", parent: None },
+    Subdivision { code: "BI-KR", name: "Karuzi", parent: None },
+    Subdivision { code: "BI-KY", name: "Kayanza", parent: None },
+    Subdivision { code: "BI-MA", name: "Makamba", parent: None },
+    Subdivision { code: "BI-MU", name: "Muramvya", parent: None },
+    Subdivision { code: "BI-MW", name: "Mwaro", parent: None },
+    Subdivision { code: "BI-MY", name: "Muyinga", parent: None },
+    Subdivision { code: "BI-NG", name: "Ngozi", parent: None },
+    Subdivision { code: "BI-RM", name: "Rumonge", parent: None },
+    Subdivision { code: "BI-RT", name: "Rutana", parent: None },
+    Subdivision { code: "BI-RY", name: "Ruyigi", parent: None },
+    Subdivision { code: "BJ-AK", name: "Atacora", parent: None },
+    Subdivision { code: "BJ-AL", name: "Alibori", parent: None },
+    Subdivision { code: "BJ-AQ", name: "Atlantique", parent: None },
+    Subdivision { code: "BJ-BO", name: "Borgou", parent: None },
+    Subdivision { code: "BJ-CO", name: "Collines", parent: None },
+    Subdivision { code: "BJ-DO", name: "Donga", parent: None },
+    Subdivision { code: "BJ-KO", name: "Couffo", parent: None },
+    Subdivision { code: "BJ-LI", name: "Littoral", parent: None },
+    Subdivision { code: "BJ-MO", name: "Mono", parent: None },
+    Subdivision { code: "BJ-OU", name: "Ouémé", parent: None },
+    Subdivision { code: "BJ-PL", name: "Plateau", parent: None },
+    Subdivision { code: "BJ-ZO", name: "Zou", parent: None },
+    Subdivision { code: "BN-BE", name: "Belait", parent: None },
+    Subdivision { code: "BN-BM", name: "Brunei-Muara", parent: None },
+    Subdivision { code: "BN-TE", name: "Temburong", parent: None },
+    Subdivision { code: "BN-TU", name: "Tutong", parent: None },
+    Subdivision { code: "BO-B", name: "El Beni", parent: None },
+    Subdivision { code: "BO-C", name: "Cochabamba", parent: None },
+    Subdivision { code: "BO-H", name: "Chuquisaca", parent: None },
+    Subdivision { code: "BO-L", name: "La Paz", parent: None },
+    Subdivision { code: "BO-N", name: "Pando", parent: None },
+    Subdivision { code: "BO-O", name: "Oruro", parent: None },
+    Subdivision { code: "BO-P", name: "Potosí", parent: None },
+    Subdivision { code: "BO-S", name: "Santa Cruz", parent: None },
+    Subdivision { code: "BO-T", name: "Tarija", parent: None },
+    Subdivision { code: "BQ-BO", name: "Bonaire", parent: None },
+    Subdivision { code: "BQ-SA", name: "Saba", parent: None },
+    Subdivision { code: "BQ-SE", name: "Sint Eustatius", parent: None },
+    Subdivision { code: "BR-AC", name: "Acre", parent: None },
+    Subdivision { code: "BR-AL", name: "Alagoas", parent: None },
+    Subdivision { code: "BR-AM", name: "Amazonas", parent: None },
+    Subdivision { code: "BR-AP", name: "Amapá", parent: None },
+    Subdivision { code: "BR-BA", name: "Bahia", parent: None },
+    Subdivision { code: "BR-CE", name: "Ceará", parent: None },
+    Subdivision { code: "BR-DF", name: "Distrito Federal", parent: None },
+    Subdivision { code: "BR-ES", name: "Espírito Santo", parent: None },
+    Subdivision { code: "BR-GO", name: "Goiás", parent: None },
+    Subdivision { code: "BR-MA", name: "Maranhão", parent: None },
+    Subdivision { code: "BR-MG", name: "Minas Gerais", parent: None },
+    Subdivision { code: "BR-MS", name: "Mato Grosso do Sul", parent: None },
+    Subdivision { code: "BR-MT", name: "Mato Grosso", parent: None },
+    Subdivision { code: "BR-PA", name: "Pará", parent: None },
+    Subdivision { code: "BR-PB", name: "Paraíba", parent: None },
+    Subdivision { code: "BR-PE", name: "Pernambuco", parent: None },
+    Subdivision { code: "BR-PI", name: "Piauí", parent: None },
+    Subdivision { code: "BR-PR", name: "Paraná", parent: None },
+    Subdivision { code: "BR-RJ", name: "Rio de Janeiro", parent: None },
+    Subdivision { code: "BR-RN", name: "Rio Grande do Norte", parent: None },
+    Subdivision { code: "BR-RO", name: "Rondônia", parent: None },
+    Subdivision { code: "BR-RR", name: "Roraima", parent: None },
+    Subdivision { code: "BR-RS", name: "Rio Grande do Sul", parent: None },
+    Subdivision { code: "BR-SC", name: "Santa Catarina", parent: None },
+    Subdivision { code: "BR-SE", name: "Sergipe", parent: None },
+    Subdivision { code: "BR-SP", name: "São Paulo", parent: None },
+    Subdivision { code: "BR-TO", name: "Tocantins", parent: None },
+    Subdivision { code: "BS-AK", name: "Acklins", parent: None },
+    Subdivision { code: "BS-BI", name: "Bimini", parent: None },
+    Subdivision { code: "BS-BP", name: "Black Point", parent: None },
+    Subdivision { code: "BS-BY", name: "Berry Islands", parent: None },
+    Subdivision { code: "BS-CE", name: "Central Eleuthera", parent: None },
+    Subdivision { code: "BS-CI", name: "Cat Island", parent: None },
+    Subdivision { code: "BS-CK", name: "Crooked Island and Long Cay", parent: None },
+    Subdivision { code: "BS-CO", name: "Central Abaco", parent: None },
+    Subdivision { code: "BS-CS", name: "Central Andros", parent: None },
+    Subdivision { code: "BS-EG", name: "East Grand Bahama", parent: None },
+    Subdivision { code: "BS-EX", name: "Exuma", parent: None },
+    Subdivision { code: "BS-FP", name: "City of Freeport", parent: None },
+    Subdivision { code: "BS-GC", name: "Grand Cay", parent: None },
+    Subdivision { code: "BS-HI", name: "Harbour Island", parent: None },
+    Subdivision { code: "BS-HT", name: "Hope Town", parent: None },
+    Subdivision { code: "BS-IN", name: "Inagua", parent: None },
+    Subdivision { code: "BS-LI", name: "Long Island", parent: None },
+    Subdivision { code: "BS-MC", name: "Mangrove Cay", parent: None },
+    Subdivision { code: "BS-MG", name: "Mayaguana", parent: None },
+    Subdivision { code: "BS-MI", name: "Moore's Island", parent: None },
+    Subdivision { code: "BS-NE", name: "North Eleuthera", parent: None },
+    Subdivision { code: "BS-NO", name: "North Abaco", parent: None },
+    Subdivision { code: "BS-NP", name: "New Providence", parent: None },
+    Subdivision { code: "BS-NS", name: "North Andros", parent: None },
+    Subdivision { code: "BS-RC", name: "Rum Cay", parent: None },
+    Subdivision { code: "BS-RI", name: "Ragged Island", parent: None },
+    Subdivision { code: "BS-SA", name: "South Andros", parent: None },
+    Subdivision { code: "BS-SE", name: "South Eleuthera", parent: None },
+    Subdivision { code: "BS-SO", name: "South Abaco", parent: None },
+    Subdivision { code: "BS-SS", name: "San Salvador", parent: None },
+    Subdivision { code: "BS-SW", name: "Spanish Wells", parent: None },
+    Subdivision { code: "BS-WG", name: "West Grand Bahama", parent: None },
+    Subdivision { code: "BT-11", name: "Paro", parent: None },
+    Subdivision { code: "BT-12", name: "Chhukha", parent: None },
+    Subdivision { code: "BT-13", name: "Haa", parent: None },
+    Subdivision { code: "BT-14", name: "Samtse", parent: None },
+    Subdivision { code: "BT-15", name: "Thimphu", parent: None },
+    Subdivision { code: "BT-21", name: "Tsirang", parent: None },
+    Subdivision { code: "BT-22", name: "Dagana", parent: None },
+    Subdivision { code: "BT-23", name: "Punakha", parent: None },
+    Subdivision { code: "BT-24", name: "Wangdue Phodrang", parent: None },
+    Subdivision { code: "BT-31", name: "Sarpang", parent: None },
+    Subdivision { code: "BT-32", name: "Trongsa", parent: None },
+    Subdivision { code: "BT-33", name: "Bumthang", parent: None },
+    Subdivision { code: "BT-34", name: "Zhemgang", parent: None },
+    Subdivision { code: "BT-41", name: "Trashigang", parent: None },
+    Subdivision { code: "BT-42", name: "Monggar", parent: None },
+    Subdivision { code: "BT-43", name: "Pema Gatshel", parent: None },
+    Subdivision { code: "BT-44", name: "Lhuentse", parent: None },
+    Subdivision { code: "BT-45", name: "Samdrup Jongkhar", parent: None },
+    Subdivision { code: "BT-GA", name: "Gasa", parent: None },
+    Subdivision { code: "BT-TY", name: "Trashi Yangtse", parent: None },
+    Subdivision { code: "BW-CE", name: "Central", parent: None },
+    Subdivision { code: "BW-CH", name: "Chobe", parent: None },
+    Subdivision { code: "BW-FR", name: "Francistown", parent: None },
+    Subdivision { code: "BW-GA", name: "Gaborone", parent: None },
+    Subdivision { code: "BW-GH", name: "Ghanzi", parent: None },
+    Subdivision { code: "BW-JW", name: "Jwaneng", parent: None },
+    Subdivision { code: "BW-KG", name: "Kgalagadi", parent: None },
+    Subdivision { code: "BW-KL", name: "Kgatleng", parent: None },
+    Subdivision { code: "BW-KW", name: "Kweneng", parent: None },
+    Subdivision { code: "BW-LO", name: "Lobatse", parent: None },
+    Subdivision { code: "BW-NE", name: "North East", parent: None },
+    Subdivision { code: "BW-NW", name: "North West", parent: None },
+    Subdivision { code: "BW-SE", name: "South East", parent: None },
+    Subdivision { code: "BW-SO", name: "Southern", parent: None },
+    Subdivision { code: "BW-SP", name: "Selibe Phikwe", parent: None },
+    Subdivision { code: "BW-ST", name: "Sowa Town", parent: None },
+    Subdivision { code: "BY-BR", name: "Bresckaja voblasć", parent: None },
+    Subdivision { code: "BY-HM", name: "Gorod Minsk", parent: None },
+    Subdivision { code: "BY-HO", name: "Gomel'skaja oblast'", parent: None },
+    Subdivision { code: "BY-HR", name: "Grodnenskaja oblast'", parent: None },
+    Subdivision { code: "BY-MA", name: "Mahilioŭskaja voblasć", parent: None },
+    Subdivision { code: "BY-MI", name: "Minskaja oblast'", parent: None },
+    Subdivision { code: "BY-VI", name: "Viciebskaja voblasć", parent: None },
+    Subdivision { code: "BZ-BZ", name: "Belize", parent: None },
+    Subdivision { code: "BZ-CY", name: "Cayo", parent: None },
+    Subdivision { code: "BZ-CZL", name: "Corozal", parent: None },
+    Subdivision { code: "BZ-OW", name: "Orange Walk", parent: None },
+    Subdivision { code: "BZ-SC", name: "Stann Creek", parent: None },
+    Subdivision { code: "BZ-TOL", name: "Toledo", parent: None },
+    Subdivision { code: "CA-AB", name: "Alberta", parent: None },
+    Subdivision { code: "CA-BC", name: "British Columbia", parent: None },
+    Subdivision { code: "CA-MB", name: "Manitoba", parent: None },
+    Subdivision { code: "CA-NB", name: "New Brunswick", parent: None },
+    Subdivision { code: "CA-NL", name: "Newfoundland and Labrador", parent: None },
+    Subdivision { code: "CA-NS", name: "Nova Scotia", parent: None },
+    Subdivision { code: "CA-NT", name: "Northwest Territories", parent: None },
+    Subdivision { code: "CA-NU", name: "Nunavut", parent: None },
+    Subdivision { code: "CA-ON", name: "Ontario", parent: None },
+    Subdivision { code: "CA-PE", name: "Prince Edward Island", parent: None },
+    Subdivision { code: "CA-QC", name: "Quebec", parent: None },
+    Subdivision { code: "CA-SK", name: "Saskatchewan", parent: None },
+    Subdivision { code: "CA-YT", name: "Yukon", parent: None },
+    Subdivision { code: "CD-BC", name: "Kongo Central", parent: None },
+    Subdivision { code: "CD-BU", name: "Bas-Uélé", parent: None },
+    Subdivision { code: "CD-EQ", name: "Équateur", parent: None },
+    Subdivision { code: "CD-HK", name: "Haut-Katanga", parent: None },
+    Subdivision { code: "CD-HL", name: "Haut-Lomami", parent: None },
+    Subdivision { code: "CD-HU", name: "Haut-Uélé", parent: None },
+    Subdivision { code: "CD-IT", name: "Ituri", parent: None },
+    Subdivision { code: "CD-KC", name: "Kasaï Central", parent: None },
+    Subdivision { code: "CD-KE", name: "Kasaï Oriental", parent: None },
+    Subdivision { code: "CD-KG", name: "Kwango", parent: None },
+    Subdivision { code: "CD-KL", name: "Kwilu", parent: None },
+    Subdivision { code: "CD-KN", name: "Kinshasa", parent: None },
+    Subdivision { code: "CD-KS", name: "Kasaï", parent: None },
+    Subdivision { code: "CD-LO", name: "Lomami", parent: None },
+    Subdivision { code: "CD-LU", name: "Lualaba", parent: None },
+    Subdivision { code: "CD-MA", name: "Maniema", parent: None },
+    Subdivision { code: "CD-MN", name: "Mai-Ndombe", parent: None },
+    Subdivision { code: "CD-MO", name: "Mongala", parent: None },
+    Subdivision { code: "CD-NK", name: "Nord-Kivu", parent: None },
+    Subdivision { code: "CD-NU", name: "Nord-Ubangi", parent: None },
+    Subdivision { code: "CD-SA", name: "Sankuru", parent: None },
+    Subdivision { code: "CD-SK", name: "Sud-Kivu", parent: None },
+    Subdivision { code: "CD-SU", name: "Sud-Ubangi", parent: None },
+    Subdivision { code: "CD-TA", name: "Tanganyika", parent: None },
+    Subdivision { code: "CD-TO", name: "Tshopo", parent: None },
+    Subdivision { code: "CD-TU", name: "Tshuapa", parent: None },
+    Subdivision { code: "CF-AC", name: "Ouham", parent: None },
+    Subdivision { code: "CF-BB", name: "Bamingui-Bangoran", parent: None },
+    Subdivision { code: "CF-BGF", name: "Bangui", parent: None },
+    Subdivision { code: "CF-BK", name: "Basse-Kotto", parent: None },
+    Subdivision { code: "CF-HK", name: "Haute-Kotto", parent: None },
+    Subdivision { code: "CF-HM", name: "Haut-Mbomou", parent: None },
+    Subdivision { code: "CF-HS", name: "Haute-Sangha / Mambéré-Kadéï", parent: None },
+    Subdivision { code: "CF-KB", name: "Gribingui", parent: None },
+    Subdivision { code: "CF-KG", name: "Kemö-Gïrïbïngï", parent: None },
+    Subdivision { code: "CF-LB", name: "Lobaye", parent: None },
+    Subdivision { code: "CF-MB", name: "Mbomou", parent: None },
+    Subdivision { code: "CF-MP", name: "Ombella-Mpoko", parent: None },
+    Subdivision { code: "CF-NM", name: "Nana-Mambéré", parent: None },
+    Subdivision { code: "CF-OP", name: "Ouham-Pendé", parent: None },
+    Subdivision { code: "CF-SE", name: "Sangha", parent: None },
+    Subdivision { code: "CF-UK", name: "Ouaka", parent: None },
+    Subdivision { code: "CF-VK", name: "Vakaga", parent: None },
+    Subdivision { code: "CG-11", name: "Bouenza", parent: None },
+    Subdivision { code: "CG-12", name: "Pool", parent: None },
+    Subdivision { code: "CG-13", name: "Sangha", parent: None },
+    Subdivision { code: "CG-14", name: "Plateaux", parent: None },
+    Subdivision { code: "CG-15", name: "Cuvette-Ouest", parent: None },
+    Subdivision { code: "CG-16", name: "Pointe-Noire", parent: None },
+    Subdivision { code: "CG-2", name: "Lékoumou", parent: None },
+    Subdivision { code: "CG-5", name: "Kouilou", parent: None },
+    Subdivision { code: "CG-7", name: "Likouala", parent: None },
+    Subdivision { code: "CG-8", name: "Cuvette", parent: None },
+    Subdivision { code: "CG-9", name: "Niari", parent: None },
+    Subdivision { code: "CG-BZV", name: "Brazzaville", parent: None },
+    Subdivision { code: "CH-AG", name: "Aargau", parent: None },
+    Subdivision { code: "CH-AI", name: "Appenzell Innerrhoden", parent: None },
+    Subdivision { code: "CH-AR", name: "Appenzell Ausserrhoden", parent: None },
+    Subdivision { code: "CH-BE", name: "Bern", parent: None },
+    Subdivision { code: "CH-BL", name: "Basel-Landschaft", parent: None },
+    Subdivision { code: "CH-BS", name: "Basel-Stadt", parent: None },
+    Subdivision { code: "CH-FR", name: "Freiburg", parent: None },
+    Subdivision { code: "CH-GE", name: "Genève", parent: None },
+    Subdivision { code: "CH-GL", name: "Glarus", parent: None },
+    Subdivision { code: "CH-GR", name: "Graubünden", parent: None },
+    Subdivision { code: "CH-JU", name: "Jura", parent: None },
+    Subdivision { code: "CH-LU", name: "Luzern", parent: None },
+    Subdivision { code: "CH-NE", name: "Neuchâtel", parent: None },
+    Subdivision { code: "CH-NW", name: "Nidwalden", parent: None },
+    Subdivision { code: "CH-OW", name: "Obwalden", parent: None },
+    Subdivision { code: "CH-SG", name: "Sankt Gallen", parent: None },
+    Subdivision { code: "CH-SH", name: "Schaffhausen", parent: None },
+    Subdivision { code: "CH-SO", name: "Solothurn", parent: None },
+    Subdivision { code: "CH-SZ", name: "Schwyz", parent: None },
+    Subdivision { code: "CH-TG", name: "Thurgau", parent: None },
+    Subdivision { code: "CH-TI", name: "Ticino", parent: None },
+    Subdivision { code: "CH-UR", name: "Uri", parent: None },
+    Subdivision { code: "CH-VD", name: "Vaud", parent: None },
+    Subdivision { code: "CH-VS", name: "Valais", parent: None },
+    Subdivision { code: "CH-ZG", name: "Zug", parent: None },
+    Subdivision { code: "CH-ZH", name: "Zürich", parent: None },
+    Subdivision { code: "CI-AB", name: "Abidjan", parent: None },
+    Subdivision { code: "CI-BS", name: "Bas-Sassandra", parent: None },
+    Subdivision { code: "CI-CM", name: "Comoé", parent: None },
+    Subdivision { code: "CI-DN", name: "Denguélé", parent: None },
+    Subdivision { code: "CI-GD", name: "Gôh-Djiboua", parent: None },
+    Subdivision { code: "CI-LC", name: "Lacs", parent: None },
+    Subdivision { code: "CI-LG", name: "Lagunes", parent: None },
+    Subdivision { code: "CI-MG", name: "Montagnes", parent: None },
+    Subdivision { code: "CI-SM", name: "Sassandra-Marahoué", parent: None },
+    Subdivision { code: "CI-SV", name: "Savanes", parent: None },
+    Subdivision { code: "CI-VB", name: "Vallée du Bandama", parent: None },
+    Subdivision { code: "CI-WR", name: "Woroba", parent: None },
+    Subdivision { code: "CI-YM", name: "Yamoussoukro", parent: None },
+    Subdivision { code: "CI-ZZ", name: "Zanzan", parent: None },
+    Subdivision { code: "CL-AI", name: "Aisén del General Carlos Ibañez del Campo", parent: None },
+    Subdivision { code: "CL-AN", name: "Antofagasta", parent: None },
+    Subdivision { code: "CL-AP", name: "Arica y Parinacota", parent: None },
+    Subdivision { code: "CL-AR", name: "La Araucanía", parent: None },
+    Subdivision { code: "CL-AT", name: "Atacama", parent: None },
+    Subdivision { code: "CL-BI", name: "Biobío", parent: None },
+    Subdivision { code: "CL-CO", name: "Coquimbo", parent: None },
+    Subdivision { code: "CL-LI", name: "Libertador General Bernardo O'Higgins", parent: None },
+    Subdivision { code: "CL-LL", name: "Los Lagos", parent: None },
+    Subdivision { code: "CL-LR", name: "Los Ríos", parent: None },
+    Subdivision { code: "CL-MA", name: "Magallanes", parent: None },
+    Subdivision { code: "CL-ML", name: "Maule", parent: None },
+    Subdivision { code: "CL-NB", name: "Ñuble", parent: None },
+    Subdivision { code: "CL-RM", name: "Región Metropolitana de Santiago", parent: None },
+    Subdivision { code: "CL-TA", name: "Tarapacá", parent: None },
+    Subdivision { code: "CL-VS", name: "Valparaíso", parent: None },
+    Subdivision { code: "CM-AD", name: "Adamaoua", parent: None },
+    Subdivision { code: "CM-CE", name: "Centre", parent: None },
+    Subdivision { code: "CM-EN", name: "Far North", parent: None },
+    Subdivision { code: "CM-ES", name: "East", parent: None },
+    Subdivision { code: "CM-LT", name: "Littoral", parent: None },
+    Subdivision { code: "CM-NO", name: "North", parent: None },
+    Subdivision { code: "CM-NW", name: "North-West", parent: None },
+    Subdivision { code: "CM-OU", name: "West", parent: None },
+    Subdivision { code: "CM-SU", name: "South", parent: None },
+    Subdivision { code: "CM-SW", name: "South-West", parent: None },
+    Subdivision { code: "CN-AH", name: "Anhui Sheng", parent: None },
+    Subdivision { code: "CN-BJ", name: "Beijing Shi", parent: None },
+    Subdivision { code: "CN-CQ", name: "Chongqing Shi", parent: None },
+    Subdivision { code: "CN-FJ", name: "Fujian Sheng", parent: None },
+    Subdivision { code: "CN-GD", name: "Guangdong Sheng", parent: None },
+    Subdivision { code: "CN-GS", name: "Gansu Sheng", parent: None },
+    Subdivision { code: "CN-GX", name: "Guangxi Zhuangzu Zizhiqu", parent: None },
+    Subdivision { code: "CN-GZ", name: "Guizhou Sheng", parent: None },
+    Subdivision { code: "CN-HA", name: "Henan Sheng", parent: None },
+    Subdivision { code: "CN-HB", name: "Hubei Sheng", parent: None },
+    Subdivision { code: "CN-HE", name: "Hebei Sheng", parent: None },
+    Subdivision { code: "CN-HI", name: "Hainan Sheng", parent: None },
+    Subdivision { code: "CN-HK", name: "Hong Kong SAR", parent: None },
+    Subdivision { code: "CN-HL", name: "Heilongjiang Sheng", parent: None },
+    Subdivision { code: "CN-HN", name: "Hunan Sheng", parent: None },
+    Subdivision { code: "CN-JL", name: "Jilin Sheng", parent: None },
+    Subdivision { code: "CN-JS", name: "Jiangsu Sheng", parent: None },
+    Subdivision { code: "CN-JX", name: "Jiangxi Sheng", parent: None },
+    Subdivision { code: "CN-LN", name: "Liaoning Sheng", parent: None },
+    Subdivision { code: "CN-MO", name: "Macao SAR", parent: None },
+    Subdivision { code: "CN-NM", name: "Nei Mongol Zizhiqu", parent: None },
+    Subdivision { code: "CN-NX", name: "Ningxia Huizi Zizhiqu", parent: None },
+    Subdivision { code: "CN-QH", name: "Qinghai Sheng", parent: None },
+    Subdivision { code: "CN-SC", name: "Sichuan Sheng", parent: None },
+    Subdivision { code: "CN-SD", name: "Shandong Sheng", parent: None },
+    Subdivision { code: "CN-SH", name: "Shanghai Shi", parent: None },
+    Subdivision { code: "CN-SN", name: "Shaanxi Sheng", parent: None },
+    Subdivision { code: "CN-SX", name: "Shanxi Sheng", parent: None },
+    Subdivision { code: "CN-TJ", name: "Tianjin Shi", parent: None },
+    Subdivision { code: "CN-TW", name: "Taiwan Sheng", parent: None },
+    Subdivision { code: "CN-XJ", name: "Xinjiang Uygur Zizhiqu", parent: None },
+    Subdivision { code: "CN-XZ", name: "Xizang Zizhiqu", parent: None },
+    Subdivision { code: "CN-YN", name: "Yunnan Sheng", parent: None },
+    Subdivision { code: "CN-ZJ", name: "Zhejiang Sheng", parent: None },
+    Subdivision { code: "CO-AMA", name: "Amazonas", parent: None },
+    Subdivision { code: "CO-ANT", name: "Antioquia", parent: None },
+    Subdivision { code: "CO-ARA", name: "Arauca", parent: None },
+    Subdivision { code: "CO-ATL", name: "Atlántico", parent: None },
+    Subdivision { code: "CO-BOL", name: "Bolívar", parent: None },
+    Subdivision { code: "CO-BOY", name: "Boyacá", parent: None },
+    Subdivision { code: "CO-CAL", name: "Caldas", parent: None },
+    Subdivision { code: "CO-CAQ", name: "Caquetá", parent: None },
+    Subdivision { code: "CO-CAS", name: "Casanare", parent: None },
+    Subdivision { code: "CO-CAU", name: "Cauca", parent: None },
+    Subdivision { code: "CO-CES", name: "Cesar", parent: None },
+    Subdivision { code: "CO-CHO", name: "Chocó", parent: None },
+    Subdivision { code: "CO-COR", name: "Córdoba", parent: None },
+    Subdivision { code: "CO-CUN", name: "Cundinamarca", parent: None },
+    Subdivision { code: "CO-DC", name: "Distrito Capital de Bogotá", parent: None },
+    Subdivision { code: "CO-GUA", name: "Guainía", parent: None },
+    Subdivision { code: "CO-GUV", name: "Guaviare", parent: None },
+    Subdivision { code: "CO-HUI", name: "Huila", parent: None },
+    Subdivision { code: "CO-LAG", name: "La Guajira", parent: None },
+    Subdivision { code: "CO-MAG", name: "Magdalena", parent: None },
+    Subdivision { code: "CO-MET", name: "Meta", parent: None },
+    Subdivision { code: "CO-NAR", name: "Nariño", parent: None },
+    Subdivision { code: "CO-NSA", name: "Norte de Santander", parent: None },
+    Subdivision { code: "CO-PUT", name: "Putumayo", parent: None },
+    Subdivision { code: "CO-QUI", name: "Quindío", parent: None },
+    Subdivision { code: "CO-RIS", name: "Risaralda", parent: None },
+    Subdivision { code: "CO-SAN", name: "Santander", parent: None },
+    Subdivision { code: "CO-SAP", name: "San Andrés, Providencia y Santa Catalina", parent: None },
+    Subdivision { code: "CO-SUC", name: "Sucre", parent: None },
+    Subdivision { code: "CO-TOL", name: "Tolima", parent: None },
+    Subdivision { code: "CO-VAC", name: "Valle del Cauca", parent: None },
+    Subdivision { code: "CO-VAU", name: "Vaupés", parent: None },
+    Subdivision { code: "CO-VID", name: "Vichada", parent: None },
+    Subdivision { code: "CR-A", name: "Alajuela", parent: None },
+    Subdivision { code: "CR-C", name: "Cartago", parent: None },
+    Subdivision { code: "CR-G", name: "Guanacaste", parent: None },
+    Subdivision { code: "CR-H", name: "Heredia", parent: None },
+    Subdivision { code: "CR-L", name: "Limón", parent: None },
+    Subdivision { code: "CR-P", name: "Puntarenas", parent: None },
+    Subdivision { code: "CR-SJ", name: "San José", parent: None },
+    Subdivision { code: "CU-01", name: "Pinar del Río", parent: None },
+    Subdivision { code: "CU-03", name: "La Habana", parent: None },
+    Subdivision { code: "CU-04", name: "Matanzas", parent: None },
+    Subdivision { code: "CU-05", name: "Villa Clara", parent: None },
+    Subdivision { code: "CU-06", name: "Cienfuegos", parent: None },
+    Subdivision { code: "CU-07", name: "Sancti Spíritus", parent: None },
+    Subdivision { code: "CU-08", name: "Ciego de Ávila", parent: None },
+    Subdivision { code: "CU-09", name: "Camagüey", parent: None },
+    Subdivision { code: "CU-10", name: "Las Tunas", parent: None },
+    Subdivision { code: "CU-11", name: "Holguín", parent: None },
+    Subdivision { code: "CU-12", name: "Granma", parent: None },
+    Subdivision { code: "CU-13", name: "Santiago de Cuba", parent: None },
+    Subdivision { code: "CU-14", name: "Guantánamo", parent: None },
+    Subdivision { code: "CU-15", name: "Artemisa", parent: None },
+    Subdivision { code: "CU-16", name: "Mayabeque", parent: None },
+    Subdivision { code: "CU-99", name: "Isla de la Juventud", parent: None },
+    Subdivision { code: "CV-B", name: "Ilhas de Barlavento", parent: None },
+    Subdivision { code: "CV-BR", name: "Brava", parent: Some("CV-S") },
+    Subdivision { code: "CV-BV", name: "Boa Vista", parent: Some("CV-B") },
+    Subdivision { code: "CV-CA", name: "Santa Catarina", parent: Some("CV-S") },
+    Subdivision { code: "CV-CF", name: "Santa Catarina do Fogo", parent: Some("CV-S") },
+    Subdivision { code: "CV-CR", name: "Santa Cruz", parent: Some("CV-S") },
+    Subdivision { code: "CV-MA", name: "Maio", parent: Some("CV-S") },
+    Subdivision { code: "CV-MO", name: "Mosteiros", parent: Some("CV-S") },
+    Subdivision { code: "CV-PA", name: "Paul", parent: Some("CV-B") },
+    Subdivision { code: "CV-PN", name: "Porto Novo", parent: Some("CV-B") },
+    Subdivision { code: "CV-PR", name: "Praia", parent: Some("CV-S") },
+    Subdivision { code: "CV-RB", name: "Ribeira Brava", parent: Some("CV-B") },
+    Subdivision { code: "CV-RG", name: "Ribeira Grande", parent: Some("CV-B") },
+    Subdivision { code: "CV-RS", name: "Ribeira Grande de Santiago", parent: Some("CV-S") },
+    Subdivision { code: "CV-S", name: "Ilhas de Sotavento", parent: None },
+    Subdivision { code: "CV-SD", name: "São Domingos", parent: Some("CV-S") },
+    Subdivision { code: "CV-SF", name: "São Filipe", parent: Some("CV-S") },
+    Subdivision { code: "CV-SL", name: "Sal", parent: Some("CV-B") },
+    Subdivision { code: "CV-SM", name: "São Miguel", parent: Some("CV-S") },
+    Subdivision { code: "CV-SO", name: "São Lourenço dos Órgãos", parent: Some("CV-S") },
+    Subdivision { code: "CV-SS", name: "São Salvador do Mundo", parent: Some("CV-S") },
+    Subdivision { code: "CV-SV", name: "São Vicente", parent: Some("CV-B") },
+    Subdivision { code: "CV-TA", name: "Tarrafal", parent: Some("CV-S") },
+    Subdivision { code: "CV-TS", name: "Tarrafal de São Nicolau", parent: Some("CV-B") },
+    Subdivision { code: "CY-01", name: "Lefkosia", parent: None },
+    Subdivision { code: "CY-02", name: "Lemesos", parent: None },
+    Subdivision { code: "CY-03", name: "Larnaka", parent: None },
+    Subdivision { code: "CY-04", name: "Ammochostos", parent: None },
+    Subdivision { code: "CY-05", name: "Baf", parent: None },
+    Subdivision { code: "CY-06", name: "Girne", parent: None },
+    Subdivision { code: "CZ-10", name: "Praha, Hlavní město", parent: None },
+    Subdivision { code: "CZ-20", name: "Středočeský kraj", parent: None },
+    Subdivision { code: "CZ-201", name: "Benešov", parent: Some("CZ-20") },
+    Subdivision { code: "CZ-202", name: "Beroun", parent: Some("CZ-20") },
+    Subdivision { code: "CZ-203", name: "Kladno", parent: Some("CZ-20") },
+    Subdivision { code: "CZ-204", name: "Kolín", parent: Some("CZ-20") },
+    Subdivision { code: "CZ-205", name: "Kutná Hora", parent: Some("CZ-20") },
+    Subdivision { code: "CZ-206", name: "Mělník", parent: Some("CZ-20") },
+    Subdivision { code: "CZ-207", name: "Mladá Boleslav", parent: Some("CZ-20") },
+    Subdivision { code: "CZ-208", name: "Nymburk", parent: Some("CZ-20") },
+    Subdivision { code: "CZ-209", name: "Praha-východ", parent: Some("CZ-20") },
+    Subdivision { code: "CZ-20A", name: "Praha-západ", parent: Some("CZ-20") },
+    Subdivision { code: "CZ-20B", name: "Příbram", parent: Some("CZ-20") },
+    Subdivision { code: "CZ-20C", name: "Rakovník", parent: Some("CZ-20") },
+    Subdivision { code: "CZ-31", name: "Jihočeský kraj", parent: None },
+    Subdivision { code: "CZ-311", name: "České Budějovice", parent: Some("CZ-31") },
+    Subdivision { code: "CZ-312", name: "Český Krumlov", parent: Some("CZ-31") },
+    Subdivision { code: "CZ-313", name: "Jindřichův Hradec", parent: Some("CZ-31") },
+    Subdivision { code: "CZ-314", name: "Písek", parent: Some("CZ-31") },
+    Subdivision { code: "CZ-315", name: "Prachatice", parent: Some("CZ-31") },
+    Subdivision { code: "CZ-316", name: "Strakonice", parent: Some("CZ-31") },
+    Subdivision { code: "CZ-317", name: "Tábor", parent: Some("CZ-31") },
+    Subdivision { code: "CZ-32", name: "Plzeňský kraj", parent: None },
+    Subdivision { code: "CZ-321", name: "Domažlice", parent: Some("CZ-32") },
+    Subdivision { code: "CZ-322", name: "Klatovy", parent: Some("CZ-32") },
+    Subdivision { code: "CZ-323", name: "Plzeň-město", parent: Some("CZ-32") },
+    Subdivision { code: "CZ-324", name: "Plzeň-jih", parent: Some("CZ-32") },
+    Subdivision { code: "CZ-325", name: "Plzeň-sever", parent: Some("CZ-32") },
+    Subdivision { code: "CZ-326", name: "Rokycany", parent: Some("CZ-32") },
+    Subdivision { code: "CZ-327", name: "Tachov", parent: Some("CZ-32") },
+    Subdivision { code: "CZ-41", name: "Karlovarský kraj", parent: None },
+    Subdivision { code: "CZ-411", name: "Cheb", parent: Some("CZ-41") },
+    Subdivision { code: "CZ-412", name: "Karlovy Vary", parent: Some("CZ-41") },
+    Subdivision { code: "CZ-413", name: "Sokolov", parent: Some("CZ-41") },
+    Subdivision { code: "CZ-42", name: "Ústecký kraj", parent: None },
+    Subdivision { code: "CZ-421", name: "Děčín", parent: Some("CZ-42") },
+    Subdivision { code: "CZ-422", name: "Chomutov", parent: Some("CZ-42") },
+    Subdivision { code: "CZ-423", name: "Litoměřice", parent: Some("CZ-42") },
+    Subdivision { code: "CZ-424", name: "Louny", parent: Some("CZ-42") },
+    Subdivision { code: "CZ-425", name: "Most", parent: Some("CZ-42") },
+    Subdivision { code: "CZ-426", name: "Teplice", parent: Some("CZ-42") },
+    Subdivision { code: "CZ-427", name: "Ústí nad Labem", parent: Some("CZ-42") },
+    Subdivision { code: "CZ-51", name: "Liberecký kraj", parent: None },
+    Subdivision { code: "CZ-511", name: "Česká Lípa", parent: Some("CZ-51") },
+    Subdivision { code: "CZ-512", name: "Jablonec nad Nisou", parent: Some("CZ-51") },
+    Subdivision { code: "CZ-513", name: "Liberec", parent: Some("CZ-51") },
+    Subdivision { code: "CZ-514", name: "Semily", parent: Some("CZ-51") },
+    Subdivision { code: "CZ-52", name: "Královéhradecký kraj", parent: None },
+    Subdivision { code: "CZ-521", name: "Hradec Králové", parent: Some("CZ-52") },
+    Subdivision { code: "CZ-522", name: "Jičín", parent: Some("CZ-52") },
+    Subdivision { code: "CZ-523", name: "Náchod", parent: Some("CZ-52") },
+    Subdivision { code: "CZ-524", name: "Rychnov nad Kněžnou", parent: Some("CZ-52") },
+    Subdivision { code: "CZ-525", name: "Trutnov", parent: Some("CZ-52") },
+    Subdivision { code: "CZ-53", name: "Pardubický kraj", parent: None },
+    Subdivision { code: "CZ-531", name: "Chrudim", parent: Some("CZ-53") },
+    Subdivision { code: "CZ-532", name: "Pardubice", parent: Some("CZ-53") },
+    Subdivision { code: "CZ-533", name: "Svitavy", parent: Some("CZ-53") },
+    Subdivision { code: "CZ-534", name: "Ústí nad Orlicí", parent: Some("CZ-53") },
+    Subdivision { code: "CZ-63", name: "Kraj Vysočina", parent: None },
+    Subdivision { code: "CZ-631", name: "Havlíčkův Brod", parent: Some("CZ-63") },
+    Subdivision { code: "CZ-632", name: "Jihlava", parent: Some("CZ-63") },
+    Subdivision { code: "CZ-633", name: "Pelhřimov", parent: Some("CZ-63") },
+    Subdivision { code: "CZ-634", name: "Třebíč", parent: Some("CZ-63") },
+    Subdivision { code: "CZ-635", name: "Žďár nad Sázavou", parent: Some("CZ-63") },
+    Subdivision { code: "CZ-64", name: "Jihomoravský kraj", parent: None },
+    Subdivision { code: "CZ-641", name: "Blansko", parent: Some("CZ-64") },
+    Subdivision { code: "CZ-642", name: "Brno-město", parent: Some("CZ-64") },
+    Subdivision { code: "CZ-643", name: "Brno-venkov", parent: Some("CZ-64") },
+    Subdivision { code: "CZ-644", name: "Břeclav", parent: Some("CZ-64") },
+    Subdivision { code: "CZ-645", name: "Hodonín", parent: Some("CZ-64") },
+    Subdivision { code: "CZ-646", name: "Vyškov", parent: Some("CZ-64") },
+    Subdivision { code: "CZ-647", name: "Znojmo", parent: Some("CZ-64") },
+    Subdivision { code: "CZ-71", name: "Olomoucký kraj", parent: None },
+    Subdivision { code: "CZ-711", name: "Jeseník", parent: Some("CZ-71") },
+    Subdivision { code: "CZ-712", name: "Olomouc", parent: Some("CZ-71") },
+    Subdivision { code: "CZ-713", name: "Prostějov", parent: Some("CZ-71") },
+    Subdivision { code: "CZ-714", name: "Přerov", parent: Some("CZ-71") },
+    Subdivision { code: "CZ-715", name: "Šumperk", parent: Some("CZ-71") },
+    Subdivision { code: "CZ-72", name: "Zlínský kraj", parent: None },
+    Subdivision { code: "CZ-721", name: "Kroměříž", parent: Some("CZ-72") },
+    Subdivision { code: "CZ-722", name: "Uherské Hradiště", parent: Some("CZ-72") },
+    Subdivision { code: "CZ-723", name: "Vsetín", parent: Some("CZ-72") },
+    Subdivision { code: "CZ-724", name: "Zlín", parent: Some("CZ-72") },
+    Subdivision { code: "CZ-80", name: "Moravskoslezský kraj", parent: None },
+    Subdivision { code: "CZ-801", name: "Bruntál", parent: Some("CZ-80") },
+    Subdivision { code: "CZ-802", name: "Frýdek-Místek", parent: Some("CZ-80") },
+    Subdivision { code: "CZ-803", name: "Karviná", parent: Some("CZ-80") },
+    Subdivision { code: "CZ-804", name: "Nový Jičín", parent: Some("CZ-80") },
+    Subdivision { code: "CZ-805", name: "Opava", parent: Some("CZ-80") },
+    Subdivision { code: "CZ-806", name: "Ostrava-město", parent: Some("CZ-80") },
+    Subdivision { code: "DE-BB", name: "Brandenburg", parent: None },
+    Subdivision { code: "DE-BE", name: "Berlin", parent: None },
+    Subdivision { code: "DE-BW", name: "Baden-Württemberg", parent: None },
+    Subdivision { code: "DE-BY", name: "Bayern", parent: None },
+    Subdivision { code: "DE-HB", name: "Bremen", parent: None },
+    Subdivision { code: "DE-HE", name: "Hessen", parent: None },
+    Subdivision { code: "DE-HH", name: "Hamburg", parent: None },
+    Subdivision { code: "DE-MV", name: "Mecklenburg-Vorpommern", parent: None },
+    Subdivision { code: "DE-NI", name: "Niedersachsen", parent: None },
+    Subdivision { code: "DE-NW", name: "Nordrhein-Westfalen", parent: None },
+    Subdivision { code: "DE-RP", name: "Rheinland-Pfalz", parent: None },
+    Subdivision { code: "DE-SH", name: "Schleswig-Holstein", parent: None },
+    Subdivision { code: "DE-SL", name: "Saarland", parent: None },
+    Subdivision { code: "DE-SN", name: "Sachsen", parent: None },
+    Subdivision { code: "DE-ST", name: "Sachsen-Anhalt", parent: None },
+    Subdivision { code: "DE-TH", name: "Thüringen", parent: None },
+    Subdivision { code: "DJ-AR", name: "Arta", parent: None },
+    Subdivision { code: "DJ-AS", name: "Ali Sabieh", parent: None },
+    Subdivision { code: "DJ-DI", name: "Dikhil", parent: None },
+    Subdivision { code: "DJ-DJ", name: "Djibouti", parent: None },
+    Subdivision { code: "DJ-OB", name: "Awbūk", parent: None },
+    Subdivision { code: "DJ-TA", name: "Tadjourah", parent: None },
+    Subdivision { code: "DK-81", name: "Nordjylland", parent: None },
+    Subdivision { code: "DK-82", name: "Midtjylland", parent: None },
+    Subdivision { code: "DK-83", name: "Syddanmark", parent: None },
+    Subdivision { code: "DK-84", name: "Hovedstaden", parent: None },
+    Subdivision { code: "DK-85", name: "Sjælland", parent: None },
+    Subdivision { code: "DM-02", name: "Saint Andrew", parent: None },
+    Subdivision { code: "DM-03", name: "Saint David", parent: None },
+    Subdivision { code: "DM-04", name: "Saint George", parent: None },
+    Subdivision { code: "DM-05", name: "Saint John", parent: None },
+    Subdivision { code: "DM-06", name: "Saint Joseph", parent: None },
+    Subdivision { code: "DM-07", name: "Saint Luke", parent: None },
+    Subdivision { code: "DM-08", name: "Saint Mark", parent: None },
+    Subdivision { code: "DM-09", name: "Saint Patrick", parent: None },
+    Subdivision { code: "DM-10", name: "Saint Paul", parent: None },
+    Subdivision { code: "DM-11", name: "Saint Peter", parent: None },
+    Subdivision { code: "DO-01", name: "Distrito Nacional (Santo Domingo)", parent: Some("DO-40") },
+    Subdivision { code: "DO-02", name: "Azua", parent: Some("DO-41") },
+    Subdivision { code: "DO-03", name: "Baoruco", parent: Some("DO-38") },
+    Subdivision { code: "DO-04", name: "Barahona", parent: Some("DO-38") },
+    Subdivision { code: "DO-05", name: "Dajabón", parent: Some("DO-34") },
+    Subdivision { code: "DO-06", name: "Duarte", parent: Some("DO-33") },
+    Subdivision { code: "DO-07", name: "Elías Piña", parent: Some("DO-37") },
+    Subdivision { code: "DO-08", name: "El Seibo", parent: Some("DO-42") },
+    Subdivision { code: "DO-09", name: "Espaillat", parent: Some("DO-35") },
+    Subdivision { code: "DO-10", name: "Independencia", parent: Some("DO-38") },
+    Subdivision { code: "DO-11", name: "La Altagracia", parent: Some("DO-42") },
+    Subdivision { code: "DO-12", name: "La Romana", parent: Some("DO-42") },
+    Subdivision { code: "DO-13", name: "La Vega", parent: Some("DO-36") },
+    Subdivision { code: "DO-14", name: "María Trinidad Sánchez", parent: Some("DO-33") },
+    Subdivision { code: "DO-15", name: "Monte Cristi", parent: Some("DO-34") },
+    Subdivision { code: "DO-16", name: "Pedernales", parent: Some("DO-38") },
+    Subdivision { code: "DO-17", name: "Peravia", parent: Some("DO-41") },
+    Subdivision { code: "DO-18", name: "Puerto Plata", parent: Some("DO-35") },
+    Subdivision { code: "DO-19", name: "Hermanas Mirabal", parent: Some("DO-33") },
+    Subdivision { code: "DO-20", name: "Samaná", parent: Some("DO-33") },
+    Subdivision { code: "DO-21", name: "San Cristóbal", parent: Some("DO-41") },
+    Subdivision { code: "DO-22", name: "San Juan", parent: Some("DO-37") },
+    Subdivision { code: "DO-23", name: "San Pedro de Macorís", parent: Some("DO-39") },
+    Subdivision { code: "DO-24", name: "Sánchez Ramírez", parent: Some("DO-36") },
+    Subdivision { code: "DO-25", name: "Santiago", parent: Some("DO-35") },
+    Subdivision { code: "DO-26", name: "Santiago Rodríguez", parent: Some("DO-34") },
+    Subdivision { code: "DO-27", name: "Valverde", parent: Some("DO-34") },
+    Subdivision { code: "DO-28", name: "Monseñor Nouel", parent: Some("DO-36") },
+    Subdivision { code: "DO-29", name: "Monte Plata", parent: Some("DO-39") },
+    Subdivision { code: "DO-30", name: "Hato Mayor", parent: Some("DO-39") },
+    Subdivision { code: "DO-31", name: "San José de Ocoa", parent: Some("DO-41") },
+    Subdivision { code: "DO-32", name: "Santo Domingo", parent: Some("DO-40") },
+    Subdivision { code: "DO-33", name: "Cibao Nordeste", parent: None },
+    Subdivision { code: "DO-34", name: "Cibao Noroeste", parent: None },
+    Subdivision { code: "DO-35", name: "Cibao Norte", parent: None },
+    Subdivision { code: "DO-36", name: "Cibao Sur", parent: None },
+    Subdivision { code: "DO-37", name: "El Valle", parent: None },
+    Subdivision { code: "DO-38", name: "Enriquillo", parent: None },
+    Subdivision { code: "DO-39", name: "Higuamo", parent: None },
+    Subdivision { code: "DO-40", name: "Ozama", parent: None },
+    Subdivision { code: "DO-41", name: "Valdesia", parent: None },
+    Subdivision { code: "DO-42", name: "Yuma", parent: None },
+    Subdivision { code: "DZ-01", name: "Adrar", parent: None },
+    Subdivision { code: "DZ-02", name: "Chlef", parent: None },
+    Subdivision { code: "DZ-03", name: "Laghouat", parent: None },
+    Subdivision { code: "DZ-04", name: "Oum el Bouaghi", parent: None },
+    Subdivision { code: "DZ-05", name: "Batna", parent: None },
+    Subdivision { code: "DZ-06", name: "Béjaïa", parent: None },
+    Subdivision { code: "DZ-07", name: "Biskra", parent: None },
+    Subdivision { code: "DZ-08", name: "Béchar", parent: None },
+    Subdivision { code: "DZ-09", name: "Blida", parent: None },
+    Subdivision { code: "DZ-10", name: "Bouira", parent: None },
+    Subdivision { code: "DZ-11", name: "Tamanrasset", parent: None },
+    Subdivision { code: "DZ-12", name: "Tébessa", parent: None },
+    Subdivision { code: "DZ-13", name: "Tlemcen", parent: None },
+    Subdivision { code: "DZ-14", name: "Tiaret", parent: None },
+    Subdivision { code: "DZ-15", name: "Tizi Ouzou", parent: None },
+    Subdivision { code: "DZ-16", name: "Alger", parent: None },
+    Subdivision { code: "DZ-17", name: "Djelfa", parent: None },
+    Subdivision { code: "DZ-18", name: "Jijel", parent: None },
+    Subdivision { code: "DZ-19", name: "Sétif", parent: None },
+    Subdivision { code: "DZ-20", name: "Saïda", parent: None },
+    Subdivision { code: "DZ-21", name: "Skikda", parent: None },
+    Subdivision { code: "DZ-22", name: "Sidi Bel Abbès", parent: None },
+    Subdivision { code: "DZ-23", name: "Annaba", parent: None },
+    Subdivision { code: "DZ-24", name: "Guelma", parent: None },
+    Subdivision { code: "DZ-25", name: "Constantine", parent: None },
+    Subdivision { code: "DZ-26", name: "Médéa", parent: None },
+    Subdivision { code: "DZ-27", name: "Mostaganem", parent: None },
+    Subdivision { code: "DZ-28", name: "M'sila", parent: None },
+    Subdivision { code: "DZ-29", name: "Mascara", parent: None },
+    Subdivision { code: "DZ-30", name: "Ouargla", parent: None },
+    Subdivision { code: "DZ-31", name: "Oran", parent: None },
+    Subdivision { code: "DZ-32", name: "El Bayadh", parent: None },
+    Subdivision { code: "DZ-33", name: "Illizi", parent: None },
+    Subdivision { code: "DZ-34", name: "Bordj Bou Arréridj", parent: None },
+    Subdivision { code: "DZ-35", name: "Boumerdès", parent: None },
+    Subdivision { code: "DZ-36", name: "El Tarf", parent: None },
+    Subdivision { code: "DZ-37", name: "Tindouf", parent: None },
+    Subdivision { code: "DZ-38", name: "Tissemsilt", parent: None },
+    Subdivision { code: "DZ-39", name: "El Oued", parent: None },
+    Subdivision { code: "DZ-40", name: "Khenchela", parent: None },
+    Subdivision { code: "DZ-41", name: "Souk Ahras", parent: None },
+    Subdivision { code: "DZ-42", name: "Tipaza", parent: None },
+    Subdivision { code: "DZ-43", name: "Mila", parent: None },
+    Subdivision { code: "DZ-44", name: "Aïn Defla", parent: None },
+    Subdivision { code: "DZ-45", name: "Naama", parent: None },
+    Subdivision { code: "DZ-46", name: "Aïn Témouchent", parent: None },
+    Subdivision { code: "DZ-47", name: "Ghardaïa", parent: None },
+    Subdivision { code: "DZ-48", name: "Relizane", parent: None },
+    Subdivision { code: "EC-A", name: "Azuay", parent: None },
+    Subdivision { code: "EC-B", name: "Bolívar", parent: None },
+    Subdivision { code: "EC-C", name: "Carchi", parent: None },
+    Subdivision { code: "EC-D", name: "Orellana", parent: None },
+    Subdivision { code: "EC-E", name: "Esmeraldas", parent: None },
+    Subdivision { code: "EC-F", name: "Cañar", parent: None },
+    Subdivision { code: "EC-G", name: "Guayas", parent: None },
+    Subdivision { code: "EC-H", name: "Chimborazo", parent: None },
+    Subdivision { code: "EC-I", name: "Imbabura", parent: None },
+    Subdivision { code: "EC-L", name: "Loja", parent: None },
+    Subdivision { code: "EC-M", name: "Manabí", parent: None },
+    Subdivision { code: "EC-N", name: "Napo", parent: None },
+    Subdivision { code: "EC-O", name: "El Oro", parent: None },
+    Subdivision { code: "EC-P", name: "Pichincha", parent: None },
+    Subdivision { code: "EC-R", name: "Los Ríos", parent: None },
+    Subdivision { code: "EC-S", name: "Morona Santiago", parent: None },
+    Subdivision { code: "EC-SD", name: "Santo Domingo de los Tsáchilas", parent: None },
+    Subdivision { code: "EC-SE", name: "Santa Elena", parent: None },
+    Subdivision { code: "EC-T", name: "Tungurahua", parent: None },
+    Subdivision { code: "EC-U", name: "Sucumbíos", parent: None },
+    Subdivision { code: "EC-W", name: "Galápagos", parent: None },
+    Subdivision { code: "EC-X", name: "Cotopaxi", parent: None },
+    Subdivision { code: "EC-Y", name: "Pastaza", parent: None },
+    Subdivision { code: "EC-Z", name: "Zamora Chinchipe", parent: None },
+    Subdivision { code: "EE-130", name: "Alutaguse", parent: Some("EE-45") },
+    Subdivision { code: "EE-141", name: "Anija", parent: Some("EE-37") },
+    Subdivision { code: "EE-142", name: "Antsla", parent: Some("EE-87") },
+    Subdivision { code: "EE-171", name: "Elva", parent: Some("EE-79") },
+    Subdivision { code: "EE-184", name: "Haapsalu", parent: Some("EE-56") },
+    Subdivision { code: "EE-191", name: "Haljala", parent: Some("EE-60") },
+    Subdivision { code: "EE-198", name: "Harku", parent: Some("EE-37") },
+    Subdivision { code: "EE-205", name: "Hiiumaa", parent: Some("EE-39") },
+    Subdivision { code: "EE-214", name: "Häädemeeste", parent: Some("EE-68") },
+    Subdivision { code: "EE-245", name: "Jõelähtme", parent: Some("EE-37") },
+    Subdivision { code: "EE-247", name: "Jõgeva", parent: Some("EE-50") },
+    Subdivision { code: "EE-251", name: "Jõhvi", parent: Some("EE-45") },
+    Subdivision { code: "EE-255", name: "Järva", parent: Some("EE-52") },
+    Subdivision { code: "EE-272", name: "Kadrina", parent: Some("EE-60") },
+    Subdivision { code: "EE-283", name: "Kambja", parent: Some("EE-79") },
+    Subdivision { code: "EE-284", name: "Kanepi", parent: Some("EE-64") },
+    Subdivision { code: "EE-291", name: "Kastre", parent: Some("EE-79") },
+    Subdivision { code: "EE-293", name: "Kehtna", parent: Some("EE-71") },
+    Subdivision { code: "EE-296", name: "Keila", parent: Some("EE-37") },
+    Subdivision { code: "EE-303", name: "Kihnu", parent: Some("EE-68") },
+    Subdivision { code: "EE-305", name: "Kiili", parent: Some("EE-37") },
+    Subdivision { code: "EE-317", name: "Kohila", parent: Some("EE-71") },
+    Subdivision { code: "EE-321", name: "Kohtla-Järve", parent: Some("EE-45") },
+    Subdivision { code: "EE-338", name: "Kose", parent: Some("EE-37") },
+    Subdivision { code: "EE-353", name: "Kuusalu", parent: Some("EE-37") },
+    Subdivision { code: "EE-37", name: "Harjumaa", parent: None },
+    Subdivision { code: "EE-39", name: "Hiiumaa", parent: None },
+    Subdivision { code: "EE-424", name: "Loksa", parent: Some("EE-37") },
+    Subdivision { code: "EE-430", name: "Lääneranna", parent: Some("EE-68") },
+    Subdivision { code: "EE-431", name: "Lääne-Harju", parent: Some("EE-37") },
+    Subdivision { code: "EE-432", name: "Luunja", parent: Some("EE-79") },
+    Subdivision { code: "EE-441", name: "Lääne-Nigula", parent: Some("EE-56") },
+    Subdivision { code: "EE-442", name: "Lüganuse", parent: Some("EE-45") },
+    Subdivision { code: "EE-446", name: "Maardu", parent: Some("EE-37") },
+    Subdivision { code: "EE-45", name: "Ida-Virumaa", parent: None },
+    Subdivision { code: "EE-478", name: "Muhu", parent: Some("EE-74") },
+    Subdivision { code: "EE-480", name: "Mulgi", parent: Some("EE-84") },
+    Subdivision { code: "EE-486", name: "Mustvee", parent: Some("EE-50") },
+    Subdivision { code: "EE-50", name: "Jõgevamaa", parent: None },
+    Subdivision { code: "EE-503", name: "Märjamaa", parent: Some("EE-71") },
+    Subdivision { code: "EE-511", name: "Narva", parent: Some("EE-45") },
+    Subdivision { code: "EE-514", name: "Narva-Jõesuu", parent: Some("EE-45") },
+    Subdivision { code: "EE-52", name: "Järvamaa", parent: None },
+    Subdivision { code: "EE-528", name: "Nõo", parent: Some("EE-79") },
+    Subdivision { code: "EE-557", name: "Otepää", parent: Some("EE-81") },
+    Subdivision { code: "EE-56", name: "Läänemaa", parent: None },
+    Subdivision { code: "EE-567", name: "Paide", parent: Some("EE-52") },
+    Subdivision { code: "EE-586", name: "Peipsiääre", parent: Some("EE-79") },
+    Subdivision { code: "EE-60", name: "Lääne-Virumaa", parent: None },
+    Subdivision { code: "EE-615", name: "Põhja-Sakala", parent: Some("EE-84") },
+    Subdivision { code: "EE-618", name: "Põltsamaa", parent: Some("EE-50") },
+    Subdivision { code: "EE-622", name: "Põlva", parent: Some("EE-64") },
+    Subdivision { code: "EE-624", name: "Pärnu", parent: Some("EE-68") },
+    Subdivision { code: "EE-638", name: "Põhja-Pärnumaa", parent: Some("EE-68") },
+    Subdivision { code: "EE-64", name: "Põlvamaa", parent: None },
+    Subdivision { code: "EE-651", name: "Raasiku", parent: Some("EE-37") },
+    Subdivision { code: "EE-653", name: "Rae", parent: Some("EE-37") },
+    Subdivision { code: "EE-661", name: "Rakvere", parent: Some("EE-60") },
+    Subdivision { code: "EE-663", name: "Rakvere", parent: Some("EE-60") },
+    Subdivision { code: "EE-668", name: "Rapla", parent: Some("EE-71") },
+    Subdivision { code: "EE-68", name: "Pärnumaa", parent: None },
+    Subdivision { code: "EE-689", name: "Ruhnu", parent: Some("EE-74") },
+    Subdivision { code: "EE-698", name: "Rõuge", parent: Some("EE-87") },
+    Subdivision { code: "EE-708", name: "Räpina", parent: Some("EE-64") },
+    Subdivision { code: "EE-71", name: "Raplamaa", parent: None },
+    Subdivision { code: "EE-712", name: "Saarde", parent: Some("EE-68") },
+    Subdivision { code: "EE-714", name: "Saaremaa", parent: Some("EE-74") },
+    Subdivision { code: "EE-719", name: "Saku", parent: Some("EE-37") },
+    Subdivision { code: "EE-726", name: "Saue", parent: Some("EE-37") },
+    Subdivision { code: "EE-732", name: "Setomaa", parent: Some("EE-87") },
+    Subdivision { code: "EE-735", name: "Sillamäe", parent: Some("EE-45") },
+    Subdivision { code: "EE-74", name: "Saaremaa", parent: None },
+    Subdivision { code: "EE-784", name: "Tallinn", parent: Some("EE-37") },
+    Subdivision { code: "EE-79", name: "Tartumaa", parent: None },
+    Subdivision { code: "EE-792", name: "Tapa", parent: Some("EE-60") },
+    Subdivision { code: "EE-793", name: "Tartu", parent: Some("EE-79") },
+    Subdivision { code: "EE-796", name: "Tartu", parent: Some("EE-79") },
+    Subdivision { code: "EE-803", name: "Toila", parent: Some("EE-45") },
+    Subdivision { code: "EE-809", name: "Tori", parent: Some("EE-68") },
+    Subdivision { code: "EE-81", name: "Valgamaa", parent: None },
+    Subdivision { code: "EE-824", name: "Tõrva", parent: Some("EE-81") },
+    Subdivision { code: "EE-834", name: "Türi", parent: Some("EE-52") },
+    Subdivision { code: "EE-84", name: "Viljandimaa", parent: None },
+    Subdivision { code: "EE-855", name: "Valga", parent: Some("EE-81") },
+    Subdivision { code: "EE-87", name: "Võrumaa", parent: None },
+    Subdivision { code: "EE-890", name: "Viimsi", parent: Some("EE-37") },
+    Subdivision { code: "EE-897", name: "Viljandi", parent: Some("EE-84") },
+    Subdivision { code: "EE-899", name: "Viljandi", parent: Some("EE-84") },
+    Subdivision { code: "EE-901", name: "Vinni", parent: Some("EE-60") },
+    Subdivision { code: "EE-903", name: "Viru-Nigula", parent: Some("EE-60") },
+    Subdivision { code: "EE-907", name: "Vormsi", parent: Some("EE-56") },
+    Subdivision { code: "EE-917", name: "Võru", parent: Some("EE-87") },
+    Subdivision { code: "EE-919", name: "Võru", parent: Some("EE-87") },
+    Subdivision { code: "EE-928", name: "Väike-Maarja", parent: Some("EE-60") },
+    Subdivision { code: "EG-ALX", name: "Al Iskandarīyah", parent: None },
+    Subdivision { code: "EG-ASN", name: "Aswān", parent: None },
+    Subdivision { code: "EG-AST", name: "Asyūţ", parent: None },
+    Subdivision { code: "EG-BA", name: "Al Baḩr al Aḩmar", parent: None },
+    Subdivision { code: "EG-BH", name: "Al Buḩayrah", parent: None },
+    Subdivision { code: "EG-BNS", name: "Banī Suwayf", parent: None },
+    Subdivision { code: "EG-C", name: "Al Qāhirah", parent: None },
+    Subdivision { code: "EG-DK", name: "Ad Daqahlīyah", parent: None },
+    Subdivision { code: "EG-DT", name: "Dumyāţ", parent: None },
+    Subdivision { code: "EG-FYM", name: "Al Fayyūm", parent: None },
+    Subdivision { code: "EG-GH", name: "Al Gharbīyah", parent: None },
+    Subdivision { code: "EG-GZ", name: "Al Jīzah", parent: None },
+    Subdivision { code: "EG-IS", name: "Al Ismā'īlīyah", parent: None },
+    Subdivision { code: "EG-JS", name: "Janūb Sīnā'", parent: None },
+    Subdivision { code: "EG-KB", name: "Al Qalyūbīyah", parent: None },
+    Subdivision { code: "EG-KFS", name: "Kafr ash Shaykh", parent: None },
+    Subdivision { code: "EG-KN", name: "Qinā", parent: None },
+    Subdivision { code: "EG-LX", name: "Al Uqşur", parent: None },
+    Subdivision { code: "EG-MN", name: "Al Minyā", parent: None },
+    Subdivision { code: "EG-MNF", name: "Al Minūfīyah", parent: None },
+    Subdivision { code: "EG-MT", name: "Maţrūḩ", parent: None },
+    Subdivision { code: "EG-PTS", name: "Būr Sa‘īd", parent: None },
+    Subdivision { code: "EG-SHG", name: "Sūhāj", parent: None },
+    Subdivision { code: "EG-SHR", name: "Ash Sharqīyah", parent: None },
+    Subdivision { code: "EG-SIN", name: "Shamāl Sīnā'", parent: None },
+    Subdivision { code: "EG-SUZ", name: "As Suways", parent: None },
+    Subdivision { code: "EG-WAD", name: "Al Wādī al Jadīd", parent: None },
+    Subdivision { code: "ER-AN", name: "Ansabā", parent: None },
+    Subdivision { code: "ER-DK", name: "Debubawi K’eyyĭḥ Baḥri", parent: None },
+    Subdivision { code: "ER-DU", name: "Al Janūbī", parent: None },
+    Subdivision { code: "ER-GB", name: "Gash-Barka", parent: None },
+    Subdivision { code: "ER-MA", name: "Al Awsaţ", parent: None },
+    Subdivision { code: "ER-SK", name: "Semienawi K’eyyĭḥ Baḥri", parent: None },
+    Subdivision { code: "ES-A", name: "Alacant*", parent: Some("ES-VC") },
+    Subdivision { code: "ES-AB", name: "Albacete", parent: Some("ES-CM") },
+    Subdivision { code: "ES-AL", name: "Almería", parent: Some("ES-AN") },
+    Subdivision { code: "ES-AN", name: "Andalucía", parent: None },
+    Subdivision { code: "ES-AR", name: "Aragón", parent: None },
+    Subdivision { code: "ES-AS", name: "Asturias, Principado de", parent: None },
+    Subdivision { code: "ES-AV", name: "Ávila", parent: Some("ES-CL") },
+    Subdivision { code: "ES-B", name: "Barcelona [Barcelona]", parent: Some("ES-CT") },
+    Subdivision { code: "ES-BA", name: "Badajoz", parent: Some("ES-EX") },
+    Subdivision { code: "ES-BI", name: "Bizkaia", parent: Some("ES-PV") },
+    Subdivision { code: "ES-BU", name: "Burgos", parent: Some("ES-CL") },
+    Subdivision { code: "ES-C", name: "A Coruña [La Coruña]", parent: Some("ES-GA") },
+    Subdivision { code: "ES-CA", name: "Cádiz", parent: Some("ES-AN") },
+    Subdivision { code: "ES-CB", name: "Cantabria", parent: None },
+    Subdivision { code: "ES-CC", name: "Cáceres", parent: Some("ES-EX") },
+    Subdivision { code: "ES-CE", name: "Ceuta", parent: None },
+    Subdivision { code: "ES-CL", name: "Castilla y León", parent: None },
+    Subdivision { code: "ES-CM", name: "Castilla-La Mancha", parent: None },
+    Subdivision { code: "ES-CN", name: "Canarias", parent: None },
+    Subdivision { code: "ES-CO", name: "Córdoba", parent: Some("ES-AN") },
+    Subdivision { code: "ES-CR", name: "Ciudad Real", parent: Some("ES-CM") },
+    Subdivision { code: "ES-CS", name: "Castelló*", parent: Some("ES-VC") },
+    Subdivision { code: "ES-CT", name: "Catalunya [Cataluña]", parent: None },
+    Subdivision { code: "ES-CU", name: "Cuenca", parent: Some("ES-CM") },
+    Subdivision { code: "ES-EX", name: "Extremadura", parent: None },
+    Subdivision { code: "ES-GA", name: "Galicia [Galicia]", parent: None },
+    Subdivision { code: "ES-GC", name: "Las Palmas", parent: Some("ES-CN") },
+    Subdivision { code: "ES-GI", name: "Girona [Gerona]", parent: Some("ES-CT") },
+    Subdivision { code: "ES-GR", name: "Granada", parent: Some("ES-AN") },
+    Subdivision { code: "ES-GU", name: "Guadalajara", parent: Some("ES-CM") },
+    Subdivision { code: "ES-H", name: "Huelva", parent: Some("ES-AN") },
+    Subdivision { code: "ES-HU", name: "Huesca", parent: Some("ES-AR") },
+    Subdivision { code: "ES-IB", name: "Illes Balears [Islas Baleares]", parent: None },
+    Subdivision { code: "ES-J", name: "Jaén", parent: Some("ES-AN") },
+    Subdivision { code: "ES-L", name: "Lleida [Lérida]", parent: Some("ES-CT") },
+    Subdivision { code: "ES-LE", name: "León", parent: Some("ES-CL") },
+    Subdivision { code: "ES-LO", name: "La Rioja", parent: Some("ES-RI") },
+    Subdivision { code: "ES-LU", name: "Lugo [Lugo]", parent: Some("ES-GA") },
+    Subdivision { code: "ES-M", name: "Madrid", parent: Some("ES-MD") },
+    Subdivision { code: "ES-MA", name: "Málaga", parent: Some("ES-AN") },
+    Subdivision { code: "ES-MC", name: "Murcia, Región de", parent: None },
+    Subdivision { code: "ES-MD", name: "Madrid, Comunidad de", parent: None },
+    Subdivision { code: "ES-ML", name: "Melilla", parent: None },
+    Subdivision { code: "ES-MU", name: "Murcia", parent: Some("ES-MC") },
+    Subdivision { code: "ES-NA", name: "Nafarroa*", parent: Some("ES-NC") },
+    Subdivision { code: "ES-NC", name: "Nafarroako Foru Komunitatea*", parent: None },
+    Subdivision { code: "ES-O", name: "Asturias", parent: Some("ES-AS") },
+    Subdivision { code: "ES-OR", name: "Ourense [Orense]", parent: Some("ES-GA") },
+    Subdivision { code: "ES-P", name: "Palencia", parent: Some("ES-CL") },
+    Subdivision { code: "ES-PM", name: "Illes Balears [Islas Baleares]", parent: Some("ES-IB") },
+    Subdivision { code: "ES-PO", name: "Pontevedra [Pontevedra]", parent: Some("ES-GA") },
+    Subdivision { code: "ES-PV", name: "Euskal Herria", parent: None },
+    Subdivision { code: "ES-RI", name: "La Rioja", parent: None },
+    Subdivision { code: "ES-S", name: "Cantabria", parent: Some("ES-CB") },
+    Subdivision { code: "ES-SA", name: "Salamanca", parent: Some("ES-CL") },
+    Subdivision { code: "ES-SE", name: "Sevilla", parent: Some("ES-AN") },
+    Subdivision { code: "ES-SG", name: "Segovia", parent: Some("ES-CL") },
+    Subdivision { code: "ES-SO", name: "Soria", parent: Some("ES-CL") },
+    Subdivision { code: "ES-SS", name: "Gipuzkoa", parent: Some("ES-PV") },
+    Subdivision { code: "ES-T", name: "Tarragona [Tarragona]", parent: Some("ES-CT") },
+    Subdivision { code: "ES-TE", name: "Teruel", parent: Some("ES-AR") },
+    Subdivision { code: "ES-TF", name: "Santa Cruz de Tenerife", parent: Some("ES-CN") },
+    Subdivision { code: "ES-TO", name: "Toledo", parent: Some("ES-CM") },
+    Subdivision { code: "ES-V", name: "Valencia", parent: Some("ES-VC") },
+    Subdivision { code: "ES-VA", name: "Valladolid", parent: Some("ES-CL") },
+    Subdivision { code: "ES-VC", name: "Valenciana, Comunidad", parent: None },
+    Subdivision { code: "ES-VI", name: "Araba*", parent: Some("ES-PV") },
+    Subdivision { code: "ES-Z", name: "Zaragoza", parent: Some("ES-AR") },
+    Subdivision { code: "ES-ZA", name: "Zamora", parent: Some("ES-CL") },
+    Subdivision { code: "ET-AA", name: "Addis Ababa", parent: None },
+    Subdivision { code: "ET-AF", name: "Afar", parent: None },
+    Subdivision { code: "ET-AM", name: "Amara", parent: None },
+    Subdivision { code: "ET-BE", name: "Benshangul-Gumaz", parent: None },
+    Subdivision { code: "ET-DD", name: "Dire Dawa", parent: None },
+    Subdivision { code: "ET-GA", name: "Gambela Peoples", parent: None },
+    Subdivision { code: "ET-HA", name: "Harari People", parent: None },
+    Subdivision { code: "ET-OR", name: "Oromia", parent: None },
+    Subdivision { code: "ET-SN", name: "Southern Nations, Nationalities and Peoples", parent: None },
+    Subdivision { code: "ET-SO", name: "Somali", parent: None },
+    Subdivision { code: "ET-TI", name: "Tigrai", parent: None },
+    Subdivision { code: "FI-01", name: "Åland", parent: None },
+    Subdivision { code: "FI-02", name: "Etelä-Karjala", parent: None },
+    Subdivision { code: "FI-03", name: "Etelä-Pohjanmaa", parent: None },
+    Subdivision { code: "FI-04", name: "Etelä-Savo", parent: None },
+    Subdivision { code: "FI-05", name: "Kainuu", parent: None },
+    Subdivision { code: "FI-06", name: "Kanta-Häme", parent: None },
+    Subdivision { code: "FI-07", name: "Keski-Pohjanmaa", parent: None },
+    Subdivision { code: "FI-08", name: "Keski-Suomi", parent: None },
+    Subdivision { code: "FI-09", name: "Kymenlaakso", parent: None },
+    Subdivision { code: "FI-10", name: "Lappi", parent: None },
+    Subdivision { code: "FI-11", name: "Pirkanmaa", parent: None },
+    Subdivision { code: "FI-12", name: "Pohjanmaa", parent: None },
+    Subdivision { code: "FI-13", name: "Pohjois-Karjala", parent: None },
+    Subdivision { code: "FI-14", name: "Pohjois-Pohjanmaa", parent: None },
+    Subdivision { code: "FI-15", name: "Pohjois-Savo", parent: None },
+    Subdivision { code: "FI-16", name: "Päijät-Häme", parent: None },
+    Subdivision { code: "FI-17", name: "Satakunta", parent: None },
+    Subdivision { code: "FI-18", name: "Uusimaa", parent: None },
+    Subdivision { code: "FI-19", name: "Varsinais-Suomi", parent: None },
+    Subdivision { code: "FJ-01", name: "Ba", parent: Some("FJ-W") },
+    Subdivision { code: "FJ-02", name: "Bua", parent: Some("FJ-N") },
+    Subdivision { code: "FJ-03", name: "Cakaudrove", parent: Some("FJ-N") },
+    Subdivision { code: "FJ-04", name: "Kadavu", parent: Some("FJ-E") },
+    Subdivision { code: "FJ-05", name: "Lau", parent: Some("FJ-E") },
+    Subdivision { code: "FJ-06", name: "Lomaiviti", parent: Some("FJ-E") },
+    Subdivision { code: "FJ-07", name: "Macuata", parent: Some("FJ-N") },
+    Subdivision { code: "FJ-08", name: "Nadroga and Navosa", parent: Some("FJ-W") },
+    Subdivision { code: "FJ-09", name: "Naitasiri", parent: Some("FJ-C") },
+    Subdivision { code: "FJ-10", name: "Namosi", parent: Some("FJ-C") },
+    Subdivision { code: "FJ-11", name: "Ra", parent: Some("FJ-W") },
+    Subdivision { code: "FJ-12", name: "Rewa", parent: Some("FJ-C") },
+    Subdivision { code: "FJ-13", name: "Serua", parent: Some("FJ-C") },
+    Subdivision { code: "FJ-14", name: "Tailevu", parent: Some("FJ-C") },
+    Subdivision { code: "FJ-C", name: "Central", parent: None },
+    Subdivision { code: "FJ-E", name: "Eastern", parent: None },
+    Subdivision { code: "FJ-N", name: "Northern", parent: None },
+    Subdivision { code: "FJ-R", name: "Rotuma", parent: None },
+    Subdivision { code: "FJ-W", name: "Western", parent: None },
+    Subdivision { code: "FM-KSA", name: "Kosrae", parent: None },
+    Subdivision { code: "FM-PNI", name: "Pohnpei", parent: None },
+    Subdivision { code: "FM-TRK", name: "Chuuk", parent: None },
+    Subdivision { code: "FM-YAP", name: "Yap", parent: None },
+    Subdivision { code: "FR-01", name: "Ain", parent: Some("FR-ARA") },
+    Subdivision { code: "FR-02", name: "Aisne", parent: Some("FR-HDF") },
+    Subdivision { code: "FR-03", name: "Allier", parent: Some("FR-ARA") },
+    Subdivision { code: "FR-04", name: "Alpes-de-Haute-Provence", parent: Some("FR-PAC") },
+    Subdivision { code: "FR-05", name: "Hautes-Alpes", parent: Some("FR-PAC") },
+    Subdivision { code: "FR-06", name: "Alpes-Maritimes", parent: Some("FR-PAC") },
+    Subdivision { code: "FR-07", name: "Ardèche", parent: Some("FR-ARA") },
+    Subdivision { code: "FR-08", name: "Ardennes", parent: Some("FR-GES") },
+    Subdivision { code: "FR-09", name: "Ariège", parent: Some("FR-OCC") },
+    Subdivision { code: "FR-10", name: "Aube", parent: Some("FR-GES") },
+    Subdivision { code: "FR-11", name: "Aude", parent: Some("FR-OCC") },
+    Subdivision { code: "FR-12", name: "Aveyron", parent: Some("FR-OCC") },
+    Subdivision { code: "FR-13", name: "Bouches-du-Rhône", parent: Some("FR-PAC") },
+    Subdivision { code: "FR-14", name: "Calvados", parent: Some("FR-NOR") },
+    Subdivision { code: "FR-15", name: "Cantal", parent: Some("FR-ARA") },
+    Subdivision { code: "FR-16", name: "Charente", parent: Some("FR-NAQ") },
+    Subdivision { code: "FR-17", name: "Charente-Maritime", parent: Some("FR-NAQ") },
+    Subdivision { code: "FR-18", name: "Cher", parent: Some("FR-CVL") },
+    Subdivision { code: "FR-19", name: "Corrèze", parent: Some("FR-NAQ") },
+    Subdivision { code: "FR-20R", name: "Corse", parent: None },
+    Subdivision { code: "FR-21", name: "Côte-d'Or", parent: Some("FR-BFC") },
+    Subdivision { code: "FR-22", name: "Côtes-d'Armor", parent: Some("FR-BRE") },
+    Subdivision { code: "FR-23", name: "Creuse", parent: Some("FR-NAQ") },
+    Subdivision { code: "FR-24", name: "Dordogne", parent: Some("FR-NAQ") },
+    Subdivision { code: "FR-25", name: "Doubs", parent: Some("FR-BFC") },
+    Subdivision { code: "FR-26", name: "Drôme", parent: Some("FR-ARA") },
+    Subdivision { code: "FR-27", name: "Eure", parent: Some("FR-NOR") },
+    Subdivision { code: "FR-28", name: "Eure-et-Loir", parent: Some("FR-CVL") },
+    Subdivision { code: "FR-29", name: "Finistère", parent: Some("FR-BRE") },
+    Subdivision { code: "FR-2A", name: "Corse-du-Sud", parent: Some("FR-20R") },
+    Subdivision { code: "FR-2B", name: "Haute-Corse", parent: Some("FR-20R") },
+    Subdivision { code: "FR-30", name: "Gard", parent: Some("FR-OCC") },
+    Subdivision { code: "FR-31", name: "Haute-Garonne", parent: Some("FR-OCC") },
+    Subdivision { code: "FR-32", name: "Gers", parent: Some("FR-OCC") },
+    Subdivision { code: "FR-33", name: "Gironde", parent: Some("FR-NAQ") },
+    Subdivision { code: "FR-34", name: "Hérault", parent: Some("FR-OCC") },
+    Subdivision { code: "FR-35", name: "Ille-et-Vilaine", parent: Some("FR-BRE") },
+    Subdivision { code: "FR-36", name: "Indre", parent: Some("FR-CVL") },
+    Subdivision { code: "FR-37", name: "Indre-et-Loire", parent: Some("FR-CVL") },
+    Subdivision { code: "FR-38", name: "Isère", parent: Some("FR-ARA") },
+    Subdivision { code: "FR-39", name: "Jura", parent: Some("FR-BFC") },
+    Subdivision { code: "FR-40", name: "Landes", parent: Some("FR-NAQ") },
+    Subdivision { code: "FR-41", name: "Loir-et-Cher", parent: Some("FR-CVL") },
+    Subdivision { code: "FR-42", name: "Loire", parent: Some("FR-ARA") },
+    Subdivision { code: "FR-43", name: "Haute-Loire", parent: Some("FR-ARA") },
+    Subdivision { code: "FR-44", name: "Loire-Atlantique", parent: Some("FR-PDL") },
+    Subdivision { code: "FR-45", name: "Loiret", parent: Some("FR-CVL") },
+    Subdivision { code: "FR-46", name: "Lot", parent: Some("FR-OCC") },
+    Subdivision { code: "FR-47", name: "Lot-et-Garonne", parent: Some("FR-NAQ") },
+    Subdivision { code: "FR-48", name: "Lozère", parent: Some("FR-OCC") },
+    Subdivision { code: "FR-49", name: "Maine-et-Loire", parent: Some("FR-PDL") },
+    Subdivision { code: "FR-50", name: "Manche", parent: Some("FR-NOR") },
+    Subdivision { code: "FR-51", name: "Marne", parent: Some("FR-GES") },
+    Subdivision { code: "FR-52", name: "Haute-Marne", parent: Some("FR-GES") },
+    Subdivision { code: "FR-53", name: "Mayenne", parent: Some("FR-PDL") },
+    Subdivision { code: "FR-54", name: "Meurthe-et-Moselle", parent: Some("FR-GES") },
+    Subdivision { code: "FR-55", name: "Meuse", parent: Some("FR-GES") },
+    Subdivision { code: "FR-56", name: "Morbihan", parent: Some("FR-BRE") },
+    Subdivision { code: "FR-57", name: "Moselle", parent: Some("FR-GES") },
+    Subdivision { code: "FR-58", name: "Nièvre", parent: Some("FR-BFC") },
+    Subdivision { code: "FR-59", name: "Nord", parent: Some("FR-HDF") },
+    Subdivision { code: "FR-60", name: "Oise", parent: Some("FR-HDF") },
+    Subdivision { code: "FR-61", name: "Orne", parent: Some("FR-NOR") },
+    Subdivision { code: "FR-62", name: "Pas-de-Calais", parent: Some("FR-HDF") },
+    Subdivision { code: "FR-63", name: "Puy-de-Dôme", parent: Some("FR-ARA") },
+    Subdivision { code: "FR-64", name: "Pyrénées-Atlantiques", parent: Some("FR-NAQ") },
+    Subdivision { code: "FR-65", name: "Hautes-Pyrénées", parent: Some("FR-OCC") },
+    Subdivision { code: "FR-66", name: "Pyrénées-Orientales", parent: Some("FR-OCC") },
+    Subdivision { code: "FR-67", name: "Bas-Rhin", parent: Some("FR-GES") },
+    Subdivision { code: "FR-68", name: "Haut-Rhin", parent: Some("FR-GES") },
+    Subdivision { code: "FR-69", name: "Rhône", parent: Some("FR-ARA") },
+    Subdivision { code: "FR-70", name: "Haute-Saône", parent: Some("FR-BFC") },
+    Subdivision { code: "FR-71", name: "Saône-et-Loire", parent: Some("FR-BFC") },
+    Subdivision { code: "FR-72", name: "Sarthe", parent: Some("FR-PDL") },
+    Subdivision { code: "FR-73", name: "Savoie", parent: Some("FR-ARA") },
+    Subdivision { code: "FR-74", name: "Haute-Savoie", parent: Some("FR-ARA") },
+    Subdivision { code: "FR-75", name: "Paris", parent: Some("FR-IDF") },
+    Subdivision { code: "FR-76", name: "Seine-Maritime", parent: Some("FR-NOR") },
+    Subdivision { code: "FR-77", name: "Seine-et-Marne", parent: Some("FR-IDF") },
+    Subdivision { code: "FR-78", name: "Yvelines", parent: Some("FR-IDF") },
+    Subdivision { code: "FR-79", name: "Deux-Sèvres", parent: Some("FR-NAQ") },
+    Subdivision { code: "FR-80", name: "Somme", parent: Some("FR-HDF") },
+    Subdivision { code: "FR-81", name: "Tarn", parent: Some("FR-OCC") },
+    Subdivision { code: "FR-82", name: "Tarn-et-Garonne", parent: Some("FR-OCC") },
+    Subdivision { code: "FR-83", name: "Var", parent: Some("FR-PAC") },
+    Subdivision { code: "FR-84", name: "Vaucluse", parent: Some("FR-PAC") },
+    Subdivision { code: "FR-85", name: "Vendée", parent: Some("FR-PDL") },
+    Subdivision { code: "FR-86", name: "Vienne", parent: Some("FR-NAQ") },
+    Subdivision { code: "FR-87", name: "Haute-Vienne", parent: Some("FR-NAQ") },
+    Subdivision { code: "FR-88", name: "Vosges", parent: Some("FR-GES") },
+    Subdivision { code: "FR-89", name: "Yonne", parent: Some("FR-BFC") },
+    Subdivision { code: "FR-90", name: "Territoire de Belfort", parent: Some("FR-BFC") },
+    Subdivision { code: "FR-91", name: "Essonne", parent: Some("FR-IDF") },
+    Subdivision { code: "FR-92", name: "Hauts-de-Seine", parent: Some("FR-IDF") },
+    Subdivision { code: "FR-93", name: "Seine-Saint-Denis", parent: Some("FR-IDF") },
+    Subdivision { code: "FR-94", name: "Val-de-Marne", parent: Some("FR-IDF") },
+    Subdivision { code: "FR-95", name: "Val-d'Oise", parent: Some("FR-IDF") },
+    Subdivision { code: "FR-971", name: "Guadeloupe", parent: Some("FR-GP") },
+    Subdivision { code: "FR-972", name: "Martinique", parent: Some("FR-MQ") },
+    Subdivision { code: "FR-973", name: "Guyane (française)", parent: Some("FR-GF") },
+    Subdivision { code: "FR-974", name: "La Réunion", parent: Some("FR-RE") },
+    Subdivision { code: "FR-976", name: "Mayotte", parent: Some("FR-YT") },
+    Subdivision { code: "FR-ARA", name: "Auvergne-Rhône-Alpes", parent: None },
+    Subdivision { code: "FR-BFC", name: "Bourgogne-Franche-Comté", parent: None },
+    Subdivision { code: "FR-BL", name: "Saint-Barthélemy", parent: None },
+    Subdivision { code: "FR-BRE", name: "Bretagne", parent: None },
+    Subdivision { code: "FR-CP", name: "Clipperton", parent: None },
+    Subdivision { code: "FR-CVL", name: "Centre-Val de Loire", parent: None },
+    Subdivision { code: "FR-GES", name: "Grand-Est", parent: None },
+    Subdivision { code: "FR-GF", name: "Guyane (française)", parent: None },
+    Subdivision { code: "FR-GP", name: "Guadeloupe", parent: None },
+    Subdivision { code: "FR-HDF", name: "Hauts-de-France", parent: None },
+    Subdivision { code: "FR-IDF", name: "Île-de-France", parent: None },
+    Subdivision { code: "FR-MF", name: "Saint-Martin", parent: None },
+    Subdivision { code: "FR-MQ", name: "Martinique", parent: None },
+    Subdivision { code: "FR-NAQ", name: "Nouvelle-Aquitaine", parent: None },
+    Subdivision { code: "FR-NC", name: "Nouvelle-Calédonie", parent: None },
+    Subdivision { code: "FR-NOR", name: "Normandie", parent: None },
+    Subdivision { code: "FR-OCC", name: "Occitanie", parent: None },
+    Subdivision { code: "FR-PAC", name: "Provence-Alpes-Côte-d’Azur", parent: None },
+    Subdivision { code: "FR-PDL", name: "Pays-de-la-Loire", parent: None },
+    Subdivision { code: "FR-PF", name: "Polynésie française", parent: None },
+    Subdivision { code: "FR-PM", name: "Saint-Pierre-et-Miquelon", parent: None },
+    Subdivision { code: "FR-RE", name: "La Réunion", parent: None },
+    Subdivision { code: "FR-TF", name: "Terres australes françaises", parent: None },
+    Subdivision { code: "FR-WF", name: "Wallis-et-Futuna", parent: None },
+    Subdivision { code: "FR-YT", name: "Mayotte", parent: None },
+    Subdivision { code: "GA-1", name: "Estuaire", parent: None },
+    Subdivision { code: "GA-2", name: "Haut-Ogooué", parent: None },
+    Subdivision { code: "GA-3", name: "Moyen-Ogooué", parent: None },
+    Subdivision { code: "GA-4", name: "Ngounié", parent: None },
+    Subdivision { code: "GA-5", name: "Nyanga", parent: None },
+    Subdivision { code: "GA-6", name: "Ogooué-Ivindo", parent: None },
+    Subdivision { code: "GA-7", name: "Ogooué-Lolo", parent: None },
+    Subdivision { code: "GA-8", name: "Ogooué-Maritime", parent: None },
+    Subdivision { code: "GA-9", name: "Woleu-Ntem", parent: None },
+    Subdivision { code: "GB-ABC", name: "Armagh City, Banbridge and Craigavon", parent: Some("GB-NIR") },
+    Subdivision { code: "GB-ABD", name: "Aberdeenshire", parent: Some("GB-SCT") },
+    Subdivision { code: "GB-ABE", name: "Aberdeen City", parent: Some("GB-SCT") },
+    Subdivision { code: "GB-AGB", name: "Argyll and Bute", parent: Some("GB-SCT") },
+    Subdivision { code: "GB-AGY", name: "Isle of Anglesey [Sir Ynys Môn GB-YNM]", parent: Some("GB-WLS") },
+    Subdivision { code: "GB-AND", name: "Ards and North Down", parent: Some("GB-NIR") },
+    Subdivision { code: "GB-ANN", name: "Antrim and Newtownabbey", parent: Some("GB-NIR") },
+    Subdivision { code: "GB-ANS", name: "Angus", parent: Some("GB-SCT") },
+    Subdivision { code: "GB-BAS", name: "Bath and North East Somerset", parent: Some("GB-ENG") },
+    Subdivision { code: "GB-BBD", name: "Blackburn with Darwen", parent: Some("GB-ENG") },
+    Subdivision { code: "GB-BCP", name: "Bournemouth, Christchurch and Poole", parent: Some("GB-ENG") },
+    Subdivision { code: "GB-BDF", name: "Bedford", parent: Some("GB-ENG") },
+    Subdivision { code: "GB-BDG", name: "Barking and Dagenham", parent: Some("GB-ENG") },
+    Subdivision { code: "GB-BEN", name: "Brent", parent: Some("GB-ENG") },
+    Subdivision { code: "GB-BEX", name: "Bexley", parent: Some("GB-ENG") },
+    Subdivision { code: "GB-BFS", name: "Belfast City", parent: Some("GB-NIR") },
+    Subdivision { code: "GB-BGE", name: "Bridgend [Pen-y-bont ar Ogwr GB-POG]", parent: Some("GB-WLS") },
+    Subdivision { code: "GB-BGW", name: "Blaenau Gwent", parent: Some("GB-WLS") },
+    Subdivision { code: "GB-BIR", name: "Birmingham", parent: Some("GB-ENG") },
+    Subdivision { code: "GB-BKM", name: "Buckinghamshire", parent: Some("GB-ENG") },
+    Subdivision { code: "GB-BNE", name: "Barnet", parent: Some("GB-ENG") },
+    Subdivision { code: "GB-BNH", name: "Brighton and Hove", parent: Some("GB-ENG") },
+    Subdivision { code: "GB-BNS", name: "Barnsley", parent: Some("GB-ENG") },
+    Subdivision { code: "GB-BOL", name: "Bolton", parent: Some("GB-ENG") },
+    Subdivision { code: "GB-BPL", name: "Blackpool", parent: Some("GB-ENG") },
+    Subdivision { code: "GB-BRC", name: "Bracknell Forest", parent: Some("GB-ENG") },
+    Subdivision { code: "GB-BRD", name: "Bradford", parent: Some("GB-ENG") },
+    Subdivision { code: "GB-BRY", name: "Bromley", parent: Some("GB-ENG") },
+    Subdivision { code: "GB-BST", name: "Bristol, City of", parent: Some("GB-ENG") },
+    Subdivision { code: "GB-BUR", name: "Bury", parent: Some("GB-ENG") },
+    Subdivision { code: "GB-CAM", name: "Cambridgeshire", parent: Some("GB-ENG") },
+    Subdivision { code: "GB-CAY", name: "Caerphilly [Caerffili GB-CAF]", parent: Some("GB-WLS") },
+    Subdivision { code: "GB-CBF", name: "Central Bedfordshire", parent: Some("GB-ENG") },
+    Subdivision { code: "GB-CCG", name: "Causeway Coast and Glens", parent: Some("GB-NIR") },
+    Subdivision { code: "GB-CGN", name: "Ceredigion [Sir Ceredigion]", parent: Some("GB-WLS") },
+    Subdivision { code: "GB-CHE", name: "Cheshire East", parent: Some("GB-ENG") },
+    Subdivision { code: "GB-CHW", name: "Cheshire West and Chester", parent: Some("GB-ENG") },
+    Subdivision { code: "GB-CLD", name: "Calderdale", parent: Some("GB-ENG") },
+    Subdivision { code: "GB-CLK", name: "Clackmannanshire", parent: Some("GB-SCT") },
+    Subdivision { code: "GB-CMA", name: "Cumbria", parent: Some("GB-ENG") },
+    Subdivision { code: "GB-CMD", name: "Camden", parent: Some("GB-ENG") },
+    Subdivision { code: "GB-CMN", name: "Carmarthenshire [Sir Gaerfyrddin GB-GFY]", parent: Some("GB-WLS") },
+    Subdivision { code: "GB-CON", name: "Cornwall", parent: Some("GB-ENG") },
+    Subdivision { code: "GB-COV", name: "Coventry", parent: Some("GB-ENG") },
+    Subdivision { code: "GB-CRF", name: "Cardiff [Caerdydd GB-CRD]", parent: Some("GB-WLS") },
+    Subdivision { code: "GB-CRY", name: "Croydon", parent: Some("GB-ENG") },
+    Subdivision { code: "GB-CWY", name: "Conwy", parent: Some("GB-WLS") },
+    Subdivision { code: "GB-DAL", name: "Darlington", parent: Some("GB-ENG") },
+    Subdivision { code: "GB-DBY", name: "Derbyshire", parent: Some("GB-ENG") },
+    Subdivision { code: "GB-DEN", name: "Denbighshire [Sir Ddinbych GB-DDB]", parent: Some("GB-WLS") },
+    Subdivision { code: "GB-DER", name: "Derby", parent: Some("GB-ENG") },
+    Subdivision { code: "GB-DEV", name: "Devon", parent: Some("GB-ENG") },
+    Subdivision { code: "GB-DGY", name: "Dumfries and Galloway", parent: Some("GB-SCT") },
+    Subdivision { code: "GB-DNC", name: "Doncaster", parent: Some("GB-ENG") },
+    Subdivision { code: "GB-DND", name: "Dundee City", parent: Some("GB-SCT") },
+    Subdivision { code: "GB-DOR", name: "Dorset", parent: Some("GB-ENG") },
+    Subdivision { code: "GB-DRS", name: "Derry and Strabane", parent: Some("GB-NIR") },
+    Subdivision { code: "GB-DUD", name: "Dudley", parent: Some("GB-ENG") },
+    Subdivision { code: "GB-DUR", name: "Durham, County", parent: Some("GB-ENG") },
+    Subdivision { code: "GB-EAL", name: "Ealing", parent: Some("GB-ENG") },
+    Subdivision { code: "GB-EAY", name: "East Ayrshire", parent: Some("GB-SCT") },
+    Subdivision { code: "GB-EDH", name: "Edinburgh, City of", parent: Some("GB-SCT") },
+    Subdivision { code: "GB-EDU", name: "East Dunbartonshire", parent: Some("GB-SCT") },
+    Subdivision { code: "GB-ELN", name: "East Lothian", parent: Some("GB-SCT") },
+    Subdivision { code: "GB-ELS", name: "Eilean Siar", parent: Some("GB-SCT") },
+    Subdivision { code: "GB-ENF", name: "Enfield", parent: Some("GB-ENG") },
+    Subdivision { code: "GB-ENG", name: "England", parent: None },
+    Subdivision { code: "GB-ERW", name: "East Renfrewshire", parent: Some("GB-SCT") },
+    Subdivision { code: "GB-ERY", name: "East Riding of Yorkshire", parent: Some("GB-ENG") },
+    Subdivision { code: "GB-ESS", name: "Essex", parent: Some("GB-ENG") },
+    Subdivision { code: "GB-ESX", name: "East Sussex", parent: Some("GB-ENG") },
+    Subdivision { code: "GB-FAL", name: "Falkirk", parent: Some("GB-SCT") },
+    Subdivision { code: "GB-FIF", name: "Fife", parent: Some("GB-SCT") },
+    Subdivision { code: "GB-FLN", name: "Flintshire [Sir y Fflint GB-FFL]", parent: Some("GB-WLS") },
+    Subdivision { code: "GB-FMO", name: "Fermanagh and Omagh", parent: Some("GB-NIR") },
+    Subdivision { code: "GB-GAT", name: "Gateshead", parent: Some("GB-ENG") },
+    Subdivision { code: "GB-GLG", name: "Glasgow City", parent: Some("GB-SCT") },
+    Subdivision { code: "GB-GLS", name: "Gloucestershire", parent: Some("GB-ENG") },
+    Subdivision { code: "GB-GRE", name: "Greenwich", parent: Some("GB-ENG") },
+    Subdivision { code: "GB-GWN", name: "Gwynedd", parent: Some("GB-WLS") },
+    Subdivision { code: "GB-HAL", name: "Halton", parent: Some("GB-ENG") },
+    Subdivision { code: "GB-HAM", name: "Hampshire", parent: Some("GB-ENG") },
+    Subdivision { code: "GB-HAV", name: "Havering", parent: Some("GB-ENG") },
+    Subdivision { code: "GB-HCK", name: "Hackney", parent: Some("GB-ENG") },
+    Subdivision { code: "GB-HEF", name: "Herefordshire", parent: Some("GB-ENG") },
+    Subdivision { code: "GB-HIL", name: "Hillingdon", parent: Some("GB-ENG") },
+    Subdivision { code: "GB-HLD", name: "Highland", parent: Some("GB-SCT") },
+    Subdivision { code: "GB-HMF", name: "Hammersmith and Fulham", parent: Some("GB-ENG") },
+    Subdivision { code: "GB-HNS", name: "Hounslow", parent: Some("GB-ENG") },
+    Subdivision { code: "GB-HPL", name: "Hartlepool", parent: Some("GB-ENG") },
+    Subdivision { code: "GB-HRT", name: "Hertfordshire", parent: Some("GB-ENG") },
+    Subdivision { code: "GB-HRW", name: "Harrow", parent: Some("GB-ENG") },
+    Subdivision { code: "GB-HRY", name: "Haringey", parent: Some("GB-ENG") },
+    Subdivision { code: "GB-IOS", name: "Isles of Scilly", parent: Some("GB-ENG") },
+    Subdivision { code: "GB-IOW", name: "Isle of Wight", parent: Some("GB-ENG") },
+    Subdivision { code: "GB-ISL", name: "Islington", parent: Some("GB-ENG") },
+    Subdivision { code: "GB-IVC", name: "Inverclyde", parent: Some("GB-SCT") },
+    Subdivision { code: "GB-KEC", name: "Kensington and Chelsea", parent: Some("GB-ENG") },
+    Subdivision { code: "GB-KEN", name: "Kent", parent: Some("GB-ENG") },
+    Subdivision { code: "GB-KHL", name: "Kingston upon Hull", parent: Some("GB-ENG") },
+    Subdivision { code: "GB-KIR", name: "Kirklees", parent: Some("GB-ENG") },
+    Subdivision { code: "GB-KTT", name: "Kingston upon Thames", parent: Some("GB-ENG") },
+    Subdivision { code: "GB-KWL", name: "Knowsley", parent: Some("GB-ENG") },
+    Subdivision { code: "GB-LAN", name: "Lancashire", parent: Some("GB-ENG") },
+    Subdivision { code: "GB-LBC", name: "Lisburn and Castlereagh", parent: Some("GB-NIR") },
+    Subdivision { code: "GB-LBH", name: "Lambeth", parent: Some("GB-ENG") },
+    Subdivision { code: "GB-LCE", name: "Leicester", parent: Some("GB-ENG") },
+    Subdivision { code: "GB-LDS", name: "Leeds", parent: Some("GB-ENG") },
+    Subdivision { code: "GB-LEC", name: "Leicestershire", parent: Some("GB-ENG") },
+    Subdivision { code: "GB-LEW", name: "Lewisham", parent: Some("GB-ENG") },
+    Subdivision { code: "GB-LIN", name: "Lincolnshire", parent: Some("GB-ENG") },
+    Subdivision { code: "GB-LIV", name: "Liverpool", parent: Some("GB-ENG") },
+    Subdivision { code: "GB-LND", name: "London, City of", parent: Some("GB-ENG") },
+    Subdivision { code: "GB-LUT", name: "Luton", parent: Some("GB-ENG") },
+    Subdivision { code: "GB-MAN", name: "Manchester", parent: Some("GB-ENG") },
+    Subdivision { code: "GB-MDB", name: "Middlesbrough", parent: Some("GB-ENG") },
+    Subdivision { code: "GB-MDW", name: "Medway", parent: Some("GB-ENG") },
+    Subdivision { code: "GB-MEA", name: "Mid and East Antrim", parent: Some("GB-NIR") },
+    Subdivision { code: "GB-MIK", name: "Milton Keynes", parent: Some("GB-ENG") },
+    Subdivision { code: "GB-MLN", name: "Midlothian", parent: Some("GB-SCT") },
+    Subdivision { code: "GB-MON", name: "Monmouthshire [Sir Fynwy GB-FYN]", parent: Some("GB-WLS") },
+    Subdivision { code: "GB-MRT", name: "Merton", parent: Some("GB-ENG") },
+    Subdivision { code: "GB-MRY", name: "Moray", parent: Some("GB-SCT") },
+    Subdivision { code: "GB-MTY", name: "Merthyr Tydfil [Merthyr Tudful GB-MTU]", parent: Some("GB-WLS") },
+    Subdivision { code: "GB-MUL", name: "Mid-Ulster", parent: Some("GB-NIR") },
+    Subdivision { code: "GB-NAY", name: "North Ayrshire", parent: Some("GB-SCT") },
+    Subdivision { code: "GB-NBL", name: "Northumberland", parent: Some("GB-ENG") },
+    Subdivision { code: "GB-NEL", name: "North East Lincolnshire", parent: Some("GB-ENG") },
+    Subdivision { code: "GB-NET", name: "Newcastle upon Tyne", parent: Some("GB-ENG") },
+    Subdivision { code: "GB-NFK", name: "Norfolk", parent: Some("GB-ENG") },
+    Subdivision { code: "GB-NGM", name: "Nottingham", parent: Some("GB-ENG") },
+    Subdivision { code: "GB-NIR", name: "Northern Ireland", parent: None },
+    Subdivision { code: "GB-NLK", name: "North Lanarkshire", parent: Some("GB-SCT") },
+    Subdivision { code: "GB-NLN", name: "North Lincolnshire", parent: Some("GB-ENG") },
+    Subdivision { code: "GB-NMD", name: "Newry, Mourne and Down", parent: Some("GB-NIR") },
+    Subdivision { code: "GB-NSM", name: "North Somerset", parent: Some("GB-ENG") },
+    Subdivision { code: "GB-NTH", name: "Northamptonshire", parent: Some("GB-ENG") },
+    Subdivision { code: "GB-NTL", name: "Neath Port Talbot [Castell-nedd Port Talbot GB-CTL]", parent: Some("GB-WLS") },
+    Subdivision { code: "GB-NTT", name: "Nottinghamshire", parent: Some("GB-ENG") },
+    Subdivision { code: "GB-NTY", name: "North Tyneside", parent: Some("GB-ENG") },
+    Subdivision { code: "GB-NWM", name: "Newham", parent: Some("GB-ENG") },
+    Subdivision { code: "GB-NWP", name: "Newport [Casnewydd GB-CNW]", parent: Some("GB-WLS") },
+    Subdivision { code: "GB-NYK", name: "North Yorkshire", parent: Some("GB-ENG") },
+    Subdivision { code: "GB-OLD", name: "Oldham", parent: Some("GB-ENG") },
+    Subdivision { code: "GB-ORK", name: "Orkney Islands", parent: Some("GB-SCT") },
+    Subdivision { code: "GB-OXF", name: "Oxfordshire", parent: Some("GB-ENG") },
+    Subdivision { code: "GB-PEM", name: "Pembrokeshire [Sir Benfro GB-BNF]", parent: Some("GB-WLS") },
+    Subdivision { code: "GB-PKN", name: "Perth and Kinross", parent: Some("GB-SCT") },
+    Subdivision { code: "GB-PLY", name: "Plymouth", parent: Some("GB-ENG") },
+    Subdivision { code: "GB-POR", name: "Portsmouth", parent: Some("GB-ENG") },
+    Subdivision { code: "GB-POW", name: "Powys", parent: Some("GB-WLS") },
+    Subdivision { code: "GB-PTE", name: "Peterborough", parent: Some("GB-ENG") },
+    Subdivision { code: "GB-RCC", name: "Redcar and Cleveland", parent: Some("GB-ENG") },
+    Subdivision { code: "GB-RCH", name: "Rochdale", parent: Some("GB-ENG") },
+    Subdivision { code: "GB-RCT", name: "Rhondda Cynon Taff [Rhondda CynonTaf]", parent: Some("GB-WLS") },
+    Subdivision { code: "GB-RDB", name: "Redbridge", parent: Some("GB-ENG") },
+    Subdivision { code: "GB-RDG", name: "Reading", parent: Some("GB-ENG") },
+    Subdivision { code: "GB-RFW", name: "Renfrewshire", parent: Some("GB-SCT") },
+    Subdivision { code: "GB-RIC", name: "Richmond upon Thames", parent: Some("GB-ENG") },
+    Subdivision { code: "GB-ROT", name: "Rotherham", parent: Some("GB-ENG") },
+    Subdivision { code: "GB-RUT", name: "Rutland", parent: Some("GB-ENG") },
+    Subdivision { code: "GB-SAW", name: "Sandwell", parent: Some("GB-ENG") },
+    Subdivision { code: "GB-SAY", name: "South Ayrshire", parent: Some("GB-SCT") },
+    Subdivision { code: "GB-SCB", name: "Scottish Borders", parent: Some("GB-SCT") },
+    Subdivision { code: "GB-SCT", name: "Scotland", parent: None },
+    Subdivision { code: "GB-SFK", name: "Suffolk", parent: Some("GB-ENG") },
+    Subdivision { code: "GB-SFT", name: "Sefton", parent: Some("GB-ENG") },
+    Subdivision { code: "GB-SGC", name: "South Gloucestershire", parent: Some("GB-ENG") },
+    Subdivision { code: "GB-SHF", name: "Sheffield", parent: Some("GB-ENG") },
+    Subdivision { code: "GB-SHN", name: "St. Helens", parent: Some("GB-ENG") },
+    Subdivision { code: "GB-SHR", name: "Shropshire", parent: Some("GB-ENG") },
+    Subdivision { code: "GB-SKP", name: "Stockport", parent: Some("GB-ENG") },
+    Subdivision { code: "GB-SLF", name: "Salford", parent: Some("GB-ENG") },
+    Subdivision { code: "GB-SLG", name: "Slough", parent: Some("GB-ENG") },
+    Subdivision { code: "GB-SLK", name: "South Lanarkshire", parent: Some("GB-SCT") },
+    Subdivision { code: "GB-SND", name: "Sunderland", parent: Some("GB-ENG") },
+    Subdivision { code: "GB-SOL", name: "Solihull", parent: Some("GB-ENG") },
+    Subdivision { code: "GB-SOM", name: "Somerset", parent: Some("GB-ENG") },
+    Subdivision { code: "GB-SOS", name: "Southend-on-Sea", parent: Some("GB-ENG") },
+    Subdivision { code: "GB-SRY", name: "Surrey", parent: Some("GB-ENG") },
+    Subdivision { code: "GB-STE", name: "Stoke-on-Trent", parent: Some("GB-ENG") },
+    Subdivision { code: "GB-STG", name: "Stirling", parent: Some("GB-SCT") },
+    Subdivision { code: "GB-STH", name: "Southampton", parent: Some("GB-ENG") },
+    Subdivision { code: "GB-STN", name: "Sutton", parent: Some("GB-ENG") },
+    Subdivision { code: "GB-STS", name: "Staffordshire", parent: Some("GB-ENG") },
+    Subdivision { code: "GB-STT", name: "Stockton-on-Tees", parent: Some("GB-ENG") },
+    Subdivision { code: "GB-STY", name: "South Tyneside", parent: Some("GB-ENG") },
+    Subdivision { code: "GB-SWA", name: "Swansea [Abertawe GB-ATA]", parent: Some("GB-WLS") },
+    Subdivision { code: "GB-SWD", name: "Swindon", parent: Some("GB-ENG") },
+    Subdivision { code: "GB-SWK", name: "Southwark", parent: Some("GB-ENG") },
+    Subdivision { code: "GB-TAM", name: "Tameside", parent: Some("GB-ENG") },
+    Subdivision { code: "GB-TFW", name: "Telford and Wrekin", parent: Some("GB-ENG") },
+    Subdivision { code: "GB-THR", name: "Thurrock", parent: Some("GB-ENG") },
+    Subdivision { code: "GB-TOB", name: "Torbay", parent: Some("GB-ENG") },
+    Subdivision { code: "GB-TOF", name: "Torfaen [Tor-faen]", parent: Some("GB-WLS") },
+    Subdivision { code: "GB-TRF", name: "Trafford", parent: Some("GB-ENG") },
+    Subdivision { code: "GB-TWH", name: "Tower Hamlets", parent: Some("GB-ENG") },
+    Subdivision { code: "GB-VGL", name: "Vale of Glamorgan, The [Bro Morgannwg GB-BMG]", parent: Some("GB-WLS") },
+    Subdivision { code: "GB-WAR", name: "Warwickshire", parent: Some("GB-ENG") },
+    Subdivision { code: "GB-WBK", name: "West Berkshire", parent: Some("GB-ENG") },
+    Subdivision { code: "GB-WDU", name: "West Dunbartonshire", parent: Some("GB-SCT") },
+    Subdivision { code: "GB-WFT", name: "Waltham Forest", parent: Some("GB-ENG") },
+    Subdivision { code: "GB-WGN", name: "Wigan", parent: Some("GB-ENG") },
+    Subdivision { code: "GB-WIL", name: "Wiltshire", parent: Some("GB-ENG") },
+    Subdivision { code: "GB-WKF", name: "Wakefield", parent: Some("GB-ENG") },
+    Subdivision { code: "GB-WLL", name: "Walsall", parent: Some("GB-ENG") },
+    Subdivision { code: "GB-WLN", name: "West Lothian", parent: Some("GB-SCT") },
+    Subdivision { code: "GB-WLS", name: "Wales [Cymru GB-CYM]", parent: None },
+    Subdivision { code: "GB-WLV", name: "Wolverhampton", parent: Some("GB-ENG") },
+    Subdivision { code: "GB-WND", name: "Wandsworth", parent: Some("GB-ENG") },
+    Subdivision { code: "GB-WNM", name: "Windsor and Maidenhead", parent: Some("GB-ENG") },
+    Subdivision { code: "GB-WOK", name: "Wokingham", parent: Some("GB-ENG") },
+    Subdivision { code: "GB-WOR", name: "Worcestershire", parent: Some("GB-ENG") },
+    Subdivision { code: "GB-WRL", name: "Wirral", parent: Some("GB-ENG") },
+    Subdivision { code: "GB-WRT", name: "Warrington", parent: Some("GB-ENG") },
+    Subdivision { code: "GB-WRX", name: "Wrexham [Wrecsam GB-WRC]", parent: Some("GB-WLS") },
+    Subdivision { code: "GB-WSM", name: "Westminster", parent: Some("GB-ENG") },
+    Subdivision { code: "GB-WSX", name: "West Sussex", parent: Some("GB-ENG") },
+    Subdivision { code: "GB-YOR", name: "York", parent: Some("GB-ENG") },
+    Subdivision { code: "GB-ZET", name: "Shetland Islands", parent: Some("GB-SCT") },
+    Subdivision { code: "GD-01", name: "Saint Andrew", parent: None },
+    Subdivision { code: "GD-02", name: "Saint David", parent: None },
+    Subdivision { code: "GD-03", name: "Saint George", parent: None },
+    Subdivision { code: "GD-04", name: "Saint John", parent: None },
+    Subdivision { code: "GD-05", name: "Saint Mark", parent: None },
+    Subdivision { code: "GD-06", name: "Saint Patrick", parent: None },
+    Subdivision { code: "GD-10", name: "Southern Grenadine Islands", parent: None },
+    Subdivision { code: "GE-AB", name: "Abkhazia", parent: None },
+    Subdivision { code: "GE-AJ", name: "Ajaria", parent: None },
+    Subdivision { code: "GE-GU", name: "Guria", parent: None },
+    Subdivision { code: "GE-IM", name: "Imereti", parent: None },
+    Subdivision { code: "GE-KA", name: "K'akheti", parent: None },
+    Subdivision { code: "GE-KK", name: "Kvemo Kartli", parent: None },
+    Subdivision { code: "GE-MM", name: "Mtskheta-Mtianeti", parent: None },
+    Subdivision { code: "GE-RL", name: "Rach'a-Lechkhumi-Kvemo Svaneti", parent: None },
+    Subdivision { code: "GE-SJ", name: "Samtskhe-Javakheti", parent: None },
+    Subdivision { code: "GE-SK", name: "Shida Kartli", parent: None },
+    Subdivision { code: "GE-SZ", name: "Samegrelo-Zemo Svaneti", parent: None },
+    Subdivision { code: "GE-TB", name: "Tbilisi", parent: None },
+    Subdivision { code: "GH-AA", name: "Greater Accra", parent: None },
+    Subdivision { code: "GH-AF", name: "Ahafo", parent: None },
+    Subdivision { code: "GH-AH", name: "Ashanti", parent: None },
+    Subdivision { code: "GH-BE", name: "Bono East", parent: None },
+    Subdivision { code: "GH-BO", name: "Bono", parent: None },
+    Subdivision { code: "GH-CP", name: "Central", parent: None },
+    Subdivision { code: "GH-EP", name: "Eastern", parent: None },
+    Subdivision { code: "GH-NE", name: "North East", parent: None },
+    Subdivision { code: "GH-NP", name: "Northern", parent: None },
+    Subdivision { code: "GH-OT", name: "Oti", parent: None },
+    Subdivision { code: "GH-SV", name: "Savannah", parent: None },
+    Subdivision { code: "GH-TV", name: "Volta", parent: None },
+    Subdivision { code: "GH-UE", name: "Upper East", parent: None },
+    Subdivision { code: "GH-UW", name: "Upper West", parent: None },
+    Subdivision { code: "GH-WN", name: "Western North", parent: None },
+    Subdivision { code: "GH-WP", name: "Western", parent: None },
+    Subdivision { code: "GL-AV", name: "Avannaata Kommunia", parent: None },
+    Subdivision { code: "GL-KU", name: "Kommune Kujalleq", parent: None },
+    Subdivision { code: "GL-QE", name: "Qeqqata Kommunia", parent: None },
+    Subdivision { code: "GL-QT", name: "Kommune Qeqertalik", parent: None },
+    Subdivision { code: "GL-SM", name: "Kommuneqarfik Sermersooq", parent: None },
+    Subdivision { code: "GM-B", name: "Banjul", parent: None },
+    Subdivision { code: "GM-L", name: "Lower River", parent: None },
+    Subdivision { code: "GM-M", name: "Central River", parent: None },
+    Subdivision { code: "GM-N", name: "North Bank", parent: None },
+    Subdivision { code: "GM-U", name: "Upper River", parent: None },
+    Subdivision { code: "GM-W", name: "Western", parent: None },
+    Subdivision { code: "GN-B", name: "Boké", parent: None },
+    Subdivision { code: "GN-BE", name: "Beyla", parent: Some("GN-N") },
+    Subdivision { code: "GN-BF", name: "Boffa", parent: Some("GN-B") },
+    Subdivision { code: "GN-BK", name: "Boké", parent: Some("GN-B") },
+    Subdivision { code: "GN-C", name: "Conakry", parent: None },
+    Subdivision { code: "GN-CO", name: "Coyah", parent: Some("GN-D") },
+    Subdivision { code: "GN-D", name: "Kindia", parent: None },
+    Subdivision { code: "GN-DB", name: "Dabola", parent: Some("GN-F") },
+    Subdivision { code: "GN-DI", name: "Dinguiraye", parent: Some("GN-F") },
+    Subdivision { code: "GN-DL", name: "Dalaba", parent: Some("GN-M") },
+    Subdivision { code: "GN-DU", name: "Dubréka", parent: Some("GN-D") },
+    Subdivision { code: "GN-F", name: "Faranah", parent: None },
+    Subdivision { code: "GN-FA", name: "Faranah", parent: Some("GN-F") },
+    Subdivision { code: "GN-FO", name: "Forécariah", parent: Some("GN-D") },
+    Subdivision { code: "GN-FR", name: "Fria", parent: Some("GN-B") },
+    Subdivision { code: "GN-GA", name: "Gaoual", parent: Some("GN-B") },
+    Subdivision { code: "GN-GU", name: "Guékédou", parent: Some("GN-N") },
+    Subdivision { code: "GN-K", name: "Kankan", parent: None },
+    Subdivision { code: "GN-KA", name: "Kankan", parent: Some("GN-K") },
+    Subdivision { code: "GN-KB", name: "Koubia", parent: Some("GN-L") },
+    Subdivision { code: "GN-KD", name: "Kindia", parent: Some("GN-D") },
+    Subdivision { code: "GN-KE", name: "Kérouané", parent: Some("GN-K") },
+    Subdivision { code: "GN-KN", name: "Koundara", parent: Some("GN-B") },
+    Subdivision { code: "GN-KO", name: "Kouroussa", parent: Some("GN-K") },
+    Subdivision { code: "GN-KS", name: "Kissidougou", parent: Some("GN-F") },
+    Subdivision { code: "GN-L", name: "Labé", parent: None },
+    Subdivision { code: "GN-LA", name: "Labé", parent: Some("GN-L") },
+    Subdivision { code: "GN-LE", name: "Lélouma", parent: Some("GN-L") },
+    Subdivision { code: "GN-LO", name: "Lola", parent: Some("GN-N") },
+    Subdivision { code: "GN-M", name: "Mamou", parent: None },
+    Subdivision { code: "GN-MC", name: "Macenta", parent: Some("GN-N") },
+    Subdivision { code: "GN-MD", name: "Mandiana", parent: Some("GN-K") },
+    Subdivision { code: "GN-ML", name: "Mali", parent: Some("GN-L") },
+    Subdivision { code: "GN-MM", name: "Mamou", parent: Some("GN-M") },
+    Subdivision { code: "GN-N", name: "Nzérékoré", parent: None },
+    Subdivision { code: "GN-NZ", name: "Nzérékoré", parent: Some("GN-N") },
+    Subdivision { code: "GN-PI", name: "Pita", parent: Some("GN-M") },
+    Subdivision { code: "GN-SI", name: "Siguiri", parent: Some("GN-K") },
+    Subdivision { code: "GN-TE", name: "Télimélé", parent: Some("GN-D") },
+    Subdivision { code: "GN-TO", name: "Tougué", parent: Some("GN-L") },
+    Subdivision { code: "GN-YO", name: "Yomou", parent: Some("GN-N") },
+    Subdivision { code: "GQ-AN", name: "Annobon", parent: Some("GQ-I") },
+    Subdivision { code: "GQ-BN", name: "Bioko Nord", parent: Some("GQ-I") },
+    Subdivision { code: "GQ-BS", name: "Bioko Sud", parent: Some("GQ-I") },
+    Subdivision { code: "GQ-C", name: "Região Continental", parent: None },
+    Subdivision { code: "GQ-CS", name: "Centro Sud", parent: Some("GQ-C") },
+    Subdivision { code: "GQ-DJ", name: "Djibloho", parent: Some("GQ-C") },
+    Subdivision { code: "GQ-I", name: "Região Insular", parent: None },
+    Subdivision { code: "GQ-KN", name: "Kié-Ntem", parent: Some("GQ-C") },
+    Subdivision { code: "GQ-LI", name: "Litoral", parent: Some("GQ-C") },
+    Subdivision { code: "GQ-WN", name: "Wele-Nzas", parent: Some("GQ-C") },
+    Subdivision { code: "GR-69", name: "Ágion Óros", parent: None },
+    Subdivision { code: "GR-A", name: "Anatolikí Makedonía kai Thráki", parent: None },
+    Subdivision { code: "GR-B", name: "Kentrikí Makedonía", parent: None },
+    Subdivision { code: "GR-C", name: "Dytikí Makedonía", parent: None },
+    Subdivision { code: "GR-D", name: "Ípeiros", parent: None },
+    Subdivision { code: "GR-E", name: "Thessalía", parent: None },
+    Subdivision { code: "GR-F", name: "Ionía Nísia", parent: None },
+    Subdivision { code: "GR-G", name: "Dytikí Elláda", parent: None },
+    Subdivision { code: "GR-H", name: "Stereá Elláda", parent: None },
+    Subdivision { code: "GR-I", name: "Attikí", parent: None },
+    Subdivision { code: "GR-J", name: "Pelopónnisos", parent: None },
+    Subdivision { code: "GR-K", name: "Vóreio Aigaío", parent: None },
+    Subdivision { code: "GR-L", name: "Nótio Aigaío", parent: None },
+    Subdivision { code: "GR-M", name: "Kríti", parent: None },
+    Subdivision { code: "GT-AV", name: "Alta Verapaz", parent: None },
+    Subdivision { code: "GT-BV", name: "Baja Verapaz", parent: None },
+    Subdivision { code: "GT-CM", name: "Chimaltenango", parent: None },
+    Subdivision { code: "GT-CQ", name: "Chiquimula", parent: None },
+    Subdivision { code: "GT-ES", name: "Escuintla", parent: None },
+    Subdivision { code: "GT-GU", name: "Guatemala", parent: None },
+    Subdivision { code: "GT-HU", name: "Huehuetenango", parent: None },
+    Subdivision { code: "GT-IZ", name: "Izabal", parent: None },
+    Subdivision { code: "GT-JA", name: "Jalapa", parent: None },
+    Subdivision { code: "GT-JU", name: "Jutiapa", parent: None },
+    Subdivision { code: "GT-PE", name: "Petén", parent: None },
+    Subdivision { code: "GT-PR", name: "El Progreso", parent: None },
+    Subdivision { code: "GT-QC", name: "Quiché", parent: None },
+    Subdivision { code: "GT-QZ", name: "Quetzaltenango", parent: None },
+    Subdivision { code: "GT-RE", name: "Retalhuleu", parent: None },
+    Subdivision { code: "GT-SA", name: "Sacatepéquez", parent: None },
+    Subdivision { code: "GT-SM", name: "San Marcos", parent: None },
+    Subdivision { code: "GT-SO", name: "Sololá", parent: None },
+    Subdivision { code: "GT-SR", name: "Santa Rosa", parent: None },
+    Subdivision { code: "GT-SU", name: "Suchitepéquez", parent: None },
+    Subdivision { code: "GT-TO", name: "Totonicapán", parent: None },
+    Subdivision { code: "GT-ZA", name: "Zacapa", parent: None },
+    Subdivision { code: "GW-BA", name: "Bafatá", parent: Some("GW-L") },
+    Subdivision { code: "GW-BL", name: "Bolama / Bijagós", parent: Some("GW-S") },
+    Subdivision { code: "GW-BM", name: "Biombo", parent: Some("GW-N") },
+    Subdivision { code: "GW-BS", name: "Bissau", parent: None },
+    Subdivision { code: "GW-CA", name: "Cacheu", parent: Some("GW-N") },
+    Subdivision { code: "GW-GA", name: "Gabú", parent: Some("GW-L") },
+    Subdivision { code: "GW-L", name: "Leste", parent: None },
+    Subdivision { code: "GW-N", name: "Norte", parent: None },
+    Subdivision { code: "GW-OI", name: "Oio", parent: Some("GW-N") },
+    Subdivision { code: "GW-QU", name: "Quinara", parent: Some("GW-S") },
+    Subdivision { code: "GW-S", name: "Sul", parent: None },
+    Subdivision { code: "GW-TO", name: "Tombali", parent: Some("GW-S") },
+    Subdivision { code: "GY-BA", name: "Barima-Waini", parent: None },
+    Subdivision { code: "GY-CU", name: "Cuyuni-Mazaruni", parent: None },
+    Subdivision { code: "GY-DE", name: "Demerara-Mahaica", parent: None },
+    Subdivision { code: "GY-EB", name: "East Berbice-Corentyne", parent: None },
+    Subdivision { code: "GY-ES", name: "Essequibo Islands-West Demerara", parent: None },
+    Subdivision { code: "GY-MA", name: "Mahaica-Berbice", parent: None },
+    Subdivision { code: "GY-PM", name: "Pomeroon-Supenaam", parent: None },
+    Subdivision { code: "GY-PT", name: "Potaro-Siparuni", parent: None },
+    Subdivision { code: "GY-UD", name: "Upper Demerara-Berbice", parent: None },
+    Subdivision { code: "GY-UT", name: "Upper Takutu-Upper Essequibo", parent: None },
+    Subdivision { code: "HN-AT", name: "Atlántida", parent: None },
+    Subdivision { code: "HN-CH", name: "Choluteca", parent: None },
+    Subdivision { code: "HN-CL", name: "Colón", parent: None },
+    Subdivision { code: "HN-CM", name: "Comayagua", parent: None },
+    Subdivision { code: "HN-CP", name: "Copán", parent: None },
+    Subdivision { code: "HN-CR", name: "Cortés", parent: None },
+    Subdivision { code: "HN-EP", name: "El Paraíso", parent: None },
+    Subdivision { code: "HN-FM", name: "Francisco Morazán", parent: None },
+    Subdivision { code: "HN-GD", name: "Gracias a Dios", parent: None },
+    Subdivision { code: "HN-IB", name: "Islas de la Bahía", parent: None },
+    Subdivision { code: "HN-IN", name: "Intibucá", parent: None },
+    Subdivision { code: "HN-LE", name: "Lempira", parent: None },
+    Subdivision { code: "HN-LP", name: "La Paz", parent: None },
+    Subdivision { code: "HN-OC", name: "Ocotepeque", parent: None },
+    Subdivision { code: "HN-OL", name: "Olancho", parent: None },
+    Subdivision { code: "HN-SB", name: "Santa Bárbara", parent: None },
+    Subdivision { code: "HN-VA", name: "Valle", parent: None },
+    Subdivision { code: "HN-YO", name: "Yoro", parent: None },
+    Subdivision { code: "HR-01", name: "Zagrebačka županija", parent: None },
+    Subdivision { code: "HR-02", name: "Krapinsko-zagorska županija", parent: None },
+    Subdivision { code: "HR-03", name: "Sisačko-moslavačka županija", parent: None },
+    Subdivision { code: "HR-04", name: "Karlovačka županija", parent: None },
+    Subdivision { code: "HR-05", name: "Varaždinska županija", parent: None },
+    Subdivision { code: "HR-06", name: "Koprivničko-križevačka županija", parent: None },
+    Subdivision { code: "HR-07", name: "Bjelovarsko-bilogorska županija", parent: None },
+    Subdivision { code: "HR-08", name: "Primorsko-goranska županija", parent: None },
+    Subdivision { code: "HR-09", name: "Ličko-senjska županija", parent: None },
+    Subdivision { code: "HR-10", name: "Virovitičko-podravska županija", parent: None },
+    Subdivision { code: "HR-11", name: "Požeško-slavonska županija", parent: None },
+    Subdivision { code: "HR-12", name: "Brodsko-posavska županija", parent: None },
+    Subdivision { code: "HR-13", name: "Zadarska županija", parent: None },
+    Subdivision { code: "HR-14", name: "Osječko-baranjska županija", parent: None },
+    Subdivision { code: "HR-15", name: "Šibensko-kninska županija", parent: None },
+    Subdivision { code: "HR-16", name: "Vukovarsko-srijemska županija", parent: None },
+    Subdivision { code: "HR-17", name: "Splitsko-dalmatinska županija", parent: None },
+    Subdivision { code: "HR-18", name: "Istarska županija", parent: None },
+    Subdivision { code: "HR-19", name: "Dubrovačko-neretvanska županija", parent: None },
+    Subdivision { code: "HR-20", name: "Međimurska županija", parent: None },
+    Subdivision { code: "HR-21", name: "Grad Zagreb", parent: None },
+    Subdivision { code: "HT-AR", name: "Artibonite", parent: None },
+    Subdivision { code: "HT-CE", name: "Centre", parent: None },
+    Subdivision { code: "HT-GA", name: "Grandans", parent: None },
+    Subdivision { code: "HT-ND", name: "Nord", parent: None },
+    Subdivision { code: "HT-NE", name: "Nord-Est", parent: None },
+    Subdivision { code: "HT-NI", name: "Nip", parent: None },
+    Subdivision { code: "HT-NO", name: "Nord-Ouest", parent: None },
+    Subdivision { code: "HT-OU", name: "Lwès", parent: None },
+    Subdivision { code: "HT-SD", name: "Sid", parent: None },
+    Subdivision { code: "HT-SE", name: "Sidès", parent: None },
+    Subdivision { code: "HU-BA", name: "Baranya", parent: None },
+    Subdivision { code: "HU-BC", name: "Békéscsaba", parent: None },
+    Subdivision { code: "HU-BE", name: "Békés", parent: None },
+    Subdivision { code: "HU-BK", name: "Bács-Kiskun", parent: None },
+    Subdivision { code: "HU-BU", name: "Budapest", parent: None },
+    Subdivision { code: "HU-BZ", name: "Borsod-Abaúj-Zemplén", parent: None },
+    Subdivision { code: "HU-CS", name: "Csongrád", parent: None },
+    Subdivision { code: "HU-DE", name: "Debrecen", parent: None },
+    Subdivision { code: "HU-DU", name: "Dunaújváros", parent: None },
+    Subdivision { code: "HU-EG", name: "Eger", parent: None },
+    Subdivision { code: "HU-ER", name: "Érd", parent: None },
+    Subdivision { code: "HU-FE", name: "Fejér", parent: None },
+    Subdivision { code: "HU-GS", name: "Győr-Moson-Sopron", parent: None },
+    Subdivision { code: "HU-GY", name: "Győr", parent: None },
+    Subdivision { code: "HU-HB", name: "Hajdú-Bihar", parent: None },
+    Subdivision { code: "HU-HE", name: "Heves", parent: None },
+    Subdivision { code: "HU-HV", name: "Hódmezővásárhely", parent: None },
+    Subdivision { code: "HU-JN", name: "Jász-Nagykun-Szolnok", parent: None },
+    Subdivision { code: "HU-KE", name: "Komárom-Esztergom", parent: None },
+    Subdivision { code: "HU-KM", name: "Kecskemét", parent: None },
+    Subdivision { code: "HU-KV", name: "Kaposvár", parent: None },
+    Subdivision { code: "HU-MI", name: "Miskolc", parent: None },
+    Subdivision { code: "HU-NK", name: "Nagykanizsa", parent: None },
+    Subdivision { code: "HU-NO", name: "Nógrád", parent: None },
+    Subdivision { code: "HU-NY", name: "Nyíregyháza", parent: None },
+    Subdivision { code: "HU-PE", name: "Pest", parent: None },
+    Subdivision { code: "HU-PS", name: "Pécs", parent: None },
+    Subdivision { code: "HU-SD", name: "Szeged", parent: None },
+    Subdivision { code: "HU-SF", name: "Székesfehérvár", parent: None },
+    Subdivision { code: "HU-SH", name: "Szombathely", parent: None },
+    Subdivision { code: "HU-SK", name: "Szolnok", parent: None },
+    Subdivision { code: "HU-SN", name: "Sopron", parent: None },
+    Subdivision { code: "HU-SO", name: "Somogy", parent: None },
+    Subdivision { code: "HU-SS", name: "Szekszárd", parent: None },
+    Subdivision { code: "HU-ST", name: "Salgótarján", parent: None },
+    Subdivision { code: "HU-SZ", name: "Szabolcs-Szatmár-Bereg", parent: None },
+    Subdivision { code: "HU-TB", name: "Tatabánya", parent: None },
+    Subdivision { code: "HU-TO", name: "Tolna", parent: None },
+    Subdivision { code: "HU-VA", name: "Vas", parent: None },
+    Subdivision { code: "HU-VE", name: "Veszprém", parent: None },
+    Subdivision { code: "HU-VM", name: "Veszprém", parent: None },
+    Subdivision { code: "HU-ZA", name: "Zala", parent: None },
+    Subdivision { code: "HU-ZE", name: "Zalaegerszeg", parent: None },
+    Subdivision { code: "ID-AC", name: "Aceh", parent: Some("ID-SM") },
+    Subdivision { code: "ID-BA", name: "Bali", parent: Some("ID-NU") },
+    Subdivision { code: "ID-BB", name: "Kepulauan Bangka Belitung", parent: Some("ID-SM") },
+    Subdivision { code: "ID-BE", name: "Bengkulu", parent: Some("ID-SM") },
+    Subdivision { code: "ID-BT", name: "Banten", parent: Some("ID-JW") },
+    Subdivision { code: "ID-GO", name: "Gorontalo", parent: Some("ID-SL") },
+    Subdivision { code: "ID-JA", name: "Jambi", parent: Some("ID-SM") },
+    Subdivision { code: "ID-JB", name: "Jawa Barat", parent: Some("ID-JW") },
+    Subdivision { code: "ID-JI", name: "Jawa Timur", parent: Some("ID-JW") },
+    Subdivision { code: "ID-JK", name: "Jakarta Raya", parent: Some("ID-JW") },
+    Subdivision { code: "ID-JT", name: "Jawa Tengah", parent: Some("ID-JW") },
+    Subdivision { code: "ID-JW", name: "Jawa", parent: None },
+    Subdivision { code: "ID-KA", name: "Kalimantan", parent: None },
+    Subdivision { code: "ID-KB", name: "Kalimantan Barat", parent: Some("ID-KA") },
+    Subdivision { code: "ID-KI", name: "Kalimantan Timur", parent: Some("ID-KA") },
+    Subdivision { code: "ID-KR", name: "Kepulauan Riau", parent: Some("ID-SM") },
+    Subdivision { code: "ID-KS", name: "Kalimantan Selatan", parent: Some("ID-KA") },
+    Subdivision { code: "ID-KT", name: "Kalimantan Tengah", parent: Some("ID-KA") },
+    Subdivision { code: "ID-KU", name: "Kalimantan Utara", parent: Some("ID-KA") },
+    Subdivision { code: "ID-LA", name: "Lampung", parent: Some("ID-SM") },
+    Subdivision { code: "ID-MA", name: "Maluku", parent: Some("ID-ML") },
+    Subdivision { code: "ID-ML", name: "Maluku", parent: None },
+    Subdivision { code: "ID-MU", name: "Maluku Utara", parent: Some("ID-ML") },
+    Subdivision { code: "ID-NB", name: "Nusa Tenggara Barat", parent: Some("ID-NU") },
+    Subdivision { code: "ID-NT", name: "Nusa Tenggara Timur", parent: Some("ID-NU") },
+    Subdivision { code: "ID-NU", name: "Nusa Tenggara", parent: None },
+    Subdivision { code: "ID-PA", name: "Papua", parent: Some("ID-PP") },
+    Subdivision { code: "ID-PB", name: "Papua Barat", parent: Some("ID-PP") },
+    Subdivision { code: "ID-PP", name: "Papua", parent: None },
+    Subdivision { code: "ID-RI", name: "Riau", parent: Some("ID-SM") },
+    Subdivision { code: "ID-SA", name: "Sulawesi Utara", parent: Some("ID-SL") },
+    Subdivision { code: "ID-SB", name: "Sumatera Barat", parent: Some("ID-SM") },
+    Subdivision { code: "ID-SG", name: "Sulawesi Tenggara", parent: Some("ID-SL") },
+    Subdivision { code: "ID-SL", name: "Sulawesi", parent: None },
+    Subdivision { code: "ID-SM", name: "Sumatera", parent: None },
+    Subdivision { code: "ID-SN", name: "Sulawesi Selatan", parent: Some("ID-SL") },
+    Subdivision { code: "ID-SR", name: "Sulawesi Barat", parent: Some("ID-SL") },
+    Subdivision { code: "ID-SS", name: "Sumatera Selatan", parent: Some("ID-SM") },
+    Subdivision { code: "ID-ST", name: "Sulawesi Tengah", parent: Some("ID-SL") },
+    Subdivision { code: "ID-SU", name: "Sumatera Utara", parent: Some("ID-SM") },
+    Subdivision { code: "ID-YO", name: "Yogyakarta", parent: Some("ID-JW") },
+    Subdivision { code: "IE-C", name: "Connaught", parent: None },
+    Subdivision { code: "IE-CE", name: "Clare", parent: Some("IE-M") },
+    Subdivision { code: "IE-CN", name: "Cavan", parent: Some("IE-U") },
+    Subdivision { code: "IE-CO", name: "Cork", parent: Some("IE-M") },
+    Subdivision { code: "IE-CW", name: "Carlow", parent: Some("IE-L") },
+    Subdivision { code: "IE-D", name: "Dublin", parent: Some("IE-L") },
+    Subdivision { code: "IE-DL", name: "Donegal", parent: Some("IE-U") },
+    Subdivision { code: "IE-G", name: "Galway", parent: Some("IE-C") },
+    Subdivision { code: "IE-KE", name: "Kildare", parent: Some("IE-L") },
+    Subdivision { code: "IE-KK", name: "Kilkenny", parent: Some("IE-L") },
+    Subdivision { code: "IE-KY", name: "Kerry", parent: Some("IE-M") },
+    Subdivision { code: "IE-L", name: "Leinster", parent: None },
+    Subdivision { code: "IE-LD", name: "Longford", parent: Some("IE-L") },
+    Subdivision { code: "IE-LH", name: "Louth", parent: Some("IE-L") },
+    Subdivision { code: "IE-LK", name: "Limerick", parent: Some("IE-M") },
+    Subdivision { code: "IE-LM", name: "Leitrim", parent: Some("IE-C") },
+    Subdivision { code: "IE-LS", name: "Laois", parent: Some("IE-L") },
+    Subdivision { code: "IE-M", name: "Munster", parent: None },
+    Subdivision { code: "IE-MH", name: "Meath", parent: Some("IE-L") },
+    Subdivision { code: "IE-MN", name: "Monaghan", parent: Some("IE-U") },
+    Subdivision { code: "IE-MO", name: "Mayo", parent: Some("IE-C") },
+    Subdivision { code: "IE-OY", name: "Offaly", parent: Some("IE-L") },
+    Subdivision { code: "IE-RN", name: "Roscommon", parent: Some("IE-C") },
+    Subdivision { code: "IE-SO", name: "Sligo", parent: Some("IE-C") },
+    Subdivision { code: "IE-TA", name: "Tipperary", parent: Some("IE-M") },
+    Subdivision { code: "IE-U", name: "Ulster", parent: None },
+    Subdivision { code: "IE-WD", name: "Waterford", parent: Some("IE-M") },
+    Subdivision { code: "IE-WH", name: "Westmeath", parent: Some("IE-L") },
+    Subdivision { code: "IE-WW", name: "Wicklow", parent: Some("IE-L") },
+    Subdivision { code: "IE-WX", name: "Wexford", parent: Some("IE-L") },
+    Subdivision { code: "IL-D", name: "Al Janūbī", parent: None },
+    Subdivision { code: "IL-HA", name: "H̱efa", parent: None },
+    Subdivision { code: "IL-JM", name: "Al Quds", parent: None },
+    Subdivision { code: "IL-M", name: "Al Awsaţ", parent: None },
+    Subdivision { code: "IL-TA", name: "Tall Abīb", parent: None },
+    Subdivision { code: "IL-Z", name: "Ash Shamālī", parent: None },
+    Subdivision { code: "IN-AN", name: "Andaman and Nicobar Islands", parent: None },
+    Subdivision { code: "IN-AP", name: "Andhra Pradesh", parent: None },
+    Subdivision { code: "IN-AR", name: "Arunāchal Pradesh", parent: None },
+    Subdivision { code: "IN-AS", name: "Assam", parent: None },
+    Subdivision { code: "IN-BR", name: "Bihār", parent: None },
+    Subdivision { code: "IN-CH", name: "Chandīgarh", parent: None },
+    Subdivision { code: "IN-CT", name: "Chhattīsgarh", parent: None },
+    Subdivision { code: "IN-DH", name: "Dādra and Nagar Haveli and Damān and Diu", parent: None },
+    Subdivision { code: "IN-DL", name: "Delhi", parent: None },
+    Subdivision { code: "IN-GA", name: "Goa", parent: None },
+    Subdivision { code: "IN-GJ", name: "Gujarāt", parent: None },
+    Subdivision { code: "IN-HP", name: "Himāchal Pradesh", parent: None },
+    Subdivision { code: "IN-HR", name: "Haryāna", parent: None },
+    Subdivision { code: "IN-JH", name: "Jhārkhand", parent: None },
+    Subdivision { code: "IN-JK", name: "Jammu and Kashmīr", parent: None },
+    Subdivision { code: "IN-KA", name: "Karnātaka", parent: None },
+    Subdivision { code: "IN-KL", name: "Kerala", parent: None },
+    Subdivision { code: "IN-LA", name: "Ladākh", parent: None },
+    Subdivision { code: "IN-LD", name: "Lakshadweep", parent: None },
+    Subdivision { code: "IN-MH", name: "Mahārāshtra", parent: None },
+    Subdivision { code: "IN-ML", name: "Meghālaya", parent: None },
+    Subdivision { code: "IN-MN", name: "Manipur", parent: None },
+    Subdivision { code: "IN-MP", name: "Madhya Pradesh", parent: None },
+    Subdivision { code: "IN-MZ", name: "Mizoram", parent: None },
+    Subdivision { code: "IN-NL", name: "Nāgāland", parent: None },
+    Subdivision { code: "IN-OR", name: "Odisha", parent: None },
+    Subdivision { code: "IN-PB", name: "Punjab", parent: None },
+    Subdivision { code: "IN-PY", name: "Puducherry", parent: None },
+    Subdivision { code: "IN-RJ", name: "Rājasthān", parent: None },
+    Subdivision { code: "IN-SK", name: "Sikkim", parent: None },
+    Subdivision { code: "IN-TG", name: "Telangāna", parent: None },
+    Subdivision { code: "IN-TN", name: "Tamil Nādu", parent: None },
+    Subdivision { code: "IN-TR", name: "Tripura", parent: None },
+    Subdivision { code: "IN-UP", name: "Uttar Pradesh", parent: None },
+    Subdivision { code: "IN-UT", name: "Uttarākhand", parent: None },
+    Subdivision { code: "IN-WB", name: "West Bengal", parent: None },
+    Subdivision { code: "IQ-AN", name: "Al Anbār", parent: None },
+    Subdivision { code: "IQ-AR", name: "Arbīl", parent: None },
+    Subdivision { code: "IQ-BA", name: "Al Başrah", parent: None },
+    Subdivision { code: "IQ-BB", name: "Bābil", parent: None },
+    Subdivision { code: "IQ-BG", name: "Baghdād", parent: None },
+    Subdivision { code: "IQ-DA", name: "Dahūk", parent: None },
+    Subdivision { code: "IQ-DI", name: "Diyālá", parent: None },
+    Subdivision { code: "IQ-DQ", name: "Dhī Qār", parent: None },
+    Subdivision { code: "IQ-KA", name: "Karbalā’", parent: None },
+    Subdivision { code: "IQ-KI", name: "Kirkūk", parent: None },
+    Subdivision { code: "IQ-MA", name: "Maysān", parent: None },
+    Subdivision { code: "IQ-MU", name: "Al Muthanná", parent: None },
+    Subdivision { code: "IQ-NA", name: "An Najaf", parent: None },
+    Subdivision { code: "IQ-NI", name: "Nīnawá", parent: None },
+    Subdivision { code: "IQ-QA", name: "Al Qādisīyah", parent: None },
+    Subdivision { code: "IQ-SD", name: "Şalāḩ ad Dīn", parent: None },
+    Subdivision { code: "IQ-SU", name: "As Sulaymānīyah", parent: None },
+    Subdivision { code: "IQ-WA", name: "Wāsiţ", parent: None },
+    Subdivision { code: "IR-00", name: "Markazī", parent: None },
+    Subdivision { code: "IR-01", name: "Gīlān", parent: None },
+    Subdivision { code: "IR-02", name: "Māzandarān", parent: None },
+    Subdivision { code: "IR-03", name: "Āz̄ārbāyjān-e Shārqī", parent: None },
+    Subdivision { code: "IR-04", name: "Āz̄ārbāyjān-e Ghārbī", parent: None },
+    Subdivision { code: "IR-05", name: "Kermānshāh", parent: None },
+    Subdivision { code: "IR-06", name: "Khūzestān", parent: None },
+    Subdivision { code: "IR-07", name: "Fārs", parent: None },
+    Subdivision { code: "IR-08", name: "Kermān", parent: None },
+    Subdivision { code: "IR-09", name: "Khorāsān-e Raẕavī", parent: None },
+    Subdivision { code: "IR-10", name: "Eşfahān", parent: None },
+    Subdivision { code: "IR-11", name: "Sīstān va Balūchestān", parent: None },
+    Subdivision { code: "IR-12", name: "Kordestān", parent: None },
+    Subdivision { code: "IR-13", name: "Hamadān", parent: None },
+    Subdivision { code: "IR-14", name: "Chahār Maḩāl va Bakhtīārī", parent: None },
+    Subdivision { code: "IR-15", name: "Lorestān", parent: None },
+    Subdivision { code: "IR-16", name: "Īlām", parent: None },
+    Subdivision { code: "IR-17", name: "Kohgīlūyeh va Bowyer Aḩmad", parent: None },
+    Subdivision { code: "IR-18", name: "Būshehr", parent: None },
+    Subdivision { code: "IR-19", name: "Zanjān", parent: None },
+    Subdivision { code: "IR-20", name: "Semnān", parent: None },
+    Subdivision { code: "IR-21", name: "Yazd", parent: None },
+    Subdivision { code: "IR-22", name: "Hormozgān", parent: None },
+    Subdivision { code: "IR-23", name: "Tehrān", parent: None },
+    Subdivision { code: "IR-24", name: "Ardabīl", parent: None },
+    Subdivision { code: "IR-25", name: "Qom", parent: None },
+    Subdivision { code: "IR-26", name: "Qazvīn", parent: None },
+    Subdivision { code: "IR-27", name: "Golestān", parent: None },
+    Subdivision { code: "IR-28", name: "Khorāsān-e Shomālī", parent: None },
+    Subdivision { code: "IR-29", name: "Khorāsān-e Jonūbī", parent: None },
+    Subdivision { code: "IR-30", name: "Alborz", parent: None },
+    Subdivision { code: "IS-1", name: "Höfuðborgarsvæði", parent: None },
+    Subdivision { code: "IS-2", name: "Suðurnes", parent: None },
+    Subdivision { code: "IS-3", name: "Vesturland", parent: None },
+    Subdivision { code: "IS-4", name: "Vestfirðir", parent: None },
+    Subdivision { code: "IS-5", name: "Norðurland vestra", parent: None },
+    Subdivision { code: "IS-6", name: "Norðurland eystra", parent: None },
+    Subdivision { code: "IS-7", name: "Austurland", parent: None },
+    Subdivision { code: "IS-8", name: "Suðurland", parent: None },
+    Subdivision { code: "IS-AKH", name: "Akrahreppur", parent: Some("IS-5") },
+    Subdivision { code: "IS-AKN", name: "Akraneskaupstaður", parent: Some("IS-3") },
+    Subdivision { code: "IS-AKU", name: "Akureyrarbær", parent: Some("IS-6") },
+    Subdivision { code: "IS-ARN", name: "Árneshreppur", parent: Some("IS-4") },
+    Subdivision { code: "IS-ASA", name: "Ásahreppur", parent: Some("IS-8") },
+    Subdivision { code: "IS-BFJ", name: "Borgarfjarðarhreppur", parent: Some("IS-7") },
+    Subdivision { code: "IS-BLA", name: "Bláskógabyggð", parent: Some("IS-8") },
+    Subdivision { code: "IS-BLO", name: "Blönduósbær", parent: Some("IS-5") },
+    Subdivision { code: "IS-BOG", name: "Borgarbyggð", parent: Some("IS-3") },
+    Subdivision { code: "IS-BOL", name: "Bolungarvíkurkaupstaður", parent: Some("IS-4") },
+    Subdivision { code: "IS-DAB", name: "Dalabyggð", parent: Some("IS-3") },
+    Subdivision { code: "IS-DAV", name: "Dalvíkurbyggð", parent: Some("IS-6") },
+    Subdivision { code: "IS-DJU", name: "Djúpavogshreppur", parent: Some("IS-7") },
+    Subdivision { code: "IS-EOM", name: "Eyja- og Miklaholtshreppur", parent: Some("IS-3") },
+    Subdivision { code: "IS-EYF", name: "Eyjafjarðarsveit", parent: Some("IS-6") },
+    Subdivision { code: "IS-FJD", name: "Fjarðabyggð", parent: Some("IS-7") },
+    Subdivision { code: "IS-FJL", name: "Fjallabyggð", parent: Some("IS-6") },
+    Subdivision { code: "IS-FLA", name: "Flóahreppur", parent: Some("IS-8") },
+    Subdivision { code: "IS-FLD", name: "Fljótsdalshérað", parent: Some("IS-7") },
+    Subdivision { code: "IS-FLR", name: "Fljótsdalshreppur", parent: Some("IS-7") },
+    Subdivision { code: "IS-GAR", name: "Garðabær", parent: Some("IS-1") },
+    Subdivision { code: "IS-GOG", name: "Grímsnes- og Grafningshreppur", parent: Some("IS-8") },
+    Subdivision { code: "IS-GRN", name: "Grindavíkurbær", parent: Some("IS-2") },
+    Subdivision { code: "IS-GRU", name: "Grundarfjarðarbær", parent: Some("IS-3") },
+    Subdivision { code: "IS-GRY", name: "Grýtubakkahreppur", parent: Some("IS-6") },
+    Subdivision { code: "IS-HAF", name: "Hafnarfjarðarkaupstaður", parent: Some("IS-1") },
+    Subdivision { code: "IS-HEL", name: "Helgafellssveit", parent: Some("IS-3") },
+    Subdivision { code: "IS-HRG", name: "Hörgársveit", parent: Some("IS-6") },
+    Subdivision { code: "IS-HRU", name: "Hrunamannahreppur", parent: Some("IS-8") },
+    Subdivision { code: "IS-HUT", name: "Húnavatnshreppur", parent: Some("IS-5") },
+    Subdivision { code: "IS-HUV", name: "Húnaþing vestra", parent: Some("IS-5") },
+    Subdivision { code: "IS-HVA", name: "Hvalfjarðarsveit", parent: Some("IS-3") },
+    Subdivision { code: "IS-HVE", name: "Hveragerðisbær", parent: Some("IS-8") },
+    Subdivision { code: "IS-ISA", name: "Ísafjarðarbær", parent: Some("IS-4") },
+    Subdivision { code: "IS-KAL", name: "Kaldrananeshreppur", parent: Some("IS-4") },
+    Subdivision { code: "IS-KJO", name: "Kjósarhreppur", parent: Some("IS-1") },
+    Subdivision { code: "IS-KOP", name: "Kópavogsbær", parent: Some("IS-1") },
+    Subdivision { code: "IS-LAN", name: "Langanesbyggð", parent: Some("IS-6") },
+    Subdivision { code: "IS-MOS", name: "Mosfellsbær", parent: Some("IS-1") },
+    Subdivision { code: "IS-MYR", name: "Mýrdalshreppur", parent: Some("IS-8") },
+    Subdivision { code: "IS-NOR", name: "Norðurþing", parent: Some("IS-6") },
+    Subdivision { code: "IS-RGE", name: "Rangárþing eystra", parent: Some("IS-8") },
+    Subdivision { code: "IS-RGY", name: "Rangárþing ytra", parent: Some("IS-8") },
+    Subdivision { code: "IS-RHH", name: "Reykhólahreppur", parent: Some("IS-4") },
+    Subdivision { code: "IS-RKN", name: "Reykjanesbær", parent: Some("IS-2") },
+    Subdivision { code: "IS-RKV", name: "Reykjavíkurborg", parent: Some("IS-1") },
+    Subdivision { code: "IS-SBH", name: "Svalbarðshreppur", parent: Some("IS-6") },
+    Subdivision { code: "IS-SBT", name: "Svalbarðsstrandarhreppur", parent: Some("IS-6") },
+    Subdivision { code: "IS-SDN", name: "Suðurnesjabær", parent: Some("IS-2") },
+    Subdivision { code: "IS-SDV", name: "Súðavíkurhreppur", parent: Some("IS-4") },
+    Subdivision { code: "IS-SEL", name: "Seltjarnarnesbær", parent: Some("IS-1") },
+    Subdivision { code: "IS-SEY", name: "Seyðisfjarðarkaupstaður", parent: Some("IS-7") },
+    Subdivision { code: "IS-SFA", name: "Sveitarfélagið Árborg", parent: Some("IS-8") },
+    Subdivision { code: "IS-SHF", name: "Sveitarfélagið Hornafjörður", parent: Some("IS-7") },
+    Subdivision { code: "IS-SKF", name: "Skaftárhreppur", parent: Some("IS-8") },
+    Subdivision { code: "IS-SKG", name: "Skagabyggð", parent: Some("IS-5") },
+    Subdivision { code: "IS-SKO", name: "Skorradalshreppur", parent: Some("IS-3") },
+    Subdivision { code: "IS-SKU", name: "Skútustaðahreppur", parent: Some("IS-6") },
+    Subdivision { code: "IS-SNF", name: "Snæfellsbær", parent: Some("IS-3") },
+    Subdivision { code: "IS-SOG", name: "Skeiða- og Gnúpverjahreppur", parent: Some("IS-8") },
+    Subdivision { code: "IS-SOL", name: "Sveitarfélagið Ölfus", parent: Some("IS-8") },
+    Subdivision { code: "IS-SSF", name: "Sveitarfélagið Skagafjörður", parent: Some("IS-5") },
+    Subdivision { code: "IS-SSS", name: "Sveitarfélagið Skagaströnd", parent: Some("IS-5") },
+    Subdivision { code: "IS-STR", name: "Strandabyggð", parent: Some("IS-4") },
+    Subdivision { code: "IS-STY", name: "Stykkishólmsbær", parent: Some("IS-3") },
+    Subdivision { code: "IS-SVG", name: "Sveitarfélagið Vogar", parent: Some("IS-2") },
+    Subdivision { code: "IS-TAL", name: "Tálknafjarðarhreppur", parent: Some("IS-4") },
+    Subdivision { code: "IS-THG", name: "Þingeyjarsveit", parent: Some("IS-6") },
+    Subdivision { code: "IS-TJO", name: "Tjörneshreppur", parent: Some("IS-6") },
+    Subdivision { code: "IS-VEM", name: "Vestmannaeyjabær", parent: Some("IS-8") },
+    Subdivision { code: "IS-VER", name: "Vesturbyggð", parent: Some("IS-4") },
+    Subdivision { code: "IS-VOP", name: "Vopnafjarðarhreppur", parent: Some("IS-7") },
+    Subdivision { code: "IT-21", name: "Piemonte", parent: None },
+    Subdivision { code: "IT-23", name: "Val d'Aoste", parent: None },
+    Subdivision { code: "IT-25", name: "Lombardia", parent: None },
+    Subdivision { code: "IT-32", name: "Trentino-Alto Adige", parent: None },
+    Subdivision { code: "IT-34", name: "Veneto", parent: None },
+    Subdivision { code: "IT-36", name: "Friuli Venezia Giulia", parent: None },
+    Subdivision { code: "IT-42", name: "Liguria", parent: None },
+    Subdivision { code: "IT-45", name: "Emilia-Romagna", parent: None },
+    Subdivision { code: "IT-52", name: "Toscana", parent: None },
+    Subdivision { code: "IT-55", name: "Umbria", parent: None },
+    Subdivision { code: "IT-57", name: "Marche", parent: None },
+    Subdivision { code: "IT-62", name: "Lazio", parent: None },
+    Subdivision { code: "IT-65", name: "Abruzzo", parent: None },
+    Subdivision { code: "IT-67", name: "Molise", parent: None },
+    Subdivision { code: "IT-72", name: "Campania", parent: None },
+    Subdivision { code: "IT-75", name: "Puglia", parent: None },
+    Subdivision { code: "IT-77", name: "Basilicata", parent: None },
+    Subdivision { code: "IT-78", name: "Calabria", parent: None },
+    Subdivision { code: "IT-82", name: "Sicilia", parent: None },
+    Subdivision { code: "IT-88", name: "Sardegna", parent: None },
+    Subdivision { code: "IT-AG", name: "Agrigento", parent: Some("IT-82") },
+    Subdivision { code: "IT-AL", name: "Alessandria", parent: Some("IT-21") },
+    Subdivision { code: "IT-AN", name: "Ancona", parent: Some("IT-57") },
+    Subdivision { code: "IT-AP", name: "Ascoli Piceno", parent: Some("IT-57") },
+    Subdivision { code: "IT-AQ", name: "L'Aquila", parent: Some("IT-65") },
+    Subdivision { code: "IT-AR", name: "Arezzo", parent: Some("IT-52") },
+    Subdivision { code: "IT-AT", name: "Asti", parent: Some("IT-21") },
+    Subdivision { code: "IT-AV", name: "Avellino", parent: Some("IT-72") },
+    Subdivision { code: "IT-BA", name: "Bari", parent: Some("IT-75") },
+    Subdivision { code: "IT-BG", name: "Bergamo", parent: Some("IT-25") },
+    Subdivision { code: "IT-BI", name: "Biella", parent: Some("IT-21") },
+    Subdivision { code: "IT-BL", name: "Belluno", parent: Some("IT-34") },
+    Subdivision { code: "IT-BN", name: "Benevento", parent: Some("IT-72") },
+    Subdivision { code: "IT-BO", name: "Bologna", parent: Some("IT-45") },
+    Subdivision { code: "IT-BR", name: "Brindisi", parent: Some("IT-75") },
+    Subdivision { code: "IT-BS", name: "Brescia", parent: Some("IT-25") },
+    Subdivision { code: "IT-BT", name: "Barletta-Andria-Trani", parent: Some("IT-75") },
+    Subdivision { code: "IT-BZ", name: "Bolzano", parent: Some("IT-32") },
+    Subdivision { code: "IT-CA", name: "Cagliari", parent: Some("IT-88") },
+    Subdivision { code: "IT-CB", name: "Campobasso", parent: Some("IT-67") },
+    Subdivision { code: "IT-CE", name: "Caserta", parent: Some("IT-72") },
+    Subdivision { code: "IT-CH", name: "Chieti", parent: Some("IT-65") },
+    Subdivision { code: "IT-CL", name: "Caltanissetta", parent: Some("IT-82") },
+    Subdivision { code: "IT-CN", name: "Cuneo", parent: Some("IT-21") },
+    Subdivision { code: "IT-CO", name: "Como", parent: Some("IT-25") },
+    Subdivision { code: "IT-CR", name: "Cremona", parent: Some("IT-25") },
+    Subdivision { code: "IT-CS", name: "Cosenza", parent: Some("IT-78") },
+    Subdivision { code: "IT-CT", name: "Catania", parent: Some("IT-82") },
+    Subdivision { code: "IT-CZ", name: "Catanzaro", parent: Some("IT-78") },
+    Subdivision { code: "IT-EN", name: "Enna", parent: Some("IT-82") },
+    Subdivision { code: "IT-FC", name: "Forlì-Cesena", parent: Some("IT-45") },
+    Subdivision { code: "IT-FE", name: "Ferrara", parent: Some("IT-45") },
+    Subdivision { code: "IT-FG", name: "Foggia", parent: Some("IT-75") },
+    Subdivision { code: "IT-FI", name: "Firenze", parent: Some("IT-52") },
+    Subdivision { code: "IT-FM", name: "Fermo", parent: Some("IT-57") },
+    Subdivision { code: "IT-FR", name: "Frosinone", parent: Some("IT-62") },
+    Subdivision { code: "IT-GE", name: "Genova", parent: Some("IT-42") },
+    Subdivision { code: "IT-GO", name: "Gorizia", parent: Some("IT-36") },
+    Subdivision { code: "IT-GR", name: "Grosseto", parent: Some("IT-52") },
+    Subdivision { code: "IT-IM", name: "Imperia", parent: Some("IT-42") },
+    Subdivision { code: "IT-IS", name: "Isernia", parent: Some("IT-67") },
+    Subdivision { code: "IT-KR", name: "Crotone", parent: Some("IT-78") },
+    Subdivision { code: "IT-LC", name: "Lecco", parent: Some("IT-25") },
+    Subdivision { code: "IT-LE", name: "Lecce", parent: Some("IT-75") },
+    Subdivision { code: "IT-LI", name: "Livorno", parent: Some("IT-52") },
+    Subdivision { code: "IT-LO", name: "Lodi", parent: Some("IT-25") },
+    Subdivision { code: "IT-LT", name: "Latina", parent: Some("IT-62") },
+    Subdivision { code: "IT-LU", name: "Lucca", parent: Some("IT-52") },
+    Subdivision { code: "IT-MB", name: "Monza e Brianza", parent: Some("IT-25") },
+    Subdivision { code: "IT-MC", name: "Macerata", parent: Some("IT-57") },
+    Subdivision { code: "IT-ME", name: "Messina", parent: Some("IT-82") },
+    Subdivision { code: "IT-MI", name: "Milano", parent: Some("IT-25") },
+    Subdivision { code: "IT-MN", name: "Mantova", parent: Some("IT-25") },
+    Subdivision { code: "IT-MO", name: "Modena", parent: Some("IT-45") },
+    Subdivision { code: "IT-MS", name: "Massa-Carrara", parent: Some("IT-52") },
+    Subdivision { code: "IT-MT", name: "Matera", parent: Some("IT-77") },
+    Subdivision { code: "IT-NA", name: "Napoli", parent: Some("IT-72") },
+    Subdivision { code: "IT-NO", name: "Novara", parent: Some("IT-21") },
+    Subdivision { code: "IT-NU", name: "Nuoro", parent: Some("IT-88") },
+    Subdivision { code: "IT-OR", name: "Oristano", parent: Some("IT-88") },
+    Subdivision { code: "IT-PA", name: "Palermo", parent: Some("IT-82") },
+    Subdivision { code: "IT-PC", name: "Piacenza", parent: Some("IT-45") },
+    Subdivision { code: "IT-PD", name: "Padova", parent: Some("IT-34") },
+    Subdivision { code: "IT-PE", name: "Pescara", parent: Some("IT-65") },
+    Subdivision { code: "IT-PG", name: "Perugia", parent: Some("IT-55") },
+    Subdivision { code: "IT-PI", name: "Pisa", parent: Some("IT-52") },
+    Subdivision { code: "IT-PN", name: "Pordenone", parent: Some("IT-36") },
+    Subdivision { code: "IT-PO", name: "Prato", parent: Some("IT-52") },
+    Subdivision { code: "IT-PR", name: "Parma", parent: Some("IT-45") },
+    Subdivision { code: "IT-PT", name: "Pistoia", parent: Some("IT-52") },
+    Subdivision { code: "IT-PU", name: "Pesaro e Urbino", parent: Some("IT-57") },
+    Subdivision { code: "IT-PV", name: "Pavia", parent: Some("IT-25") },
+    Subdivision { code: "IT-PZ", name: "Potenza", parent: Some("IT-77") },
+    Subdivision { code: "IT-RA", name: "Ravenna", parent: Some("IT-45") },
+    Subdivision { code: "IT-RC", name: "Reggio Calabria", parent: Some("IT-78") },
+    Subdivision { code: "IT-RE", name: "Reggio Emilia", parent: Some("IT-45") },
+    Subdivision { code: "IT-RG", name: "Ragusa", parent: Some("IT-82") },
+    Subdivision { code: "IT-RI", name: "Rieti", parent: Some("IT-62") },
+    Subdivision { code: "IT-RM", name: "Roma", parent: Some("IT-62") },
+    Subdivision { code: "IT-RN", name: "Rimini", parent: Some("IT-45") },
+    Subdivision { code: "IT-RO", name: "Rovigo", parent: Some("IT-34") },
+    Subdivision { code: "IT-SA", name: "Salerno", parent: Some("IT-72") },
+    Subdivision { code: "IT-SI", name: "Siena", parent: Some("IT-52") },
+    Subdivision { code: "IT-SO", name: "Sondrio", parent: Some("IT-25") },
+    Subdivision { code: "IT-SP", name: "La Spezia", parent: Some("IT-42") },
+    Subdivision { code: "IT-SR", name: "Siracusa", parent: Some("IT-82") },
+    Subdivision { code: "IT-SS", name: "Sassari", parent: Some("IT-88") },
+    Subdivision { code: "IT-SU", name: "Sud Sardegna", parent: Some("IT-88") },
+    Subdivision { code: "IT-SV", name: "Savona", parent: Some("IT-42") },
+    Subdivision { code: "IT-TA", name: "Taranto", parent: Some("IT-75") },
+    Subdivision { code: "IT-TE", name: "Teramo", parent: Some("IT-65") },
+    Subdivision { code: "IT-TN", name: "Trento", parent: Some("IT-32") },
+    Subdivision { code: "IT-TO", name: "Torino", parent: Some("IT-21") },
+    Subdivision { code: "IT-TP", name: "Trapani", parent: Some("IT-82") },
+    Subdivision { code: "IT-TR", name: "Terni", parent: Some("IT-55") },
+    Subdivision { code: "IT-TS", name: "Trieste", parent: Some("IT-36") },
+    Subdivision { code: "IT-TV", name: "Treviso", parent: Some("IT-34") },
+    Subdivision { code: "IT-UD", name: "Udine", parent: Some("IT-36") },
+    Subdivision { code: "IT-VA", name: "Varese", parent: Some("IT-25") },
+    Subdivision { code: "IT-VB", name: "Verbano-Cusio-Ossola", parent: Some("IT-21") },
+    Subdivision { code: "IT-VC", name: "Vercelli", parent: Some("IT-21") },
+    Subdivision { code: "IT-VE", name: "Venezia", parent: Some("IT-34") },
+    Subdivision { code: "IT-VI", name: "Vicenza", parent: Some("IT-34") },
+    Subdivision { code: "IT-VR", name: "Verona", parent: Some("IT-34") },
+    Subdivision { code: "IT-VT", name: "Viterbo", parent: Some("IT-62") },
+    Subdivision { code: "IT-VV", name: "Vibo Valentia", parent: Some("IT-78") },
+    Subdivision { code: "JM-01", name: "Kingston", parent: None },
+    Subdivision { code: "JM-02", name: "Saint Andrew", parent: None },
+    Subdivision { code: "JM-03", name: "Saint Thomas", parent: None },
+    Subdivision { code: "JM-04", name: "Portland", parent: None },
+    Subdivision { code: "JM-05", name: "Saint Mary", parent: None },
+    Subdivision { code: "JM-06", name: "Saint Ann", parent: None },
+    Subdivision { code: "JM-07", name: "Trelawny", parent: None },
+    Subdivision { code: "JM-08", name: "Saint James", parent: None },
+    Subdivision { code: "JM-09", name: "Hanover", parent: None },
+    Subdivision { code: "JM-10", name: "Westmoreland", parent: None },
+    Subdivision { code: "JM-11", name: "Saint Elizabeth", parent: None },
+    Subdivision { code: "JM-12", name: "Manchester", parent: None },
+    Subdivision { code: "JM-13", name: "Clarendon", parent: None },
+    Subdivision { code: "JM-14", name: "Saint Catherine", parent: None },
+    Subdivision { code: "JO-AJ", name: "‘Ajlūn", parent: None },
+    Subdivision { code: "JO-AM", name: "Al ‘A̅şimah", parent: None },
+    Subdivision { code: "JO-AQ", name: "Al ‘Aqabah", parent: None },
+    Subdivision { code: "JO-AT", name: "Aţ Ţafīlah", parent: None },
+    Subdivision { code: "JO-AZ", name: "Az Zarqā’", parent: None },
+    Subdivision { code: "JO-BA", name: "Al Balqā’", parent: None },
+    Subdivision { code: "JO-IR", name: "Irbid", parent: None },
+    Subdivision { code: "JO-JA", name: "Jarash", parent: None },
+    Subdivision { code: "JO-KA", name: "Al Karak", parent: None },
+    Subdivision { code: "JO-MA", name: "Al Mafraq", parent: None },
+    Subdivision { code: "JO-MD", name: "Mādabā", parent: None },
+    Subdivision { code: "JO-MN", name: "Ma‘ān", parent: None },
+    Subdivision { code: "JP-01", name: "Hokkaido", parent: None },
+    Subdivision { code: "JP-02", name: "Aomori", parent: None },
+    Subdivision { code: "JP-03", name: "Iwate", parent: None },
+    Subdivision { code: "JP-04", name: "Miyagi", parent: None },
+    Subdivision { code: "JP-05", name: "Akita", parent: None },
+    Subdivision { code: "JP-06", name: "Yamagata", parent: None },
+    Subdivision { code: "JP-07", name: "Fukushima", parent: None },
+    Subdivision { code: "JP-08", name: "Ibaraki", parent: None },
+    Subdivision { code: "JP-09", name: "Tochigi", parent: None },
+    Subdivision { code: "JP-10", name: "Gunma", parent: None },
+    Subdivision { code: "JP-11", name: "Saitama", parent: None },
+    Subdivision { code: "JP-12", name: "Chiba", parent: None },
+    Subdivision { code: "JP-13", name: "Tokyo", parent: None },
+    Subdivision { code: "JP-14", name: "Kanagawa", parent: None },
+    Subdivision { code: "JP-15", name: "Niigata", parent: None },
+    Subdivision { code: "JP-16", name: "Toyama", parent: None },
+    Subdivision { code: "JP-17", name: "Ishikawa", parent: None },
+    Subdivision { code: "JP-18", name: "Fukui", parent: None },
+    Subdivision { code: "JP-19", name: "Yamanashi", parent: None },
+    Subdivision { code: "JP-20", name: "Nagano", parent: None },
+    Subdivision { code: "JP-21", name: "Gifu", parent: None },
+    Subdivision { code: "JP-22", name: "Shizuoka", parent: None },
+    Subdivision { code: "JP-23", name: "Aichi", parent: None },
+    Subdivision { code: "JP-24", name: "Mie", parent: None },
+    Subdivision { code: "JP-25", name: "Shiga", parent: None },
+    Subdivision { code: "JP-26", name: "Kyoto", parent: None },
+    Subdivision { code: "JP-27", name: "Osaka", parent: None },
+    Subdivision { code: "JP-28", name: "Hyogo", parent: None },
+    Subdivision { code: "JP-29", name: "Nara", parent: None },
+    Subdivision { code: "JP-30", name: "Wakayama", parent: None },
+    Subdivision { code: "JP-31", name: "Tottori", parent: None },
+    Subdivision { code: "JP-32", name: "Shimane", parent: None },
+    Subdivision { code: "JP-33", name: "Okayama", parent: None },
+    Subdivision { code: "JP-34", name: "Hiroshima", parent: None },
+    Subdivision { code: "JP-35", name: "Yamaguchi", parent: None },
+    Subdivision { code: "JP-36", name: "Tokushima", parent: None },
+    Subdivision { code: "JP-37", name: "Kagawa", parent: None },
+    Subdivision { code: "JP-38", name: "Ehime", parent: None },
+    Subdivision { code: "JP-39", name: "Kochi", parent: None },
+    Subdivision { code: "JP-40", name: "Fukuoka", parent: None },
+    Subdivision { code: "JP-41", name: "Saga", parent: None },
+    Subdivision { code: "JP-42", name: "Nagasaki", parent: None },
+    Subdivision { code: "JP-43", name: "Kumamoto", parent: None },
+    Subdivision { code: "JP-44", name: "Oita", parent: None },
+    Subdivision { code: "JP-45", name: "Miyazaki", parent: None },
+    Subdivision { code: "JP-46", name: "Kagoshima", parent: None },
+    Subdivision { code: "JP-47", name: "Okinawa", parent: None },
+    Subdivision { code: "KE-01", name: "Baringo", parent: None },
+    Subdivision { code: "KE-02", name: "Bomet", parent: None },
+    Subdivision { code: "KE-03", name: "Bungoma", parent: None },
+    Subdivision { code: "KE-04", name: "Busia", parent: None },
+    Subdivision { code: "KE-05", name: "Elgeyo/Marakwet", parent: None },
+    Subdivision { code: "KE-06", name: "Embu", parent: None },
+    Subdivision { code: "KE-07", name: "Garissa", parent: None },
+    Subdivision { code: "KE-08", name: "Homa Bay", parent: None },
+    Subdivision { code: "KE-09", name: "Isiolo", parent: None },
+    Subdivision { code: "KE-10", name: "Kajiado", parent: None },
+    Subdivision { code: "KE-11", name: "Kakamega", parent: None },
+    Subdivision { code: "KE-12", name: "Kericho", parent: None },
+    Subdivision { code: "KE-13", name: "Kiambu", parent: None },
+    Subdivision { code: "KE-14", name: "Kilifi", parent: None },
+    Subdivision { code: "KE-15", name: "Kirinyaga", parent: None },
+    Subdivision { code: "KE-16", name: "Kisii", parent: None },
+    Subdivision { code: "KE-17", name: "Kisumu", parent: None },
+    Subdivision { code: "KE-18", name: "Kitui", parent: None },
+    Subdivision { code: "KE-19", name: "Kwale", parent: None },
+    Subdivision { code: "KE-20", name: "Laikipia", parent: None },
+    Subdivision { code: "KE-21", name: "Lamu", parent: None },
+    Subdivision { code: "KE-22", name: "Machakos", parent: None },
+    Subdivision { code: "KE-23", name: "Makueni", parent: None },
+    Subdivision { code: "KE-24", name: "Mandera", parent: None },
+    Subdivision { code: "KE-25", name: "Marsabit", parent: None },
+    Subdivision { code: "KE-26", name: "Meru", parent: None },
+    Subdivision { code: "KE-27", name: "Migori", parent: None },
+    Subdivision { code: "KE-28", name: "Mombasa", parent: None },
+    Subdivision { code: "KE-29", name: "Murang'a", parent: None },
+    Subdivision { code: "KE-30", name: "Nairobi City", parent: None },
+    Subdivision { code: "KE-31", name: "Nakuru", parent: None },
+    Subdivision { code: "KE-32", name: "Nandi", parent: None },
+    Subdivision { code: "KE-33", name: "Narok", parent: None },
+    Subdivision { code: "KE-34", name: "Nyamira", parent: None },
+    Subdivision { code: "KE-35", name: "Nyandarua", parent: None },
+    Subdivision { code: "KE-36", name: "Nyeri", parent: None },
+    Subdivision { code: "KE-37", name: "Samburu", parent: None },
+    Subdivision { code: "KE-38", name: "Siaya", parent: None },
+    Subdivision { code: "KE-39", name: "Taita/Taveta", parent: None },
+    Subdivision { code: "KE-40", name: "Tana River", parent: None },
+    Subdivision { code: "KE-41", name: "Tharaka-Nithi", parent: None },
+    Subdivision { code: "KE-42", name: "Trans Nzoia", parent: None },
+    Subdivision { code: "KE-43", name: "Turkana", parent: None },
+    Subdivision { code: "KE-44", name: "Uasin Gishu", parent: None },
+    Subdivision { code: "KE-45", name: "Vihiga", parent: None },
+    Subdivision { code: "KE-46", name: "Wajir", parent: None },
+    Subdivision { code: "KE-47", name: "West Pokot", parent: None },
+    Subdivision { code: "KG-B", name: "Batken", parent: None },
+    Subdivision { code: "KG-C", name: "Chuyskaya oblast'", parent: None },
+    Subdivision { code: "KG-GB", name: "Bishkek Shaary", parent: None },
+    Subdivision { code: "KG-GO", name: "Gorod Osh", parent: None },
+    Subdivision { code: "KG-J", name: "Dzhalal-Abadskaya oblast'", parent: None },
+    Subdivision { code: "KG-N", name: "Naryn", parent: None },
+    Subdivision { code: "KG-O", name: "Osh", parent: None },
+    Subdivision { code: "KG-T", name: "Talas", parent: None },
+    Subdivision { code: "KG-Y", name: "Issyk-Kul'skaja oblast'", parent: None },
+    Subdivision { code: "KH-1", name: "Banteay Mean Choăy", parent: None },
+    Subdivision { code: "KH-10", name: "Kracheh", parent: None },
+    Subdivision { code: "KH-11", name: "Mondol Kiri", parent: None },
+    Subdivision { code: "KH-12", name: "Phnom Penh", parent: None },
+    Subdivision { code: "KH-13", name: "Preah Vihear", parent: None },
+    Subdivision { code: "KH-14", name: "Prey Veaeng", parent: None },
+    Subdivision { code: "KH-15", name: "Pousaat", parent: None },
+    Subdivision { code: "KH-16", name: "Rotanak Kiri", parent: None },
+    Subdivision { code: "KH-17", name: "Siem Reab", parent: None },
+    Subdivision { code: "KH-18", name: "Preah Sihanouk", parent: None },
+    Subdivision { code: "KH-19", name: "Stoĕng Trêng", parent: None },
+    Subdivision { code: "KH-2", name: "Baat Dambang", parent: None },
+    Subdivision { code: "KH-20", name: "Svaay Rieng", parent: None },
+    Subdivision { code: "KH-21", name: "Taakaev", parent: None },
+    Subdivision { code: "KH-22", name: "Otdar Mean Chey", parent: None },
+    Subdivision { code: "KH-23", name: "Kaeb", parent: None },
+    Subdivision { code: "KH-24", name: "Pailin", parent: None },
+    Subdivision { code: "KH-25", name: "Tbong Khmum", parent: None },
+    Subdivision { code: "KH-3", name: "Kampong Chaam", parent: None },
+    Subdivision { code: "KH-4", name: "Kampong Chhnang", parent: None },
+    Subdivision { code: "KH-5", name: "Kampong Spueu", parent: None },
+    Subdivision { code: "KH-6", name: "Kampong Thum", parent: None },
+    Subdivision { code: "KH-7", name: "Kampot", parent: None },
+    Subdivision { code: "KH-8", name: "Kandaal", parent: None },
+    Subdivision { code: "KH-9", name: "Kaoh Kong", parent: None },
+    Subdivision { code: "KI-G", name: "Gilbert Islands", parent: None },
+    Subdivision { code: "KI-L", name: "Line Islands", parent: None },
+    Subdivision { code: "KI-P", name: "Phoenix Islands", parent: None },
+    Subdivision { code: "KM-A", name: "Andjouân", parent: None },
+    Subdivision { code: "KM-G", name: "Andjazîdja", parent: None },
+    Subdivision { code: "KM-M", name: "Mohéli", parent: None },
+    Subdivision { code: "KN-01", name: "Christ Church Nichola Town", parent: Some("KN-K") },
+    Subdivision { code: "KN-02", name: "Saint Anne Sandy Point", parent: Some("KN-K") },
+    Subdivision { code: "KN-03", name: "Saint George Basseterre", parent: Some("KN-K") },
+    Subdivision { code: "KN-04", name: "Saint George Gingerland", parent: Some("KN-N") },
+    Subdivision { code: "KN-05", name: "Saint James Windward", parent: Some("KN-N") },
+    Subdivision { code: "KN-06", name: "Saint John Capisterre", parent: Some("KN-K") },
+    Subdivision { code: "KN-07", name: "Saint John Figtree", parent: Some("KN-N") },
+    Subdivision { code: "KN-08", name: "Saint Mary Cayon", parent: Some("KN-K") },
+    Subdivision { code: "KN-09", name: "Saint Paul Capisterre", parent: Some("KN-K") },
+    Subdivision { code: "KN-10", name: "Saint Paul Charlestown", parent: Some("KN-N") },
+    Subdivision { code: "KN-11", name: "Saint Peter Basseterre", parent: Some("KN-K") },
+    Subdivision { code: "KN-12", name: "Saint Thomas Lowland", parent: Some("KN-N") },
+    Subdivision { code: "KN-13", name: "Saint Thomas Middle Island", parent: Some("KN-K") },
+    Subdivision { code: "KN-15", name: "Trinity Palmetto Point", parent: Some("KN-K") },
+    Subdivision { code: "KN-K", name: "Saint Kitts", parent: None },
+    Subdivision { code: "KN-N", name: "Nevis", parent: None },
+    Subdivision { code: "KP-01", name: "P'yǒngyang", parent: None },
+    Subdivision { code: "KP-02", name: "P'yǒngan-namdo", parent: None },
+    Subdivision { code: "KP-03", name: "P'yǒngan-bukto", parent: None },
+    Subdivision { code: "KP-04", name: "Chagang-do", parent: None },
+    Subdivision { code: "KP-05", name: "Hwanghae-namdo", parent: None },
+    Subdivision { code: "KP-06", name: "Hwanghae-bukto", parent: None },
+    Subdivision { code: "KP-07", name: "Kangweonto", parent: None },
+    Subdivision { code: "KP-08", name: "Hamgyǒng-namdo", parent: None },
+    Subdivision { code: "KP-09", name: "Hamgyǒng-bukto", parent: None },
+    Subdivision { code: "KP-10", name: "Ryanggang-do", parent: None },
+    Subdivision { code: "KP-13", name: "Raseon", parent: None },
+    Subdivision { code: "KP-14", name: "Nampho", parent: None },
+    Subdivision { code: "KR-11", name: "Seoul-teukbyeolsi", parent: None },
+    Subdivision { code: "KR-26", name: "Busan-gwangyeoksi", parent: None },
+    Subdivision { code: "KR-27", name: "Daegu-gwangyeoksi", parent: None },
+    Subdivision { code: "KR-28", name: "Incheon-gwangyeoksi", parent: None },
+    Subdivision { code: "KR-29", name: "Gwangju-gwangyeoksi", parent: None },
+    Subdivision { code: "KR-30", name: "Daejeon-gwangyeoksi", parent: None },
+    Subdivision { code: "KR-31", name: "Ulsan-gwangyeoksi", parent: None },
+    Subdivision { code: "KR-41", name: "Gyeonggi-do", parent: None },
+    Subdivision { code: "KR-42", name: "Gangwon-do", parent: None },
+    Subdivision { code: "KR-43", name: "Chungcheongbuk-do", parent: None },
+    Subdivision { code: "KR-44", name: "Chungcheongnam-do", parent: None },
+    Subdivision { code: "KR-45", name: "Jeollabuk-do", parent: None },
+    Subdivision { code: "KR-46", name: "Jeollanam-do", parent: None },
+    Subdivision { code: "KR-47", name: "Gyeongsangbuk-do", parent: None },
+    Subdivision { code: "KR-48", name: "Gyeongsangnam-do", parent: None },
+    Subdivision { code: "KR-49", name: "Jeju-teukbyeoljachido", parent: None },
+    Subdivision { code: "KR-50", name: "Sejong", parent: None },
+    Subdivision { code: "KW-AH", name: "Al Aḩmadī", parent: None },
+    Subdivision { code: "KW-FA", name: "Al Farwānīyah", parent: None },
+    Subdivision { code: "KW-HA", name: "Ḩawallī", parent: None },
+    Subdivision { code: "KW-JA", name: "Al Jahrā’", parent: None },
+    Subdivision { code: "KW-KU", name: "Al ‘Āşimah", parent: None },
+    Subdivision { code: "KW-MU", name: "Mubārak al Kabīr", parent: None },
+    Subdivision { code: "KZ-AKM", name: "Akmolinskaja oblast'", parent: None },
+    Subdivision { code: "KZ-AKT", name: "Aktjubinskaja oblast'", parent: None },
+    Subdivision { code: "KZ-ALA", name: "Almaty", parent: None },
+    Subdivision { code: "KZ-ALM", name: "Almatinskaja oblast'", parent: None },
+    Subdivision { code: "KZ-AST", name: "Nur-Sultan", parent: None },
+    Subdivision { code: "KZ-ATY", name: "Atyrauskaja oblast'", parent: None },
+    Subdivision { code: "KZ-KAR", name: "Karagandinskaja oblast'", parent: None },
+    Subdivision { code: "KZ-KUS", name: "Kostanajskaja oblast'", parent: None },
+    Subdivision { code: "KZ-KZY", name: "Kyzylordinskaja oblast'", parent: None },
+    Subdivision { code: "KZ-MAN", name: "Mangghystaū oblysy", parent: None },
+    Subdivision { code: "KZ-PAV", name: "Pavlodar oblysy", parent: None },
+    Subdivision { code: "KZ-SEV", name: "Severo-Kazahstanskaja oblast'", parent: None },
+    Subdivision { code: "KZ-SHY", name: "Shymkent", parent: None },
+    Subdivision { code: "KZ-VOS", name: "Shyghys Qazaqstan oblysy", parent: None },
+    Subdivision { code: "KZ-YUZ", name: "Turkestankaya oblast'", parent: None },
+    Subdivision { code: "KZ-ZAP", name: "Batys Qazaqstan oblysy", parent: None },
+    Subdivision { code: "KZ-ZHA", name: "Zhambyl oblysy", parent: None },
+    Subdivision { code: "LA-AT", name: "Attapu", parent: None },
+    Subdivision { code: "LA-BK", name: "Bokèo", parent: None },
+    Subdivision { code: "LA-BL", name: "Bolikhamxai", parent: None },
+    Subdivision { code: "LA-CH", name: "Champasak", parent: None },
+    Subdivision { code: "LA-HO", name: "Houaphan", parent: None },
+    Subdivision { code: "LA-KH", name: "Khammouan", parent: None },
+    Subdivision { code: "LA-LM", name: "Louang Namtha", parent: None },
+    Subdivision { code: "LA-LP", name: "Louangphabang", parent: None },
+    Subdivision { code: "LA-OU", name: "Oudômxai", parent: None },
+    Subdivision { code: "LA-PH", name: "Phôngsali", parent: None },
+    Subdivision { code: "LA-SL", name: "Salavan", parent: None },
+    Subdivision { code: "LA-SV", name: "Savannakhét", parent: None },
+    Subdivision { code: "LA-VI", name: "Viangchan", parent: None },
+    Subdivision { code: "LA-VT", name: "Viangchan", parent: None },
+    Subdivision { code: "LA-XA", name: "Xaignabouli", parent: None },
+    Subdivision { code: "LA-XE", name: "Xékong", parent: None },
+    Subdivision { code: "LA-XI", name: "Xiangkhouang", parent: None },
+    Subdivision { code: "LA-XS", name: "Xaisômboun", parent: None },
+    Subdivision { code: "LB-AK", name: "Aakkâr", parent: None },
+    Subdivision { code: "LB-AS", name: "Ash Shimāl", parent: None },
+    Subdivision { code: "LB-BA", name: "Bayrūt", parent: None },
+    Subdivision { code: "LB-BH", name: "Baalbek-Hermel", parent: None },
+    Subdivision { code: "LB-BI", name: "Al Biqā‘", parent: None },
+    Subdivision { code: "LB-JA", name: "Al Janūb", parent: None },
+    Subdivision { code: "LB-JL", name: "Jabal Lubnān", parent: None },
+    Subdivision { code: "LB-NA", name: "An Nabaţīyah", parent: None },
+    Subdivision { code: "LC-01", name: "Anse la Raye", parent: None },
+    Subdivision { code: "LC-02", name: "Castries", parent: None },
+    Subdivision { code: "LC-03", name: "Choiseul", parent: None },
+    Subdivision { code: "LC-05", name: "Dennery", parent: None },
+    Subdivision { code: "LC-06", name: "Gros Islet", parent: None },
+    Subdivision { code: "LC-07", name: "Laborie", parent: None },
+    Subdivision { code: "LC-08", name: "Micoud", parent: None },
+    Subdivision { code: "LC-10", name: "Soufrière", parent: None },
+    Subdivision { code: "LC-11", name: "Vieux Fort", parent: None },
+    Subdivision { code: "LC-12", name: "Canaries", parent: None },
+    Subdivision { code: "LI-01", name: "Balzers", parent: None },
+    Subdivision { code: "LI-02", name: "Eschen", parent: None },
+    Subdivision { code: "LI-03", name: "Gamprin", parent: None },
+    Subdivision { code: "LI-04", name: "Mauren", parent: None },
+    Subdivision { code: "LI-05", name: "Planken", parent: None },
+    Subdivision { code: "LI-06", name: "Ruggell", parent: None },
+    Subdivision { code: "LI-07", name: "Schaan", parent: None },
+    Subdivision { code: "LI-08", name: "Schellenberg", parent: None },
+    Subdivision { code: "LI-09", name: "Triesen", parent: None },
+    Subdivision { code: "LI-10", name: "Triesenberg", parent: None },
+    Subdivision { code: "LI-11", name: "Vaduz", parent: None },
+    Subdivision { code: "LK-1", name: "Western Province", parent: None },
+    Subdivision { code: "LK-11", name: "Colombo", parent: Some("LK-1") },
+    Subdivision { code: "LK-12", name: "Gampaha", parent: Some("LK-1") },
+    Subdivision { code: "LK-13", name: "Kalutara", parent: Some("LK-1") },
+    Subdivision { code: "LK-2", name: "Central Province", parent: None },
+    Subdivision { code: "LK-21", name: "Kandy", parent: Some("LK-2") },
+    Subdivision { code: "LK-22", name: "Matale", parent: Some("LK-2") },
+    Subdivision { code: "LK-23", name: "Nuwara Eliya", parent: Some("LK-2") },
+    Subdivision { code: "LK-3", name: "Southern Province", parent: None },
+    Subdivision { code: "LK-31", name: "Galle", parent: Some("LK-3") },
+    Subdivision { code: "LK-32", name: "Matara", parent: Some("LK-3") },
+    Subdivision { code: "LK-33", name: "Hambantota", parent: Some("LK-3") },
+    Subdivision { code: "LK-4", name: "Northern Province", parent: None },
+    Subdivision { code: "LK-41", name: "Jaffna", parent: Some("LK-4") },
+    Subdivision { code: "LK-42", name: "Kilinochchi", parent: Some("LK-4") },
+    Subdivision { code: "LK-43", name: "Mannar", parent: Some("LK-4") },
+    Subdivision { code: "LK-44", name: "Vavuniya", parent: Some("LK-4") },
+    Subdivision { code: "LK-45", name: "Mullaittivu", parent: Some("LK-4") },
+    Subdivision { code: "LK-5", name: "Eastern Province", parent: None },
+    Subdivision { code: "LK-51", name: "Batticaloa", parent: Some("LK-5") },
+    Subdivision { code: "LK-52", name: "Ampara", parent: Some("LK-5") },
+    Subdivision { code: "LK-53", name: "Trincomalee", parent: Some("LK-5") },
+    Subdivision { code: "LK-6", name: "North Western Province", parent: None },
+    Subdivision { code: "LK-61", name: "Kurunegala", parent: Some("LK-6") },
+    Subdivision { code: "LK-62", name: "Puttalam", parent: Some("LK-6") },
+    Subdivision { code: "LK-7", name: "North Central Province", parent: None },
+    Subdivision { code: "LK-71", name: "Anuradhapura", parent: Some("LK-7") },
+    Subdivision { code: "LK-72", name: "Polonnaruwa", parent: Some("LK-7") },
+    Subdivision { code: "LK-8", name: "Uva Province", parent: None },
+    Subdivision { code: "LK-81", name: "Badulla", parent: Some("LK-8") },
+    Subdivision { code: "LK-82", name: "Monaragala", parent: Some("LK-8") },
+    Subdivision { code: "LK-9", name: "Sabaragamuwa Province", parent: None },
+    Subdivision { code: "LK-91", name: "Ratnapura", parent: Some("LK-9") },
+    Subdivision { code: "LK-92", name: "Kegalla", parent: Some("LK-9") },
+    Subdivision { code: "LR-BG", name: "Bong", parent: None },
+    Subdivision { code: "LR-BM", name: "Bomi", parent: None },
+    Subdivision { code: "LR-CM", name: "Grand Cape Mount", parent: None },
+    Subdivision { code: "LR-GB", name: "Grand Bassa", parent: None },
+    Subdivision { code: "LR-GG", name: "Grand Gedeh", parent: None },
+    Subdivision { code: "LR-GK", name: "Grand Kru", parent: None },
+    Subdivision { code: "LR-GP", name: "Gbarpolu", parent: None },
+    Subdivision { code: "LR-LO", name: "Lofa", parent: None },
+    Subdivision { code: "LR-MG", name: "Margibi", parent: None },
+    Subdivision { code: "LR-MO", name: "Montserrado", parent: None },
+    Subdivision { code: "LR-MY", name: "Maryland", parent: None },
+    Subdivision { code: "LR-NI", name: "Nimba", parent: None },
+    Subdivision { code: "LR-RG", name: "River Gee", parent: None },
+    Subdivision { code: "LR-RI", name: "River Cess", parent: None },
+    Subdivision { code: "LR-SI", name: "Sinoe", parent: None },
+    Subdivision { code: "LS-A", name: "Maseru", parent: None },
+    Subdivision { code: "LS-B", name: "Botha-Bothe", parent: None },
+    Subdivision { code: "LS-C", name: "Leribe", parent: None },
+    Subdivision { code: "LS-D", name: "Berea", parent: None },
+    Subdivision { code: "LS-E", name: "Mafeteng", parent: None },
+    Subdivision { code: "LS-F", name: "Mohale's Hoek", parent: None },
+    Subdivision { code: "LS-G", name: "Quthing", parent: None },
+    Subdivision { code: "LS-H", name: "Qacha's Nek", parent: None },
+    Subdivision { code: "LS-J", name: "Mokhotlong", parent: None },
+    Subdivision { code: "LS-K", name: "Thaba-Tseka", parent: None },
+    Subdivision { code: "LT-01", name: "Akmenė", parent: None },
+    Subdivision { code: "LT-02", name: "Alytaus miestas", parent: None },
+    Subdivision { code: "LT-03", name: "Alytus", parent: None },
+    Subdivision { code: "LT-04", name: "Anykščiai", parent: None },
+    Subdivision { code: "LT-05", name: "Birštono", parent: None },
+    Subdivision { code: "LT-06", name: "Biržai", parent: None },
+    Subdivision { code: "LT-07", name: "Druskininkai", parent: None },
+    Subdivision { code: "LT-08", name: "Elektrėnai", parent: None },
+    Subdivision { code: "LT-09", name: "Ignalina", parent: None },
+    Subdivision { code: "LT-10", name: "Jonava", parent: None },
+    Subdivision { code: "LT-11", name: "Joniškis", parent: None },
+    Subdivision { code: "LT-12", name: "Jurbarkas", parent: None },
+    Subdivision { code: "LT-13", name: "Kaišiadorys", parent: None },
+    Subdivision { code: "LT-14", name: "Kalvarijos", parent: None },
+    Subdivision { code: "LT-15", name: "Kauno miestas", parent: None },
+    Subdivision { code: "LT-16", name: "Kaunas", parent: None },
+    Subdivision { code: "LT-17", name: "Kazlų Rūdos", parent: None },
+    Subdivision { code: "LT-18", name: "Kėdainiai", parent: None },
+    Subdivision { code: "LT-19", name: "Kelmė", parent: None },
+    Subdivision { code: "LT-20", name: "Klaipėdos miestas", parent: None },
+    Subdivision { code: "LT-21", name: "Klaipėda", parent: None },
+    Subdivision { code: "LT-22", name: "Kretinga", parent: None },
+    Subdivision { code: "LT-23", name: "Kupiškis", parent: None },
+    Subdivision { code: "LT-24", name: "Lazdijai", parent: None },
+    Subdivision { code: "LT-25", name: "Marijampolė", parent: None },
+    Subdivision { code: "LT-26", name: "Mažeikiai", parent: None },
+    Subdivision { code: "LT-27", name: "Molėtai", parent: None },
+    Subdivision { code: "LT-28", name: "Neringa", parent: None },
+    Subdivision { code: "LT-29", name: "Pagėgiai", parent: None },
+    Subdivision { code: "LT-30", name: "Pakruojis", parent: None },
+    Subdivision { code: "LT-31", name: "Palangos miestas", parent: None },
+    Subdivision { code: "LT-32", name: "Panevėžio miestas", parent: None },
+    Subdivision { code: "LT-33", name: "Panevėžys", parent: None },
+    Subdivision { code: "LT-34", name: "Pasvalys", parent: None },
+    Subdivision { code: "LT-35", name: "Plungė", parent: None },
+    Subdivision { code: "LT-36", name: "Prienai", parent: None },
+    Subdivision { code: "LT-37", name: "Radviliškis", parent: None },
+    Subdivision { code: "LT-38", name: "Raseiniai", parent: None },
+    Subdivision { code: "LT-39", name: "Rietavo", parent: None },
+    Subdivision { code: "LT-40", name: "Rokiškis", parent: None },
+    Subdivision { code: "LT-41", name: "Šakiai", parent: None },
+    Subdivision { code: "LT-42", name: "Šalčininkai", parent: None },
+    Subdivision { code: "LT-43", name: "Šiaulių miestas", parent: None },
+    Subdivision { code: "LT-44", name: "Šiauliai", parent: None },
+    Subdivision { code: "LT-45", name: "Šilalė", parent: None },
+    Subdivision { code: "LT-46", name: "Šilutė", parent: None },
+    Subdivision { code: "LT-47", name: "Širvintos", parent: None },
+    Subdivision { code: "LT-48", name: "Skuodas", parent: None },
+    Subdivision { code: "LT-49", name: "Švenčionys", parent: None },
+    Subdivision { code: "LT-50", name: "Tauragė", parent: None },
+    Subdivision { code: "LT-51", name: "Telšiai", parent: None },
+    Subdivision { code: "LT-52", name: "Trakai", parent: None },
+    Subdivision { code: "LT-53", name: "Ukmergė", parent: None },
+    Subdivision { code: "LT-54", name: "Utena", parent: None },
+    Subdivision { code: "LT-55", name: "Varėna", parent: None },
+    Subdivision { code: "LT-56", name: "Vilkaviškis", parent: None },
+    Subdivision { code: "LT-57", name: "Vilniaus miestas", parent: None },
+    Subdivision { code: "LT-58", name: "Vilnius", parent: None },
+    Subdivision { code: "LT-59", name: "Visaginas", parent: None },
+    Subdivision { code: "LT-60", name: "Zarasai", parent: None },
+    Subdivision { code: "LT-AL", name: "Alytaus apskritis", parent: None },
+    Subdivision { code: "LT-KL", name: "Klaipėdos apskritis", parent: None },
+    Subdivision { code: "LT-KU", name: "Kauno apskritis", parent: None },
+    Subdivision { code: "LT-MR", name: "Marijampolės apskritis", parent: None },
+    Subdivision { code: "LT-PN", name: "Panevėžio apskritis", parent: None },
+    Subdivision { code: "LT-SA", name: "Šiaulių apskritis", parent: None },
+    Subdivision { code: "LT-TA", name: "Tauragės apskritis", parent: None },
+    Subdivision { code: "LT-TE", name: "Telšių apskritis", parent: None },
+    Subdivision { code: "LT-UT", name: "Utenos apskritis", parent: None },
+    Subdivision { code: "LT-VL", name: "Vilniaus apskritis", parent: None },
+    Subdivision { code: "LU-CA", name: "Capellen", parent: None },
+    Subdivision { code: "LU-CL", name: "Clerf", parent: None },
+    Subdivision { code: "LU-DI", name: "Diekirch", parent: None },
+    Subdivision { code: "LU-EC", name: "Echternach", parent: None },
+    Subdivision { code: "LU-ES", name: "Esch an der Alzette", parent: None },
+    Subdivision { code: "LU-GR", name: "Grevenmacher", parent: None },
+    Subdivision { code: "LU-LU", name: "Luxembourg", parent: None },
+    Subdivision { code: "LU-ME", name: "Mersch", parent: None },
+    Subdivision { code: "LU-RD", name: "Redange", parent: None },
+    Subdivision { code: "LU-RM", name: "Remich", parent: None },
+    Subdivision { code: "LU-VD", name: "Veianen", parent: None },
+    Subdivision { code: "LU-WI", name: "Wiltz", parent: None },
+    Subdivision { code: "LV-001", name: "Aglonas novads", parent: None },
+    Subdivision { code: "LV-002", name: "Aizkraukles novads", parent: None },
+    Subdivision { code: "LV-003", name: "Aizputes novads", parent: None },
+    Subdivision { code: "LV-004", name: "Aknīstes novads", parent: None },
+    Subdivision { code: "LV-005", name: "Alojas novads", parent: None },
+    Subdivision { code: "LV-006", name: "Alsungas novads", parent: None },
+    Subdivision { code: "LV-007", name: "Alūksnes novads", parent: None },
+    Subdivision { code: "LV-008", name: "Amatas novads", parent: None },
+    Subdivision { code: "LV-009", name: "Apes novads", parent: None },
+    Subdivision { code: "LV-010", name: "Auces novads", parent: None },
+    Subdivision { code: "LV-011", name: "Ādažu novads", parent: None },
+    Subdivision { code: "LV-012", name: "Babītes novads", parent: None },
+    Subdivision { code: "LV-013", name: "Baldones novads", parent: None },
+    Subdivision { code: "LV-014", name: "Baltinavas novads", parent: None },
+    Subdivision { code: "LV-015", name: "Balvu novads", parent: None },
+    Subdivision { code: "LV-016", name: "Bauskas novads", parent: None },
+    Subdivision { code: "LV-017", name: "Beverīnas novads", parent: None },
+    Subdivision { code: "LV-018", name: "Brocēnu novads", parent: None },
+    Subdivision { code: "LV-019", name: "Burtnieku novads", parent: None },
+    Subdivision { code: "LV-020", name: "Carnikavas novads", parent: None },
+    Subdivision { code: "LV-021", name: "Cesvaines novads", parent: None },
+    Subdivision { code: "LV-022", name: "Cēsu novads", parent: None },
+    Subdivision { code: "LV-023", name: "Ciblas novads", parent: None },
+    Subdivision { code: "LV-024", name: "Dagdas novads", parent: None },
+    Subdivision { code: "LV-025", name: "Daugavpils novads", parent: None },
+    Subdivision { code: "LV-026", name: "Dobeles novads", parent: None },
+    Subdivision { code: "LV-027", name: "Dundagas novads", parent: None },
+    Subdivision { code: "LV-028", name: "Durbes novads", parent: None },
+    Subdivision { code: "LV-029", name: "Engures novads", parent: None },
+    Subdivision { code: "LV-030", name: "Ērgļu novads", parent: None },
+    Subdivision { code: "LV-031", name: "Garkalnes novads", parent: None },
+    Subdivision { code: "LV-032", name: "Grobiņas novads", parent: None },
+    Subdivision { code: "LV-033", name: "Gulbenes novads", parent: None },
+    Subdivision { code: "LV-034", name: "Iecavas novads", parent: None },
+    Subdivision { code: "LV-035", name: "Ikšķiles novads", parent: None },
+    Subdivision { code: "LV-036", name: "Ilūkstes novads", parent: None },
+    Subdivision { code: "LV-037", name: "Inčukalna novads", parent: None },
+    Subdivision { code: "LV-038", name: "Jaunjelgavas novads", parent: None },
+    Subdivision { code: "LV-039", name: "Jaunpiebalgas novads", parent: None },
+    Subdivision { code: "LV-040", name: "Jaunpils novads", parent: None },
+    Subdivision { code: "LV-041", name: "Jelgavas novads", parent: None },
+    Subdivision { code: "LV-042", name: "Jēkabpils novads", parent: None },
+    Subdivision { code: "LV-043", name: "Kandavas novads", parent: None },
+    Subdivision { code: "LV-044", name: "Kārsavas novads", parent: None },
+    Subdivision { code: "LV-045", name: "Kocēnu novads", parent: None },
+    Subdivision { code: "LV-046", name: "Kokneses novads", parent: None },
+    Subdivision { code: "LV-047", name: "Krāslavas novads", parent: None },
+    Subdivision { code: "LV-048", name: "Krimuldas novads", parent: None },
+    Subdivision { code: "LV-049", name: "Krustpils novads", parent: None },
+    Subdivision { code: "LV-050", name: "Kuldīgas novads", parent: None },
+    Subdivision { code: "LV-051", name: "Ķeguma novads", parent: None },
+    Subdivision { code: "LV-052", name: "Ķekavas novads", parent: None },
+    Subdivision { code: "LV-053", name: "Lielvārdes novads", parent: None },
+    Subdivision { code: "LV-054", name: "Limbažu novads", parent: None },
+    Subdivision { code: "LV-055", name: "Līgatnes novads", parent: None },
+    Subdivision { code: "LV-056", name: "Līvānu novads", parent: None },
+    Subdivision { code: "LV-057", name: "Lubānas novads", parent: None },
+    Subdivision { code: "LV-058", name: "Ludzas novads", parent: None },
+    Subdivision { code: "LV-059", name: "Madonas novads", parent: None },
+    Subdivision { code: "LV-060", name: "Mazsalacas novads", parent: None },
+    Subdivision { code: "LV-061", name: "Mālpils novads", parent: None },
+    Subdivision { code: "LV-062", name: "Mārupes novads", parent: None },
+    Subdivision { code: "LV-063", name: "Mērsraga novads", parent: None },
+    Subdivision { code: "LV-064", name: "Naukšēnu novads", parent: None },
+    Subdivision { code: "LV-065", name: "Neretas novads", parent: None },
+    Subdivision { code: "LV-066", name: "Nīcas novads", parent: None },
+    Subdivision { code: "LV-067", name: "Ogres novads", parent: None },
+    Subdivision { code: "LV-068", name: "Olaines novads", parent: None },
+    Subdivision { code: "LV-069", name: "Ozolnieku novads", parent: None },
+    Subdivision { code: "LV-070", name: "Pārgaujas novads", parent: None },
+    Subdivision { code: "LV-071", name: "Pāvilostas novads", parent: None },
+    Subdivision { code: "LV-072", name: "Pļaviņu novads", parent: None },
+    Subdivision { code: "LV-073", name: "Preiļu novads", parent: None },
+    Subdivision { code: "LV-074", name: "Priekules novads", parent: None },
+    Subdivision { code: "LV-075", name: "Priekuļu novads", parent: None },
+    Subdivision { code: "LV-076", name: "Raunas novads", parent: None },
+    Subdivision { code: "LV-077", name: "Rēzeknes novads", parent: None },
+    Subdivision { code: "LV-078", name: "Riebiņu novads", parent: None },
+    Subdivision { code: "LV-079", name: "Rojas novads", parent: None },
+    Subdivision { code: "LV-080", name: "Ropažu novads", parent: None },
+    Subdivision { code: "LV-081", name: "Rucavas novads", parent: None },
+    Subdivision { code: "LV-082", name: "Rugāju novads", parent: None },
+    Subdivision { code: "LV-083", name: "Rundāles novads", parent: None },
+    Subdivision { code: "LV-084", name: "Rūjienas novads", parent: None },
+    Subdivision { code: "LV-085", name: "Salas novads", parent: None },
+    Subdivision { code: "LV-086", name: "Salacgrīvas novads", parent: None },
+    Subdivision { code: "LV-087", name: "Salaspils novads", parent: None },
+    Subdivision { code: "LV-088", name: "Saldus novads", parent: None },
+    Subdivision { code: "LV-089", name: "Saulkrastu novads", parent: None },
+    Subdivision { code: "LV-090", name: "Sējas novads", parent: None },
+    Subdivision { code: "LV-091", name: "Siguldas novads", parent: None },
+    Subdivision { code: "LV-092", name: "Skrīveru novads", parent: None },
+    Subdivision { code: "LV-093", name: "Skrundas novads", parent: None },
+    Subdivision { code: "LV-094", name: "Smiltenes novads", parent: None },
+    Subdivision { code: "LV-095", name: "Stopiņu novads", parent: None },
+    Subdivision { code: "LV-096", name: "Strenču novads", parent: None },
+    Subdivision { code: "LV-097", name: "Talsu novads", parent: None },
+    Subdivision { code: "LV-098", name: "Tērvetes novads", parent: None },
+    Subdivision { code: "LV-099", name: "Tukuma novads", parent: None },
+    Subdivision { code: "LV-100", name: "Vaiņodes novads", parent: None },
+    Subdivision { code: "LV-101", name: "Valkas novads", parent: None },
+    Subdivision { code: "LV-102", name: "Varakļānu novads", parent: None },
+    Subdivision { code: "LV-103", name: "Vārkavas novads", parent: None },
+    Subdivision { code: "LV-104", name: "Vecpiebalgas novads", parent: None },
+    Subdivision { code: "LV-105", name: "Vecumnieku novads", parent: None },
+    Subdivision { code: "LV-106", name: "Ventspils novads", parent: None },
+    Subdivision { code: "LV-107", name: "Viesītes novads", parent: None },
+    Subdivision { code: "LV-108", name: "Viļakas novads", parent: None },
+    Subdivision { code: "LV-109", name: "Viļānu novads", parent: None },
+    Subdivision { code: "LV-110", name: "Zilupes novads", parent: None },
+    Subdivision { code: "LV-DGV", name: "Daugavpils", parent: None },
+    Subdivision { code: "LV-JEL", name: "Jelgava", parent: None },
+    Subdivision { code: "LV-JKB", name: "Jēkabpils", parent: None },
+    Subdivision { code: "LV-JUR", name: "Jūrmala", parent: None },
+    Subdivision { code: "LV-LPX", name: "Liepāja", parent: None },
+    Subdivision { code: "LV-REZ", name: "Rēzekne", parent: None },
+    Subdivision { code: "LV-RIX", name: "Rīga", parent: None },
+    Subdivision { code: "LV-VEN", name: "Ventspils", parent: None },
+    Subdivision { code: "LV-VMR", name: "Valmiera", parent: None },
+    Subdivision { code: "LY-BA", name: "Banghāzī", parent: None },
+    Subdivision { code: "LY-BU", name: "Al Buţnān", parent: None },
+    Subdivision { code: "LY-DR", name: "Darnah", parent: None },
+    Subdivision { code: "LY-GT", name: "Ghāt", parent: None },
+    Subdivision { code: "LY-JA", name: "Al Jabal al Akhḑar", parent: None },
+    Subdivision { code: "LY-JG", name: "Al Jabal al Gharbī", parent: None },
+    Subdivision { code: "LY-JI", name: "Al Jafārah", parent: None },
+    Subdivision { code: "LY-JU", name: "Al Jufrah", parent: None },
+    Subdivision { code: "LY-KF", name: "Al Kufrah", parent: None },
+    Subdivision { code: "LY-MB", name: "Al Marqab", parent: None },
+    Subdivision { code: "LY-MI", name: "Mişrātah", parent: None },
+    Subdivision { code: "LY-MJ", name: "Al Marj", parent: None },
+    Subdivision { code: "LY-MQ", name: "Murzuq", parent: None },
+    Subdivision { code: "LY-NL", name: "Nālūt", parent: None },
+    Subdivision { code: "LY-NQ", name: "An Nuqāţ al Khams", parent: None },
+    Subdivision { code: "LY-SB", name: "Sabhā", parent: None },
+    Subdivision { code: "LY-SR", name: "Surt", parent: None },
+    Subdivision { code: "LY-TB", name: "Ţarābulus", parent: None },
+    Subdivision { code: "LY-WA", name: "Al Wāḩāt", parent: None },
+    Subdivision { code: "LY-WD", name: "Wādī al Ḩayāt", parent: None },
+    Subdivision { code: "LY-WS", name: "Wādī ash Shāţi’", parent: None },
+    Subdivision { code: "LY-ZA", name: "Az Zāwiyah", parent: None },
+    Subdivision { code: "MA-01", name: "Tanger-Tétouan-Al Hoceïma", parent: None },
+    Subdivision { code: "MA-02", name: "L'Oriental", parent: None },
+    Subdivision { code: "MA-03", name: "Fès-Meknès", parent: None },
+    Subdivision { code: "MA-04", name: "Rabat-Salé-Kénitra", parent: None },
+    Subdivision { code: "MA-05", name: "Béni Mellal-Khénifra", parent: None },
+    Subdivision { code: "MA-06", name: "Casablanca-Settat", parent: None },
+    Subdivision { code: "MA-07", name: "Marrakech-Safi", parent: None },
+    Subdivision { code: "MA-08", name: "Drâa-Tafilalet", parent: None },
+    Subdivision { code: "MA-09", name: "Souss-Massa", parent: None },
+    Subdivision { code: "MA-10", name: "Guelmim-Oued Noun (EH-partial)", parent: None },
+    Subdivision { code: "MA-11", name: "Laâyoune-Sakia El Hamra (EH-partial)", parent: None },
+    Subdivision { code: "MA-12", name: "Dakhla-Oued Ed-Dahab (EH)", parent: None },
+    Subdivision { code: "MA-AGD", name: "Agadir-Ida-Ou-Tanane", parent: Some("MA-09") },
+    Subdivision { code: "MA-AOU", name: "Aousserd (EH)", parent: Some("MA-12") },
+    Subdivision { code: "MA-ASZ", name: "Assa-Zag (EH-partial)", parent: Some("MA-10") },
+    Subdivision { code: "MA-AZI", name: "Azilal", parent: Some("MA-05") },
+    Subdivision { code: "MA-BEM", name: "Béni Mellal", parent: Some("MA-05") },
+    Subdivision { code: "MA-BER", name: "Berkane", parent: Some("MA-02") },
+    Subdivision { code: "MA-BES", name: "Benslimane", parent: Some("MA-06") },
+    Subdivision { code: "MA-BOD", name: "Boujdour (EH)", parent: Some("MA-11") },
+    Subdivision { code: "MA-BOM", name: "Boulemane", parent: Some("MA-03") },
+    Subdivision { code: "MA-BRR", name: "Berrechid", parent: Some("MA-06") },
+    Subdivision { code: "MA-CAS", name: "Casablanca", parent: Some("MA-06") },
+    Subdivision { code: "MA-CHE", name: "Chefchaouen", parent: Some("MA-01") },
+    Subdivision { code: "MA-CHI", name: "Chichaoua", parent: Some("MA-07") },
+    Subdivision { code: "MA-CHT", name: "Chtouka-Ait Baha", parent: Some("MA-06") },
+    Subdivision { code: "MA-DRI", name: "Driouch", parent: Some("MA-02") },
+    Subdivision { code: "MA-ERR", name: "Errachidia", parent: Some("MA-08") },
+    Subdivision { code: "MA-ESI", name: "Essaouira", parent: Some("MA-07") },
+    Subdivision { code: "MA-ESM", name: "Es-Semara (EH-partial)", parent: Some("MA-11") },
+    Subdivision { code: "MA-FAH", name: "Fahs-Anjra", parent: Some("MA-01") },
+    Subdivision { code: "MA-FES", name: "Fès", parent: Some("MA-03") },
+    Subdivision { code: "MA-FIG", name: "Figuig", parent: Some("MA-02") },
+    Subdivision { code: "MA-FQH", name: "Fquih Ben Salah", parent: Some("MA-05") },
+    Subdivision { code: "MA-GUE", name: "Guelmim", parent: Some("MA-10") },
+    Subdivision { code: "MA-GUF", name: "Guercif", parent: Some("MA-02") },
+    Subdivision { code: "MA-HAJ", name: "El Hajeb", parent: Some("MA-03") },
+    Subdivision { code: "MA-HAO", name: "Al Haouz", parent: Some("MA-07") },
+    Subdivision { code: "MA-HOC", name: "Al Hoceïma", parent: Some("MA-01") },
+    Subdivision { code: "MA-IFR", name: "Ifrane", parent: Some("MA-03") },
+    Subdivision { code: "MA-INE", name: "Inezgane-Ait Melloul", parent: Some("MA-09") },
+    Subdivision { code: "MA-JDI", name: "El Jadida", parent: Some("MA-06") },
+    Subdivision { code: "MA-JRA", name: "Jerada", parent: Some("MA-02") },
+    Subdivision { code: "MA-KEN", name: "Kénitra", parent: Some("MA-04") },
+    Subdivision { code: "MA-KES", name: "El Kelâa des Sraghna", parent: Some("MA-07") },
+    Subdivision { code: "MA-KHE", name: "Khémisset", parent: Some("MA-04") },
+    Subdivision { code: "MA-KHN", name: "Khénifra", parent: Some("MA-05") },
+    Subdivision { code: "MA-KHO", name: "Khouribga", parent: Some("MA-05") },
+    Subdivision { code: "MA-LAA", name: "Laâyoune (EH)", parent: Some("MA-11") },
+    Subdivision { code: "MA-LAR", name: "Larache", parent: Some("MA-01") },
+    Subdivision { code: "MA-MAR", name: "Marrakech", parent: Some("MA-07") },
+    Subdivision { code: "MA-MDF", name: "M’diq-Fnideq", parent: Some("MA-01") },
+    Subdivision { code: "MA-MED", name: "Médiouna", parent: Some("MA-06") },
+    Subdivision { code: "MA-MEK", name: "Meknès", parent: Some("MA-03") },
+    Subdivision { code: "MA-MID", name: "Midelt", parent: Some("MA-08") },
+    Subdivision { code: "MA-MOH", name: "Mohammadia", parent: Some("MA-06") },
+    Subdivision { code: "MA-MOU", name: "Moulay Yacoub", parent: Some("MA-03") },
+    Subdivision { code: "MA-NAD", name: "Nador", parent: Some("MA-02") },
+    Subdivision { code: "MA-NOU", name: "Nouaceur", parent: Some("MA-04") },
+    Subdivision { code: "MA-OUA", name: "Ouarzazate", parent: Some("MA-08") },
+    Subdivision { code: "MA-OUD", name: "Oued Ed-Dahab (EH)", parent: Some("MA-12") },
+    Subdivision { code: "MA-OUJ", name: "Oujda-Angad", parent: Some("MA-02") },
+    Subdivision { code: "MA-OUZ", name: "Ouezzane", parent: Some("MA-01") },
+    Subdivision { code: "MA-RAB", name: "Rabat", parent: Some("MA-04") },
+    Subdivision { code: "MA-REH", name: "Rehamna", parent: Some("MA-07") },
+    Subdivision { code: "MA-SAF", name: "Safi", parent: Some("MA-07") },
+    Subdivision { code: "MA-SAL", name: "Salé", parent: Some("MA-04") },
+    Subdivision { code: "MA-SEF", name: "Sefrou", parent: Some("MA-03") },
+    Subdivision { code: "MA-SET", name: "Settat", parent: Some("MA-06") },
+    Subdivision { code: "MA-SIB", name: "Sidi Bennour", parent: Some("MA-06") },
+    Subdivision { code: "MA-SIF", name: "Sidi Ifni", parent: Some("MA-10") },
+    Subdivision { code: "MA-SIK", name: "Sidi Kacem", parent: Some("MA-04") },
+    Subdivision { code: "MA-SIL", name: "Sidi Slimane", parent: Some("MA-04") },
+    Subdivision { code: "MA-SKH", name: "Skhirate-Témara", parent: Some("MA-04") },
+    Subdivision { code: "MA-TAF", name: "Tarfaya (EH-partial)", parent: Some("MA-11") },
+    Subdivision { code: "MA-TAI", name: "Taourirt", parent: Some("MA-02") },
+    Subdivision { code: "MA-TAO", name: "Taounate", parent: Some("MA-03") },
+    Subdivision { code: "MA-TAR", name: "Taroudannt", parent: Some("MA-09") },
+    Subdivision { code: "MA-TAT", name: "Tata", parent: Some("MA-09") },
+    Subdivision { code: "MA-TAZ", name: "Taza", parent: Some("MA-03") },
+    Subdivision { code: "MA-TET", name: "Tétouan", parent: Some("MA-01") },
+    Subdivision { code: "MA-TIN", name: "Tinghir", parent: Some("MA-08") },
+    Subdivision { code: "MA-TIZ", name: "Tiznit", parent: Some("MA-09") },
+    Subdivision { code: "MA-TNG", name: "Tanger-Assilah", parent: Some("MA-01") },
+    Subdivision { code: "MA-TNT", name: "Tan-Tan (EH-partial)", parent: Some("MA-10") },
+    Subdivision { code: "MA-YUS", name: "Youssoufia", parent: Some("MA-07") },
+    Subdivision { code: "MA-ZAG", name: "Zagora", parent: Some("MA-08") },
+    Subdivision { code: "MC-CL", name: "La Colle", parent: None },
+    Subdivision { code: "MC-CO", name: "La Condamine", parent: None },
+    Subdivision { code: "MC-FO", name: "Fontvieille", parent: None },
+    Subdivision { code: "MC-GA", name: "La Gare", parent: None },
+    Subdivision { code: "MC-JE", name: "Jardin Exotique", parent: None },
+    Subdivision { code: "MC-LA", name: "Larvotto", parent: None },
+    Subdivision { code: "MC-MA", name: "Malbousquet", parent: None },
+    Subdivision { code: "MC-MC", name: "Monte-Carlo", parent: None },
+    Subdivision { code: "MC-MG", name: "Moneghetti", parent: None },
+    Subdivision { code: "MC-MO", name: "Monaco-Ville", parent: None },
+    Subdivision { code: "MC-MU", name: "Moulins", parent: None },
+    Subdivision { code: "MC-PH", name: "Port-Hercule", parent: None },
+    Subdivision { code: "MC-SD", name: "Sainte-Dévote", parent: None },
+    Subdivision { code: "MC-SO", name: "La Source", parent: None },
+    Subdivision { code: "MC-SP", name: "Spélugues", parent: None },
+    Subdivision { code: "MC-SR", name: "Saint-Roman", parent: None },
+    Subdivision { code: "MC-VR", name: "Vallon de la Rousse", parent: None },
+    Subdivision { code: "MD-AN", name: "Anenii Noi", parent: None },
+    Subdivision { code: "MD-BA", name: "Bălți", parent: None },
+    Subdivision { code: "MD-BD", name: "Bender [Tighina]", parent: None },
+    Subdivision { code: "MD-BR", name: "Briceni", parent: None },
+    Subdivision { code: "MD-BS", name: "Basarabeasca", parent: None },
+    Subdivision { code: "MD-CA", name: "Cahul", parent: None },
+    Subdivision { code: "MD-CL", name: "Călărași", parent: None },
+    Subdivision { code: "MD-CM", name: "Cimișlia", parent: None },
+    Subdivision { code: "MD-CR", name: "Criuleni", parent: None },
+    Subdivision { code: "MD-CS", name: "Căușeni", parent: None },
+    Subdivision { code: "MD-CT", name: "Cantemir", parent: None },
+    Subdivision { code: "MD-CU", name: "Chișinău", parent: None },
+    Subdivision { code: "MD-DO", name: "Dondușeni", parent: None },
+    Subdivision { code: "MD-DR", name: "Drochia", parent: None },
+    Subdivision { code: "MD-DU", name: "Dubăsari", parent: None },
+    Subdivision { code: "MD-ED", name: "Edineț", parent: None },
+    Subdivision { code: "MD-FA", name: "Fălești", parent: None },
+    Subdivision { code: "MD-FL", name: "Florești", parent: None },
+    Subdivision { code: "MD-GA", name: "Găgăuzia, Unitatea teritorială autonomă (UTAG)", parent: None },
+    Subdivision { code: "MD-GL", name: "Glodeni", parent: None },
+    Subdivision { code: "MD-HI", name: "Hîncești", parent: None },
+    Subdivision { code: "MD-IA", name: "Ialoveni", parent: None },
+    Subdivision { code: "MD-LE", name: "Leova", parent: None },
+    Subdivision { code: "MD-NI", name: "Nisporeni", parent: None },
+    Subdivision { code: "MD-OC", name: "Ocnița", parent: None },
+    Subdivision { code: "MD-OR", name: "Orhei", parent: None },
+    Subdivision { code: "MD-RE", name: "Rezina", parent: None },
+    Subdivision { code: "MD-RI", name: "Rîșcani", parent: None },
+    Subdivision { code: "MD-SD", name: "Șoldănești", parent: None },
+    Subdivision { code: "MD-SI", name: "Sîngerei", parent: None },
+    Subdivision { code: "MD-SN", name: "Stînga Nistrului, unitatea teritorială din", parent: None },
+    Subdivision { code: "MD-SO", name: "Soroca", parent: None },
+    Subdivision { code: "MD-ST", name: "Strășeni", parent: None },
+    Subdivision { code: "MD-SV", name: "Ștefan Vodă", parent: None },
+    Subdivision { code: "MD-TA", name: "Taraclia", parent: None },
+    Subdivision { code: "MD-TE", name: "Telenești", parent: None },
+    Subdivision { code: "MD-UN", name: "Ungheni", parent: None },
+    Subdivision { code: "ME-01", name: "Andrijevica", parent: None },
+    Subdivision { code: "ME-02", name: "Bar", parent: None },
+    Subdivision { code: "ME-03", name: "Berane", parent: None },
+    Subdivision { code: "ME-04", name: "Bijelo Polje", parent: None },
+    Subdivision { code: "ME-05", name: "Budva", parent: None },
+    Subdivision { code: "ME-06", name: "Cetinje", parent: None },
+    Subdivision { code: "ME-07", name: "Danilovgrad", parent: None },
+    Subdivision { code: "ME-08", name: "Herceg-Novi", parent: None },
+    Subdivision { code: "ME-09", name: "Kolašin", parent: None },
+    Subdivision { code: "ME-10", name: "Kotor", parent: None },
+    Subdivision { code: "ME-11", name: "Mojkovac", parent: None },
+    Subdivision { code: "ME-12", name: "Nikšić", parent: None },
+    Subdivision { code: "ME-13", name: "Plav", parent: None },
+    Subdivision { code: "ME-14", name: "Pljevlja", parent: None },
+    Subdivision { code: "ME-15", name: "Plužine", parent: None },
+    Subdivision { code: "ME-16", name: "Podgorica", parent: None },
+    Subdivision { code: "ME-17", name: "Rožaje", parent: None },
+    Subdivision { code: "ME-18", name: "Šavnik", parent: None },
+    Subdivision { code: "ME-19", name: "Tivat", parent: None },
+    Subdivision { code: "ME-20", name: "Ulcinj", parent: None },
+    Subdivision { code: "ME-21", name: "Žabljak", parent: None },
+    Subdivision { code: "ME-22", name: "Gusinje", parent: None },
+    Subdivision { code: "ME-23", name: "Petnjica", parent: None },
+    Subdivision { code: "ME-24", name: "Tuzi", parent: None },
+    Subdivision { code: "MG-A", name: "Toamasina", parent: None },
+    Subdivision { code: "MG-D", name: "Antsiranana", parent: None },
+    Subdivision { code: "MG-F", name: "Fianarantsoa", parent: None },
+    Subdivision { code: "MG-M", name: "Mahajanga", parent: None },
+    Subdivision { code: "MG-T", name: "Antananarivo", parent: None },
+    Subdivision { code: "MG-U", name: "Toliara", parent: None },
+    Subdivision { code: "MH-ALK", name: "Ailuk", parent: Some("MH-T") },
+    Subdivision { code: "MH-ALL", name: "Ailinglaplap", parent: Some("MH-L") },
+    Subdivision { code: "MH-ARN", name: "Arno", parent: Some("MH-T") },
+    Subdivision { code: "MH-AUR", name: "Aur", parent: Some("MH-T") },
+    Subdivision { code: "MH-EBO", name: "Ebon", parent: Some("MH-L") },
+    Subdivision { code: "MH-ENI", name: "Enewetak & Ujelang", parent: Some("MH-L") },
+    Subdivision { code: "MH-JAB", name: "Jabat", parent: Some("MH-L") },
+    Subdivision { code: "MH-JAL", name: "Jaluit", parent: Some("MH-L") },
+    Subdivision { code: "MH-KIL", name: "Bikini & Kili", parent: Some("MH-L") },
+    Subdivision { code: "MH-KWA", name: "Kwajalein", parent: Some("MH-L") },
+    Subdivision { code: "MH-L", name: "Ralik chain", parent: None },
+    Subdivision { code: "MH-LAE", name: "Lae", parent: Some("MH-L") },
+    Subdivision { code: "MH-LIB", name: "Lib", parent: Some("MH-L") },
+    Subdivision { code: "MH-LIK", name: "Likiep", parent: Some("MH-T") },
+    Subdivision { code: "MH-MAJ", name: "Majuro", parent: Some("MH-T") },
+    Subdivision { code: "MH-MAL", name: "Maloelap", parent: Some("MH-T") },
+    Subdivision { code: "MH-MEJ", name: "Mejit", parent: Some("MH-T") },
+    Subdivision { code: "MH-MIL", name: "Mili", parent: Some("MH-T") },
+    Subdivision { code: "MH-NMK", name: "Namdrik", parent: Some("MH-L") },
+    Subdivision { code: "MH-NMU", name: "Namu", parent: Some("MH-L") },
+    Subdivision { code: "MH-RON", name: "Rongelap", parent: Some("MH-L") },
+    Subdivision { code: "MH-T", name: "Ratak chain", parent: None },
+    Subdivision { code: "MH-UJA", name: "Ujae", parent: Some("MH-L") },
+    Subdivision { code: "MH-UTI", name: "Utrik", parent: Some("MH-T") },
+    Subdivision { code: "MH-WTH", name: "Wotho", parent: Some("MH-L") },
+    Subdivision { code: "MH-WTJ", name: "Wotje", parent: Some("MH-T") },
+    Subdivision { code: "MK-101", name: "Veles", parent: None },
+    Subdivision { code: "MK-102", name: "Gradsko", parent: None },
+    Subdivision { code: "MK-103", name: "Demir Kapija", parent: None },
+    Subdivision { code: "MK-104", name: "Kavadarci", parent: None },
+    Subdivision { code: "MK-105", name: "Lozovo", parent: None },
+    Subdivision { code: "MK-106", name: "Negotino", parent: None },
+    Subdivision { code: "MK-107", name: "Rosoman", parent: None },
+    Subdivision { code: "MK-108", name: "Sveti Nikole", parent: None },
+    Subdivision { code: "MK-109", name: "Čaška", parent: None },
+    Subdivision { code: "MK-201", name: "Berovo", parent: None },
+    Subdivision { code: "MK-202", name: "Vinica", parent: None },
+    Subdivision { code: "MK-203", name: "Delčevo", parent: None },
+    Subdivision { code: "MK-204", name: "Zrnovci", parent: None },
+    Subdivision { code: "MK-205", name: "Karbinci", parent: None },
+    Subdivision { code: "MK-206", name: "Kočani", parent: None },
+    Subdivision { code: "MK-207", name: "Makedonska Kamenica", parent: None },
+    Subdivision { code: "MK-208", name: "Pehčevo", parent: None },
+    Subdivision { code: "MK-209", name: "Probištip", parent: None },
+    Subdivision { code: "MK-210", name: "Češinovo-Obleševo", parent: None },
+    Subdivision { code: "MK-211", name: "Štip", parent: None },
+    Subdivision { code: "MK-301", name: "Vevčani", parent: None },
+    Subdivision { code: "MK-303", name: "Debar", parent: None },
+    Subdivision { code: "MK-304", name: "Debrca", parent: None },
+    Subdivision { code: "MK-307", name: "Kičevo", parent: None },
+    Subdivision { code: "MK-308", name: "Makedonski Brod", parent: None },
+    Subdivision { code: "MK-310", name: "Ohrid", parent: None },
+    Subdivision { code: "MK-311", name: "Plasnica", parent: None },
+    Subdivision { code: "MK-312", name: "Struga", parent: None },
+    Subdivision { code: "MK-313", name: "Centar Župa", parent: None },
+    Subdivision { code: "MK-401", name: "Bogdanci", parent: None },
+    Subdivision { code: "MK-402", name: "Bosilovo", parent: None },
+    Subdivision { code: "MK-403", name: "Valandovo", parent: None },
+    Subdivision { code: "MK-404", name: "Vasilevo", parent: None },
+    Subdivision { code: "MK-405", name: "Gevgelija", parent: None },
+    Subdivision { code: "MK-406", name: "Dojran", parent: None },
+    Subdivision { code: "MK-407", name: "Konče", parent: None },
+    Subdivision { code: "MK-408", name: "Novo Selo", parent: None },
+    Subdivision { code: "MK-409", name: "Radoviš", parent: None },
+    Subdivision { code: "MK-410", name: "Strumica", parent: None },
+    Subdivision { code: "MK-501", name: "Bitola", parent: None },
+    Subdivision { code: "MK-502", name: "Demir Hisar", parent: None },
+    Subdivision { code: "MK-503", name: "Dolneni", parent: None },
+    Subdivision { code: "MK-504", name: "Krivogaštani", parent: None },
+    Subdivision { code: "MK-505", name: "Kruševo", parent: None },
+    Subdivision { code: "MK-506", name: "Mogila", parent: None },
+    Subdivision { code: "MK-507", name: "Novaci", parent: None },
+    Subdivision { code: "MK-508", name: "Prilep", parent: None },
+    Subdivision { code: "MK-509", name: "Resen", parent: None },
+    Subdivision { code: "MK-601", name: "Bogovinje", parent: None },
+    Subdivision { code: "MK-602", name: "Brvenica", parent: None },
+    Subdivision { code: "MK-603", name: "Vrapčište", parent: None },
+    Subdivision { code: "MK-604", name: "Gostivar", parent: None },
+    Subdivision { code: "MK-605", name: "Želino", parent: None },
+    Subdivision { code: "MK-606", name: "Jegunovce", parent: None },
+    Subdivision { code: "MK-607", name: "Mavrovo i Rostuše", parent: None },
+    Subdivision { code: "MK-608", name: "Tearce", parent: None },
+    Subdivision { code: "MK-609", name: "Tetovo", parent: None },
+    Subdivision { code: "MK-701", name: "Kratovo", parent: None },
+    Subdivision { code: "MK-702", name: "Kriva Palanka", parent: None },
+    Subdivision { code: "MK-703", name: "Kumanovo", parent: None },
+    Subdivision { code: "MK-704", name: "Lipkovo", parent: None },
+    Subdivision { code: "MK-705", name: "Rankovce", parent: None },
+    Subdivision { code: "MK-706", name: "Staro Nagoričane", parent: None },
+    Subdivision { code: "MK-801", name: "Aerodrom †", parent: None },
+    Subdivision { code: "MK-802", name: "Aračinovo", parent: None },
+    Subdivision { code: "MK-803", name: "Butel †", parent: None },
+    Subdivision { code: "MK-804", name: "Gazi Baba †", parent: None },
+    Subdivision { code: "MK-805", name: "Gjorče Petrov †", parent: None },
+    Subdivision { code: "MK-806", name: "Zelenikovo", parent: None },
+    Subdivision { code: "MK-807", name: "Ilinden", parent: None },
+    Subdivision { code: "MK-808", name: "Karpoš †", parent: None },
+    Subdivision { code: "MK-809", name: "Kisela Voda †", parent: None },
+    Subdivision { code: "MK-810", name: "Petrovec", parent: None },
+    Subdivision { code: "MK-811", name: "Saraj †", parent: None },
+    Subdivision { code: "MK-812", name: "Sopište", parent: None },
+    Subdivision { code: "MK-813", name: "Studeničani", parent: None },
+    Subdivision { code: "MK-814", name: "Centar †", parent: None },
+    Subdivision { code: "MK-815", name: "Čair †", parent: None },
+    Subdivision { code: "MK-816", name: "Čučer-Sandevo", parent: None },
+    Subdivision { code: "MK-817", name: "Šuto Orizari †", parent: None },
+    Subdivision { code: "ML-1", name: "Kayes", parent: None },
+    Subdivision { code: "ML-10", name: "Taoudénit", parent: None },
+    Subdivision { code: "ML-2", name: "Koulikoro", parent: None },
+    Subdivision { code: "ML-3", name: "Sikasso", parent: None },
+    Subdivision { code: "ML-4", name: "Ségou", parent: None },
+    Subdivision { code: "ML-5", name: "Mopti", parent: None },
+    Subdivision { code: "ML-6", name: "Tombouctou", parent: None },
+    Subdivision { code: "ML-7", name: "Gao", parent: None },
+    Subdivision { code: "ML-8", name: "Kidal", parent: None },
+    Subdivision { code: "ML-9", name: "Ménaka", parent: None },
+    Subdivision { code: "ML-BKO", name: "Bamako", parent: None },
+    Subdivision { code: "MM-01", name: "Sagaing", parent: None },
+    Subdivision { code: "MM-02", name: "Bago", parent: None },
+    Subdivision { code: "MM-03", name: "Magway", parent: None },
+    Subdivision { code: "MM-04", name: "Mandalay", parent: None },
+    Subdivision { code: "MM-05", name: "Tanintharyi", parent: None },
+    Subdivision { code: "MM-06", name: "Yangon", parent: None },
+    Subdivision { code: "MM-07", name: "Ayeyarwady", parent: None },
+    Subdivision { code: "MM-11", name: "Kachin", parent: None },
+    Subdivision { code: "MM-12", name: "Kayah", parent: None },
+    Subdivision { code: "MM-13", name: "Kayin", parent: None },
+    Subdivision { code: "MM-14", name: "Chin", parent: None },
+    Subdivision { code: "MM-15", name: "Mon", parent: None },
+    Subdivision { code: "MM-16", name: "Rakhine", parent: None },
+    Subdivision { code: "MM-17", name: "Shan", parent: None },
+    Subdivision { code: "MM-18", name: "Nay Pyi Taw", parent: None },
+    Subdivision { code: "MN-035", name: "Orhon", parent: None },
+    Subdivision { code: "MN-037", name: "Darhan uul", parent: None },
+    Subdivision { code: "MN-039", name: "Hentiy", parent: None },
+    Subdivision { code: "MN-041", name: "Hövsgöl", parent: None },
+    Subdivision { code: "MN-043", name: "Hovd", parent: None },
+    Subdivision { code: "MN-046", name: "Uvs", parent: None },
+    Subdivision { code: "MN-047", name: "Töv", parent: None },
+    Subdivision { code: "MN-049", name: "Selenge", parent: None },
+    Subdivision { code: "MN-051", name: "Sühbaatar", parent: None },
+    Subdivision { code: "MN-053", name: "Ömnögovĭ", parent: None },
+    Subdivision { code: "MN-055", name: "Övörhangay", parent: None },
+    Subdivision { code: "MN-057", name: "Dzavhan", parent: None },
+    Subdivision { code: "MN-059", name: "Dundgovĭ", parent: None },
+    Subdivision { code: "MN-061", name: "Dornod", parent: None },
+    Subdivision { code: "MN-063", name: "Dornogovĭ", parent: None },
+    Subdivision { code: "MN-064", name: "Govĭ-Sümber", parent: None },
+    Subdivision { code: "MN-065", name: "Govĭ-Altay", parent: None },
+    Subdivision { code: "MN-067", name: "Bulgan", parent: None },
+    Subdivision { code: "MN-069", name: "Bayanhongor", parent: None },
+    Subdivision { code: "MN-071", name: "Bayan-Ölgiy", parent: None },
+    Subdivision { code: "MN-073", name: "Arhangay", parent: None },
+    Subdivision { code: "MN-1", name: "Ulaanbaatar", parent: None },
+    Subdivision { code: "MR-01", name: "Hodh ech Chargui", parent: None },
+    Subdivision { code: "MR-02", name: "Hodh el Gharbi", parent: None },
+    Subdivision { code: "MR-03", name: "Assaba", parent: None },
+    Subdivision { code: "MR-04", name: "Gorgol", parent: None },
+    Subdivision { code: "MR-05", name: "Brakna", parent: None },
+    Subdivision { code: "MR-06", name: "Trarza", parent: None },
+    Subdivision { code: "MR-07", name: "Adrar", parent: None },
+    Subdivision { code: "MR-08", name: "Dakhlet Nouâdhibou", parent: None },
+    Subdivision { code: "MR-09", name: "Tagant", parent: None },
+    Subdivision { code: "MR-10", name: "Guidimaka", parent: None },
+    Subdivision { code: "MR-11", name: "Tiris Zemmour", parent: None },
+    Subdivision { code: "MR-12", name: "Inchiri", parent: None },
+    Subdivision { code: "MR-13", name: "Nouakchott Ouest", parent: None },
+    Subdivision { code: "MR-14", name: "Nouakchott Nord", parent: None },
+    Subdivision { code: "MR-15", name: "Nouakchott Sud", parent: None },
+    Subdivision { code: "MT-01", name: "Attard", parent: None },
+    Subdivision { code: "MT-02", name: "Balzan", parent: None },
+    Subdivision { code: "MT-03", name: "Birgu", parent: None },
+    Subdivision { code: "MT-04", name: "Birkirkara", parent: None },
+    Subdivision { code: "MT-05", name: "Birżebbuġa", parent: None },
+    Subdivision { code: "MT-06", name: "Bormla", parent: None },
+    Subdivision { code: "MT-07", name: "Dingli", parent: None },
+    Subdivision { code: "MT-08", name: "Fgura", parent: None },
+    Subdivision { code: "MT-09", name: "Floriana", parent: None },
+    Subdivision { code: "MT-10", name: "Fontana", parent: None },
+    Subdivision { code: "MT-11", name: "Gudja", parent: None },
+    Subdivision { code: "MT-12", name: "Gżira", parent: None },
+    Subdivision { code: "MT-13", name: "Għajnsielem", parent: None },
+    Subdivision { code: "MT-14", name: "Għarb", parent: None },
+    Subdivision { code: "MT-15", name: "Għargħur", parent: None },
+    Subdivision { code: "MT-16", name: "Għasri", parent: None },
+    Subdivision { code: "MT-17", name: "Għaxaq", parent: None },
+    Subdivision { code: "MT-18", name: "Ħamrun", parent: None },
+    Subdivision { code: "MT-19", name: "Iklin", parent: None },
+    Subdivision { code: "MT-20", name: "Isla", parent: None },
+    Subdivision { code: "MT-21", name: "Kalkara", parent: None },
+    Subdivision { code: "MT-22", name: "Kerċem", parent: None },
+    Subdivision { code: "MT-23", name: "Kirkop", parent: None },
+    Subdivision { code: "MT-24", name: "Lija", parent: None },
+    Subdivision { code: "MT-25", name: "Luqa", parent: None },
+    Subdivision { code: "MT-26", name: "Marsa", parent: None },
+    Subdivision { code: "MT-27", name: "Marsaskala", parent: None },
+    Subdivision { code: "MT-28", name: "Marsaxlokk", parent: None },
+    Subdivision { code: "MT-29", name: "Mdina", parent: None },
+    Subdivision { code: "MT-30", name: "Mellieħa", parent: None },
+    Subdivision { code: "MT-31", name: "Mġarr", parent: None },
+    Subdivision { code: "MT-32", name: "Mosta", parent: None },
+    Subdivision { code: "MT-33", name: "Mqabba", parent: None },
+    Subdivision { code: "MT-34", name: "Msida", parent: None },
+    Subdivision { code: "MT-35", name: "Mtarfa", parent: None },
+    Subdivision { code: "MT-36", name: "Munxar", parent: None },
+    Subdivision { code: "MT-37", name: "Nadur", parent: None },
+    Subdivision { code: "MT-38", name: "Naxxar", parent: None },
+    Subdivision { code: "MT-39", name: "Paola", parent: None },
+    Subdivision { code: "MT-40", name: "Pembroke", parent: None },
+    Subdivision { code: "MT-41", name: "Pietà", parent: None },
+    Subdivision { code: "MT-42", name: "Qala", parent: None },
+    Subdivision { code: "MT-43", name: "Qormi", parent: None },
+    Subdivision { code: "MT-44", name: "Qrendi", parent: None },
+    Subdivision { code: "MT-45", name: "Rabat Gozo", parent: None },
+    Subdivision { code: "MT-46", name: "Rabat Malta", parent: None },
+    Subdivision { code: "MT-47", name: "Safi", parent: None },
+    Subdivision { code: "MT-48", name: "Saint Julian's", parent: None },
+    Subdivision { code: "MT-49", name: "Saint John", parent: None },
+    Subdivision { code: "MT-50", name: "Saint Lawrence", parent: None },
+    Subdivision { code: "MT-51", name: "Saint Paul's Bay", parent: None },
+    Subdivision { code: "MT-52", name: "Sannat", parent: None },
+    Subdivision { code: "MT-53", name: "Saint Lucia's", parent: None },
+    Subdivision { code: "MT-54", name: "Santa Venera", parent: None },
+    Subdivision { code: "MT-55", name: "Siġġiewi", parent: None },
+    Subdivision { code: "MT-56", name: "Sliema", parent: None },
+    Subdivision { code: "MT-57", name: "Swieqi", parent: None },
+    Subdivision { code: "MT-58", name: "Ta' Xbiex", parent: None },
+    Subdivision { code: "MT-59", name: "Tarxien", parent: None },
+    Subdivision { code: "MT-60", name: "Valletta", parent: None },
+    Subdivision { code: "MT-61", name: "Xagħra", parent: None },
+    Subdivision { code: "MT-62", name: "Xewkija", parent: None },
+    Subdivision { code: "MT-63", name: "Xgħajra", parent: None },
+    Subdivision { code: "MT-64", name: "Żabbar", parent: None },
+    Subdivision { code: "MT-65", name: "Żebbuġ Gozo", parent: None },
+    Subdivision { code: "MT-66", name: "Żebbuġ Malta", parent: None },
+    Subdivision { code: "MT-67", name: "Żejtun", parent: None },
+    Subdivision { code: "MT-68", name: "Żurrieq", parent: None },
+    Subdivision { code: "MU-AG", name: "Agalega Islands", parent: None },
+    Subdivision { code: "MU-BL", name: "Black River", parent: None },
+    Subdivision { code: "MU-CC", name: "Cargados Carajos Shoals", parent: None },
+    Subdivision { code: "MU-FL", name: "Flacq", parent: None },
+    Subdivision { code: "MU-GP", name: "Grand Port", parent: None },
+    Subdivision { code: "MU-MO", name: "Moka", parent: None },
+    Subdivision { code: "MU-PA", name: "Pamplemousses", parent: None },
+    Subdivision { code: "MU-PL", name: "Port Louis", parent: None },
+    Subdivision { code: "MU-PW", name: "Plaines Wilhems", parent: None },
+    Subdivision { code: "MU-RO", name: "Rodrigues Island", parent: None },
+    Subdivision { code: "MU-RR", name: "Rivière du Rempart", parent: None },
+    Subdivision { code: "MU-SA", name: "Savanne", parent: None },
+    Subdivision { code: "MV-00", name: "South Ari Atoll", parent: None },
+    Subdivision { code: "MV-01", name: "Addu City", parent: None },
+    Subdivision { code: "MV-02", name: "North Ari Atoll", parent: None },
+    Subdivision { code: "MV-03", name: "Faadhippolhu", parent: None },
+    Subdivision { code: "MV-04", name: "Felidhu Atoll", parent: None },
+    Subdivision { code: "MV-05", name: "Hahdhunmathi", parent: None },
+    Subdivision { code: "MV-07", name: "North Thiladhunmathi", parent: None },
+    Subdivision { code: "MV-08", name: "Kolhumadulu", parent: None },
+    Subdivision { code: "MV-12", name: "Mulaku Atoll", parent: None },
+    Subdivision { code: "MV-13", name: "North Maalhosmadulu", parent: None },
+    Subdivision { code: "MV-14", name: "North Nilandhe Atoll", parent: None },
+    Subdivision { code: "MV-17", name: "South Nilandhe Atoll", parent: None },
+    Subdivision { code: "MV-20", name: "South Maalhosmadulu", parent: None },
+    Subdivision { code: "MV-23", name: "South Thiladhunmathi", parent: None },
+    Subdivision { code: "MV-24", name: "North Miladhunmadulu", parent: None },
+    Subdivision { code: "MV-25", name: "South Miladhunmadulu", parent: None },
+    Subdivision { code: "MV-26", name: "Male Atoll", parent: None },
+    Subdivision { code: "MV-27", name: "North Huvadhu Atoll", parent: None },
+    Subdivision { code: "MV-28", name: "South Huvadhu Atoll", parent: None },
+    Subdivision { code: "MV-29", name: "Fuvammulah", parent: None },
+    Subdivision { code: "MV-MLE", name: "Male", parent: None },
+    Subdivision { code: "MW-BA", name: "Balaka", parent: Some("MW-S") },
+    Subdivision { code: "MW-BL", name: "Blantyre", parent: Some("MW-S") },
+    Subdivision { code: "MW-C", name: "Central Region", parent: None },
+    Subdivision { code: "MW-CK", name: "Chikwawa", parent: Some("MW-S") },
+    Subdivision { code: "MW-CR", name: "Chiradzulu", parent: Some("MW-S") },
+    Subdivision { code: "MW-CT", name: "Chitipa", parent: Some("MW-N") },
+    Subdivision { code: "MW-DE", name: "Dedza", parent: Some("MW-C") },
+    Subdivision { code: "MW-DO", name: "Dowa", parent: Some("MW-C") },
+    Subdivision { code: "MW-KR", name: "Karonga", parent: Some("MW-N") },
+    Subdivision { code: "MW-KS", name: "Kasungu", parent: Some("MW-C") },
+    Subdivision { code: "MW-LI", name: "Lilongwe", parent: Some("MW-C") },
+    Subdivision { code: "MW-LK", name: "Likoma", parent: Some("MW-N") },
+    Subdivision { code: "MW-MC", name: "Mchinji", parent: Some("MW-C") },
+    Subdivision { code: "MW-MG", name: "Mangochi", parent: Some("MW-S") },
+    Subdivision { code: "MW-MH", name: "Machinga", parent: Some("MW-S") },
+    Subdivision { code: "MW-MU", name: "Mulanje", parent: Some("MW-S") },
+    Subdivision { code: "MW-MW", name: "Mwanza", parent: Some("MW-S") },
+    Subdivision { code: "MW-MZ", name: "Mzimba", parent: Some("MW-N") },
+    Subdivision { code: "MW-N", name: "Northern Region", parent: None },
+    Subdivision { code: "MW-NB", name: "Nkhata Bay", parent: Some("MW-N") },
+    Subdivision { code: "MW-NE", name: "Neno", parent: Some("MW-S") },
+    Subdivision { code: "MW-NI", name: "Ntchisi", parent: Some("MW-C") },
+    Subdivision { code: "MW-NK", name: "Nkhotakota", parent: Some("MW-C") },
+    Subdivision { code: "MW-NS", name: "Nsanje", parent: Some("MW-S") },
+    Subdivision { code: "MW-NU", name: "Ntcheu", parent: Some("MW-C") },
+    Subdivision { code: "MW-PH", name: "Phalombe", parent: Some("MW-S") },
+    Subdivision { code: "MW-RU", name: "Rumphi", parent: Some("MW-N") },
+    Subdivision { code: "MW-S", name: "Southern Region", parent: None },
+    Subdivision { code: "MW-SA", name: "Salima", parent: Some("MW-C") },
+    Subdivision { code: "MW-TH", name: "Thyolo", parent: Some("MW-S") },
+    Subdivision { code: "MW-ZO", name: "Zomba", parent: Some("MW-S") },
+    Subdivision { code: "MX-AGU", name: "Aguascalientes", parent: None },
+    Subdivision { code: "MX-BCN", name: "Baja California", parent: None },
+    Subdivision { code: "MX-BCS", name: "Baja California Sur", parent: None },
+    Subdivision { code: "MX-CAM", name: "Campeche", parent: None },
+    Subdivision { code: "MX-CHH", name: "Chihuahua", parent: None },
+    Subdivision { code: "MX-CHP", name: "Chiapas", parent: None },
+    Subdivision { code: "MX-CMX", name: "Ciudad de México", parent: None },
+    Subdivision { code: "MX-COA", name: "Coahuila de Zaragoza", parent: None },
+    Subdivision { code: "MX-COL", name: "Colima", parent: None },
+    Subdivision { code: "MX-DUR", name: "Durango", parent: None },
+    Subdivision { code: "MX-GRO", name: "Guerrero", parent: None },
+    Subdivision { code: "MX-GUA", name: "Guanajuato", parent: None },
+    Subdivision { code: "MX-HID", name: "Hidalgo", parent: None },
+    Subdivision { code: "MX-JAL", name: "Jalisco", parent: None },
+    Subdivision { code: "MX-MEX", name: "México", parent: None },
+    Subdivision { code: "MX-MIC", name: "Michoacán de Ocampo", parent: None },
+    Subdivision { code: "MX-MOR", name: "Morelos", parent: None },
+    Subdivision { code: "MX-NAY", name: "Nayarit", parent: None },
+    Subdivision { code: "MX-NLE", name: "Nuevo León", parent: None },
+    Subdivision { code: "MX-OAX", name: "Oaxaca", parent: None },
+    Subdivision { code: "MX-PUE", name: "Puebla", parent: None },
+    Subdivision { code: "MX-QUE", name: "Querétaro", parent: None },
+    Subdivision { code: "MX-ROO", name: "Quintana Roo", parent: None },
+    Subdivision { code: "MX-SIN", name: "Sinaloa", parent: None },
+    Subdivision { code: "MX-SLP", name: "San Luis Potosí", parent: None },
+    Subdivision { code: "MX-SON", name: "Sonora", parent: None },
+    Subdivision { code: "MX-TAB", name: "Tabasco", parent: None },
+    Subdivision { code: "MX-TAM", name: "Tamaulipas", parent: None },
+    Subdivision { code: "MX-TLA", name: "Tlaxcala", parent: None },
+    Subdivision { code: "MX-VER", name: "Veracruz de Ignacio de la Llave", parent: None },
+    Subdivision { code: "MX-YUC", name: "Yucatán", parent: None },
+    Subdivision { code: "MX-ZAC", name: "Zacatecas", parent: None },
+    Subdivision { code: "MY-01", name: "Johor", parent: None },
+    Subdivision { code: "MY-02", name: "Kedah", parent: None },
+    Subdivision { code: "MY-03", name: "Kelantan", parent: None },
+    Subdivision { code: "MY-04", name: "Melaka", parent: None },
+    Subdivision { code: "MY-05", name: "Negeri Sembilan", parent: None },
+    Subdivision { code: "MY-06", name: "Pahang", parent: None },
+    Subdivision { code: "MY-07", name: "Pulau Pinang", parent: None },
+    Subdivision { code: "MY-08", name: "Perak", parent: None },
+    Subdivision { code: "MY-09", name: "Perlis", parent: None },
+    Subdivision { code: "MY-10", name: "Selangor", parent: None },
+    Subdivision { code: "MY-11", name: "Terengganu", parent: None },
+    Subdivision { code: "MY-12", name: "Sabah", parent: None },
+    Subdivision { code: "MY-13", name: "Sarawak", parent: None },
+    Subdivision { code: "MY-14", name: "Wilayah Persekutuan Kuala Lumpur", parent: None },
+    Subdivision { code: "MY-15", name: "Wilayah Persekutuan Labuan", parent: None },
+    Subdivision { code: "MY-16", name: "Wilayah Persekutuan Putrajaya", parent: None },
+    Subdivision { code: "MZ-A", name: "Niassa", parent: None },
+    Subdivision { code: "MZ-B", name: "Manica", parent: None },
+    Subdivision { code: "MZ-G", name: "Gaza", parent: None },
+    Subdivision { code: "MZ-I", name: "Inhambane", parent: None },
+    Subdivision { code: "MZ-L", name: "Maputo", parent: None },
+    Subdivision { code: "MZ-MPM", name: "Maputo", parent: None },
+    Subdivision { code: "MZ-N", name: "Nampula", parent: None },
+    Subdivision { code: "MZ-P", name: "Cabo Delgado", parent: None },
+    Subdivision { code: "MZ-Q", name: "Zambézia", parent: None },
+    Subdivision { code: "MZ-S", name: "Sofala", parent: None },
+    Subdivision { code: "MZ-T", name: "Tete", parent: None },
+    Subdivision { code: "NA-CA", name: "Zambezi", parent: None },
+    Subdivision { code: "NA-ER", name: "Erongo", parent: None },
+    Subdivision { code: "NA-HA", name: "Hardap", parent: None },
+    Subdivision { code: "NA-KA", name: "//Karas", parent: None },
+    Subdivision { code: "NA-KE", name: "Kavango East", parent: None },
+    Subdivision { code: "NA-KH", name: "Khomas", parent: None },
+    Subdivision { code: "NA-KU", name: "Kunene", parent: None },
+    Subdivision { code: "NA-KW", name: "Kavango West", parent: None },
+    Subdivision { code: "NA-OD", name: "Otjozondjupa", parent: None },
+    Subdivision { code: "NA-OH", name: "Omaheke", parent: None },
+    Subdivision { code: "NA-ON", name: "Oshana", parent: None },
+    Subdivision { code: "NA-OS", name: "Omusati", parent: None },
+    Subdivision { code: "NA-OT", name: "Oshikoto", parent: None },
+    Subdivision { code: "NA-OW", name: "Ohangwena", parent: None },
+    Subdivision { code: "NE-1", name: "Agadez", parent: None },
+    Subdivision { code: "NE-2", name: "Diffa", parent: None },
+    Subdivision { code: "NE-3", name: "Dosso", parent: None },
+    Subdivision { code: "NE-4", name: "Maradi", parent: None },
+    Subdivision { code: "NE-5", name: "Tahoua", parent: None },
+    Subdivision { code: "NE-6", name: "Tillabéri", parent: None },
+    Subdivision { code: "NE-7", name: "Zinder", parent: None },
+    Subdivision { code: "NE-8", name: "Niamey", parent: None },
+    Subdivision { code: "NG-AB", name: "Abia", parent: None },
+    Subdivision { code: "NG-AD", name: "Adamawa", parent: None },
+    Subdivision { code: "NG-AK", name: "Akwa Ibom", parent: None },
+    Subdivision { code: "NG-AN", name: "Anambra", parent: None },
+    Subdivision { code: "NG-BA", name: "Bauchi", parent: None },
+    Subdivision { code: "NG-BE", name: "Benue", parent: None },
+    Subdivision { code: "NG-BO", name: "Borno", parent: None },
+    Subdivision { code: "NG-BY", name: "Bayelsa", parent: None },
+    Subdivision { code: "NG-CR", name: "Cross River", parent: None },
+    Subdivision { code: "NG-DE", name: "Delta", parent: None },
+    Subdivision { code: "NG-EB", name: "Ebonyi", parent: None },
+    Subdivision { code: "NG-ED", name: "Edo", parent: None },
+    Subdivision { code: "NG-EK", name: "Ekiti", parent: None },
+    Subdivision { code: "NG-EN", name: "Enugu", parent: None },
+    Subdivision { code: "NG-FC", name: "Abuja Federal Capital Territory", parent: None },
+    Subdivision { code: "NG-GO", name: "Gombe", parent: None },
+    Subdivision { code: "NG-IM", name: "Imo", parent: None },
+    Subdivision { code: "NG-JI", name: "Jigawa", parent: None },
+    Subdivision { code: "NG-KD", name: "Kaduna", parent: None },
+    Subdivision { code: "NG-KE", name: "Kebbi", parent: None },
+    Subdivision { code: "NG-KN", name: "Kano", parent: None },
+    Subdivision { code: "NG-KO", name: "Kogi", parent: None },
+    Subdivision { code: "NG-KT", name: "Katsina", parent: None },
+    Subdivision { code: "NG-KW", name: "Kwara", parent: None },
+    Subdivision { code: "NG-LA", name: "Lagos", parent: None },
+    Subdivision { code: "NG-NA", name: "Nasarawa", parent: None },
+    Subdivision { code: "NG-NI", name: "Niger", parent: None },
+    Subdivision { code: "NG-OG", name: "Ogun", parent: None },
+    Subdivision { code: "NG-ON", name: "Ondo", parent: None },
+    Subdivision { code: "NG-OS", name: "Osun", parent: None },
+    Subdivision { code: "NG-OY", name: "Oyo", parent: None },
+    Subdivision { code: "NG-PL", name: "Plateau", parent: None },
+    Subdivision { code: "NG-RI", name: "Rivers", parent: None },
+    Subdivision { code: "NG-SO", name: "Sokoto", parent: None },
+    Subdivision { code: "NG-TA", name: "Taraba", parent: None },
+    Subdivision { code: "NG-YO", name: "Yobe", parent: None },
+    Subdivision { code: "NG-ZA", name: "Zamfara", parent: None },
+    Subdivision { code: "NI-AN", name: "Costa Caribe Norte", parent: None },
+    Subdivision { code: "NI-AS", name: "Costa Caribe Sur", parent: None },
+    Subdivision { code: "NI-BO", name: "Boaco", parent: None },
+    Subdivision { code: "NI-CA", name: "Carazo", parent: None },
+    Subdivision { code: "NI-CI", name: "Chinandega", parent: None },
+    Subdivision { code: "NI-CO", name: "Chontales", parent: None },
+    Subdivision { code: "NI-ES", name: "Estelí", parent: None },
+    Subdivision { code: "NI-GR", name: "Granada", parent: None },
+    Subdivision { code: "NI-JI", name: "Jinotega", parent: None },
+    Subdivision { code: "NI-LE", name: "León", parent: None },
+    Subdivision { code: "NI-MD", name: "Madriz", parent: None },
+    Subdivision { code: "NI-MN", name: "Managua", parent: None },
+    Subdivision { code: "NI-MS", name: "Masaya", parent: None },
+    Subdivision { code: "NI-MT", name: "Matagalpa", parent: None },
+    Subdivision { code: "NI-NS", name: "Nueva Segovia", parent: None },
+    Subdivision { code: "NI-RI", name: "Rivas", parent: None },
+    Subdivision { code: "NI-SJ", name: "Río San Juan", parent: None },
+    Subdivision { code: "NL-AW", name: "Aruba", parent: None },
+    Subdivision { code: "NL-BQ1", name: "Bonaire", parent: None },
+    Subdivision { code: "NL-BQ2", name: "Saba", parent: None },
+    Subdivision { code: "NL-BQ3", name: "Sint Eustatius", parent: None },
+    Subdivision { code: "NL-CW", name: "Curaçao", parent: None },
+    Subdivision { code: "NL-DR", name: "Drenthe", parent: None },
+    Subdivision { code: "NL-FL", name: "Flevoland", parent: None },
+    Subdivision { code: "NL-FR", name: "Fryslân", parent: None },
+    Subdivision { code: "NL-GE", name: "Gelderland", parent: None },
+    Subdivision { code: "NL-GR", name: "Groningen", parent: None },
+    Subdivision { code: "NL-LI", name: "Limburg", parent: None },
+    Subdivision { code: "NL-NB", name: "Noord-Brabant", parent: None },
+    Subdivision { code: "NL-NH", name: "Noord-Holland", parent: None },
+    Subdivision { code: "NL-OV", name: "Overijssel", parent: None },
+    Subdivision { code: "NL-SX", name: "Sint Maarten", parent: None },
+    Subdivision { code: "NL-UT", name: "Utrecht", parent: None },
+    Subdivision { code: "NL-ZE", name: "Zeeland", parent: None },
+    Subdivision { code: "NL-ZH", name: "Zuid-Holland", parent: None },
+    Subdivision { code: "NO-03", name: "Oslo", parent: None },
+    Subdivision { code: "NO-11", name: "Rogaland", parent: None },
+    Subdivision { code: "NO-15", name: "Møre og Romsdal", parent: None },
+    Subdivision { code: "NO-18", name: "Nordland", parent: None },
+    Subdivision { code: "NO-21", name: "Svalbard (Arctic Region)", parent: None },
+    Subdivision { code: "NO-22", name: "Jan Mayen (Arctic Region)", parent: None },
+    Subdivision { code: "NO-30", name: "Viken", parent: None },
+    Subdivision { code: "NO-34", name: "Innlandet", parent: None },
+    Subdivision { code: "NO-38", name: "Vestfold og Telemark", parent: None },
+    Subdivision { code: "NO-42", name: "Agder", parent: None },
+    Subdivision { code: "NO-46", name: "Vestland", parent: None },
+    Subdivision { code: "NO-50", name: "Trööndelage", parent: None },
+    Subdivision { code: "NO-54", name: "Romssa ja Finnmárkku", parent: None },
+    Subdivision { code: "NP-1", name: "Central", parent: None },
+    Subdivision { code: "NP-2", name: "Mid Western", parent: None },
+    Subdivision { code: "NP-3", name: "Western", parent: None },
+    Subdivision { code: "NP-4", name: "Eastern", parent: None },
+    Subdivision { code: "NP-5", name: "Far Western", parent: None },
+    Subdivision { code: "NP-BA", name: "Bagmati", parent: Some("NP-1") },
+    Subdivision { code: "NP-BH", name: "Bheri", parent: Some("NP-2") },
+    Subdivision { code: "NP-DH", name: "Dhawalagiri", parent: Some("NP-3") },
+    Subdivision { code: "NP-GA", name: "Gandaki", parent: Some("NP-3") },
+    Subdivision { code: "NP-JA", name: "Janakpur", parent: Some("NP-1") },
+    Subdivision { code: "NP-KA", name: "Karnali", parent: Some("NP-2") },
+    Subdivision { code: "NP-KO", name: "Kosi", parent: Some("NP-4") },
+    Subdivision { code: "NP-LU", name: "Lumbini", parent: Some("NP-3") },
+    Subdivision { code: "NP-MA", name: "Mahakali", parent: Some("NP-5") },
+    Subdivision { code: "NP-ME", name: "Mechi", parent: Some("NP-4") },
+    Subdivision { code: "NP-NA", name: "Narayani", parent: Some("NP-1") },
+    Subdivision { code: "NP-P1", name: "Province 1", parent: None },
+    Subdivision { code: "NP-P2", name: "Province 2", parent: None },
+    Subdivision { code: "NP-P3", name: "Bāgmatī", parent: None },
+    Subdivision { code: "NP-P4", name: "Gandaki", parent: None },
+    Subdivision { code: "NP-P5", name: "Province 5", parent: None },
+    Subdivision { code: "NP-P6", name: "Karnali", parent: None },
+    Subdivision { code: "NP-P7", name: "Sudūr Pashchim", parent: None },
+    Subdivision { code: "NP-RA", name: "Rapti", parent: Some("NP-2") },
+    Subdivision { code: "NP-SA", name: "Sagarmatha", parent: Some("NP-4") },
+    Subdivision { code: "NP-SE", name: "Seti", parent: Some("NP-5") },
+    Subdivision { code: "NR-01", name: "Aiwo", parent: None },
+    Subdivision { code: "NR-02", name: "Anabar", parent: None },
+    Subdivision { code: "NR-03", name: "Anetan", parent: None },
+    Subdivision { code: "NR-04", name: "Anibare", parent: None },
+    Subdivision { code: "NR-05", name: "Baitsi", parent: None },
+    Subdivision { code: "NR-06", name: "Boe", parent: None },
+    Subdivision { code: "NR-07", name: "Buada", parent: None },
+    Subdivision { code: "NR-08", name: "Denigomodu", parent: None },
+    Subdivision { code: "NR-09", name: "Ewa", parent: None },
+    Subdivision { code: "NR-10", name: "Ijuw", parent: None },
+    Subdivision { code: "NR-11", name: "Meneng", parent: None },
+    Subdivision { code: "NR-12", name: "Nibok", parent: None },
+    Subdivision { code: "NR-13", name: "Uaboe", parent: None },
+    Subdivision { code: "NR-14", name: "Yaren", parent: None },
+    Subdivision { code: "NZ-AUK", name: "Auckland", parent: None },
+    Subdivision { code: "NZ-BOP", name: "Bay of Plenty", parent: None },
+    Subdivision { code: "NZ-CAN", name: "Canterbury", parent: None },
+    Subdivision { code: "NZ-CIT", name: "Chatham Islands Territory", parent: None },
+    Subdivision { code: "NZ-GIS", name: "Gisborne", parent: None },
+    Subdivision { code: "NZ-HKB", name: "Hawke's Bay", parent: None },
+    Subdivision { code: "NZ-MBH", name: "Marlborough", parent: None },
+    Subdivision { code: "NZ-MWT", name: "Manawatu-Wanganui", parent: None },
+    Subdivision { code: "NZ-NSN", name: "Nelson", parent: None },
+    Subdivision { code: "NZ-NTL", name: "Northland", parent: None },
+    Subdivision { code: "NZ-OTA", name: "Otago", parent: None },
+    Subdivision { code: "NZ-STL", name: "Southland", parent: None },
+    Subdivision { code: "NZ-TAS", name: "Tasman", parent: None },
+    Subdivision { code: "NZ-TKI", name: "Taranaki", parent: None },
+    Subdivision { code: "NZ-WGN", name: "Wellington", parent: None },
+    Subdivision { code: "NZ-WKO", name: "Waikato", parent: None },
+    Subdivision { code: "NZ-WTC", name: "West Coast", parent: None },
+    Subdivision { code: "OM-BJ", name: "Janūb al Bāţinah", parent: None },
+    Subdivision { code: "OM-BS", name: "Shamāl al Bāţinah", parent: None },
+    Subdivision { code: "OM-BU", name: "Al Buraymī", parent: None },
+    Subdivision { code: "OM-DA", name: "Ad Dākhilīyah", parent: None },
+    Subdivision { code: "OM-MA", name: "Masqaţ", parent: None },
+    Subdivision { code: "OM-MU", name: "Musandam", parent: None },
+    Subdivision { code: "OM-SJ", name: "Janūb ash Sharqīyah", parent: None },
+    Subdivision { code: "OM-SS", name: "Shamāl ash Sharqīyah", parent: None },
+    Subdivision { code: "OM-WU", name: "Al Wusţá", parent: None },
+    Subdivision { code: "OM-ZA", name: "Az̧ Z̧āhirah", parent: None },
+    Subdivision { code: "OM-ZU", name: "Z̧ufār", parent: None },
+    Subdivision { code: "PA-1", name: "Bocas del Toro", parent: None },
+    Subdivision { code: "PA-10", name: "Panamá Oeste", parent: None },
+    Subdivision { code: "PA-2", name: "Coclé", parent: None },
+    Subdivision { code: "PA-3", name: "Colón", parent: None },
+    Subdivision { code: "PA-4", name: "Chiriquí", parent: None },
+    Subdivision { code: "PA-5", name: "Darién", parent: None },
+    Subdivision { code: "PA-6", name: "Herrera", parent: None },
+    Subdivision { code: "PA-7", name: "Los Santos", parent: None },
+    Subdivision { code: "PA-8", name: "Panamá", parent: None },
+    Subdivision { code: "PA-9", name: "Veraguas", parent: None },
+    Subdivision { code: "PA-EM", name: "Emberá", parent: None },
+    Subdivision { code: "PA-KY", name: "Guna Yala", parent: None },
+    Subdivision { code: "PA-NB", name: "Ngöbe-Buglé", parent: None },
+    Subdivision { code: "PE-AMA", name: "Amarumayu", parent: None },
+    Subdivision { code: "PE-ANC", name: "Ancash", parent: None },
+    Subdivision { code: "PE-APU", name: "Apurimaq", parent: None },
+    Subdivision { code: "PE-ARE", name: "Arequipa", parent: None },
+    Subdivision { code: "PE-AYA", name: "Ayacucho", parent: None },
+    Subdivision { code: "PE-CAJ", name: "Cajamarca", parent: None },
+    Subdivision { code: "PE-CAL", name: "El Callao", parent: None },
+    Subdivision { code: "PE-CUS", name: "Cusco", parent: None },
+    Subdivision { code: "PE-HUC", name: "Huánuco", parent: None },
+    Subdivision { code: "PE-HUV", name: "Huancavelica", parent: None },
+    Subdivision { code: "PE-ICA", name: "Ica", parent: None },
+    Subdivision { code: "PE-JUN", name: "Hunin", parent: None },
+    Subdivision { code: "PE-LAL", name: "La Libertad", parent: None },
+    Subdivision { code: "PE-LAM", name: "Lambayeque", parent: None },
+    Subdivision { code: "PE-LIM", name: "Lima", parent: None },
+    Subdivision { code: "PE-LMA", name: "Lima hatun llaqta", parent: None },
+    Subdivision { code: "PE-LOR", name: "Loreto", parent: None },
+    Subdivision { code: "PE-MDD", name: "Madre de Dios", parent: None },
+    Subdivision { code: "PE-MOQ", name: "Moquegua", parent: None },
+    Subdivision { code: "PE-PAS", name: "Pasco", parent: None },
+    Subdivision { code: "PE-PIU", name: "Piura", parent: None },
+    Subdivision { code: "PE-PUN", name: "Puno", parent: None },
+    Subdivision { code: "PE-SAM", name: "San Martin", parent: None },
+    Subdivision { code: "PE-TAC", name: "Tacna", parent: None },
+    Subdivision { code: "PE-TUM", name: "Tumbes", parent: None },
+    Subdivision { code: "PE-UCA", name: "Ucayali", parent: None },
+    Subdivision { code: "PG-CPK", name: "Chimbu", parent: None },
+    Subdivision { code: "PG-CPM", name: "Central", parent: None },
+    Subdivision { code: "PG-EBR", name: "East New Britain", parent: None },
+    Subdivision { code: "PG-EHG", name: "Eastern Highlands", parent: None },
+    Subdivision { code: "PG-EPW", name: "Enga", parent: None },
+    Subdivision { code: "PG-ESW", name: "East Sepik", parent: None },
+    Subdivision { code: "PG-GPK", name: "Gulf", parent: None },
+    Subdivision { code: "PG-HLA", name: "Hela", parent: None },
+    Subdivision { code: "PG-JWK", name: "Jiwaka", parent: None },
+    Subdivision { code: "PG-MBA", name: "Milne Bay", parent: None },
+    Subdivision { code: "PG-MPL", name: "Morobe", parent: None },
+    Subdivision { code: "PG-MPM", name: "Madang", parent: None },
+    Subdivision { code: "PG-MRL", name: "Manus", parent: None },
+    Subdivision { code: "PG-NCD", name: "National Capital District (Port Moresby)", parent: None },
+    Subdivision { code: "PG-NIK", name: "New Ireland", parent: None },
+    Subdivision { code: "PG-NPP", name: "Northern", parent: None },
+    Subdivision { code: "PG-NSB", name: "Bougainville", parent: None },
+    Subdivision { code: "PG-SAN", name: "West Sepik", parent: None },
+    Subdivision { code: "PG-SHM", name: "Southern Highlands", parent: None },
+    Subdivision { code: "PG-WBK", name: "West New Britain", parent: None },
+    Subdivision { code: "PG-WHM", name: "Western Highlands", parent: None },
+    Subdivision { code: "PG-WPD", name: "Western", parent: None },
+    Subdivision { code: "PH-00", name: "National Capital Region", parent: None },
+    Subdivision { code: "PH-01", name: "Ilocos (Region I)", parent: None },
+    Subdivision { code: "PH-02", name: "Cagayan Valley (Region II)", parent: None },
+    Subdivision { code: "PH-03", name: "Central Luzon (Region III)", parent: None },
+    Subdivision { code: "PH-05", name: "Bicol (Region V)", parent: None },
+    Subdivision { code: "PH-06", name: "Western Visayas (Region VI)", parent: None },
+    Subdivision { code: "PH-07", name: "Central Visayas (Region VII)", parent: None },
+    Subdivision { code: "PH-08", name: "Eastern Visayas (Region VIII)", parent: None },
+    Subdivision { code: "PH-09", name: "Zamboanga Peninsula (Region IX)", parent: None },
+    Subdivision { code: "PH-10", name: "Northern Mindanao (Region X)", parent: None },
+    Subdivision { code: "PH-11", name: "Davao (Region XI)", parent: None },
+    Subdivision { code: "PH-12", name: "Soccsksargen (Region XII)", parent: None },
+    Subdivision { code: "PH-13", name: "Caraga (Region XIII)", parent: None },
+    Subdivision { code: "PH-14", name: "Autonomous Region in Muslim Mindanao (ARMM)", parent: None },
+    Subdivision { code: "PH-15", name: "Cordillera Administrative Region (CAR)", parent: None },
+    Subdivision { code: "PH-40", name: "Calabarzon (Region IV-A)", parent: None },
+    Subdivision { code: "PH-41", name: "Mimaropa (Region IV-B)", parent: None },
+    Subdivision { code: "PH-ABR", name: "Abra", parent: Some("PH-15") },
+    Subdivision { code: "PH-AGN", name: "Agusan del Norte", parent: Some("PH-13") },
+    Subdivision { code: "PH-AGS", name: "Agusan del Sur", parent: Some("PH-13") },
+    Subdivision { code: "PH-AKL", name: "Aklan", parent: Some("PH-06") },
+    Subdivision { code: "PH-ALB", name: "Albay", parent: Some("PH-05") },
+    Subdivision { code: "PH-ANT", name: "Antique", parent: Some("PH-06") },
+    Subdivision { code: "PH-APA", name: "Apayao", parent: Some("PH-15") },
+    Subdivision { code: "PH-AUR", name: "Aurora", parent: Some("PH-03") },
+    Subdivision { code: "PH-BAN", name: "Bataan", parent: Some("PH-03") },
+    Subdivision { code: "PH-BAS", name: "Basilan", parent: Some("PH-09") },
+    Subdivision { code: "PH-BEN", name: "Benguet", parent: Some("PH-15") },
+    Subdivision { code: "PH-BIL", name: "Biliran", parent: Some("PH-08") },
+    Subdivision { code: "PH-BOH", name: "Bohol", parent: Some("PH-07") },
+    Subdivision { code: "PH-BTG", name: "Batangas", parent: Some("PH-40") },
+    Subdivision { code: "PH-BTN", name: "Batanes", parent: Some("PH-02") },
+    Subdivision { code: "PH-BUK", name: "Bukidnon", parent: Some("PH-10") },
+    Subdivision { code: "PH-BUL", name: "Bulacan", parent: Some("PH-03") },
+    Subdivision { code: "PH-CAG", name: "Cagayan", parent: Some("PH-02") },
+    Subdivision { code: "PH-CAM", name: "Camiguin", parent: Some("PH-10") },
+    Subdivision { code: "PH-CAN", name: "Camarines Norte", parent: Some("PH-05") },
+    Subdivision { code: "PH-CAP", name: "Capiz", parent: Some("PH-06") },
+    Subdivision { code: "PH-CAS", name: "Camarines Sur", parent: Some("PH-05") },
+    Subdivision { code: "PH-CAT", name: "Catanduanes", parent: Some("PH-05") },
+    Subdivision { code: "PH-CAV", name: "Cavite", parent: Some("PH-40") },
+    Subdivision { code: "PH-CEB", name: "Cebu", parent: Some("PH-07") },
+    Subdivision { code: "PH-COM", name: "Davao de Oro", parent: Some("PH-11") },
+    Subdivision { code: "PH-DAO", name: "Davao Oriental", parent: Some("PH-11") },
+    Subdivision { code: "PH-DAS", name: "Davao del Sur", parent: Some("PH-11") },
+    Subdivision { code: "PH-DAV", name: "Davao del Norte", parent: Some("PH-11") },
+    Subdivision { code: "PH-DIN", name: "Dinagat Islands", parent: Some("PH-13") },
+    Subdivision { code: "PH-DVO", name: "Davao Occidental", parent: Some("PH-11") },
+    Subdivision { code: "PH-EAS", name: "Eastern Samar", parent: Some("PH-08") },
+    Subdivision { code: "PH-GUI", name: "Guimaras", parent: Some("PH-06") },
+    Subdivision { code: "PH-IFU", name: "Ifugao", parent: Some("PH-15") },
+    Subdivision { code: "PH-ILI", name: "Iloilo", parent: Some("PH-06") },
+    Subdivision { code: "PH-ILN", name: "Ilocos Norte", parent: Some("PH-01") },
+    Subdivision { code: "PH-ILS", name: "Ilocos Sur", parent: Some("PH-01") },
+    Subdivision { code: "PH-ISA", name: "Isabela", parent: Some("PH-02") },
+    Subdivision { code: "PH-KAL", name: "Kalinga", parent: Some("PH-15") },
+    Subdivision { code: "PH-LAG", name: "Laguna", parent: Some("PH-40") },
+    Subdivision { code: "PH-LAN", name: "Lanao del Norte", parent: Some("PH-12") },
+    Subdivision { code: "PH-LAS", name: "Lanao del Sur", parent: Some("PH-14") },
+    Subdivision { code: "PH-LEY", name: "Leyte", parent: Some("PH-08") },
+    Subdivision { code: "PH-LUN", name: "La Union", parent: Some("PH-01") },
+    Subdivision { code: "PH-MAD", name: "Marinduque", parent: Some("PH-41") },
+    Subdivision { code: "PH-MAG", name: "Maguindanao", parent: Some("PH-14") },
+    Subdivision { code: "PH-MAS", name: "Masbate", parent: Some("PH-05") },
+    Subdivision { code: "PH-MDC", name: "Mindoro Occidental", parent: Some("PH-41") },
+    Subdivision { code: "PH-MDR", name: "Mindoro Oriental", parent: Some("PH-41") },
+    Subdivision { code: "PH-MOU", name: "Mountain Province", parent: Some("PH-15") },
+    Subdivision { code: "PH-MSC", name: "Misamis Occidental", parent: Some("PH-10") },
+    Subdivision { code: "PH-MSR", name: "Misamis Oriental", parent: Some("PH-10") },
+    Subdivision { code: "PH-NCO", name: "Cotabato", parent: Some("PH-12") },
+    Subdivision { code: "PH-NEC", name: "Negros Occidental", parent: Some("PH-06") },
+    Subdivision { code: "PH-NER", name: "Negros Oriental", parent: Some("PH-07") },
+    Subdivision { code: "PH-NSA", name: "Northern Samar", parent: Some("PH-08") },
+    Subdivision { code: "PH-NUE", name: "Nueva Ecija", parent: Some("PH-03") },
+    Subdivision { code: "PH-NUV", name: "Nueva Vizcaya", parent: Some("PH-02") },
+    Subdivision { code: "PH-PAM", name: "Pampanga", parent: Some("PH-03") },
+    Subdivision { code: "PH-PAN", name: "Pangasinan", parent: Some("PH-01") },
+    Subdivision { code: "PH-PLW", name: "Palawan", parent: Some("PH-41") },
+    Subdivision { code: "PH-QUE", name: "Quezon", parent: Some("PH-40") },
+    Subdivision { code: "PH-QUI", name: "Quirino", parent: Some("PH-02") },
+    Subdivision { code: "PH-RIZ", name: "Rizal", parent: Some("PH-40") },
+    Subdivision { code: "PH-ROM", name: "Romblon", parent: Some("PH-41") },
+    Subdivision { code: "PH-SAR", name: "Sarangani", parent: Some("PH-11") },
+    Subdivision { code: "PH-SCO", name: "South Cotabato", parent: Some("PH-11") },
+    Subdivision { code: "PH-SIG", name: "Siquijor", parent: Some("PH-07") },
+    Subdivision { code: "PH-SLE", name: "Southern Leyte", parent: Some("PH-08") },
+    Subdivision { code: "PH-SLU", name: "Sulu", parent: Some("PH-14") },
+    Subdivision { code: "PH-SOR", name: "Sorsogon", parent: Some("PH-05") },
+    Subdivision { code: "PH-SUK", name: "Sultan Kudarat", parent: Some("PH-12") },
+    Subdivision { code: "PH-SUN", name: "Surigao del Norte", parent: Some("PH-13") },
+    Subdivision { code: "PH-SUR", name: "Surigao del Sur", parent: Some("PH-13") },
+    Subdivision { code: "PH-TAR", name: "Tarlac", parent: Some("PH-03") },
+    Subdivision { code: "PH-TAW", name: "Tawi-Tawi", parent: Some("PH-14") },
+    Subdivision { code: "PH-WSA", name: "Samar", parent: Some("PH-08") },
+    Subdivision { code: "PH-ZAN", name: "Zamboanga del Norte", parent: Some("PH-09") },
+    Subdivision { code: "PH-ZAS", name: "Zamboanga del Sur", parent: Some("PH-09") },
+    Subdivision { code: "PH-ZMB", name: "Zambales", parent: Some("PH-03") },
+    Subdivision { code: "PH-ZSI", name: "Zamboanga Sibugay", parent: Some("PH-09") },
+    Subdivision { code: "PK-BA", name: "Balochistan", parent: None },
+    Subdivision { code: "PK-GB", name: "Gilgit-Baltistan", parent: None },
+    Subdivision { code: "PK-IS", name: "Islamabad", parent: None },
+    Subdivision { code: "PK-JK", name: "Azad Jammu and Kashmir", parent: None },
+    Subdivision { code: "PK-KP", name: "Khyber Pakhtunkhwa", parent: None },
+    Subdivision { code: "PK-PB", name: "Punjab", parent: None },
+    Subdivision { code: "PK-SD", name: "Sindh", parent: None },
+    Subdivision { code: "PL-02", name: "Dolnośląskie", parent: None },
+    Subdivision { code: "PL-04", name: "Kujawsko-pomorskie", parent: None },
+    Subdivision { code: "PL-06", name: "Lubelskie", parent: None },
+    Subdivision { code: "PL-08", name: "Lubuskie", parent: None },
+    Subdivision { code: "PL-10", name: "Łódzkie", parent: None },
+    Subdivision { code: "PL-12", name: "Małopolskie", parent: None },
+    Subdivision { code: "PL-14", name: "Mazowieckie", parent: None },
+    Subdivision { code: "PL-16", name: "Opolskie", parent: None },
+    Subdivision { code: "PL-18", name: "Podkarpackie", parent: None },
+    Subdivision { code: "PL-20", name: "Podlaskie", parent: None },
+    Subdivision { code: "PL-22", name: "Pomorskie", parent: None },
+    Subdivision { code: "PL-24", name: "Śląskie", parent: None },
+    Subdivision { code: "PL-26", name: "Świętokrzyskie", parent: None },
+    Subdivision { code: "PL-28", name: "Warmińsko-mazurskie", parent: None },
+    Subdivision { code: "PL-30", name: "Wielkopolskie", parent: None },
+    Subdivision { code: "PL-32", name: "Zachodniopomorskie", parent: None },
+    Subdivision { code: "PS-BTH", name: "Bethlehem", parent: None },
+    Subdivision { code: "PS-DEB", name: "Deir El Balah", parent: None },
+    Subdivision { code: "PS-GZA", name: "Gaza", parent: None },
+    Subdivision { code: "PS-HBN", name: "Hebron", parent: None },
+    Subdivision { code: "PS-JEM", name: "Jerusalem", parent: None },
+    Subdivision { code: "PS-JEN", name: "Jenin", parent: None },
+    Subdivision { code: "PS-JRH", name: "Jericho and Al Aghwar", parent: None },
+    Subdivision { code: "PS-KYS", name: "Khan Yunis", parent: None },
+    Subdivision { code: "PS-NBS", name: "Nablus", parent: None },
+    Subdivision { code: "PS-NGZ", name: "North Gaza", parent: None },
+    Subdivision { code: "PS-QQA", name: "Qalqilya", parent: None },
+    Subdivision { code: "PS-RBH", name: "Ramallah", parent: None },
+    Subdivision { code: "PS-RFH", name: "Rafah", parent: None },
+    Subdivision { code: "PS-SLT", name: "Salfit", parent: None },
+    Subdivision { code: "PS-TBS", name: "Tubas", parent: None },
+    Subdivision { code: "PS-TKM", name: "Tulkarm", parent: None },
+    Subdivision { code: "PT-01", name: "Aveiro", parent: None },
+    Subdivision { code: "PT-02", name: "Beja", parent: None },
+    Subdivision { code: "PT-03", name: "Braga", parent: None },
+    Subdivision { code: "PT-04", name: "Bragança", parent: None },
+    Subdivision { code: "PT-05", name: "Castelo Branco", parent: None },
+    Subdivision { code: "PT-06", name: "Coimbra", parent: None },
+    Subdivision { code: "PT-07", name: "Évora", parent: None },
+    Subdivision { code: "PT-08", name: "Faro", parent: None },
+    Subdivision { code: "PT-09", name: "Guarda", parent: None },
+    Subdivision { code: "PT-10", name: "Leiria", parent: None },
+    Subdivision { code: "PT-11", name: "Lisboa", parent: None },
+    Subdivision { code: "PT-12", name: "Portalegre", parent: None },
+    Subdivision { code: "PT-13", name: "Porto", parent: None },
+    Subdivision { code: "PT-14", name: "Santarém", parent: None },
+    Subdivision { code: "PT-15", name: "Setúbal", parent: None },
+    Subdivision { code: "PT-16", name: "Viana do Castelo", parent: None },
+    Subdivision { code: "PT-17", name: "Vila Real", parent: None },
+    Subdivision { code: "PT-18", name: "Viseu", parent: None },
+    Subdivision { code: "PT-20", name: "Região Autónoma dos Açores", parent: None },
+    Subdivision { code: "PT-30", name: "Região Autónoma da Madeira", parent: None },
+    Subdivision { code: "PW-002", name: "Aimeliik", parent: None },
+    Subdivision { code: "PW-004", name: "Airai", parent: None },
+    Subdivision { code: "PW-010", name: "Angaur", parent: None },
+    Subdivision { code: "PW-050", name: "Hatohobei", parent: None },
+    Subdivision { code: "PW-100", name: "Kayangel", parent: None },
+    Subdivision { code: "PW-150", name: "Koror", parent: None },
+    Subdivision { code: "PW-212", name: "Melekeok", parent: None },
+    Subdivision { code: "PW-214", name: "Ngaraard", parent: None },
+    Subdivision { code: "PW-218", name: "Ngarchelong", parent: None },
+    Subdivision { code: "PW-222", name: "Ngardmau", parent: None },
+    Subdivision { code: "PW-224", name: "Ngatpang", parent: None },
+    Subdivision { code: "PW-226", name: "Ngchesar", parent: None },
+    Subdivision { code: "PW-227", name: "Ngeremlengui", parent: None },
+    Subdivision { code: "PW-228", name: "Ngiwal", parent: None },
+    Subdivision { code: "PW-350", name: "Peleliu", parent: None },
+    Subdivision { code: "PW-370", name: "Sonsorol", parent: None },
+    Subdivision { code: "PY-1", name: "Concepción", parent: None },
+    Subdivision { code: "PY-10", name: "Alto Paraná", parent: None },
+    Subdivision { code: "PY-11", name: "Central", parent: None },
+    Subdivision { code: "PY-12", name: "Ñeembucú", parent: None },
+    Subdivision { code: "PY-13", name: "Amambay", parent: None },
+    Subdivision { code: "PY-14", name: "Canindeyú", parent: None },
+    Subdivision { code: "PY-15", name: "Presidente Hayes", parent: None },
+    Subdivision { code: "PY-16", name: "Alto Paraguay", parent: None },
+    Subdivision { code: "PY-19", name: "Boquerón", parent: None },
+    Subdivision { code: "PY-2", name: "San Pedro", parent: None },
+    Subdivision { code: "PY-3", name: "Cordillera", parent: None },
+    Subdivision { code: "PY-4", name: "Guairá", parent: None },
+    Subdivision { code: "PY-5", name: "Caaguazú", parent: None },
+    Subdivision { code: "PY-6", name: "Caazapá", parent: None },
+    Subdivision { code: "PY-7", name: "Itapúa", parent: None },
+    Subdivision { code: "PY-8", name: "Misiones", parent: None },
+    Subdivision { code: "PY-9", name: "Paraguarí", parent: None },
+    Subdivision { code: "PY-ASU", name: "Asunción", parent: None },
+    Subdivision { code: "QA-DA", name: "Ad Dawḩah", parent: None },
+    Subdivision { code: "QA-KH", name: "Al Khawr wa adh Dhakhīrah", parent: None },
+    Subdivision { code: "QA-MS", name: "Ash Shamāl", parent: None },
+    Subdivision { code: "QA-RA", name: "Ar Rayyān", parent: None },
+    Subdivision { code: "QA-SH", name: "Ash Shīḩānīyah", parent: None },
+    Subdivision { code: "QA-US", name: "Umm Şalāl", parent: None },
+    Subdivision { code: "QA-WA", name: "Al Wakrah", parent: None },
+    Subdivision { code: "QA-ZA", name: "Az̧ Z̧a‘āyin", parent: None },
+    Subdivision { code: "RO-AB", name: "Alba", parent: None },
+    Subdivision { code: "RO-AG", name: "Argeș", parent: None },
+    Subdivision { code: "RO-AR", name: "Arad", parent: None },
+    Subdivision { code: "RO-B", name: "București", parent: None },
+    Subdivision { code: "RO-BC", name: "Bacău", parent: None },
+    Subdivision { code: "RO-BH", name: "Bihor", parent: None },
+    Subdivision { code: "RO-BN", name: "Bistrița-Năsăud", parent: None },
+    Subdivision { code: "RO-BR", name: "Brăila", parent: None },
+    Subdivision { code: "RO-BT", name: "Botoșani", parent: None },
+    Subdivision { code: "RO-BV", name: "Brașov", parent: None },
+    Subdivision { code: "RO-BZ", name: "Buzău", parent: None },
+    Subdivision { code: "RO-CJ", name: "Cluj", parent: None },
+    Subdivision { code: "RO-CL", name: "Călărași", parent: None },
+    Subdivision { code: "RO-CS", name: "Caraș-Severin", parent: None },
+    Subdivision { code: "RO-CT", name: "Constanța", parent: None },
+    Subdivision { code: "RO-CV", name: "Covasna", parent: None },
+    Subdivision { code: "RO-DB", name: "Dâmbovița", parent: None },
+    Subdivision { code: "RO-DJ", name: "Dolj", parent: None },
+    Subdivision { code: "RO-GJ", name: "Gorj", parent: None },
+    Subdivision { code: "RO-GL", name: "Galați", parent: None },
+    Subdivision { code: "RO-GR", name: "Giurgiu", parent: None },
+    Subdivision { code: "RO-HD", name: "Hunedoara", parent: None },
+    Subdivision { code: "RO-HR", name: "Harghita", parent: None },
+    Subdivision { code: "RO-IF", name: "Ilfov", parent: None },
+    Subdivision { code: "RO-IL", name: "Ialomița", parent: None },
+    Subdivision { code: "RO-IS", name: "Iași", parent: None },
+    Subdivision { code: "RO-MH", name: "Mehedinți", parent: None },
+    Subdivision { code: "RO-MM", name: "Maramureș", parent: None },
+    Subdivision { code: "RO-MS", name: "Mureș", parent: None },
+    Subdivision { code: "RO-NT", name: "Neamț", parent: None },
+    Subdivision { code: "RO-OT", name: "Olt", parent: None },
+    Subdivision { code: "RO-PH", name: "Prahova", parent: None },
+    Subdivision { code: "RO-SB", name: "Sibiu", parent: None },
+    Subdivision { code: "RO-SJ", name: "Sălaj", parent: None },
+    Subdivision { code: "RO-SM", name: "Satu Mare", parent: None },
+    Subdivision { code: "RO-SV", name: "Suceava", parent: None },
+    Subdivision { code: "RO-TL", name: "Tulcea", parent: None },
+    Subdivision { code: "RO-TM", name: "Timiș", parent: None },
+    Subdivision { code: "RO-TR", name: "Teleorman", parent: None },
+    Subdivision { code: "RO-VL", name: "Vâlcea", parent: None },
+    Subdivision { code: "RO-VN", name: "Vrancea", parent: None },
+    Subdivision { code: "RO-VS", name: "Vaslui", parent: None },
+    Subdivision { code: "RS-00", name: "Beograd", parent: None },
+    Subdivision { code: "RS-01", name: "Severnobački okrug", parent: Some("RS-VO") },
+    Subdivision { code: "RS-02", name: "Srednjebanatski okrug", parent: Some("RS-VO") },
+    Subdivision { code: "RS-03", name: "Severnobanatski okrug", parent: Some("RS-VO") },
+    Subdivision { code: "RS-04", name: "Južnobanatski okrug", parent: Some("RS-VO") },
+    Subdivision { code: "RS-05", name: "Zapadnobački okrug", parent: Some("RS-VO") },
+    Subdivision { code: "RS-06", name: "Južnobački okrug", parent: Some("RS-VO") },
+    Subdivision { code: "RS-07", name: "Sremski okrug", parent: Some("RS-VO") },
+    Subdivision { code: "RS-08", name: "Mačvanski okrug", parent: None },
+    Subdivision { code: "RS-09", name: "Kolubarski okrug", parent: None },
+    Subdivision { code: "RS-10", name: "Podunavski okrug", parent: None },
+    Subdivision { code: "RS-11", name: "Braničevski okrug", parent: None },
+    Subdivision { code: "RS-12", name: "Šumadijski okrug", parent: None },
+    Subdivision { code: "RS-13", name: "Pomoravski okrug", parent: None },
+    Subdivision { code: "RS-14", name: "Borski okrug", parent: None },
+    Subdivision { code: "RS-15", name: "Zaječarski okrug", parent: None },
+    Subdivision { code: "RS-16", name: "Zlatiborski okrug", parent: None },
+    Subdivision { code: "RS-17", name: "Moravički okrug", parent: None },
+    Subdivision { code: "RS-18", name: "Raški okrug", parent: None },
+    Subdivision { code: "RS-19", name: "Rasinski okrug", parent: None },
+    Subdivision { code: "RS-20", name: "Nišavski okrug", parent: None },
+    Subdivision { code: "RS-21", name: "Toplički okrug", parent: None },
+    Subdivision { code: "RS-22", name: "Pirotski okrug", parent: None },
+    Subdivision { code: "RS-23", name: "Jablanički okrug", parent: None },
+    Subdivision { code: "RS-24", name: "Pčinjski okrug", parent: None },
+    Subdivision { code: "RS-25", name: "Kosovski okrug", parent: Some("RS-KM") },
+    Subdivision { code: "RS-26", name: "Pećki okrug", parent: Some("RS-KM") },
+    Subdivision { code: "RS-27", name: "Prizrenski okrug", parent: Some("RS-KM") },
+    Subdivision { code: "RS-28", name: "Kosovsko-Mitrovački okrug", parent: Some("RS-KM") },
+    Subdivision { code: "RS-29", name: "Kosovsko-Pomoravski okrug", parent: Some("RS-KM") },
+    Subdivision { code: "RS-KM", name: "Kosovo-Metohija", parent: None },
+    Subdivision { code: "RS-VO", name: "Vojvodina", parent: None },
+    Subdivision { code: "RU-AD", name: "Adygeja, Respublika", parent: None },
+    Subdivision { code: "RU-AL", name: "Altaj, Respublika", parent: None },
+    Subdivision { code: "RU-ALT", name: "Altajskij kraj", parent: None },
+    Subdivision { code: "RU-AMU", name: "Amurskaja oblast'", parent: None },
+    Subdivision { code: "RU-ARK", name: "Arhangel'skaja oblast'", parent: None },
+    Subdivision { code: "RU-AST", name: "Astrahanskaja oblast'", parent: None },
+    Subdivision { code: "RU-BA", name: "Bashkortostan, Respublika", parent: None },
+    Subdivision { code: "RU-BEL", name: "Belgorodskaja oblast'", parent: None },
+    Subdivision { code: "RU-BRY", name: "Brjanskaja oblast'", parent: None },
+    Subdivision { code: "RU-BU", name: "Burjatija, Respublika", parent: None },
+    Subdivision { code: "RU-CE", name: "Chechenskaya Respublika", parent: None },
+    Subdivision { code: "RU-CHE", name: "Chelyabinskaya oblast'", parent: None },
+    Subdivision { code: "RU-CHU", name: "Chukotskiy avtonomnyy okrug", parent: None },
+    Subdivision { code: "RU-CU", name: "Chuvashskaya Respublika", parent: None },
+    Subdivision { code: "RU-DA", name: "Dagestan, Respublika", parent: None },
+    Subdivision { code: "RU-IN", name: "Ingushetiya, Respublika", parent: None },
+    Subdivision { code: "RU-IRK", name: "Irkutskaja oblast'", parent: None },
+    Subdivision { code: "RU-IVA", name: "Ivanovskaja oblast'", parent: None },
+    Subdivision { code: "RU-KAM", name: "Kamchatskiy kray", parent: None },
+    Subdivision { code: "RU-KB", name: "Kabardino-Balkarskaja Respublika", parent: None },
+    Subdivision { code: "RU-KC", name: "Karachayevo-Cherkesskaya Respublika", parent: None },
+    Subdivision { code: "RU-KDA", name: "Krasnodarskij kraj", parent: None },
+    Subdivision { code: "RU-KEM", name: "Kemerovskaja oblast'", parent: None },
+    Subdivision { code: "RU-KGD", name: "Kaliningradskaja oblast'", parent: None },
+    Subdivision { code: "RU-KGN", name: "Kurganskaja oblast'", parent: None },
+    Subdivision { code: "RU-KHA", name: "Habarovskij kraj", parent: None },
+    Subdivision { code: "RU-KHM", name: "Hanty-Mansijskij avtonomnyj okrug", parent: None },
+    Subdivision { code: "RU-KIR", name: "Kirovskaja oblast'", parent: None },
+    Subdivision { code: "RU-KK", name: "Hakasija, Respublika", parent: None },
+    Subdivision { code: "RU-KL", name: "Kalmykija, Respublika", parent: None },
+    Subdivision { code: "RU-KLU", name: "Kaluzhskaya oblast'", parent: None },
+    Subdivision { code: "RU-KO", name: "Komi, Respublika", parent: None },
+    Subdivision { code: "RU-KOS", name: "Kostromskaja oblast'", parent: None },
+    Subdivision { code: "RU-KR", name: "Karelija, Respublika", parent: None },
+    Subdivision { code: "RU-KRS", name: "Kurskaja oblast'", parent: None },
+    Subdivision { code: "RU-KYA", name: "Krasnojarskij kraj", parent: None },
+    Subdivision { code: "RU-LEN", name: "Leningradskaja oblast'", parent: None },
+    Subdivision { code: "RU-LIP", name: "Lipeckaja oblast'", parent: None },
+    Subdivision { code: "RU-MAG", name: "Magadanskaja oblast'", parent: None },
+    Subdivision { code: "RU-ME", name: "Marij Èl, Respublika", parent: None },
+    Subdivision { code: "RU-MO", name: "Mordovija, Respublika", parent: None },
+    Subdivision { code: "RU-MOS", name: "Moskovskaja oblast'", parent: None },
+    Subdivision { code: "RU-MOW", name: "Moskva", parent: None },
+    Subdivision { code: "RU-MUR", name: "Murmanskaja oblast'", parent: None },
+    Subdivision { code: "RU-NEN", name: "Neneckij avtonomnyj okrug", parent: None },
+    Subdivision { code: "RU-NGR", name: "Novgorodskaja oblast'", parent: None },
+    Subdivision { code: "RU-NIZ", name: "Nizhegorodskaya oblast'", parent: None },
+    Subdivision { code: "RU-NVS", name: "Novosibirskaja oblast'", parent: None },
+    Subdivision { code: "RU-OMS", name: "Omskaja oblast'", parent: None },
+    Subdivision { code: "RU-ORE", name: "Orenburgskaja oblast'", parent: None },
+    Subdivision { code: "RU-ORL", name: "Orlovskaja oblast'", parent: None },
+    Subdivision { code: "RU-PER", name: "Permskij kraj", parent: None },
+    Subdivision { code: "RU-PNZ", name: "Penzenskaja oblast'", parent: None },
+    Subdivision { code: "RU-PRI", name: "Primorskij kraj", parent: None },
+    Subdivision { code: "RU-PSK", name: "Pskovskaja oblast'", parent: None },
+    Subdivision { code: "RU-ROS", name: "Rostovskaja oblast'", parent: None },
+    Subdivision { code: "RU-RYA", name: "Rjazanskaja oblast'", parent: None },
+    Subdivision { code: "RU-SA", name: "Saha, Respublika", parent: None },
+    Subdivision { code: "RU-SAK", name: "Sahalinskaja oblast'", parent: None },
+    Subdivision { code: "RU-SAM", name: "Samarskaja oblast'", parent: None },
+    Subdivision { code: "RU-SAR", name: "Saratovskaja oblast'", parent: None },
+    Subdivision { code: "RU-SE", name: "Severnaja Osetija, Respublika", parent: None },
+    Subdivision { code: "RU-SMO", name: "Smolenskaja oblast'", parent: None },
+    Subdivision { code: "RU-SPE", name: "Sankt-Peterburg", parent: None },
+    Subdivision { code: "RU-STA", name: "Stavropol'skij kraj", parent: None },
+    Subdivision { code: "RU-SVE", name: "Sverdlovskaja oblast'", parent: None },
+    Subdivision { code: "RU-TA", name: "Tatarstan, Respublika", parent: None },
+    Subdivision { code: "RU-TAM", name: "Tambovskaja oblast'", parent: None },
+    Subdivision { code: "RU-TOM", name: "Tomskaja oblast'", parent: None },
+    Subdivision { code: "RU-TUL", name: "Tul'skaja oblast'", parent: None },
+    Subdivision { code: "RU-TVE", name: "Tverskaja oblast'", parent: None },
+    Subdivision { code: "RU-TY", name: "Tyva, Respublika", parent: None },
+    Subdivision { code: "RU-TYU", name: "Tjumenskaja oblast'", parent: None },
+    Subdivision { code: "RU-UD", name: "Udmurtskaja Respublika", parent: None },
+    Subdivision { code: "RU-ULY", name: "Ul'janovskaja oblast'", parent: None },
+    Subdivision { code: "RU-VGG", name: "Volgogradskaja oblast'", parent: None },
+    Subdivision { code: "RU-VLA", name: "Vladimirskaja oblast'", parent: None },
+    Subdivision { code: "RU-VLG", name: "Vologodskaja oblast'", parent: None },
+    Subdivision { code: "RU-VOR", name: "Voronezhskaya oblast'", parent: None },
+    Subdivision { code: "RU-YAN", name: "Jamalo-Neneckij avtonomnyj okrug", parent: None },
+    Subdivision { code: "RU-YAR", name: "Jaroslavskaja oblast'", parent: None },
+    Subdivision { code: "RU-YEV", name: "Evrejskaja avtonomnaja oblast'", parent: None },
+    Subdivision { code: "RU-ZAB", name: "Zabajkal'skij kraj", parent: None },
+    Subdivision { code: "RW-01", name: "City of Kigali", parent: None },
+    Subdivision { code: "RW-02", name: "Eastern", parent: None },
+    Subdivision { code: "RW-03", name: "Northern", parent: None },
+    Subdivision { code: "RW-04", name: "Western", parent: None },
+    Subdivision { code: "RW-05", name: "Southern", parent: None },
+    Subdivision { code: "SA-01", name: "Ar Riyāḑ", parent: None },
+    Subdivision { code: "SA-02", name: "Makkah al Mukarramah", parent: None },
+    Subdivision { code: "SA-03", name: "Al Madīnah al Munawwarah", parent: None },
+    Subdivision { code: "SA-04", name: "Ash Sharqīyah", parent: None },
+    Subdivision { code: "SA-05", name: "Al Qaşīm", parent: None },
+    Subdivision { code: "SA-06", name: "Ḩā'il", parent: None },
+    Subdivision { code: "SA-07", name: "Tabūk", parent: None },
+    Subdivision { code: "SA-08", name: "Al Ḩudūd ash Shamālīyah", parent: None },
+    Subdivision { code: "SA-09", name: "Jāzān", parent: None },
+    Subdivision { code: "SA-10", name: "Najrān", parent: None },
+    Subdivision { code: "SA-11", name: "Al Bāḩah", parent: None },
+    Subdivision { code: "SA-12", name: "Al Jawf", parent: None },
+    Subdivision { code: "SA-14", name: "'Asīr", parent: None },
+    Subdivision { code: "SB-CE", name: "Central", parent: None },
+    Subdivision { code: "SB-CH", name: "Choiseul", parent: None },
+    Subdivision { code: "SB-CT", name: "Capital Territory (Honiara)", parent: None },
+    Subdivision { code: "SB-GU", name: "Guadalcanal", parent: None },
+    Subdivision { code: "SB-IS", name: "Isabel", parent: None },
+    Subdivision { code: "SB-MK", name: "Makira-Ulawa", parent: None },
+    Subdivision { code: "SB-ML", name: "Malaita", parent: None },
+    Subdivision { code: "SB-RB", name: "Rennell and Bellona", parent: None },
+    Subdivision { code: "SB-TE", name: "Temotu", parent: None },
+    Subdivision { code: "SB-WE", name: "Western", parent: None },
+    Subdivision { code: "SC-01", name: "Anse aux Pins", parent: None },
+    Subdivision { code: "SC-02", name: "Anse Boileau", parent: None },
+    Subdivision { code: "SC-03", name: "Anse Etoile", parent: None },
+    Subdivision { code: "SC-04", name: "Au Cap", parent: None },
+    Subdivision { code: "SC-05", name: "Anse Royale", parent: None },
+    Subdivision { code: "SC-06", name: "Baie Lazare", parent: None },
+    Subdivision { code: "SC-07", name: "Baie Sainte Anne", parent: None },
+    Subdivision { code: "SC-08", name: "Beau Vallon", parent: None },
+    Subdivision { code: "SC-09", name: "Bel Air", parent: None },
+    Subdivision { code: "SC-10", name: "Bel Ombre", parent: None },
+    Subdivision { code: "SC-11", name: "Cascade", parent: None },
+    Subdivision { code: "SC-12", name: "Glacis", parent: None },
+    Subdivision { code: "SC-13", name: "Grand Anse Mahe", parent: None },
+    Subdivision { code: "SC-14", name: "Grand Anse Praslin", parent: None },
+    Subdivision { code: "SC-15", name: "La Digue", parent: None },
+    Subdivision { code: "SC-16", name: "English River", parent: None },
+    Subdivision { code: "SC-17", name: "Mont Buxton", parent: None },
+    Subdivision { code: "SC-18", name: "Mont Fleuri", parent: None },
+    Subdivision { code: "SC-19", name: "Plaisance", parent: None },
+    Subdivision { code: "SC-20", name: "Pointe Larue", parent: None },
+    Subdivision { code: "SC-21", name: "Port Glaud", parent: None },
+    Subdivision { code: "SC-22", name: "Saint Louis", parent: None },
+    Subdivision { code: "SC-23", name: "Takamaka", parent: None },
+    Subdivision { code: "SC-24", name: "Les Mamelles", parent: None },
+    Subdivision { code: "SC-25", name: "Roche Caiman", parent: None },
+    Subdivision { code: "SC-26", name: "Ile Perseverance I", parent: None },
+    Subdivision { code: "SC-27", name: "Ile Perseverance II", parent: None },
+    Subdivision { code: "SD-DC", name: "Central Darfur", parent: None },
+    Subdivision { code: "SD-DE", name: "East Darfur", parent: None },
+    Subdivision { code: "SD-DN", name: "North Darfur", parent: None },
+    Subdivision { code: "SD-DS", name: "South Darfur", parent: None },
+    Subdivision { code: "SD-DW", name: "West Darfur", parent: None },
+    Subdivision { code: "SD-GD", name: "Gedaref", parent: None },
+    Subdivision { code: "SD-GK", name: "West Kordofan", parent: None },
+    Subdivision { code: "SD-GZ", name: "Gezira", parent: None },
+    Subdivision { code: "SD-KA", name: "Kassala", parent: None },
+    Subdivision { code: "SD-KH", name: "Khartoum", parent: None },
+    Subdivision { code: "SD-KN", name: "North Kordofan", parent: None },
+    Subdivision { code: "SD-KS", name: "South Kordofan", parent: None },
+    Subdivision { code: "SD-NB", name: "Blue Nile", parent: None },
+    Subdivision { code: "SD-NO", name: "Northern", parent: None },
+    Subdivision { code: "SD-NR", name: "River Nile", parent: None },
+    Subdivision { code: "SD-NW", name: "White Nile", parent: None },
+    Subdivision { code: "SD-RS", name: "Red Sea", parent: None },
+    Subdivision { code: "SD-SI", name: "Sennar", parent: None },
+    Subdivision { code: "SE-AB", name: "Stockholms län [SE-01]", parent: None },
+    Subdivision { code: "SE-AC", name: "Västerbottens län [SE-24]", parent: None },
+    Subdivision { code: "SE-BD", name: "Norrbottens län [SE-25]", parent: None },
+    Subdivision { code: "SE-C", name: "Uppsala län [SE-03]", parent: None },
+    Subdivision { code: "SE-D", name: "Södermanlands län [SE-04]", parent: None },
+    Subdivision { code: "SE-E", name: "Östergötlands län [SE-05]", parent: None },
+    Subdivision { code: "SE-F", name: "Jönköpings län [SE-06]", parent: None },
+    Subdivision { code: "SE-G", name: "Kronobergs län [SE-07]", parent: None },
+    Subdivision { code: "SE-H", name: "Kalmar län [SE-08]", parent: None },
+    Subdivision { code: "SE-I", name: "Gotlands län [SE-09]", parent: None },
+    Subdivision { code: "SE-K", name: "Blekinge län [SE-10]", parent: None },
+    Subdivision { code: "SE-M", name: "Skåne län [SE-12]", parent: None },
+    Subdivision { code: "SE-N", name: "Hallands län [SE-13]", parent: None },
+    Subdivision { code: "SE-O", name: "Västra Götalands län [SE-14]", parent: None },
+    Subdivision { code: "SE-S", name: "Värmlands län [SE-17]", parent: None },
+    Subdivision { code: "SE-T", name: "Örebro län [SE-18]", parent: None },
+    Subdivision { code: "SE-U", name: "Västmanlands län [SE-19]", parent: None },
+    Subdivision { code: "SE-W", name: "Dalarnas län [SE-20]", parent: None },
+    Subdivision { code: "SE-X", name: "Gävleborgs län [SE-21]", parent: None },
+    Subdivision { code: "SE-Y", name: "Västernorrlands län [SE-22]", parent: None },
+    Subdivision { code: "SE-Z", name: "Jämtlands län [SE-23]", parent: None },
+    Subdivision { code: "SG-01", name: "Central Singapore", parent: None },
+    Subdivision { code: "SG-02", name: "North East", parent: None },
+    Subdivision { code: "SG-03", name: "North West", parent: None },
+    Subdivision { code: "SG-04", name: "South East", parent: None },
+    Subdivision { code: "SG-05", name: "South West", parent: None },
+    Subdivision { code: "SH-AC", name: "Ascension", parent: None },
+    Subdivision { code: "SH-HL", name: "Saint Helena", parent: None },
+    Subdivision { code: "SH-TA", name: "Tristan da Cunha", parent: None },
+    Subdivision { code: "SI-001", name: "Ajdovščina", parent: None },
+    Subdivision { code: "SI-002", name: "Beltinci", parent: None },
+    Subdivision { code: "SI-003", name: "Bled", parent: None },
+    Subdivision { code: "SI-004", name: "Bohinj", parent: None },
+    Subdivision { code: "SI-005", name: "Borovnica", parent: None },
+    Subdivision { code: "SI-006", name: "Bovec", parent: None },
+    Subdivision { code: "SI-007", name: "Brda", parent: None },
+    Subdivision { code: "SI-008", name: "Brezovica", parent: None },
+    Subdivision { code: "SI-009", name: "Brežice", parent: None },
+    Subdivision { code: "SI-010", name: "Tišina", parent: None },
+    Subdivision { code: "SI-011", name: "Celje", parent: None },
+    Subdivision { code: "SI-012", name: "Cerklje na Gorenjskem", parent: None },
+    Subdivision { code: "SI-013", name: "Cerknica", parent: None },
+    Subdivision { code: "SI-014", name: "Cerkno", parent: None },
+    Subdivision { code: "SI-015", name: "Črenšovci", parent: None },
+    Subdivision { code: "SI-016", name: "Črna na Koroškem", parent: None },
+    Subdivision { code: "SI-017", name: "Črnomelj", parent: None },
+    Subdivision { code: "SI-018", name: "Destrnik", parent: None },
+    Subdivision { code: "SI-019", name: "Divača", parent: None },
+    Subdivision { code: "SI-020", name: "Dobrepolje", parent: None },
+    Subdivision { code: "SI-021", name: "Dobrova-Polhov Gradec", parent: None },
+    Subdivision { code: "SI-022", name: "Dol pri Ljubljani", parent: None },
+    Subdivision { code: "SI-023", name: "Domžale", parent: None },
+    Subdivision { code: "SI-024", name: "Dornava", parent: None },
+    Subdivision { code: "SI-025", name: "Dravograd", parent: None },
+    Subdivision { code: "SI-026", name: "Duplek", parent: None },
+    Subdivision { code: "SI-027", name: "Gorenja vas-Poljane", parent: None },
+    Subdivision { code: "SI-028", name: "Gorišnica", parent: None },
+    Subdivision { code: "SI-029", name: "Gornja Radgona", parent: None },
+    Subdivision { code: "SI-030", name: "Gornji Grad", parent: None },
+    Subdivision { code: "SI-031", name: "Gornji Petrovci", parent: None },
+    Subdivision { code: "SI-032", name: "Grosuplje", parent: None },
+    Subdivision { code: "SI-033", name: "Šalovci", parent: None },
+    Subdivision { code: "SI-034", name: "Hrastnik", parent: None },
+    Subdivision { code: "SI-035", name: "Hrpelje-Kozina", parent: None },
+    Subdivision { code: "SI-036", name: "Idrija", parent: None },
+    Subdivision { code: "SI-037", name: "Ig", parent: None },
+    Subdivision { code: "SI-038", name: "Ilirska Bistrica", parent: None },
+    Subdivision { code: "SI-039", name: "Ivančna Gorica", parent: None },
+    Subdivision { code: "SI-040", name: "Izola", parent: None },
+    Subdivision { code: "SI-041", name: "Jesenice", parent: None },
+    Subdivision { code: "SI-042", name: "Juršinci", parent: None },
+    Subdivision { code: "SI-043", name: "Kamnik", parent: None },
+    Subdivision { code: "SI-044", name: "Kanal", parent: None },
+    Subdivision { code: "SI-045", name: "Kidričevo", parent: None },
+    Subdivision { code: "SI-046", name: "Kobarid", parent: None },
+    Subdivision { code: "SI-047", name: "Kobilje", parent: None },
+    Subdivision { code: "SI-048", name: "Kočevje", parent: None },
+    Subdivision { code: "SI-049", name: "Komen", parent: None },
+    Subdivision { code: "SI-050", name: "Koper", parent: None },
+    Subdivision { code: "SI-051", name: "Kozje", parent: None },
+    Subdivision { code: "SI-052", name: "Kranj", parent: None },
+    Subdivision { code: "SI-053", name: "Kranjska Gora", parent: None },
+    Subdivision { code: "SI-054", name: "Krško", parent: None },
+    Subdivision { code: "SI-055", name: "Kungota", parent: None },
+    Subdivision { code: "SI-056", name: "Kuzma", parent: None },
+    Subdivision { code: "SI-057", name: "Laško", parent: None },
+    Subdivision { code: "SI-058", name: "Lenart", parent: None },
+    Subdivision { code: "SI-059", name: "Lendava", parent: None },
+    Subdivision { code: "SI-060", name: "Litija", parent: None },
+    Subdivision { code: "SI-061", name: "Ljubljana", parent: None },
+    Subdivision { code: "SI-062", name: "Ljubno", parent: None },
+    Subdivision { code: "SI-063", name: "Ljutomer", parent: None },
+    Subdivision { code: "SI-064", name: "Logatec", parent: None },
+    Subdivision { code: "SI-065", name: "Loška dolina", parent: None },
+    Subdivision { code: "SI-066", name: "Loški Potok", parent: None },
+    Subdivision { code: "SI-067", name: "Luče", parent: None },
+    Subdivision { code: "SI-068", name: "Lukovica", parent: None },
+    Subdivision { code: "SI-069", name: "Majšperk", parent: None },
+    Subdivision { code: "SI-070", name: "Maribor", parent: None },
+    Subdivision { code: "SI-071", name: "Medvode", parent: None },
+    Subdivision { code: "SI-072", name: "Mengeš", parent: None },
+    Subdivision { code: "SI-073", name: "Metlika", parent: None },
+    Subdivision { code: "SI-074", name: "Mežica", parent: None },
+    Subdivision { code: "SI-075", name: "Miren-Kostanjevica", parent: None },
+    Subdivision { code: "SI-076", name: "Mislinja", parent: None },
+    Subdivision { code: "SI-077", name: "Moravče", parent: None },
+    Subdivision { code: "SI-078", name: "Moravske Toplice", parent: None },
+    Subdivision { code: "SI-079", name: "Mozirje", parent: None },
+    Subdivision { code: "SI-080", name: "Murska Sobota", parent: None },
+    Subdivision { code: "SI-081", name: "Muta", parent: None },
+    Subdivision { code: "SI-082", name: "Naklo", parent: None },
+    Subdivision { code: "SI-083", name: "Nazarje", parent: None },
+    Subdivision { code: "SI-084", name: "Nova Gorica", parent: None },
+    Subdivision { code: "SI-085", name: "Novo Mesto", parent: None },
+    Subdivision { code: "SI-086", name: "Odranci", parent: None },
+    Subdivision { code: "SI-087", name: "Ormož", parent: None },
+    Subdivision { code: "SI-088", name: "Osilnica", parent: None },
+    Subdivision { code: "SI-089", name: "Pesnica", parent: None },
+    Subdivision { code: "SI-090", name: "Piran", parent: None },
+    Subdivision { code: "SI-091", name: "Pivka", parent: None },
+    Subdivision { code: "SI-092", name: "Podčetrtek", parent: None },
+    Subdivision { code: "SI-093", name: "Podvelka", parent: None },
+    Subdivision { code: "SI-094", name: "Postojna", parent: None },
+    Subdivision { code: "SI-095", name: "Preddvor", parent: None },
+    Subdivision { code: "SI-096", name: "Ptuj", parent: None },
+    Subdivision { code: "SI-097", name: "Puconci", parent: None },
+    Subdivision { code: "SI-098", name: "Rače-Fram", parent: None },
+    Subdivision { code: "SI-099", name: "Radeče", parent: None },
+    Subdivision { code: "SI-100", name: "Radenci", parent: None },
+    Subdivision { code: "SI-101", name: "Radlje ob Dravi", parent: None },
+    Subdivision { code: "SI-102", name: "Radovljica", parent: None },
+    Subdivision { code: "SI-103", name: "Ravne na Koroškem", parent: None },
+    Subdivision { code: "SI-104", name: "Ribnica", parent: None },
+    Subdivision { code: "SI-105", name: "Rogašovci", parent: None },
+    Subdivision { code: "SI-106", name: "Rogaška Slatina", parent: None },
+    Subdivision { code: "SI-107", name: "Rogatec", parent: None },
+    Subdivision { code: "SI-108", name: "Ruše", parent: None },
+    Subdivision { code: "SI-109", name: "Semič", parent: None },
+    Subdivision { code: "SI-110", name: "Sevnica", parent: None },
+    Subdivision { code: "SI-111", name: "Sežana", parent: None },
+    Subdivision { code: "SI-112", name: "Slovenj Gradec", parent: None },
+    Subdivision { code: "SI-113", name: "Slovenska Bistrica", parent: None },
+    Subdivision { code: "SI-114", name: "Slovenske Konjice", parent: None },
+    Subdivision { code: "SI-115", name: "Starše", parent: None },
+    Subdivision { code: "SI-116", name: "Sveti Jurij ob Ščavnici", parent: None },
+    Subdivision { code: "SI-117", name: "Šenčur", parent: None },
+    Subdivision { code: "SI-118", name: "Šentilj", parent: None },
+    Subdivision { code: "SI-119", name: "Šentjernej", parent: None },
+    Subdivision { code: "SI-120", name: "Šentjur", parent: None },
+    Subdivision { code: "SI-121", name: "Škocjan", parent: None },
+    Subdivision { code: "SI-122", name: "Škofja Loka", parent: None },
+    Subdivision { code: "SI-123", name: "Škofljica", parent: None },
+    Subdivision { code: "SI-124", name: "Šmarje pri Jelšah", parent: None },
+    Subdivision { code: "SI-125", name: "Šmartno ob Paki", parent: None },
+    Subdivision { code: "SI-126", name: "Šoštanj", parent: None },
+    Subdivision { code: "SI-127", name: "Štore", parent: None },
+    Subdivision { code: "SI-128", name: "Tolmin", parent: None },
+    Subdivision { code: "SI-129", name: "Trbovlje", parent: None },
+    Subdivision { code: "SI-130", name: "Trebnje", parent: None },
+    Subdivision { code: "SI-131", name: "Tržič", parent: None },
+    Subdivision { code: "SI-132", name: "Turnišče", parent: None },
+    Subdivision { code: "SI-133", name: "Velenje", parent: None },
+    Subdivision { code: "SI-134", name: "Velike Lašče", parent: None },
+    Subdivision { code: "SI-135", name: "Videm", parent: None },
+    Subdivision { code: "SI-136", name: "Vipava", parent: None },
+    Subdivision { code: "SI-137", name: "Vitanje", parent: None },
+    Subdivision { code: "SI-138", name: "Vodice", parent: None },
+    Subdivision { code: "SI-139", name: "Vojnik", parent: None },
+    Subdivision { code: "SI-140", name: "Vrhnika", parent: None },
+    Subdivision { code: "SI-141", name: "Vuzenica", parent: None },
+    Subdivision { code: "SI-142", name: "Zagorje ob Savi", parent: None },
+    Subdivision { code: "SI-143", name: "Zavrč", parent: None },
+    Subdivision { code: "SI-144", name: "Zreče", parent: None },
+    Subdivision { code: "SI-146", name: "Železniki", parent: None },
+    Subdivision { code: "SI-147", name: "Žiri", parent: None },
+    Subdivision { code: "SI-148", name: "Benedikt", parent: None },
+    Subdivision { code: "SI-149", name: "Bistrica ob Sotli", parent: None },
+    Subdivision { code: "SI-150", name: "Bloke", parent: None },
+    Subdivision { code: "SI-151", name: "Braslovče", parent: None },
+    Subdivision { code: "SI-152", name: "Cankova", parent: None },
+    Subdivision { code: "SI-153", name: "Cerkvenjak", parent: None },
+    Subdivision { code: "SI-154", name: "Dobje", parent: None },
+    Subdivision { code: "SI-155", name: "Dobrna", parent: None },
+    Subdivision { code: "SI-156", name: "Dobrovnik", parent: None },
+    Subdivision { code: "SI-157", name: "Dolenjske Toplice", parent: None },
+    Subdivision { code: "SI-158", name: "Grad", parent: None },
+    Subdivision { code: "SI-159", name: "Hajdina", parent: None },
+    Subdivision { code: "SI-160", name: "Hoče-Slivnica", parent: None },
+    Subdivision { code: "SI-161", name: "Hodoš", parent: None },
+    Subdivision { code: "SI-162", name: "Horjul", parent: None },
+    Subdivision { code: "SI-163", name: "Jezersko", parent: None },
+    Subdivision { code: "SI-164", name: "Komenda", parent: None },
+    Subdivision { code: "SI-165", name: "Kostel", parent: None },
+    Subdivision { code: "SI-166", name: "Križevci", parent: None },
+    Subdivision { code: "SI-167", name: "Lovrenc na Pohorju", parent: None },
+    Subdivision { code: "SI-168", name: "Markovci", parent: None },
+    Subdivision { code: "SI-169", name: "Miklavž na Dravskem polju", parent: None },
+    Subdivision { code: "SI-170", name: "Mirna Peč", parent: None },
+    Subdivision { code: "SI-171", name: "Oplotnica", parent: None },
+    Subdivision { code: "SI-172", name: "Podlehnik", parent: None },
+    Subdivision { code: "SI-173", name: "Polzela", parent: None },
+    Subdivision { code: "SI-174", name: "Prebold", parent: None },
+    Subdivision { code: "SI-175", name: "Prevalje", parent: None },
+    Subdivision { code: "SI-176", name: "Razkrižje", parent: None },
+    Subdivision { code: "SI-177", name: "Ribnica na Pohorju", parent: None },
+    Subdivision { code: "SI-178", name: "Selnica ob Dravi", parent: None },
+    Subdivision { code: "SI-179", name: "Sodražica", parent: None },
+    Subdivision { code: "SI-180", name: "Solčava", parent: None },
+    Subdivision { code: "SI-181", name: "Sveta Ana", parent: None },
+    Subdivision { code: "SI-182", name: "Sveti Andraž v Slovenskih goricah", parent: None },
+    Subdivision { code: "SI-183", name: "Šempeter-Vrtojba", parent: None },
+    Subdivision { code: "SI-184", name: "Tabor", parent: None },
+    Subdivision { code: "SI-185", name: "Trnovska Vas", parent: None },
+    Subdivision { code: "SI-186", name: "Trzin", parent: None },
+    Subdivision { code: "SI-187", name: "Velika Polana", parent: None },
+    Subdivision { code: "SI-188", name: "Veržej", parent: None },
+    Subdivision { code: "SI-189", name: "Vransko", parent: None },
+    Subdivision { code: "SI-190", name: "Žalec", parent: None },
+    Subdivision { code: "SI-191", name: "Žetale", parent: None },
+    Subdivision { code: "SI-192", name: "Žirovnica", parent: None },
+    Subdivision { code: "SI-193", name: "Žužemberk", parent: None },
+    Subdivision { code: "SI-194", name: "Šmartno pri Litiji", parent: None },
+    Subdivision { code: "SI-195", name: "Apače", parent: None },
+    Subdivision { code: "SI-196", name: "Cirkulane", parent: None },
+    Subdivision { code: "SI-197", name: "Kosanjevica na Krki", parent: None },
+    Subdivision { code: "SI-198", name: "Makole", parent: None },
+    Subdivision { code: "SI-199", name: "Mokronog-Trebelno", parent: None },
+    Subdivision { code: "SI-200", name: "Poljčane", parent: None },
+    Subdivision { code: "SI-201", name: "Renče-Vogrsko", parent: None },
+    Subdivision { code: "SI-202", name: "Središče ob Dravi", parent: None },
+    Subdivision { code: "SI-203", name: "Straža", parent: None },
+    Subdivision { code: "SI-204", name: "Sveta Trojica v Slovenskih goricah", parent: None },
+    Subdivision { code: "SI-205", name: "Sveti Tomaž", parent: None },
+    Subdivision { code: "SI-206", name: "Šmarješke Toplice", parent: None },
+    Subdivision { code: "SI-207", name: "Gorje", parent: None },
+    Subdivision { code: "SI-208", name: "Log-Dragomer", parent: None },
+    Subdivision { code: "SI-209", name: "Rečica ob Savinji", parent: None },
+    Subdivision { code: "SI-210", name: "Sveti Jurij v Slovenskih goricah", parent: None },
+    Subdivision { code: "SI-211", name: "Šentrupert", parent: None },
+    Subdivision { code: "SI-212", name: "Mirna", parent: None },
+    Subdivision { code: "SI-213", name: "Ankaran", parent: None },
+    Subdivision { code: "SK-BC", name: "Banskobystrický kraj", parent: None },
+    Subdivision { code: "SK-BL", name: "Bratislavský kraj", parent: None },
+    Subdivision { code: "SK-KI", name: "Košický kraj", parent: None },
+    Subdivision { code: "SK-NI", name: "Nitriansky kraj", parent: None },
+    Subdivision { code: "SK-PV", name: "Prešovský kraj", parent: None },
+    Subdivision { code: "SK-TA", name: "Trnavský kraj", parent: None },
+    Subdivision { code: "SK-TC", name: "Trenčiansky kraj", parent: None },
+    Subdivision { code: "SK-ZI", name: "Žilinský kraj", parent: None },
+    Subdivision { code: "SL-E", name: "Eastern", parent: None },
+    Subdivision { code: "SL-N", name: "Northern", parent: None },
+    Subdivision { code: "SL-NW", name: "North Western", parent: None },
+    Subdivision { code: "SL-S", name: "Southern", parent: None },
+    Subdivision { code: "SL-W", name: "Western Area (Freetown)", parent: None },
+    Subdivision { code: "SM-01", name: "Acquaviva", parent: None },
+    Subdivision { code: "SM-02", name: "Chiesanuova", parent: None },
+    Subdivision { code: "SM-03", name: "Domagnano", parent: None },
+    Subdivision { code: "SM-04", name: "Faetano", parent: None },
+    Subdivision { code: "SM-05", name: "Fiorentino", parent: None },
+    Subdivision { code: "SM-06", name: "Borgo Maggiore", parent: None },
+    Subdivision { code: "SM-07", name: "Città di San Marino", parent: None },
+    Subdivision { code: "SM-08", name: "Montegiardino", parent: None },
+    Subdivision { code: "SM-09", name: "Serravalle", parent: None },
+    Subdivision { code: "SN-DB", name: "Diourbel", parent: None },
+    Subdivision { code: "SN-DK", name: "Dakar", parent: None },
+    Subdivision { code: "SN-FK", name: "Fatick", parent: None },
+    Subdivision { code: "SN-KA", name: "Kaffrine", parent: None },
+    Subdivision { code: "SN-KD", name: "Kolda", parent: None },
+    Subdivision { code: "SN-KE", name: "Kédougou", parent: None },
+    Subdivision { code: "SN-KL", name: "Kaolack", parent: None },
+    Subdivision { code: "SN-LG", name: "Louga", parent: None },
+    Subdivision { code: "SN-MT", name: "Matam", parent: None },
+    Subdivision { code: "SN-SE", name: "Sédhiou", parent: None },
+    Subdivision { code: "SN-SL", name: "Saint-Louis", parent: None },
+    Subdivision { code: "SN-TC", name: "Tambacounda", parent: None },
+    Subdivision { code: "SN-TH", name: "Thiès", parent: None },
+    Subdivision { code: "SN-ZG", name: "Ziguinchor", parent: None },
+    Subdivision { code: "SO-AW", name: "Awdal", parent: None },
+    Subdivision { code: "SO-BK", name: "Bakool", parent: None },
+    Subdivision { code: "SO-BN", name: "Banaadir", parent: None },
+    Subdivision { code: "SO-BR", name: "Bari", parent: None },
+    Subdivision { code: "SO-BY", name: "Bay", parent: None },
+    Subdivision { code: "SO-GA", name: "Galguduud", parent: None },
+    Subdivision { code: "SO-GE", name: "Gedo", parent: None },
+    Subdivision { code: "SO-HI", name: "Hiiraan", parent: None },
+    Subdivision { code: "SO-JD", name: "Jubbada Dhexe", parent: None },
+    Subdivision { code: "SO-JH", name: "Jubbada Hoose", parent: None },
+    Subdivision { code: "SO-MU", name: "Mudug", parent: None },
+    Subdivision { code: "SO-NU", name: "Nugaal", parent: None },
+    Subdivision { code: "SO-SA", name: "Sanaag", parent: None },
+    Subdivision { code: "SO-SD", name: "Shabeellaha Dhexe", parent: None },
+    Subdivision { code: "SO-SH", name: "Shabeellaha Hoose", parent: None },
+    Subdivision { code: "SO-SO", name: "Sool", parent: None },
+    Subdivision { code: "SO-TO", name: "Togdheer", parent: None },
+    Subdivision { code: "SO-WO", name: "Woqooyi Galbeed", parent: None },
+    Subdivision { code: "SR-BR", name: "Brokopondo", parent: None },
+    Subdivision { code: "SR-CM", name: "Commewijne", parent: None },
+    Subdivision { code: "SR-CR", name: "Coronie", parent: None },
+    Subdivision { code: "SR-MA", name: "Marowijne", parent: None },
+    Subdivision { code: "SR-NI", name: "Nickerie", parent: None },
+    Subdivision { code: "SR-PM", name: "Paramaribo", parent: None },
+    Subdivision { code: "SR-PR", name: "Para", parent: None },
+    Subdivision { code: "SR-SA", name: "Saramacca", parent: None },
+    Subdivision { code: "SR-SI", name: "Sipaliwini", parent: None },
+    Subdivision { code: "SR-WA", name: "Wanica", parent: None },
+    Subdivision { code: "SS-BN", name: "Northern Bahr el Ghazal", parent: None },
+    Subdivision { code: "SS-BW", name: "Western Bahr el Ghazal", parent: None },
+    Subdivision { code: "SS-EC", name: "Central Equatoria", parent: None },
+    Subdivision { code: "SS-EE", name: "Eastern Equatoria", parent: None },
+    Subdivision { code: "SS-EW", name: "Western Equatoria", parent: None },
+    Subdivision { code: "SS-JG", name: "Jonglei", parent: None },
+    Subdivision { code: "SS-LK", name: "Lakes", parent: None },
+    Subdivision { code: "SS-NU", name: "Upper Nile", parent: None },
+    Subdivision { code: "SS-UY", name: "Unity", parent: None },
+    Subdivision { code: "SS-WR", name: "Warrap", parent: None },
+    Subdivision { code: "ST-01", name: "Água Grande", parent: None },
+    Subdivision { code: "ST-02", name: "Cantagalo", parent: None },
+    Subdivision { code: "ST-03", name: "Caué", parent: None },
+    Subdivision { code: "ST-04", name: "Lembá", parent: None },
+    Subdivision { code: "ST-05", name: "Lobata", parent: None },
+    Subdivision { code: "ST-06", name: "Mé-Zóchi", parent: None },
+    Subdivision { code: "ST-P", name: "Príncipe", parent: None },
+    Subdivision { code: "SV-AH", name: "Ahuachapán", parent: None },
+    Subdivision { code: "SV-CA", name: "Cabañas", parent: None },
+    Subdivision { code: "SV-CH", name: "Chalatenango", parent: None },
+    Subdivision { code: "SV-CU", name: "Cuscatlán", parent: None },
+    Subdivision { code: "SV-LI", name: "La Libertad", parent: None },
+    Subdivision { code: "SV-MO", name: "Morazán", parent: None },
+    Subdivision { code: "SV-PA", name: "La Paz", parent: None },
+    Subdivision { code: "SV-SA", name: "Santa Ana", parent: None },
+    Subdivision { code: "SV-SM", name: "San Miguel", parent: None },
+    Subdivision { code: "SV-SO", name: "Sonsonate", parent: None },
+    Subdivision { code: "SV-SS", name: "San Salvador", parent: None },
+    Subdivision { code: "SV-SV", name: "San Vicente", parent: None },
+    Subdivision { code: "SV-UN", name: "La Unión", parent: None },
+    Subdivision { code: "SV-US", name: "Usulután", parent: None },
+    Subdivision { code: "SY-DI", name: "Dimashq", parent: None },
+    Subdivision { code: "SY-DR", name: "Dar'ā", parent: None },
+    Subdivision { code: "SY-DY", name: "Dayr az Zawr", parent: None },
+    Subdivision { code: "SY-HA", name: "Al Ḩasakah", parent: None },
+    Subdivision { code: "SY-HI", name: "Ḩimş", parent: None },
+    Subdivision { code: "SY-HL", name: "Ḩalab", parent: None },
+    Subdivision { code: "SY-HM", name: "Ḩamāh", parent: None },
+    Subdivision { code: "SY-ID", name: "Idlib", parent: None },
+    Subdivision { code: "SY-LA", name: "Al Lādhiqīyah", parent: None },
+    Subdivision { code: "SY-QU", name: "Al Qunayţirah", parent: None },
+    Subdivision { code: "SY-RA", name: "Ar Raqqah", parent: None },
+    Subdivision { code: "SY-RD", name: "Rīf Dimashq", parent: None },
+    Subdivision { code: "SY-SU", name: "As Suwaydā'", parent: None },
+    Subdivision { code: "SY-TA", name: "Ţarţūs", parent: None },
+    Subdivision { code: "SZ-HH", name: "Hhohho", parent: None },
+    Subdivision { code: "SZ-LU", name: "Lubombo", parent: None },
+    Subdivision { code: "SZ-MA", name: "Manzini", parent: None },
+    Subdivision { code: "SZ-SH", name: "Shiselweni", parent: None },
+    Subdivision { code: "TD-BA", name: "Al Baţḩā’", parent: None },
+    Subdivision { code: "TD-BG", name: "Bahr el Ghazal", parent: None },
+    Subdivision { code: "TD-BO", name: "Borkou", parent: None },
+    Subdivision { code: "TD-CB", name: "Chari-Baguirmi", parent: None },
+    Subdivision { code: "TD-EE", name: "Ennedi-Est", parent: None },
+    Subdivision { code: "TD-EO", name: "Ennedi-Ouest", parent: None },
+    Subdivision { code: "TD-GR", name: "Guéra", parent: None },
+    Subdivision { code: "TD-HL", name: "Hadjer Lamis", parent: None },
+    Subdivision { code: "TD-KA", name: "Kanem", parent: None },
+    Subdivision { code: "TD-LC", name: "Al Buḩayrah", parent: None },
+    Subdivision { code: "TD-LO", name: "Logone-Occidental", parent: None },
+    Subdivision { code: "TD-LR", name: "Logone-Oriental", parent: None },
+    Subdivision { code: "TD-MA", name: "Mandoul", parent: None },
+    Subdivision { code: "TD-MC", name: "Moyen-Chari", parent: None },
+    Subdivision { code: "TD-ME", name: "Mayo-Kebbi-Est", parent: None },
+    Subdivision { code: "TD-MO", name: "Mayo-Kebbi-Ouest", parent: None },
+    Subdivision { code: "TD-ND", name: "Madīnat Injamīnā", parent: None },
+    Subdivision { code: "TD-OD", name: "Ouaddaï", parent: None },
+    Subdivision { code: "TD-SA", name: "Salamat", parent: None },
+    Subdivision { code: "TD-SI", name: "Sila", parent: None },
+    Subdivision { code: "TD-TA", name: "Tandjilé", parent: None },
+    Subdivision { code: "TD-TI", name: "Tibastī", parent: None },
+    Subdivision { code: "TD-WF", name: "Wadi Fira", parent: None },
+    Subdivision { code: "TG-C", name: "Centrale", parent: None },
+    Subdivision { code: "TG-K", name: "Kara", parent: None },
+    Subdivision { code: "TG-M", name: "Maritime (Région)", parent: None },
+    Subdivision { code: "TG-P", name: "Plateaux", parent: None },
+    Subdivision { code: "TG-S", name: "Savanes", parent: None },
+    Subdivision { code: "TH-10", name: "Krung Thep Maha Nakhon", parent: None },
+    Subdivision { code: "TH-11", name: "Samut Prakan", parent: None },
+    Subdivision { code: "TH-12", name: "Nonthaburi", parent: None },
+    Subdivision { code: "TH-13", name: "Pathum Thani", parent: None },
+    Subdivision { code: "TH-14", name: "Phra Nakhon Si Ayutthaya", parent: None },
+    Subdivision { code: "TH-15", name: "Ang Thong", parent: None },
+    Subdivision { code: "TH-16", name: "Lop Buri", parent: None },
+    Subdivision { code: "TH-17", name: "Sing Buri", parent: None },
+    Subdivision { code: "TH-18", name: "Chai Nat", parent: None },
+    Subdivision { code: "TH-19", name: "Saraburi", parent: None },
+    Subdivision { code: "TH-20", name: "Chon Buri", parent: None },
+    Subdivision { code: "TH-21", name: "Rayong", parent: None },
+    Subdivision { code: "TH-22", name: "Chanthaburi", parent: None },
+    Subdivision { code: "TH-23", name: "Trat", parent: None },
+    Subdivision { code: "TH-24", name: "Chachoengsao", parent: None },
+    Subdivision { code: "TH-25", name: "Prachin Buri", parent: None },
+    Subdivision { code: "TH-26", name: "Nakhon Nayok", parent: None },
+    Subdivision { code: "TH-27", name: "Sa Kaeo", parent: None },
+    Subdivision { code: "TH-30", name: "Nakhon Ratchasima", parent: None },
+    Subdivision { code: "TH-31", name: "Buri Ram", parent: None },
+    Subdivision { code: "TH-32", name: "Surin", parent: None },
+    Subdivision { code: "TH-33", name: "Si Sa Ket", parent: None },
+    Subdivision { code: "TH-34", name: "Ubon Ratchathani", parent: None },
+    Subdivision { code: "TH-35", name: "Yasothon", parent: None },
+    Subdivision { code: "TH-36", name: "Chaiyaphum", parent: None },
+    Subdivision { code: "TH-37", name: "Amnat Charoen", parent: None },
+    Subdivision { code: "TH-38", name: "Bueng Kan", parent: None },
+    Subdivision { code: "TH-39", name: "Nong Bua Lam Phu", parent: None },
+    Subdivision { code: "TH-40", name: "Khon Kaen", parent: None },
+    Subdivision { code: "TH-41", name: "Udon Thani", parent: None },
+    Subdivision { code: "TH-42", name: "Loei", parent: None },
+    Subdivision { code: "TH-43", name: "Nong Khai", parent: None },
+    Subdivision { code: "TH-44", name: "Maha Sarakham", parent: None },
+    Subdivision { code: "TH-45", name: "Roi Et", parent: None },
+    Subdivision { code: "TH-46", name: "Kalasin", parent: None },
+    Subdivision { code: "TH-47", name: "Sakon Nakhon", parent: None },
+    Subdivision { code: "TH-48", name: "Nakhon Phanom", parent: None },
+    Subdivision { code: "TH-49", name: "Mukdahan", parent: None },
+    Subdivision { code: "TH-50", name: "Chiang Mai", parent: None },
+    Subdivision { code: "TH-51", name: "Lamphun", parent: None },
+    Subdivision { code: "TH-52", name: "Lampang", parent: None },
+    Subdivision { code: "TH-53", name: "Uttaradit", parent: None },
+    Subdivision { code: "TH-54", name: "Phrae", parent: None },
+    Subdivision { code: "TH-55", name: "Nan", parent: None },
+    Subdivision { code: "TH-56", name: "Phayao", parent: None },
+    Subdivision { code: "TH-57", name: "Chiang Rai", parent: None },
+    Subdivision { code: "TH-58", name: "Mae Hong Son", parent: None },
+    Subdivision { code: "TH-60", name: "Nakhon Sawan", parent: None },
+    Subdivision { code: "TH-61", name: "Uthai Thani", parent: None },
+    Subdivision { code: "TH-62", name: "Kamphaeng Phet", parent: None },
+    Subdivision { code: "TH-63", name: "Tak", parent: None },
+    Subdivision { code: "TH-64", name: "Sukhothai", parent: None },
+    Subdivision { code: "TH-65", name: "Phitsanulok", parent: None },
+    Subdivision { code: "TH-66", name: "Phichit", parent: None },
+    Subdivision { code: "TH-67", name: "Phetchabun", parent: None },
+    Subdivision { code: "TH-70", name: "Ratchaburi", parent: None },
+    Subdivision { code: "TH-71", name: "Kanchanaburi", parent: None },
+    Subdivision { code: "TH-72", name: "Suphan Buri", parent: None },
+    Subdivision { code: "TH-73", name: "Nakhon Pathom", parent: None },
+    Subdivision { code: "TH-74", name: "Samut Sakhon", parent: None },
+    Subdivision { code: "TH-75", name: "Samut Songkhram", parent: None },
+    Subdivision { code: "TH-76", name: "Phetchaburi", parent: None },
+    Subdivision { code: "TH-77", name: "Prachuap Khiri Khan", parent: None },
+    Subdivision { code: "TH-80", name: "Nakhon Si Thammarat", parent: None },
+    Subdivision { code: "TH-81", name: "Krabi", parent: None },
+    Subdivision { code: "TH-82", name: "Phangnga", parent: None },
+    Subdivision { code: "TH-83", name: "Phuket", parent: None },
+    Subdivision { code: "TH-84", name: "Surat Thani", parent: None },
+    Subdivision { code: "TH-85", name: "Ranong", parent: None },
+    Subdivision { code: "TH-86", name: "Chumphon", parent: None },
+    Subdivision { code: "TH-90", name: "Songkhla", parent: None },
+    Subdivision { code: "TH-91", name: "Satun", parent: None },
+    Subdivision { code: "TH-92", name: "Trang", parent: None },
+    Subdivision { code: "TH-93", name: "Phatthalung", parent: None },
+    Subdivision { code: "TH-94", name: "Pattani", parent: None },
+    Subdivision { code: "TH-95", name: "Yala", parent: None },
+    Subdivision { code: "TH-96", name: "Narathiwat", parent: None },
+    Subdivision { code: "TH-S", name: "Phatthaya", parent: None },
+    Subdivision { code: "TJ-DU", name: "Dushanbe", parent: None },
+    Subdivision { code: "TJ-GB", name: "Kŭhistoni Badakhshon", parent: None },
+    Subdivision { code: "TJ-KT", name: "Khatlon", parent: None },
+    Subdivision { code: "TJ-RA", name: "nohiyahoi tobei jumhurí", parent: None },
+    Subdivision { code: "TJ-SU", name: "Sughd", parent: None },
+    Subdivision { code: "TL-AL", name: "Aileu", parent: None },
+    Subdivision { code: "TL-AN", name: "Ainaro", parent: None },
+    Subdivision { code: "TL-BA", name: "Baucau", parent: None },
+    Subdivision { code: "TL-BO", name: "Bobonaro", parent: None },
+    Subdivision { code: "TL-CO", name: "Cova Lima", parent: None },
+    Subdivision { code: "TL-DI", name: "Díli", parent: None },
+    Subdivision { code: "TL-ER", name: "Ermera", parent: None },
+    Subdivision { code: "TL-LA", name: "Lautein", parent: None },
+    Subdivision { code: "TL-LI", name: "Likisá", parent: None },
+    Subdivision { code: "TL-MF", name: "Manufahi", parent: None },
+    Subdivision { code: "TL-MT", name: "Manatuto", parent: None },
+    Subdivision { code: "TL-OE", name: "Oekusi-Ambenu", parent: None },
+    Subdivision { code: "TL-VI", name: "Vikeke", parent: None },
+    Subdivision { code: "TM-A", name: "Ahal", parent: None },
+    Subdivision { code: "TM-B", name: "Balkan", parent: None },
+    Subdivision { code: "TM-D", name: "Daşoguz", parent: None },
+    Subdivision { code: "TM-L", name: "Lebap", parent: None },
+    Subdivision { code: "TM-M", name: "Mary", parent: None },
+    Subdivision { code: "TM-S", name: "Aşgabat", parent: None },
+    Subdivision { code: "TN-11", name: "Tunis", parent: None },
+    Subdivision { code: "TN-12", name: "L'Ariana", parent: None },
+    Subdivision { code: "TN-13", name: "Ben Arous", parent: None },
+    Subdivision { code: "TN-14", name: "La Manouba", parent: None },
+    Subdivision { code: "TN-21", name: "Nabeul", parent: None },
+    Subdivision { code: "TN-22", name: "Zaghouan", parent: None },
+    Subdivision { code: "TN-23", name: "Bizerte", parent: None },
+    Subdivision { code: "TN-31", name: "Béja", parent: None },
+    Subdivision { code: "TN-32", name: "Jendouba", parent: None },
+    Subdivision { code: "TN-33", name: "Le Kef", parent: None },
+    Subdivision { code: "TN-34", name: "Siliana", parent: None },
+    Subdivision { code: "TN-41", name: "Kairouan", parent: None },
+    Subdivision { code: "TN-42", name: "Kasserine", parent: None },
+    Subdivision { code: "TN-43", name: "Sidi Bouzid", parent: None },
+    Subdivision { code: "TN-51", name: "Sousse", parent: None },
+    Subdivision { code: "TN-52", name: "Monastir", parent: None },
+    Subdivision { code: "TN-53", name: "Mahdia", parent: None },
+    Subdivision { code: "TN-61", name: "Sfax", parent: None },
+    Subdivision { code: "TN-71", name: "Gafsa", parent: None },
+    Subdivision { code: "TN-72", name: "Tozeur", parent: None },
+    Subdivision { code: "TN-73", name: "Kébili", parent: None },
+    Subdivision { code: "TN-81", name: "Gabès", parent: None },
+    Subdivision { code: "TN-82", name: "Médenine", parent: None },
+    Subdivision { code: "TN-83", name: "Tataouine", parent: None },
+    Subdivision { code: "TO-01", name: "'Eua", parent: None },
+    Subdivision { code: "TO-02", name: "Ha'apai", parent: None },
+    Subdivision { code: "TO-03", name: "Niuas", parent: None },
+    Subdivision { code: "TO-04", name: "Tongatapu", parent: None },
+    Subdivision { code: "TO-05", name: "Vava'u", parent: None },
+    Subdivision { code: "TR-01", name: "Adana", parent: None },
+    Subdivision { code: "TR-02", name: "Adıyaman", parent: None },
+    Subdivision { code: "TR-03", name: "Afyonkarahisar", parent: None },
+    Subdivision { code: "TR-04", name: "Ağrı", parent: None },
+    Subdivision { code: "TR-05", name: "Amasya", parent: None },
+    Subdivision { code: "TR-06", name: "Ankara", parent: None },
+    Subdivision { code: "TR-07", name: "Antalya", parent: None },
+    Subdivision { code: "TR-08", name: "Artvin", parent: None },
+    Subdivision { code: "TR-09", name: "Aydın", parent: None },
+    Subdivision { code: "TR-10", name: "Balıkesir", parent: None },
+    Subdivision { code: "TR-11", name: "Bilecik", parent: None },
+    Subdivision { code: "TR-12", name: "Bingöl", parent: None },
+    Subdivision { code: "TR-13", name: "Bitlis", parent: None },
+    Subdivision { code: "TR-14", name: "Bolu", parent: None },
+    Subdivision { code: "TR-15", name: "Burdur", parent: None },
+    Subdivision { code: "TR-16", name: "Bursa", parent: None },
+    Subdivision { code: "TR-17", name: "Çanakkale", parent: None },
+    Subdivision { code: "TR-18", name: "Çankırı", parent: None },
+    Subdivision { code: "TR-19", name: "Çorum", parent: None },
+    Subdivision { code: "TR-20", name: "Denizli", parent: None },
+    Subdivision { code: "TR-21", name: "Diyarbakır", parent: None },
+    Subdivision { code: "TR-22", name: "Edirne", parent: None },
+    Subdivision { code: "TR-23", name: "Elazığ", parent: None },
+    Subdivision { code: "TR-24", name: "Erzincan", parent: None },
+    Subdivision { code: "TR-25", name: "Erzurum", parent: None },
+    Subdivision { code: "TR-26", name: "Eskişehir", parent: None },
+    Subdivision { code: "TR-27", name: "Gaziantep", parent: None },
+    Subdivision { code: "TR-28", name: "Giresun", parent: None },
+    Subdivision { code: "TR-29", name: "Gümüşhane", parent: None },
+    Subdivision { code: "TR-30", name: "Hakkâri", parent: None },
+    Subdivision { code: "TR-31", name: "Hatay", parent: None },
+    Subdivision { code: "TR-32", name: "Isparta", parent: None },
+    Subdivision { code: "TR-33", name: "Mersin", parent: None },
+    Subdivision { code: "TR-34", name: "İstanbul", parent: None },
+    Subdivision { code: "TR-35", name: "İzmir", parent: None },
+    Subdivision { code: "TR-36", name: "Kars", parent: None },
+    Subdivision { code: "TR-37", name: "Kastamonu", parent: None },
+    Subdivision { code: "TR-38", name: "Kayseri", parent: None },
+    Subdivision { code: "TR-39", name: "Kırklareli", parent: None },
+    Subdivision { code: "TR-40", name: "Kırşehir", parent: None },
+    Subdivision { code: "TR-41", name: "Kocaeli", parent: None },
+    Subdivision { code: "TR-42", name: "Konya", parent: None },
+    Subdivision { code: "TR-43", name: "Kütahya", parent: None },
+    Subdivision { code: "TR-44", name: "Malatya", parent: None },
+    Subdivision { code: "TR-45", name: "Manisa", parent: None },
+    Subdivision { code: "TR-46", name: "Kahramanmaraş", parent: None },
+    Subdivision { code: "TR-47", name: "Mardin", parent: None },
+    Subdivision { code: "TR-48", name: "Muğla", parent: None },
+    Subdivision { code: "TR-49", name: "Muş", parent: None },
+    Subdivision { code: "TR-50", name: "Nevşehir", parent: None },
+    Subdivision { code: "TR-51", name: "Niğde", parent: None },
+    Subdivision { code: "TR-52", name: "Ordu", parent: None },
+    Subdivision { code: "TR-53", name: "Rize", parent: None },
+    Subdivision { code: "TR-54", name: "Sakarya", parent: None },
+    Subdivision { code: "TR-55", name: "Samsun", parent: None },
+    Subdivision { code: "TR-56", name: "Siirt", parent: None },
+    Subdivision { code: "TR-57", name: "Sinop", parent: None },
+    Subdivision { code: "TR-58", name: "Sivas", parent: None },
+    Subdivision { code: "TR-59", name: "Tekirdağ", parent: None },
+    Subdivision { code: "TR-60", name: "Tokat", parent: None },
+    Subdivision { code: "TR-61", name: "Trabzon", parent: None },
+    Subdivision { code: "TR-62", name: "Tunceli", parent: None },
+    Subdivision { code: "TR-63", name: "Şanlıurfa", parent: None },
+    Subdivision { code: "TR-64", name: "Uşak", parent: None },
+    Subdivision { code: "TR-65", name: "Van", parent: None },
+    Subdivision { code: "TR-66", name: "Yozgat", parent: None },
+    Subdivision { code: "TR-67", name: "Zonguldak", parent: None },
+    Subdivision { code: "TR-68", name: "Aksaray", parent: None },
+    Subdivision { code: "TR-69", name: "Bayburt", parent: None },
+    Subdivision { code: "TR-70", name: "Karaman", parent: None },
+    Subdivision { code: "TR-71", name: "Kırıkkale", parent: None },
+    Subdivision { code: "TR-72", name: "Batman", parent: None },
+    Subdivision { code: "TR-73", name: "Şırnak", parent: None },
+    Subdivision { code: "TR-74", name: "Bartın", parent: None },
+    Subdivision { code: "TR-75", name: "Ardahan", parent: None },
+    Subdivision { code: "TR-76", name: "Iğdır", parent: None },
+    Subdivision { code: "TR-77", name: "Yalova", parent: None },
+    Subdivision { code: "TR-78", name: "Karabük", parent: None },
+    Subdivision { code: "TR-79", name: "Kilis", parent: None },
+    Subdivision { code: "TR-80", name: "Osmaniye", parent: None },
+    Subdivision { code: "TR-81", name: "Düzce", parent: None },
+    Subdivision { code: "TT-ARI", name: "Arima", parent: None },
+    Subdivision { code: "TT-CHA", name: "Chaguanas", parent: None },
+    Subdivision { code: "TT-CTT", name: "Couva-Tabaquite-Talparo", parent: None },
+    Subdivision { code: "TT-DMN", name: "Diego Martin", parent: None },
+    Subdivision { code: "TT-MRC", name: "Mayaro-Rio Claro", parent: None },
+    Subdivision { code: "TT-PED", name: "Penal-Debe", parent: None },
+    Subdivision { code: "TT-POS", name: "Port of Spain", parent: None },
+    Subdivision { code: "TT-PRT", name: "Princes Town", parent: None },
+    Subdivision { code: "TT-PTF", name: "Point Fortin", parent: None },
+    Subdivision { code: "TT-SFO", name: "San Fernando", parent: None },
+    Subdivision { code: "TT-SGE", name: "Sangre Grande", parent: None },
+    Subdivision { code: "TT-SIP", name: "Siparia", parent: None },
+    Subdivision { code: "TT-SJL", name: "San Juan-Laventille", parent: None },
+    Subdivision { code: "TT-TOB", name: "Tobago", parent: None },
+    Subdivision { code: "TT-TUP", name: "Tunapuna-Piarco", parent: None },
+    Subdivision { code: "TV-FUN", name: "Funafuti", parent: None },
+    Subdivision { code: "TV-NIT", name: "Niutao", parent: None },
+    Subdivision { code: "TV-NKF", name: "Nukufetau", parent: None },
+    Subdivision { code: "TV-NKL", name: "Nukulaelae", parent: None },
+    Subdivision { code: "TV-NMA", name: "Nanumea", parent: None },
+    Subdivision { code: "TV-NMG", name: "Nanumaga", parent: None },
+    Subdivision { code: "TV-NUI", name: "Nui", parent: None },
+    Subdivision { code: "TV-VAI", name: "Vaitupu", parent: None },
+    Subdivision { code: "TW-CHA", name: "Changhua", parent: None },
+    Subdivision { code: "TW-CYI", name: "Chiayi", parent: None },
+    Subdivision { code: "TW-CYQ", name: "Chiayi", parent: None },
+    Subdivision { code: "TW-HSQ", name: "Hsinchu", parent: None },
+    Subdivision { code: "TW-HSZ", name: "Hsinchu", parent: None },
+    Subdivision { code: "TW-HUA", name: "Hualien", parent: None },
+    Subdivision { code: "TW-ILA", name: "Yilan", parent: None },
+    Subdivision { code: "TW-KEE", name: "Keelung", parent: None },
+    Subdivision { code: "TW-KHH", name: "Kaohsiung", parent: None },
+    Subdivision { code: "TW-KIN", name: "Kinmen", parent: None },
+    Subdivision { code: "TW-LIE", name: "Lienchiang", parent: None },
+    Subdivision { code: "TW-MIA", name: "Miaoli", parent: None },
+    Subdivision { code: "TW-NAN", name: "Nantou", parent: None },
+    Subdivision { code: "TW-NWT", name: "New Taipei", parent: None },
+    Subdivision { code: "TW-PEN", name: "Penghu", parent: None },
+    Subdivision { code: "TW-PIF", name: "Pingtung", parent: None },
+    Subdivision { code: "TW-TAO", name: "Taoyuan", parent: None },
+    Subdivision { code: "TW-TNN", name: "Tainan", parent: None },
+    Subdivision { code: "TW-TPE", name: "Taipei", parent: None },
+    Subdivision { code: "TW-TTT", name: "Taitung", parent: None },
+    Subdivision { code: "TW-TXG", name: "Taichung", parent: None },
+    Subdivision { code: "TW-YUN", name: "Yunlin", parent: None },
+    Subdivision { code: "TZ-01", name: "Arusha", parent: None },
+    Subdivision { code: "TZ-02", name: "Dar es Salaam", parent: None },
+    Subdivision { code: "TZ-03", name: "Dodoma", parent: None },
+    Subdivision { code: "TZ-04", name: "Iringa", parent: None },
+    Subdivision { code: "TZ-05", name: "Kagera", parent: None },
+    Subdivision { code: "TZ-06", name: "Pemba North", parent: None },
+    Subdivision { code: "TZ-07", name: "Zanzibar North", parent: None },
+    Subdivision { code: "TZ-08", name: "Kigoma", parent: None },
+    Subdivision { code: "TZ-09", name: "Kilimanjaro", parent: None },
+    Subdivision { code: "TZ-10", name: "Pemba South", parent: None },
+    Subdivision { code: "TZ-11", name: "Zanzibar South", parent: None },
+    Subdivision { code: "TZ-12", name: "Lindi", parent: None },
+    Subdivision { code: "TZ-13", name: "Mara", parent: None },
+    Subdivision { code: "TZ-14", name: "Mbeya", parent: None },
+    Subdivision { code: "TZ-15", name: "Zanzibar West", parent: None },
+    Subdivision { code: "TZ-16", name: "Morogoro", parent: None },
+    Subdivision { code: "TZ-17", name: "Mtwara", parent: None },
+    Subdivision { code: "TZ-18", name: "Mwanza", parent: None },
+    Subdivision { code: "TZ-19", name: "Coast", parent: None },
+    Subdivision { code: "TZ-20", name: "Rukwa", parent: None },
+    Subdivision { code: "TZ-21", name: "Ruvuma", parent: None },
+    Subdivision { code: "TZ-22", name: "Shinyanga", parent: None },
+    Subdivision { code: "TZ-23", name: "Singida", parent: None },
+    Subdivision { code: "TZ-24", name: "Tabora", parent: None },
+    Subdivision { code: "TZ-25", name: "Tanga", parent: None },
+    Subdivision { code: "TZ-26", name: "Manyara", parent: None },
+    Subdivision { code: "TZ-27", name: "Geita", parent: None },
+    Subdivision { code: "TZ-28", name: "Katavi", parent: None },
+    Subdivision { code: "TZ-29", name: "Njombe", parent: None },
+    Subdivision { code: "TZ-30", name: "Simiyu", parent: None },
+    Subdivision { code: "TZ-31", name: "Songwe", parent: None },
+    Subdivision { code: "UA-05", name: "Vinnytska oblast", parent: None },
+    Subdivision { code: "UA-07", name: "Volynska oblast", parent: None },
+    Subdivision { code: "UA-09", name: "Luhanska oblast", parent: None },
+    Subdivision { code: "UA-12", name: "Dnipropetrovska oblast", parent: None },
+    Subdivision { code: "UA-14", name: "Donetska oblast", parent: None },
+    Subdivision { code: "UA-18", name: "Zhytomyrska oblast", parent: None },
+    Subdivision { code: "UA-21", name: "Zakarpatska oblast", parent: None },
+    Subdivision { code: "UA-23", name: "Zaporizka oblast", parent: None },
+    Subdivision { code: "UA-26", name: "Ivano-Frankivska oblast", parent: None },
+    Subdivision { code: "UA-30", name: "Kyiv", parent: None },
+    Subdivision { code: "UA-32", name: "Kyivska oblast", parent: None },
+    Subdivision { code: "UA-35", name: "Kirovohradska oblast", parent: None },
+    Subdivision { code: "UA-40", name: "Sevastopol", parent: None },
+    Subdivision { code: "UA-43", name: "Avtonomna Respublika Krym", parent: None },
+    Subdivision { code: "UA-46", name: "Lvivska oblast", parent: None },
+    Subdivision { code: "UA-48", name: "Mykolaivska oblast", parent: None },
+    Subdivision { code: "UA-51", name: "Odeska oblast", parent: None },
+    Subdivision { code: "UA-53", name: "Poltavska oblast", parent: None },
+    Subdivision { code: "UA-56", name: "Rivnenska oblast", parent: None },
+    Subdivision { code: "UA-59", name: "Sumska oblast", parent: None },
+    Subdivision { code: "UA-61", name: "Ternopilska oblast", parent: None },
+    Subdivision { code: "UA-63", name: "Kharkivska oblast", parent: None },
+    Subdivision { code: "UA-65", name: "Khersonska oblast", parent: None },
+    Subdivision { code: "UA-68", name: "Khmelnytska oblast", parent: None },
+    Subdivision { code: "UA-71", name: "Cherkaska oblast", parent: None },
+    Subdivision { code: "UA-74", name: "Chernihivska oblast", parent: None },
+    Subdivision { code: "UA-77", name: "Chernivetska oblast", parent: None },
+    Subdivision { code: "UG-101", name: "Kalangala", parent: Some("UG-C") },
+    Subdivision { code: "UG-102", name: "Kampala", parent: Some("UG-C") },
+    Subdivision { code: "UG-103", name: "Kiboga", parent: Some("UG-C") },
+    Subdivision { code: "UG-104", name: "Luwero", parent: Some("UG-C") },
+    Subdivision { code: "UG-105", name: "Masaka", parent: Some("UG-C") },
+    Subdivision { code: "UG-106", name: "Mpigi", parent: Some("UG-C") },
+    Subdivision { code: "UG-107", name: "Mubende", parent: Some("UG-C") },
+    Subdivision { code: "UG-108", name: "Mukono", parent: Some("UG-C") },
+    Subdivision { code: "UG-109", name: "Nakasongola", parent: Some("UG-C") },
+    Subdivision { code: "UG-110", name: "Rakai", parent: Some("UG-C") },
+    Subdivision { code: "UG-111", name: "Sembabule", parent: Some("UG-C") },
+    Subdivision { code: "UG-112", name: "Kayunga", parent: Some("UG-C") },
+    Subdivision { code: "UG-113", name: "Wakiso", parent: Some("UG-C") },
+    Subdivision { code: "UG-114", name: "Lyantonde", parent: Some("UG-C") },
+    Subdivision { code: "UG-115", name: "Mityana", parent: Some("UG-C") },
+    Subdivision { code: "UG-116", name: "Nakaseke", parent: Some("UG-C") },
+    Subdivision { code: "UG-117", name: "Buikwe", parent: Some("UG-C") },
+    Subdivision { code: "UG-118", name: "Bukomansibi", parent: Some("UG-C") },
+    Subdivision { code: "UG-119", name: "Butambala", parent: Some("UG-C") },
+    Subdivision { code: "UG-120", name: "Buvuma", parent: Some("UG-C") },
+    Subdivision { code: "UG-121", name: "Gomba", parent: Some("UG-C") },
+    Subdivision { code: "UG-122", name: "Kalungu", parent: Some("UG-C") },
+    Subdivision { code: "UG-123", name: "Kyankwanzi", parent: Some("UG-C") },
+    Subdivision { code: "UG-124", name: "Lwengo", parent: Some("UG-C") },
+    Subdivision { code: "UG-125", name: "Kyotera", parent: Some("UG-C") },
+    Subdivision { code: "UG-126", name: "Kasanda", parent: Some("UG-C") },
+    Subdivision { code: "UG-201", name: "Bugiri", parent: Some("UG-E") },
+    Subdivision { code: "UG-202", name: "Busia", parent: Some("UG-E") },
+    Subdivision { code: "UG-203", name: "Iganga", parent: Some("UG-E") },
+    Subdivision { code: "UG-204", name: "Jinja", parent: Some("UG-E") },
+    Subdivision { code: "UG-205", name: "Kamuli", parent: Some("UG-E") },
+    Subdivision { code: "UG-206", name: "Kapchorwa", parent: Some("UG-E") },
+    Subdivision { code: "UG-207", name: "Katakwi", parent: Some("UG-E") },
+    Subdivision { code: "UG-208", name: "Kumi", parent: Some("UG-E") },
+    Subdivision { code: "UG-209", name: "Mbale", parent: Some("UG-E") },
+    Subdivision { code: "UG-210", name: "Pallisa", parent: Some("UG-E") },
+    Subdivision { code: "UG-211", name: "Soroti", parent: Some("UG-E") },
+    Subdivision { code: "UG-212", name: "Tororo", parent: Some("UG-E") },
+    Subdivision { code: "UG-213", name: "Kaberamaido", parent: Some("UG-E") },
+    Subdivision { code: "UG-214", name: "Mayuge", parent: Some("UG-E") },
+    Subdivision { code: "UG-215", name: "Sironko", parent: Some("UG-E") },
+    Subdivision { code: "UG-216", name: "Amuria", parent: Some("UG-E") },
+    Subdivision { code: "UG-217", name: "Budaka", parent: Some("UG-E") },
+    Subdivision { code: "UG-218", name: "Bududa", parent: Some("UG-E") },
+    Subdivision { code: "UG-219", name: "Bukedea", parent: Some("UG-E") },
+    Subdivision { code: "UG-220", name: "Bukwo", parent: Some("UG-E") },
+    Subdivision { code: "UG-221", name: "Butaleja", parent: Some("UG-E") },
+    Subdivision { code: "UG-222", name: "Kaliro", parent: Some("UG-E") },
+    Subdivision { code: "UG-223", name: "Manafwa", parent: Some("UG-E") },
+    Subdivision { code: "UG-224", name: "Namutumba", parent: Some("UG-E") },
+    Subdivision { code: "UG-225", name: "Bulambuli", parent: Some("UG-E") },
+    Subdivision { code: "UG-226", name: "Buyende", parent: Some("UG-E") },
+    Subdivision { code: "UG-227", name: "Kibuku", parent: Some("UG-E") },
+    Subdivision { code: "UG-228", name: "Kween", parent: Some("UG-E") },
+    Subdivision { code: "UG-229", name: "Luuka", parent: Some("UG-E") },
+    Subdivision { code: "UG-230", name: "Namayingo", parent: Some("UG-E") },
+    Subdivision { code: "UG-231", name: "Ngora", parent: Some("UG-E") },
+    Subdivision { code: "UG-232", name: "Serere", parent: Some("UG-E") },
+    Subdivision { code: "UG-233", name: "Butebo", parent: Some("UG-E") },
+    Subdivision { code: "UG-234", name: "Namisindwa", parent: Some("UG-E") },
+    Subdivision { code: "UG-235", name: "Bugweri", parent: Some("UG-E") },
+    Subdivision { code: "UG-236", name: "Kapelebyong", parent: Some("UG-E") },
+    Subdivision { code: "UG-237", name: "Kalaki", parent: Some("UG-E") },
+    Subdivision { code: "UG-301", name: "Adjumani", parent: Some("UG-N") },
+    Subdivision { code: "UG-302", name: "Apac", parent: Some("UG-N") },
+    Subdivision { code: "UG-303", name: "Arua", parent: Some("UG-N") },
+    Subdivision { code: "UG-304", name: "Gulu", parent: Some("UG-N") },
+    Subdivision { code: "UG-305", name: "Kitgum", parent: Some("UG-N") },
+    Subdivision { code: "UG-306", name: "Kotido", parent: Some("UG-N") },
+    Subdivision { code: "UG-307", name: "Lira", parent: Some("UG-N") },
+    Subdivision { code: "UG-308", name: "Moroto", parent: Some("UG-N") },
+    Subdivision { code: "UG-309", name: "Moyo", parent: Some("UG-N") },
+    Subdivision { code: "UG-310", name: "Nebbi", parent: Some("UG-N") },
+    Subdivision { code: "UG-311", name: "Nakapiripirit", parent: Some("UG-N") },
+    Subdivision { code: "UG-312", name: "Pader", parent: Some("UG-N") },
+    Subdivision { code: "UG-313", name: "Yumbe", parent: Some("UG-N") },
+    Subdivision { code: "UG-314", name: "Abim", parent: Some("UG-N") },
+    Subdivision { code: "UG-315", name: "Amolatar", parent: Some("UG-N") },
+    Subdivision { code: "UG-316", name: "Amuru", parent: Some("UG-N") },
+    Subdivision { code: "UG-317", name: "Dokolo", parent: Some("UG-N") },
+    Subdivision { code: "UG-318", name: "Kaabong", parent: Some("UG-N") },
+    Subdivision { code: "UG-319", name: "Koboko", parent: Some("UG-N") },
+    Subdivision { code: "UG-320", name: "Maracha", parent: Some("UG-N") },
+    Subdivision { code: "UG-321", name: "Oyam", parent: Some("UG-N") },
+    Subdivision { code: "UG-322", name: "Agago", parent: Some("UG-N") },
+    Subdivision { code: "UG-323", name: "Alebtong", parent: Some("UG-N") },
+    Subdivision { code: "UG-324", name: "Amudat", parent: Some("UG-N") },
+    Subdivision { code: "UG-325", name: "Kole", parent: Some("UG-N") },
+    Subdivision { code: "UG-326", name: "Lamwo", parent: Some("UG-N") },
+    Subdivision { code: "UG-327", name: "Napak", parent: Some("UG-N") },
+    Subdivision { code: "UG-328", name: "Nwoya", parent: Some("UG-N") },
+    Subdivision { code: "UG-329", name: "Otuke", parent: Some("UG-N") },
+    Subdivision { code: "UG-330", name: "Zombo", parent: Some("UG-N") },
+    Subdivision { code: "UG-331", name: "Omoro", parent: Some("UG-N") },
+    Subdivision { code: "UG-332", name: "Pakwach", parent: Some("UG-N") },
+    Subdivision { code: "UG-333", name: "Kwania", parent: Some("UG-N") },
+    Subdivision { code: "UG-334", name: "Nabilatuk", parent: Some("UG-N") },
+    Subdivision { code: "UG-335", name: "Karenga", parent: Some("UG-N") },
+    Subdivision { code: "UG-336", name: "Madi-Okollo", parent: Some("UG-N") },
+    Subdivision { code: "UG-337", name: "Obongi", parent: Some("UG-N") },
+    Subdivision { code: "UG-401", name: "Bundibugyo", parent: Some("UG-W") },
+    Subdivision { code: "UG-402", name: "Bushenyi", parent: Some("UG-W") },
+    Subdivision { code: "UG-403", name: "Hoima", parent: Some("UG-W") },
+    Subdivision { code: "UG-404", name: "Kabale", parent: Some("UG-W") },
+    Subdivision { code: "UG-405", name: "Kabarole", parent: Some("UG-W") },
+    Subdivision { code: "UG-406", name: "Kasese", parent: Some("UG-W") },
+    Subdivision { code: "UG-407", name: "Kibaale", parent: Some("UG-W") },
+    Subdivision { code: "UG-408", name: "Kisoro", parent: Some("UG-W") },
+    Subdivision { code: "UG-409", name: "Masindi", parent: Some("UG-W") },
+    Subdivision { code: "UG-410", name: "Mbarara", parent: Some("UG-W") },
+    Subdivision { code: "UG-411", name: "Ntungamo", parent: Some("UG-W") },
+    Subdivision { code: "UG-412", name: "Rukungiri", parent: Some("UG-W") },
+    Subdivision { code: "UG-413", name: "Kamwenge", parent: Some("UG-W") },
+    Subdivision { code: "UG-414", name: "Kanungu", parent: Some("UG-W") },
+    Subdivision { code: "UG-415", name: "Kyenjojo", parent: Some("UG-W") },
+    Subdivision { code: "UG-416", name: "Buliisa", parent: Some("UG-W") },
+    Subdivision { code: "UG-417", name: "Ibanda", parent: Some("UG-W") },
+    Subdivision { code: "UG-418", name: "Isingiro", parent: Some("UG-W") },
+    Subdivision { code: "UG-419", name: "Kiruhura", parent: Some("UG-W") },
+    Subdivision { code: "UG-420", name: "Buhweju", parent: Some("UG-W") },
+    Subdivision { code: "UG-421", name: "Kiryandongo", parent: Some("UG-W") },
+    Subdivision { code: "UG-422", name: "Kyegegwa", parent: Some("UG-W") },
+    Subdivision { code: "UG-423", name: "Mitooma", parent: Some("UG-W") },
+    Subdivision { code: "UG-424", name: "Ntoroko", parent: Some("UG-W") },
+    Subdivision { code: "UG-425", name: "Rubirizi", parent: Some("UG-W") },
+    Subdivision { code: "UG-426", name: "Sheema", parent: Some("UG-W") },
+    Subdivision { code: "UG-427", name: "Kagadi", parent: Some("UG-W") },
+    Subdivision { code: "UG-428", name: "Kakumiro", parent: Some("UG-W") },
+    Subdivision { code: "UG-429", name: "Rubanda", parent: Some("UG-W") },
+    Subdivision { code: "UG-430", name: "Bunyangabu", parent: Some("UG-W") },
+    Subdivision { code: "UG-431", name: "Rukiga", parent: Some("UG-W") },
+    Subdivision { code: "UG-432", name: "Kikuube", parent: Some("UG-W") },
+    Subdivision { code: "UG-433", name: "Kazo", parent: Some("UG-W") },
+    Subdivision { code: "UG-434", name: "Kitagwenda", parent: Some("UG-W") },
+    Subdivision { code: "UG-435", name: "Rwampara", parent: Some("UG-W") },
+    Subdivision { code: "UG-C", name: "Central", parent: None },
+    Subdivision { code: "UG-E", name: "Eastern", parent: None },
+    Subdivision { code: "UG-N", name: "Northern", parent: None },
+    Subdivision { code: "UG-W", name: "Western", parent: None },
+    Subdivision { code: "UM-67", name: "Johnston Atoll", parent: None },
+    Subdivision { code: "UM-71", name: "Midway Islands", parent: None },
+    Subdivision { code: "UM-76", name: "Navassa Island", parent: None },
+    Subdivision { code: "UM-79", name: "Wake Island", parent: None },
+    Subdivision { code: "UM-81", name: "Baker Island", parent: None },
+    Subdivision { code: "UM-84", name: "Howland Island", parent: None },
+    Subdivision { code: "UM-86", name: "Jarvis Island", parent: None },
+    Subdivision { code: "UM-89", name: "Kingman Reef", parent: None },
+    Subdivision { code: "UM-95", name: "Palmyra Atoll", parent: None },
+    Subdivision { code: "US-AK", name: "Alaska", parent: None },
+    Subdivision { code: "US-AL", name: "Alabama", parent: None },
+    Subdivision { code: "US-AR", name: "Arkansas", parent: None },
+    Subdivision { code: "US-AS", name: "American Samoa", parent: None },
+    Subdivision { code: "US-AZ", name: "Arizona", parent: None },
+    Subdivision { code: "US-CA", name: "California", parent: None },
+    Subdivision { code: "US-CO", name: "Colorado", parent: None },
+    Subdivision { code: "US-CT", name: "Connecticut", parent: None },
+    Subdivision { code: "US-DC", name: "District of Columbia", parent: None },
+    Subdivision { code: "US-DE", name: "Delaware", parent: None },
+    Subdivision { code: "US-FL", name: "Florida", parent: None },
+    Subdivision { code: "US-GA", name: "Georgia", parent: None },
+    Subdivision { code: "US-GU", name: "Guam", parent: None },
+    Subdivision { code: "US-HI", name: "Hawaii", parent: None },
+    Subdivision { code: "US-IA", name: "Iowa", parent: None },
+    Subdivision { code: "US-ID", name: "Idaho", parent: None },
+    Subdivision { code: "US-IL", name: "Illinois", parent: None },
+    Subdivision { code: "US-IN", name: "Indiana", parent: None },
+    Subdivision { code: "US-KS", name: "Kansas", parent: None },
+    Subdivision { code: "US-KY", name: "Kentucky", parent: None },
+    Subdivision { code: "US-LA", name: "Louisiana", parent: None },
+    Subdivision { code: "US-MA", name: "Massachusetts", parent: None },
+    Subdivision { code: "US-MD", name: "Maryland", parent: None },
+    Subdivision { code: "US-ME", name: "Maine", parent: None },
+    Subdivision { code: "US-MI", name: "Michigan", parent: None },
+    Subdivision { code: "US-MN", name: "Minnesota", parent: None },
+    Subdivision { code: "US-MO", name: "Missouri", parent: None },
+    Subdivision { code: "US-MP", name: "Northern Mariana Islands", parent: None },
+    Subdivision { code: "US-MS", name: "Mississippi", parent: None },
+    Subdivision { code: "US-MT", name: "Montana", parent: None },
+    Subdivision { code: "US-NC", name: "North Carolina", parent: None },
+    Subdivision { code: "US-ND", name: "North Dakota", parent: None },
+    Subdivision { code: "US-NE", name: "Nebraska", parent: None },
+    Subdivision { code: "US-NH", name: "New Hampshire", parent: None },
+    Subdivision { code: "US-NJ", name: "New Jersey", parent: None },
+    Subdivision { code: "US-NM", name: "New Mexico", parent: None },
+    Subdivision { code: "US-NV", name: "Nevada", parent: None },
+    Subdivision { code: "US-NY", name: "New York", parent: None },
+    Subdivision { code: "US-OH", name: "Ohio", parent: None },
+    Subdivision { code: "US-OK", name: "Oklahoma", parent: None },
+    Subdivision { code: "US-OR", name: "Oregon", parent: None },
+    Subdivision { code: "US-PA", name: "Pennsylvania", parent: None },
+    Subdivision { code: "US-PR", name: "Puerto Rico", parent: None },
+    Subdivision { code: "US-RI", name: "Rhode Island", parent: None },
+    Subdivision { code: "US-SC", name: "South Carolina", parent: None },
+    Subdivision { code: "US-SD", name: "South Dakota", parent: None },
+    Subdivision { code: "US-TN", name: "Tennessee", parent: None },
+    Subdivision { code: "US-TX", name: "Texas", parent: None },
+    Subdivision { code: "US-UM", name: "United States Minor Outlying Islands", parent: None },
+    Subdivision { code: "US-UT", name: "Utah", parent: None },
+    Subdivision { code: "US-VA", name: "Virginia", parent: None },
+    Subdivision { code: "US-VI", name: "Virgin Islands, U.S.", parent: None },
+    Subdivision { code: "US-VT", name: "Vermont", parent: None },
+    Subdivision { code: "US-WA", name: "Washington", parent: None },
+    Subdivision { code: "US-WI", name: "Wisconsin", parent: None },
+    Subdivision { code: "US-WV", name: "West Virginia", parent: None },
+    Subdivision { code: "US-WY", name: "Wyoming", parent: None },
+    Subdivision { code: "UY-AR", name: "Artigas", parent: None },
+    Subdivision { code: "UY-CA", name: "Canelones", parent: None },
+    Subdivision { code: "UY-CL", name: "Cerro Largo", parent: None },
+    Subdivision { code: "UY-CO", name: "Colonia", parent: None },
+    Subdivision { code: "UY-DU", name: "Durazno", parent: None },
+    Subdivision { code: "UY-FD", name: "Florida", parent: None },
+    Subdivision { code: "UY-FS", name: "Flores", parent: None },
+    Subdivision { code: "UY-LA", name: "Lavalleja", parent: None },
+    Subdivision { code: "UY-MA", name: "Maldonado", parent: None },
+    Subdivision { code: "UY-MO", name: "Montevideo", parent: None },
+    Subdivision { code: "UY-PA", name: "Paysandú", parent: None },
+    Subdivision { code: "UY-RN", name: "Río Negro", parent: None },
+    Subdivision { code: "UY-RO", name: "Rocha", parent: None },
+    Subdivision { code: "UY-RV", name: "Rivera", parent: None },
+    Subdivision { code: "UY-SA", name: "Salto", parent: None },
+    Subdivision { code: "UY-SJ", name: "San José", parent: None },
+    Subdivision { code: "UY-SO", name: "Soriano", parent: None },
+    Subdivision { code: "UY-TA", name: "Tacuarembó", parent: None },
+    Subdivision { code: "UY-TT", name: "Treinta y Tres", parent: None },
+    Subdivision { code: "UZ-AN", name: "Andijon", parent: None },
+    Subdivision { code: "UZ-BU", name: "Buxoro", parent: None },
+    Subdivision { code: "UZ-FA", name: "Farg‘ona", parent: None },
+    Subdivision { code: "UZ-JI", name: "Jizzax", parent: None },
+    Subdivision { code: "UZ-NG", name: "Namangan", parent: None },
+    Subdivision { code: "UZ-NW", name: "Navoiy", parent: None },
+    Subdivision { code: "UZ-QA", name: "Qashqadaryo", parent: None },
+    Subdivision { code: "UZ-QR", name: "Qoraqalpog‘iston Respublikasi", parent: None },
+    Subdivision { code: "UZ-SA", name: "Samarqand", parent: None },
+    Subdivision { code: "UZ-SI", name: "Sirdaryo", parent: None },
+    Subdivision { code: "UZ-SU", name: "Surxondaryo", parent: None },
+    Subdivision { code: "UZ-TK", name: "Toshkent", parent: None },
+    Subdivision { code: "UZ-TO", name: "Toshkent", parent: None },
+    Subdivision { code: "UZ-XO", name: "Xorazm", parent: None },
+    Subdivision { code: "VC-01", name: "Charlotte", parent: None },
+    Subdivision { code: "VC-02", name: "Saint Andrew", parent: None },
+    Subdivision { code: "VC-03", name: "Saint David", parent: None },
+    Subdivision { code: "VC-04", name: "Saint George", parent: None },
+    Subdivision { code: "VC-05", name: "Saint Patrick", parent: None },
+    Subdivision { code: "VC-06", name: "Grenadines", parent: None },
+    Subdivision { code: "VE-A", name: "Distrito Capital", parent: None },
+    Subdivision { code: "VE-B", name: "Anzoátegui", parent: None },
+    Subdivision { code: "VE-C", name: "Apure", parent: None },
+    Subdivision { code: "VE-D", name: "Aragua", parent: None },
+    Subdivision { code: "VE-E", name: "Barinas", parent: None },
+    Subdivision { code: "VE-F", name: "Bolívar", parent: None },
+    Subdivision { code: "VE-G", name: "Carabobo", parent: None },
+    Subdivision { code: "VE-H", name: "Cojedes", parent: None },
+    Subdivision { code: "VE-I", name: "Falcón", parent: None },
+    Subdivision { code: "VE-J", name: "Guárico", parent: None },
+    Subdivision { code: "VE-K", name: "Lara", parent: None },
+    Subdivision { code: "VE-L", name: "Mérida", parent: None },
+    Subdivision { code: "VE-M", name: "Miranda", parent: None },
+    Subdivision { code: "VE-N", name: "Monagas", parent: None },
+    Subdivision { code: "VE-O", name: "Nueva Esparta", parent: None },
+    Subdivision { code: "VE-P", name: "Portuguesa", parent: None },
+    Subdivision { code: "VE-R", name: "Sucre", parent: None },
+    Subdivision { code: "VE-S", name: "Táchira", parent: None },
+    Subdivision { code: "VE-T", name: "Trujillo", parent: None },
+    Subdivision { code: "VE-U", name: "Yaracuy", parent: None },
+    Subdivision { code: "VE-V", name: "Zulia", parent: None },
+    Subdivision { code: "VE-W", name: "Dependencias Federales", parent: None },
+    Subdivision { code: "VE-X", name: "La Guaira", parent: None },
+    Subdivision { code: "VE-Y", name: "Delta Amacuro", parent: None },
+    Subdivision { code: "VE-Z", name: "Amazonas", parent: None },
+    Subdivision { code: "VN-01", name: "Lai Châu", parent: None },
+    Subdivision { code: "VN-02", name: "Lào Cai", parent: None },
+    Subdivision { code: "VN-03", name: "Hà Giang", parent: None },
+    Subdivision { code: "VN-04", name: "Cao Bằng", parent: None },
+    Subdivision { code: "VN-05", name: "Sơn La", parent: None },
+    Subdivision { code: "VN-06", name: "Yên Bái", parent: None },
+    Subdivision { code: "VN-07", name: "Tuyên Quang", parent: None },
+    Subdivision { code: "VN-09", name: "Lạng Sơn", parent: None },
+    Subdivision { code: "VN-13", name: "Quảng Ninh", parent: None },
+    Subdivision { code: "VN-14", name: "Hòa Bình", parent: None },
+    Subdivision { code: "VN-18", name: "Ninh Bình", parent: None },
+    Subdivision { code: "VN-20", name: "Thái Bình", parent: None },
+    Subdivision { code: "VN-21", name: "Thanh Hóa", parent: None },
+    Subdivision { code: "VN-22", name: "Nghệ An", parent: None },
+    Subdivision { code: "VN-23", name: "Hà Tĩnh", parent: None },
+    Subdivision { code: "VN-24", name: "Quảng Bình", parent: None },
+    Subdivision { code: "VN-25", name: "Quảng Trị", parent: None },
+    Subdivision { code: "VN-26", name: "Thừa Thiên-Huế", parent: None },
+    Subdivision { code: "VN-27", name: "Quảng Nam", parent: None },
+    Subdivision { code: "VN-28", name: "Kon Tum", parent: None },
+    Subdivision { code: "VN-29", name: "Quảng Ngãi", parent: None },
+    Subdivision { code: "VN-30", name: "Gia Lai", parent: None },
+    Subdivision { code: "VN-31", name: "Bình Định", parent: None },
+    Subdivision { code: "VN-32", name: "Phú Yên", parent: None },
+    Subdivision { code: "VN-33", name: "Đắk Lắk", parent: None },
+    Subdivision { code: "VN-34", name: "Khánh Hòa", parent: None },
+    Subdivision { code: "VN-35", name: "Lâm Đồng", parent: None },
+    Subdivision { code: "VN-36", name: "Ninh Thuận", parent: None },
+    Subdivision { code: "VN-37", name: "Tây Ninh", parent: None },
+    Subdivision { code: "VN-39", name: "Đồng Nai", parent: None },
+    Subdivision { code: "VN-40", name: "Bình Thuận", parent: None },
+    Subdivision { code: "VN-41", name: "Long An", parent: None },
+    Subdivision { code: "VN-43", name: "Bà Rịa - Vũng Tàu", parent: None },
+    Subdivision { code: "VN-44", name: "An Giang", parent: None },
+    Subdivision { code: "VN-45", name: "Đồng Tháp", parent: None },
+    Subdivision { code: "VN-46", name: "Tiền Giang", parent: None },
+    Subdivision { code: "VN-47", name: "Kiến Giang", parent: None },
+    Subdivision { code: "VN-49", name: "Vĩnh Long", parent: None },
+    Subdivision { code: "VN-50", name: "Bến Tre", parent: None },
+    Subdivision { code: "VN-51", name: "Trà Vinh", parent: None },
+    Subdivision { code: "VN-52", name: "Sóc Trăng", parent: None },
+    Subdivision { code: "VN-53", name: "Bắc Kạn", parent: None },
+    Subdivision { code: "VN-54", name: "Bắc Giang", parent: None },
+    Subdivision { code: "VN-55", name: "Bạc Liêu", parent: None },
+    Subdivision { code: "VN-56", name: "Bắc Ninh", parent: None },
+    Subdivision { code: "VN-57", name: "Bình Dương", parent: None },
+    Subdivision { code: "VN-58", name: "Bình Phước", parent: None },
+    Subdivision { code: "VN-59", name: "Cà Mau", parent: None },
+    Subdivision { code: "VN-61", name: "Hải Dương", parent: None },
+    Subdivision { code: "VN-63", name: "Hà Nam", parent: None },
+    Subdivision { code: "VN-66", name: "Hưng Yên", parent: None },
+    Subdivision { code: "VN-67", name: "Nam Định", parent: None },
+    Subdivision { code: "VN-68", name: "Phú Thọ", parent: None },
+    Subdivision { code: "VN-69", name: "Thái Nguyên", parent: None },
+    Subdivision { code: "VN-70", name: "Vĩnh Phúc", parent: None },
+    Subdivision { code: "VN-71", name: "Điện Biên", parent: None },
+    Subdivision { code: "VN-72", name: "Đắk Nông", parent: None },
+    Subdivision { code: "VN-73", name: "Hậu Giang", parent: None },
+    Subdivision { code: "VN-CT", name: "Cần Thơ", parent: None },
+    Subdivision { code: "VN-DN", name: "Đà Nẵng", parent: None },
+    Subdivision { code: "VN-HN", name: "Hà Nội", parent: None },
+    Subdivision { code: "VN-HP", name: "Hải Phòng", parent: None },
+    Subdivision { code: "VN-SG", name: "Hồ Chí Minh", parent: None },
+    Subdivision { code: "VU-MAP", name: "Malampa", parent: None },
+    Subdivision { code: "VU-PAM", name: "Pénama", parent: None },
+    Subdivision { code: "VU-SAM", name: "Sanma", parent: None },
+    Subdivision { code: "VU-SEE", name: "Shéfa", parent: None },
+    Subdivision { code: "VU-TAE", name: "Taféa", parent: None },
+    Subdivision { code: "VU-TOB", name: "Torba", parent: None },
+    Subdivision { code: "WF-AL", name: "Alo", parent: None },
+    Subdivision { code: "WF-SG", name: "Sigave", parent: None },
+    Subdivision { code: "WF-UV", name: "Uvea", parent: None },
+    Subdivision { code: "WS-AA", name: "A'ana", parent: None },
+    Subdivision { code: "WS-AL", name: "Aiga-i-le-Tai", parent: None },
+    Subdivision { code: "WS-AT", name: "Atua", parent: None },
+    Subdivision { code: "WS-FA", name: "Fa'asaleleaga", parent: None },
+    Subdivision { code: "WS-GE", name: "Gaga'emauga", parent: None },
+    Subdivision { code: "WS-GI", name: "Gagaifomauga", parent: None },
+    Subdivision { code: "WS-PA", name: "Palauli", parent: None },
+    Subdivision { code: "WS-SA", name: "Satupa'itea", parent: None },
+    Subdivision { code: "WS-TU", name: "Tuamasaga", parent: None },
+    Subdivision { code: "WS-VF", name: "Va'a-o-Fonoti", parent: None },
+    Subdivision { code: "WS-VS", name: "Vaisigano", parent: None },
+    Subdivision { code: "YE-AB", name: "Abyan", parent: None },
+    Subdivision { code: "YE-AD", name: "‘Adan", parent: None },
+    Subdivision { code: "YE-AM", name: "‘Amrān", parent: None },
+    Subdivision { code: "YE-BA", name: "Al Bayḑā’", parent: None },
+    Subdivision { code: "YE-DA", name: "Aḑ Ḑāli‘", parent: None },
+    Subdivision { code: "YE-DH", name: "Dhamār", parent: None },
+    Subdivision { code: "YE-HD", name: "Ḩaḑramawt", parent: None },
+    Subdivision { code: "YE-HJ", name: "Ḩajjah", parent: None },
+    Subdivision { code: "YE-HU", name: "Al Ḩudaydah", parent: None },
+    Subdivision { code: "YE-IB", name: "Ibb", parent: None },
+    Subdivision { code: "YE-JA", name: "Al Jawf", parent: None },
+    Subdivision { code: "YE-LA", name: "Laḩij", parent: None },
+    Subdivision { code: "YE-MA", name: "Ma’rib", parent: None },
+    Subdivision { code: "YE-MR", name: "Al Mahrah", parent: None },
+    Subdivision { code: "YE-MW", name: "Al Maḩwīt", parent: None },
+    Subdivision { code: "YE-RA", name: "Raymah", parent: None },
+    Subdivision { code: "YE-SA", name: "Amānat al ‘Āşimah [city]", parent: None },
+    Subdivision { code: "YE-SD", name: "Şāʻdah", parent: None },
+    Subdivision { code: "YE-SH", name: "Shabwah", parent: None },
+    Subdivision { code: "YE-SN", name: "Şanʻā’", parent: None },
+    Subdivision { code: "YE-SU", name: "Arkhabīl Suquţrá", parent: None },
+    Subdivision { code: "YE-TA", name: "Tāʻizz", parent: None },
+    Subdivision { code: "ZA-EC", name: "Eastern Cape", parent: None },
+    Subdivision { code: "ZA-FS", name: "Free State", parent: None },
+    Subdivision { code: "ZA-GP", name: "Gauteng", parent: None },
+    Subdivision { code: "ZA-KZN", name: "Kwazulu-Natal", parent: None },
+    Subdivision { code: "ZA-LP", name: "Limpopo", parent: None },
+    Subdivision { code: "ZA-MP", name: "Mpumalanga", parent: None },
+    Subdivision { code: "ZA-NC", name: "Northern Cape", parent: None },
+    Subdivision { code: "ZA-NW", name: "North-West", parent: None },
+    Subdivision { code: "ZA-WC", name: "Western Cape", parent: None },
+    Subdivision { code: "ZM-01", name: "Western", parent: None },
+    Subdivision { code: "ZM-02", name: "Central", parent: None },
+    Subdivision { code: "ZM-03", name: "Eastern", parent: None },
+    Subdivision { code: "ZM-04", name: "Luapula", parent: None },
+    Subdivision { code: "ZM-05", name: "Northern", parent: None },
+    Subdivision { code: "ZM-06", name: "North-Western", parent: None },
+    Subdivision { code: "ZM-07", name: "Southern", parent: None },
+    Subdivision { code: "ZM-08", name: "Copperbelt", parent: None },
+    Subdivision { code: "ZM-09", name: "Lusaka", parent: None },
+    Subdivision { code: "ZM-10", name: "Muchinga", parent: None },
+    Subdivision { code: "ZW-BU", name: "Bulawayo", parent: None },
+    Subdivision { code: "ZW-HA", name: "Harare", parent: None },
+    Subdivision { code: "ZW-MA", name: "Manicaland", parent: None },
+    Subdivision { code: "ZW-MC", name: "Mashonaland Central", parent: None },
+    Subdivision { code: "ZW-ME", name: "Mashonaland East", parent: None },
+    Subdivision { code: "ZW-MI", name: "Midlands", parent: None },
+    Subdivision { code: "ZW-MN", name: "Matabeleland North", parent: None },
+    Subdivision { code: "ZW-MS", name: "Matabeleland South", parent: None },
+    Subdivision { code: "ZW-MV", name: "Masvingo", parent: None },
+    Subdivision { code: "ZW-MW", name: "Mashonaland West", parent: None },
+];
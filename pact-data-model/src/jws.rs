@@ -0,0 +1,133 @@
+/*
+ * Copyright (c) 2024 SINE Foundation e.V.
+ *
+ * This software is released under the MIT License, see LICENSE.
+ */
+
+//! Detached JWS signatures (RFC 7515, Appendix F) of `ProductFootprint`s.
+//!
+//! The JWS payload is the canonical JSON serialization of the footprint (see
+//! `ProductFootprint::to_canonical_json`). It is omitted from the signature, which therefore has
+//! the form `<header>..<signature>`, and must be recomputed from the footprint by the verifier.
+//! Signatures use RS256.
+
+use base64::{engine::general_purpose::URL_SAFE_NO_PAD, Engine as _};
+use jsonwebtoken::jwk::JwkSet;
+use jsonwebtoken::{crypto, decode_header, Algorithm, DecodingKey, EncodingKey, Header};
+use schemars::JsonSchema;
+use serde::Serialize;
+
+use crate::ProductFootprint;
+
+#[derive(Debug)]
+/// Error returned when a footprint cannot be signed or its signature cannot be verified
+pub enum SignatureError {
+    /// The footprint could not be serialized
+    Serialization(serde_json::Error),
+    /// The signature is not a detached JWS
+    Malformed,
+    /// The signature uses an algorithm other than RS256
+    UnsupportedAlgorithm(Algorithm),
+    /// The Json Web Key Set does not contain the signing key
+    UnknownKey(Option<String>),
+    /// The signature does not match the footprint
+    Invalid,
+    Jwt(jsonwebtoken::errors::Error),
+}
+
+impl std::fmt::Display for SignatureError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SignatureError::Serialization(e) => write!(f, "cannot serialize footprint: {e}"),
+            SignatureError::Malformed => f.write_str("signature is not a detached JWS"),
+            SignatureError::UnsupportedAlgorithm(alg) => {
+                write!(f, "unsupported signature algorithm {alg:?}")
+            }
+            SignatureError::UnknownKey(Some(kid)) => write!(f, "unknown key {kid}"),
+            SignatureError::UnknownKey(None) => f.write_str("no unique key to verify with"),
+            SignatureError::Invalid => f.write_str("signature does not match footprint"),
+            SignatureError::Jwt(e) => write!(f, "{e}"),
+        }
+    }
+}
+
+impl std::error::Error for SignatureError {}
+
+impl From<serde_json::Error> for SignatureError {
+    fn from(e: serde_json::Error) -> Self {
+        SignatureError::Serialization(e)
+    }
+}
+
+impl From<jsonwebtoken::errors::Error> for SignatureError {
+    fn from(e: jsonwebtoken::errors::Error) -> Self {
+        SignatureError::Jwt(e)
+    }
+}
+
+/// Signs the canonical JSON serialization of `pf` with the RSA private key `key`, returning a
+/// detached JWS whose header references the key by `key_id`
+pub fn sign_footprint<T: JsonSchema + Serialize>(
+    pf: &ProductFootprint<T>,
+    key: &EncodingKey,
+    key_id: &str,
+) -> Result<String, SignatureError> {
+    let mut header = Header::new(Algorithm::RS256);
+    header.typ = None;
+    header.kid = Some(key_id.to_string());
+
+    let header = URL_SAFE_NO_PAD.encode(serde_json::to_vec(&header)?);
+    let signature = crypto::sign(
+        signing_input(&header, pf)?.as_bytes(),
+        key,
+        Algorithm::RS256,
+    )?;
+
+    Ok(format!("{header}..{signature}"))
+}
+
+/// Verifies that `jws` is a detached JWS of the canonical JSON serialization of `pf`, signed
+/// with a key of `jwks`. The key is looked up by the `kid` of the JWS header, or is the only key
+/// of `jwks` if the header has none.
+pub fn verify_footprint<T: JsonSchema + Serialize>(
+    pf: &ProductFootprint<T>,
+    jws: &str,
+    jwks: &JwkSet,
+) -> Result<(), SignatureError> {
+    let [header_b64, "", signature] = jws.split('.').collect::<Vec<_>>()[..] else {
+        return Err(SignatureError::Malformed);
+    };
+
+    let header = decode_header(jws)?;
+    if header.alg != Algorithm::RS256 {
+        return Err(SignatureError::UnsupportedAlgorithm(header.alg));
+    }
+
+    let jwk = match &header.kid {
+        Some(kid) => jwks.find(kid),
+        None if jwks.keys.len() == 1 => jwks.keys.first(),
+        None => None,
+    }
+    .ok_or_else(|| SignatureError::UnknownKey(header.kid.clone()))?;
+    let key = DecodingKey::from_jwk(jwk)?;
+
+    if crypto::verify(
+        signature,
+        signing_input(header_b64, pf)?.as_bytes(),
+        &key,
+        Algorithm::RS256,
+    )? {
+        Ok(())
+    } else {
+        Err(SignatureError::Invalid)
+    }
+}
+
+/// The JWS Signing Input of `pf`, i.e. `<header>.<payload>`
+fn signing_input<T: JsonSchema + Serialize>(
+    header: &str,
+    pf: &ProductFootprint<T>,
+) -> Result<String, SignatureError> {
+    let payload = URL_SAFE_NO_PAD.encode(pf.to_canonical_json()?);
+    Ok(format!("{header}.{payload}"))
+}
@@ -21,7 +21,64 @@ use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
 mod schema_gen;
-pub use schema_gen::generate_schema;
+pub use schema_gen::{generate_schema, product_footprint_schema};
+
+mod validation;
+pub use validation::{Rule, Violation};
+
+mod schema_validation;
+pub use schema_validation::{
+    json_footprints, validate_json, validate_json_with_schema, SchemaViolation,
+};
+
+mod lifecycle;
+pub use lifecycle::LifecycleError;
+
+mod builder;
+pub use builder::{
+    CarbonFootprintBuilder, MissingFieldsError, ProductFootprintBuilder, DEFAULT_SPEC_VERSION,
+};
+
+pub mod iso3166;
+
+mod geography;
+
+mod rescale;
+pub use rescale::RescaleError;
+
+mod bom;
+pub use bom::{aggregate_bom, BomComponent, BomError};
+
+mod gwp;
+pub use gwp::{GhgInventory, GreenhouseGas, GwpError, IpccReport};
+
+mod canonical;
+pub use canonical::ContentHash;
+
+#[cfg(feature = "jws")]
+mod jws;
+#[cfg(feature = "jws")]
+pub use jws::{sign_footprint, verify_footprint, SignatureError};
+
+mod diff;
+pub use diff::{diff, Change, FootprintDiff};
+
+mod flat_csv;
+pub use flat_csv::{csv_columns, from_csv, to_csv, CellError, CsvError};
+
+mod extension;
+pub use extension::{ExtensionData, ExtensionError, ExtensionRegistry};
+
+mod urn;
+pub use urn::{CodeAssigner, ParsedUrn, Sgtin};
+
+pub mod v3;
+
+#[cfg(feature = "quickcheck")]
+mod arbitrary_impls;
+
+#[cfg(test)]
+mod test_data;
 
 #[derive(Debug, Serialize, Deserialize, Clone, JsonSchema, PartialEq)]
 #[serde(rename_all = "camelCase")]
@@ -164,7 +221,7 @@ pub enum DeclaredUnit {
     SquareMeter,
 }
 
-#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq)]
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, JsonSchema)]
 /// Data Type "CrossSectoralStandard" of Spec Version 3
 pub enum CrossSectoralStandard {
     #[serde(rename = "GHGP Product")]
@@ -207,9 +264,11 @@ pub enum CharacterizationFactors {
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[serde(try_from = "String")]
 pub struct IpccCharacterizationFactorsSource(String);
 
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[serde(try_from = "Vec<IpccCharacterizationFactorsSource>")]
 pub struct IpccCharacterizationFactorsSources(pub Vec<IpccCharacterizationFactorsSource>);
 
 #[derive(Debug, Serialize, JsonSchema, Deserialize, Clone, PartialEq)]
@@ -226,15 +285,18 @@ pub enum BiogenicAccountingMethodology {
 
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
 #[serde(rename_all = "camelCase")]
+#[serde(try_from = "Decimal")]
 pub struct PositiveDecimal(pub Decimal);
 
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
 #[serde(rename_all = "camelCase")]
+#[serde(try_from = "Decimal")]
 pub struct NegativeDecimal(Decimal);
 
 /// a f64 in the 0..5 range
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
 #[serde(rename_all = "camelCase")]
+#[serde(try_from = "f64")]
 pub struct ExemptedEmissionsPercent(pub f64);
 
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
@@ -245,41 +307,52 @@ pub struct WrappedDecimal(#[serde(with = "rust_decimal::serde::str")] pub Decima
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
 #[serde(rename_all = "camelCase")]
 /// Data Type "Percent" of Spec Version 2
+#[serde(try_from = "f64")]
 pub struct Percent(f64);
 
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
 #[serde(rename_all = "camelCase")]
+#[serde(try_from = "Decimal")]
 pub struct StrictlyPositiveDecimal(pub Decimal);
 
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
 #[serde(rename_all = "camelCase")]
+#[serde(try_from = "f32")]
 pub struct FloatBetween1and3(f32);
 
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[serde(try_from = "String")]
 pub struct NonEmptyString(pub String);
 
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[serde(try_from = "Vec<NonEmptyString>")]
 pub struct NonEmptyStringVec(pub Vec<NonEmptyString>);
 
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[serde(try_from = "Vec<PfId>")]
 pub struct NonEmptyPfIdVec(pub Vec<PfId>);
 
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[serde(try_from = "Vec<Urn>")]
 /// Data Type "CompanyIdSet" of Spec Version 2
 pub struct CompanyIdSet(pub Vec<Urn>);
 
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[serde(try_from = "Vec<Urn>")]
 /// Data Type "ProductIdSet" of Spec Version 2
 pub struct ProductIdSet(pub Vec<Urn>);
 
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[serde(try_from = "Vec<EmissionFactorDS>")]
 /// Data Type "EmissionFactorDSSet" of Spec Version 2
 pub struct EmissionFactorDSSet(pub Vec<EmissionFactorDS>);
 
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[serde(try_from = "String")]
 pub struct Urn(pub String);
 
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[serde(try_from = "String")]
 pub struct SpecVersionString(pub String);
 
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
@@ -364,8 +437,9 @@ pub struct CrossSectoralStandardSet(pub Vec<DeprecatedCrossSectoralStandard>);
 pub struct ISO3166CC(pub String);
 
 impl ISO3166CC {
+    /// Checks whether this is an assigned ISO 3166-1 alpha-2 code, see `iso3166::country`
     pub fn is_valid(&self) -> bool {
-        self.0.len() == 2 && self.0.chars().all(|c| c.is_ascii_uppercase())
+        iso3166::country(&self.0).is_some()
     }
 }
 
@@ -382,6 +456,7 @@ pub enum ProductOrSectorSpecificRuleOperator {
 #[derive(Debug, Serialize, Deserialize, Clone, JsonSchema, PartialEq)]
 #[serde(rename_all = "camelCase")]
 // TODO JsonSchema: add validation rule WRT operator == Other implying other_operator_name to be set; otherwise it must be empty.
+// Until then, the rule is enforced by `CarbonFootprint::validate`.
 pub struct ProductOrSectorSpecificRule {
     pub operator: ProductOrSectorSpecificRuleOperator,
     pub rule_names: NonEmptyStringVec,
@@ -480,27 +555,81 @@ impl From<String> for ISO3166CC {
     }
 }
 
-impl From<String> for IpccCharacterizationFactorsSource {
-    fn from(s: String) -> IpccCharacterizationFactorsSource {
-        IpccCharacterizationFactorsSource(s)
+impl TryFrom<String> for IpccCharacterizationFactorsSource {
+    type Error = ConstraintError;
+
+    fn try_from(s: String) -> Result<IpccCharacterizationFactorsSource, ConstraintError> {
+        // mirrors the pattern `^AR\d+$` of the JSON schema
+        let is_valid = s
+            .strip_prefix("AR")
+            .map(|n| !n.is_empty() && n.chars().all(|c| c.is_ascii_digit()))
+            .unwrap_or_default();
+        if !is_valid {
+            return Err(ConstraintError::new(
+                "IpccCharacterizationFactorsSource",
+                format!("'{s}' is not of the form AR<number>"),
+            ));
+        }
+        Ok(IpccCharacterizationFactorsSource(s))
+    }
+}
+
+impl TryFrom<&str> for IpccCharacterizationFactorsSource {
+    type Error = ConstraintError;
+
+    fn try_from(s: &str) -> Result<IpccCharacterizationFactorsSource, ConstraintError> {
+        IpccCharacterizationFactorsSource::try_from(s.to_string())
     }
 }
 
-impl From<Vec<IpccCharacterizationFactorsSource>> for IpccCharacterizationFactorsSources {
-    fn from(v: Vec<IpccCharacterizationFactorsSource>) -> IpccCharacterizationFactorsSources {
-        IpccCharacterizationFactorsSources(v)
+impl From<CharacterizationFactors> for IpccCharacterizationFactorsSource {
+    fn from(
+        characterization_factors: CharacterizationFactors,
+    ) -> IpccCharacterizationFactorsSource {
+        let label = match characterization_factors {
+            CharacterizationFactors::Ar5 => "AR5",
+            CharacterizationFactors::Ar6 => "AR6",
+        };
+        IpccCharacterizationFactorsSource(label.to_string())
     }
 }
 
-impl From<Decimal> for PositiveDecimal {
-    fn from(f: Decimal) -> PositiveDecimal {
-        PositiveDecimal(f)
+impl TryFrom<Vec<IpccCharacterizationFactorsSource>> for IpccCharacterizationFactorsSources {
+    type Error = ConstraintError;
+
+    fn try_from(
+        v: Vec<IpccCharacterizationFactorsSource>,
+    ) -> Result<IpccCharacterizationFactorsSources, ConstraintError> {
+        check_non_empty_set("IpccCharacterizationFactorsSources", &v)?;
+        Ok(IpccCharacterizationFactorsSources(v))
+    }
+}
+
+impl TryFrom<Decimal> for PositiveDecimal {
+    type Error = ConstraintError;
+
+    fn try_from(f: Decimal) -> Result<PositiveDecimal, ConstraintError> {
+        if f.is_sign_negative() && !f.is_zero() {
+            return Err(ConstraintError::new(
+                "PositiveDecimal",
+                format!("{f} is negative"),
+            ));
+        }
+        Ok(PositiveDecimal(f))
     }
 }
 
-impl From<Decimal> for NegativeDecimal {
-    fn from(f: Decimal) -> NegativeDecimal {
-        NegativeDecimal(f)
+impl TryFrom<Decimal> for NegativeDecimal {
+    type Error = ConstraintError;
+
+    fn try_from(f: Decimal) -> Result<NegativeDecimal, ConstraintError> {
+        if f.is_sign_positive() && !f.is_zero() {
+            return Err(ConstraintError::new(
+                "NegativeDecimal",
+                format!("{f} is positive"),
+            ));
+        }
+        Ok(NegativeDecimal(f))
     }
 }
 
@@ -510,60 +639,202 @@ impl From<Decimal> for WrappedDecimal {
     }
 }
 
-impl From<Decimal> for StrictlyPositiveDecimal {
-    fn from(f: Decimal) -> StrictlyPositiveDecimal {
-        StrictlyPositiveDecimal(f)
+impl TryFrom<Decimal> for StrictlyPositiveDecimal {
+    type Error = ConstraintError;
+
+    fn try_from(f: Decimal) -> Result<StrictlyPositiveDecimal, ConstraintError> {
+        if f <= Decimal::ZERO {
+            return Err(ConstraintError::new(
+                "StrictlyPositiveDecimal",
+                format!("{f} is not greater than 0"),
+            ));
+        }
+        Ok(StrictlyPositiveDecimal(f))
+    }
+}
+
+impl TryFrom<f64> for ExemptedEmissionsPercent {
+    type Error = ConstraintError;
+
+    fn try_from(f: f64) -> Result<ExemptedEmissionsPercent, ConstraintError> {
+        if !(0.0..=5.0).contains(&f) {
+            return Err(ConstraintError::new(
+                "ExemptedEmissionsPercent",
+                format!("{f} is not within 0..5"),
+            ));
+        }
+        Ok(ExemptedEmissionsPercent(f))
+    }
+}
+
+impl TryFrom<f64> for Percent {
+    type Error = ConstraintError;
+
+    fn try_from(f: f64) -> Result<Percent, ConstraintError> {
+        if !(0.0..=100.0).contains(&f) {
+            return Err(ConstraintError::new(
+                "Percent",
+                format!("{f} is not within 0..100"),
+            ));
+        }
+        Ok(Percent(f))
+    }
+}
+
+impl TryFrom<f32> for FloatBetween1and3 {
+    type Error = ConstraintError;
+
+    fn try_from(f: f32) -> Result<FloatBetween1and3, ConstraintError> {
+        if !(1.0..=3.0).contains(&f) {
+            return Err(ConstraintError::new(
+                "FloatBetween1and3",
+                format!("{f} is not within 1..3"),
+            ));
+        }
+        Ok(FloatBetween1and3(f))
+    }
+}
+
+impl TryFrom<String> for NonEmptyString {
+    type Error = ConstraintError;
+
+    fn try_from(s: String) -> Result<NonEmptyString, ConstraintError> {
+        if s.is_empty() {
+            return Err(ConstraintError::new("NonEmptyString", "string is empty"));
+        }
+        Ok(NonEmptyString(s))
+    }
+}
+
+impl TryFrom<&str> for NonEmptyString {
+    type Error = ConstraintError;
+
+    fn try_from(s: &str) -> Result<NonEmptyString, ConstraintError> {
+        NonEmptyString::try_from(s.to_string())
     }
 }
 
-impl From<f64> for ExemptedEmissionsPercent {
-    fn from(f: f64) -> ExemptedEmissionsPercent {
-        ExemptedEmissionsPercent(f)
+impl TryFrom<Vec<NonEmptyString>> for NonEmptyStringVec {
+    type Error = ConstraintError;
+
+    fn try_from(v: Vec<NonEmptyString>) -> Result<NonEmptyStringVec, ConstraintError> {
+        check_non_empty_set("NonEmptyStringVec", &v)?;
+        Ok(NonEmptyStringVec(v))
+    }
+}
+
+impl TryFrom<Vec<PfId>> for NonEmptyPfIdVec {
+    type Error = ConstraintError;
+
+    fn try_from(v: Vec<PfId>) -> Result<Self, ConstraintError> {
+        check_non_empty_set("NonEmptyPfIdVec", &v)?;
+        Ok(NonEmptyPfIdVec(v))
     }
 }
 
-impl From<f64> for Percent {
-    fn from(f: f64) -> Percent {
-        Percent(f)
+impl TryFrom<Vec<Urn>> for CompanyIdSet {
+    type Error = ConstraintError;
+
+    fn try_from(v: Vec<Urn>) -> Result<CompanyIdSet, ConstraintError> {
+        check_non_empty_set("CompanyIdSet", &v)?;
+        Ok(CompanyIdSet(v))
     }
 }
 
-impl From<f32> for FloatBetween1and3 {
-    fn from(f: f32) -> FloatBetween1and3 {
-        FloatBetween1and3(f)
+impl TryFrom<Vec<Urn>> for ProductIdSet {
+    type Error = ConstraintError;
+
+    fn try_from(v: Vec<Urn>) -> Result<ProductIdSet, ConstraintError> {
+        check_non_empty_set("ProductIdSet", &v)?;
+        Ok(ProductIdSet(v))
     }
 }
 
-impl From<String> for NonEmptyString {
-    fn from(s: String) -> NonEmptyString {
-        NonEmptyString(s)
+impl TryFrom<Vec<EmissionFactorDS>> for EmissionFactorDSSet {
+    type Error = ConstraintError;
+
+    fn try_from(v: Vec<EmissionFactorDS>) -> Result<EmissionFactorDSSet, ConstraintError> {
+        check_non_empty_set("EmissionFactorDSSet", &v)?;
+        Ok(EmissionFactorDSSet(v))
     }
 }
 
-impl From<Vec<NonEmptyString>> for NonEmptyStringVec {
-    fn from(v: Vec<NonEmptyString>) -> NonEmptyStringVec {
-        NonEmptyStringVec(v)
+impl TryFrom<String> for Urn {
+    type Error = ConstraintError;
+
+    fn try_from(s: String) -> Result<Urn, ConstraintError> {
+        let is_urn = s
+            .get(..4)
+            .map(|scheme| scheme.eq_ignore_ascii_case("urn:"))
+            .unwrap_or_default();
+        if !is_urn {
+            return Err(ConstraintError::new(
+                "Urn",
+                format!("'{s}' does not start with 'urn:'"),
+            ));
+        }
+        Ok(Urn(s))
     }
 }
 
-impl From<Vec<PfId>> for NonEmptyPfIdVec {
-    fn from(v: Vec<PfId>) -> Self {
-        NonEmptyPfIdVec(v)
+impl TryFrom<&str> for Urn {
+    type Error = ConstraintError;
+
+    fn try_from(s: &str) -> Result<Urn, ConstraintError> {
+        Urn::try_from(s.to_string())
     }
 }
 
-impl From<String> for Urn {
-    fn from(s: String) -> Urn {
-        Urn(s)
+impl TryFrom<String> for SpecVersionString {
+    type Error = ConstraintError;
+
+    fn try_from(s: String) -> Result<SpecVersionString, ConstraintError> {
+        // mirrors the pattern `^\d+\.\d+\.\d+(-\d{8})?$` of the JSON schema
+        let is_digits = |p: &str| !p.is_empty() && p.chars().all(|c| c.is_ascii_digit());
+        let (version, suffix) = match s.split_once('-') {
+            Some((version, suffix)) => (version, Some(suffix)),
+            None => (s.as_str(), None),
+        };
+        let parts = version.split('.').collect::<Vec<_>>();
+        let is_valid = parts.len() == 3
+            && parts.iter().all(|p| is_digits(p))
+            && suffix.map(|s| s.len() == 8 && is_digits(s)).unwrap_or(true);
+        if !is_valid {
+            return Err(ConstraintError::new(
+                "SpecVersionString",
+                format!("'{s}' is not of the form MAJOR.MINOR.PATCH(-YYYYMMDD)"),
+            ));
+        }
+        Ok(SpecVersionString(s))
     }
 }
 
-impl From<String> for SpecVersionString {
-    fn from(s: String) -> SpecVersionString {
-        SpecVersionString(s)
+impl TryFrom<&str> for SpecVersionString {
+    type Error = ConstraintError;
+
+    fn try_from(s: &str) -> Result<SpecVersionString, ConstraintError> {
+        SpecVersionString::try_from(s.to_string())
     }
 }
 
+/// checks that a set-like vector is non-empty and free of duplicates, as required by the
+/// `minItems` and `uniqueItems` rules of the schema generated by `json_set_schema`
+fn check_non_empty_set<T: PartialEq>(
+    data_type: &'static str,
+    v: &[T],
+) -> Result<(), ConstraintError> {
+    if v.is_empty() {
+        return Err(ConstraintError::new(data_type, "vector is empty"));
+    }
+    if v.iter().enumerate().any(|(i, item)| v[..i].contains(item)) {
+        return Err(ConstraintError::new(
+            data_type,
+            "vector contains duplicate items",
+        ));
+    }
+    Ok(())
+}
+
 impl JsonSchema for GeographicScope {
     fn schema_name() -> String {
         "GeographicScope".to_string()
@@ -1014,6 +1285,30 @@ pub enum UuidError {
     VersionError,
 }
 
+#[derive(Debug, Clone, PartialEq)]
+/// Error returned when a value violates the constraints declared by the JSON schema of a data type
+pub struct ConstraintError {
+    pub data_type: &'static str,
+    pub message: String,
+}
+
+impl ConstraintError {
+    fn new(data_type: &'static str, message: impl Into<String>) -> Self {
+        ConstraintError {
+            data_type,
+            message: message.into(),
+        }
+    }
+}
+
+impl std::fmt::Display for ConstraintError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "invalid {}: {}", self.data_type, self.message)
+    }
+}
+
+impl std::error::Error for ConstraintError {}
+
 impl JsonSchema for PfId {
     fn schema_name() -> String {
         "PfId".into()
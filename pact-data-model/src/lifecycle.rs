@@ -0,0 +1,240 @@
+/*
+ * Copyright (c) 2024 SINE Foundation e.V.
+ *
+ * This software is released under the MIT License, see LICENSE.
+ */
+
+//! Lifecycle of `ProductFootprint`s: changing a footprint through a new version, deprecating it,
+//! and superseding one or more footprints by a successor footprint.
+//!
+//! All operations leave the footprints untouched if they fail.
+
+use chrono::{DateTime, Utc};
+use schemars::JsonSchema;
+
+use crate::{NonEmptyPfIdVec, PfId, PfStatus, ProductFootprint, VersionInteger};
+
+#[derive(Debug, Clone, PartialEq)]
+/// Error returned when a lifecycle operation would violate the rules of the Tech Spec
+pub enum LifecycleError {
+    /// The footprint with the given id is `Deprecated` and must not be changed anymore
+    NotActive(PfId),
+    /// The footprint with the given id already has the highest possible `version`
+    VersionOverflow(PfId),
+    /// The timestamp of the change is before the footprint's `created` or `updated` timestamp
+    ChangedBeforeLastChange(PfId),
+    /// No footprints to supersede were given
+    NoPredecessors,
+    /// The footprint with the given id cannot be superseded by itself
+    SupersedesItself(PfId),
+    /// The footprint with the given id is to be superseded more than once
+    DuplicatePredecessor(PfId),
+}
+
+impl std::fmt::Display for LifecycleError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            LifecycleError::NotActive(id) => write!(f, "footprint {id} is not active"),
+            LifecycleError::VersionOverflow(id) => {
+                write!(f, "footprint {id} has reached the maximum version")
+            }
+            LifecycleError::ChangedBeforeLastChange(id) => {
+                write!(f, "footprint {id} cannot be changed before its last change")
+            }
+            LifecycleError::NoPredecessors => f.write_str("no footprints to supersede"),
+            LifecycleError::SupersedesItself(id) => {
+                write!(f, "footprint {id} cannot supersede itself")
+            }
+            LifecycleError::DuplicatePredecessor(id) => {
+                write!(f, "footprint {id} is superseded more than once")
+            }
+        }
+    }
+}
+
+impl std::error::Error for LifecycleError {}
+
+impl<T: JsonSchema> ProductFootprint<T> {
+    /// Turns this footprint into its next version, incrementing `version` and setting `updated`
+    /// to `now`. The footprint must be `Active`.
+    pub fn new_version(&mut self, now: DateTime<Utc>) -> Result<(), LifecycleError> {
+        let version = self.next_version(now)?;
+
+        self.version = version;
+        self.updated = Some(now);
+
+        Ok(())
+    }
+
+    /// Deprecates this footprint through a new version with status `Deprecated` and the given
+    /// `statusComment`. The footprint must be `Active`.
+    pub fn deprecate(
+        &mut self,
+        status_comment: impl Into<String>,
+        now: DateTime<Utc>,
+    ) -> Result<(), LifecycleError> {
+        self.new_version(now)?;
+
+        self.status = PfStatus::Deprecated;
+        self.status_comment = Some(status_comment.into());
+
+        Ok(())
+    }
+
+    /// Makes this footprint the successor of `predecessors`: their ids are appended to
+    /// `precedingPfIds` and each predecessor is deprecated with a `statusComment` referring to
+    /// this footprint. This footprint and all predecessors must be `Active`.
+    pub fn supersede(
+        &mut self,
+        predecessors: &mut [ProductFootprint<T>],
+        now: DateTime<Utc>,
+    ) -> Result<(), LifecycleError> {
+        if predecessors.is_empty() {
+            return Err(LifecycleError::NoPredecessors);
+        }
+        if self.status != PfStatus::Active {
+            return Err(LifecycleError::NotActive(self.id.clone()));
+        }
+
+        for (i, predecessor) in predecessors.iter().enumerate() {
+            if predecessor.id == self.id {
+                return Err(LifecycleError::SupersedesItself(self.id.clone()));
+            }
+            if predecessors[..i].iter().any(|p| p.id == predecessor.id) {
+                return Err(LifecycleError::DuplicatePredecessor(predecessor.id.clone()));
+            }
+            predecessor.next_version(now)?;
+        }
+
+        let status_comment = format!("Superseded by ProductFootprint {}", self.id);
+        for predecessor in predecessors.iter_mut() {
+            predecessor.deprecate(status_comment.clone(), now)?;
+
+            let preceding_pf_ids = self
+                .preceding_pf_ids
+                .get_or_insert_with(|| NonEmptyPfIdVec(vec![]));
+            if !preceding_pf_ids.0.contains(&predecessor.id) {
+                preceding_pf_ids.0.push(predecessor.id.clone());
+            }
+        }
+
+        Ok(())
+    }
+
+    /// checks that this footprint can be changed at `now`, returning its next version
+    fn next_version(&self, now: DateTime<Utc>) -> Result<VersionInteger, LifecycleError> {
+        if self.status != PfStatus::Active {
+            return Err(LifecycleError::NotActive(self.id.clone()));
+        }
+        if now < self.updated.unwrap_or(self.created) {
+            return Err(LifecycleError::ChangedBeforeLastChange(self.id.clone()));
+        }
+
+        self.version
+            .0
+            .checked_add(1)
+            .map(VersionInteger)
+            .ok_or_else(|| LifecycleError::VersionOverflow(self.id.clone()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_data::example_pf;
+    use chrono::Duration;
+    use uuid::Uuid;
+
+    #[test]
+    fn test_new_version() {
+        let mut pf = example_pf();
+        let now = pf.created + Duration::days(1);
+
+        pf.new_version(now).unwrap();
+        assert_eq!(pf.version, VersionInteger(2));
+        assert_eq!(pf.updated, Some(now));
+        assert_eq!(pf.validate(), vec![]);
+
+        let before = pf.clone();
+        assert_eq!(
+            pf.new_version(now - Duration::hours(1)),
+            Err(LifecycleError::ChangedBeforeLastChange(pf.id.clone()))
+        );
+        pf.version = VersionInteger(i32::MAX);
+        assert_eq!(
+            pf.new_version(now),
+            Err(LifecycleError::VersionOverflow(pf.id.clone()))
+        );
+        pf.version = before.version.clone();
+        assert_eq!(pf, before);
+    }
+
+    #[test]
+    fn test_deprecate() {
+        let mut pf = example_pf();
+        let now = pf.created + Duration::days(1);
+
+        pf.deprecate("Recalculated", now).unwrap();
+        assert_eq!(pf.version, VersionInteger(2));
+        assert_eq!(pf.status, PfStatus::Deprecated);
+        assert_eq!(pf.status_comment, Some("Recalculated".to_string()));
+
+        assert_eq!(
+            pf.deprecate("Again", now),
+            Err(LifecycleError::NotActive(pf.id.clone()))
+        );
+        assert_eq!(
+            pf.new_version(now),
+            Err(LifecycleError::NotActive(pf.id.clone()))
+        );
+    }
+
+    #[test]
+    fn test_supersede() {
+        let existing = PfId(Uuid::new_v4());
+        let mut a = example_pf();
+        let mut b = example_pf();
+        b.id = PfId(Uuid::new_v4());
+        let mut successor = example_pf();
+        successor.id = PfId(Uuid::new_v4());
+        successor.preceding_pf_ids = Some(NonEmptyPfIdVec(vec![existing.clone(), a.id.clone()]));
+        let now = a.created + Duration::days(1);
+
+        let mut predecessors = vec![a.clone(), b.clone()];
+        successor.supersede(&mut predecessors, now).unwrap();
+        assert_eq!(
+            successor.preceding_pf_ids,
+            Some(NonEmptyPfIdVec(vec![existing, a.id.clone(), b.id.clone()]))
+        );
+        for p in &predecessors {
+            assert_eq!(p.status, PfStatus::Deprecated);
+            assert_eq!(p.version, VersionInteger(2));
+            assert_eq!(
+                p.status_comment,
+                Some(format!("Superseded by ProductFootprint {}", successor.id))
+            );
+        }
+
+        a.status = PfStatus::Deprecated;
+        let mut predecessors = vec![b.clone(), a.clone()];
+        assert_eq!(
+            successor.supersede(&mut predecessors, now),
+            Err(LifecycleError::NotActive(a.id.clone()))
+        );
+        assert_eq!(predecessors, vec![b.clone(), a]);
+
+        let mut predecessors = vec![b.clone(), b.clone()];
+        assert_eq!(
+            successor.supersede(&mut predecessors, now),
+            Err(LifecycleError::DuplicatePredecessor(b.id.clone()))
+        );
+        assert_eq!(
+            successor.supersede(&mut [successor.clone()], now),
+            Err(LifecycleError::SupersedesItself(successor.id.clone()))
+        );
+        assert_eq!(
+            successor.supersede(&mut [], now),
+            Err(LifecycleError::NoPredecessors)
+        );
+    }
+}
@@ -5,9 +5,381 @@
  * This software is released under the MIT License, see LICENSE.
  */
 
+//! Command-line tool for validating, converting and inspecting files of PACT `ProductFootprint`s.
+//!
+//! JSON files may contain a single footprint, an array of footprints or a response of the PACT
+//! API's `ListFootprints` action (i.e. an object with a `data` array). CSV files use the flat
+//! layout of `pact_data_model::to_csv`. The format of a file is derived from its extension unless
+//! given explicitly.
+
+use std::collections::BTreeMap;
+use std::fs::File;
+use std::io::{self, Read, Write};
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+
+use clap::{Parser, Subcommand, ValueEnum};
 use pact_data_model::*;
-use std::io::Error;
+use rust_decimal::Decimal;
+use serde_json::Value;
+
+#[derive(Parser)]
+#[command(version, about = "Validate, convert and inspect PACT footprint files")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Checks the footprints of a file against the JSON Schema and the rules of the Tech Spec
+    Validate {
+        file: PathBuf,
+        #[arg(long)]
+        format: Option<Format>,
+    },
+    /// Converts footprints between JSON and CSV, reading from stdin and writing to stdout unless
+    /// files are given
+    Convert {
+        #[arg(long)]
+        from: Format,
+        #[arg(long)]
+        to: Format,
+        #[arg(long, short)]
+        input: Option<PathBuf>,
+        #[arg(long, short)]
+        output: Option<PathBuf>,
+    },
+    /// Writes the JSON Schema of `ProductFootprint` to a directory
+    Schema {
+        #[arg(long)]
+        out: PathBuf,
+    },
+    /// Prints the number of footprints and their total `pCfExcludingBiogenic` per declared unit
+    /// and status
+    Summarize {
+        file: PathBuf,
+        #[arg(long)]
+        format: Option<Format>,
+    },
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, ValueEnum)]
+enum Format {
+    Json,
+    Csv,
+}
+
+impl Format {
+    fn of(path: &Path) -> Format {
+        match path.extension().and_then(|e| e.to_str()) {
+            Some(extension) if extension.eq_ignore_ascii_case("csv") => Format::Csv,
+            _ => Format::Json,
+        }
+    }
+}
+
+type Error = Box<dyn std::error::Error>;
+
+fn main() -> ExitCode {
+    match run(Cli::parse().command) {
+        Ok(exit_code) => exit_code,
+        Err(e) => {
+            eprintln!("error: {e}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn run(command: Command) -> Result<ExitCode, Error> {
+    match command {
+        Command::Validate { file, format } => {
+            let format = format.unwrap_or_else(|| Format::of(&file));
+            validate(&read(Some(&file))?, format)
+        }
+        Command::Convert {
+            from,
+            to,
+            input,
+            output,
+        } => {
+            let writer: Box<dyn Write> = match output {
+                Some(path) => Box::new(File::create(path)?),
+                None => Box::new(io::stdout()),
+            };
+            convert(&read(input.as_deref())?, from, to, writer)?;
+            Ok(ExitCode::SUCCESS)
+        }
+        Command::Schema { out } => {
+            std::fs::create_dir_all(&out)?;
+            generate_schema(&out)?;
+            Ok(ExitCode::SUCCESS)
+        }
+        Command::Summarize { file, format } => {
+            let format = format.unwrap_or_else(|| Format::of(&file));
+            let footprints = parse(&read(Some(&file))?, format)?;
+            print!("{}", summarize(&footprints));
+            Ok(ExitCode::SUCCESS)
+        }
+    }
+}
+
+/// The contents of `path`, or of stdin if there is none
+fn read(path: Option<&Path>) -> Result<String, io::Error> {
+    let mut contents = String::new();
+    match path {
+        Some(path) => File::open(path)?.read_to_string(&mut contents)?,
+        None => io::stdin().read_to_string(&mut contents)?,
+    };
+    Ok(contents)
+}
+
+fn parse(contents: &str, format: Format) -> Result<Vec<ProductFootprint<Value>>, Error> {
+    Ok(match format {
+        Format::Json => json_footprints(contents)?
+            .into_iter()
+            .map(serde_json::from_value)
+            .collect::<Result<_, _>>()?,
+        Format::Csv => from_csv(contents.as_bytes())?,
+    })
+}
+
+fn validate(contents: &str, format: Format) -> Result<ExitCode, Error> {
+    let (count, errors) = violations(contents, format)?;
+
+    for error in &errors {
+        println!("{error}");
+    }
+    if errors.is_empty() {
+        println!("{count} footprint(s) valid");
+        Ok(ExitCode::SUCCESS)
+    } else {
+        println!("{} violation(s) found", errors.len());
+        Ok(ExitCode::FAILURE)
+    }
+}
+
+/// The number of footprints of `contents` and the violations found in them. Footprints are
+/// referred to by their index in JSON files and by their row in CSV files, with the header being
+/// row 1 (see `CellError`).
+fn violations(contents: &str, format: Format) -> Result<(usize, Vec<String>), Error> {
+    let mut errors = vec![];
+    let mut count = 0;
+
+    match format {
+        Format::Json => {
+            for (i, footprint) in json_footprints(contents)?.into_iter().enumerate() {
+                count += 1;
+                if let Err(violations) = validate_json(&footprint) {
+                    errors.extend(violations.iter().map(|v| format!("footprint {i}: {v}")));
+                    continue;
+                }
+                match serde_json::from_value::<ProductFootprint<Value>>(footprint) {
+                    Ok(pf) => {
+                        errors.extend(pf.validate().iter().map(|v| format!("footprint {i}: {v}")))
+                    }
+                    Err(e) => errors.push(format!("footprint {i}: {e}")),
+                }
+            }
+        }
+        Format::Csv => match from_csv::<Value, _>(contents.as_bytes()) {
+            Ok(footprints) => {
+                for (i, pf) in footprints.iter().enumerate() {
+                    count += 1;
+                    errors.extend(pf.validate().iter().map(|v| format!("row {}: {v}", i + 2)));
+                }
+            }
+            Err(CsvError::Invalid(cell_errors)) => {
+                errors.extend(cell_errors.iter().map(ToString::to_string))
+            }
+            Err(e) => return Err(e.into()),
+        },
+    }
+
+    Ok((count, errors))
+}
+
+/// Converts the footprints of `contents` from format `from` to format `to`, writing them to
+/// `writer`
+fn convert(contents: &str, from: Format, to: Format, mut writer: impl Write) -> Result<(), Error> {
+    let footprints = parse(contents, from)?;
+    match to {
+        Format::Json => {
+            serde_json::to_writer_pretty(&mut writer, &footprints)?;
+            writeln!(writer)?;
+        }
+        Format::Csv => to_csv(&footprints, writer)?,
+    }
+    Ok(())
+}
+
+/// A table of the number of footprints and their total `pCfExcludingBiogenic` per declared unit
+/// and status
+fn summarize(footprints: &[ProductFootprint<Value>]) -> String {
+    let mut totals = BTreeMap::<(String, String), (usize, Decimal)>::new();
+    for pf in footprints {
+        let key = (
+            serde_json::to_value(pf.pcf.declared_unit)
+                .ok()
+                .and_then(|v| v.as_str().map(str::to_string))
+                .unwrap_or_default(),
+            format!("{:?}", pf.status),
+        );
+        let (count, total) = totals.entry(key).or_default();
+        *count += 1;
+        *total += pf.pcf.p_cf_excluding_biogenic.0;
+    }
+
+    let mut summary = format!(
+        "{:<16}{:<12}{:>12}{:>28}\n",
+        "declaredUnit", "status", "footprints", "pCfExcludingBiogenic"
+    );
+    for ((unit, status), (count, total)) in totals {
+        summary += &format!(
+            "{unit:<16}{status:<12}{count:>12}{:>28}\n",
+            total.normalize().to_string()
+        );
+    }
+    summary
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{TimeZone, Utc};
+    use rust_decimal_macros::dec;
+
+    fn example_pf(declared_unit: DeclaredUnit, p_cf: Decimal) -> ProductFootprint<Value> {
+        let pcf = CarbonFootprint::builder()
+            .declared_unit(declared_unit)
+            .unitary_product_amount(StrictlyPositiveDecimal(Decimal::ONE))
+            .p_cf_excluding_biogenic(PositiveDecimal(p_cf))
+            .fossil_ghg_emissions(PositiveDecimal(p_cf))
+            .fossil_carbon_content(PositiveDecimal(Decimal::ZERO))
+            .biogenic_carbon_content(PositiveDecimal(Decimal::ZERO))
+            .characterization_factors(CharacterizationFactors::Ar6)
+            .ipcc_characterization_factors_sources(
+                vec![CharacterizationFactors::Ar6.into()]
+                    .try_into()
+                    .unwrap(),
+            )
+            .cross_sectoral_standards_used(CrossSectoralStandardSet(vec![
+                DeprecatedCrossSectoralStandard::Ghgp,
+            ]))
+            .reference_period_start(Utc.with_ymd_and_hms(2023, 1, 1, 0, 0, 0).unwrap())
+            .reference_period_end(Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap())
+            .exempted_emissions_percent(ExemptedEmissionsPercent(0.0))
+            .packaging_emissions_included(false)
+            .build()
+            .unwrap();
+
+        ProductFootprint::builder()
+            .created(Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap())
+            .company_name(NonEmptyString("My Corp".to_string()))
+            .company_ids(CompanyIdSet(vec![Urn("urn:uuid:4a9ef8a6".to_string())]))
+            .product_ids(ProductIdSet(vec![
+                Urn("urn:gtin:4712345060507".to_string()),
+            ]))
+            .product_category_cpc(NonEmptyString("3342".to_string()))
+            .product_name_company(NonEmptyString("Cardboard".to_string()))
+            .pcf(pcf)
+            .build()
+            .unwrap()
+    }
+
+    /// A footprint whose reference period ends before it starts
+    fn invalid_pf() -> ProductFootprint<Value> {
+        let mut pf = example_pf(DeclaredUnit::Kilogram, Decimal::ONE);
+        pf.pcf.reference_period_end = Utc.with_ymd_and_hms(2022, 1, 1, 0, 0, 0).unwrap();
+        pf
+    }
+
+    fn csv(footprints: &[ProductFootprint<Value>]) -> String {
+        let mut csv = vec![];
+        to_csv(footprints, &mut csv).unwrap();
+        String::from_utf8(csv).unwrap()
+    }
+
+    #[test]
+    fn test_validate() {
+        let valid = example_pf(DeclaredUnit::Kilogram, Decimal::ONE);
+        let json = serde_json::to_string(&[&valid, &invalid_pf()]).unwrap();
+        let (count, errors) = violations(&json, Format::Json).unwrap();
+        assert_eq!(count, 2);
+        assert_eq!(errors.len(), 1);
+        assert!(errors[0].starts_with("footprint 1: /pcf/referencePeriodEnd"));
+
+        // rows of semantic violations are numbered like those of invalid cells
+        let (count, errors) =
+            violations(&csv(&[valid.clone(), invalid_pf()]), Format::Csv).unwrap();
+        assert_eq!(count, 2);
+        assert_eq!(errors.len(), 1);
+        assert!(errors[0].starts_with("row 3: /pcf/referencePeriodEnd"));
+
+        let invalid_cell = csv(&[valid.clone(), valid]).replace(",kilogram,", ",bananas,");
+        let (_, errors) = violations(&invalid_cell, Format::Csv).unwrap();
+        assert_eq!(errors.len(), 2);
+        assert!(errors[0].starts_with("row 2, column pcf.declaredUnit"));
+        assert!(errors[1].starts_with("row 3, column pcf.declaredUnit"));
+    }
+
+    #[test]
+    fn test_convert() {
+        let footprints = vec![
+            example_pf(DeclaredUnit::Kilogram, Decimal::ONE),
+            example_pf(DeclaredUnit::Liter, dec!(2.5)),
+        ];
+        let json = serde_json::to_string(&footprints).unwrap();
+
+        let mut csv = vec![];
+        convert(&json, Format::Json, Format::Csv, &mut csv).unwrap();
+        let mut converted = vec![];
+        convert(
+            std::str::from_utf8(&csv).unwrap(),
+            Format::Csv,
+            Format::Json,
+            &mut converted,
+        )
+        .unwrap();
+
+        assert_eq!(
+            parse(std::str::from_utf8(&converted).unwrap(), Format::Json).unwrap(),
+            footprints
+        );
+    }
+
+    #[test]
+    fn test_summarize() {
+        let footprints = vec![
+            example_pf(DeclaredUnit::Kilogram, Decimal::ONE),
+            example_pf(DeclaredUnit::Kilogram, dec!(2.50)),
+            example_pf(DeclaredUnit::Liter, dec!(3)),
+        ];
+
+        let summary = summarize(&footprints);
+        let lines: Vec<Vec<_>> = summary
+            .lines()
+            .map(|line| line.split_whitespace().collect())
+            .collect();
+        assert_eq!(
+            lines,
+            vec![
+                vec![
+                    "declaredUnit",
+                    "status",
+                    "footprints",
+                    "pCfExcludingBiogenic"
+                ],
+                vec!["kilogram", "Active", "2", "3.5"],
+                vec!["liter", "Active", "1", "3"],
+            ]
+        );
+    }
 
-fn main() -> Result<(), Error> {
-    generate_schema()
+    #[test]
+    fn test_format_of() {
+        assert_eq!(Format::of(Path::new("footprints.CSV")), Format::Csv);
+        assert_eq!(Format::of(Path::new("footprints.json")), Format::Json);
+        assert_eq!(Format::of(Path::new("footprints")), Format::Json);
+    }
 }
@@ -0,0 +1,273 @@
+/*
+ * Copyright (c) 2024 SINE Foundation e.V.
+ *
+ * This software is released under the MIT License, see LICENSE.
+ */
+
+//! Rescaling of `CarbonFootprint`s to a different `unitaryProductAmount` and conversion between
+//! compatible `DeclaredUnit`s, so that footprints declared in different ways can be compared.
+//!
+//! The emission and carbon content values of a `CarbonFootprint` are taken to refer to
+//! `unitaryProductAmount` units of the declared unit. Rescaling therefore scales them
+//! proportionally, whereas converting the declared unit only changes the declared amount.
+
+use rust_decimal::Decimal;
+
+use crate::{
+    CarbonFootprint, DeclaredUnit, NegativeDecimal, PositiveDecimal, StrictlyPositiveDecimal,
+    WrappedDecimal,
+};
+
+#[derive(Debug, Clone, PartialEq)]
+/// Error returned when rescaling or converting a `CarbonFootprint` fails
+pub enum RescaleError {
+    /// There is no conversion between the two declared units
+    IncompatibleUnits {
+        from: DeclaredUnit,
+        to: DeclaredUnit,
+    },
+    /// Converting between mass and volume requires a density
+    DensityRequired {
+        from: DeclaredUnit,
+        to: DeclaredUnit,
+    },
+    /// A rescaled value does not fit into a `Decimal`
+    Overflow,
+}
+
+impl std::fmt::Display for RescaleError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            RescaleError::IncompatibleUnits { from, to } => {
+                write!(f, "cannot convert {from:?} to {to:?}")
+            }
+            RescaleError::DensityRequired { from, to } => {
+                write!(f, "converting {from:?} to {to:?} requires a density")
+            }
+            RescaleError::Overflow => f.write_str("rescaled value is out of range"),
+        }
+    }
+}
+
+impl std::error::Error for RescaleError {}
+
+impl DeclaredUnit {
+    /// The number of `to` units in one unit of `self`, if the two units measure the same
+    /// quantity. `density` (in kg per cubic meter) enables conversions between mass and volume.
+    pub fn conversion_factor(
+        self,
+        to: DeclaredUnit,
+        density: Option<&StrictlyPositiveDecimal>,
+    ) -> Result<Decimal, RescaleError> {
+        use DeclaredUnit::*;
+
+        // factors to the base unit of the quantity, i.e. cubic meter, kilogram or megajoule
+        let to_base = |unit| match unit {
+            Liter => Some((Quantity::Volume, Decimal::new(1, 3))),
+            CubicMeter => Some((Quantity::Volume, Decimal::ONE)),
+            Kilogram => Some((Quantity::Mass, Decimal::ONE)),
+            KilowattHour => Some((Quantity::Energy, Decimal::new(36, 1))),
+            Megajoule => Some((Quantity::Energy, Decimal::ONE)),
+            TonKilometer | SquareMeter => None,
+        };
+
+        if self == to {
+            return Ok(Decimal::ONE);
+        }
+        let (Some((from_quantity, from_factor)), Some((to_quantity, to_factor))) =
+            (to_base(self), to_base(to))
+        else {
+            return Err(RescaleError::IncompatibleUnits { from: self, to });
+        };
+
+        let factor = match (from_quantity, to_quantity) {
+            (from, to) if from == to => Some(Decimal::ONE),
+            (Quantity::Mass, Quantity::Volume) | (Quantity::Volume, Quantity::Mass) => {
+                let Some(density) = density else {
+                    return Err(RescaleError::DensityRequired { from: self, to });
+                };
+                if from_quantity == Quantity::Mass {
+                    Decimal::ONE.checked_div(density.0)
+                } else {
+                    Some(density.0)
+                }
+            }
+            _ => return Err(RescaleError::IncompatibleUnits { from: self, to }),
+        };
+
+        factor
+            .and_then(|f| f.checked_mul(from_factor))
+            .and_then(|f| f.checked_div(to_factor))
+            .ok_or(RescaleError::Overflow)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Quantity {
+    Volume,
+    Mass,
+    Energy,
+}
+
+impl CarbonFootprint {
+    /// Rescales this footprint to the given `unitaryProductAmount`, scaling all emission and
+    /// carbon content values proportionally.
+    pub fn rescale(
+        &self,
+        unitary_product_amount: StrictlyPositiveDecimal,
+    ) -> Result<CarbonFootprint, RescaleError> {
+        let (new_amount, old_amount) = (unitary_product_amount.0, self.unitary_product_amount.0);
+
+        // multiplying before dividing keeps results like 7.2 * 1 / 36 exact
+        let scale = |d: Decimal| {
+            d.checked_mul(new_amount)
+                .and_then(|d| d.checked_div(old_amount))
+                .map(|d| d.normalize())
+                .ok_or(RescaleError::Overflow)
+        };
+        let scale_positive = |d: &PositiveDecimal| scale(d.0).map(PositiveDecimal);
+        let scale_optional =
+            |d: &Option<PositiveDecimal>| d.as_ref().map(scale_positive).transpose();
+
+        Ok(CarbonFootprint {
+            unitary_product_amount,
+            p_cf_excluding_biogenic: scale_positive(&self.p_cf_excluding_biogenic)?,
+            p_cf_including_biogenic: self
+                .p_cf_including_biogenic
+                .as_ref()
+                .map(|d| scale(d.0).map(WrappedDecimal))
+                .transpose()?,
+            fossil_ghg_emissions: scale_positive(&self.fossil_ghg_emissions)?,
+            fossil_carbon_content: scale_positive(&self.fossil_carbon_content)?,
+            biogenic_carbon_content: scale_positive(&self.biogenic_carbon_content)?,
+            d_luc_ghg_emissions: scale_optional(&self.d_luc_ghg_emissions)?,
+            land_management_ghg_emissions: scale_optional(&self.land_management_ghg_emissions)?,
+            other_biogenic_ghg_emissions: scale_optional(&self.other_biogenic_ghg_emissions)?,
+            i_luc_ghg_emissions: scale_optional(&self.i_luc_ghg_emissions)?,
+            biogenic_carbon_withdrawal: self
+                .biogenic_carbon_withdrawal
+                .as_ref()
+                .map(|d| scale(d.0).map(NegativeDecimal))
+                .transpose()?,
+            aircraft_ghg_emissions: scale_optional(&self.aircraft_ghg_emissions)?,
+            packaging_ghg_emissions: scale_optional(&self.packaging_ghg_emissions)?,
+            ..self.clone()
+        })
+    }
+
+    /// Converts this footprint to the given declared unit, converting `unitaryProductAmount`
+    /// accordingly. `density` (in kg per cubic meter) is required for conversions between
+    /// mass and volume.
+    pub fn convert_declared_unit(
+        &self,
+        declared_unit: DeclaredUnit,
+        density: Option<&StrictlyPositiveDecimal>,
+    ) -> Result<CarbonFootprint, RescaleError> {
+        let factor = self
+            .declared_unit
+            .conversion_factor(declared_unit, density)?;
+        let unitary_product_amount = self
+            .unitary_product_amount
+            .0
+            .checked_mul(factor)
+            .ok_or(RescaleError::Overflow)?;
+
+        Ok(CarbonFootprint {
+            declared_unit,
+            unitary_product_amount: StrictlyPositiveDecimal(unitary_product_amount.normalize()),
+            ..self.clone()
+        })
+    }
+
+    /// Converts this footprint to the given declared unit and rescales it to the given
+    /// `unitaryProductAmount`, e.g. to compare footprints per 1 kilogram.
+    pub fn normalize(
+        &self,
+        declared_unit: DeclaredUnit,
+        unitary_product_amount: StrictlyPositiveDecimal,
+        density: Option<&StrictlyPositiveDecimal>,
+    ) -> Result<CarbonFootprint, RescaleError> {
+        self.convert_declared_unit(declared_unit, density)?
+            .rescale(unitary_product_amount)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_data::example_pf;
+    use rust_decimal_macros::dec;
+
+    #[test]
+    fn test_rescale() {
+        let mut cf = example_pf().pcf;
+        cf.unitary_product_amount = StrictlyPositiveDecimal(dec!(2));
+        cf.p_cf_excluding_biogenic = PositiveDecimal(dec!(3));
+        cf.biogenic_carbon_withdrawal = Some(NegativeDecimal(dec!(-1)));
+
+        let rescaled = cf.rescale(StrictlyPositiveDecimal(dec!(5))).unwrap();
+        assert_eq!(rescaled.unitary_product_amount.0, dec!(5));
+        assert_eq!(rescaled.p_cf_excluding_biogenic.0, dec!(7.5));
+        assert_eq!(rescaled.fossil_ghg_emissions.0, dec!(2.5));
+        assert_eq!(rescaled.biogenic_carbon_withdrawal.unwrap().0, dec!(-2.5));
+        assert_eq!(rescaled.d_luc_ghg_emissions, None);
+        assert_eq!(rescaled.reference_period_start, cf.reference_period_start);
+    }
+
+    #[test]
+    fn test_convert_declared_unit() {
+        let mut cf = example_pf().pcf;
+        cf.declared_unit = DeclaredUnit::Liter;
+        cf.unitary_product_amount = StrictlyPositiveDecimal(dec!(500));
+
+        let m3 = cf
+            .convert_declared_unit(DeclaredUnit::CubicMeter, None)
+            .unwrap();
+        assert_eq!(m3.unitary_product_amount.0, dec!(0.5));
+        assert_eq!(m3.p_cf_excluding_biogenic, cf.p_cf_excluding_biogenic);
+
+        let density = StrictlyPositiveDecimal(dec!(800));
+        let kg = cf
+            .convert_declared_unit(DeclaredUnit::Kilogram, Some(&density))
+            .unwrap();
+        assert_eq!(kg.unitary_product_amount.0, dec!(400));
+        let back = kg
+            .convert_declared_unit(DeclaredUnit::Liter, Some(&density))
+            .unwrap();
+        assert_eq!(back.unitary_product_amount.0, dec!(500));
+
+        assert_eq!(
+            cf.convert_declared_unit(DeclaredUnit::Kilogram, None),
+            Err(RescaleError::DensityRequired {
+                from: DeclaredUnit::Liter,
+                to: DeclaredUnit::Kilogram
+            })
+        );
+        assert_eq!(
+            cf.convert_declared_unit(DeclaredUnit::Megajoule, Some(&density)),
+            Err(RescaleError::IncompatibleUnits {
+                from: DeclaredUnit::Liter,
+                to: DeclaredUnit::Megajoule
+            })
+        );
+    }
+
+    #[test]
+    fn test_normalize() {
+        let mut cf = example_pf().pcf;
+        cf.declared_unit = DeclaredUnit::KilowattHour;
+        cf.unitary_product_amount = StrictlyPositiveDecimal(dec!(10));
+        cf.p_cf_excluding_biogenic = PositiveDecimal(dec!(7.2));
+
+        let normalized = cf
+            .normalize(
+                DeclaredUnit::Megajoule,
+                StrictlyPositiveDecimal(Decimal::ONE),
+                None,
+            )
+            .unwrap();
+        assert_eq!(normalized.declared_unit, DeclaredUnit::Megajoule);
+        assert_eq!(normalized.unitary_product_amount.0, Decimal::ONE);
+        assert_eq!(normalized.p_cf_excluding_biogenic.0, dec!(0.2));
+    }
+}
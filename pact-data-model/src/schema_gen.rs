@@ -1,6 +1,7 @@
 use std::{
     fs::File,
     io::{Error, Write},
+    path::Path,
 };
 
 use schemars::{
@@ -12,15 +13,13 @@ use serde_json::{to_string_pretty, Value};
 
 use crate::{NonEmptyString, ProductFootprint, UNRegionOrSubregion, ISO3166CC};
 
-pub fn generate_schema() -> Result<(), Error> {
-    let mut schema = schema_for!(ProductFootprint<Value>);
-
-    update_schema_title(&mut schema);
-    include_geo_scope_props(&mut schema);
+/// Writes the JSON Schema of `ProductFootprint` to `data-model-schema.json` in `out_dir`
+pub fn generate_schema(out_dir: &Path) -> Result<(), Error> {
+    let schema = product_footprint_schema();
 
     let schema_json = to_string_pretty(&schema).expect("Failed to serialize schema");
 
-    let mut file = File::create("./pact-data-model/schema/data-model-schema.json")?;
+    let mut file = File::create(out_dir.join("data-model-schema.json"))?;
 
     file.write_all(schema_json.as_bytes())?;
 
@@ -29,6 +28,16 @@ pub fn generate_schema() -> Result<(), Error> {
     Ok(())
 }
 
+/// The JSON Schema of `ProductFootprint`, as published in `schema/data-model-schema.json`
+pub fn product_footprint_schema() -> RootSchema {
+    let mut schema = schema_for!(ProductFootprint<Value>);
+
+    update_schema_title(&mut schema);
+    include_geo_scope_props(&mut schema);
+
+    schema
+}
+
 fn update_schema_title(schema: &mut RootSchema) {
     if let Some(metadata) = schema.schema.metadata.as_mut() {
         metadata.title = Some("ProductFootprint".to_string());
@@ -68,8 +77,6 @@ fn include_geo_scope_props(schema: &mut RootSchema) {
 #[test]
 
 fn compare_schemas() {
-    use crate::ProductFootprint;
-    use schemars::schema_for;
     use serde_json::{to_string_pretty, Value};
     use std::{fs::File, io::Read};
 
@@ -87,12 +94,8 @@ fn compare_schemas() {
         serde_json::from_str(json_str).unwrap()
     }
 
-    let mut gen_schema = schema_for!(ProductFootprint<Value>);
-    update_schema_title(&mut gen_schema);
-    include_geo_scope_props(&mut gen_schema);
-
     assert_eq!(
         normalize_json(&read_schema("data-model-schema.json")),
-        normalize_json(&to_string_pretty(&gen_schema).unwrap())
+        normalize_json(&to_string_pretty(&product_footprint_schema()).unwrap())
     );
 }
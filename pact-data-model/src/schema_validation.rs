@@ -0,0 +1,567 @@
+/*
+ * Copyright (c) 2024 SINE Foundation e.V.
+ *
+ * This software is released under the MIT License, see LICENSE.
+ */
+
+//! Validation of raw JSON against the JSON Schemas generated from the data model, reporting all
+//! violations at once together with their location.
+//!
+//! The validator supports the subset of JSON Schema (draft 7) emitted by `schemars`: `$ref`s to
+//! local definitions, `type`, `enum`, `const`, `format` (`date-time` and `uuid`), the string,
+//! number, array and object keywords, `allOf`, `anyOf`, `oneOf` and `not`.
+
+use std::collections::HashMap;
+
+use regex::Regex;
+use schemars::schema::{InstanceType, RootSchema, Schema, SchemaObject, SingleOrVec};
+use serde_json::Value;
+
+use crate::product_footprint_schema;
+
+#[derive(Debug, Clone, PartialEq)]
+/// A violation of a JSON Schema by a JSON value
+pub struct SchemaViolation {
+    /// JSON Pointer to the violating value, e.g. `/pcf/declaredUnit`
+    pub instance_path: String,
+    /// The violated JSON Schema keyword, e.g. `required`
+    pub keyword: &'static str,
+    pub message: String,
+}
+
+impl std::fmt::Display for SchemaViolation {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let path = if self.instance_path.is_empty() {
+            "/"
+        } else {
+            &self.instance_path
+        };
+        write!(f, "{path}: {} ({})", self.message, self.keyword)
+    }
+}
+
+/// Validates `value` against the JSON Schema of `ProductFootprint`, see
+/// `product_footprint_schema`
+pub fn validate_json(value: &Value) -> Result<(), Vec<SchemaViolation>> {
+    validate_json_with_schema(&product_footprint_schema(), value)
+}
+
+/// Validates `value` against `schema`
+pub fn validate_json_with_schema(
+    schema: &RootSchema,
+    value: &Value,
+) -> Result<(), Vec<SchemaViolation>> {
+    let mut validator = Validator {
+        root: schema,
+        regexes: HashMap::new(),
+    };
+    let violations = validator.validate_object(&schema.schema, value, "");
+
+    if violations.is_empty() {
+        Ok(())
+    } else {
+        Err(violations)
+    }
+}
+
+/// The footprints of a JSON document holding a single footprint, an array of footprints or a
+/// response of the PACT API's `ListFootprints` action (i.e. an object with a `data` array)
+pub fn json_footprints(contents: &str) -> Result<Vec<Value>, serde_json::Error> {
+    Ok(match serde_json::from_str(contents)? {
+        Value::Array(footprints) => footprints,
+        Value::Object(mut object) => match object.get_mut("data") {
+            Some(Value::Array(footprints)) => std::mem::take(footprints),
+            _ => vec![Value::Object(object)],
+        },
+        footprint => vec![footprint],
+    })
+}
+
+struct Validator<'a> {
+    root: &'a RootSchema,
+    regexes: HashMap<String, Result<Regex, regex::Error>>,
+}
+
+impl Validator<'_> {
+    fn validate(&mut self, schema: &Schema, value: &Value, path: &str) -> Vec<SchemaViolation> {
+        match schema {
+            Schema::Bool(true) => vec![],
+            Schema::Bool(false) => vec![violation(path, "false", "no value is allowed")],
+            Schema::Object(schema) => self.validate_object(schema, value, path),
+        }
+    }
+
+    fn validate_object(
+        &mut self,
+        schema: &SchemaObject,
+        value: &Value,
+        path: &str,
+    ) -> Vec<SchemaViolation> {
+        let mut violations = vec![];
+
+        if let Some(reference) = &schema.reference {
+            let definition = reference
+                .strip_prefix("#/definitions/")
+                .and_then(|name| self.root.definitions.get(name));
+            match definition {
+                Some(definition) => violations.extend(self.validate(definition, value, path)),
+                None => violations.push(violation(
+                    path,
+                    "$ref",
+                    format!("unresolvable reference {reference}"),
+                )),
+            }
+        }
+
+        if let Some(instance_type) = &schema.instance_type {
+            let types = match instance_type {
+                SingleOrVec::Single(t) => std::slice::from_ref(t.as_ref()),
+                SingleOrVec::Vec(ts) => ts.as_slice(),
+            };
+            if !types.iter().any(|t| has_type(value, t)) {
+                let expected = types
+                    .iter()
+                    .map(|t| format!("{t:?}").to_lowercase())
+                    .collect::<Vec<_>>()
+                    .join(" or ");
+                violations.push(violation(path, "type", format!("expected {expected}")));
+                // the remaining keywords are meaningless for a value of the wrong type
+                return violations;
+            }
+        }
+
+        if let Some(values) = &schema.enum_values {
+            if !values.contains(value) {
+                violations.push(violation(
+                    path,
+                    "enum",
+                    format!("{value} is not one of {}", Value::from(values.clone())),
+                ));
+            }
+        }
+        if let Some(expected) = &schema.const_value {
+            if expected != value {
+                violations.push(violation(path, "const", format!("expected {expected}")));
+            }
+        }
+        if let (Some(format), Value::String(s)) = (&schema.format, value) {
+            let valid = match format.as_str() {
+                "date-time" => chrono::DateTime::parse_from_rfc3339(s).is_ok(),
+                "uuid" => uuid::Uuid::parse_str(s).is_ok(),
+                _ => true,
+            };
+            if !valid {
+                violations.push(violation(path, "format", format!("not a valid {format}")));
+            }
+        }
+
+        self.validate_string(schema, value, path, &mut violations);
+        validate_number(schema, value, path, &mut violations);
+        self.validate_array(schema, value, path, &mut violations);
+        self.validate_properties(schema, value, path, &mut violations);
+        self.validate_subschemas(schema, value, path, &mut violations);
+
+        violations
+    }
+
+    fn validate_string(
+        &mut self,
+        schema: &SchemaObject,
+        value: &Value,
+        path: &str,
+        violations: &mut Vec<SchemaViolation>,
+    ) {
+        let (Some(validation), Value::String(s)) = (&schema.string, value) else {
+            return;
+        };
+        let length = s.chars().count() as u32;
+
+        if let Some(min) = validation.min_length.filter(|min| length < *min) {
+            violations.push(violation(
+                path,
+                "minLength",
+                format!("shorter than {min} characters"),
+            ));
+        }
+        if let Some(max) = validation.max_length.filter(|max| length > *max) {
+            violations.push(violation(
+                path,
+                "maxLength",
+                format!("longer than {max} characters"),
+            ));
+        }
+        if let Some(pattern) = &validation.pattern {
+            let regex = self
+                .regexes
+                .entry(pattern.clone())
+                .or_insert_with(|| Regex::new(pattern));
+            match regex {
+                Ok(regex) if !regex.is_match(s) => violations.push(violation(
+                    path,
+                    "pattern",
+                    format!("\"{s}\" does not match {pattern}"),
+                )),
+                Ok(_) => {}
+                // the value cannot be checked, so a broken schema must not pass it silently
+                Err(e) => violations.push(violation(
+                    path,
+                    "pattern",
+                    format!("invalid pattern {pattern}: {e}"),
+                )),
+            }
+        }
+    }
+
+    fn validate_array(
+        &mut self,
+        schema: &SchemaObject,
+        value: &Value,
+        path: &str,
+        violations: &mut Vec<SchemaViolation>,
+    ) {
+        let (Some(validation), Value::Array(items)) = (&schema.array, value) else {
+            return;
+        };
+
+        if let Some(min) = validation
+            .min_items
+            .filter(|min| (items.len() as u32) < *min)
+        {
+            violations.push(violation(
+                path,
+                "minItems",
+                format!("fewer than {min} items"),
+            ));
+        }
+        if let Some(max) = validation
+            .max_items
+            .filter(|max| (items.len() as u32) > *max)
+        {
+            violations.push(violation(
+                path,
+                "maxItems",
+                format!("more than {max} items"),
+            ));
+        }
+        if validation.unique_items == Some(true)
+            && items
+                .iter()
+                .enumerate()
+                .any(|(i, item)| items[..i].contains(item))
+        {
+            violations.push(violation(path, "uniqueItems", "items are not unique"));
+        }
+        match &validation.items {
+            Some(SingleOrVec::Single(item_schema)) => {
+                for (i, item) in items.iter().enumerate() {
+                    violations.extend(self.validate(item_schema, item, &child(path, i)));
+                }
+            }
+            Some(SingleOrVec::Vec(item_schemas)) => {
+                for (i, (item_schema, item)) in item_schemas.iter().zip(items).enumerate() {
+                    violations.extend(self.validate(item_schema, item, &child(path, i)));
+                }
+                if let Some(additional) = &validation.additional_items {
+                    for (i, item) in items.iter().enumerate().skip(item_schemas.len()) {
+                        violations.extend(self.validate(additional, item, &child(path, i)));
+                    }
+                }
+            }
+            None => {}
+        }
+    }
+
+    fn validate_properties(
+        &mut self,
+        schema: &SchemaObject,
+        value: &Value,
+        path: &str,
+        violations: &mut Vec<SchemaViolation>,
+    ) {
+        let (Some(validation), Value::Object(object)) = (&schema.object, value) else {
+            return;
+        };
+
+        for required in &validation.required {
+            if !object.contains_key(required) {
+                violations.push(violation(
+                    path,
+                    "required",
+                    format!("missing property \"{required}\""),
+                ));
+            }
+        }
+        for (name, property) in object {
+            let property_path = child(path, name);
+            if let Some(property_schema) = validation.properties.get(name) {
+                violations.extend(self.validate(property_schema, property, &property_path));
+            } else if let Some(additional) = &validation.additional_properties {
+                if let Schema::Bool(false) = additional.as_ref() {
+                    violations.push(violation(
+                        &property_path,
+                        "additionalProperties",
+                        format!("unknown property \"{name}\""),
+                    ));
+                } else {
+                    violations.extend(self.validate(additional, property, &property_path));
+                }
+            }
+        }
+    }
+
+    fn validate_subschemas(
+        &mut self,
+        schema: &SchemaObject,
+        value: &Value,
+        path: &str,
+        violations: &mut Vec<SchemaViolation>,
+    ) {
+        let Some(subschemas) = &schema.subschemas else {
+            return;
+        };
+
+        for subschema in subschemas.all_of.iter().flatten() {
+            violations.extend(self.validate(subschema, value, path));
+        }
+
+        if let Some(any_of) = &subschemas.any_of {
+            let failures: Vec<_> = any_of
+                .iter()
+                .map(|s| self.validate(s, value, path))
+                .collect();
+            if failures.iter().all(|f| !f.is_empty()) {
+                violations
+                    .extend(self.explain_alternatives(any_of, failures, value, path, "anyOf"));
+            }
+        }
+
+        if let Some(one_of) = &subschemas.one_of {
+            let failures: Vec<_> = one_of
+                .iter()
+                .map(|s| self.validate(s, value, path))
+                .collect();
+            match failures.iter().filter(|f| f.is_empty()).count() {
+                0 => violations
+                    .extend(self.explain_alternatives(one_of, failures, value, path, "oneOf")),
+                1 => {}
+                n => violations.push(violation(
+                    path,
+                    "oneOf",
+                    format!("matches {n} of the alternatives instead of exactly one"),
+                )),
+            }
+        }
+
+        if let Some(not) = &subschemas.not {
+            if self.validate(not, value, path).is_empty() {
+                violations.push(violation(path, "not", "matches a disallowed schema"));
+            }
+        }
+    }
+
+    /// Explains why `value` matches none of `alternatives`. Optional values are described by an
+    /// alternative of the actual schema and `null`; if `value` is not `null`, the violations of
+    /// the actual schema are reported instead of the failed alternatives.
+    fn explain_alternatives(
+        &mut self,
+        alternatives: &[Schema],
+        mut failures: Vec<Vec<SchemaViolation>>,
+        value: &Value,
+        path: &str,
+        keyword: &'static str,
+    ) -> Vec<SchemaViolation> {
+        let candidates: Vec<usize> = (0..alternatives.len())
+            .filter(|i| value.is_null() || !is_null_schema(&alternatives[*i]))
+            .collect();
+
+        match candidates.as_slice() {
+            [i] => std::mem::take(&mut failures[*i]),
+            _ => vec![violation(
+                path,
+                keyword,
+                format!("matches none of the {} alternatives", alternatives.len()),
+            )],
+        }
+    }
+}
+
+fn validate_number(
+    schema: &SchemaObject,
+    value: &Value,
+    path: &str,
+    violations: &mut Vec<SchemaViolation>,
+) {
+    let (Some(validation), Some(n)) = (&schema.number, value.as_f64()) else {
+        return;
+    };
+
+    if let Some(min) = validation.minimum.filter(|min| n < *min) {
+        violations.push(violation(path, "minimum", format!("less than {min}")));
+    }
+    if let Some(max) = validation.maximum.filter(|max| n > *max) {
+        violations.push(violation(path, "maximum", format!("greater than {max}")));
+    }
+    if let Some(min) = validation.exclusive_minimum.filter(|min| n <= *min) {
+        violations.push(violation(
+            path,
+            "exclusiveMinimum",
+            format!("not greater than {min}"),
+        ));
+    }
+    if let Some(max) = validation.exclusive_maximum.filter(|max| n >= *max) {
+        violations.push(violation(
+            path,
+            "exclusiveMaximum",
+            format!("not less than {max}"),
+        ));
+    }
+    if let Some(multiple) = validation.multiple_of {
+        if (n / multiple).fract() != 0.0 {
+            violations.push(violation(
+                path,
+                "multipleOf",
+                format!("not a multiple of {multiple}"),
+            ));
+        }
+    }
+}
+
+fn has_type(value: &Value, instance_type: &InstanceType) -> bool {
+    match instance_type {
+        InstanceType::Null => value.is_null(),
+        InstanceType::Boolean => value.is_boolean(),
+        InstanceType::Object => value.is_object(),
+        InstanceType::Array => value.is_array(),
+        InstanceType::Number => value.is_number(),
+        InstanceType::String => value.is_string(),
+        InstanceType::Integer => {
+            value.is_i64() || value.is_u64() || value.as_f64().is_some_and(|n| n.fract() == 0.0)
+        }
+    }
+}
+
+fn is_null_schema(schema: &Schema) -> bool {
+    matches!(
+        schema,
+        Schema::Object(SchemaObject {
+            instance_type: Some(SingleOrVec::Single(t)),
+            ..
+        }) if **t == InstanceType::Null
+    )
+}
+
+/// The JSON Pointer to the child `token` of `path`
+pub(crate) fn child(path: &str, token: impl ToString) -> String {
+    let token = token.to_string().replace('~', "~0").replace('/', "~1");
+    format!("{path}/{token}")
+}
+
+fn violation(path: &str, keyword: &'static str, message: impl Into<String>) -> SchemaViolation {
+    SchemaViolation {
+        instance_path: path.to_string(),
+        keyword,
+        message: message.into(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_data::example_pf;
+
+    #[test]
+    fn test_validate_valid_json() {
+        let value = serde_json::to_value(example_pf()).unwrap();
+        assert_eq!(validate_json(&value), Ok(()));
+    }
+
+    #[test]
+    fn test_validate_invalid_json() {
+        let mut value = serde_json::to_value(example_pf()).unwrap();
+        value["id"] = "not-a-uuid".into();
+        value["version"] = (-1).into();
+        value["pcf"]["declaredUnit"] = "gallon".into();
+        value["pcf"]["pCfExcludingBiogenic"] = "-1".into();
+        value["pcf"]
+            .as_object_mut()
+            .unwrap()
+            .remove("fossilGhgEmissions");
+        value["pcf"]["dqi"] = serde_json::json!({ "coveragePercent": 150.0 });
+
+        let violations = validate_json(&value).unwrap_err();
+        let mut found: Vec<_> = violations
+            .iter()
+            .map(|v| (v.instance_path.as_str(), v.keyword))
+            .collect();
+        found.sort();
+
+        assert_eq!(
+            found,
+            vec![
+                ("/id", "format"),
+                ("/pcf", "required"),
+                ("/pcf/declaredUnit", "enum"),
+                ("/pcf/dqi", "required"),
+                ("/pcf/dqi", "required"),
+                ("/pcf/dqi", "required"),
+                ("/pcf/dqi", "required"),
+                ("/pcf/dqi", "required"),
+                ("/pcf/dqi/coveragePercent", "maximum"),
+                ("/pcf/pCfExcludingBiogenic", "pattern"),
+                ("/version", "minimum"),
+            ],
+            "{violations:#?}"
+        );
+    }
+
+    #[test]
+    fn test_violation_display() {
+        let mut value = serde_json::to_value(example_pf()).unwrap();
+        value.as_object_mut().unwrap().remove("pcf");
+        value["companyIds"] = serde_json::json!([]);
+
+        let violations = validate_json(&value).unwrap_err();
+        let messages: Vec<_> = violations.iter().map(|v| v.to_string()).collect();
+        assert_eq!(
+            messages,
+            vec![
+                "/: missing property \"pcf\" (required)",
+                "/companyIds: fewer than 1 items (minItems)",
+            ]
+        );
+        assert_eq!(child("/a", "b/c~d"), "/a/b~1c~0d");
+    }
+
+    #[test]
+    fn test_validate_invalid_pattern() {
+        let schema: RootSchema =
+            serde_json::from_value(serde_json::json!({ "type": "string", "pattern": "^(AR" }))
+                .unwrap();
+
+        let violations = validate_json_with_schema(&schema, &"AR5".into()).unwrap_err();
+        assert_eq!(violations.len(), 1);
+        assert_eq!(violations[0].keyword, "pattern");
+        assert!(violations[0].message.starts_with("invalid pattern ^(AR"));
+    }
+
+    #[test]
+    fn test_json_footprints() {
+        let pf = serde_json::json!({ "id": "1" });
+        assert_eq!(
+            json_footprints(r#"{ "id": "1" }"#).unwrap(),
+            vec![pf.clone()]
+        );
+        assert_eq!(
+            json_footprints(r#"[{ "id": "1" }]"#).unwrap(),
+            vec![pf.clone()]
+        );
+        assert_eq!(
+            json_footprints(r#"{ "data": [{ "id": "1" }] }"#).unwrap(),
+            vec![pf]
+        );
+        assert_eq!(
+            json_footprints(r#"{ "data": "1" }"#).unwrap(),
+            vec![serde_json::json!({ "data": "1" })]
+        );
+    }
+}
@@ -0,0 +1,43 @@
+//! Example data shared by the unit tests of this crate
+
+use chrono::{TimeZone, Utc};
+use rust_decimal::Decimal;
+use serde_json::Value;
+
+use crate::*;
+
+pub(crate) fn example_pf() -> ProductFootprint<Value> {
+    let pcf = CarbonFootprint::builder()
+        .declared_unit(DeclaredUnit::Kilogram)
+        .unitary_product_amount(StrictlyPositiveDecimal(Decimal::ONE))
+        .p_cf_excluding_biogenic(PositiveDecimal(Decimal::ONE))
+        .fossil_ghg_emissions(PositiveDecimal(Decimal::ONE))
+        .fossil_carbon_content(PositiveDecimal(Decimal::ZERO))
+        .biogenic_carbon_content(PositiveDecimal(Decimal::ZERO))
+        .characterization_factors(CharacterizationFactors::Ar6)
+        .ipcc_characterization_factors_sources(IpccCharacterizationFactorsSources(vec![
+            IpccCharacterizationFactorsSource::from(CharacterizationFactors::Ar6),
+        ]))
+        .cross_sectoral_standards_used(CrossSectoralStandardSet(vec![
+            DeprecatedCrossSectoralStandard::Ghgp,
+        ]))
+        .reference_period_start(Utc.with_ymd_and_hms(2023, 1, 1, 0, 0, 0).unwrap())
+        .reference_period_end(Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap())
+        .exempted_emissions_percent(ExemptedEmissionsPercent(0.0))
+        .packaging_emissions_included(false)
+        .build()
+        .unwrap();
+
+    ProductFootprint::builder()
+        .created(Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap())
+        .company_name(NonEmptyString("My Corp".to_string()))
+        .company_ids(CompanyIdSet(vec![Urn("urn:uuid:4a9ef8a6".to_string())]))
+        .product_ids(ProductIdSet(vec![
+            Urn("urn:gtin:4712345060507".to_string()),
+        ]))
+        .product_category_cpc(NonEmptyString("3342".to_string()))
+        .product_name_company(NonEmptyString("Cardboard".to_string()))
+        .pcf(pcf)
+        .build()
+        .unwrap()
+}
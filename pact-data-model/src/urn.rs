@@ -0,0 +1,399 @@
+/*
+ * Copyright (c) 2024 SINE Foundation e.V.
+ *
+ * This software is released under the MIT License, see LICENSE.
+ */
+
+//! Parsing of `Urn`s in the namespaces recommended by the Tech Spec for `companyIds` and
+//! `productIds`.
+//!
+//! The namespace identifier and the fixed parts of the recognized namespaces are matched case
+//! insensitively. Converting a `ParsedUrn` back into a `Urn` yields the canonical, lower case
+//! form of these parts.
+
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::{ConstraintError, Urn};
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[serde(try_from = "Urn", into = "Urn")]
+/// A `Urn` parsed according to its namespace
+pub enum ParsedUrn {
+    /// `urn:pathfinder:company:customcode:{buyer,vendor}-assigned:<code>`
+    CompanyCustomCode {
+        assigned_by: CodeAssigner,
+        code: String,
+    },
+    /// `urn:pathfinder:product:customcode:{buyer,vendor}-assigned:<code>`
+    ProductCustomCode {
+        assigned_by: CodeAssigner,
+        code: String,
+    },
+    /// `urn:pathfinder:product:id:<id type>:<id>`, e.g. `urn:pathfinder:product:id:cas:64-17-5`
+    ProductId { id_type: String, id: String },
+    /// `urn:uuid:<uuid>`
+    Uuid(Uuid),
+    /// `urn:epc:id:sgtin:<company prefix>.<item reference>.<serial number>`
+    Sgtin(Sgtin),
+    /// `urn:lei:<lei>`
+    Lei(String),
+    /// Any URN in a namespace not listed above
+    Other(Urn),
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq)]
+/// The party that assigned a custom code
+pub enum CodeAssigner {
+    Buyer,
+    Vendor,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+/// Serialized GTIN in its EPC pure identity form
+pub struct Sgtin {
+    pub company_prefix: String,
+    pub item_reference: String,
+    pub serial_number: String,
+}
+
+impl ParsedUrn {
+    /// The party that assigned the custom code of a `CompanyCustomCode` or `ProductCustomCode`
+    pub fn assigned_by(&self) -> Option<CodeAssigner> {
+        match self {
+            ParsedUrn::CompanyCustomCode { assigned_by, .. }
+            | ParsedUrn::ProductCustomCode { assigned_by, .. } => Some(*assigned_by),
+            _ => None,
+        }
+    }
+
+    /// The custom code of a `CompanyCustomCode` or `ProductCustomCode`
+    pub fn custom_code(&self) -> Option<&str> {
+        match self {
+            ParsedUrn::CompanyCustomCode { code, .. }
+            | ParsedUrn::ProductCustomCode { code, .. } => Some(code),
+            _ => None,
+        }
+    }
+
+    /// The id type and id of a `ProductId`
+    pub fn product_id(&self) -> Option<(&str, &str)> {
+        match self {
+            ParsedUrn::ProductId { id_type, id } => Some((id_type, id)),
+            _ => None,
+        }
+    }
+
+    pub fn uuid(&self) -> Option<Uuid> {
+        match self {
+            ParsedUrn::Uuid(uuid) => Some(*uuid),
+            _ => None,
+        }
+    }
+
+    pub fn sgtin(&self) -> Option<&Sgtin> {
+        match self {
+            ParsedUrn::Sgtin(sgtin) => Some(sgtin),
+            _ => None,
+        }
+    }
+
+    pub fn lei(&self) -> Option<&str> {
+        match self {
+            ParsedUrn::Lei(lei) => Some(lei),
+            _ => None,
+        }
+    }
+}
+
+impl Urn {
+    /// Parses this URN according to its namespace, failing if it belongs to a recognized
+    /// namespace but is malformed
+    pub fn parse(&self) -> Result<ParsedUrn, ConstraintError> {
+        ParsedUrn::try_from(self.clone())
+    }
+}
+
+impl TryFrom<Urn> for ParsedUrn {
+    type Error = ConstraintError;
+
+    fn try_from(urn: Urn) -> Result<ParsedUrn, ConstraintError> {
+        let invalid = |reason: &str| {
+            ConstraintError::new("Urn", format!("'{}' is not a valid {reason}", urn.0))
+        };
+
+        // the scheme is checked when constructing `Urn`s via `TryFrom`, but not for literals
+        let Some((_, rest)) = urn.0.split_once(':') else {
+            return Ok(ParsedUrn::Other(urn));
+        };
+        let Some((nid, nss)) = rest.split_once(':') else {
+            return Ok(ParsedUrn::Other(urn));
+        };
+
+        if nid.eq_ignore_ascii_case("pathfinder") {
+            let parts: Vec<&str> = nss.splitn(4, ':').collect();
+            match parts.as_slice() {
+                [kind, customcode, assigner, code]
+                    if customcode.eq_ignore_ascii_case("customcode") =>
+                {
+                    let assigned_by = if assigner.eq_ignore_ascii_case("buyer-assigned") {
+                        CodeAssigner::Buyer
+                    } else if assigner.eq_ignore_ascii_case("vendor-assigned") {
+                        CodeAssigner::Vendor
+                    } else {
+                        return Err(invalid("custom code URN"));
+                    };
+                    if code.is_empty() {
+                        return Err(invalid("custom code URN"));
+                    }
+                    let code = code.to_string();
+
+                    if kind.eq_ignore_ascii_case("company") {
+                        Ok(ParsedUrn::CompanyCustomCode { assigned_by, code })
+                    } else if kind.eq_ignore_ascii_case("product") {
+                        Ok(ParsedUrn::ProductCustomCode { assigned_by, code })
+                    } else {
+                        Ok(ParsedUrn::Other(urn))
+                    }
+                }
+                [product, id, id_type, value]
+                    if product.eq_ignore_ascii_case("product") && id.eq_ignore_ascii_case("id") =>
+                {
+                    if id_type.is_empty() || value.is_empty() {
+                        return Err(invalid("product id URN"));
+                    }
+                    Ok(ParsedUrn::ProductId {
+                        id_type: id_type.to_ascii_lowercase(),
+                        id: value.to_string(),
+                    })
+                }
+                _ => Ok(ParsedUrn::Other(urn)),
+            }
+        } else if nid.eq_ignore_ascii_case("uuid") {
+            Uuid::parse_str(nss)
+                .map(ParsedUrn::Uuid)
+                .map_err(|_| invalid("UUID URN"))
+        } else if nid.eq_ignore_ascii_case("epc") {
+            let Some(sgtin) = strip_prefix_ignore_case(nss, "id:sgtin:") else {
+                return Ok(ParsedUrn::Other(urn));
+            };
+            parse_sgtin(sgtin)
+                .map(ParsedUrn::Sgtin)
+                .ok_or_else(|| invalid("SGTIN URN"))
+        } else if nid.eq_ignore_ascii_case("lei") {
+            let lei = nss.to_ascii_uppercase();
+            if is_valid_lei(&lei) {
+                Ok(ParsedUrn::Lei(lei))
+            } else {
+                Err(invalid("LEI URN"))
+            }
+        } else {
+            Ok(ParsedUrn::Other(urn))
+        }
+    }
+}
+
+impl TryFrom<&str> for ParsedUrn {
+    type Error = ConstraintError;
+
+    fn try_from(s: &str) -> Result<ParsedUrn, ConstraintError> {
+        Urn::try_from(s)?.parse()
+    }
+}
+
+impl From<ParsedUrn> for Urn {
+    fn from(urn: ParsedUrn) -> Urn {
+        let assigner = |assigned_by| match assigned_by {
+            CodeAssigner::Buyer => "buyer-assigned",
+            CodeAssigner::Vendor => "vendor-assigned",
+        };
+
+        Urn(match urn {
+            ParsedUrn::CompanyCustomCode { assigned_by, code } => format!(
+                "urn:pathfinder:company:customcode:{}:{code}",
+                assigner(assigned_by)
+            ),
+            ParsedUrn::ProductCustomCode { assigned_by, code } => format!(
+                "urn:pathfinder:product:customcode:{}:{code}",
+                assigner(assigned_by)
+            ),
+            ParsedUrn::ProductId { id_type, id } => {
+                format!("urn:pathfinder:product:id:{id_type}:{id}")
+            }
+            ParsedUrn::Uuid(uuid) => format!("urn:uuid:{uuid}"),
+            ParsedUrn::Sgtin(Sgtin {
+                company_prefix,
+                item_reference,
+                serial_number,
+            }) => format!("urn:epc:id:sgtin:{company_prefix}.{item_reference}.{serial_number}"),
+            ParsedUrn::Lei(lei) => format!("urn:lei:{lei}"),
+            ParsedUrn::Other(urn) => return urn,
+        })
+    }
+}
+
+impl std::fmt::Display for ParsedUrn {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&Urn::from(self.clone()).0)
+    }
+}
+
+impl JsonSchema for ParsedUrn {
+    fn schema_name() -> String {
+        Urn::schema_name()
+    }
+
+    fn json_schema(gen: &mut schemars::gen::SchemaGenerator) -> schemars::schema::Schema {
+        Urn::json_schema(gen)
+    }
+}
+
+fn strip_prefix_ignore_case<'a>(s: &'a str, prefix: &str) -> Option<&'a str> {
+    match s.get(..prefix.len()) {
+        Some(head) if head.eq_ignore_ascii_case(prefix) => Some(&s[prefix.len()..]),
+        _ => None,
+    }
+}
+
+/// parses `<company prefix>.<item reference>.<serial number>`, where company prefix and item
+/// reference (including the indicator digit) consist of 13 digits in total
+fn parse_sgtin(s: &str) -> Option<Sgtin> {
+    let mut parts = s.splitn(3, '.');
+    let (company_prefix, item_reference, serial_number) =
+        (parts.next()?, parts.next()?, parts.next()?);
+
+    let is_digits = |s: &str| !s.is_empty() && s.chars().all(|c| c.is_ascii_digit());
+    if !is_digits(company_prefix)
+        || !is_digits(item_reference)
+        || company_prefix.len() + item_reference.len() != 13
+        || serial_number.is_empty()
+    {
+        return None;
+    }
+
+    Some(Sgtin {
+        company_prefix: company_prefix.to_string(),
+        item_reference: item_reference.to_string(),
+        serial_number: serial_number.to_string(),
+    })
+}
+
+/// checks the format and the ISO 7064 MOD 97-10 check digits of an (upper case) LEI
+fn is_valid_lei(lei: &str) -> bool {
+    if lei.len() != 20 || !lei.chars().all(|c| c.is_ascii_alphanumeric()) {
+        return false;
+    }
+
+    let remainder = lei.chars().fold(0u32, |acc, c| {
+        let value = c.to_digit(36).unwrap_or_default();
+        if value < 10 {
+            (acc * 10 + value) % 97
+        } else {
+            (acc * 100 + value) % 97
+        }
+    });
+
+    remainder == 1
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_recognized_namespaces() {
+        assert_eq!(
+            ParsedUrn::try_from("urn:pathfinder:company:customcode:buyer-assigned:1234"),
+            Ok(ParsedUrn::CompanyCustomCode {
+                assigned_by: CodeAssigner::Buyer,
+                code: "1234".to_string()
+            })
+        );
+
+        let product = ParsedUrn::try_from(
+            "urn:pathfinder:product:customcode:vendor-assigned:shipment:shipment-1",
+        )
+        .unwrap();
+        assert_eq!(product.assigned_by(), Some(CodeAssigner::Vendor));
+        assert_eq!(product.custom_code(), Some("shipment:shipment-1"));
+
+        let product_id = ParsedUrn::try_from("urn:pathfinder:product:id:cas:64-17-5").unwrap();
+        assert_eq!(product_id.product_id(), Some(("cas", "64-17-5")));
+
+        let uuid = ParsedUrn::try_from("urn:uuid:69585f84-a5c1-4b4a-9f5c-3f0b1e5a3c21").unwrap();
+        assert_eq!(
+            uuid.uuid(),
+            Some(Uuid::parse_str("69585f84-a5c1-4b4a-9f5c-3f0b1e5a3c21").unwrap())
+        );
+
+        let sgtin = ParsedUrn::try_from("urn:epc:id:sgtin:0614141.112345.400").unwrap();
+        assert_eq!(
+            sgtin.sgtin(),
+            Some(&Sgtin {
+                company_prefix: "0614141".to_string(),
+                item_reference: "112345".to_string(),
+                serial_number: "400".to_string(),
+            })
+        );
+
+        let lei = ParsedUrn::try_from("urn:lei:5493001kjtiigc8y1r12").unwrap();
+        assert_eq!(lei.lei(), Some("5493001KJTIIGC8Y1R12"));
+
+        assert_eq!(
+            ParsedUrn::try_from("urn:epc:id:sgln:4063973.00000.8"),
+            Ok(ParsedUrn::Other(Urn(
+                "urn:epc:id:sgln:4063973.00000.8".to_string()
+            )))
+        );
+        assert_eq!(
+            ParsedUrn::try_from("urn:gtin:4712345060507")
+                .unwrap()
+                .uuid(),
+            None
+        );
+    }
+
+    #[test]
+    fn test_parse_malformed() {
+        for urn in [
+            "urn:pathfinder:company:customcode:seller-assigned:1234",
+            "urn:pathfinder:product:customcode:vendor-assigned:",
+            "urn:pathfinder:product:id:cas:",
+            "urn:uuid:69585GB6-56T9-6958-E526-6FDGZJHU1326",
+            "urn:epc:id:sgtin:0614141.1123.400",
+            "urn:epc:id:sgtin:0614141.112345",
+            "urn:lei:5493001KJTIIGC8Y1R13",
+            "urn:lei:5493001KJTIIGC8Y1R",
+        ] {
+            assert!(ParsedUrn::try_from(urn).is_err(), "{urn}");
+        }
+    }
+
+    #[test]
+    fn test_serde_roundtrip() {
+        for urn in [
+            "urn:pathfinder:company:customcode:vendor-assigned:abc:def",
+            "urn:pathfinder:product:customcode:buyer-assigned:1234",
+            "urn:pathfinder:product:id:iupac-inchi:1S/C2H6O/c1-2-3/h3H,2H2,1H3",
+            "urn:uuid:69585f84-a5c1-4b4a-9f5c-3f0b1e5a3c21",
+            "urn:epc:id:sgtin:0614141.112345.400",
+            "urn:lei:5493001KJTIIGC8Y1R12",
+            "urn:gtin:4712345060507",
+        ] {
+            let json = serde_json::to_string(urn).unwrap();
+            let parsed: ParsedUrn = serde_json::from_str(&json).unwrap();
+            assert_eq!(parsed.to_string(), urn);
+            assert_eq!(serde_json::to_string(&parsed).unwrap(), json);
+        }
+
+        assert_eq!(
+            ParsedUrn::try_from("URN:Pathfinder:Product:CustomCode:Vendor-Assigned:1234")
+                .unwrap()
+                .to_string(),
+            "urn:pathfinder:product:customcode:vendor-assigned:1234"
+        );
+        assert!(serde_json::from_str::<ParsedUrn>(r#""urn:uuid:1234""#).is_err());
+    }
+}
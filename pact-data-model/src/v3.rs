@@ -0,0 +1,672 @@
+/*
+ * Copyright (c) 2024 SINE Foundation e.V.
+ *
+ * This software is released under the MIT License, see LICENSE.
+ */
+
+//! Data Types "ProductFootprint" and "CarbonFootprint" of Tech Spec Version 3, together with
+//! conversions from and to their Version 2 counterparts of the crate root.
+//!
+//! Conversions are strict through `TryFrom`, failing with a `ConversionError` that lists every
+//! property which could not be carried over. A property is considered "not carried over" whenever
+//! converting the result back would not restore its value. Lossy conversions that only report
+//! such properties are available through `ProductFootprint::from_v2_lossy` and
+//! `ProductFootprint::into_v2_lossy`.
+//!
+//! The `specVersion` of a converted footprint is `SPEC_VERSION` for Version 3 and
+//! `DEFAULT_SPEC_VERSION` for Version 2; any other `specVersion` is reported as not carried over.
+//!
+//! Version 3 splits the land management emissions of Version 2 into fossil emissions, biogenic CO2
+//! emissions and biogenic CO2 removals. `landManagementGhgEmissions` of Version 2 corresponds to
+//! `landManagementBiogenicCO2Emissions`, as Version 2 accounts fossil land management emissions in
+//! `fossilGhgEmissions`, and `otherBiogenicGhgEmissions` corresponds to `biogenicNonCO2Emissions`.
+
+use chrono::{DateTime, Utc};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    check_non_empty_set, Assurance, BiogenicAccountingMethodology, CharacterizationFactors,
+    CompanyIdSet, ConstraintError, CrossSectoralStandard, DataModelExtension,
+    DataQualityIndicators, DeprecatedCrossSectoralStandard, EmissionFactorDSSet,
+    ExemptedEmissionsPercent, GeographicScope, IpccCharacterizationFactorsSource,
+    IpccCharacterizationFactorsSources, NonEmptyPfIdVec, NonEmptyString, Percent, PfId, PfStatus,
+    PositiveDecimal, ProductIdSet, ProductOrSectorSpecificRuleSet, SpecVersionString,
+    StrictlyPositiveDecimal, Urn, VersionInteger, WrappedDecimal, DEFAULT_SPEC_VERSION,
+};
+
+/// Spec Version set on footprints converted to Version 3
+pub const SPEC_VERSION: &str = "3.0.0";
+
+/// URN prefix of UN CPC codes within `productClassifications`
+pub const UN_CPC_URN_PREFIX: &str = "urn:pact:productclassification:un-cpc:";
+
+#[derive(Debug, Serialize, Deserialize, Clone, JsonSchema, PartialEq)]
+#[serde(rename_all = "camelCase")]
+/// Data Type "ProductFootprint" of Tech Spec Version 3
+pub struct ProductFootprint<T: JsonSchema> {
+    pub id: PfId,
+    pub spec_version: SpecVersionString,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub preceding_pf_ids: Option<NonEmptyPfIdVec>,
+    pub created: DateTime<Utc>,
+    pub status: PfStatus,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status_comment: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub validity_period_start: Option<DateTime<Utc>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub validity_period_end: Option<DateTime<Utc>>,
+    pub company_name: NonEmptyString,
+    pub company_ids: CompanyIdSet,
+    pub product_description: String,
+    pub product_ids: ProductIdSet,
+    pub product_classifications: ProductClassificationSet,
+    pub product_name_company: NonEmptyString,
+    pub comment: String,
+
+    pub pcf: CarbonFootprint,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub extensions: Option<Vec<DataModelExtension<T>>>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+/// Data Type "CarbonFootprint" of Tech Spec Version 3
+pub struct CarbonFootprint {
+    pub declared_unit_of_measurement: DeclaredUnit,
+    pub declared_unit_amount: StrictlyPositiveDecimal,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub product_mass_per_declared_unit: Option<PositiveDecimal>,
+
+    pub reference_period_start: DateTime<Utc>,
+    pub reference_period_end: DateTime<Utc>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(flatten)]
+    pub geographic_scope: Option<GeographicScope>,
+
+    pub boundary_processes_description: String,
+
+    pub pcf_excluding_biogenic_uptake: PositiveDecimal,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub pcf_including_biogenic_uptake: Option<WrappedDecimal>,
+    pub fossil_ghg_emissions: PositiveDecimal,
+    pub fossil_carbon_content: PositiveDecimal,
+    pub biogenic_carbon_content: PositiveDecimal,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub land_use_change_ghg_emissions: Option<PositiveDecimal>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub land_management_fossil_ghg_emissions: Option<PositiveDecimal>,
+    #[serde(
+        skip_serializing_if = "Option::is_none",
+        rename = "landManagementBiogenicCO2Emissions"
+    )]
+    pub land_management_biogenic_co2_emissions: Option<PositiveDecimal>,
+    /// The removals as a positive amount
+    #[serde(
+        skip_serializing_if = "Option::is_none",
+        rename = "landManagementBiogenicCO2Removals"
+    )]
+    pub land_management_biogenic_co2_removals: Option<PositiveDecimal>,
+    #[serde(
+        skip_serializing_if = "Option::is_none",
+        rename = "biogenicNonCO2Emissions"
+    )]
+    pub biogenic_non_co2_emissions: Option<PositiveDecimal>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub aircraft_ghg_emissions: Option<PositiveDecimal>,
+
+    pub packaging_emissions_included: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub packaging_ghg_emissions: Option<PositiveDecimal>,
+
+    pub ipcc_characterization_factors: IpccCharacterizationFactorsSources,
+    pub cross_sectoral_standards: CrossSectoralStandardSet,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub product_or_sector_specific_rules: Option<ProductOrSectorSpecificRuleSet>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub biogenic_accounting_methodology: Option<BiogenicAccountingMethodology>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub secondary_emission_factor_sources: Option<EmissionFactorDSSet>,
+
+    pub exempted_emissions_percent: ExemptedEmissionsPercent,
+    pub exempted_emissions_description: String,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub allocation_rules_description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub uncertainty_assessment_description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub primary_data_share: Option<Percent>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub dqi: Option<DataQualityIndicators>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub assurance: Option<Assurance>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, JsonSchema, PartialEq)]
+/// Data Type "DeclaredUnit" of Tech Spec Version 3
+pub enum DeclaredUnit {
+    #[serde(rename = "liter")]
+    Liter,
+    #[serde(rename = "kilogram")]
+    Kilogram,
+    #[serde(rename = "cubic meter")]
+    CubicMeter,
+    #[serde(rename = "kilowatt hour")]
+    KilowattHour,
+    #[serde(rename = "megajoule")]
+    Megajoule,
+    #[serde(rename = "ton kilometer")]
+    TonKilometer,
+    #[serde(rename = "square meter")]
+    SquareMeter,
+    #[serde(rename = "piece")]
+    Piece,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[serde(try_from = "Vec<Urn>")]
+/// Data Type "ProductClassificationSet" of Tech Spec Version 3
+pub struct ProductClassificationSet(pub Vec<Urn>);
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[serde(try_from = "Vec<CrossSectoralStandard>")]
+/// Data Type "CrossSectoralStandardSet" of Tech Spec Version 3
+pub struct CrossSectoralStandardSet(pub Vec<CrossSectoralStandard>);
+
+impl TryFrom<Vec<Urn>> for ProductClassificationSet {
+    type Error = ConstraintError;
+
+    fn try_from(v: Vec<Urn>) -> Result<ProductClassificationSet, ConstraintError> {
+        check_non_empty_set("ProductClassificationSet", &v)?;
+        Ok(ProductClassificationSet(v))
+    }
+}
+
+impl TryFrom<Vec<CrossSectoralStandard>> for CrossSectoralStandardSet {
+    type Error = ConstraintError;
+
+    fn try_from(
+        v: Vec<CrossSectoralStandard>,
+    ) -> Result<CrossSectoralStandardSet, ConstraintError> {
+        check_non_empty_set("CrossSectoralStandardSet", &v)?;
+        Ok(CrossSectoralStandardSet(v))
+    }
+}
+
+impl JsonSchema for ProductClassificationSet {
+    fn schema_name() -> String {
+        "ProductClassificationSet".into()
+    }
+
+    fn json_schema(gen: &mut schemars::gen::SchemaGenerator) -> schemars::schema::Schema {
+        crate::json_set_schema::<Urn>(gen, Some(1))
+    }
+}
+
+impl JsonSchema for CrossSectoralStandardSet {
+    fn schema_name() -> String {
+        "CrossSectoralStandardSet".into()
+    }
+
+    fn json_schema(gen: &mut schemars::gen::SchemaGenerator) -> schemars::schema::Schema {
+        crate::json_set_schema::<CrossSectoralStandard>(gen, Some(1))
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+/// Error returned by conversions between Spec Versions 2 and 3
+pub struct ConversionError {
+    /// JSON pointers to the properties of the source footprint that could not be carried over
+    pub fields: Vec<String>,
+}
+
+impl std::fmt::Display for ConversionError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "could not carry over {}", self.fields.join(", "))
+    }
+}
+
+impl std::error::Error for ConversionError {}
+
+impl From<crate::DeclaredUnit> for DeclaredUnit {
+    fn from(unit: crate::DeclaredUnit) -> Self {
+        match unit {
+            crate::DeclaredUnit::Liter => DeclaredUnit::Liter,
+            crate::DeclaredUnit::Kilogram => DeclaredUnit::Kilogram,
+            crate::DeclaredUnit::CubicMeter => DeclaredUnit::CubicMeter,
+            crate::DeclaredUnit::KilowattHour => DeclaredUnit::KilowattHour,
+            crate::DeclaredUnit::Megajoule => DeclaredUnit::Megajoule,
+            crate::DeclaredUnit::TonKilometer => DeclaredUnit::TonKilometer,
+            crate::DeclaredUnit::SquareMeter => DeclaredUnit::SquareMeter,
+        }
+    }
+}
+
+impl TryFrom<DeclaredUnit> for crate::DeclaredUnit {
+    type Error = DeclaredUnit;
+
+    fn try_from(unit: DeclaredUnit) -> Result<Self, DeclaredUnit> {
+        match unit {
+            DeclaredUnit::Liter => Ok(crate::DeclaredUnit::Liter),
+            DeclaredUnit::Kilogram => Ok(crate::DeclaredUnit::Kilogram),
+            DeclaredUnit::CubicMeter => Ok(crate::DeclaredUnit::CubicMeter),
+            DeclaredUnit::KilowattHour => Ok(crate::DeclaredUnit::KilowattHour),
+            DeclaredUnit::Megajoule => Ok(crate::DeclaredUnit::Megajoule),
+            DeclaredUnit::TonKilometer => Ok(crate::DeclaredUnit::TonKilometer),
+            DeclaredUnit::SquareMeter => Ok(crate::DeclaredUnit::SquareMeter),
+            DeclaredUnit::Piece => Err(unit),
+        }
+    }
+}
+
+impl From<DeprecatedCrossSectoralStandard> for CrossSectoralStandard {
+    fn from(standard: DeprecatedCrossSectoralStandard) -> Self {
+        match standard {
+            DeprecatedCrossSectoralStandard::Ghgp => CrossSectoralStandard::Ghgp,
+            DeprecatedCrossSectoralStandard::ISO14067 => CrossSectoralStandard::ISO14067,
+            DeprecatedCrossSectoralStandard::ISO14044 => CrossSectoralStandard::ISO14044,
+        }
+    }
+}
+
+impl TryFrom<CrossSectoralStandard> for DeprecatedCrossSectoralStandard {
+    type Error = CrossSectoralStandard;
+
+    fn try_from(standard: CrossSectoralStandard) -> Result<Self, CrossSectoralStandard> {
+        match standard {
+            CrossSectoralStandard::Ghgp => Ok(DeprecatedCrossSectoralStandard::Ghgp),
+            CrossSectoralStandard::ISO14067 => Ok(DeprecatedCrossSectoralStandard::ISO14067),
+            CrossSectoralStandard::ISO14044 => Ok(DeprecatedCrossSectoralStandard::ISO14044),
+            _ => Err(standard),
+        }
+    }
+}
+
+/// picks the Version 2 `characterizationFactors` from a Version 3 `ipccCharacterizationFactors`
+/// list, preferring the most recent assessment report
+fn v2_characterization_factors(
+    sources: &IpccCharacterizationFactorsSources,
+) -> Option<CharacterizationFactors> {
+    [CharacterizationFactors::Ar6, CharacterizationFactors::Ar5]
+        .into_iter()
+        .find(|&cf| {
+            sources
+                .0
+                .contains(&IpccCharacterizationFactorsSource::from(cf))
+        })
+}
+
+impl<T: JsonSchema> ProductFootprint<T> {
+    /// Converts a Version 2 footprint into Version 3, returning the converted footprint together
+    /// with the JSON pointers of all Version 2 properties that could not be carried over.
+    pub fn from_v2_lossy(pf: crate::ProductFootprint<T>) -> (Self, Vec<String>) {
+        let mut dropped = vec![];
+
+        if pf.spec_version.0 != DEFAULT_SPEC_VERSION {
+            dropped.push("/specVersion".to_string());
+        }
+        if pf.version != VersionInteger(1) {
+            dropped.push("/version".to_string());
+        }
+        if pf.updated.is_some() {
+            dropped.push("/updated".to_string());
+        }
+
+        let pcf = pf.pcf;
+        if pcf.i_luc_ghg_emissions.is_some() {
+            dropped.push("/pcf/iLucGhgEmissions".to_string());
+        }
+        if pcf.biogenic_carbon_withdrawal.is_some() {
+            dropped.push("/pcf/biogenicCarbonWithdrawal".to_string());
+        }
+        if v2_characterization_factors(&pcf.ipcc_characterization_factors_sources)
+            != Some(pcf.characterization_factors)
+        {
+            dropped.push("/pcf/characterizationFactors".to_string());
+        }
+
+        let footprint = ProductFootprint {
+            id: pf.id,
+            spec_version: SpecVersionString(SPEC_VERSION.to_string()),
+            preceding_pf_ids: pf.preceding_pf_ids,
+            created: pf.created,
+            status: pf.status,
+            status_comment: pf.status_comment,
+            validity_period_start: pf.validity_period_start,
+            validity_period_end: pf.validity_period_end,
+            company_name: pf.company_name,
+            company_ids: pf.company_ids,
+            product_description: pf.product_description,
+            product_ids: pf.product_ids,
+            product_classifications: ProductClassificationSet(vec![Urn(format!(
+                "{UN_CPC_URN_PREFIX}{}",
+                pf.product_category_cpc.0
+            ))]),
+            product_name_company: pf.product_name_company,
+            comment: pf.comment,
+            pcf: CarbonFootprint {
+                declared_unit_of_measurement: pcf.declared_unit.into(),
+                declared_unit_amount: pcf.unitary_product_amount,
+                product_mass_per_declared_unit: None,
+                reference_period_start: pcf.reference_period_start,
+                reference_period_end: pcf.reference_period_end,
+                geographic_scope: pcf.geographic_scope,
+                boundary_processes_description: pcf.boundary_processes_description,
+                pcf_excluding_biogenic_uptake: pcf.p_cf_excluding_biogenic,
+                pcf_including_biogenic_uptake: pcf.p_cf_including_biogenic,
+                fossil_ghg_emissions: pcf.fossil_ghg_emissions,
+                fossil_carbon_content: pcf.fossil_carbon_content,
+                biogenic_carbon_content: pcf.biogenic_carbon_content,
+                land_use_change_ghg_emissions: pcf.d_luc_ghg_emissions,
+                land_management_fossil_ghg_emissions: None,
+                land_management_biogenic_co2_emissions: pcf.land_management_ghg_emissions,
+                land_management_biogenic_co2_removals: None,
+                biogenic_non_co2_emissions: pcf.other_biogenic_ghg_emissions,
+                aircraft_ghg_emissions: pcf.aircraft_ghg_emissions,
+                packaging_emissions_included: pcf.packaging_emissions_included,
+                packaging_ghg_emissions: pcf.packaging_ghg_emissions,
+                ipcc_characterization_factors: pcf.ipcc_characterization_factors_sources,
+                cross_sectoral_standards: CrossSectoralStandardSet(
+                    pcf.cross_sectoral_standards_used
+                        .0
+                        .into_iter()
+                        .map(CrossSectoralStandard::from)
+                        .collect(),
+                ),
+                product_or_sector_specific_rules: pcf.product_or_sector_specific_rules,
+                biogenic_accounting_methodology: pcf.biogenic_accounting_methodology,
+                secondary_emission_factor_sources: pcf.secondary_emission_factor_sources,
+                exempted_emissions_percent: pcf.exempted_emissions_percent,
+                exempted_emissions_description: pcf.exempted_emissions_description,
+                allocation_rules_description: pcf.allocation_rules_description,
+                uncertainty_assessment_description: pcf.uncertainty_assessment_description,
+                primary_data_share: pcf.primary_data_share,
+                dqi: pcf.dqi,
+                assurance: pcf.assurance,
+            },
+            extensions: pf.extensions,
+        };
+
+        (footprint, dropped)
+    }
+
+    /// Converts this footprint into Version 2, returning the converted footprint together with
+    /// the JSON pointers of all Version 3 properties that could not be carried over.
+    ///
+    /// Fails if a property that is mandatory in Version 2 cannot be derived, i.e. if there is no
+    /// UN CPC code among the `productClassifications`, if the declared unit does not exist in
+    /// Version 2, or if `ipccCharacterizationFactors` contains neither `AR5` nor `AR6`.
+    pub fn into_v2_lossy(
+        self,
+    ) -> Result<(crate::ProductFootprint<T>, Vec<String>), ConversionError> {
+        let mut dropped = vec![];
+        let mut missing = vec![];
+
+        if self.spec_version.0 != SPEC_VERSION {
+            dropped.push("/specVersion".to_string());
+        }
+
+        let mut product_category_cpc = None;
+        for (i, urn) in self.product_classifications.0.iter().enumerate() {
+            match urn.0.strip_prefix(UN_CPC_URN_PREFIX) {
+                Some(cpc) if product_category_cpc.is_none() && !cpc.is_empty() => {
+                    product_category_cpc = Some(NonEmptyString(cpc.to_string()))
+                }
+                _ => dropped.push(format!("/productClassifications/{i}")),
+            }
+        }
+        if product_category_cpc.is_none() {
+            missing.push("/productClassifications".to_string());
+        }
+
+        let pcf = self.pcf;
+        let declared_unit = crate::DeclaredUnit::try_from(pcf.declared_unit_of_measurement).ok();
+        if declared_unit.is_none() {
+            missing.push("/pcf/declaredUnitOfMeasurement".to_string());
+        }
+        if pcf.product_mass_per_declared_unit.is_some() {
+            dropped.push("/pcf/productMassPerDeclaredUnit".to_string());
+        }
+        if pcf.land_management_fossil_ghg_emissions.is_some() {
+            dropped.push("/pcf/landManagementFossilGhgEmissions".to_string());
+        }
+        if pcf.land_management_biogenic_co2_removals.is_some() {
+            dropped.push("/pcf/landManagementBiogenicCO2Removals".to_string());
+        }
+
+        let characterization_factors =
+            v2_characterization_factors(&pcf.ipcc_characterization_factors);
+        if characterization_factors.is_none() {
+            missing.push("/pcf/ipccCharacterizationFactors".to_string());
+        }
+
+        let mut cross_sectoral_standards_used = vec![];
+        for (i, standard) in pcf.cross_sectoral_standards.0.into_iter().enumerate() {
+            match DeprecatedCrossSectoralStandard::try_from(standard) {
+                Ok(standard) => cross_sectoral_standards_used.push(standard),
+                Err(_) => dropped.push(format!("/pcf/crossSectoralStandards/{i}")),
+            }
+        }
+
+        let (Some(product_category_cpc), Some(declared_unit), Some(characterization_factors)) = (
+            product_category_cpc,
+            declared_unit,
+            characterization_factors,
+        ) else {
+            return Err(ConversionError { fields: missing });
+        };
+
+        let footprint = crate::ProductFootprint {
+            id: self.id,
+            spec_version: SpecVersionString(DEFAULT_SPEC_VERSION.to_string()),
+            preceding_pf_ids: self.preceding_pf_ids,
+            version: VersionInteger(1),
+            created: self.created,
+            updated: None,
+            status: self.status,
+            status_comment: self.status_comment,
+            validity_period_start: self.validity_period_start,
+            validity_period_end: self.validity_period_end,
+            company_name: self.company_name,
+            company_ids: self.company_ids,
+            product_description: self.product_description,
+            product_ids: self.product_ids,
+            product_category_cpc,
+            product_name_company: self.product_name_company,
+            comment: self.comment,
+            pcf: crate::CarbonFootprint {
+                declared_unit,
+                unitary_product_amount: pcf.declared_unit_amount,
+                p_cf_excluding_biogenic: pcf.pcf_excluding_biogenic_uptake,
+                p_cf_including_biogenic: pcf.pcf_including_biogenic_uptake,
+                fossil_ghg_emissions: pcf.fossil_ghg_emissions,
+                fossil_carbon_content: pcf.fossil_carbon_content,
+                biogenic_carbon_content: pcf.biogenic_carbon_content,
+                d_luc_ghg_emissions: pcf.land_use_change_ghg_emissions,
+                land_management_ghg_emissions: pcf.land_management_biogenic_co2_emissions,
+                other_biogenic_ghg_emissions: pcf.biogenic_non_co2_emissions,
+                i_luc_ghg_emissions: None,
+                biogenic_carbon_withdrawal: None,
+                aircraft_ghg_emissions: pcf.aircraft_ghg_emissions,
+                characterization_factors,
+                ipcc_characterization_factors_sources: pcf.ipcc_characterization_factors,
+                cross_sectoral_standards_used: crate::CrossSectoralStandardSet(
+                    cross_sectoral_standards_used,
+                ),
+                product_or_sector_specific_rules: pcf.product_or_sector_specific_rules,
+                biogenic_accounting_methodology: pcf.biogenic_accounting_methodology,
+                boundary_processes_description: pcf.boundary_processes_description,
+                reference_period_start: pcf.reference_period_start,
+                reference_period_end: pcf.reference_period_end,
+                geographic_scope: pcf.geographic_scope,
+                secondary_emission_factor_sources: pcf.secondary_emission_factor_sources,
+                exempted_emissions_percent: pcf.exempted_emissions_percent,
+                exempted_emissions_description: pcf.exempted_emissions_description,
+                packaging_emissions_included: pcf.packaging_emissions_included,
+                packaging_ghg_emissions: pcf.packaging_ghg_emissions,
+                allocation_rules_description: pcf.allocation_rules_description,
+                uncertainty_assessment_description: pcf.uncertainty_assessment_description,
+                primary_data_share: pcf.primary_data_share,
+                dqi: pcf.dqi,
+                assurance: pcf.assurance,
+            },
+            extensions: self.extensions,
+        };
+
+        Ok((footprint, dropped))
+    }
+}
+
+impl<T: JsonSchema> TryFrom<crate::ProductFootprint<T>> for ProductFootprint<T> {
+    type Error = ConversionError;
+
+    fn try_from(pf: crate::ProductFootprint<T>) -> Result<Self, ConversionError> {
+        match ProductFootprint::from_v2_lossy(pf) {
+            (footprint, dropped) if dropped.is_empty() => Ok(footprint),
+            (_, dropped) => Err(ConversionError { fields: dropped }),
+        }
+    }
+}
+
+impl<T: JsonSchema> TryFrom<ProductFootprint<T>> for crate::ProductFootprint<T> {
+    type Error = ConversionError;
+
+    fn try_from(pf: ProductFootprint<T>) -> Result<Self, ConversionError> {
+        match pf.into_v2_lossy()? {
+            (footprint, dropped) if dropped.is_empty() => Ok(footprint),
+            (_, dropped) => Err(ConversionError { fields: dropped }),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_data::example_pf;
+    use rust_decimal::Decimal;
+
+    #[test]
+    fn test_v2_v3_roundtrip() {
+        let mut v2 = example_pf();
+        v2.pcf.land_management_ghg_emissions = Some(PositiveDecimal(Decimal::ONE));
+        v2.pcf.other_biogenic_ghg_emissions = Some(PositiveDecimal(Decimal::TWO));
+        let v3 = ProductFootprint::try_from(v2.clone()).unwrap();
+
+        assert_eq!(v3.spec_version, SpecVersionString("3.0.0".to_string()));
+        assert_eq!(
+            v3.product_classifications,
+            ProductClassificationSet(vec![Urn(
+                "urn:pact:productclassification:un-cpc:3342".to_string()
+            )])
+        );
+        assert_eq!(
+            v3.pcf.cross_sectoral_standards,
+            CrossSectoralStandardSet(vec![CrossSectoralStandard::Ghgp])
+        );
+
+        let json = serde_json::to_value(&v3).unwrap();
+        assert_eq!(json["pcf"]["declaredUnitOfMeasurement"], "kilogram");
+        assert_eq!(json["pcf"]["pcfExcludingBiogenicUptake"], "1");
+        assert_eq!(json["pcf"]["landManagementBiogenicCO2Emissions"], "1");
+        assert_eq!(json["pcf"]["biogenicNonCO2Emissions"], "2");
+        assert_eq!(
+            serde_json::from_value::<ProductFootprint<serde_json::Value>>(json).unwrap(),
+            v3
+        );
+
+        assert_eq!(crate::ProductFootprint::try_from(v3).unwrap(), v2);
+    }
+
+    #[test]
+    fn test_v2_to_v3_dropped_fields() {
+        let mut v2 = example_pf();
+        v2.spec_version = SpecVersionString("2.0.0".to_string());
+        v2.version = VersionInteger(2);
+        v2.pcf.i_luc_ghg_emissions = Some(PositiveDecimal(Decimal::ONE));
+        v2.pcf.characterization_factors = CharacterizationFactors::Ar5;
+        v2.pcf.ipcc_characterization_factors_sources = IpccCharacterizationFactorsSources(vec![
+            IpccCharacterizationFactorsSource::from(CharacterizationFactors::Ar5),
+            IpccCharacterizationFactorsSource::from(CharacterizationFactors::Ar6),
+        ]);
+
+        let expected = vec![
+            "/specVersion".to_string(),
+            "/version".to_string(),
+            "/pcf/iLucGhgEmissions".to_string(),
+            "/pcf/characterizationFactors".to_string(),
+        ];
+
+        let (v3, dropped) = ProductFootprint::from_v2_lossy(v2.clone());
+        assert_eq!(dropped, expected);
+        assert_eq!(v3.id, v2.id);
+
+        assert_eq!(
+            ProductFootprint::try_from(v2),
+            Err(ConversionError { fields: expected })
+        );
+    }
+
+    #[test]
+    fn test_v3_to_v2_dropped_fields() {
+        let (mut v3, _) = ProductFootprint::from_v2_lossy(example_pf());
+        v3.spec_version = SpecVersionString("3.0.1".to_string());
+        v3.product_classifications
+            .0
+            .push(Urn("urn:pact:productclassification:cn:4819".to_string()));
+        v3.pcf.product_mass_per_declared_unit = Some(PositiveDecimal(Decimal::ONE));
+        v3.pcf.land_management_fossil_ghg_emissions = Some(PositiveDecimal(Decimal::ONE));
+        v3.pcf.land_management_biogenic_co2_removals = Some(PositiveDecimal(Decimal::ONE));
+        v3.pcf
+            .cross_sectoral_standards
+            .0
+            .push(CrossSectoralStandard::ISO14083);
+
+        let (v2, dropped) = v3.clone().into_v2_lossy().unwrap();
+        assert_eq!(v2.product_category_cpc, NonEmptyString("3342".to_string()));
+        assert_eq!(v2.spec_version.0, DEFAULT_SPEC_VERSION);
+        assert_eq!(
+            dropped,
+            vec![
+                "/specVersion".to_string(),
+                "/productClassifications/1".to_string(),
+                "/pcf/productMassPerDeclaredUnit".to_string(),
+                "/pcf/landManagementFossilGhgEmissions".to_string(),
+                "/pcf/landManagementBiogenicCO2Removals".to_string(),
+                "/pcf/crossSectoralStandards/1".to_string(),
+            ]
+        );
+
+        v3.product_classifications.0.remove(0);
+        v3.pcf.declared_unit_of_measurement = DeclaredUnit::Piece;
+        assert_eq!(
+            crate::ProductFootprint::try_from(v3),
+            Err(ConversionError {
+                fields: vec![
+                    "/productClassifications".to_string(),
+                    "/pcf/declaredUnitOfMeasurement".to_string(),
+                ]
+            })
+        );
+    }
+
+    #[test]
+    fn test_deser_sets() {
+        let urn = r#""urn:pact:productclassification:un-cpc:3342""#;
+        assert!(serde_json::from_str::<ProductClassificationSet>("[]").is_err());
+        assert!(
+            serde_json::from_str::<ProductClassificationSet>(&format!("[{urn},{urn}]")).is_err()
+        );
+        assert!(serde_json::from_str::<ProductClassificationSet>(&format!("[{urn}]")).is_ok());
+
+        assert!(serde_json::from_str::<CrossSectoralStandardSet>("[]").is_err());
+        assert!(
+            serde_json::from_str::<CrossSectoralStandardSet>(r#"["ISO14067","ISO14067"]"#).is_err()
+        );
+        assert!(serde_json::from_str::<CrossSectoralStandardSet>(r#"["ISO14067"]"#).is_ok());
+    }
+}
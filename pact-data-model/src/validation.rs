@@ -0,0 +1,300 @@
+/*
+ * Copyright (c) 2024 SINE Foundation e.V.
+ *
+ * This software is released under the MIT License, see LICENSE.
+ */
+
+//! Semantic validation of `ProductFootprint`s and `CarbonFootprint`s, covering the cross-field
+//! rules of the Tech Spec that cannot be expressed through the types or the JSON schema alone.
+
+use schemars::JsonSchema;
+use serde::Serialize;
+
+use crate::{
+    CarbonFootprint, GeographicScope, ProductFootprint, ProductOrSectorSpecificRuleOperator,
+};
+
+#[derive(Debug, Serialize, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+/// A violation of a semantic rule of the Tech Spec
+pub struct Violation {
+    /// JSON pointer (RFC 6901) to the offending property, relative to the validated value
+    pub path: String,
+    pub rule: Rule,
+}
+
+#[derive(Debug, Serialize, Clone, Copy, PartialEq)]
+#[serde(rename_all = "camelCase")]
+/// The semantic rules checked by `ProductFootprint::validate` and `CarbonFootprint::validate`
+pub enum Rule {
+    /// `updated` must not be before `created`
+    UpdatedBeforeCreated,
+    /// `validityPeriodEnd` must be after `validityPeriodStart`
+    ValidityPeriodEndNotAfterStart,
+    /// `precedingPfIds` must not contain the footprint's own `id`
+    PrecedingPfIdsContainsSelf,
+    /// `referencePeriodEnd` must be after `referencePeriodStart`
+    ReferencePeriodEndNotAfterStart,
+    /// `packagingGhgEmissions` must only be defined if `packagingEmissionsIncluded` is true
+    PackagingGhgEmissionsWithoutPackagingEmissionsIncluded,
+    /// `otherOperatorName` must be defined if `operator` is `Other`
+    OtherOperatorNameMissing,
+    /// `otherOperatorName` must not be defined unless `operator` is `Other`
+    OtherOperatorNameNotAllowed,
+    /// `geographyCountry` must be an assigned ISO 3166-1 alpha-2 code
+    UnknownGeographyCountry,
+    /// `geographyCountrySubdivision` must be an assigned ISO 3166-2 code
+    UnknownGeographyCountrySubdivision,
+}
+
+impl std::fmt::Display for Rule {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let message = match self {
+            Rule::UpdatedBeforeCreated => "updated must not be before created",
+            Rule::ValidityPeriodEndNotAfterStart => {
+                "validityPeriodEnd must be after validityPeriodStart"
+            }
+            Rule::PrecedingPfIdsContainsSelf => {
+                "precedingPfIds must not contain the footprint's id"
+            }
+            Rule::ReferencePeriodEndNotAfterStart => {
+                "referencePeriodEnd must be after referencePeriodStart"
+            }
+            Rule::PackagingGhgEmissionsWithoutPackagingEmissionsIncluded => {
+                "packagingGhgEmissions must only be defined if packagingEmissionsIncluded is true"
+            }
+            Rule::OtherOperatorNameMissing => {
+                "otherOperatorName must be defined if operator is Other"
+            }
+            Rule::OtherOperatorNameNotAllowed => {
+                "otherOperatorName must only be defined if operator is Other"
+            }
+            Rule::UnknownGeographyCountry => "geographyCountry must be an ISO 3166-1 alpha-2 code",
+            Rule::UnknownGeographyCountrySubdivision => {
+                "geographyCountrySubdivision must be an ISO 3166-2 code"
+            }
+        };
+        f.write_str(message)
+    }
+}
+
+impl std::fmt::Display for Violation {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}: {}", self.path, self.rule)
+    }
+}
+
+impl Violation {
+    fn new(path: impl Into<String>, rule: Rule) -> Self {
+        Violation {
+            path: path.into(),
+            rule,
+        }
+    }
+}
+
+impl<T: JsonSchema> ProductFootprint<T> {
+    /// Checks the cross-field rules of the Tech Spec, returning all violations found. An empty
+    /// vector means the footprint is semantically valid.
+    pub fn validate(&self) -> Vec<Violation> {
+        let mut violations = vec![];
+
+        if let Some(updated) = self.updated {
+            if updated < self.created {
+                violations.push(Violation::new("/updated", Rule::UpdatedBeforeCreated));
+            }
+        }
+
+        if let (Some(start), Some(end)) = (self.validity_period_start, self.validity_period_end) {
+            if end <= start {
+                violations.push(Violation::new(
+                    "/validityPeriodEnd",
+                    Rule::ValidityPeriodEndNotAfterStart,
+                ));
+            }
+        }
+
+        if let Some(preceding_pf_ids) = &self.preceding_pf_ids {
+            for (i, pf_id) in preceding_pf_ids.0.iter().enumerate() {
+                if *pf_id == self.id {
+                    violations.push(Violation::new(
+                        format!("/precedingPfIds/{i}"),
+                        Rule::PrecedingPfIdsContainsSelf,
+                    ));
+                }
+            }
+        }
+
+        violations.extend(self.pcf.validate().into_iter().map(|v| Violation {
+            path: format!("/pcf{}", v.path),
+            ..v
+        }));
+
+        violations
+    }
+}
+
+impl CarbonFootprint {
+    /// Checks the cross-field rules of the Tech Spec, returning all violations found. An empty
+    /// vector means the carbon footprint is semantically valid.
+    pub fn validate(&self) -> Vec<Violation> {
+        let mut violations = vec![];
+
+        if self.reference_period_end <= self.reference_period_start {
+            violations.push(Violation::new(
+                "/referencePeriodEnd",
+                Rule::ReferencePeriodEndNotAfterStart,
+            ));
+        }
+
+        match &self.geographic_scope {
+            Some(GeographicScope::Country(country)) if !country.is_valid() => violations.push(
+                Violation::new("/geographyCountry", Rule::UnknownGeographyCountry),
+            ),
+            Some(scope @ GeographicScope::Subdivision(_)) if scope.subdivision().is_none() => {
+                violations.push(Violation::new(
+                    "/geographyCountrySubdivision",
+                    Rule::UnknownGeographyCountrySubdivision,
+                ))
+            }
+            _ => {}
+        }
+
+        if self.packaging_ghg_emissions.is_some() && !self.packaging_emissions_included {
+            violations.push(Violation::new(
+                "/packagingGhgEmissions",
+                Rule::PackagingGhgEmissionsWithoutPackagingEmissionsIncluded,
+            ));
+        }
+
+        if let Some(rules) = &self.product_or_sector_specific_rules {
+            for (i, rule) in rules.0.iter().enumerate() {
+                let path = format!("/productOrSectorSpecificRules/{i}/otherOperatorName");
+                match (&rule.operator, &rule.other_operator_name) {
+                    (ProductOrSectorSpecificRuleOperator::Other, None) => {
+                        violations.push(Violation::new(path, Rule::OtherOperatorNameMissing))
+                    }
+                    (ProductOrSectorSpecificRuleOperator::Other, Some(_)) | (_, None) => {}
+                    (_, Some(_)) => {
+                        violations.push(Violation::new(path, Rule::OtherOperatorNameNotAllowed))
+                    }
+                }
+            }
+        }
+
+        violations
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_data::example_pf;
+    use crate::*;
+    use chrono::Duration;
+    use rust_decimal::Decimal;
+    use uuid::Uuid;
+
+    #[test]
+    fn test_valid_footprint() {
+        assert_eq!(example_pf().validate(), vec![]);
+    }
+
+    #[test]
+    fn test_footprint_rules() {
+        let mut pf = example_pf();
+        pf.updated = Some(pf.created - Duration::days(1));
+        pf.validity_period_start = Some(pf.created);
+        pf.validity_period_end = Some(pf.created);
+        pf.preceding_pf_ids = Some(NonEmptyPfIdVec(vec![PfId(Uuid::new_v4()), pf.id.clone()]));
+
+        assert_eq!(
+            pf.validate(),
+            vec![
+                Violation::new("/updated", Rule::UpdatedBeforeCreated),
+                Violation::new("/validityPeriodEnd", Rule::ValidityPeriodEndNotAfterStart),
+                Violation::new("/precedingPfIds/1", Rule::PrecedingPfIdsContainsSelf),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_carbon_footprint_rules() {
+        let mut pf = example_pf();
+        pf.pcf.reference_period_end = pf.pcf.reference_period_start;
+        pf.pcf.packaging_ghg_emissions = Some(PositiveDecimal(Decimal::ONE));
+        pf.pcf.product_or_sector_specific_rules = Some(ProductOrSectorSpecificRuleSet(vec![
+            ProductOrSectorSpecificRule {
+                operator: ProductOrSectorSpecificRuleOperator::Other,
+                rule_names: NonEmptyStringVec(vec![NonEmptyString("Rule".to_string())]),
+                other_operator_name: None,
+            },
+            ProductOrSectorSpecificRule {
+                operator: ProductOrSectorSpecificRuleOperator::Pef,
+                rule_names: NonEmptyStringVec(vec![NonEmptyString("Rule".to_string())]),
+                other_operator_name: None,
+            },
+            ProductOrSectorSpecificRule {
+                operator: ProductOrSectorSpecificRuleOperator::EPDInternational,
+                rule_names: NonEmptyStringVec(vec![NonEmptyString("Rule".to_string())]),
+                other_operator_name: Some(NonEmptyString("Operator".to_string())),
+            },
+        ]));
+
+        assert_eq!(
+            pf.validate(),
+            vec![
+                Violation::new(
+                    "/pcf/referencePeriodEnd",
+                    Rule::ReferencePeriodEndNotAfterStart
+                ),
+                Violation::new(
+                    "/pcf/packagingGhgEmissions",
+                    Rule::PackagingGhgEmissionsWithoutPackagingEmissionsIncluded
+                ),
+                Violation::new(
+                    "/pcf/productOrSectorSpecificRules/0/otherOperatorName",
+                    Rule::OtherOperatorNameMissing
+                ),
+                Violation::new(
+                    "/pcf/productOrSectorSpecificRules/2/otherOperatorName",
+                    Rule::OtherOperatorNameNotAllowed
+                ),
+            ]
+        );
+
+        pf.pcf.packaging_emissions_included = true;
+        assert_eq!(pf.pcf.validate().len(), 3);
+    }
+
+    #[test]
+    fn test_geographic_scope_rules() {
+        let mut pf = example_pf();
+
+        pf.pcf.geographic_scope = Some(GeographicScope::Country(ISO3166CC("DE".to_string())));
+        assert_eq!(pf.validate(), vec![]);
+        pf.pcf.geographic_scope = Some(GeographicScope::Country(ISO3166CC("XX".to_string())));
+        assert_eq!(
+            pf.validate(),
+            vec![Violation::new(
+                "/pcf/geographyCountry",
+                Rule::UnknownGeographyCountry
+            )]
+        );
+
+        pf.pcf.geographic_scope = Some(GeographicScope::Subdivision(NonEmptyString(
+            "DE-BY".to_string(),
+        )));
+        assert_eq!(pf.validate(), vec![]);
+        pf.pcf.geographic_scope = Some(GeographicScope::Subdivision(NonEmptyString(
+            "DE-ZZ".to_string(),
+        )));
+        assert_eq!(
+            pf.validate(),
+            vec![Violation::new(
+                "/pcf/geographyCountrySubdivision",
+                Rule::UnknownGeographyCountrySubdivision
+            )]
+        );
+    }
+}
@@ -21,7 +21,7 @@ fn test_deser_geography() {
         aircraft_ghg_emissions: None,
         characterization_factors: CharacterizationFactors::Ar6,
         ipcc_characterization_factors_sources: IpccCharacterizationFactorsSources(vec![
-            IpccCharacterizationFactorsSource::from("AR6".to_string()),
+            IpccCharacterizationFactorsSource::from(CharacterizationFactors::Ar6),
         ]),
         cross_sectoral_standards_used: CrossSectoralStandardSet(vec![
             DeprecatedCrossSectoralStandard::Ghgp,
@@ -192,3 +192,177 @@ fn test_deser_geography() {
         );
     }
 }
+
+#[test]
+fn test_deser_newtype_invariants() {
+    assert!(serde_json::from_str::<PositiveDecimal>(r#""0""#).is_ok());
+    assert!(serde_json::from_str::<PositiveDecimal>(r#""-1.5""#).is_err());
+
+    assert!(serde_json::from_str::<StrictlyPositiveDecimal>(r#""0.001""#).is_ok());
+    assert!(serde_json::from_str::<StrictlyPositiveDecimal>(r#""0""#).is_err());
+
+    assert!(serde_json::from_str::<NegativeDecimal>(r#""-1.5""#).is_ok());
+    assert!(serde_json::from_str::<NegativeDecimal>(r#""0""#).is_ok());
+    assert!(serde_json::from_str::<NegativeDecimal>(r#""1.5""#).is_err());
+
+    assert!(serde_json::from_str::<Percent>("100").is_ok());
+    assert!(serde_json::from_str::<Percent>("100.1").is_err());
+    assert!(serde_json::from_str::<ExemptedEmissionsPercent>("5").is_ok());
+    assert!(serde_json::from_str::<ExemptedEmissionsPercent>("5.1").is_err());
+    assert!(serde_json::from_str::<FloatBetween1and3>("1").is_ok());
+    assert!(serde_json::from_str::<FloatBetween1and3>("0.9").is_err());
+
+    assert!(serde_json::from_str::<NonEmptyString>(r#""a""#).is_ok());
+    assert!(serde_json::from_str::<NonEmptyString>(r#""""#).is_err());
+    assert!(serde_json::from_str::<NonEmptyStringVec>(r#"["a", "b"]"#).is_ok());
+    assert!(serde_json::from_str::<NonEmptyStringVec>("[]").is_err());
+    assert!(serde_json::from_str::<NonEmptyStringVec>(r#"["a", "a"]"#).is_err());
+    assert!(serde_json::from_str::<NonEmptyStringVec>(r#"["a", ""]"#).is_err());
+    assert!(
+        serde_json::from_str::<NonEmptyPfIdVec>(r#"["3893bb5d-da16-4dc1-9185-11d97476c254"]"#)
+            .is_ok()
+    );
+    assert!(serde_json::from_str::<NonEmptyPfIdVec>("[]").is_err());
+
+    assert!(serde_json::from_str::<Urn>(
+        r#""urn:pathfinder:company:customcode:buyer-assigned:4321""#
+    )
+    .is_ok());
+    assert!(serde_json::from_str::<Urn>(r#""URN:uuid:69585GB6""#).is_ok());
+    assert!(serde_json::from_str::<Urn>(r#""pathfinder:company""#).is_err());
+
+    assert!(serde_json::from_str::<SpecVersionString>(r#""2.2.0""#).is_ok());
+    assert!(serde_json::from_str::<SpecVersionString>(r#""2.3.0-20240717""#).is_ok());
+    assert!(serde_json::from_str::<SpecVersionString>(r#""2.2""#).is_err());
+    assert!(serde_json::from_str::<SpecVersionString>(r#""2.2.0-beta""#).is_err());
+
+    let urn = r#""urn:pathfinder:company:customcode:buyer-assigned:4321""#;
+    assert!(serde_json::from_str::<CompanyIdSet>(&format!("[{urn}]")).is_ok());
+    assert!(serde_json::from_str::<CompanyIdSet>("[]").is_err());
+    assert!(serde_json::from_str::<ProductIdSet>(&format!("[{urn}, {urn}]")).is_err());
+    assert!(serde_json::from_str::<EmissionFactorDSSet>(
+        r#"[{"name": "ecoinvent", "version": "3.9.1"}, {"name": "ecoinvent", "version": "3.9.1"}]"#
+    )
+    .is_err());
+
+    assert!(serde_json::from_str::<IpccCharacterizationFactorsSource>(r#""AR6""#).is_ok());
+    assert!(serde_json::from_str::<IpccCharacterizationFactorsSource>(r#""AR""#).is_err());
+    assert!(serde_json::from_str::<IpccCharacterizationFactorsSource>(r#""ar5""#).is_err());
+    assert!(
+        serde_json::from_str::<IpccCharacterizationFactorsSources>(r#"["AR5", "AR6"]"#).is_ok()
+    );
+    assert!(serde_json::from_str::<IpccCharacterizationFactorsSources>("[]").is_err());
+    assert!(
+        serde_json::from_str::<IpccCharacterizationFactorsSources>(r#"["AR6", "AR6"]"#).is_err()
+    );
+}
+
+#[test]
+fn test_newtype_try_from() {
+    assert_eq!(
+        PositiveDecimal::try_from(Decimal::ONE),
+        Ok(PositiveDecimal(Decimal::ONE))
+    );
+
+    let err = PositiveDecimal::try_from(Decimal::NEGATIVE_ONE).unwrap_err();
+    assert_eq!(err.data_type, "PositiveDecimal");
+    assert_eq!(err.to_string(), "invalid PositiveDecimal: -1 is negative");
+
+    assert!(StrictlyPositiveDecimal::try_from(Decimal::ZERO).is_err());
+    assert!(NegativeDecimal::try_from(Decimal::ONE).is_err());
+    assert!(Percent::try_from(f64::NAN).is_err());
+    assert!(FloatBetween1and3::try_from(3.5).is_err());
+    assert!(NonEmptyString::try_from("").is_err());
+    assert!(NonEmptyPfIdVec::try_from(vec![]).is_err());
+    assert!(Urn::try_from("urn:lei:5493001KJTIIGC8Y1R12").is_ok());
+    assert!(SpecVersionString::try_from("2.0.0").is_ok());
+    assert!(CompanyIdSet::try_from(vec![]).is_err());
+    assert!(ProductIdSet::try_from(vec![Urn::try_from("urn:gtin:4712345060507").unwrap()]).is_ok());
+    assert!(EmissionFactorDSSet::try_from(vec![]).is_err());
+    assert!(IpccCharacterizationFactorsSource::try_from("AR4").is_ok());
+    assert!(IpccCharacterizationFactorsSource::try_from("SAR").is_err());
+}
+
+#[test]
+fn test_builder_missing_fields() {
+    let err = CarbonFootprint::builder()
+        .declared_unit(DeclaredUnit::Kilogram)
+        .packaging_emissions_included(false)
+        .build()
+        .unwrap_err();
+    assert_eq!(
+        err.fields,
+        vec![
+            "unitaryProductAmount",
+            "pCfExcludingBiogenic",
+            "fossilGhgEmissions",
+            "fossilCarbonContent",
+            "biogenicCarbonContent",
+            "characterizationFactors",
+            "ipccCharacterizationFactorsSources",
+            "crossSectoralStandardsUsed",
+            "referencePeriodStart",
+            "referencePeriodEnd",
+            "exemptedEmissionsPercent",
+        ]
+    );
+
+    let err = ProductFootprint::<serde_json::Value>::builder()
+        .company_name(NonEmptyString("My Corp".to_string()))
+        .build()
+        .unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "missing mandatory fields: created, companyIds, productIds, productCategoryCpc, \
+         productNameCompany, pcf"
+    );
+}
+
+#[test]
+fn test_builder_defaults() {
+    let pcf = CarbonFootprint::builder()
+        .declared_unit(DeclaredUnit::Liter)
+        .unitary_product_amount(StrictlyPositiveDecimal(Decimal::ONE))
+        .p_cf_excluding_biogenic(PositiveDecimal(Decimal::ONE))
+        .fossil_ghg_emissions(PositiveDecimal(Decimal::ONE))
+        .fossil_carbon_content(PositiveDecimal(Decimal::ZERO))
+        .biogenic_carbon_content(PositiveDecimal(Decimal::ZERO))
+        .characterization_factors(CharacterizationFactors::Ar5)
+        .ipcc_characterization_factors_sources(IpccCharacterizationFactorsSources(vec![
+            IpccCharacterizationFactorsSource::from(CharacterizationFactors::Ar5),
+        ]))
+        .cross_sectoral_standards_used(CrossSectoralStandardSet(vec![
+            DeprecatedCrossSectoralStandard::Ghgp,
+        ]))
+        .reference_period_start(Utc.with_ymd_and_hms(2023, 1, 1, 0, 0, 0).unwrap())
+        .reference_period_end(Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap())
+        .exempted_emissions_percent(ExemptedEmissionsPercent(0.0))
+        .packaging_emissions_included(false)
+        .build()
+        .unwrap();
+    assert_eq!(pcf.boundary_processes_description, "");
+    assert_eq!(pcf.exempted_emissions_description, "");
+
+    let build = || {
+        ProductFootprint::<serde_json::Value>::builder()
+            .created(Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap())
+            .company_name(NonEmptyString("My Corp".to_string()))
+            .company_ids(CompanyIdSet(vec![Urn("urn:uuid:4a9ef8a6".to_string())]))
+            .product_ids(ProductIdSet(vec![
+                Urn("urn:gtin:4712345060507".to_string()),
+            ]))
+            .product_category_cpc(NonEmptyString("3342".to_string()))
+            .product_name_company(NonEmptyString("Cardboard".to_string()))
+            .pcf(pcf.clone())
+            .build()
+            .unwrap()
+    };
+    let pf = build();
+    assert_eq!(pf.spec_version, SpecVersionString("2.2.0".to_string()));
+    assert_eq!(pf.version, VersionInteger(1));
+    assert_eq!(pf.status, PfStatus::Active);
+    assert_eq!(pf.product_description, "");
+    assert_eq!(pf.comment, "");
+    assert_ne!(pf.id, build().id);
+    assert_eq!(pf.validate(), vec![]);
+}